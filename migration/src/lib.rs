@@ -1,12 +1,62 @@
 pub use sea_orm_migration::prelude::*;
 
 mod m20220101_000001_create_table;
+mod m20261019_000002_create_transfer_batches_table;
+mod m20261019_000003_create_scheduled_transfers_table;
+mod m20261019_000004_create_beneficiaries_table;
+mod m20261019_000005_create_bank_accounts_table;
+mod m20261019_000006_add_topup_payment_columns;
+mod m20261019_000007_create_virtual_accounts_table;
+mod m20261019_000008_create_qris_payments_table;
+mod m20261019_000009_create_merchants_table;
+mod m20261019_000010_create_refunds_table;
+mod m20261019_000011_create_webhooks_table;
+mod m20261019_000012_create_settlements_table;
+mod m20261019_000013_create_fee_rules_table;
+mod m20261019_000014_create_transaction_limits_table;
+mod m20261019_000015_create_kyc_submissions_table;
+mod m20261019_000016_create_fraud_tables;
+mod m20261019_000017_create_screening_results_table;
+mod m20261019_000018_create_audit_events_table;
+mod m20261019_000019_add_payment_intent_fraud_review;
+mod m20261019_000020_key_qris_payments_on_merchants;
+mod m20261019_000021_add_settlement_fraud_review;
+mod m20261019_000022_add_fee_rule_to_topups_and_withdraws;
+mod m20261019_000023_add_withdraw_payout_reference;
+mod m20261019_000024_add_transfer_batch_item_fraud_review;
+mod m20261019_000025_add_scheduled_transfer_attempt_fraud_review;
 
 pub struct Migrator;
 
 #[async_trait::async_trait]
 impl MigratorTrait for Migrator {
     fn migrations() -> Vec<Box<dyn MigrationTrait>> {
-        vec![Box::new(m20220101_000001_create_table::Migration)]
+        vec![
+            Box::new(m20220101_000001_create_table::Migration),
+            Box::new(m20261019_000002_create_transfer_batches_table::Migration),
+            Box::new(m20261019_000003_create_scheduled_transfers_table::Migration),
+            Box::new(m20261019_000004_create_beneficiaries_table::Migration),
+            Box::new(m20261019_000005_create_bank_accounts_table::Migration),
+            Box::new(m20261019_000006_add_topup_payment_columns::Migration),
+            Box::new(m20261019_000007_create_virtual_accounts_table::Migration),
+            Box::new(m20261019_000008_create_qris_payments_table::Migration),
+            Box::new(m20261019_000009_create_merchants_table::Migration),
+            Box::new(m20261019_000010_create_refunds_table::Migration),
+            Box::new(m20261019_000011_create_webhooks_table::Migration),
+            Box::new(m20261019_000012_create_settlements_table::Migration),
+            Box::new(m20261019_000013_create_fee_rules_table::Migration),
+            Box::new(m20261019_000014_create_transaction_limits_table::Migration),
+            Box::new(m20261019_000015_create_kyc_submissions_table::Migration),
+            Box::new(m20261019_000016_create_fraud_tables::Migration),
+            Box::new(m20261019_000017_create_screening_results_table::Migration),
+            Box::new(m20261019_000018_create_audit_events_table::Migration),
+            Box::new(m20261019_000019_add_payment_intent_fraud_review::Migration),
+            Box::new(m20261019_000020_key_qris_payments_on_merchants::Migration),
+            Box::new(m20261019_000021_add_settlement_fraud_review::Migration),
+            Box::new(m20261019_000022_add_fee_rule_to_topups_and_withdraws::Migration),
+            Box::new(m20261019_000023_add_withdraw_payout_reference::Migration),
+            Box::new(m20261019_000024_add_transfer_batch_item_fraud_review::Migration),
+            Box::new(m20261019_000025_add_scheduled_transfer_attempt_fraud_review::Migration),
+        ]
     }
 }
@@ -0,0 +1,182 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Create Transfer Batches Table
+        let transfer_batches_table = Table::create()
+            .table(TransferBatches::Table)
+            .if_not_exists()
+            .col(
+                ColumnDef::new(TransferBatches::BatchId)
+                    .integer()
+                    .not_null()
+                    .auto_increment()
+                    .primary_key(),
+            )
+            .col(ColumnDef::new(TransferBatches::UserId).integer().not_null())
+            .col(
+                ColumnDef::new(TransferBatches::Status)
+                    .string_len(50)
+                    .not_null()
+                    .default("pending"),
+            )
+            .col(
+                ColumnDef::new(TransferBatches::TotalItems)
+                    .integer()
+                    .not_null()
+                    .default(0),
+            )
+            .col(
+                ColumnDef::new(TransferBatches::TotalAmount)
+                    .big_integer()
+                    .not_null()
+                    .default(0),
+            )
+            .col(
+                ColumnDef::new(TransferBatches::SucceededItems)
+                    .integer()
+                    .not_null()
+                    .default(0),
+            )
+            .col(
+                ColumnDef::new(TransferBatches::FailedItems)
+                    .integer()
+                    .not_null()
+                    .default(0),
+            )
+            .col(ColumnDef::new(TransferBatches::CompletedAt).timestamp())
+            .col(
+                ColumnDef::new(TransferBatches::CreatedAt)
+                    .timestamp()
+                    .default(Expr::current_timestamp()),
+            )
+            .col(
+                ColumnDef::new(TransferBatches::UpdatedAt)
+                    .timestamp()
+                    .default(Expr::current_timestamp()),
+            )
+            .foreign_key(
+                ForeignKey::create()
+                    .name("fk-transfer_batches-user_id")
+                    .from(TransferBatches::Table, TransferBatches::UserId)
+                    .to(Users::Table, Users::UserId)
+                    .on_update(ForeignKeyAction::Cascade)
+                    .on_delete(ForeignKeyAction::Cascade),
+            )
+            .to_owned();
+        manager.create_table(transfer_batches_table).await?;
+
+        // Create Transfer Batch Items Table
+        let transfer_batch_items_table = Table::create()
+            .table(TransferBatchItems::Table)
+            .if_not_exists()
+            .col(
+                ColumnDef::new(TransferBatchItems::ItemId)
+                    .integer()
+                    .not_null()
+                    .auto_increment()
+                    .primary_key(),
+            )
+            .col(
+                ColumnDef::new(TransferBatchItems::BatchId)
+                    .integer()
+                    .not_null(),
+            )
+            .col(
+                ColumnDef::new(TransferBatchItems::LineNo)
+                    .integer()
+                    .not_null(),
+            )
+            .col(
+                ColumnDef::new(TransferBatchItems::TransferTo)
+                    .integer()
+                    .not_null(),
+            )
+            .col(
+                ColumnDef::new(TransferBatchItems::TransferAmount)
+                    .integer()
+                    .not_null(),
+            )
+            .col(
+                ColumnDef::new(TransferBatchItems::Status)
+                    .string_len(50)
+                    .not_null()
+                    .default("pending"),
+            )
+            .col(ColumnDef::new(TransferBatchItems::TransferId).integer())
+            .col(ColumnDef::new(TransferBatchItems::ErrorMessage).text())
+            .col(
+                ColumnDef::new(TransferBatchItems::CreatedAt)
+                    .timestamp()
+                    .default(Expr::current_timestamp()),
+            )
+            .col(
+                ColumnDef::new(TransferBatchItems::UpdatedAt)
+                    .timestamp()
+                    .default(Expr::current_timestamp()),
+            )
+            .foreign_key(
+                ForeignKey::create()
+                    .name("fk-transfer_batch_items-batch_id")
+                    .from(TransferBatchItems::Table, TransferBatchItems::BatchId)
+                    .to(TransferBatches::Table, TransferBatches::BatchId)
+                    .on_update(ForeignKeyAction::Cascade)
+                    .on_delete(ForeignKeyAction::Cascade),
+            )
+            .to_owned();
+        manager.create_table(transfer_batch_items_table).await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(TransferBatchItems::Table).to_owned())
+            .await?;
+        manager
+            .drop_table(Table::drop().table(TransferBatches::Table).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(Iden)]
+enum Users {
+    Table,
+    UserId,
+}
+
+#[derive(Iden)]
+enum TransferBatches {
+    Table,
+    BatchId,
+    UserId,
+    Status,
+    TotalItems,
+    TotalAmount,
+    SucceededItems,
+    FailedItems,
+    CompletedAt,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[derive(Iden)]
+enum TransferBatchItems {
+    Table,
+    ItemId,
+    BatchId,
+    LineNo,
+    TransferTo,
+    TransferAmount,
+    Status,
+    TransferId,
+    ErrorMessage,
+    CreatedAt,
+    UpdatedAt,
+}
@@ -0,0 +1,89 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Payment tracking on Topups. Existing rows were credited on
+        // creation, so they default to paid.
+        let topup_columns = [
+            ColumnDef::new(Topups::Status)
+                .string_len(20)
+                .not_null()
+                .default("paid")
+                .to_owned(),
+            ColumnDef::new(Topups::PaymentReference)
+                .string_len(100)
+                .to_owned(),
+            ColumnDef::new(Topups::PaymentInstructions)
+                .text()
+                .to_owned(),
+            ColumnDef::new(Topups::PaidAt).timestamp().to_owned(),
+        ];
+
+        for mut column in topup_columns {
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(Topups::Table)
+                        .add_column(&mut column)
+                        .to_owned(),
+                )
+                .await?;
+        }
+
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name("idx-topups-payment_reference")
+                    .table(Topups::Table)
+                    .col(Topups::PaymentReference)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("idx-topups-payment_reference")
+                    .table(Topups::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        for column in [
+            Topups::PaidAt,
+            Topups::PaymentInstructions,
+            Topups::PaymentReference,
+            Topups::Status,
+        ] {
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(Topups::Table)
+                        .drop_column(column)
+                        .to_owned(),
+                )
+                .await?;
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Iden)]
+enum Topups {
+    Table,
+    Status,
+    PaymentReference,
+    PaymentInstructions,
+    PaidAt,
+}
@@ -0,0 +1,232 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Create Fee Rules Table
+        let fee_rules_table = Table::create()
+            .table(FeeRules::Table)
+            .if_not_exists()
+            .col(
+                ColumnDef::new(FeeRules::FeeRuleId)
+                    .integer()
+                    .not_null()
+                    .auto_increment()
+                    .primary_key(),
+            )
+            .col(
+                ColumnDef::new(FeeRules::TransactionType)
+                    .string_len(20)
+                    .not_null(),
+            )
+            .col(ColumnDef::new(FeeRules::Method).string_len(50))
+            .col(ColumnDef::new(FeeRules::MerchantId).integer())
+            .col(ColumnDef::new(FeeRules::FeeType).string_len(20).not_null())
+            .col(
+                ColumnDef::new(FeeRules::FlatAmount)
+                    .integer()
+                    .not_null()
+                    .default(0),
+            )
+            .col(
+                ColumnDef::new(FeeRules::PercentageBps)
+                    .integer()
+                    .not_null()
+                    .default(0),
+            )
+            .col(ColumnDef::new(FeeRules::Tiers).text())
+            .col(ColumnDef::new(FeeRules::MinFee).integer())
+            .col(ColumnDef::new(FeeRules::MaxFee).integer())
+            .col(
+                ColumnDef::new(FeeRules::IsActive)
+                    .boolean()
+                    .not_null()
+                    .default(true),
+            )
+            .col(
+                ColumnDef::new(FeeRules::CreatedAt)
+                    .timestamp()
+                    .default(Expr::current_timestamp()),
+            )
+            .col(
+                ColumnDef::new(FeeRules::UpdatedAt)
+                    .timestamp()
+                    .default(Expr::current_timestamp()),
+            )
+            .foreign_key(
+                ForeignKey::create()
+                    .name("fk-fee_rules-merchant_id")
+                    .from(FeeRules::Table, FeeRules::MerchantId)
+                    .to(Merchants::Table, Merchants::MerchantId)
+                    .on_update(ForeignKeyAction::Cascade)
+                    .on_delete(ForeignKeyAction::Cascade),
+            )
+            .to_owned();
+        manager.create_table(fee_rules_table).await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name("idx-fee_rules-transaction_type")
+                    .table(FeeRules::Table)
+                    .col(FeeRules::TransactionType)
+                    .to_owned(),
+            )
+            .await?;
+
+        // Create Fee Revenues Table, the ledger of the revenue account
+        let fee_revenues_table = Table::create()
+            .table(FeeRevenues::Table)
+            .if_not_exists()
+            .col(
+                ColumnDef::new(FeeRevenues::FeeRevenueId)
+                    .integer()
+                    .not_null()
+                    .auto_increment()
+                    .primary_key(),
+            )
+            .col(
+                ColumnDef::new(FeeRevenues::TransactionType)
+                    .string_len(20)
+                    .not_null(),
+            )
+            .col(
+                ColumnDef::new(FeeRevenues::TransactionId)
+                    .integer()
+                    .not_null(),
+            )
+            .col(ColumnDef::new(FeeRevenues::FeeRuleId).integer())
+            .col(ColumnDef::new(FeeRevenues::Amount).integer().not_null())
+            .col(
+                ColumnDef::new(FeeRevenues::CreatedAt)
+                    .timestamp()
+                    .default(Expr::current_timestamp()),
+            )
+            .foreign_key(
+                ForeignKey::create()
+                    .name("fk-fee_revenues-fee_rule_id")
+                    .from(FeeRevenues::Table, FeeRevenues::FeeRuleId)
+                    .to(FeeRules::Table, FeeRules::FeeRuleId)
+                    .on_update(ForeignKeyAction::Cascade)
+                    .on_delete(ForeignKeyAction::SetNull),
+            )
+            .to_owned();
+        manager.create_table(fee_revenues_table).await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name("idx-fee_revenues-transaction")
+                    .table(FeeRevenues::Table)
+                    .col(FeeRevenues::TransactionType)
+                    .col(FeeRevenues::TransactionId)
+                    .to_owned(),
+            )
+            .await?;
+
+        for table in [
+            Topups::Table.into_iden(),
+            Transfers::Table.into_iden(),
+            Withdraws::Table.into_iden(),
+        ] {
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(table)
+                        .add_column(
+                            ColumnDef::new(FeeRules::FeeAmount)
+                                .integer()
+                                .not_null()
+                                .default(0),
+                        )
+                        .to_owned(),
+                )
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        for table in [
+            Topups::Table.into_iden(),
+            Transfers::Table.into_iden(),
+            Withdraws::Table.into_iden(),
+        ] {
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(table)
+                        .drop_column(FeeRules::FeeAmount)
+                        .to_owned(),
+                )
+                .await?;
+        }
+
+        manager
+            .drop_table(Table::drop().table(FeeRevenues::Table).to_owned())
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(FeeRules::Table).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(Iden)]
+enum Merchants {
+    Table,
+    MerchantId,
+}
+
+#[derive(Iden)]
+enum Topups {
+    Table,
+}
+
+#[derive(Iden)]
+enum Transfers {
+    Table,
+}
+
+#[derive(Iden)]
+enum Withdraws {
+    Table,
+}
+
+#[derive(Iden)]
+enum FeeRules {
+    Table,
+    FeeRuleId,
+    TransactionType,
+    Method,
+    MerchantId,
+    FeeType,
+    FlatAmount,
+    PercentageBps,
+    Tiers,
+    MinFee,
+    MaxFee,
+    IsActive,
+    FeeAmount,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[derive(Iden)]
+enum FeeRevenues {
+    Table,
+    FeeRevenueId,
+    TransactionType,
+    TransactionId,
+    FeeRuleId,
+    Amount,
+    CreatedAt,
+}
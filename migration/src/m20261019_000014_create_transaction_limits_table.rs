@@ -0,0 +1,200 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // KYC tier of each user, existing users start unverified
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Users::Table)
+                    .add_column(
+                        ColumnDef::new(Users::KycLevel)
+                            .integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // Create Transaction Limits Table
+        let transaction_limits_table = Table::create()
+            .table(TransactionLimits::Table)
+            .if_not_exists()
+            .col(
+                ColumnDef::new(TransactionLimits::TransactionLimitId)
+                    .integer()
+                    .not_null()
+                    .auto_increment()
+                    .primary_key(),
+            )
+            .col(
+                ColumnDef::new(TransactionLimits::KycLevel)
+                    .integer()
+                    .not_null(),
+            )
+            .col(
+                ColumnDef::new(TransactionLimits::TransactionType)
+                    .string_len(20)
+                    .not_null(),
+            )
+            .col(ColumnDef::new(TransactionLimits::MaxBalance).integer())
+            .col(ColumnDef::new(TransactionLimits::MaxTransactionAmount).integer())
+            .col(ColumnDef::new(TransactionLimits::DailyAmount).integer())
+            .col(ColumnDef::new(TransactionLimits::MonthlyAmount).integer())
+            .col(
+                ColumnDef::new(TransactionLimits::CreatedAt)
+                    .timestamp()
+                    .default(Expr::current_timestamp()),
+            )
+            .col(
+                ColumnDef::new(TransactionLimits::UpdatedAt)
+                    .timestamp()
+                    .default(Expr::current_timestamp()),
+            )
+            .to_owned();
+        manager.create_table(transaction_limits_table).await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name("idx-transaction_limits-kyc_level-transaction_type")
+                    .table(TransactionLimits::Table)
+                    .col(TransactionLimits::KycLevel)
+                    .col(TransactionLimits::TransactionType)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        // Default limits: level 0 is an unverified wallet, level 1 a
+        // verified one
+        let mut insert = Query::insert();
+        insert.into_table(TransactionLimits::Table).columns([
+            TransactionLimits::KycLevel,
+            TransactionLimits::TransactionType,
+            TransactionLimits::MaxBalance,
+            TransactionLimits::MaxTransactionAmount,
+            TransactionLimits::DailyAmount,
+            TransactionLimits::MonthlyAmount,
+        ]);
+
+        for limit in DEFAULT_LIMITS {
+            insert.values_panic([
+                limit.kyc_level.into(),
+                limit.transaction_type.into(),
+                limit.max_balance.into(),
+                limit.max_transaction_amount.into(),
+                limit.daily_amount.into(),
+                limit.monthly_amount.into(),
+            ]);
+        }
+
+        manager.exec_stmt(insert).await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(TransactionLimits::Table).to_owned())
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Users::Table)
+                    .drop_column(Users::KycLevel)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+/// A seeded limit row; `None` means no limit.
+struct DefaultLimit {
+    kyc_level: i32,
+    transaction_type: &'static str,
+    max_balance: Option<i32>,
+    max_transaction_amount: Option<i32>,
+    daily_amount: Option<i32>,
+    monthly_amount: Option<i32>,
+}
+
+const DEFAULT_LIMITS: [DefaultLimit; 6] = [
+    DefaultLimit {
+        kyc_level: 0,
+        transaction_type: "topup",
+        max_balance: Some(2_000_000),
+        max_transaction_amount: Some(2_000_000),
+        daily_amount: None,
+        monthly_amount: Some(20_000_000),
+    },
+    DefaultLimit {
+        kyc_level: 0,
+        transaction_type: "transfer",
+        max_balance: Some(2_000_000),
+        max_transaction_amount: Some(1_000_000),
+        daily_amount: Some(5_000_000),
+        monthly_amount: Some(20_000_000),
+    },
+    DefaultLimit {
+        kyc_level: 0,
+        transaction_type: "withdraw",
+        max_balance: None,
+        max_transaction_amount: Some(1_000_000),
+        daily_amount: Some(2_000_000),
+        monthly_amount: Some(10_000_000),
+    },
+    DefaultLimit {
+        kyc_level: 1,
+        transaction_type: "topup",
+        max_balance: Some(20_000_000),
+        max_transaction_amount: Some(20_000_000),
+        daily_amount: None,
+        monthly_amount: Some(40_000_000),
+    },
+    DefaultLimit {
+        kyc_level: 1,
+        transaction_type: "transfer",
+        max_balance: Some(20_000_000),
+        max_transaction_amount: Some(10_000_000),
+        daily_amount: Some(25_000_000),
+        monthly_amount: Some(40_000_000),
+    },
+    DefaultLimit {
+        kyc_level: 1,
+        transaction_type: "withdraw",
+        max_balance: None,
+        max_transaction_amount: Some(10_000_000),
+        daily_amount: Some(20_000_000),
+        monthly_amount: Some(40_000_000),
+    },
+];
+
+#[derive(Iden)]
+enum Users {
+    Table,
+    KycLevel,
+}
+
+#[derive(Iden)]
+enum TransactionLimits {
+    Table,
+    TransactionLimitId,
+    KycLevel,
+    TransactionType,
+    MaxBalance,
+    MaxTransactionAmount,
+    DailyAmount,
+    MonthlyAmount,
+    CreatedAt,
+    UpdatedAt,
+}
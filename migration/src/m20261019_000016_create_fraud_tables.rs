@@ -0,0 +1,344 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Create Fraud Rules Table
+        let fraud_rules_table = Table::create()
+            .table(FraudRules::Table)
+            .if_not_exists()
+            .col(
+                ColumnDef::new(FraudRules::FraudRuleId)
+                    .integer()
+                    .not_null()
+                    .auto_increment()
+                    .primary_key(),
+            )
+            .col(
+                ColumnDef::new(FraudRules::Name)
+                    .string_len(100)
+                    .not_null()
+                    .unique_key(),
+            )
+            .col(
+                ColumnDef::new(FraudRules::RuleType)
+                    .string_len(30)
+                    .not_null(),
+            )
+            .col(ColumnDef::new(FraudRules::TransactionType).string_len(20))
+            .col(ColumnDef::new(FraudRules::Threshold).integer().not_null())
+            .col(ColumnDef::new(FraudRules::WindowSeconds).integer())
+            .col(ColumnDef::new(FraudRules::Action).string_len(10).not_null())
+            .col(
+                ColumnDef::new(FraudRules::IsActive)
+                    .boolean()
+                    .not_null()
+                    .default(true),
+            )
+            .col(
+                ColumnDef::new(FraudRules::CreatedAt)
+                    .timestamp()
+                    .default(Expr::current_timestamp()),
+            )
+            .col(
+                ColumnDef::new(FraudRules::UpdatedAt)
+                    .timestamp()
+                    .default(Expr::current_timestamp()),
+            )
+            .to_owned();
+        manager.create_table(fraud_rules_table).await?;
+
+        // Create Fraud Reviews Table, the analyst queue of held transactions
+        let fraud_reviews_table = Table::create()
+            .table(FraudReviews::Table)
+            .if_not_exists()
+            .col(
+                ColumnDef::new(FraudReviews::FraudReviewId)
+                    .integer()
+                    .not_null()
+                    .auto_increment()
+                    .primary_key(),
+            )
+            .col(ColumnDef::new(FraudReviews::UserId).integer().not_null())
+            .col(
+                ColumnDef::new(FraudReviews::TransactionType)
+                    .string_len(20)
+                    .not_null(),
+            )
+            .col(ColumnDef::new(FraudReviews::Amount).integer().not_null())
+            .col(ColumnDef::new(FraudReviews::CounterpartyId).integer())
+            .col(ColumnDef::new(FraudReviews::Payload).text().not_null())
+            .col(
+                ColumnDef::new(FraudReviews::TriggeredRules)
+                    .text()
+                    .not_null(),
+            )
+            .col(
+                ColumnDef::new(FraudReviews::Status)
+                    .string_len(20)
+                    .not_null()
+                    .default("pending"),
+            )
+            .col(ColumnDef::new(FraudReviews::TransactionId).integer())
+            .col(ColumnDef::new(FraudReviews::ReviewerId).integer())
+            .col(ColumnDef::new(FraudReviews::ReviewNote).text())
+            .col(ColumnDef::new(FraudReviews::ReviewedAt).timestamp())
+            .col(
+                ColumnDef::new(FraudReviews::CreatedAt)
+                    .timestamp()
+                    .default(Expr::current_timestamp()),
+            )
+            .col(
+                ColumnDef::new(FraudReviews::UpdatedAt)
+                    .timestamp()
+                    .default(Expr::current_timestamp()),
+            )
+            .foreign_key(
+                ForeignKey::create()
+                    .name("fk-fraud_reviews-user_id")
+                    .from(FraudReviews::Table, FraudReviews::UserId)
+                    .to(Users::Table, Users::UserId)
+                    .on_update(ForeignKeyAction::Cascade)
+                    .on_delete(ForeignKeyAction::Cascade),
+            )
+            .foreign_key(
+                ForeignKey::create()
+                    .name("fk-fraud_reviews-reviewer_id")
+                    .from(FraudReviews::Table, FraudReviews::ReviewerId)
+                    .to(Users::Table, Users::UserId)
+                    .on_update(ForeignKeyAction::Cascade)
+                    .on_delete(ForeignKeyAction::SetNull),
+            )
+            .to_owned();
+        manager.create_table(fraud_reviews_table).await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name("idx-fraud_reviews-status")
+                    .table(FraudReviews::Table)
+                    .col(FraudReviews::Status)
+                    .to_owned(),
+            )
+            .await?;
+
+        // Create User Devices Table, the devices each user has been seen on
+        let user_devices_table = Table::create()
+            .table(UserDevices::Table)
+            .if_not_exists()
+            .col(
+                ColumnDef::new(UserDevices::UserDeviceId)
+                    .integer()
+                    .not_null()
+                    .auto_increment()
+                    .primary_key(),
+            )
+            .col(ColumnDef::new(UserDevices::UserId).integer().not_null())
+            .col(
+                ColumnDef::new(UserDevices::DeviceId)
+                    .string_len(100)
+                    .not_null(),
+            )
+            .col(
+                ColumnDef::new(UserDevices::FirstSeenAt)
+                    .timestamp()
+                    .not_null()
+                    .default(Expr::current_timestamp()),
+            )
+            .col(
+                ColumnDef::new(UserDevices::LastSeenAt)
+                    .timestamp()
+                    .not_null()
+                    .default(Expr::current_timestamp()),
+            )
+            .foreign_key(
+                ForeignKey::create()
+                    .name("fk-user_devices-user_id")
+                    .from(UserDevices::Table, UserDevices::UserId)
+                    .to(Users::Table, Users::UserId)
+                    .on_update(ForeignKeyAction::Cascade)
+                    .on_delete(ForeignKeyAction::Cascade),
+            )
+            .to_owned();
+        manager.create_table(user_devices_table).await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name("idx-user_devices-user_id-device_id")
+                    .table(UserDevices::Table)
+                    .col(UserDevices::UserId)
+                    .col(UserDevices::DeviceId)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        // Default rules; transaction type `None` applies to every type
+        let mut insert = Query::insert();
+        insert.into_table(FraudRules::Table).columns([
+            FraudRules::Name,
+            FraudRules::RuleType,
+            FraudRules::TransactionType,
+            FraudRules::Threshold,
+            FraudRules::WindowSeconds,
+            FraudRules::Action,
+        ]);
+
+        for rule in DEFAULT_RULES {
+            insert.values_panic([
+                rule.name.into(),
+                rule.rule_type.into(),
+                rule.transaction_type.into(),
+                rule.threshold.into(),
+                rule.window_seconds.into(),
+                rule.action.into(),
+            ]);
+        }
+
+        manager.exec_stmt(insert).await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(UserDevices::Table).to_owned())
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(FraudReviews::Table).to_owned())
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(FraudRules::Table).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
+
+/// A seeded fraud rule; what `threshold` and `window_seconds` mean depends
+/// on the rule type.
+struct DefaultRule {
+    name: &'static str,
+    rule_type: &'static str,
+    transaction_type: Option<&'static str>,
+    threshold: i32,
+    window_seconds: Option<i32>,
+    action: &'static str,
+}
+
+const DEFAULT_RULES: [DefaultRule; 7] = [
+    DefaultRule {
+        name: "transfer_velocity_review",
+        rule_type: "velocity",
+        transaction_type: Some("transfer"),
+        threshold: 5,
+        window_seconds: Some(60),
+        action: "review",
+    },
+    DefaultRule {
+        name: "transfer_velocity_deny",
+        rule_type: "velocity",
+        transaction_type: Some("transfer"),
+        threshold: 10,
+        window_seconds: Some(60),
+        action: "deny",
+    },
+    DefaultRule {
+        name: "withdraw_velocity_review",
+        rule_type: "velocity",
+        transaction_type: Some("withdraw"),
+        threshold: 3,
+        window_seconds: Some(60),
+        action: "review",
+    },
+    DefaultRule {
+        name: "topup_velocity_review",
+        rule_type: "velocity",
+        transaction_type: Some("topup"),
+        threshold: 5,
+        window_seconds: Some(60),
+        action: "review",
+    },
+    DefaultRule {
+        name: "amount_anomaly_review",
+        rule_type: "amount_anomaly",
+        transaction_type: None,
+        threshold: 10,
+        window_seconds: Some(90 * 24 * 60 * 60),
+        action: "review",
+    },
+    DefaultRule {
+        name: "new_device_new_beneficiary_review",
+        rule_type: "new_device_beneficiary",
+        transaction_type: None,
+        threshold: 1,
+        window_seconds: Some(24 * 60 * 60),
+        action: "review",
+    },
+    DefaultRule {
+        name: "transfer_round_trip_review",
+        rule_type: "round_trip",
+        transaction_type: Some("transfer"),
+        threshold: 1,
+        window_seconds: Some(60 * 60),
+        action: "review",
+    },
+];
+
+#[derive(Iden)]
+enum Users {
+    Table,
+    UserId,
+}
+
+#[derive(Iden)]
+enum FraudRules {
+    Table,
+    FraudRuleId,
+    Name,
+    RuleType,
+    TransactionType,
+    Threshold,
+    WindowSeconds,
+    Action,
+    IsActive,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[derive(Iden)]
+enum FraudReviews {
+    Table,
+    FraudReviewId,
+    UserId,
+    TransactionType,
+    Amount,
+    CounterpartyId,
+    Payload,
+    TriggeredRules,
+    Status,
+    TransactionId,
+    ReviewerId,
+    ReviewNote,
+    ReviewedAt,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[derive(Iden)]
+enum UserDevices {
+    Table,
+    UserDeviceId,
+    UserId,
+    DeviceId,
+    FirstSeenAt,
+    LastSeenAt,
+}
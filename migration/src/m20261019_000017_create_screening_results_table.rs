@@ -0,0 +1,153 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Create Screening Results Table, one row per watchlist match
+        let screening_results_table = Table::create()
+            .table(ScreeningResults::Table)
+            .if_not_exists()
+            .col(
+                ColumnDef::new(ScreeningResults::ScreeningResultId)
+                    .integer()
+                    .not_null()
+                    .auto_increment()
+                    .primary_key(),
+            )
+            .col(
+                ColumnDef::new(ScreeningResults::SubjectType)
+                    .string_len(20)
+                    .not_null(),
+            )
+            .col(
+                ColumnDef::new(ScreeningResults::SubjectId)
+                    .integer()
+                    .not_null(),
+            )
+            .col(
+                ColumnDef::new(ScreeningResults::UserId)
+                    .integer()
+                    .not_null(),
+            )
+            .col(
+                ColumnDef::new(ScreeningResults::ScreenedName)
+                    .string_len(255)
+                    .not_null(),
+            )
+            .col(
+                ColumnDef::new(ScreeningResults::WatchlistEntryId)
+                    .string_len(100)
+                    .not_null(),
+            )
+            .col(
+                ColumnDef::new(ScreeningResults::WatchlistName)
+                    .string_len(50)
+                    .not_null(),
+            )
+            .col(
+                ColumnDef::new(ScreeningResults::MatchedName)
+                    .string_len(255)
+                    .not_null(),
+            )
+            .col(ColumnDef::new(ScreeningResults::Score).integer().not_null())
+            .col(
+                ColumnDef::new(ScreeningResults::Status)
+                    .string_len(20)
+                    .not_null()
+                    .default("pending"),
+            )
+            .col(ColumnDef::new(ScreeningResults::ReviewerId).integer())
+            .col(ColumnDef::new(ScreeningResults::ReviewNote).text())
+            .col(ColumnDef::new(ScreeningResults::ReviewedAt).timestamp())
+            .col(
+                ColumnDef::new(ScreeningResults::CreatedAt)
+                    .timestamp()
+                    .default(Expr::current_timestamp()),
+            )
+            .col(
+                ColumnDef::new(ScreeningResults::UpdatedAt)
+                    .timestamp()
+                    .default(Expr::current_timestamp()),
+            )
+            .foreign_key(
+                ForeignKey::create()
+                    .name("fk-screening_results-user_id")
+                    .from(ScreeningResults::Table, ScreeningResults::UserId)
+                    .to(Users::Table, Users::UserId)
+                    .on_update(ForeignKeyAction::Cascade)
+                    .on_delete(ForeignKeyAction::Cascade),
+            )
+            .foreign_key(
+                ForeignKey::create()
+                    .name("fk-screening_results-reviewer_id")
+                    .from(ScreeningResults::Table, ScreeningResults::ReviewerId)
+                    .to(Users::Table, Users::UserId)
+                    .on_update(ForeignKeyAction::Cascade)
+                    .on_delete(ForeignKeyAction::SetNull),
+            )
+            .to_owned();
+        manager.create_table(screening_results_table).await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name("idx-screening_results-status")
+                    .table(ScreeningResults::Table)
+                    .col(ScreeningResults::Status)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name("idx-screening_results-subject_type-subject_id")
+                    .table(ScreeningResults::Table)
+                    .col(ScreeningResults::SubjectType)
+                    .col(ScreeningResults::SubjectId)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(ScreeningResults::Table).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(Iden)]
+enum Users {
+    Table,
+    UserId,
+}
+
+#[derive(Iden)]
+enum ScreeningResults {
+    Table,
+    ScreeningResultId,
+    SubjectType,
+    SubjectId,
+    UserId,
+    ScreenedName,
+    WatchlistEntryId,
+    WatchlistName,
+    MatchedName,
+    Score,
+    Status,
+    ReviewerId,
+    ReviewNote,
+    ReviewedAt,
+    CreatedAt,
+    UpdatedAt,
+}
@@ -0,0 +1,161 @@
+use sea_orm_migration::{prelude::*, sea_orm::DatabaseBackend};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Create Audit Events Table. The actor is not a foreign key so the
+        // trail outlives deleted users.
+        let audit_events_table = Table::create()
+            .table(AuditEvents::Table)
+            .if_not_exists()
+            .col(
+                ColumnDef::new(AuditEvents::AuditEventId)
+                    .integer()
+                    .not_null()
+                    .auto_increment()
+                    .primary_key(),
+            )
+            .col(ColumnDef::new(AuditEvents::ActorId).integer())
+            .col(
+                ColumnDef::new(AuditEvents::Action)
+                    .string_len(255)
+                    .not_null(),
+            )
+            .col(
+                ColumnDef::new(AuditEvents::ResourceType)
+                    .string_len(100)
+                    .not_null(),
+            )
+            .col(ColumnDef::new(AuditEvents::ResourceId).string_len(100))
+            .col(ColumnDef::new(AuditEvents::Before).text())
+            .col(ColumnDef::new(AuditEvents::After).text())
+            .col(ColumnDef::new(AuditEvents::ClientIp).string_len(64))
+            .col(
+                ColumnDef::new(AuditEvents::RequestId)
+                    .string_len(100)
+                    .not_null(),
+            )
+            .col(
+                ColumnDef::new(AuditEvents::CreatedAt)
+                    .timestamp()
+                    .not_null(),
+            )
+            .col(
+                ColumnDef::new(AuditEvents::PrevHash)
+                    .string_len(64)
+                    .not_null(),
+            )
+            .col(ColumnDef::new(AuditEvents::Hash).string_len(64).not_null())
+            .to_owned();
+        manager.create_table(audit_events_table).await?;
+
+        // Each hash can be followed by one event only, so concurrent writers
+        // cannot fork the chain
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name("idx-audit_events-prev_hash")
+                    .table(AuditEvents::Table)
+                    .col(AuditEvents::PrevHash)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name("idx-audit_events-resource_type-resource_id")
+                    .table(AuditEvents::Table)
+                    .col(AuditEvents::ResourceType)
+                    .col(AuditEvents::ResourceId)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name("idx-audit_events-actor_id")
+                    .table(AuditEvents::Table)
+                    .col(AuditEvents::ActorId)
+                    .to_owned(),
+            )
+            .await?;
+
+        // The table is append-only, updates and deletes are refused
+        let db = manager.get_connection();
+
+        match manager.get_database_backend() {
+            DatabaseBackend::Postgres => {
+                db.execute_unprepared(
+                    "CREATE OR REPLACE FUNCTION audit_events_append_only() RETURNS trigger AS $$
+                     BEGIN
+                         RAISE EXCEPTION 'audit_events is append-only';
+                     END;
+                     $$ LANGUAGE plpgsql",
+                )
+                .await?;
+
+                db.execute_unprepared(
+                    "CREATE TRIGGER audit_events_append_only
+                     BEFORE UPDATE OR DELETE ON audit_events
+                     FOR EACH ROW EXECUTE FUNCTION audit_events_append_only()",
+                )
+                .await?;
+            }
+            DatabaseBackend::Sqlite => {
+                for operation in ["UPDATE", "DELETE"] {
+                    db.execute_unprepared(&format!(
+                        "CREATE TRIGGER audit_events_no_{} BEFORE {} ON audit_events
+                         BEGIN SELECT RAISE(ABORT, 'audit_events is append-only'); END",
+                        operation.to_lowercase(),
+                        operation
+                    ))
+                    .await?;
+                }
+            }
+            DatabaseBackend::MySql => {}
+        }
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(AuditEvents::Table).to_owned())
+            .await?;
+
+        if manager.get_database_backend() == DatabaseBackend::Postgres {
+            manager
+                .get_connection()
+                .execute_unprepared("DROP FUNCTION IF EXISTS audit_events_append_only()")
+                .await?;
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Iden)]
+enum AuditEvents {
+    Table,
+    AuditEventId,
+    ActorId,
+    Action,
+    ResourceType,
+    ResourceId,
+    Before,
+    After,
+    ClientIp,
+    RequestId,
+    CreatedAt,
+    PrevHash,
+    Hash,
+}
@@ -0,0 +1,87 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // An intent whose payment is held for fraud review stays in
+        // processing, linked to the review that decides it.
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(PaymentIntents::Table)
+                    .add_column(ColumnDef::new(PaymentIntents::FraudReviewId).integer())
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name("idx-payment_intents-fraud_review_id")
+                    .table(PaymentIntents::Table)
+                    .col(PaymentIntents::FraudReviewId)
+                    .to_owned(),
+            )
+            .await?;
+
+        // Merchants are notified through their webhook endpoint, so the
+        // per-intent callback URL was never delivered to.
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(PaymentIntents::Table)
+                    .drop_column(PaymentIntents::CallbackUrl)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(PaymentIntents::Table)
+                    .add_column(
+                        ColumnDef::new(PaymentIntents::CallbackUrl)
+                            .string_len(255)
+                            .not_null()
+                            .default(""),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("idx-payment_intents-fraud_review_id")
+                    .table(PaymentIntents::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(PaymentIntents::Table)
+                    .drop_column(PaymentIntents::FraudReviewId)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(Iden)]
+enum PaymentIntents {
+    Table,
+    FraudReviewId,
+    CallbackUrl,
+}
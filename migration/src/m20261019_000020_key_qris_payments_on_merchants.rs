@@ -0,0 +1,153 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // `merchant_id` held the issuing user. It becomes `user_id`, and
+        // `merchant_id` now points at the merchant the QRIS is settled to.
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("idx-qris_payments-merchant_id")
+                    .table(QrisPayments::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(QrisPayments::Table)
+                    .rename_column(QrisPayments::MerchantId, QrisPayments::UserId)
+                    .to_owned(),
+            )
+            .await?;
+
+        let qris_columns = [
+            ColumnDef::new(QrisPayments::MerchantId)
+                .integer()
+                .to_owned(),
+            ColumnDef::new(QrisPayments::SettlementId)
+                .integer()
+                .to_owned(),
+            ColumnDef::new(QrisPayments::FraudReviewId)
+                .integer()
+                .to_owned(),
+        ];
+
+        for mut column in qris_columns {
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(QrisPayments::Table)
+                        .add_column(&mut column)
+                        .to_owned(),
+                )
+                .await?;
+        }
+
+        // QRIS issued by users without a merchant keep a null merchant and
+        // can no longer be paid.
+        manager
+            .get_connection()
+            .execute_unprepared(
+                "UPDATE qris_payments SET merchant_id = \
+                 (SELECT merchants.merchant_id FROM merchants \
+                 WHERE merchants.user_id = qris_payments.user_id)",
+            )
+            .await?;
+
+        for (name, column) in [
+            ("idx-qris_payments-user_id", QrisPayments::UserId),
+            ("idx-qris_payments-merchant_id", QrisPayments::MerchantId),
+            (
+                "idx-qris_payments-settlement_id",
+                QrisPayments::SettlementId,
+            ),
+            (
+                "idx-qris_payments-fraud_review_id",
+                QrisPayments::FraudReviewId,
+            ),
+        ] {
+            manager
+                .create_index(
+                    Index::create()
+                        .if_not_exists()
+                        .name(name)
+                        .table(QrisPayments::Table)
+                        .col(column)
+                        .to_owned(),
+                )
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        for name in [
+            "idx-qris_payments-fraud_review_id",
+            "idx-qris_payments-settlement_id",
+            "idx-qris_payments-merchant_id",
+            "idx-qris_payments-user_id",
+        ] {
+            manager
+                .drop_index(
+                    Index::drop()
+                        .name(name)
+                        .table(QrisPayments::Table)
+                        .to_owned(),
+                )
+                .await?;
+        }
+
+        for column in [
+            QrisPayments::FraudReviewId,
+            QrisPayments::SettlementId,
+            QrisPayments::MerchantId,
+        ] {
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(QrisPayments::Table)
+                        .drop_column(column)
+                        .to_owned(),
+                )
+                .await?;
+        }
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(QrisPayments::Table)
+                    .rename_column(QrisPayments::UserId, QrisPayments::MerchantId)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name("idx-qris_payments-merchant_id")
+                    .table(QrisPayments::Table)
+                    .col(QrisPayments::MerchantId)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(Iden)]
+enum QrisPayments {
+    Table,
+    MerchantId,
+    UserId,
+    SettlementId,
+    FraudReviewId,
+}
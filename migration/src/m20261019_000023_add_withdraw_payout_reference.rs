@@ -0,0 +1,70 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // The reference a payout is sent under, so a payout whose request
+        // failed in transit can still be looked up with the provider.
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Withdraws::Table)
+                    .add_column(ColumnDef::new(Withdraws::PayoutReference).string_len(64))
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .get_connection()
+            .execute_unprepared(
+                "UPDATE withdraws SET payout_reference = 'WD-' || withdraw_id \
+                 WHERE payout_reference IS NULL",
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name("idx-withdraws-payout_reference")
+                    .table(Withdraws::Table)
+                    .col(Withdraws::PayoutReference)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("idx-withdraws-payout_reference")
+                    .table(Withdraws::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Withdraws::Table)
+                    .drop_column(Withdraws::PayoutReference)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(Iden)]
+enum Withdraws {
+    Table,
+    PayoutReference,
+}
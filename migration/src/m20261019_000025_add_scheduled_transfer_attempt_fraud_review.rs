@@ -0,0 +1,61 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // A scheduled run whose transfer is held for fraud review is
+        // recorded as a `held` attempt, linked to the review that decides it.
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(ScheduledTransferAttempts::Table)
+                    .add_column(ColumnDef::new(ScheduledTransferAttempts::FraudReviewId).integer())
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name("idx-scheduled_transfer_attempts-fraud_review_id")
+                    .table(ScheduledTransferAttempts::Table)
+                    .col(ScheduledTransferAttempts::FraudReviewId)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("idx-scheduled_transfer_attempts-fraud_review_id")
+                    .table(ScheduledTransferAttempts::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(ScheduledTransferAttempts::Table)
+                    .drop_column(ScheduledTransferAttempts::FraudReviewId)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(Iden)]
+enum ScheduledTransferAttempts {
+    Table,
+    FraudReviewId,
+}
@@ -0,0 +1,68 @@
+use async_trait::async_trait;
+use chrono::NaiveDateTime;
+use mockall::automock;
+use sea_orm::DbErr;
+use std::sync::Arc;
+
+use crate::{
+    domain::{
+        request::audit::{CreateAuditEvent, FindAuditEventsQuery},
+        response::{
+            audit::{AuditChainVerificationResponse, AuditEventResponse},
+            ApiResponse, ErrorResponse,
+        },
+    },
+    entities::audit_events,
+};
+
+pub type DynAuditRepository = Arc<dyn AuditRepositoryTrait + Send + Sync>;
+pub type DynAuditService = Arc<dyn AuditServiceTrait + Send + Sync>;
+
+#[automock]
+#[async_trait]
+pub trait AuditRepositoryTrait {
+    /// Newest event, the one the next event links to.
+    async fn find_last(&self) -> Result<Option<audit_events::Model>, DbErr>;
+    async fn create(
+        &self,
+        input: &CreateAuditEvent,
+        created_at: NaiveDateTime,
+        prev_hash: &str,
+        hash: &str,
+    ) -> Result<audit_events::Model, DbErr>;
+    /// Newest first.
+    async fn find(&self, query: &FindAuditEventsQuery) -> Result<Vec<audit_events::Model>, DbErr>;
+    /// Events after `after_id` in chain order.
+    async fn find_after(
+        &self,
+        after_id: i32,
+        limit: u64,
+    ) -> Result<Vec<audit_events::Model>, DbErr>;
+    /// Current state of a resource as JSON, with secrets removed. `None`
+    /// when the resource type is unknown or the row does not exist.
+    async fn snapshot(
+        &self,
+        resource_type: &str,
+        resource_id: i32,
+    ) -> Result<Option<serde_json::Value>, DbErr>;
+}
+
+#[automock]
+#[async_trait]
+pub trait AuditServiceTrait {
+    /// Appends the event to the hash chain.
+    async fn record(&self, input: &CreateAuditEvent) -> Result<audit_events::Model, ErrorResponse>;
+    async fn snapshot(
+        &self,
+        resource_type: &str,
+        resource_id: i32,
+    ) -> Result<Option<serde_json::Value>, ErrorResponse>;
+    async fn find_events(
+        &self,
+        query: &FindAuditEventsQuery,
+    ) -> Result<ApiResponse<Vec<AuditEventResponse>>, ErrorResponse>;
+    /// Walks the whole chain, recomputing every hash.
+    async fn verify_chain(
+        &self,
+    ) -> Result<ApiResponse<AuditChainVerificationResponse>, ErrorResponse>;
+}
@@ -0,0 +1,43 @@
+use async_trait::async_trait;
+use mockall::automock;
+use sea_orm::DbErr;
+use std::sync::Arc;
+
+use crate::{
+    domain::{
+        request::bank_account::CreateBankAccountRequest,
+        response::{bank_account::BankAccountResponse, ApiResponse, ErrorResponse},
+    },
+    entities::bank_accounts,
+};
+
+pub type DynBankAccountRepository = Arc<dyn BankAccountRepositoryTrait + Send + Sync>;
+pub type DynBankAccountService = Arc<dyn BankAccountServiceTrait + Send + Sync>;
+
+#[automock]
+#[async_trait]
+pub trait BankAccountRepositoryTrait {
+    async fn find_by_user(&self, user_id: i32) -> Result<Vec<bank_accounts::Model>, DbErr>;
+    async fn find_by_id(&self, id: i32) -> Result<Option<bank_accounts::Model>, DbErr>;
+    async fn create(&self, input: &CreateBankAccountRequest)
+        -> Result<bank_accounts::Model, DbErr>;
+    async fn delete(&self, id: i32) -> Result<(), DbErr>;
+}
+
+#[automock]
+#[async_trait]
+pub trait BankAccountServiceTrait {
+    async fn get_bank_accounts(
+        &self,
+        user_id: i32,
+    ) -> Result<ApiResponse<Vec<BankAccountResponse>>, ErrorResponse>;
+    async fn create_bank_account(
+        &self,
+        input: &CreateBankAccountRequest,
+    ) -> Result<ApiResponse<BankAccountResponse>, ErrorResponse>;
+    async fn delete_bank_account(
+        &self,
+        id: i32,
+        user_id: i32,
+    ) -> Result<ApiResponse<()>, ErrorResponse>;
+}
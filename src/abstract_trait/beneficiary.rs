@@ -0,0 +1,57 @@
+use async_trait::async_trait;
+use mockall::automock;
+use sea_orm::DbErr;
+use std::sync::Arc;
+
+use crate::{
+    domain::{
+        request::beneficiary::{CreateBeneficiaryRequest, UpdateBeneficiaryRequest},
+        response::{beneficiary::BeneficiaryResponse, ApiResponse, ErrorResponse},
+    },
+    entities::beneficiaries,
+};
+
+pub type DynBeneficiaryRepository = Arc<dyn BeneficiaryRepositoryTrait + Send + Sync>;
+pub type DynBeneficiaryService = Arc<dyn BeneficiaryServiceTrait + Send + Sync>;
+
+#[automock]
+#[async_trait]
+pub trait BeneficiaryRepositoryTrait {
+    async fn find_by_user(&self, user_id: i32) -> Result<Vec<beneficiaries::Model>, DbErr>;
+    async fn find_by_id(&self, id: i32) -> Result<Option<beneficiaries::Model>, DbErr>;
+    async fn create(
+        &self,
+        input: &CreateBeneficiaryRequest,
+        verified: bool,
+    ) -> Result<beneficiaries::Model, DbErr>;
+    async fn update(&self, input: &UpdateBeneficiaryRequest)
+        -> Result<beneficiaries::Model, DbErr>;
+    async fn delete(&self, id: i32) -> Result<(), DbErr>;
+}
+
+#[automock]
+#[async_trait]
+pub trait BeneficiaryServiceTrait {
+    async fn get_beneficiaries(
+        &self,
+        user_id: i32,
+    ) -> Result<ApiResponse<Vec<BeneficiaryResponse>>, ErrorResponse>;
+    async fn get_beneficiary(
+        &self,
+        id: i32,
+        user_id: i32,
+    ) -> Result<ApiResponse<BeneficiaryResponse>, ErrorResponse>;
+    async fn create_beneficiary(
+        &self,
+        input: &CreateBeneficiaryRequest,
+    ) -> Result<ApiResponse<BeneficiaryResponse>, ErrorResponse>;
+    async fn update_beneficiary(
+        &self,
+        input: &UpdateBeneficiaryRequest,
+    ) -> Result<ApiResponse<BeneficiaryResponse>, ErrorResponse>;
+    async fn delete_beneficiary(
+        &self,
+        id: i32,
+        user_id: i32,
+    ) -> Result<ApiResponse<()>, ErrorResponse>;
+}
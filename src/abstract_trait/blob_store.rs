@@ -0,0 +1,19 @@
+use async_trait::async_trait;
+use mockall::automock;
+use std::sync::Arc;
+
+use crate::utils::errors::AppError;
+
+pub type DynBlobStore = Arc<dyn BlobStoreTrait + Send + Sync>;
+
+/// Stores uploaded files under slash separated keys such as
+/// `kyc/1/id_card.png`.
+#[automock]
+#[async_trait]
+pub trait BlobStoreTrait {
+    /// Writes the file, replacing any file already stored under the key.
+    async fn put(&self, key: &str, bytes: &[u8]) -> Result<(), AppError>;
+    async fn get(&self, key: &str) -> Result<Vec<u8>, AppError>;
+    /// Removes the file. Deleting a missing key is not an error.
+    async fn delete(&self, key: &str) -> Result<(), AppError>;
+}
@@ -0,0 +1,10 @@
+use chrono::NaiveDateTime;
+use mockall::automock;
+use std::sync::Arc;
+
+#[automock]
+pub trait ClockTrait: Send + Sync {
+    fn now(&self) -> NaiveDateTime;
+}
+
+pub type DynClock = Arc<dyn ClockTrait + Send + Sync>;
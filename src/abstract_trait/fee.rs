@@ -0,0 +1,59 @@
+use async_trait::async_trait;
+use mockall::automock;
+use sea_orm::DbErr;
+use std::sync::Arc;
+
+use crate::{
+    domain::{
+        request::fee::{FeeQuoteRequest, RecordFeeRevenue},
+        response::{
+            fee::{FeeQuoteResponse, FeeRuleResponse},
+            ApiResponse, ErrorResponse,
+        },
+    },
+    entities::{fee_revenues, fee_rules},
+};
+
+pub type DynFeeRepository = Arc<dyn FeeRepositoryTrait + Send + Sync>;
+pub type DynFeeService = Arc<dyn FeeServiceTrait + Send + Sync>;
+
+/// The fee charged on a transaction and the rule it came from.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct FeeQuote {
+    pub fee_rule_id: Option<i32>,
+    pub fee_amount: i32,
+}
+
+#[automock]
+#[async_trait]
+pub trait FeeRepositoryTrait {
+    async fn find_active_rules(&self) -> Result<Vec<fee_rules::Model>, DbErr>;
+    async fn find_active_rules_by_type(
+        &self,
+        transaction_type: &str,
+    ) -> Result<Vec<fee_rules::Model>, DbErr>;
+    /// Writes the fee to the revenue ledger and credits it to the saldo of
+    /// `revenue_user_id` in one transaction.
+    async fn record_revenue(
+        &self,
+        input: &RecordFeeRevenue,
+        revenue_user_id: i32,
+    ) -> Result<fee_revenues::Model, DbErr>;
+}
+
+#[automock]
+#[async_trait]
+pub trait FeeServiceTrait {
+    async fn get_fee_rules(&self) -> Result<ApiResponse<Vec<FeeRuleResponse>>, ErrorResponse>;
+    async fn get_fee_quote(
+        &self,
+        input: &FeeQuoteRequest,
+    ) -> Result<ApiResponse<FeeQuoteResponse>, ErrorResponse>;
+    /// Fee for a transaction under the most specific matching rule; zero
+    /// when no rule matches. Fails when a fee is due but no revenue account
+    /// is configured to receive it.
+    async fn calculate_fee(&self, input: &FeeQuoteRequest) -> Result<FeeQuote, ErrorResponse>;
+    /// Posts a charged fee to the revenue account and credits it. Zero fees
+    /// are skipped.
+    async fn post_fee(&self, input: &RecordFeeRevenue) -> Result<(), ErrorResponse>;
+}
@@ -0,0 +1,114 @@
+use async_trait::async_trait;
+use chrono::NaiveDateTime;
+use mockall::automock;
+use sea_orm::DbErr;
+use std::sync::Arc;
+
+use crate::{
+    domain::{
+        request::fraud::{CreateFraudReview, DecideFraudReviewRequest, ScreenTransactionRequest},
+        response::{
+            fraud::{FraudReviewResponse, FraudRuleResponse},
+            ApiResponse, ErrorResponse,
+        },
+    },
+    entities::{fraud_reviews, fraud_rules, user_devices},
+};
+
+pub type DynFraudRepository = Arc<dyn FraudRepositoryTrait + Send + Sync>;
+pub type DynFraudEngine = Arc<dyn FraudEngineTrait + Send + Sync>;
+pub type DynFraudReviewService = Arc<dyn FraudReviewServiceTrait + Send + Sync>;
+
+#[automock]
+#[async_trait]
+pub trait FraudRepositoryTrait {
+    async fn find_rules(&self) -> Result<Vec<fraud_rules::Model>, DbErr>;
+    /// Active rules for the transaction type, including the ones for every
+    /// type.
+    async fn find_active_rules(
+        &self,
+        transaction_type: &str,
+    ) -> Result<Vec<fraud_rules::Model>, DbErr>;
+    async fn count_user_transactions_since(
+        &self,
+        transaction_type: &str,
+        user_id: i32,
+        since: NaiveDateTime,
+    ) -> Result<i64, DbErr>;
+    /// Number and total amount of the user's transactions since `since`.
+    async fn user_amount_stats_since(
+        &self,
+        transaction_type: &str,
+        user_id: i32,
+        since: NaiveDateTime,
+    ) -> Result<(i64, i64), DbErr>;
+    /// Earlier transactions of the user to the receiving user of a transfer
+    /// or the bank account of a withdraw.
+    async fn count_to_counterparty(
+        &self,
+        transaction_type: &str,
+        user_id: i32,
+        counterparty_id: i32,
+    ) -> Result<i64, DbErr>;
+    async fn count_transfers_since(
+        &self,
+        transfer_from: i32,
+        transfer_to: i32,
+        since: NaiveDateTime,
+    ) -> Result<i64, DbErr>;
+    /// Records that the user was seen on the device, returning when it was
+    /// first seen.
+    async fn touch_device(
+        &self,
+        user_id: i32,
+        device_id: &str,
+    ) -> Result<user_devices::Model, DbErr>;
+    async fn create_review(&self, input: &CreateFraudReview)
+        -> Result<fraud_reviews::Model, DbErr>;
+    async fn find_review_by_id(&self, id: i32) -> Result<Option<fraud_reviews::Model>, DbErr>;
+    /// Reviews of every user, oldest first so analysts work in order.
+    async fn find_reviews(
+        &self,
+        status: Option<String>,
+    ) -> Result<Vec<fraud_reviews::Model>, DbErr>;
+    /// Moves a review from `from_status` to `to_status`, recording the
+    /// reviewer. Returns false when it was no longer in `from_status`.
+    async fn transition_review(
+        &self,
+        id: i32,
+        from_status: &str,
+        to_status: &str,
+        reviewer_id: i32,
+        note: Option<String>,
+    ) -> Result<bool, DbErr>;
+    async fn set_review_transaction(&self, id: i32, transaction_id: i32) -> Result<(), DbErr>;
+}
+
+#[automock]
+#[async_trait]
+pub trait FraudEngineTrait {
+    /// Runs the fraud rules against a transaction before it moves money.
+    /// Denied transactions fail with `FraudDenied`; held ones are queued for
+    /// review and fail with `HeldForReview`.
+    async fn screen(&self, input: &ScreenTransactionRequest) -> Result<(), ErrorResponse>;
+}
+
+#[automock]
+#[async_trait]
+pub trait FraudReviewServiceTrait {
+    async fn get_rules(&self) -> Result<ApiResponse<Vec<FraudRuleResponse>>, ErrorResponse>;
+    async fn get_reviews(
+        &self,
+        status: Option<String>,
+    ) -> Result<ApiResponse<Vec<FraudReviewResponse>>, ErrorResponse>;
+    /// Releases a held transaction, carrying it out without screening it
+    /// again.
+    async fn approve_review(
+        &self,
+        input: &DecideFraudReviewRequest,
+    ) -> Result<ApiResponse<FraudReviewResponse>, ErrorResponse>;
+    async fn decline_review(
+        &self,
+        input: &DecideFraudReviewRequest,
+    ) -> Result<ApiResponse<FraudReviewResponse>, ErrorResponse>;
+}
@@ -0,0 +1,26 @@
+use async_trait::async_trait;
+use mockall::automock;
+use sea_orm::DbErr;
+use std::sync::Arc;
+
+use crate::domain::response::health::ReadinessResponse;
+
+pub type DynHealthRepository = Arc<dyn HealthRepositoryTrait + Send + Sync>;
+pub type DynHealthService = Arc<dyn HealthServiceTrait + Send + Sync>;
+
+#[automock]
+#[async_trait]
+pub trait HealthRepositoryTrait {
+    async fn ping(&self) -> Result<(), DbErr>;
+    /// `None` when no read replica is configured.
+    async fn ping_replica(&self) -> Option<Result<(), DbErr>>;
+    /// Names of the migrations not yet applied to the database.
+    async fn pending_migrations(&self) -> Result<Vec<String>, DbErr>;
+}
+
+#[automock]
+#[async_trait]
+pub trait HealthServiceTrait {
+    /// Database, migration and background worker health.
+    async fn readiness(&self) -> ReadinessResponse;
+}
@@ -0,0 +1,84 @@
+use async_trait::async_trait;
+use mockall::automock;
+use sea_orm::DbErr;
+use std::sync::Arc;
+
+use crate::{
+    domain::{
+        request::kyc::{
+            CreateKycSubmissionRequest, RecordKycSubmissionEvent, ReviewKycSubmissionRequest,
+        },
+        response::{
+            kyc::{KycDocumentFile, KycSubmissionResponse},
+            ApiResponse, ErrorResponse,
+        },
+    },
+    entities::{kyc_submission_events, kyc_submissions},
+};
+
+pub type DynKycRepository = Arc<dyn KycRepositoryTrait + Send + Sync>;
+pub type DynKycService = Arc<dyn KycServiceTrait + Send + Sync>;
+
+#[automock]
+#[async_trait]
+pub trait KycRepositoryTrait {
+    async fn create_submission(
+        &self,
+        user_id: i32,
+        requested_level: i32,
+        id_card_key: &str,
+        selfie_key: &str,
+    ) -> Result<kyc_submissions::Model, DbErr>;
+    async fn find_by_id(&self, id: i32) -> Result<Option<kyc_submissions::Model>, DbErr>;
+    async fn find_by_user(&self, user_id: i32) -> Result<Vec<kyc_submissions::Model>, DbErr>;
+    /// Submissions of every user, oldest first so reviewers work in order.
+    async fn find_by_status(
+        &self,
+        status: Option<String>,
+    ) -> Result<Vec<kyc_submissions::Model>, DbErr>;
+    /// The user's submission that is still `submitted` or `in_review`.
+    async fn find_open_by_user(
+        &self,
+        user_id: i32,
+    ) -> Result<Option<kyc_submissions::Model>, DbErr>;
+    /// Moves a submission from `from_status` to `to_status`, recording the
+    /// reviewer. Returns false when it was no longer in `from_status`.
+    async fn transition_status(
+        &self,
+        id: i32,
+        from_status: &str,
+        to_status: &str,
+        reviewer_id: i32,
+        note: Option<String>,
+    ) -> Result<bool, DbErr>;
+    async fn record_event(
+        &self,
+        input: &RecordKycSubmissionEvent,
+    ) -> Result<kyc_submission_events::Model, DbErr>;
+}
+
+#[automock]
+#[async_trait]
+pub trait KycServiceTrait {
+    async fn create_submission(
+        &self,
+        input: &CreateKycSubmissionRequest,
+    ) -> Result<ApiResponse<KycSubmissionResponse>, ErrorResponse>;
+    async fn get_my_submissions(
+        &self,
+        user_id: i32,
+    ) -> Result<ApiResponse<Vec<KycSubmissionResponse>>, ErrorResponse>;
+    async fn get_submissions(
+        &self,
+        status: Option<String>,
+    ) -> Result<ApiResponse<Vec<KycSubmissionResponse>>, ErrorResponse>;
+    /// `document` is `id_card` or `selfie`.
+    async fn get_document(&self, id: i32, document: &str)
+        -> Result<KycDocumentFile, ErrorResponse>;
+    /// Moves a submission through review. Approval raises the user's KYC
+    /// level.
+    async fn review_submission(
+        &self,
+        input: &ReviewKycSubmissionRequest,
+    ) -> Result<ApiResponse<KycSubmissionResponse>, ErrorResponse>;
+}
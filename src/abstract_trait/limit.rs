@@ -0,0 +1,59 @@
+use async_trait::async_trait;
+use chrono::NaiveDateTime;
+use mockall::automock;
+use sea_orm::DbErr;
+use std::sync::Arc;
+
+use crate::{
+    domain::{
+        request::limit::CheckLimitRequest,
+        response::{
+            limit::{TransactionLimitResponse, UserLimitsResponse},
+            ApiResponse, ErrorResponse,
+        },
+    },
+    entities::transaction_limits,
+};
+
+pub type DynLimitRepository = Arc<dyn LimitRepositoryTrait + Send + Sync>;
+pub type DynLimitService = Arc<dyn LimitServiceTrait + Send + Sync>;
+
+#[automock]
+#[async_trait]
+pub trait LimitRepositoryTrait {
+    async fn find_all(&self) -> Result<Vec<transaction_limits::Model>, DbErr>;
+    async fn find_by_level(&self, kyc_level: i32) -> Result<Vec<transaction_limits::Model>, DbErr>;
+    async fn find_limit(
+        &self,
+        kyc_level: i32,
+        transaction_type: &str,
+    ) -> Result<Option<transaction_limits::Model>, DbErr>;
+    /// Total a user has moved with a transaction type since `since`,
+    /// leaving out failed transactions.
+    async fn sum_user_amount_since(
+        &self,
+        transaction_type: &str,
+        user_id: i32,
+        since: NaiveDateTime,
+    ) -> Result<i64, DbErr>;
+}
+
+#[automock]
+#[async_trait]
+pub trait LimitServiceTrait {
+    async fn get_limits(&self)
+        -> Result<ApiResponse<Vec<TransactionLimitResponse>>, ErrorResponse>;
+    async fn get_user_limits(
+        &self,
+        user_id: i32,
+    ) -> Result<ApiResponse<UserLimitsResponse>, ErrorResponse>;
+    /// Checks the single transaction, daily and monthly limits of the user
+    /// moving the money.
+    async fn check_transaction_limits(
+        &self,
+        input: &CheckLimitRequest,
+    ) -> Result<(), ErrorResponse>;
+    /// Checks that crediting the amount keeps the receiving wallet under
+    /// its maximum balance.
+    async fn check_balance_limit(&self, input: &CheckLimitRequest) -> Result<(), ErrorResponse>;
+}
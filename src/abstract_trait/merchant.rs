@@ -0,0 +1,37 @@
+use async_trait::async_trait;
+use mockall::automock;
+use sea_orm::DbErr;
+use std::sync::Arc;
+
+use crate::{
+    domain::{
+        request::merchant::CreateMerchantRequest,
+        response::{merchant::MerchantResponse, ApiResponse, ErrorResponse},
+    },
+    entities::merchants,
+};
+
+pub type DynMerchantRepository = Arc<dyn MerchantRepositoryTrait + Send + Sync>;
+pub type DynMerchantService = Arc<dyn MerchantServiceTrait + Send + Sync>;
+
+#[automock]
+#[async_trait]
+pub trait MerchantRepositoryTrait {
+    async fn find_all(&self) -> Result<Vec<merchants::Model>, DbErr>;
+    async fn find_by_id(&self, id: i32) -> Result<Option<merchants::Model>, DbErr>;
+    async fn find_by_user(&self, user_id: i32) -> Result<Option<merchants::Model>, DbErr>;
+    async fn create(&self, input: &CreateMerchantRequest) -> Result<merchants::Model, DbErr>;
+}
+
+#[automock]
+#[async_trait]
+pub trait MerchantServiceTrait {
+    async fn create_merchant(
+        &self,
+        input: &CreateMerchantRequest,
+    ) -> Result<ApiResponse<MerchantResponse>, ErrorResponse>;
+    async fn get_merchant(
+        &self,
+        user_id: i32,
+    ) -> Result<ApiResponse<MerchantResponse>, ErrorResponse>;
+}
@@ -1,8 +1,32 @@
+pub mod audit;
 pub mod auth;
+pub mod bank_account;
+pub mod beneficiary;
+pub mod blob_store;
+pub mod fee;
+pub mod fraud;
+pub mod kyc;
+pub mod clock;
 pub mod hashing;
+pub mod health;
 pub mod jwt;
+pub mod limit;
+pub mod merchant;
+pub mod payment_channel;
+pub mod payment_intent;
+pub mod payout;
+pub mod qris;
+pub mod refund;
 pub mod saldo;
+pub mod scheduled_transfer;
+pub mod screening;
+pub mod settlement;
 pub mod topup;
 pub mod transfer;
+pub mod transfer_batch;
 pub mod user;
+pub mod virtual_account;
+pub mod watchlist;
+pub mod webhook;
+pub mod webhook_sender;
 pub mod withdraw;
@@ -0,0 +1,25 @@
+use async_trait::async_trait;
+use mockall::automock;
+use std::sync::Arc;
+
+use crate::{domain::response::topup::PaymentInstructions, utils::errors::AppError};
+
+pub type DynPaymentChannel = Arc<dyn PaymentChannelTrait + Send + Sync>;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PaymentChannelRequest {
+    /// Our payment reference; the provider echoes it back in its callback.
+    pub reference: String,
+    pub method: String,
+    pub amount: i32,
+}
+
+#[automock]
+#[async_trait]
+pub trait PaymentChannelTrait {
+    async fn create_payment(
+        &self,
+        input: &PaymentChannelRequest,
+    ) -> Result<PaymentInstructions, AppError>;
+    fn verify_signature(&self, payload: &[u8], signature: &str) -> bool;
+}
@@ -0,0 +1,93 @@
+use async_trait::async_trait;
+use mockall::automock;
+use sea_orm::DbErr;
+use std::sync::Arc;
+
+use crate::{
+    domain::{
+        request::payment_intent::CreatePaymentIntentRequest,
+        response::{payment_intent::PaymentIntentResponse, ApiResponse, ErrorResponse},
+    },
+    entities::payment_intents,
+};
+
+pub type DynPaymentIntentRepository = Arc<dyn PaymentIntentRepositoryTrait + Send + Sync>;
+pub type DynPaymentIntentService = Arc<dyn PaymentIntentServiceTrait + Send + Sync>;
+
+#[automock]
+#[async_trait]
+pub trait PaymentIntentRepositoryTrait {
+    async fn find_by_id(&self, id: i32) -> Result<Option<payment_intents::Model>, DbErr>;
+    async fn find_by_merchant(
+        &self,
+        merchant_id: i32,
+    ) -> Result<Vec<payment_intents::Model>, DbErr>;
+    async fn find_by_reference(
+        &self,
+        merchant_id: i32,
+        reference: &str,
+    ) -> Result<Option<payment_intents::Model>, DbErr>;
+    async fn find_by_fraud_review(
+        &self,
+        fraud_review_id: i32,
+    ) -> Result<Option<payment_intents::Model>, DbErr>;
+    async fn create(
+        &self,
+        input: &CreatePaymentIntentRequest,
+        merchant_id: i32,
+    ) -> Result<payment_intents::Model, DbErr>;
+    /// Moves an intent from `from_status` to `to_status`, returning whether
+    /// this call made the change.
+    async fn transition_status(
+        &self,
+        id: i32,
+        from_status: &str,
+        to_status: &str,
+    ) -> Result<bool, DbErr>;
+    /// Links a processing intent to the fraud review holding its transfer,
+    /// returning whether the intent was still processing.
+    async fn hold_for_review(
+        &self,
+        id: i32,
+        customer_id: i32,
+        fraud_review_id: i32,
+    ) -> Result<bool, DbErr>;
+    async fn record_success(
+        &self,
+        id: i32,
+        customer_id: i32,
+        transfer_id: i32,
+    ) -> Result<payment_intents::Model, DbErr>;
+    /// Adds `amount` to `amount_refunded` of a succeeded intent, but only
+    /// while the total stays within the paid amount. Returns whether the
+    /// reservation was made.
+    async fn reserve_refund(&self, id: i32, amount: i32) -> Result<bool, DbErr>;
+    async fn release_refund(&self, id: i32, amount: i32) -> Result<(), DbErr>;
+}
+
+#[automock]
+#[async_trait]
+pub trait PaymentIntentServiceTrait {
+    async fn create_payment_intent(
+        &self,
+        input: &CreatePaymentIntentRequest,
+    ) -> Result<ApiResponse<PaymentIntentResponse>, ErrorResponse>;
+    async fn get_payment_intents(
+        &self,
+        user_id: i32,
+    ) -> Result<ApiResponse<Vec<PaymentIntentResponse>>, ErrorResponse>;
+    async fn get_payment_intent(
+        &self,
+        id: i32,
+    ) -> Result<ApiResponse<PaymentIntentResponse>, ErrorResponse>;
+    async fn confirm_payment_intent(
+        &self,
+        id: i32,
+        customer_id: i32,
+    ) -> Result<ApiResponse<PaymentIntentResponse>, ErrorResponse>;
+    async fn cancel_payment_intent(
+        &self,
+        id: i32,
+        user_id: i32,
+    ) -> Result<ApiResponse<PaymentIntentResponse>, ErrorResponse>;
+}
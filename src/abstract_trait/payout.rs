@@ -0,0 +1,40 @@
+use async_trait::async_trait;
+use mockall::automock;
+use std::sync::Arc;
+
+use crate::utils::errors::AppError;
+
+pub type DynPayoutProvider = Arc<dyn PayoutProviderTrait + Send + Sync>;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PayoutRequest {
+    /// Our own reference, used by the provider to deduplicate retries.
+    pub reference: String,
+    pub bank_code: String,
+    pub account_number: String,
+    pub holder_name: String,
+    pub amount: i32,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum PayoutStatus {
+    Pending,
+    Succeeded,
+    Failed(String),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PayoutResult {
+    pub provider_reference: String,
+    pub status: PayoutStatus,
+}
+
+#[automock]
+#[async_trait]
+pub trait PayoutProviderTrait {
+    async fn create_payout(&self, input: &PayoutRequest) -> Result<PayoutResult, AppError>;
+    async fn get_payout_status(&self, provider_reference: &str) -> Result<PayoutStatus, AppError>;
+    /// The payout requested under our `reference`, `None` when the provider
+    /// never received it.
+    async fn find_payout(&self, reference: &str) -> Result<Option<PayoutResult>, AppError>;
+}
@@ -0,0 +1,79 @@
+use async_trait::async_trait;
+use mockall::automock;
+use sea_orm::DbErr;
+use std::sync::Arc;
+
+use crate::{
+    domain::{
+        request::qris::{CreateQrisRequest, PayQrisRequest},
+        response::{
+            qris::{QrisImage, QrisResponse},
+            ApiResponse, ErrorResponse,
+        },
+    },
+    entities::qris_payments,
+};
+
+pub type DynQrisRepository = Arc<dyn QrisRepositoryTrait + Send + Sync>;
+pub type DynQrisService = Arc<dyn QrisServiceTrait + Send + Sync>;
+
+#[automock]
+#[async_trait]
+pub trait QrisRepositoryTrait {
+    async fn find_by_reference(
+        &self,
+        reference: &str,
+    ) -> Result<Option<qris_payments::Model>, DbErr>;
+    async fn find_by_fraud_review(
+        &self,
+        fraud_review_id: i32,
+    ) -> Result<Option<qris_payments::Model>, DbErr>;
+    async fn create(
+        &self,
+        input: &CreateQrisRequest,
+        merchant_id: i32,
+        reference: &str,
+        payload: &str,
+    ) -> Result<qris_payments::Model, DbErr>;
+    /// Moves a QRIS payment from `from_status` to `to_status`, returning
+    /// whether this call made the change.
+    async fn transition_status(
+        &self,
+        id: i32,
+        from_status: &str,
+        to_status: &str,
+    ) -> Result<bool, DbErr>;
+    /// Links a processing QRIS to the fraud review holding its transfer,
+    /// returning whether the QRIS was still processing.
+    async fn hold_for_review(
+        &self,
+        id: i32,
+        payer_id: i32,
+        fraud_review_id: i32,
+    ) -> Result<bool, DbErr>;
+    async fn record_payment(
+        &self,
+        id: i32,
+        payer_id: i32,
+        transfer_id: i32,
+    ) -> Result<qris_payments::Model, DbErr>;
+}
+
+#[automock]
+#[async_trait]
+pub trait QrisServiceTrait {
+    async fn create_qris(
+        &self,
+        input: &CreateQrisRequest,
+    ) -> Result<ApiResponse<QrisResponse>, ErrorResponse>;
+    async fn get_qris_image(
+        &self,
+        reference: &str,
+        user_id: i32,
+        format: &str,
+    ) -> Result<QrisImage, ErrorResponse>;
+    async fn pay_qris(
+        &self,
+        input: &PayQrisRequest,
+    ) -> Result<ApiResponse<QrisResponse>, ErrorResponse>;
+}
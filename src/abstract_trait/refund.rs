@@ -0,0 +1,41 @@
+use async_trait::async_trait;
+use mockall::automock;
+use sea_orm::DbErr;
+use std::sync::Arc;
+
+use crate::{
+    domain::{
+        request::refund::CreateRefundRequest,
+        response::{refund::RefundResponse, ApiResponse, ErrorResponse},
+    },
+    entities::refunds,
+};
+
+pub type DynRefundRepository = Arc<dyn RefundRepositoryTrait + Send + Sync>;
+pub type DynRefundService = Arc<dyn RefundServiceTrait + Send + Sync>;
+
+#[automock]
+#[async_trait]
+pub trait RefundRepositoryTrait {
+    async fn find_by_payment_intent(
+        &self,
+        payment_intent_id: i32,
+    ) -> Result<Vec<refunds::Model>, DbErr>;
+    async fn create(&self, input: &CreateRefundRequest) -> Result<refunds::Model, DbErr>;
+    async fn mark_succeeded(&self, id: i32) -> Result<refunds::Model, DbErr>;
+    async fn mark_failed(&self, id: i32, failure_reason: &str) -> Result<refunds::Model, DbErr>;
+}
+
+#[automock]
+#[async_trait]
+pub trait RefundServiceTrait {
+    async fn create_refund(
+        &self,
+        input: &CreateRefundRequest,
+    ) -> Result<ApiResponse<RefundResponse>, ErrorResponse>;
+    async fn get_refunds(
+        &self,
+        payment_intent_id: i32,
+        user_id: i32,
+    ) -> Result<ApiResponse<Vec<RefundResponse>>, ErrorResponse>;
+}
@@ -0,0 +1,104 @@
+use async_trait::async_trait;
+use chrono::NaiveDateTime;
+use mockall::automock;
+use sea_orm::DbErr;
+use std::sync::Arc;
+
+use crate::{
+    domain::{
+        request::scheduled_transfer::{
+            CreateScheduledTransferAttempt, CreateScheduledTransferRequest,
+            UpdateScheduledTransferRequest, UpdateScheduledTransferRun,
+        },
+        response::{
+            scheduled_transfer::{ScheduledTransferDetailResponse, ScheduledTransferResponse},
+            ApiResponse, ErrorResponse,
+        },
+    },
+    entities::{scheduled_transfer_attempts, scheduled_transfers},
+};
+
+pub type DynScheduledTransferRepository = Arc<dyn ScheduledTransferRepositoryTrait + Send + Sync>;
+pub type DynScheduledTransferService = Arc<dyn ScheduledTransferServiceTrait + Send + Sync>;
+
+#[automock]
+#[async_trait]
+pub trait ScheduledTransferRepositoryTrait {
+    async fn find_by_user(&self, user_id: i32) -> Result<Vec<scheduled_transfers::Model>, DbErr>;
+    async fn find_by_id(&self, id: i32) -> Result<Option<scheduled_transfers::Model>, DbErr>;
+    async fn find_due(
+        &self,
+        now: NaiveDateTime,
+        limit: u64,
+    ) -> Result<Vec<scheduled_transfers::Model>, DbErr>;
+    async fn create(
+        &self,
+        input: &CreateScheduledTransferRequest,
+        next_run_at: NaiveDateTime,
+    ) -> Result<scheduled_transfers::Model, DbErr>;
+    async fn update(
+        &self,
+        input: &UpdateScheduledTransferRequest,
+    ) -> Result<scheduled_transfers::Model, DbErr>;
+    /// Applies `input` only while the schedule is active and still due at
+    /// `due_at`; `false` when another run already claimed that occurrence.
+    async fn claim_run(
+        &self,
+        input: &UpdateScheduledTransferRun,
+        due_at: NaiveDateTime,
+    ) -> Result<bool, DbErr>;
+    async fn update_run(
+        &self,
+        input: &UpdateScheduledTransferRun,
+    ) -> Result<scheduled_transfers::Model, DbErr>;
+    async fn delete(&self, id: i32) -> Result<(), DbErr>;
+    async fn create_attempt(
+        &self,
+        input: &CreateScheduledTransferAttempt,
+    ) -> Result<scheduled_transfer_attempts::Model, DbErr>;
+    async fn find_attempts(
+        &self,
+        schedule_id: i32,
+    ) -> Result<Vec<scheduled_transfer_attempts::Model>, DbErr>;
+    async fn find_attempt_by_fraud_review(
+        &self,
+        fraud_review_id: i32,
+    ) -> Result<Option<scheduled_transfer_attempts::Model>, DbErr>;
+    /// Records the outcome of an attempt whose transfer was held for fraud
+    /// review; `false` when it is no longer `held`.
+    async fn resolve_held_attempt(
+        &self,
+        attempt_id: i32,
+        status: &str,
+        transfer_id: Option<i32>,
+        error_message: Option<String>,
+    ) -> Result<bool, DbErr>;
+}
+
+#[automock]
+#[async_trait]
+pub trait ScheduledTransferServiceTrait {
+    async fn get_scheduled_transfers(
+        &self,
+        user_id: i32,
+    ) -> Result<ApiResponse<Vec<ScheduledTransferResponse>>, ErrorResponse>;
+    async fn get_scheduled_transfer(
+        &self,
+        id: i32,
+        user_id: i32,
+    ) -> Result<ApiResponse<ScheduledTransferDetailResponse>, ErrorResponse>;
+    async fn create_scheduled_transfer(
+        &self,
+        input: &CreateScheduledTransferRequest,
+    ) -> Result<ApiResponse<ScheduledTransferResponse>, ErrorResponse>;
+    async fn update_scheduled_transfer(
+        &self,
+        input: &UpdateScheduledTransferRequest,
+    ) -> Result<ApiResponse<ScheduledTransferResponse>, ErrorResponse>;
+    async fn delete_scheduled_transfer(
+        &self,
+        id: i32,
+        user_id: i32,
+    ) -> Result<ApiResponse<()>, ErrorResponse>;
+    async fn run_due_schedules(&self) -> Result<usize, ErrorResponse>;
+}
@@ -0,0 +1,67 @@
+use async_trait::async_trait;
+use mockall::automock;
+use sea_orm::DbErr;
+use std::sync::Arc;
+
+use crate::{
+    domain::{
+        request::screening::{
+            CreateScreeningResult, ReviewScreeningResultRequest, ScreenNameRequest,
+        },
+        response::{
+            screening::{ScreeningResultResponse, WatchlistStatusResponse},
+            ApiResponse, ErrorResponse,
+        },
+    },
+    entities::screening_results,
+};
+
+pub type DynScreeningRepository = Arc<dyn ScreeningRepositoryTrait + Send + Sync>;
+pub type DynScreeningService = Arc<dyn ScreeningServiceTrait + Send + Sync>;
+
+#[automock]
+#[async_trait]
+pub trait ScreeningRepositoryTrait {
+    async fn create_result(
+        &self,
+        input: &CreateScreeningResult,
+    ) -> Result<screening_results::Model, DbErr>;
+    async fn find_by_id(&self, id: i32) -> Result<Option<screening_results::Model>, DbErr>;
+    /// Results of every user, oldest first so reviewers work in order.
+    async fn find_by_status(
+        &self,
+        status: Option<String>,
+    ) -> Result<Vec<screening_results::Model>, DbErr>;
+    /// Moves a result from `from_status` to `to_status`, recording the
+    /// reviewer. Returns false when it was no longer in `from_status`.
+    async fn transition_status(
+        &self,
+        id: i32,
+        from_status: &str,
+        to_status: &str,
+        reviewer_id: i32,
+        note: Option<String>,
+    ) -> Result<bool, DbErr>;
+}
+
+#[automock]
+#[async_trait]
+pub trait ScreeningServiceTrait {
+    /// Screens the names against the watchlist and stores each matching
+    /// entry for review. Returns the stored matches.
+    async fn screen(
+        &self,
+        input: &ScreenNameRequest,
+    ) -> Result<Vec<ScreeningResultResponse>, ErrorResponse>;
+    async fn get_results(
+        &self,
+        status: Option<String>,
+    ) -> Result<ApiResponse<Vec<ScreeningResultResponse>>, ErrorResponse>;
+    async fn review_result(
+        &self,
+        input: &ReviewScreeningResultRequest,
+    ) -> Result<ApiResponse<ScreeningResultResponse>, ErrorResponse>;
+    /// Reads the watchlist file again without a restart.
+    async fn reload_watchlist(&self)
+        -> Result<ApiResponse<WatchlistStatusResponse>, ErrorResponse>;
+}
@@ -0,0 +1,89 @@
+use async_trait::async_trait;
+use chrono::NaiveDateTime;
+use mockall::automock;
+use sea_orm::DbErr;
+use std::sync::Arc;
+
+use crate::{
+    domain::response::{settlement::SettlementResponse, ApiResponse, ErrorResponse},
+    entities::{payment_intents, qris_payments, refunds, settlements},
+};
+
+pub type DynSettlementRepository = Arc<dyn SettlementRepositoryTrait + Send + Sync>;
+pub type DynSettlementService = Arc<dyn SettlementServiceTrait + Send + Sync>;
+
+#[automock]
+#[async_trait]
+pub trait SettlementRepositoryTrait {
+    async fn find_by_merchant(&self, merchant_id: i32) -> Result<Vec<settlements::Model>, DbErr>;
+    async fn find_by_id(&self, id: i32) -> Result<Option<settlements::Model>, DbErr>;
+    /// Settlements whose payout is `held` or `failed` and should be tried
+    /// again.
+    async fn find_retryable(&self) -> Result<Vec<settlements::Model>, DbErr>;
+    async fn find_by_fraud_review(
+        &self,
+        fraud_review_id: i32,
+    ) -> Result<Option<settlements::Model>, DbErr>;
+    /// Claims every unsettled succeeded payment, paid QRIS and refund of the
+    /// merchant up to `period_end` into a new settlement with its totals. Returns
+    /// `None` when there is nothing to settle or the period is already
+    /// settled.
+    async fn create_settlement(
+        &self,
+        merchant_id: i32,
+        period_start: NaiveDateTime,
+        period_end: NaiveDateTime,
+    ) -> Result<Option<settlements::Model>, DbErr>;
+    async fn find_payments(&self, settlement_id: i32)
+        -> Result<Vec<payment_intents::Model>, DbErr>;
+    async fn find_qris_payments(
+        &self,
+        settlement_id: i32,
+    ) -> Result<Vec<qris_payments::Model>, DbErr>;
+    async fn find_refunds(&self, settlement_id: i32) -> Result<Vec<refunds::Model>, DbErr>;
+    /// Records the fees charged on the settlement's payments and lowers its
+    /// net amount accordingly.
+    async fn update_fee(&self, id: i32, fee_amount: i32) -> Result<settlements::Model, DbErr>;
+    async fn update_payout(
+        &self,
+        id: i32,
+        status: &str,
+        withdraw_id: Option<i32>,
+        failure_reason: Option<String>,
+    ) -> Result<settlements::Model, DbErr>;
+    /// Moves a `held` or `failed` settlement back to `pending` so exactly
+    /// one run retries its payout; `false` when another run claimed it.
+    async fn claim_retry(&self, id: i32, from_status: &str) -> Result<bool, DbErr>;
+    /// Parks the settlement in `in_review` while the fraud review decides
+    /// its payout withdrawal.
+    async fn hold_for_review(
+        &self,
+        id: i32,
+        fraud_review_id: i32,
+    ) -> Result<settlements::Model, DbErr>;
+}
+
+#[automock]
+#[async_trait]
+pub trait SettlementServiceTrait {
+    async fn get_settlements(
+        &self,
+        user_id: i32,
+    ) -> Result<ApiResponse<Vec<SettlementResponse>>, ErrorResponse>;
+    async fn get_settlement(
+        &self,
+        id: i32,
+        user_id: i32,
+    ) -> Result<ApiResponse<SettlementResponse>, ErrorResponse>;
+    /// CSV listing every payment, QRIS and refund included in the
+    /// settlement.
+    async fn get_settlement_report_csv(
+        &self,
+        id: i32,
+        user_id: i32,
+    ) -> Result<String, ErrorResponse>;
+    /// Settles the previous UTC day for every merchant and retries the
+    /// payout of held and failed settlements, returning how many settlements
+    /// were created.
+    async fn run_daily_settlements(&self) -> Result<usize, ErrorResponse>;
+}
@@ -4,6 +4,7 @@ use sea_orm::DbErr;
 use std::sync::Arc;
 
 use crate::{
+    abstract_trait::fee::FeeQuote,
     domain::{
         request::topup::{CreateTopupRequest, UpdateTopupAmount, UpdateTopupRequest},
         response::{topup::TopupResponse, ApiResponse, ErrorResponse},
@@ -25,7 +26,32 @@ pub trait TopupRepositoryTrait {
 
     async fn find_by_user(&self, id: i32) -> Result<Option<topups::Model>, DbErr>;
 
-    async fn create(&self, input: &CreateTopupRequest) -> Result<topups::Model, DbErr>;
+    async fn find_by_payment_reference(
+        &self,
+        payment_reference: &str,
+    ) -> Result<Option<topups::Model>, DbErr>;
+
+    async fn create(
+        &self,
+        input: &CreateTopupRequest,
+        payment_reference: &str,
+        fee: FeeQuote,
+    ) -> Result<topups::Model, DbErr>;
+
+    async fn update_payment_instructions(
+        &self,
+        topup_id: i32,
+        payment_instructions: &str,
+    ) -> Result<topups::Model, DbErr>;
+
+    /// Moves a top-up from `from_status` to `to_status`, returning whether
+    /// this call made the change.
+    async fn transition_status(
+        &self,
+        topup_id: i32,
+        from_status: &str,
+        to_status: &str,
+    ) -> Result<bool, DbErr>;
 
     async fn update(&self, input: &UpdateTopupRequest) -> Result<topups::Model, DbErr>;
 
@@ -52,11 +78,34 @@ pub trait TopupServiceTrait {
         &self,
         input: &CreateTopupRequest,
     ) -> Result<ApiResponse<TopupResponse>, ErrorResponse>;
+    /// Carries out a top-up released from fraud review, skipping the fraud
+    /// screening it already went through.
+    async fn create_approved_topup(
+        &self,
+        input: &CreateTopupRequest,
+    ) -> Result<ApiResponse<TopupResponse>, ErrorResponse>;
+    /// Changes the amount of a pending top-up of `input.user_id`. The saldo
+    /// is only ever credited by the provider callback.
     async fn update_topup(
         &self,
         input: &UpdateTopupRequest,
     ) -> Result<ApiResponse<Option<TopupResponse>>, ErrorResponse>;
+    /// Takes a paid top-up back out of the saldo and revenue, leaving it
+    /// `reversed`. Meant for admins correcting a top-up.
+    async fn reverse_topup(&self, id: i32) -> Result<ApiResponse<TopupResponse>, ErrorResponse>;
     async fn delete_topup(&self, id: i32) -> Result<ApiResponse<()>, ErrorResponse>;
+    async fn handle_payment_callback(
+        &self,
+        payload: &[u8],
+        signature: &str,
+    ) -> Result<ApiResponse<TopupResponse>, ErrorResponse>;
+    /// Records a top-up for money that has already arrived and credits the
+    /// saldo. Repeating a `payment_reference` returns the existing top-up.
+    async fn create_paid_topup(
+        &self,
+        input: &CreateTopupRequest,
+        payment_reference: &str,
+    ) -> Result<ApiResponse<TopupResponse>, ErrorResponse>;
 }
 
 // #[cfg(test)]
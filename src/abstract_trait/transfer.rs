@@ -1,4 +1,5 @@
 use async_trait::async_trait;
+use chrono::NaiveDateTime;
 use mockall::automock;
 use sea_orm::DbErr;
 use std::sync::Arc;
@@ -6,9 +7,13 @@ use std::sync::Arc;
 use crate::{
     domain::{
         request::transfer::{
-            CreateTransferRequest, UpdateTransferAmountRequest, UpdateTransferRequest,
+            CreateTransferByAccountRequest, CreateTransferRequest, UpdateTransferAmountRequest,
+            UpdateTransferRequest,
+        },
+        response::{
+            transfer::{RecipientResponse, TransferResponse},
+            ApiResponse, ErrorResponse,
         },
-        response::{transfer::TransferResponse, ApiResponse, ErrorResponse},
     },
     entities::transfers,
 };
@@ -23,13 +28,29 @@ pub trait TransferRepositoryTrait {
     async fn find_by_id(&self, id: i32) -> Result<Option<transfers::Model>, DbErr>;
     async fn find_by_users(&self, id: i32) -> Result<Option<Vec<transfers::Model>>, DbErr>;
     async fn find_by_user(&self, id: i32) -> Result<Option<transfers::Model>, DbErr>;
-    async fn create(&self, input: &CreateTransferRequest) -> Result<transfers::Model, DbErr>;
+    async fn create(
+        &self,
+        input: &CreateTransferRequest,
+        fee_amount: i32,
+    ) -> Result<transfers::Model, DbErr>;
     async fn update(&self, input: &UpdateTransferRequest) -> Result<transfers::Model, DbErr>;
     async fn update_amount(
         &self,
         input: &UpdateTransferAmountRequest,
     ) -> Result<transfers::Model, DbErr>;
     async fn delete(&self, id: i32) -> Result<(), DbErr>;
+    /// When `transfer_from` first paid `transfer_to`, if ever.
+    async fn find_first_transfer_time(
+        &self,
+        transfer_from: i32,
+        transfer_to: i32,
+    ) -> Result<Option<NaiveDateTime>, DbErr>;
+    async fn sum_amount_since(
+        &self,
+        transfer_from: i32,
+        transfer_to: i32,
+        since: NaiveDateTime,
+    ) -> Result<i64, DbErr>;
 }
 
 #[automock]
@@ -52,6 +73,20 @@ pub trait TransferServiceTrait {
         &self,
         input: &CreateTransferRequest,
     ) -> Result<ApiResponse<TransferResponse>, ErrorResponse>;
+    /// Carries out a transfer released from fraud review, skipping the fraud
+    /// screening it already went through.
+    async fn create_approved_transfer(
+        &self,
+        input: &CreateTransferRequest,
+    ) -> Result<ApiResponse<TransferResponse>, ErrorResponse>;
+    async fn find_recipient(
+        &self,
+        noc_transfer: &str,
+    ) -> Result<ApiResponse<RecipientResponse>, ErrorResponse>;
+    async fn create_transfer_by_account(
+        &self,
+        input: &CreateTransferByAccountRequest,
+    ) -> Result<ApiResponse<TransferResponse>, ErrorResponse>;
     async fn update_transfer(
         &self,
         input: &UpdateTransferRequest,
@@ -62,7 +97,7 @@ pub trait TransferServiceTrait {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use chrono::{DateTime, Utc};
+    use chrono::Utc;
     use mockall::predicate::*;
     use tokio;
 
@@ -75,9 +110,10 @@ mod tests {
             transfer_from: 1001,
             transfer_to: 1002,
             transfer_amount: 5000,
-            transfer_time: DateTime::<Utc>::from(Utc::now()).naive_utc(),
-            created_at: Some(DateTime::<Utc>::from(Utc::now()).naive_utc()),
-            updated_at: Some(DateTime::<Utc>::from(Utc::now()).naive_utc()),
+            transfer_time: Utc::now().naive_utc(),
+            fee_amount: 0,
+            created_at: Some(Utc::now().naive_utc()),
+            updated_at: Some(Utc::now().naive_utc()),
         };
 
         mock_repo
@@ -101,9 +137,10 @@ mod tests {
             transfer_from: 1001,
             transfer_to: 1002,
             transfer_amount: 5000,
-            transfer_time: DateTime::<Utc>::from(Utc::now()).naive_utc(),
-            created_at: Some(DateTime::<Utc>::from(Utc::now()).naive_utc()),
-            updated_at: Some(DateTime::<Utc>::from(Utc::now()).naive_utc()),
+            transfer_time: Utc::now().naive_utc(),
+            fee_amount: 0,
+            created_at: Some(Utc::now().naive_utc()),
+            updated_at: Some(Utc::now().naive_utc()),
         };
 
         mock_repo
@@ -127,6 +164,9 @@ mod tests {
             transfer_from: 1001,
             transfer_to: 1002,
             transfer_amount: 5000,
+            beneficiary_id: None,
+            device_id: None,
+            merchant_payment: false,
         };
 
         let mock_transfer = transfers::Model {
@@ -134,21 +174,22 @@ mod tests {
             transfer_from: 1001,
             transfer_to: 1002,
             transfer_amount: 5000,
-            transfer_time: DateTime::<Utc>::from(Utc::now()).naive_utc(),
-            created_at: Some(DateTime::<Utc>::from(Utc::now()).naive_utc()),
-            updated_at: Some(DateTime::<Utc>::from(Utc::now()).naive_utc()),
+            transfer_time: Utc::now().naive_utc(),
+            fee_amount: 0,
+            created_at: Some(Utc::now().naive_utc()),
+            updated_at: Some(Utc::now().naive_utc()),
         };
 
         mock_repo
             .expect_create()
-            .withf(move |input| {
+            .withf(move |input, _| {
                 input.transfer_from == mock_request.transfer_from
                     && input.transfer_to == mock_request.transfer_to
                     && input.transfer_amount == mock_request.transfer_amount
             })
-            .returning(move |_| Ok(mock_transfer.clone()));
+            .returning(move |_, _| Ok(mock_transfer.clone()));
 
-        let result = mock_repo.create(&mock_request).await;
+        let result = mock_repo.create(&mock_request, 0).await;
 
         assert!(result.is_ok());
         let transfer = result.unwrap();
@@ -170,9 +211,10 @@ mod tests {
             transfer_from: 1001,
             transfer_to: 1002,
             transfer_amount: 7000,
-            transfer_time: DateTime::<Utc>::from(Utc::now()).naive_utc(),
-            created_at: Some(DateTime::<Utc>::from(Utc::now()).naive_utc()),
-            updated_at: Some(DateTime::<Utc>::from(Utc::now()).naive_utc()),
+            transfer_time: Utc::now().naive_utc(),
+            fee_amount: 0,
+            created_at: Some(Utc::now().naive_utc()),
+            updated_at: Some(Utc::now().naive_utc()),
         };
 
         mock_repo
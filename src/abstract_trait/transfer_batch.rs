@@ -0,0 +1,72 @@
+use async_trait::async_trait;
+use mockall::automock;
+use sea_orm::DbErr;
+use std::sync::Arc;
+
+use crate::{
+    domain::{
+        request::transfer_batch::{
+            CreateTransferBatchRequest, UpdateTransferBatchItemResult, UpdateTransferBatchStatus,
+        },
+        response::{
+            transfer_batch::{TransferBatchDetailResponse, TransferBatchResponse},
+            ApiResponse, ErrorResponse,
+        },
+    },
+    entities::{transfer_batch_items, transfer_batches},
+};
+
+pub type DynTransferBatchRepository = Arc<dyn TransferBatchRepositoryTrait + Send + Sync>;
+pub type DynTransferBatchService = Arc<dyn TransferBatchServiceTrait + Send + Sync>;
+
+#[automock]
+#[async_trait]
+pub trait TransferBatchRepositoryTrait {
+    async fn find_by_id(&self, id: i32) -> Result<Option<transfer_batches::Model>, DbErr>;
+    async fn find_items(&self, batch_id: i32) -> Result<Vec<transfer_batch_items::Model>, DbErr>;
+    async fn find_item_by_fraud_review(
+        &self,
+        fraud_review_id: i32,
+    ) -> Result<Option<transfer_batch_items::Model>, DbErr>;
+    async fn find_by_status(
+        &self,
+        status: &str,
+        limit: u64,
+    ) -> Result<Vec<transfer_batches::Model>, DbErr>;
+    async fn create(
+        &self,
+        input: &CreateTransferBatchRequest,
+    ) -> Result<transfer_batches::Model, DbErr>;
+    async fn update_status(
+        &self,
+        input: &UpdateTransferBatchStatus,
+    ) -> Result<transfer_batches::Model, DbErr>;
+    /// Moves a pending item to `processing`; `false` when it was not pending.
+    async fn claim_item(&self, item_id: i32) -> Result<bool, DbErr>;
+    /// Records the outcome of a claimed item; `false` when it was not
+    /// `processing`.
+    async fn update_item_result(
+        &self,
+        input: &UpdateTransferBatchItemResult,
+    ) -> Result<bool, DbErr>;
+    /// Records the outcome of an item whose transfer was held for fraud
+    /// review; `false` when it is no longer `held`.
+    async fn resolve_held_item(&self, input: &UpdateTransferBatchItemResult)
+        -> Result<bool, DbErr>;
+}
+
+#[automock]
+#[async_trait]
+pub trait TransferBatchServiceTrait {
+    async fn create_batch(
+        &self,
+        input: &CreateTransferBatchRequest,
+    ) -> Result<ApiResponse<TransferBatchResponse>, ErrorResponse>;
+    async fn get_batch(
+        &self,
+        id: i32,
+        user_id: i32,
+    ) -> Result<ApiResponse<TransferBatchDetailResponse>, ErrorResponse>;
+    async fn get_batch_result_csv(&self, id: i32, user_id: i32) -> Result<String, ErrorResponse>;
+    async fn process_pending_batches(&self) -> Result<usize, ErrorResponse>;
+}
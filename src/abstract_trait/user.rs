@@ -26,7 +26,10 @@ pub trait UserRepositoryTrait {
     async fn create_user(&self, input: &CreateUserRequest) -> Result<users::Model, DbErr>;
     async fn find_by_email(&self, email: &str) -> Result<Option<users::Model>, DbErr>;
     async fn find_by_id(&self, id: i32) -> Result<Option<users::Model>, DbErr>;
+    async fn find_by_noc_transfer(&self, noc_transfer: &str)
+        -> Result<Option<users::Model>, DbErr>;
     async fn update_user(&self, input: &UpdateUserRequest) -> Result<users::Model, DbErr>;
+    async fn update_kyc_level(&self, id: i32, kyc_level: i32) -> Result<users::Model, DbErr>;
     async fn delete_user(&self, id: i32) -> Result<(), DbErr>;
 }
 
@@ -0,0 +1,88 @@
+use async_trait::async_trait;
+use mockall::automock;
+use sea_orm::DbErr;
+use std::sync::Arc;
+
+use crate::{
+    domain::{
+        request::virtual_account::{
+            CreateVirtualAccountRequest, ResolveSuspensePaymentRequest,
+            VirtualAccountPaymentRequest,
+        },
+        response::{
+            virtual_account::{
+                SuspensePaymentResponse, VirtualAccountPaymentResponse, VirtualAccountResponse,
+            },
+            ApiResponse, ErrorResponse,
+        },
+    },
+    entities::{suspense_payments, virtual_accounts},
+};
+
+pub type DynVirtualAccountRepository = Arc<dyn VirtualAccountRepositoryTrait + Send + Sync>;
+pub type DynVirtualAccountService = Arc<dyn VirtualAccountServiceTrait + Send + Sync>;
+
+#[automock]
+#[async_trait]
+pub trait VirtualAccountRepositoryTrait {
+    async fn find_by_user(&self, user_id: i32) -> Result<Vec<virtual_accounts::Model>, DbErr>;
+    async fn find_by_number(
+        &self,
+        bank_code: &str,
+        va_number: &str,
+    ) -> Result<Option<virtual_accounts::Model>, DbErr>;
+    async fn create(
+        &self,
+        input: &CreateVirtualAccountRequest,
+        va_number: &str,
+    ) -> Result<virtual_accounts::Model, DbErr>;
+    async fn find_suspense_by_id(
+        &self,
+        id: i32,
+    ) -> Result<Option<suspense_payments::Model>, DbErr>;
+    async fn find_suspense_by_payment_id(
+        &self,
+        payment_id: &str,
+    ) -> Result<Option<suspense_payments::Model>, DbErr>;
+    async fn find_suspense_by_status(
+        &self,
+        status: &str,
+    ) -> Result<Vec<suspense_payments::Model>, DbErr>;
+    async fn create_suspense(
+        &self,
+        input: &VirtualAccountPaymentRequest,
+    ) -> Result<suspense_payments::Model, DbErr>;
+    /// Marks an unresolved suspense payment as resolved, returning whether
+    /// this call made the change.
+    async fn resolve_suspense(
+        &self,
+        id: i32,
+        user_id: i32,
+        topup_id: i32,
+    ) -> Result<bool, DbErr>;
+}
+
+#[automock]
+#[async_trait]
+pub trait VirtualAccountServiceTrait {
+    async fn get_virtual_accounts(
+        &self,
+        user_id: i32,
+    ) -> Result<ApiResponse<Vec<VirtualAccountResponse>>, ErrorResponse>;
+    async fn create_virtual_account(
+        &self,
+        input: &CreateVirtualAccountRequest,
+    ) -> Result<ApiResponse<VirtualAccountResponse>, ErrorResponse>;
+    async fn handle_payment(
+        &self,
+        payload: &[u8],
+        signature: &str,
+    ) -> Result<ApiResponse<VirtualAccountPaymentResponse>, ErrorResponse>;
+    async fn get_suspense_payments(
+        &self,
+    ) -> Result<ApiResponse<Vec<SuspensePaymentResponse>>, ErrorResponse>;
+    async fn resolve_suspense_payment(
+        &self,
+        input: &ResolveSuspensePaymentRequest,
+    ) -> Result<ApiResponse<SuspensePaymentResponse>, ErrorResponse>;
+}
@@ -0,0 +1,20 @@
+use async_trait::async_trait;
+use mockall::automock;
+use std::sync::Arc;
+
+use crate::utils::{errors::AppError, watchlist::WatchlistMatch};
+
+pub type DynWatchlist = Arc<dyn WatchlistTrait + Send + Sync>;
+
+/// Sanctions and watchlist entries names are screened against.
+#[automock]
+#[async_trait]
+pub trait WatchlistTrait {
+    /// Entries scoring at least `min_score` (0 to 100) against the name,
+    /// best match first.
+    fn search(&self, name: &str, min_score: i32) -> Vec<WatchlistMatch>;
+    fn entry_count(&self) -> usize;
+    /// Reads the list again, keeping the current one when that fails.
+    /// Returns the number of entries loaded.
+    async fn reload(&self) -> Result<usize, AppError>;
+}
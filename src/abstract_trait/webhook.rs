@@ -0,0 +1,103 @@
+use async_trait::async_trait;
+use chrono::NaiveDateTime;
+use mockall::automock;
+use sea_orm::DbErr;
+use std::sync::Arc;
+
+use crate::{
+    domain::{
+        request::webhook::CreateWebhookEndpointRequest,
+        response::{
+            webhook::{WebhookDeliveryResponse, WebhookEndpointResponse},
+            ApiResponse, ErrorResponse,
+        },
+    },
+    entities::{webhook_deliveries, webhook_endpoints, webhook_events},
+};
+
+pub type DynWebhookRepository = Arc<dyn WebhookRepositoryTrait + Send + Sync>;
+pub type DynWebhookService = Arc<dyn WebhookServiceTrait + Send + Sync>;
+
+/// Outcome of one delivery attempt, written back to the delivery log.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DeliveryAttempt {
+    pub status: String,
+    pub response_status: Option<i32>,
+    pub error: Option<String>,
+    pub next_attempt_at: NaiveDateTime,
+}
+
+#[automock]
+#[async_trait]
+pub trait WebhookRepositoryTrait {
+    async fn find_endpoints_by_merchant(
+        &self,
+        merchant_id: i32,
+    ) -> Result<Vec<webhook_endpoints::Model>, DbErr>;
+    async fn find_endpoint_by_id(&self, id: i32)
+        -> Result<Option<webhook_endpoints::Model>, DbErr>;
+    async fn create_endpoint(
+        &self,
+        input: &CreateWebhookEndpointRequest,
+        merchant_id: i32,
+        secret: &str,
+    ) -> Result<webhook_endpoints::Model, DbErr>;
+    async fn deactivate_endpoint(&self, id: i32) -> Result<webhook_endpoints::Model, DbErr>;
+    async fn find_event_by_id(&self, id: i32) -> Result<Option<webhook_events::Model>, DbErr>;
+    async fn find_deliveries_by_merchant(
+        &self,
+        merchant_id: i32,
+    ) -> Result<Vec<webhook_deliveries::Model>, DbErr>;
+    async fn find_delivery_by_id(
+        &self,
+        id: i32,
+    ) -> Result<Option<webhook_deliveries::Model>, DbErr>;
+    async fn find_due_deliveries(
+        &self,
+        now: NaiveDateTime,
+        limit: u64,
+    ) -> Result<Vec<webhook_deliveries::Model>, DbErr>;
+    async fn create_delivery(
+        &self,
+        webhook_event_id: i32,
+        webhook_endpoint_id: i32,
+        merchant_id: i32,
+    ) -> Result<webhook_deliveries::Model, DbErr>;
+    /// Counts one more attempt and records its outcome.
+    async fn record_attempt(
+        &self,
+        id: i32,
+        attempt: &DeliveryAttempt,
+    ) -> Result<webhook_deliveries::Model, DbErr>;
+}
+
+#[automock]
+#[async_trait]
+pub trait WebhookServiceTrait {
+    async fn create_endpoint(
+        &self,
+        input: &CreateWebhookEndpointRequest,
+    ) -> Result<ApiResponse<WebhookEndpointResponse>, ErrorResponse>;
+    async fn get_endpoints(
+        &self,
+        user_id: i32,
+    ) -> Result<ApiResponse<Vec<WebhookEndpointResponse>>, ErrorResponse>;
+    async fn delete_endpoint(
+        &self,
+        id: i32,
+        user_id: i32,
+    ) -> Result<ApiResponse<WebhookEndpointResponse>, ErrorResponse>;
+    async fn get_deliveries(
+        &self,
+        user_id: i32,
+    ) -> Result<ApiResponse<Vec<WebhookDeliveryResponse>>, ErrorResponse>;
+    /// Queues a fresh delivery of the same event to the same endpoint.
+    async fn replay_delivery(
+        &self,
+        id: i32,
+        user_id: i32,
+    ) -> Result<ApiResponse<WebhookDeliveryResponse>, ErrorResponse>;
+    /// Sends every delivery whose next attempt is due, returning how many
+    /// were attempted.
+    async fn deliver_due_webhooks(&self) -> Result<usize, ErrorResponse>;
+}
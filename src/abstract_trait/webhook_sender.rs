@@ -0,0 +1,25 @@
+use async_trait::async_trait;
+use mockall::automock;
+use std::sync::Arc;
+
+use crate::utils::errors::AppError;
+
+pub type DynWebhookSender = Arc<dyn WebhookSenderTrait + Send + Sync>;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct WebhookRequest {
+    pub url: String,
+    pub delivery_id: i32,
+    pub event_type: String,
+    pub body: String,
+    /// Hex HMAC-SHA256 of `body` with the endpoint's secret.
+    pub signature: String,
+}
+
+#[automock]
+#[async_trait]
+pub trait WebhookSenderTrait {
+    /// Posts the webhook and returns the HTTP status the endpoint answered
+    /// with. Transport failures such as timeouts are errors.
+    async fn send(&self, request: &WebhookRequest) -> Result<u16, AppError>;
+}
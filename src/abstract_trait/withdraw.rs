@@ -4,8 +4,9 @@ use sea_orm::DbErr;
 use std::sync::Arc;
 
 use crate::{
+    abstract_trait::fee::FeeQuote,
     domain::{
-        request::withdraw::{CreateWithdrawRequest, UpdateWithdrawRequest},
+        request::withdraw::{CreateWithdrawRequest, UpdateWithdrawRequest, UpdateWithdrawStatus},
         response::{withdraw::WithdrawResponse, ApiResponse, ErrorResponse},
     },
     entities::withdraws,
@@ -21,8 +22,23 @@ pub trait WithdrawRepositoryTrait {
     async fn find_by_id(&self, id: i32) -> Result<Option<withdraws::Model>, DbErr>;
     async fn find_by_users(&self, id: i32) -> Result<Option<Vec<withdraws::Model>>, DbErr>;
     async fn find_by_user(&self, id: i32) -> Result<Option<withdraws::Model>, DbErr>;
-    async fn create(&self, input: &CreateWithdrawRequest) -> Result<withdraws::Model, DbErr>;
+    async fn find_by_status(
+        &self,
+        status: &str,
+        limit: u64,
+    ) -> Result<Vec<withdraws::Model>, DbErr>;
+    async fn create(
+        &self,
+        input: &CreateWithdrawRequest,
+        fee: FeeQuote,
+    ) -> Result<withdraws::Model, DbErr>;
     async fn update(&self, input: &UpdateWithdrawRequest) -> Result<withdraws::Model, DbErr>;
+    /// Moves a pending withdraw to the reported status; `None` when it is
+    /// no longer pending because another update got there first.
+    async fn update_status(
+        &self,
+        input: &UpdateWithdrawStatus,
+    ) -> Result<Option<withdraws::Model>, DbErr>;
     async fn delete(&self, id: i32) -> Result<(), DbErr>;
 }
 
@@ -46,11 +62,18 @@ pub trait WithdrawServiceTrait {
         &self,
         input: &CreateWithdrawRequest,
     ) -> Result<ApiResponse<WithdrawResponse>, ErrorResponse>;
+    /// Carries out a withdraw released from fraud review, skipping the fraud
+    /// screening it already went through.
+    async fn create_approved_withdraw(
+        &self,
+        input: &CreateWithdrawRequest,
+    ) -> Result<ApiResponse<WithdrawResponse>, ErrorResponse>;
     async fn update_withdraw(
         &self,
         input: &UpdateWithdrawRequest,
     ) -> Result<ApiResponse<Option<WithdrawResponse>>, ErrorResponse>;
     async fn delete_withdraw(&self, id: i32) -> Result<ApiResponse<()>, ErrorResponse>;
+    async fn sync_pending_payouts(&self) -> Result<usize, ErrorResponse>;
 }
 
 // #[cfg(test)]
@@ -0,0 +1,12 @@
+use chrono::{NaiveDateTime, Utc};
+
+use crate::abstract_trait::clock::ClockTrait;
+
+#[derive(Clone, Default)]
+pub struct SystemClock;
+
+impl ClockTrait for SystemClock {
+    fn now(&self) -> NaiveDateTime {
+        Utc::now().naive_utc()
+    }
+}
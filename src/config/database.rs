@@ -1,44 +1,164 @@
-use sea_orm::{Database, DatabaseConnection};
-use sea_orm_migration::{MigrationTrait, SchemaManager};
+use opentelemetry::{
+    global,
+    trace::{Span, SpanKind, Tracer},
+    KeyValue,
+};
+use sea_orm::{metric, ConnectOptions, Database, DatabaseBackend, DatabaseConnection, DbErr};
+use sea_orm_migration::MigratorTrait;
+use std::{future::Future, time::SystemTime};
+use tracing_opentelemetry::OpenTelemetrySpanExt;
 
-use crate::utils::errors::ConnectionManagerError;
+use crate::utils::{errors::ConnectionManagerError, log_tracing::TRACER_NAME};
+
+/// Exports every finished query as a span below the span that ran it. The
+/// span is recorded once the query is done, so it is backdated to its start.
+fn trace_query(info: &metric::Info<'_>) {
+    let ended = SystemTime::now();
+    let started = ended.checked_sub(info.elapsed).unwrap_or(ended);
+
+    let system = match info.statement.db_backend {
+        DatabaseBackend::Postgres => "postgresql",
+        DatabaseBackend::Sqlite => "sqlite",
+        DatabaseBackend::MySql => "mysql",
+    };
+
+    let tracer = global::tracer(TRACER_NAME);
+    let mut span = tracer
+        .span_builder("db.query")
+        .with_kind(SpanKind::Client)
+        .with_start_time(started)
+        .with_attributes([
+            KeyValue::new("db.system", system),
+            KeyValue::new("db.statement", info.statement.sql.clone()),
+            KeyValue::new("error", info.failed),
+        ])
+        .start_with_context(&tracer, &tracing::Span::current().context());
+    span.end_with_timestamp(ended);
+}
+
+tokio::task_local! {
+    static READ_FROM_REPLICA: ();
+}
+
+/// Runs `future` with reads that tolerate replication lag going to the
+/// replica. Everything else, background jobs included, reads the primary.
+pub async fn reading_from_replica<F: Future>(future: F) -> F::Output {
+    READ_FROM_REPLICA.scope((), future).await
+}
+
+/// The primary every write goes to and an optional read replica.
+#[derive(Clone)]
+pub struct DatabasePools {
+    primary: DatabaseConnection,
+    replica: Option<DatabaseConnection>,
+}
+
+impl DatabasePools {
+    pub fn new(primary: DatabaseConnection, replica: Option<DatabaseConnection>) -> Self {
+        Self { primary, replica }
+    }
+
+    pub fn primary(&self) -> &DatabaseConnection {
+        &self.primary
+    }
+
+    pub fn replica(&self) -> Option<&DatabaseConnection> {
+        self.replica.as_ref()
+    }
+
+    /// Connection for `find_all`, `find_by_id` and report queries: the
+    /// replica inside [`reading_from_replica`], the primary otherwise.
+    pub fn reader(&self) -> &DatabaseConnection {
+        match &self.replica {
+            Some(replica) if READ_FROM_REPLICA.try_with(|_| ()).is_ok() => replica,
+            _ => &self.primary,
+        }
+    }
+}
+
+impl DatabasePools {
+    pub async fn close(self) -> Result<(), DbErr> {
+        if let Some(replica) = self.replica {
+            replica.close().await?;
+        }
+
+        self.primary.close().await
+    }
+}
+
+impl From<DatabaseConnection> for DatabasePools {
+    fn from(primary: DatabaseConnection) -> Self {
+        Self::new(primary, None)
+    }
+}
 
 pub struct ConnectionManager;
 
+/// Connections of a pool at one point in time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PoolUsage {
+    pub active: u32,
+    pub idle: u32,
+    pub max: u32,
+}
+
 impl ConnectionManager {
-    pub async fn new_pool<M: MigrationTrait + Default>(
-        connection_string: &str,
+    pub async fn new_pool<M: MigratorTrait>(
+        options: impl Into<ConnectOptions>,
         run_migrations: bool,
     ) -> Result<DatabaseConnection, ConnectionManagerError> {
-        let pool = Database::connect(connection_string)
+        let mut pool = Database::connect(options)
             .await
             .map_err(ConnectionManagerError::ConnectionError)?;
 
         if run_migrations {
-            let schema_manager = SchemaManager::new(&pool);
-            let migration = M::default();
-
-            migration
-                .up(&schema_manager)
+            M::up(&pool, None)
                 .await
                 .map_err(ConnectionManagerError::MigrationError)?;
         }
 
+        pool.set_metric_callback(trace_query);
+
         Ok(pool)
     }
 
-    pub async fn new_pool_sqlite<M: MigrationTrait + Default>(
+    pub fn pool_usage(pool: &DatabaseConnection) -> Option<PoolUsage> {
+        let (size, idle, max) = match pool {
+            DatabaseConnection::SqlxPostgresPoolConnection(_) => {
+                let pool = pool.get_postgres_connection_pool();
+                (
+                    pool.size(),
+                    pool.num_idle(),
+                    pool.options().get_max_connections(),
+                )
+            }
+            DatabaseConnection::SqlxSqlitePoolConnection(_) => {
+                let pool = pool.get_sqlite_connection_pool();
+                (
+                    pool.size(),
+                    pool.num_idle(),
+                    pool.options().get_max_connections(),
+                )
+            }
+            _ => return None,
+        };
+
+        let idle = idle as u32;
+
+        Some(PoolUsage {
+            active: size.saturating_sub(idle),
+            idle,
+            max,
+        })
+    }
+
+    pub async fn new_pool_sqlite<M: MigratorTrait>(
     ) -> Result<DatabaseConnection, ConnectionManagerError> {
         let pool = Database::connect("sqlite::memory:")
             .await
             .map_err(ConnectionManagerError::ConnectionError)?;
 
-        let schema_manager = SchemaManager::new(&pool);
-
-        let migration = M::default();
-
-        migration
-            .up(&schema_manager)
+        M::up(&pool, None)
             .await
             .map_err(ConnectionManagerError::MigrationError)?;
 
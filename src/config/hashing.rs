@@ -5,6 +5,12 @@ use bcrypt::{hash, verify, BcryptError};
 #[derive(Clone)]
 pub struct Hashing;
 
+impl Default for Hashing {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl Hashing {
     pub fn new() -> Self {
         Hashing
@@ -25,7 +31,7 @@ impl HashingTrait for Hashing {
         match verify(password, hashed_password) {
             Ok(true) => Ok(()),
             Ok(false) => Err(AppError::HashingError(BcryptError::from(
-                std::io::Error::new(std::io::ErrorKind::Other, "Passwords do not match."),
+                std::io::Error::other("Passwords do not match."),
             ))),
             Err(e) => Err(AppError::BcryptError(e.to_string())),
         }
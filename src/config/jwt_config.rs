@@ -23,12 +23,15 @@ impl Claims {
 #[derive(Clone)]
 pub struct JwtConfig {
     pub jwt_secret: String,
+    /// How long an issued token stays valid.
+    pub access_token_ttl: Duration,
 }
 
 impl JwtConfig {
-    pub fn new(jwt_secret: &str) -> Self {
+    pub fn new(jwt_secret: &str, access_token_ttl: Duration) -> Self {
         JwtConfig {
             jwt_secret: jwt_secret.to_string(),
+            access_token_ttl,
         }
     }
 }
@@ -38,7 +41,7 @@ impl JwtServiceTrait for JwtConfig {
     fn generate_token(&self, user_id: i64) -> Result<String, AppError> {
         let now = Utc::now();
         let iat = now.timestamp() as usize;
-        let exp = (now + Duration::minutes(60)).timestamp() as usize;
+        let exp = (now + self.access_token_ttl).timestamp() as usize;
 
         let claims = Claims::new(user_id, exp, iat);
 
@@ -48,7 +51,7 @@ impl JwtServiceTrait for JwtConfig {
             &EncodingKey::from_secret(self.jwt_secret.as_ref()),
         ) {
             Ok(token) => Ok(token),
-            Err(err) => Err(AppError::TokenGenerationError(err.into())),
+            Err(err) => Err(AppError::TokenGenerationError(err)),
         }
     }
 
@@ -1,4 +1,6 @@
 pub mod database;
+#[allow(clippy::module_inception)]
 pub mod config;
 pub mod jwt_config;
-pub mod hashing;
\ No newline at end of file
+pub mod hashing;
+pub mod clock;
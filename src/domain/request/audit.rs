@@ -0,0 +1,68 @@
+use serde::{Deserialize, Serialize};
+use utoipa::IntoParams;
+
+/// Events returned per page when no limit is given.
+pub const DEFAULT_AUDIT_EVENT_LIMIT: u64 = 100;
+pub const MAX_AUDIT_EVENT_LIMIT: u64 = 1000;
+
+/// Fields never copied into the audit trail.
+pub const AUDIT_REDACTED_FIELDS: [&str; 3] = ["password", "secret", "token"];
+
+/// Removes [`AUDIT_REDACTED_FIELDS`] from a snapshot, at any depth.
+pub fn redact_snapshot(value: &mut serde_json::Value) {
+    match value {
+        serde_json::Value::Object(fields) => {
+            for field in AUDIT_REDACTED_FIELDS {
+                fields.remove(field);
+            }
+
+            fields.values_mut().for_each(redact_snapshot);
+        }
+        serde_json::Value::Array(items) => items.iter_mut().for_each(redact_snapshot),
+        _ => {}
+    }
+}
+
+/// A state-changing action about to be appended to the audit trail.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CreateAuditEvent {
+    /// User who made the request, empty for anonymous requests such as
+    /// registration.
+    pub actor_id: Option<i32>,
+    /// Method and route, e.g. `DELETE /api/users/{id}`.
+    pub action: String,
+    pub resource_type: String,
+    pub resource_id: Option<String>,
+    pub before: Option<serde_json::Value>,
+    pub after: Option<serde_json::Value>,
+    pub client_ip: Option<String>,
+    pub request_id: String,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, IntoParams)]
+pub struct FindAuditEventsQuery {
+    pub actor_id: Option<i32>,
+    pub resource_type: Option<String>,
+    pub resource_id: Option<String>,
+    pub request_id: Option<String>,
+    /// Only events older than this one, to page backwards.
+    pub before_id: Option<i32>,
+    /// Events per page, at most 1000.
+    pub limit: Option<u64>,
+}
+
+impl FindAuditEventsQuery {
+    pub fn validate(&self) -> Result<(), String> {
+        if self
+            .limit
+            .is_some_and(|limit| limit == 0 || limit > MAX_AUDIT_EVENT_LIMIT)
+        {
+            return Err(format!(
+                "Limit must be between 1 and {}",
+                MAX_AUDIT_EVENT_LIMIT
+            ));
+        }
+
+        Ok(())
+    }
+}
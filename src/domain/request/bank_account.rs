@@ -0,0 +1,42 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::utils::payment_method_validator::bank_code_validator;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct CreateBankAccountRequest {
+    #[serde(default)]
+    pub user_id: i32,
+    pub bank_code: String,
+    pub account_number: String,
+    pub holder_name: String,
+}
+
+impl CreateBankAccountRequest {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.user_id <= 0 {
+            return Err("User ID must be a positive integer".to_string());
+        }
+
+        if !bank_code_validator(&self.bank_code) {
+            return Err("Bank code is not supported".to_string());
+        }
+
+        if self.account_number.len() < 5
+            || self.account_number.len() > 34
+            || !self.account_number.chars().all(|c| c.is_ascii_digit())
+        {
+            return Err("Account number must be 5 to 34 digits".to_string());
+        }
+
+        if self.holder_name.trim().is_empty() {
+            return Err("Holder name is required".to_string());
+        }
+
+        if self.holder_name.len() > 100 {
+            return Err("Holder name cannot be longer than 100 characters".to_string());
+        }
+
+        Ok(())
+    }
+}
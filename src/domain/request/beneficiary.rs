@@ -0,0 +1,106 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::utils::random_vcc::is_valid_account_number;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct CreateBeneficiaryRequest {
+    #[serde(default)]
+    pub user_id: i32,
+    pub alias: String,
+    /// `account` for another customer's `noc_transfer`, `bank` for an external bank account.
+    pub beneficiary_type: String,
+    pub noc_transfer: Option<String>,
+    pub bank_code: Option<String>,
+    pub bank_account_number: Option<String>,
+}
+
+impl CreateBeneficiaryRequest {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.user_id <= 0 {
+            return Err("User ID must be a positive integer".to_string());
+        }
+
+        if self.alias.trim().is_empty() {
+            return Err("Alias is required".to_string());
+        }
+
+        if self.alias.len() > 100 {
+            return Err("Alias cannot be longer than 100 characters".to_string());
+        }
+
+        match self.beneficiary_type.as_str() {
+            "account" => {
+                let noc_transfer = self
+                    .noc_transfer
+                    .as_deref()
+                    .ok_or("Account number is required for account beneficiaries")?;
+
+                if !is_valid_account_number(noc_transfer) {
+                    return Err("Invalid recipient account number".to_string());
+                }
+
+                if self.bank_code.is_some() || self.bank_account_number.is_some() {
+                    return Err("Bank details are only allowed for bank beneficiaries".to_string());
+                }
+            }
+            "bank" => {
+                let bank_code = self
+                    .bank_code
+                    .as_deref()
+                    .ok_or("Bank code is required for bank beneficiaries")?;
+                let account_number = self
+                    .bank_account_number
+                    .as_deref()
+                    .ok_or("Bank account number is required for bank beneficiaries")?;
+
+                if bank_code.trim().is_empty() || bank_code.len() > 20 {
+                    return Err("Bank code must be between 1 and 20 characters".to_string());
+                }
+
+                if account_number.len() < 5
+                    || account_number.len() > 34
+                    || !account_number.chars().all(|c| c.is_ascii_digit())
+                {
+                    return Err("Bank account number must be 5 to 34 digits".to_string());
+                }
+
+                if self.noc_transfer.is_some() {
+                    return Err(
+                        "Account number is only allowed for account beneficiaries".to_string()
+                    );
+                }
+            }
+            _ => return Err("Beneficiary type must be either account or bank".to_string()),
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct UpdateBeneficiaryRequest {
+    #[serde(default)]
+    pub beneficiary_id: i32,
+    #[serde(default)]
+    pub user_id: i32,
+    pub alias: String,
+}
+
+impl UpdateBeneficiaryRequest {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.beneficiary_id <= 0 {
+            return Err("Beneficiary ID must be a positive integer".to_string());
+        }
+
+        if self.alias.trim().is_empty() {
+            return Err("Alias is required".to_string());
+        }
+
+        if self.alias.len() > 100 {
+            return Err("Alias cannot be longer than 100 characters".to_string());
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,43 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// Transaction types a fee rule can apply to.
+pub const FEE_TRANSACTION_TYPES: [&str; 4] = ["topup", "transfer", "withdraw", "payment"];
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct FeeQuoteRequest {
+    /// One of `topup`, `transfer`, `withdraw` or `payment`.
+    pub transaction_type: String,
+    /// Channel of the transaction, e.g. the top-up method.
+    #[serde(default)]
+    pub method: Option<String>,
+    #[serde(default)]
+    pub merchant_id: Option<i32>,
+    pub amount: i32,
+}
+
+impl FeeQuoteRequest {
+    pub fn validate(&self) -> Result<(), String> {
+        if !FEE_TRANSACTION_TYPES.contains(&self.transaction_type.as_str()) {
+            return Err(format!(
+                "Transaction type must be one of {}",
+                FEE_TRANSACTION_TYPES.join(", ")
+            ));
+        }
+
+        if self.amount <= 0 {
+            return Err("Amount must be a positive integer".to_string());
+        }
+
+        Ok(())
+    }
+}
+
+/// A fee posted to the revenue account. Reversals use a negative amount.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RecordFeeRevenue {
+    pub transaction_type: String,
+    pub transaction_id: i32,
+    pub fee_rule_id: Option<i32>,
+    pub amount: i32,
+}
@@ -0,0 +1,83 @@
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+
+/// States of a held transaction in the analyst queue. `failed` means it was
+/// approved but could no longer be carried out.
+pub const FRAUD_REVIEW_STATUSES: [&str; 4] = ["pending", "approved", "declined", "failed"];
+
+/// Rules understood by the fraud engine:
+///
+/// - `velocity`: more than `threshold` transactions within the window.
+/// - `amount_anomaly`: an amount over `threshold` times the user's average
+///   within the window.
+/// - `new_device_beneficiary`: a device first seen within the window paying
+///   a counterparty the user has paid fewer than `threshold` times.
+/// - `round_trip`: a transfer back to someone who sent the user at least
+///   `threshold` transfers within the window.
+pub const FRAUD_RULE_TYPES: [&str; 4] = [
+    "velocity",
+    "amount_anomaly",
+    "new_device_beneficiary",
+    "round_trip",
+];
+
+/// A transaction about to move money, as seen by the fraud engine.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScreenTransactionRequest {
+    /// `topup`, `transfer` or `withdraw`.
+    pub transaction_type: String,
+    pub user_id: i32,
+    pub amount: i32,
+    /// Receiving user of a transfer or bank account of a withdraw.
+    pub counterparty_id: Option<i32>,
+    pub device_id: Option<String>,
+    /// The original request as JSON, replayed when a held transaction is
+    /// approved.
+    pub payload: String,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CreateFraudReview {
+    pub user_id: i32,
+    pub transaction_type: String,
+    pub amount: i32,
+    pub counterparty_id: Option<i32>,
+    pub payload: String,
+    pub triggered_rules: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct DecideFraudReviewRequest {
+    #[serde(default)]
+    pub fraud_review_id: i32,
+    #[serde(default)]
+    pub reviewer_id: i32,
+    /// Analyst note, required when declining.
+    #[serde(default)]
+    pub note: Option<String>,
+}
+
+impl DecideFraudReviewRequest {
+    pub fn validate(&self, approve: bool) -> Result<(), String> {
+        if self.fraud_review_id <= 0 {
+            return Err("Fraud review ID must be a positive integer".to_string());
+        }
+
+        let has_note = self
+            .note
+            .as_deref()
+            .is_some_and(|note| !note.trim().is_empty());
+
+        if !approve && !has_note {
+            return Err("A note is required when declining a transaction".to_string());
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, IntoParams)]
+pub struct FindFraudReviewsQuery {
+    /// Only reviews with this status.
+    pub status: Option<String>,
+}
@@ -0,0 +1,129 @@
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+
+/// States of a KYC submission, in workflow order.
+pub const KYC_SUBMISSION_STATUSES: [&str; 4] = ["submitted", "in_review", "approved", "rejected"];
+
+/// Largest accepted document upload.
+pub const MAX_KYC_DOCUMENT_BYTES: usize = 5 * 1024 * 1024;
+
+/// File extension stored for an accepted document content type.
+pub fn kyc_document_extension(content_type: &str) -> Option<&'static str> {
+    match content_type {
+        "image/jpeg" => Some("jpg"),
+        "image/png" => Some("png"),
+        "application/pdf" => Some("pdf"),
+        _ => None,
+    }
+}
+
+/// Content type of a stored document, from the extension of its key.
+pub fn kyc_document_content_type(key: &str) -> &'static str {
+    match key.rsplit('.').next() {
+        Some("jpg") => "image/jpeg",
+        Some("png") => "image/png",
+        Some("pdf") => "application/pdf",
+        _ => "application/octet-stream",
+    }
+}
+
+/// An uploaded identity document.
+#[derive(Debug, Clone, PartialEq)]
+pub struct KycDocument {
+    pub content_type: String,
+    pub bytes: Vec<u8>,
+}
+
+impl KycDocument {
+    fn validate(&self, name: &str, allow_pdf: bool) -> Result<(), String> {
+        if self.bytes.is_empty() {
+            return Err(format!("The {} file is empty", name));
+        }
+
+        if self.bytes.len() > MAX_KYC_DOCUMENT_BYTES {
+            return Err(format!(
+                "The {} file cannot be larger than {} bytes",
+                name, MAX_KYC_DOCUMENT_BYTES
+            ));
+        }
+
+        match kyc_document_extension(&self.content_type) {
+            Some("pdf") if !allow_pdf => Err(format!("The {} must be a JPEG or PNG image", name)),
+            Some(_) => Ok(()),
+            None if allow_pdf => Err(format!("The {} must be a JPEG or PNG image or a PDF", name)),
+            None => Err(format!("The {} must be a JPEG or PNG image", name)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CreateKycSubmissionRequest {
+    pub user_id: i32,
+    pub id_card: KycDocument,
+    pub selfie: KycDocument,
+}
+
+impl CreateKycSubmissionRequest {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.user_id <= 0 {
+            return Err("User ID must be a positive integer".to_string());
+        }
+
+        self.id_card.validate("ID card", true)?;
+        self.selfie.validate("selfie", false)?;
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct ReviewKycSubmissionRequest {
+    #[serde(default)]
+    pub kyc_submission_id: i32,
+    #[serde(default)]
+    pub reviewer_id: i32,
+    /// `in_review`, `approved` or `rejected`.
+    pub status: String,
+    /// Reason shown to the user, required when rejecting.
+    #[serde(default)]
+    pub note: Option<String>,
+}
+
+impl ReviewKycSubmissionRequest {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.kyc_submission_id <= 0 {
+            return Err("KYC submission ID must be a positive integer".to_string());
+        }
+
+        if !["in_review", "approved", "rejected"].contains(&self.status.as_str()) {
+            return Err("Status must be one of in_review, approved, rejected".to_string());
+        }
+
+        let has_note = self
+            .note
+            .as_deref()
+            .is_some_and(|note| !note.trim().is_empty());
+
+        if self.status == "rejected" && !has_note {
+            return Err("A note is required when rejecting a submission".to_string());
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, IntoParams)]
+pub struct FindKycSubmissionsQuery {
+    /// Only submissions with this status.
+    pub status: Option<String>,
+}
+
+/// A status change of a submission, kept as its audit trail.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RecordKycSubmissionEvent {
+    pub kyc_submission_id: i32,
+    pub actor_id: i32,
+    pub from_status: Option<String>,
+    pub to_status: String,
+    pub note: Option<String>,
+}
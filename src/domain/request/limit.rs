@@ -0,0 +1,34 @@
+/// KYC tier of a wallet that has not been verified yet.
+pub const KYC_LEVEL_UNVERIFIED: i32 = 0;
+/// KYC tier of a wallet whose identity documents were approved.
+pub const KYC_LEVEL_VERIFIED: i32 = 1;
+
+/// An amount about to move for a user, checked against the limits of the
+/// user's KYC tier.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CheckLimitRequest {
+    pub user_id: i32,
+    /// One of `topup`, `transfer` or `withdraw`.
+    pub transaction_type: String,
+    pub amount: i32,
+}
+
+/// Smallest amount each kind of transaction may move, set under
+/// `transactions` in the configuration. QRIS and payment intent payments
+/// are transfers and share the transfer minimum.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MinimumAmounts {
+    pub topup: i32,
+    pub transfer: i32,
+    pub withdraw: i32,
+}
+
+impl Default for MinimumAmounts {
+    fn default() -> Self {
+        Self {
+            topup: 50_000,
+            transfer: 50_000,
+            withdraw: 50_000,
+        }
+    }
+}
@@ -0,0 +1,27 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct CreateMerchantRequest {
+    #[serde(default)]
+    pub user_id: i32,
+    pub name: String,
+}
+
+impl CreateMerchantRequest {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.user_id <= 0 {
+            return Err("User ID must be a positive integer".to_string());
+        }
+
+        if self.name.trim().is_empty() {
+            return Err("Merchant name is required".to_string());
+        }
+
+        if self.name.len() > 100 {
+            return Err("Merchant name cannot be longer than 100 characters".to_string());
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,47 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::utils::rupiah::thousands;
+
+pub const SUPPORTED_CURRENCIES: [&str; 1] = ["IDR"];
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct CreatePaymentIntentRequest {
+    /// The merchant's owning user; set from the token.
+    #[serde(default)]
+    pub user_id: i32,
+    pub amount: i32,
+    pub currency: String,
+    /// The merchant's own order reference, unique per merchant.
+    pub reference: String,
+    #[schema(format = "date-time")]
+    pub expires_at: DateTime<Utc>,
+}
+
+impl CreatePaymentIntentRequest {
+    pub fn validate(&self, min_amount: i32) -> Result<(), String> {
+        if self.user_id <= 0 {
+            return Err("User ID must be a positive integer".to_string());
+        }
+
+        // Confirming an intent is a transfer, so it shares the transfer minimum.
+        if self.amount < min_amount {
+            return Err(format!("Amount must be at least {}", thousands(min_amount)));
+        }
+
+        if !SUPPORTED_CURRENCIES.contains(&self.currency.as_str()) {
+            return Err("Currency must be IDR".to_string());
+        }
+
+        if self.reference.trim().is_empty() {
+            return Err("Reference is required".to_string());
+        }
+
+        if self.reference.len() > 64 {
+            return Err("Reference cannot be longer than 64 characters".to_string());
+        }
+
+        Ok(())
+    }
+}
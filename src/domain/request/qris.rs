@@ -0,0 +1,49 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::utils::rupiah::thousands;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct CreateQrisRequest {
+    /// The merchant's owning user; set from the token.
+    #[serde(default)]
+    pub user_id: i32,
+    pub amount: i32,
+}
+
+impl CreateQrisRequest {
+    pub fn validate(&self, min_amount: i32) -> Result<(), String> {
+        if self.user_id <= 0 {
+            return Err("User ID must be a positive integer".to_string());
+        }
+
+        // Paying a QRIS is a transfer, so it shares the transfer minimum.
+        if self.amount < min_amount {
+            return Err(format!("Amount must be at least {}", thousands(min_amount)));
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct PayQrisRequest {
+    #[serde(default)]
+    pub payer_id: i32,
+    /// The decoded QRIS string as scanned from the merchant's QR code.
+    pub payload: String,
+}
+
+impl PayQrisRequest {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.payer_id <= 0 {
+            return Err("Payer ID must be a positive integer".to_string());
+        }
+
+        if self.payload.trim().is_empty() {
+            return Err("QRIS payload is required".to_string());
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,40 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct CreateRefundRequest {
+    /// Set from the path.
+    #[serde(default)]
+    pub payment_intent_id: i32,
+    /// The merchant's owning user; set from the token.
+    #[serde(default)]
+    pub user_id: i32,
+    pub amount: i32,
+    pub reason: String,
+}
+
+impl CreateRefundRequest {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.payment_intent_id <= 0 {
+            return Err("Payment ID must be a positive integer".to_string());
+        }
+
+        if self.user_id <= 0 {
+            return Err("User ID must be a positive integer".to_string());
+        }
+
+        if self.amount <= 0 {
+            return Err("Refund amount must be a positive integer".to_string());
+        }
+
+        if self.reason.trim().is_empty() {
+            return Err("Refund reason is required".to_string());
+        }
+
+        if self.reason.len() > 255 {
+            return Err("Refund reason cannot be longer than 255 characters".to_string());
+        }
+
+        Ok(())
+    }
+}
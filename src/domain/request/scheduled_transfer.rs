@@ -0,0 +1,116 @@
+use chrono::{DateTime, NaiveDateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::utils::{rupiah::thousands, schedule::FREQUENCIES};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct CreateScheduledTransferRequest {
+    #[serde(default)]
+    pub transfer_from: i32,
+    pub transfer_to: i32,
+    pub transfer_amount: i32,
+    /// One of `once`, `weekly` or `monthly`.
+    pub frequency: String,
+    #[schema(format = "date-time")]
+    pub start_at: DateTime<Utc>,
+    /// Required for monthly schedules; days past the end of a month run on its last day.
+    pub day_of_month: Option<i32>,
+}
+
+impl CreateScheduledTransferRequest {
+    pub fn validate(&self, min_amount: i32) -> Result<(), String> {
+        if self.transfer_from <= 0 {
+            return Err("Transfer from must be a positive integer".to_string());
+        }
+
+        if self.transfer_to <= 0 {
+            return Err("Transfer to must be a positive integer".to_string());
+        }
+
+        if self.transfer_to == self.transfer_from {
+            return Err("Transfer to cannot be the sender".to_string());
+        }
+
+        if self.transfer_amount < min_amount {
+            return Err(format!(
+                "Transfer amount must be at least {}",
+                thousands(min_amount)
+            ));
+        }
+
+        if !FREQUENCIES.contains(&self.frequency.as_str()) {
+            return Err("Frequency must be one of once, weekly or monthly".to_string());
+        }
+
+        match (self.frequency.as_str(), self.day_of_month) {
+            ("monthly", None) => {
+                return Err("Day of month is required for monthly schedules".to_string());
+            }
+            ("monthly", Some(day)) if !(1..=31).contains(&day) => {
+                return Err("Day of month must be between 1 and 31".to_string());
+            }
+            ("once" | "weekly", Some(_)) => {
+                return Err("Day of month is only allowed for monthly schedules".to_string());
+            }
+            _ => {}
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct UpdateScheduledTransferRequest {
+    #[serde(default)]
+    pub schedule_id: i32,
+    #[serde(default)]
+    pub transfer_from: i32,
+    pub transfer_amount: Option<i32>,
+    /// `active` to resume or `paused` to pause the schedule.
+    pub status: Option<String>,
+}
+
+impl UpdateScheduledTransferRequest {
+    pub fn validate(&self, min_amount: i32) -> Result<(), String> {
+        if self.schedule_id <= 0 {
+            return Err("Schedule ID must be a positive integer".to_string());
+        }
+
+        if let Some(amount) = self.transfer_amount {
+            if amount < min_amount {
+                return Err(format!(
+                    "Transfer amount must be at least {}",
+                    thousands(min_amount)
+                ));
+            }
+        }
+
+        if let Some(status) = &self.status {
+            if status != "active" && status != "paused" {
+                return Err("Status must be either active or paused".to_string());
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UpdateScheduledTransferRun {
+    pub schedule_id: i32,
+    pub status: String,
+    pub next_run_at: NaiveDateTime,
+    pub retry_count: i32,
+    pub last_run_at: NaiveDateTime,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CreateScheduledTransferAttempt {
+    pub schedule_id: i32,
+    pub status: String,
+    pub transfer_id: Option<i32>,
+    pub error_message: Option<String>,
+    pub attempted_at: NaiveDateTime,
+    pub fraud_review_id: Option<i32>,
+}
@@ -0,0 +1,72 @@
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+
+/// States of a watchlist match. `cleared` is a false positive, `confirmed`
+/// a true match.
+pub const SCREENING_RESULT_STATUSES: [&str; 3] = ["pending", "cleared", "confirmed"];
+
+/// Someone screened against the watchlist.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScreenNameRequest {
+    /// `user` or `beneficiary`.
+    pub subject_type: String,
+    pub subject_id: i32,
+    /// User the subject belongs to.
+    pub user_id: i32,
+    /// Every name the subject is known by.
+    pub names: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CreateScreeningResult {
+    pub subject_type: String,
+    pub subject_id: i32,
+    pub user_id: i32,
+    pub screened_name: String,
+    pub watchlist_entry_id: String,
+    pub watchlist_name: String,
+    pub matched_name: String,
+    pub score: i32,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct ReviewScreeningResultRequest {
+    #[serde(default)]
+    pub screening_result_id: i32,
+    #[serde(default)]
+    pub reviewer_id: i32,
+    /// `cleared` or `confirmed`.
+    pub status: String,
+    /// Analyst note, required when clearing a match.
+    #[serde(default)]
+    pub note: Option<String>,
+}
+
+impl ReviewScreeningResultRequest {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.screening_result_id <= 0 {
+            return Err("Screening result ID must be a positive integer".to_string());
+        }
+
+        if !matches!(self.status.as_str(), "cleared" | "confirmed") {
+            return Err("Status must be either cleared or confirmed".to_string());
+        }
+
+        let has_note = self
+            .note
+            .as_deref()
+            .is_some_and(|note| !note.trim().is_empty());
+
+        if self.status == "cleared" && !has_note {
+            return Err("A note is required when clearing a match".to_string());
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, IntoParams)]
+pub struct FindScreeningResultsQuery {
+    /// Only results with this status.
+    pub status: Option<String>,
+}
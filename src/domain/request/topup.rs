@@ -1,7 +1,7 @@
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 
-use crate::utils::payment_method_validator::payment_method_validator;
+use crate::utils::{payment_method_validator::payment_method_validator, rupiah::thousands};
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
 pub struct CreateTopupRequest {
@@ -12,7 +12,7 @@ pub struct CreateTopupRequest {
 }
 
 impl CreateTopupRequest {
-    pub fn validate(&self) -> Result<(), String> {
+    pub fn validate(&self, min_amount: i32) -> Result<(), String> {
         if self.user_id <= 0 {
             return Err("User ID must be a positive integer".to_string());
         }
@@ -21,8 +21,11 @@ impl CreateTopupRequest {
             return Err("Top-up number is required".to_string());
         }
 
-        if self.topup_amount <= 50000 {
-            return Err("Topup amount must be greater than or equal to 50000".to_string());
+        if self.topup_amount < min_amount {
+            return Err(format!(
+                "Topup amount must be at least {}",
+                thousands(min_amount)
+            ));
         }
 
         if self.topup_method.is_empty() {
@@ -46,7 +49,7 @@ pub struct UpdateTopupRequest {
 }
 
 impl UpdateTopupRequest {
-    pub fn validate(&self) -> Result<(), String> {
+    pub fn validate(&self, min_amount: i32) -> Result<(), String> {
         if self.user_id <= 0 {
             return Err("User ID must be a positive integer".to_string());
         }
@@ -55,8 +58,11 @@ impl UpdateTopupRequest {
             return Err("Top-up ID must be a positive integer".to_string());
         }
 
-        if self.topup_amount <= 50000 {
-            return Err("Topup amount must be greater than or equal to 50000".to_string());
+        if self.topup_amount < min_amount {
+            return Err(format!(
+                "Topup amount must be at least {}",
+                thousands(min_amount)
+            ));
         }
 
         if self.topup_method.is_empty() {
@@ -78,13 +84,43 @@ pub struct UpdateTopupAmount {
 }
 
 impl UpdateTopupAmount {
-    pub fn validate(&self) -> Result<(), String> {
+    pub fn validate(&self, min_amount: i32) -> Result<(), String> {
         if self.topup_id <= 0 {
             return Err("Top-up ID must be a positive integer".to_string());
         }
 
-        if self.topup_amount <= 50000 {
-            return Err("Topup amount must be greater than or equal to 50000".to_string());
+        if self.topup_amount < min_amount {
+            return Err(format!(
+                "Topup amount must be at least {}",
+                thousands(min_amount)
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+/// Body of the payment channel callback for a pending top-up.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct PaymentCallbackRequest {
+    pub payment_reference: String,
+    /// `paid`, `failed` or `expired`.
+    pub status: String,
+    pub amount: i32,
+}
+
+impl PaymentCallbackRequest {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.payment_reference.trim().is_empty() {
+            return Err("Payment reference is required".to_string());
+        }
+
+        if !["paid", "failed", "expired"].contains(&self.status.as_str()) {
+            return Err("Status must be one of paid, failed or expired".to_string());
+        }
+
+        if self.amount <= 0 {
+            return Err("Amount must be a positive integer".to_string());
         }
 
         Ok(())
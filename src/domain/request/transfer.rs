@@ -1,25 +1,50 @@
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 
+use crate::utils::{random_vcc::is_valid_account_number, rupiah::thousands};
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
 pub struct CreateTransferRequest {
     pub transfer_from: i32,
+    #[serde(default)]
     pub transfer_to: i32,
     pub transfer_amount: i32,
+    /// Saved beneficiary to pay instead of `transfer_to`.
+    #[serde(default)]
+    pub beneficiary_id: Option<i32>,
+    /// Device the request was made from, used by fraud screening.
+    #[serde(default)]
+    pub device_id: Option<String>,
+    /// Set for QRIS and payment intent payments, whose fee is charged to the
+    /// merchant at settlement instead of to the payer. Ignored from clients.
+    #[serde(default)]
+    pub merchant_payment: bool,
 }
 
 impl CreateTransferRequest {
-    pub fn validate(&self) -> Result<(), String> {
+    pub fn validate(&self, min_amount: i32) -> Result<(), String> {
         if self.transfer_from <= 0 {
             return Err("Transfer from must be a positive integer".to_string());
         }
 
-        if self.transfer_to <= 0 {
-            return Err("Transfer to must be a positive integer".to_string());
+        match self.beneficiary_id {
+            Some(beneficiary_id) if beneficiary_id <= 0 => {
+                return Err("Beneficiary ID must be a positive integer".to_string());
+            }
+            Some(_) if self.transfer_to != 0 => {
+                return Err("Provide either transfer_to or beneficiary_id, not both".to_string());
+            }
+            None if self.transfer_to <= 0 => {
+                return Err("Transfer to must be a positive integer".to_string());
+            }
+            _ => {}
         }
 
-        if self.transfer_amount < 50000 {
-            return Err("Transfer amount must be at least 50,000".to_string());
+        if self.transfer_amount < min_amount {
+            return Err(format!(
+                "Transfer amount must be at least {}",
+                thousands(min_amount)
+            ));
         }
 
         Ok(())
@@ -35,7 +60,7 @@ pub struct UpdateTransferRequest {
 }
 
 impl UpdateTransferRequest {
-    pub fn validate(&self) -> Result<(), String> {
+    pub fn validate(&self, min_amount: i32) -> Result<(), String> {
         if self.transfer_id <= 0 {
             return Err("Transfer ID must be a positive integer".to_string());
         }
@@ -48,8 +73,11 @@ impl UpdateTransferRequest {
             return Err("Transfer to must be a positive integer".to_string());
         }
 
-        if self.transfer_amount < 50000 {
-            return Err("Transfer amount must be at least 50,000".to_string());
+        if self.transfer_amount < min_amount {
+            return Err(format!(
+                "Transfer amount must be at least {}",
+                thousands(min_amount)
+            ));
         }
 
         Ok(())
@@ -63,7 +91,7 @@ pub struct UpdateTransferAmountRequest {
 }
 
 impl UpdateTransferAmountRequest {
-    pub fn validate(&self) -> Result<(), String> {
+    pub fn validate(&self, min_amount: i32) -> Result<(), String> {
         if self.transfer_id <= 0 {
             return Err("Transfer ID must be a positive integer".to_string());
         }
@@ -72,8 +100,44 @@ impl UpdateTransferAmountRequest {
             return Err("Transfer amount must be greater than zero".to_string());
         }
 
-        if self.transfer_amount < 50000 {
-            return Err("Transfer amount must be at least 50,000".to_string());
+        if self.transfer_amount < min_amount {
+            return Err(format!(
+                "Transfer amount must be at least {}",
+                thousands(min_amount)
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct CreateTransferByAccountRequest {
+    #[serde(default)]
+    pub transfer_from: i32,
+    /// Recipient account number (`noc_transfer`).
+    pub noc_transfer: String,
+    pub transfer_amount: i32,
+    /// Device the request was made from, used by fraud screening.
+    #[serde(default)]
+    pub device_id: Option<String>,
+}
+
+impl CreateTransferByAccountRequest {
+    pub fn validate(&self, min_amount: i32) -> Result<(), String> {
+        if self.transfer_from <= 0 {
+            return Err("Transfer from must be a positive integer".to_string());
+        }
+
+        if !is_valid_account_number(&self.noc_transfer) {
+            return Err("Invalid recipient account number".to_string());
+        }
+
+        if self.transfer_amount < min_amount {
+            return Err(format!(
+                "Transfer amount must be at least {}",
+                thousands(min_amount)
+            ));
         }
 
         Ok(())
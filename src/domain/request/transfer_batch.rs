@@ -0,0 +1,117 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::utils::rupiah::thousands;
+
+pub const MAX_BATCH_ITEMS: usize = 1000;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct TransferBatchItemRequest {
+    pub transfer_to: i32,
+    pub transfer_amount: i32,
+}
+
+impl TransferBatchItemRequest {
+    pub fn validate(&self, transfer_from: i32, min_amount: i32) -> Result<(), String> {
+        if self.transfer_to <= 0 {
+            return Err("Transfer to must be a positive integer".to_string());
+        }
+
+        if self.transfer_to == transfer_from {
+            return Err("Transfer to cannot be the sender".to_string());
+        }
+
+        if self.transfer_amount < min_amount {
+            return Err(format!(
+                "Transfer amount must be at least {}",
+                thousands(min_amount)
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CreateTransferBatchRequest {
+    pub transfer_from: i32,
+    pub items: Vec<TransferBatchItemRequest>,
+}
+
+impl CreateTransferBatchRequest {
+    /// Parses an uploaded CSV file with a `transfer_to,transfer_amount` header row.
+    pub fn from_csv(transfer_from: i32, data: &[u8]) -> Result<Self, String> {
+        let mut reader = csv::ReaderBuilder::new()
+            .trim(csv::Trim::All)
+            .from_reader(data);
+
+        let items = reader
+            .deserialize::<TransferBatchItemRequest>()
+            .enumerate()
+            .map(|(index, record)| record.map_err(|err| format!("Line {}: {}", index + 2, err)))
+            .collect::<Result<Vec<_>, String>>()?;
+
+        Ok(Self {
+            transfer_from,
+            items,
+        })
+    }
+
+    pub fn validate(&self, min_amount: i32) -> Result<(), String> {
+        if self.transfer_from <= 0 {
+            return Err("Transfer from must be a positive integer".to_string());
+        }
+
+        if self.items.is_empty() {
+            return Err("Batch must contain at least one transfer".to_string());
+        }
+
+        if self.items.len() > MAX_BATCH_ITEMS {
+            return Err(format!(
+                "Batch cannot contain more than {} transfers",
+                MAX_BATCH_ITEMS
+            ));
+        }
+
+        let errors: Vec<String> = self
+            .items
+            .iter()
+            .enumerate()
+            .filter_map(|(index, item)| {
+                item.validate(self.transfer_from, min_amount)
+                    .err()
+                    .map(|err| format!("Item {}: {}", index + 1, err))
+            })
+            .collect();
+
+        if !errors.is_empty() {
+            return Err(errors.join("; "));
+        }
+
+        Ok(())
+    }
+
+    pub fn total_amount(&self) -> i64 {
+        self.items
+            .iter()
+            .map(|item| item.transfer_amount as i64)
+            .sum()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UpdateTransferBatchItemResult {
+    pub item_id: i32,
+    pub status: String,
+    pub transfer_id: Option<i32>,
+    pub error_message: Option<String>,
+    pub fraud_review_id: Option<i32>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UpdateTransferBatchStatus {
+    pub batch_id: i32,
+    pub status: String,
+    pub succeeded_items: i32,
+    pub failed_items: i32,
+}
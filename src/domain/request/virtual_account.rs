@@ -0,0 +1,83 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::utils::payment_method_validator::virtual_account_bank_validator;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct CreateVirtualAccountRequest {
+    #[serde(default)]
+    pub user_id: i32,
+    pub bank_code: String,
+}
+
+impl CreateVirtualAccountRequest {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.user_id <= 0 {
+            return Err("User ID must be a positive integer".to_string());
+        }
+
+        if !virtual_account_bank_validator(&self.bank_code) {
+            return Err("Virtual accounts are only available for bca, mandiri and bni".to_string());
+        }
+
+        Ok(())
+    }
+}
+
+/// Body of the bank's "VA payment received" webhook.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct VirtualAccountPaymentRequest {
+    /// The bank's unique id for the incoming transfer.
+    pub payment_id: String,
+    pub bank_code: String,
+    pub va_number: String,
+    pub amount: i32,
+}
+
+impl VirtualAccountPaymentRequest {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.payment_id.trim().is_empty() {
+            return Err("Payment ID is required".to_string());
+        }
+
+        if self.payment_id.len() > 100 {
+            return Err("Payment ID cannot be longer than 100 characters".to_string());
+        }
+
+        if self.bank_code.trim().is_empty() {
+            return Err("Bank code is required".to_string());
+        }
+
+        if self.va_number.trim().is_empty() {
+            return Err("Virtual account number is required".to_string());
+        }
+
+        if self.amount <= 0 {
+            return Err("Amount must be a positive integer".to_string());
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct ResolveSuspensePaymentRequest {
+    #[serde(default)]
+    pub suspense_payment_id: i32,
+    /// The user the payment is credited to.
+    pub user_id: i32,
+}
+
+impl ResolveSuspensePaymentRequest {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.suspense_payment_id <= 0 {
+            return Err("Suspense payment ID must be a positive integer".to_string());
+        }
+
+        if self.user_id <= 0 {
+            return Err("User ID must be a positive integer".to_string());
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,28 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct CreateWebhookEndpointRequest {
+    /// The merchant's owning user; set from the token.
+    #[serde(default)]
+    pub user_id: i32,
+    pub url: String,
+}
+
+impl CreateWebhookEndpointRequest {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.user_id <= 0 {
+            return Err("User ID must be a positive integer".to_string());
+        }
+
+        if !(self.url.starts_with("https://") || self.url.starts_with("http://")) {
+            return Err("Webhook URL must be an http or https URL".to_string());
+        }
+
+        if self.url.len() > 255 {
+            return Err("Webhook URL cannot be longer than 255 characters".to_string());
+        }
+
+        Ok(())
+    }
+}
@@ -2,21 +2,34 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 
+use crate::utils::rupiah::thousands;
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
 pub struct CreateWithdrawRequest {
     pub user_id: i32,
+    pub bank_account_id: i32,
     pub withdraw_amount: i32,
     pub withdraw_time: DateTime<Utc>,
+    /// Device the request was made from, used by fraud screening.
+    #[serde(default)]
+    pub device_id: Option<String>,
 }
 
 impl CreateWithdrawRequest {
-    pub fn validate(&self) -> Result<(), String> {
+    pub fn validate(&self, min_amount: i32) -> Result<(), String> {
         if self.user_id <= 0 {
             return Err("User ID must be positive".to_string());
         }
 
-        if self.withdraw_amount <= 50000 {
-            return Err("Withdraw amount must be at least 50,000".to_string());
+        if self.bank_account_id <= 0 {
+            return Err("Bank account ID must be positive".to_string());
+        }
+
+        if self.withdraw_amount < min_amount {
+            return Err(format!(
+                "Withdraw amount must be at least {}",
+                thousands(min_amount)
+            ));
         }
 
         if self.withdraw_time > Utc::now() {
@@ -27,6 +40,14 @@ impl CreateWithdrawRequest {
     }
 }
 
+#[derive(Debug, Clone, PartialEq)]
+pub struct UpdateWithdrawStatus {
+    pub withdraw_id: i32,
+    pub status: String,
+    pub provider_reference: Option<String>,
+    pub failure_reason: Option<String>,
+}
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
 pub struct UpdateWithdrawRequest {
     pub user_id: i32,
@@ -36,7 +57,7 @@ pub struct UpdateWithdrawRequest {
 }
 
 impl UpdateWithdrawRequest {
-    pub fn validate(&self) -> Result<(), String> {
+    pub fn validate(&self, min_amount: i32) -> Result<(), String> {
         if self.user_id <= 0 {
             return Err("User ID must be positive".to_string());
         }
@@ -45,8 +66,11 @@ impl UpdateWithdrawRequest {
             return Err("Withdraw ID must be positive".to_string());
         }
 
-        if self.withdraw_amount <= 50000 {
-            return Err("Withdraw amount must be at least 50,000".to_string());
+        if self.withdraw_amount < min_amount {
+            return Err(format!(
+                "Withdraw amount must be at least {}",
+                thousands(min_amount)
+            ));
         }
 
         if self.withdraw_time > Utc::now() {
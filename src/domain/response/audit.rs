@@ -0,0 +1,61 @@
+use chrono::{DateTime, TimeZone, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::entities::audit_events;
+
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+pub struct AuditEventResponse {
+    pub audit_event_id: i32,
+    pub actor_id: Option<i32>,
+    /// Method and route, e.g. `DELETE /api/users/{id}`.
+    pub action: String,
+    pub resource_type: String,
+    pub resource_id: Option<String>,
+    /// The resource before the action, when it could be loaded.
+    #[schema(value_type = Option<Object>)]
+    pub before: Option<serde_json::Value>,
+    /// The resource after the action, empty once deleted.
+    #[schema(value_type = Option<Object>)]
+    pub after: Option<serde_json::Value>,
+    pub client_ip: Option<String>,
+    pub request_id: String,
+    #[schema(format = "date-time")]
+    pub created_at: DateTime<Utc>,
+    /// Hash of the previous event in the chain.
+    pub prev_hash: String,
+    pub hash: String,
+}
+
+fn parse_snapshot(value: Option<String>) -> Option<serde_json::Value> {
+    value.map(|value| serde_json::from_str(&value).unwrap_or(serde_json::Value::String(value)))
+}
+
+impl From<audit_events::Model> for AuditEventResponse {
+    fn from(value: audit_events::Model) -> Self {
+        AuditEventResponse {
+            audit_event_id: value.audit_event_id,
+            actor_id: value.actor_id,
+            action: value.action,
+            resource_type: value.resource_type,
+            resource_id: value.resource_id,
+            before: parse_snapshot(value.before),
+            after: parse_snapshot(value.after),
+            client_ip: value.client_ip,
+            request_id: value.request_id,
+            created_at: Utc.from_utc_datetime(&value.created_at),
+            prev_hash: value.prev_hash,
+            hash: value.hash,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+pub struct AuditChainVerificationResponse {
+    pub events_checked: u64,
+    pub valid: bool,
+    /// First event whose hash or link does not match, when the chain is
+    /// broken.
+    pub first_invalid_event_id: Option<i32>,
+    pub reason: Option<String>,
+}
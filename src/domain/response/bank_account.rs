@@ -0,0 +1,31 @@
+use chrono::{DateTime, TimeZone, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::entities::bank_accounts;
+
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+pub struct BankAccountResponse {
+    pub bank_account_id: i32,
+    pub bank_code: String,
+    pub account_number: String,
+    pub holder_name: String,
+    #[schema(format = "date-time")]
+    pub created_at: Option<DateTime<Utc>>,
+
+    #[schema(format = "date-time")]
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+impl From<bank_accounts::Model> for BankAccountResponse {
+    fn from(value: bank_accounts::Model) -> Self {
+        BankAccountResponse {
+            bank_account_id: value.bank_account_id,
+            bank_code: value.bank_code,
+            account_number: value.account_number,
+            holder_name: value.holder_name,
+            created_at: value.created_at.map(|dt| Utc.from_utc_datetime(&dt)),
+            updated_at: value.updated_at.map(|dt| Utc.from_utc_datetime(&dt)),
+        }
+    }
+}
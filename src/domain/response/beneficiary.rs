@@ -0,0 +1,37 @@
+use chrono::{DateTime, TimeZone, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::entities::beneficiaries;
+
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+pub struct BeneficiaryResponse {
+    pub beneficiary_id: i32,
+    pub alias: String,
+    pub beneficiary_type: String,
+    pub noc_transfer: Option<String>,
+    pub bank_code: Option<String>,
+    pub bank_account_number: Option<String>,
+    pub verified: bool,
+    #[schema(format = "date-time")]
+    pub created_at: Option<DateTime<Utc>>,
+
+    #[schema(format = "date-time")]
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+impl From<beneficiaries::Model> for BeneficiaryResponse {
+    fn from(value: beneficiaries::Model) -> Self {
+        BeneficiaryResponse {
+            beneficiary_id: value.beneficiary_id,
+            alias: value.alias,
+            beneficiary_type: value.beneficiary_type,
+            noc_transfer: value.noc_transfer,
+            bank_code: value.bank_code,
+            bank_account_number: value.bank_account_number,
+            verified: value.verified,
+            created_at: value.created_at.map(|dt| Utc.from_utc_datetime(&dt)),
+            updated_at: value.updated_at.map(|dt| Utc.from_utc_datetime(&dt)),
+        }
+    }
+}
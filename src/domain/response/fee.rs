@@ -0,0 +1,70 @@
+use chrono::{DateTime, TimeZone, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::entities::fee_rules;
+
+/// One band of a tiered fee rule. Tiers are checked in order and the first
+/// one whose `up_to` covers the amount applies; the last tier usually has
+/// no `up_to`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, ToSchema)]
+pub struct FeeTier {
+    pub up_to: Option<i32>,
+    #[serde(default)]
+    pub flat_amount: i32,
+    #[serde(default)]
+    pub percentage_bps: i32,
+}
+
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+pub struct FeeRuleResponse {
+    pub fee_rule_id: i32,
+    pub transaction_type: String,
+    /// `None` applies to every method.
+    pub method: Option<String>,
+    /// `None` applies to every merchant.
+    pub merchant_id: Option<i32>,
+    /// `flat`, `percentage` or `tiered`.
+    pub fee_type: String,
+    pub flat_amount: i32,
+    /// Percentage in basis points, so 70 is 0.7%.
+    pub percentage_bps: i32,
+    pub tiers: Option<Vec<FeeTier>>,
+    pub min_fee: Option<i32>,
+    pub max_fee: Option<i32>,
+    #[schema(format = "date-time")]
+    pub created_at: Option<DateTime<Utc>>,
+
+    #[schema(format = "date-time")]
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+impl From<fee_rules::Model> for FeeRuleResponse {
+    fn from(value: fee_rules::Model) -> Self {
+        FeeRuleResponse {
+            fee_rule_id: value.fee_rule_id,
+            transaction_type: value.transaction_type,
+            method: value.method,
+            merchant_id: value.merchant_id,
+            fee_type: value.fee_type,
+            flat_amount: value.flat_amount,
+            percentage_bps: value.percentage_bps,
+            tiers: value
+                .tiers
+                .and_then(|tiers| serde_json::from_str(&tiers).ok()),
+            min_fee: value.min_fee,
+            max_fee: value.max_fee,
+            created_at: value.created_at.map(|dt| Utc.from_utc_datetime(&dt)),
+            updated_at: value.updated_at.map(|dt| Utc.from_utc_datetime(&dt)),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+pub struct FeeQuoteResponse {
+    pub transaction_type: String,
+    pub amount: i32,
+    pub fee_amount: i32,
+    /// The rule that produced the fee, `None` when no rule applies.
+    pub fee_rule_id: Option<i32>,
+}
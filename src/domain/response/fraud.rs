@@ -0,0 +1,96 @@
+use chrono::{DateTime, TimeZone, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::entities::{fraud_reviews, fraud_rules};
+
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+pub struct FraudRuleResponse {
+    pub fraud_rule_id: i32,
+    pub name: String,
+    /// `velocity`, `amount_anomaly`, `new_device_beneficiary` or `round_trip`.
+    pub rule_type: String,
+    /// Transaction type the rule applies to, every type when empty.
+    pub transaction_type: Option<String>,
+    pub threshold: i32,
+    pub window_seconds: Option<i32>,
+    /// `review` or `deny`.
+    pub action: String,
+    pub is_active: bool,
+    #[schema(format = "date-time")]
+    pub created_at: Option<DateTime<Utc>>,
+
+    #[schema(format = "date-time")]
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+impl From<fraud_rules::Model> for FraudRuleResponse {
+    fn from(value: fraud_rules::Model) -> Self {
+        FraudRuleResponse {
+            fraud_rule_id: value.fraud_rule_id,
+            name: value.name,
+            rule_type: value.rule_type,
+            transaction_type: value.transaction_type,
+            threshold: value.threshold,
+            window_seconds: value.window_seconds,
+            action: value.action,
+            is_active: value.is_active,
+            created_at: value.created_at.map(|dt| Utc.from_utc_datetime(&dt)),
+            updated_at: value.updated_at.map(|dt| Utc.from_utc_datetime(&dt)),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+pub struct FraudReviewResponse {
+    pub fraud_review_id: i32,
+    pub user_id: i32,
+    pub transaction_type: String,
+    pub amount: i32,
+    pub counterparty_id: Option<i32>,
+    /// The held request, carried out as is on approval.
+    #[schema(value_type = Object)]
+    pub payload: serde_json::Value,
+    /// Names of the rules that held the transaction.
+    pub triggered_rules: Vec<String>,
+    /// `pending`, `approved`, `declined` or `failed`.
+    pub status: String,
+    /// Topup, transfer or withdraw created on approval.
+    pub transaction_id: Option<i32>,
+    pub reviewer_id: Option<i32>,
+    pub review_note: Option<String>,
+    #[schema(format = "date-time")]
+    pub reviewed_at: Option<DateTime<Utc>>,
+    #[schema(format = "date-time")]
+    pub created_at: Option<DateTime<Utc>>,
+
+    #[schema(format = "date-time")]
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+impl From<fraud_reviews::Model> for FraudReviewResponse {
+    fn from(value: fraud_reviews::Model) -> Self {
+        FraudReviewResponse {
+            fraud_review_id: value.fraud_review_id,
+            user_id: value.user_id,
+            transaction_type: value.transaction_type,
+            amount: value.amount,
+            counterparty_id: value.counterparty_id,
+            payload: serde_json::from_str(&value.payload)
+                .unwrap_or(serde_json::Value::String(value.payload)),
+            triggered_rules: value
+                .triggered_rules
+                .split(',')
+                .filter(|name| !name.is_empty())
+                .map(str::to_string)
+                .collect(),
+            status: value.status,
+            transaction_id: value.transaction_id,
+            reviewer_id: value.reviewer_id,
+            review_note: value.review_note,
+            reviewed_at: value.reviewed_at.map(|dt| Utc.from_utc_datetime(&dt)),
+            created_at: value.created_at.map(|dt| Utc.from_utc_datetime(&dt)),
+            updated_at: value.updated_at.map(|dt| Utc.from_utc_datetime(&dt)),
+        }
+    }
+}
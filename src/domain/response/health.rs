@@ -0,0 +1,73 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, ToSchema)]
+pub struct ComponentHealth {
+    /// `up` or `down`.
+    pub status: String,
+    /// Whether the service is unavailable while this component is down.
+    pub critical: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub detail: Option<String>,
+}
+
+impl ComponentHealth {
+    pub fn up(critical: bool, detail: Option<String>) -> Self {
+        Self {
+            status: "up".to_string(),
+            critical,
+            detail,
+        }
+    }
+
+    pub fn down(critical: bool, detail: String) -> Self {
+        Self {
+            status: "down".to_string(),
+            critical,
+            detail: Some(detail),
+        }
+    }
+
+    pub fn is_up(&self) -> bool {
+        self.status == "up"
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, ToSchema)]
+pub struct ReadinessResponse {
+    /// `ready`, `degraded` when only non-critical components are down, or
+    /// `unavailable`.
+    pub status: String,
+    pub components: BTreeMap<String, ComponentHealth>,
+}
+
+impl ReadinessResponse {
+    pub fn new(components: BTreeMap<String, ComponentHealth>) -> Self {
+        let status = if components.values().all(ComponentHealth::is_up) {
+            "ready"
+        } else if components
+            .values()
+            .any(|component| component.critical && !component.is_up())
+        {
+            "unavailable"
+        } else {
+            "degraded"
+        };
+
+        Self {
+            status: status.to_string(),
+            components,
+        }
+    }
+
+    pub fn is_available(&self) -> bool {
+        self.status != "unavailable"
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+pub struct LivenessResponse {
+    pub status: String,
+}
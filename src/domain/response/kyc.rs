@@ -0,0 +1,47 @@
+use chrono::{DateTime, TimeZone, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::entities::kyc_submissions;
+
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+pub struct KycSubmissionResponse {
+    pub kyc_submission_id: i32,
+    pub user_id: i32,
+    /// KYC level the user is raised to on approval.
+    pub requested_level: i32,
+    /// `submitted`, `in_review`, `approved` or `rejected`.
+    pub status: String,
+    pub reviewer_id: Option<i32>,
+    pub review_note: Option<String>,
+    #[schema(format = "date-time")]
+    pub reviewed_at: Option<DateTime<Utc>>,
+    #[schema(format = "date-time")]
+    pub created_at: Option<DateTime<Utc>>,
+
+    #[schema(format = "date-time")]
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+impl From<kyc_submissions::Model> for KycSubmissionResponse {
+    fn from(value: kyc_submissions::Model) -> Self {
+        KycSubmissionResponse {
+            kyc_submission_id: value.kyc_submission_id,
+            user_id: value.user_id,
+            requested_level: value.requested_level,
+            status: value.status,
+            reviewer_id: value.reviewer_id,
+            review_note: value.review_note,
+            reviewed_at: value.reviewed_at.map(|dt| Utc.from_utc_datetime(&dt)),
+            created_at: value.created_at.map(|dt| Utc.from_utc_datetime(&dt)),
+            updated_at: value.updated_at.map(|dt| Utc.from_utc_datetime(&dt)),
+        }
+    }
+}
+
+/// A stored document, returned to reviewers as a file download.
+#[derive(Debug, Clone, PartialEq)]
+pub struct KycDocumentFile {
+    pub content_type: String,
+    pub bytes: Vec<u8>,
+}
@@ -0,0 +1,48 @@
+use chrono::{DateTime, TimeZone, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::entities::transaction_limits;
+
+/// Limits of one transaction type for a KYC tier. `None` means no limit.
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+pub struct TransactionLimitResponse {
+    pub transaction_limit_id: i32,
+    pub kyc_level: i32,
+    pub transaction_type: String,
+    /// Highest saldo the transaction may leave the receiving wallet with.
+    pub max_balance: Option<i32>,
+    pub max_transaction_amount: Option<i32>,
+    /// Cumulative amount allowed since midnight.
+    pub daily_amount: Option<i32>,
+    /// Cumulative amount allowed since the first of the month.
+    pub monthly_amount: Option<i32>,
+    #[schema(format = "date-time")]
+    pub created_at: Option<DateTime<Utc>>,
+
+    #[schema(format = "date-time")]
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+impl From<transaction_limits::Model> for TransactionLimitResponse {
+    fn from(value: transaction_limits::Model) -> Self {
+        TransactionLimitResponse {
+            transaction_limit_id: value.transaction_limit_id,
+            kyc_level: value.kyc_level,
+            transaction_type: value.transaction_type,
+            max_balance: value.max_balance,
+            max_transaction_amount: value.max_transaction_amount,
+            daily_amount: value.daily_amount,
+            monthly_amount: value.monthly_amount,
+            created_at: value.created_at.map(|dt| Utc.from_utc_datetime(&dt)),
+            updated_at: value.updated_at.map(|dt| Utc.from_utc_datetime(&dt)),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+pub struct UserLimitsResponse {
+    pub user_id: i32,
+    pub kyc_level: i32,
+    pub limits: Vec<TransactionLimitResponse>,
+}
@@ -0,0 +1,29 @@
+use chrono::{DateTime, TimeZone, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::entities::merchants;
+
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+pub struct MerchantResponse {
+    pub merchant_id: i32,
+    pub user_id: i32,
+    pub name: String,
+    #[schema(format = "date-time")]
+    pub created_at: Option<DateTime<Utc>>,
+
+    #[schema(format = "date-time")]
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+impl From<merchants::Model> for MerchantResponse {
+    fn from(value: merchants::Model) -> Self {
+        MerchantResponse {
+            merchant_id: value.merchant_id,
+            user_id: value.user_id,
+            name: value.name,
+            created_at: value.created_at.map(|dt| Utc.from_utc_datetime(&dt)),
+            updated_at: value.updated_at.map(|dt| Utc.from_utc_datetime(&dt)),
+        }
+    }
+}
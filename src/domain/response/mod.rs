@@ -3,12 +3,30 @@ use core::fmt;
 use serde::Serialize;
 use utoipa::ToSchema;
 
-use crate::utils::errors::AppError;
+use crate::{middleware::request_id::current_request_id, utils::errors::AppError};
 
+pub mod audit;
+pub mod bank_account;
+pub mod beneficiary;
+pub mod fee;
+pub mod fraud;
+pub mod health;
+pub mod kyc;
+pub mod limit;
+pub mod merchant;
+pub mod payment_intent;
+pub mod qris;
+pub mod refund;
 pub mod saldo;
+pub mod scheduled_transfer;
+pub mod screening;
+pub mod settlement;
 pub mod topup;
 pub mod transfer;
+pub mod transfer_batch;
 pub mod user;
+pub mod virtual_account;
+pub mod webhook;
 pub mod withdraw;
 
 #[derive(Debug, Serialize, ToSchema)]
@@ -28,14 +46,34 @@ impl<T: std::fmt::Debug> fmt::Display for ApiResponse<T> {
         )
     }
 }
+/// Failures callers react to, so they don't have to match on messages.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorKind {
+    InsufficientBalance,
+    /// Carries the id of the fraud review holding the transaction.
+    HeldForReview(i32),
+    Other,
+}
+
 #[derive(Debug, Serialize, ToSchema)]
 pub struct ErrorResponse {
     pub status: String,
     pub message: String,
+    /// Id of the request that failed, also sent as `X-Request-Id`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request_id: Option<String>,
+    #[serde(skip)]
+    pub kind: ErrorKind,
 }
 
 impl From<AppError> for ErrorResponse {
     fn from(error: AppError) -> Self {
+        let kind = match error {
+            AppError::InsufficientBalance => ErrorKind::InsufficientBalance,
+            AppError::HeldForReview(review_id, _) => ErrorKind::HeldForReview(review_id),
+            _ => ErrorKind::Other,
+        };
+
         let (status, message) = match error {
             AppError::DbError(_) => ("error".to_string(), "Database error occurred".to_string()),
             AppError::PasswordError(ref msg) => ("error".to_string(), msg.clone()),
@@ -63,8 +101,40 @@ impl From<AppError> for ErrorResponse {
             AppError::EmailAlreadyExists => {
                 ("error".to_string(), "Email already exists".to_string())
             }
+            AppError::InsufficientBalance => {
+                ("error".to_string(), "Insufficient balance".to_string())
+            }
+            AppError::PayoutProviderError(ref msg) => {
+                ("error".to_string(), format!("Payout provider error: {}", msg))
+            }
+            AppError::PaymentChannelError(ref msg) => {
+                ("error".to_string(), format!("Payment channel error: {}", msg))
+            }
+            AppError::InvalidSignature => {
+                ("error".to_string(), "Invalid callback signature".to_string())
+            }
+            AppError::WebhookDeliveryError(ref msg) => {
+                ("error".to_string(), format!("Webhook delivery error: {}", msg))
+            }
+            AppError::FeeRuleError(ref msg) => {
+                ("error".to_string(), format!("Invalid fee rule: {}", msg))
+            }
+            AppError::LimitExceeded(ref msg) => ("error".to_string(), msg.clone()),
+            AppError::BlobStoreError(ref msg) => {
+                ("error".to_string(), format!("Blob store error: {}", msg))
+            }
+            AppError::FraudDenied(ref msg) => ("error".to_string(), msg.clone()),
+            AppError::HeldForReview(_, ref msg) => ("pending".to_string(), msg.clone()),
+            AppError::WatchlistError(ref msg) => {
+                ("error".to_string(), format!("Watchlist error: {}", msg))
+            }
         };
-        ErrorResponse { status, message }
+        ErrorResponse {
+            status,
+            message,
+            request_id: current_request_id(),
+            kind,
+        }
     }
 }
 
@@ -0,0 +1,54 @@
+use chrono::{DateTime, TimeZone, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::entities::payment_intents;
+
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+pub struct PaymentIntentResponse {
+    pub payment_intent_id: i32,
+    pub merchant_id: i32,
+    pub amount: i32,
+    pub currency: String,
+    pub reference: String,
+    /// `requires_confirmation`, `processing` while the customer's transfer
+    /// runs or is held for fraud review, then `succeeded`, `canceled` or
+    /// `expired`.
+    pub status: String,
+    #[schema(format = "date-time")]
+    pub expires_at: DateTime<Utc>,
+    pub customer_id: Option<i32>,
+    pub transfer_id: Option<i32>,
+    #[schema(format = "date-time")]
+    pub succeeded_at: Option<DateTime<Utc>>,
+    /// Sum of refunds that succeeded or are still in flight.
+    pub amount_refunded: i32,
+    /// The fraud review holding the customer's transfer, if any.
+    pub fraud_review_id: Option<i32>,
+    #[schema(format = "date-time")]
+    pub created_at: Option<DateTime<Utc>>,
+
+    #[schema(format = "date-time")]
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+impl From<payment_intents::Model> for PaymentIntentResponse {
+    fn from(value: payment_intents::Model) -> Self {
+        PaymentIntentResponse {
+            payment_intent_id: value.payment_intent_id,
+            merchant_id: value.merchant_id,
+            amount: value.amount,
+            currency: value.currency,
+            reference: value.reference,
+            status: value.status,
+            expires_at: Utc.from_utc_datetime(&value.expires_at),
+            customer_id: value.customer_id,
+            transfer_id: value.transfer_id,
+            succeeded_at: value.succeeded_at.map(|dt| Utc.from_utc_datetime(&dt)),
+            amount_refunded: value.amount_refunded,
+            fraud_review_id: value.fraud_review_id,
+            created_at: value.created_at.map(|dt| Utc.from_utc_datetime(&dt)),
+            updated_at: value.updated_at.map(|dt| Utc.from_utc_datetime(&dt)),
+        }
+    }
+}
@@ -0,0 +1,55 @@
+use chrono::{DateTime, TimeZone, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::entities::qris_payments;
+
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+pub struct QrisResponse {
+    pub qris_payment_id: i32,
+    pub merchant_id: Option<i32>,
+    pub reference: String,
+    pub amount: i32,
+    /// EMVCo payload to encode in the QR code.
+    pub payload: String,
+    /// `pending`, `processing` while the transfer runs or is held for
+    /// fraud review, then `paid`.
+    pub status: String,
+    pub payer_id: Option<i32>,
+    pub transfer_id: Option<i32>,
+    #[schema(format = "date-time")]
+    pub paid_at: Option<DateTime<Utc>>,
+    /// The fraud review holding the payer's transfer, if any.
+    pub fraud_review_id: Option<i32>,
+    #[schema(format = "date-time")]
+    pub created_at: Option<DateTime<Utc>>,
+
+    #[schema(format = "date-time")]
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+impl From<qris_payments::Model> for QrisResponse {
+    fn from(value: qris_payments::Model) -> Self {
+        QrisResponse {
+            qris_payment_id: value.qris_payment_id,
+            merchant_id: value.merchant_id,
+            reference: value.reference,
+            amount: value.amount,
+            payload: value.payload,
+            status: value.status,
+            payer_id: value.payer_id,
+            transfer_id: value.transfer_id,
+            paid_at: value.paid_at.map(|dt| Utc.from_utc_datetime(&dt)),
+            fraud_review_id: value.fraud_review_id,
+            created_at: value.created_at.map(|dt| Utc.from_utc_datetime(&dt)),
+            updated_at: value.updated_at.map(|dt| Utc.from_utc_datetime(&dt)),
+        }
+    }
+}
+
+/// A rendered QR code and the content type to serve it with.
+#[derive(Debug, Clone, PartialEq)]
+pub struct QrisImage {
+    pub content_type: String,
+    pub body: Vec<u8>,
+}
@@ -0,0 +1,39 @@
+use chrono::{DateTime, TimeZone, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::entities::refunds;
+
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+pub struct RefundResponse {
+    pub refund_id: i32,
+    pub payment_intent_id: i32,
+    pub amount: i32,
+    pub reason: String,
+    /// `pending` while the money moves, then `succeeded` or `failed`.
+    pub status: String,
+    pub failure_reason: Option<String>,
+    #[schema(format = "date-time")]
+    pub refunded_at: Option<DateTime<Utc>>,
+    #[schema(format = "date-time")]
+    pub created_at: Option<DateTime<Utc>>,
+
+    #[schema(format = "date-time")]
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+impl From<refunds::Model> for RefundResponse {
+    fn from(value: refunds::Model) -> Self {
+        RefundResponse {
+            refund_id: value.refund_id,
+            payment_intent_id: value.payment_intent_id,
+            amount: value.amount,
+            reason: value.reason,
+            status: value.status,
+            failure_reason: value.failure_reason,
+            refunded_at: value.refunded_at.map(|dt| Utc.from_utc_datetime(&dt)),
+            created_at: value.created_at.map(|dt| Utc.from_utc_datetime(&dt)),
+            updated_at: value.updated_at.map(|dt| Utc.from_utc_datetime(&dt)),
+        }
+    }
+}
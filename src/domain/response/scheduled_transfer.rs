@@ -0,0 +1,77 @@
+use chrono::{DateTime, TimeZone, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::entities::{scheduled_transfer_attempts, scheduled_transfers};
+
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+pub struct ScheduledTransferResponse {
+    pub schedule_id: i32,
+    pub transfer_from: i32,
+    pub transfer_to: i32,
+    pub transfer_amount: i32,
+    pub frequency: String,
+    pub day_of_month: Option<i32>,
+    pub start_at: DateTime<Utc>,
+    pub next_run_at: DateTime<Utc>,
+    pub status: String,
+    pub retry_count: i32,
+    #[schema(format = "date-time")]
+    pub last_run_at: Option<DateTime<Utc>>,
+    #[schema(format = "date-time")]
+    pub created_at: Option<DateTime<Utc>>,
+
+    #[schema(format = "date-time")]
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+impl From<scheduled_transfers::Model> for ScheduledTransferResponse {
+    fn from(value: scheduled_transfers::Model) -> Self {
+        ScheduledTransferResponse {
+            schedule_id: value.schedule_id,
+            transfer_from: value.transfer_from,
+            transfer_to: value.transfer_to,
+            transfer_amount: value.transfer_amount,
+            frequency: value.frequency,
+            day_of_month: value.day_of_month,
+            start_at: Utc.from_utc_datetime(&value.start_at),
+            next_run_at: Utc.from_utc_datetime(&value.next_run_at),
+            status: value.status,
+            retry_count: value.retry_count,
+            last_run_at: value.last_run_at.map(|dt| Utc.from_utc_datetime(&dt)),
+            created_at: value.created_at.map(|dt| Utc.from_utc_datetime(&dt)),
+            updated_at: value.updated_at.map(|dt| Utc.from_utc_datetime(&dt)),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+pub struct ScheduledTransferAttemptResponse {
+    pub attempt_id: i32,
+    /// `succeeded`, `retry_scheduled`, `failed`, or `held` while the
+    /// transfer waits for fraud review.
+    pub status: String,
+    pub transfer_id: Option<i32>,
+    pub error_message: Option<String>,
+    pub attempted_at: DateTime<Utc>,
+    pub fraud_review_id: Option<i32>,
+}
+
+impl From<scheduled_transfer_attempts::Model> for ScheduledTransferAttemptResponse {
+    fn from(value: scheduled_transfer_attempts::Model) -> Self {
+        ScheduledTransferAttemptResponse {
+            attempt_id: value.attempt_id,
+            status: value.status,
+            transfer_id: value.transfer_id,
+            error_message: value.error_message,
+            attempted_at: Utc.from_utc_datetime(&value.attempted_at),
+            fraud_review_id: value.fraud_review_id,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+pub struct ScheduledTransferDetailResponse {
+    pub schedule: ScheduledTransferResponse,
+    pub attempts: Vec<ScheduledTransferAttemptResponse>,
+}
@@ -0,0 +1,62 @@
+use chrono::{DateTime, TimeZone, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::entities::screening_results;
+
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+pub struct ScreeningResultResponse {
+    pub screening_result_id: i32,
+    /// `user` or `beneficiary`.
+    pub subject_type: String,
+    pub subject_id: i32,
+    pub user_id: i32,
+    pub screened_name: String,
+    /// Identifier of the entry on its source list.
+    pub watchlist_entry_id: String,
+    /// Source list of the entry.
+    pub watchlist_name: String,
+    /// Name or alias of the entry that matched.
+    pub matched_name: String,
+    /// Similarity from 0 to 100.
+    pub score: i32,
+    /// `pending`, `cleared` or `confirmed`.
+    pub status: String,
+    pub reviewer_id: Option<i32>,
+    pub review_note: Option<String>,
+    #[schema(format = "date-time")]
+    pub reviewed_at: Option<DateTime<Utc>>,
+    #[schema(format = "date-time")]
+    pub created_at: Option<DateTime<Utc>>,
+
+    #[schema(format = "date-time")]
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+impl From<screening_results::Model> for ScreeningResultResponse {
+    fn from(value: screening_results::Model) -> Self {
+        ScreeningResultResponse {
+            screening_result_id: value.screening_result_id,
+            subject_type: value.subject_type,
+            subject_id: value.subject_id,
+            user_id: value.user_id,
+            screened_name: value.screened_name,
+            watchlist_entry_id: value.watchlist_entry_id,
+            watchlist_name: value.watchlist_name,
+            matched_name: value.matched_name,
+            score: value.score,
+            status: value.status,
+            reviewer_id: value.reviewer_id,
+            review_note: value.review_note,
+            reviewed_at: value.reviewed_at.map(|dt| Utc.from_utc_datetime(&dt)),
+            created_at: value.created_at.map(|dt| Utc.from_utc_datetime(&dt)),
+            updated_at: value.updated_at.map(|dt| Utc.from_utc_datetime(&dt)),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+pub struct WatchlistStatusResponse {
+    /// Entries in the list now used for screening.
+    pub entries: i32,
+}
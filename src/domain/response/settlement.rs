@@ -0,0 +1,58 @@
+use chrono::{DateTime, TimeZone, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::entities::settlements;
+
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+pub struct SettlementResponse {
+    pub settlement_id: i32,
+    pub merchant_id: i32,
+    #[schema(format = "date-time")]
+    pub period_start: DateTime<Utc>,
+    #[schema(format = "date-time")]
+    pub period_end: DateTime<Utc>,
+    pub payment_count: i32,
+    pub gross_amount: i32,
+    pub refunded_amount: i32,
+    pub fee_amount: i32,
+    /// `gross_amount - refunded_amount - fee_amount`, the amount paid out.
+    pub net_amount: i32,
+    /// `paid_out` once the payout withdrawal is created, `held` when the
+    /// net amount stays in the merchant's saldo, `in_review` while the
+    /// payout is held for fraud review, `declined` when the review rejects
+    /// it, or `failed`. Held and failed settlements are retried by the next
+    /// daily run.
+    pub status: String,
+    pub withdraw_id: Option<i32>,
+    pub failure_reason: Option<String>,
+    /// Fraud review deciding the payout while the settlement is `in_review`.
+    pub fraud_review_id: Option<i32>,
+    #[schema(format = "date-time")]
+    pub created_at: Option<DateTime<Utc>>,
+
+    #[schema(format = "date-time")]
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+impl From<settlements::Model> for SettlementResponse {
+    fn from(value: settlements::Model) -> Self {
+        SettlementResponse {
+            settlement_id: value.settlement_id,
+            merchant_id: value.merchant_id,
+            period_start: Utc.from_utc_datetime(&value.period_start),
+            period_end: Utc.from_utc_datetime(&value.period_end),
+            payment_count: value.payment_count,
+            gross_amount: value.gross_amount,
+            refunded_amount: value.refunded_amount,
+            fee_amount: value.fee_amount,
+            net_amount: value.net_amount,
+            status: value.status,
+            withdraw_id: value.withdraw_id,
+            failure_reason: value.failure_reason,
+            fraud_review_id: value.fraud_review_id,
+            created_at: value.created_at.map(|dt| Utc.from_utc_datetime(&dt)),
+            updated_at: value.updated_at.map(|dt| Utc.from_utc_datetime(&dt)),
+        }
+    }
+}
@@ -4,6 +4,22 @@ use utoipa::ToSchema;
 
 use crate::entities::topups;
 
+/// How the customer completes a pending top-up, as returned by the payment channel.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, ToSchema)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum PaymentInstructions {
+    VirtualAccount {
+        bank_code: String,
+        account_number: String,
+    },
+    Qr {
+        payload: String,
+    },
+    Redirect {
+        url: String,
+    },
+}
+
 #[derive(Debug, Deserialize, Serialize, ToSchema)]
 pub struct TopupResponse {
     pub topup_id: i32,
@@ -12,6 +28,15 @@ pub struct TopupResponse {
     pub topup_amount: i32,
     pub topup_method: String,
     pub topup_time: DateTime<Utc>,
+    /// `pending` until the payment channel confirms it as `paid`, `failed` or `expired`.
+    /// A paid top-up an admin took back is `reversed`.
+    pub status: String,
+    pub payment_reference: Option<String>,
+    pub payment_instructions: Option<PaymentInstructions>,
+    #[schema(format = "date-time")]
+    pub paid_at: Option<DateTime<Utc>>,
+    /// Fee deducted from `topup_amount` when the top-up is credited.
+    pub fee_amount: i32,
     #[schema(format = "date-time")]
     pub created_at: Option<DateTime<Utc>>,
 
@@ -28,6 +53,13 @@ impl From<topups::Model> for TopupResponse {
             topup_amount: value.topup_amount,
             topup_method: value.topup_method,
             topup_time: Utc.from_utc_datetime(&value.topup_time),
+            status: value.status,
+            payment_reference: value.payment_reference,
+            payment_instructions: value
+                .payment_instructions
+                .and_then(|instructions| serde_json::from_str(&instructions).ok()),
+            paid_at: value.paid_at.map(|dt| Utc.from_utc_datetime(&dt)),
+            fee_amount: value.fee_amount,
             created_at: value.created_at.map(|dt| Utc.from_utc_datetime(&dt)),
             updated_at: value.updated_at.map(|dt| Utc.from_utc_datetime(&dt)),
         }
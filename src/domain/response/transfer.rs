@@ -2,7 +2,7 @@ use chrono::{DateTime, TimeZone, Utc};
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 
-use crate::entities::transfers;
+use crate::entities::{transfers, users};
 
 #[derive(Debug, Deserialize, Serialize, ToSchema)]
 pub struct TransferResponse {
@@ -11,6 +11,8 @@ pub struct TransferResponse {
     pub transfer_to: i32,
     pub transfer_amount: i32,
     pub transfer_time: DateTime<Utc>,
+    /// Fee charged to the sender on top of `transfer_amount`.
+    pub fee_amount: i32,
     #[schema(format = "date-time")]
     pub created_at: Option<DateTime<Utc>>,
 
@@ -26,8 +28,42 @@ impl From<transfers::Model> for TransferResponse {
             transfer_to: value.transfer_to,
             transfer_amount: value.transfer_amount,
             transfer_time: Utc.from_utc_datetime(&value.transfer_time),
+            fee_amount: value.fee_amount,
             created_at: value.created_at.map(|dt| Utc.from_utc_datetime(&dt)),
             updated_at: value.updated_at.map(|dt| Utc.from_utc_datetime(&dt)),
         }
     }
 }
+
+/// Recipient shown to the sender before confirming a transfer by account number.
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+pub struct RecipientResponse {
+    pub noc_transfer: String,
+    pub masked_name: String,
+}
+
+fn mask_word(word: &str) -> String {
+    let mut chars = word.chars();
+
+    match chars.next() {
+        Some(first) => std::iter::once(first)
+            .chain(chars.map(|_| '*'))
+            .collect(),
+        None => String::new(),
+    }
+}
+
+impl From<users::Model> for RecipientResponse {
+    fn from(value: users::Model) -> Self {
+        let masked_name = format!("{} {}", value.firstname, value.lastname)
+            .split_whitespace()
+            .map(mask_word)
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        RecipientResponse {
+            noc_transfer: value.noc_transfer,
+            masked_name,
+        }
+    }
+}
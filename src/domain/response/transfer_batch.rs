@@ -0,0 +1,75 @@
+use chrono::{DateTime, TimeZone, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::entities::{transfer_batch_items, transfer_batches};
+
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+pub struct TransferBatchResponse {
+    pub batch_id: i32,
+    pub user_id: i32,
+    pub status: String,
+    pub total_items: i32,
+    pub total_amount: i64,
+    pub succeeded_items: i32,
+    pub failed_items: i32,
+    #[schema(format = "date-time")]
+    pub completed_at: Option<DateTime<Utc>>,
+    #[schema(format = "date-time")]
+    pub created_at: Option<DateTime<Utc>>,
+
+    #[schema(format = "date-time")]
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+impl From<transfer_batches::Model> for TransferBatchResponse {
+    fn from(value: transfer_batches::Model) -> Self {
+        TransferBatchResponse {
+            batch_id: value.batch_id,
+            user_id: value.user_id,
+            status: value.status,
+            total_items: value.total_items,
+            total_amount: value.total_amount,
+            succeeded_items: value.succeeded_items,
+            failed_items: value.failed_items,
+            completed_at: value.completed_at.map(|dt| Utc.from_utc_datetime(&dt)),
+            created_at: value.created_at.map(|dt| Utc.from_utc_datetime(&dt)),
+            updated_at: value.updated_at.map(|dt| Utc.from_utc_datetime(&dt)),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+pub struct TransferBatchItemResponse {
+    pub item_id: i32,
+    pub line_no: i32,
+    pub transfer_to: i32,
+    pub transfer_amount: i32,
+    /// `pending`, `processing`, `held` while the transfer waits for fraud
+    /// review, then `succeeded`, `failed` or `interrupted`.
+    pub status: String,
+    pub transfer_id: Option<i32>,
+    pub error_message: Option<String>,
+    pub fraud_review_id: Option<i32>,
+}
+
+impl From<transfer_batch_items::Model> for TransferBatchItemResponse {
+    fn from(value: transfer_batch_items::Model) -> Self {
+        TransferBatchItemResponse {
+            item_id: value.item_id,
+            line_no: value.line_no,
+            transfer_to: value.transfer_to,
+            transfer_amount: value.transfer_amount,
+            status: value.status,
+            transfer_id: value.transfer_id,
+            error_message: value.error_message,
+            fraud_review_id: value.fraud_review_id,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+pub struct TransferBatchDetailResponse {
+    pub batch: TransferBatchResponse,
+    pub items: Vec<TransferBatchItemResponse>,
+}
@@ -10,6 +10,10 @@ pub struct UserResponse {
     pub lastname: String,
     pub email: String,
     pub noc_transfer: String,
+    /// KYC tier, 0 for an unverified wallet.
+    pub kyc_level: i32,
+    /// `user`, or `admin` for staff.
+    pub role: String,
     #[schema(format = "date-time")]
     pub created_at: Option<DateTime<Utc>>,
 
@@ -25,6 +29,8 @@ impl From<users::Model> for UserResponse {
             lastname: value.lastname,
             email: value.email,
             noc_transfer: value.noc_transfer,
+            kyc_level: value.kyc_level,
+            role: value.role,
             created_at: value.created_at.map(|dt| Utc.from_utc_datetime(&dt)),
             updated_at: value.updated_at.map(|dt| Utc.from_utc_datetime(&dt)),
         }
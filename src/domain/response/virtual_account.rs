@@ -0,0 +1,76 @@
+use chrono::{DateTime, TimeZone, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::{
+    domain::response::topup::TopupResponse,
+    entities::{suspense_payments, virtual_accounts},
+};
+
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+pub struct VirtualAccountResponse {
+    pub virtual_account_id: i32,
+    pub bank_code: String,
+    pub va_number: String,
+    #[schema(format = "date-time")]
+    pub created_at: Option<DateTime<Utc>>,
+
+    #[schema(format = "date-time")]
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+impl From<virtual_accounts::Model> for VirtualAccountResponse {
+    fn from(value: virtual_accounts::Model) -> Self {
+        VirtualAccountResponse {
+            virtual_account_id: value.virtual_account_id,
+            bank_code: value.bank_code,
+            va_number: value.va_number,
+            created_at: value.created_at.map(|dt| Utc.from_utc_datetime(&dt)),
+            updated_at: value.updated_at.map(|dt| Utc.from_utc_datetime(&dt)),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+pub struct SuspensePaymentResponse {
+    pub suspense_payment_id: i32,
+    pub payment_id: String,
+    pub bank_code: String,
+    pub va_number: String,
+    pub amount: i32,
+    /// `unresolved` until it is assigned to a user, then `resolved`.
+    pub status: String,
+    pub resolved_user_id: Option<i32>,
+    pub topup_id: Option<i32>,
+    #[schema(format = "date-time")]
+    pub created_at: Option<DateTime<Utc>>,
+
+    #[schema(format = "date-time")]
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+impl From<suspense_payments::Model> for SuspensePaymentResponse {
+    fn from(value: suspense_payments::Model) -> Self {
+        SuspensePaymentResponse {
+            suspense_payment_id: value.suspense_payment_id,
+            payment_id: value.payment_id,
+            bank_code: value.bank_code,
+            va_number: value.va_number,
+            amount: value.amount,
+            status: value.status,
+            resolved_user_id: value.resolved_user_id,
+            topup_id: value.topup_id,
+            created_at: value.created_at.map(|dt| Utc.from_utc_datetime(&dt)),
+            updated_at: value.updated_at.map(|dt| Utc.from_utc_datetime(&dt)),
+        }
+    }
+}
+
+/// Outcome of an incoming VA payment: a completed top-up when the number
+/// belongs to a user, otherwise the suspense entry awaiting review.
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+pub struct VirtualAccountPaymentResponse {
+    pub matched: bool,
+    pub topup: Option<TopupResponse>,
+    pub suspense_payment: Option<SuspensePaymentResponse>,
+}
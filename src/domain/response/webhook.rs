@@ -0,0 +1,74 @@
+use chrono::{DateTime, TimeZone, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::entities::{webhook_deliveries, webhook_endpoints};
+
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+pub struct WebhookEndpointResponse {
+    pub webhook_endpoint_id: i32,
+    pub merchant_id: i32,
+    pub url: String,
+    /// Signing secret, only returned when the endpoint is registered.
+    pub secret: Option<String>,
+    pub is_active: bool,
+    #[schema(format = "date-time")]
+    pub created_at: Option<DateTime<Utc>>,
+
+    #[schema(format = "date-time")]
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+impl From<webhook_endpoints::Model> for WebhookEndpointResponse {
+    fn from(value: webhook_endpoints::Model) -> Self {
+        WebhookEndpointResponse {
+            webhook_endpoint_id: value.webhook_endpoint_id,
+            merchant_id: value.merchant_id,
+            url: value.url,
+            secret: None,
+            is_active: value.is_active,
+            created_at: value.created_at.map(|dt| Utc.from_utc_datetime(&dt)),
+            updated_at: value.updated_at.map(|dt| Utc.from_utc_datetime(&dt)),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+pub struct WebhookDeliveryResponse {
+    pub webhook_delivery_id: i32,
+    pub webhook_event_id: i32,
+    pub webhook_endpoint_id: i32,
+    /// `pending` until the endpoint answers 2xx (`succeeded`) or the
+    /// attempts run out (`failed`).
+    pub status: String,
+    pub attempts: i32,
+    #[schema(format = "date-time")]
+    pub next_attempt_at: DateTime<Utc>,
+    pub last_response_status: Option<i32>,
+    pub last_error: Option<String>,
+    #[schema(format = "date-time")]
+    pub delivered_at: Option<DateTime<Utc>>,
+    #[schema(format = "date-time")]
+    pub created_at: Option<DateTime<Utc>>,
+
+    #[schema(format = "date-time")]
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+impl From<webhook_deliveries::Model> for WebhookDeliveryResponse {
+    fn from(value: webhook_deliveries::Model) -> Self {
+        WebhookDeliveryResponse {
+            webhook_delivery_id: value.webhook_delivery_id,
+            webhook_event_id: value.webhook_event_id,
+            webhook_endpoint_id: value.webhook_endpoint_id,
+            status: value.status,
+            attempts: value.attempts,
+            next_attempt_at: Utc.from_utc_datetime(&value.next_attempt_at),
+            last_response_status: value.last_response_status,
+            last_error: value.last_error,
+            delivered_at: value.delivered_at.map(|dt| Utc.from_utc_datetime(&dt)),
+            created_at: value.created_at.map(|dt| Utc.from_utc_datetime(&dt)),
+            updated_at: value.updated_at.map(|dt| Utc.from_utc_datetime(&dt)),
+        }
+    }
+}
@@ -10,6 +10,13 @@ pub struct WithdrawResponse {
     pub user_id: i32,
     pub withdraw_amount: i32,
     pub withdraw_time: DateTime<Utc>,
+    pub bank_account_id: Option<i32>,
+    /// `pending` while the payout is in flight, then `succeeded` or `failed`.
+    pub status: String,
+    pub provider_reference: Option<String>,
+    pub failure_reason: Option<String>,
+    /// Fee held with `withdraw_amount` and kept once the payout succeeds.
+    pub fee_amount: i32,
     #[schema(format = "date-time")]
     pub created_at: Option<DateTime<Utc>>,
 
@@ -24,6 +31,11 @@ impl From<withdraws::Model> for WithdrawResponse {
             user_id: value.user_id,
             withdraw_amount: value.withdraw_amount,
             withdraw_time: Utc.from_utc_datetime(&value.withdraw_time),
+            bank_account_id: value.bank_account_id,
+            status: value.status,
+            provider_reference: value.provider_reference,
+            failure_reason: value.failure_reason,
+            fee_amount: value.fee_amount,
             created_at: value.created_at.map(|dt| Utc.from_utc_datetime(&dt)),
             updated_at: value.updated_at.map(|dt| Utc.from_utc_datetime(&dt)),
         }
@@ -2,10 +2,36 @@
 
 pub mod prelude;
 
+pub mod audit_events;
+pub mod bank_accounts;
+pub mod beneficiaries;
+pub mod fee_revenues;
+pub mod fee_rules;
+pub mod fraud_reviews;
+pub mod fraud_rules;
+pub mod kyc_submission_events;
+pub mod kyc_submissions;
+pub mod merchants;
+pub mod payment_intents;
+pub mod qris_payments;
+pub mod refunds;
 pub mod saldo;
+pub mod scheduled_transfer_attempts;
+pub mod scheduled_transfers;
+pub mod screening_results;
+pub mod settlements;
+pub mod suspense_payments;
 pub mod topups;
+pub mod transaction_limits;
+pub mod transfer_batch_items;
+pub mod transfer_batches;
 pub mod transfers;
+pub mod user_devices;
 pub mod users;
+pub mod virtual_accounts;
+pub mod webhook_deliveries;
+pub mod webhook_endpoints;
+pub mod webhook_events;
 pub mod withdraws;
 
 
@@ -14,4 +40,29 @@ pub use saldo::Entity as Saldo;
 pub use transfers::Entity as Transfer;
 pub use topups::Entity as Topup;
 pub use withdraws::Entity as Withdraws;
-
+pub use transfer_batches::Entity as TransferBatch;
+pub use transfer_batch_items::Entity as TransferBatchItem;
+pub use scheduled_transfer_attempts::Entity as ScheduledTransferAttempt;
+pub use scheduled_transfers::Entity as ScheduledTransfer;
+pub use beneficiaries::Entity as Beneficiary;
+pub use bank_accounts::Entity as BankAccount;
+pub use virtual_accounts::Entity as VirtualAccount;
+pub use suspense_payments::Entity as SuspensePayment;
+pub use qris_payments::Entity as QrisPayment;
+pub use merchants::Entity as Merchant;
+pub use payment_intents::Entity as PaymentIntent;
+pub use refunds::Entity as Refund;
+pub use settlements::Entity as Settlement;
+pub use fee_rules::Entity as FeeRule;
+pub use fee_revenues::Entity as FeeRevenue;
+pub use transaction_limits::Entity as TransactionLimit;
+pub use kyc_submissions::Entity as KycSubmission;
+pub use kyc_submission_events::Entity as KycSubmissionEvent;
+pub use fraud_rules::Entity as FraudRule;
+pub use fraud_reviews::Entity as FraudReview;
+pub use user_devices::Entity as UserDevice;
+pub use screening_results::Entity as ScreeningResult;
+pub use audit_events::Entity as AuditEvent;
+pub use webhook_endpoints::Entity as WebhookEndpoint;
+pub use webhook_events::Entity as WebhookEvent;
+pub use webhook_deliveries::Entity as WebhookDelivery;
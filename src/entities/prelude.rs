@@ -1,7 +1,33 @@
 //! `SeaORM` Entity, @generated by sea-orm-codegen 1.1.0
 
+pub use super::audit_events::Entity as AuditEvents;
+pub use super::bank_accounts::Entity as BankAccounts;
+pub use super::beneficiaries::Entity as Beneficiaries;
+pub use super::fee_revenues::Entity as FeeRevenues;
+pub use super::fee_rules::Entity as FeeRules;
+pub use super::fraud_reviews::Entity as FraudReviews;
+pub use super::fraud_rules::Entity as FraudRules;
+pub use super::kyc_submission_events::Entity as KycSubmissionEvents;
+pub use super::kyc_submissions::Entity as KycSubmissions;
+pub use super::merchants::Entity as Merchants;
+pub use super::payment_intents::Entity as PaymentIntents;
+pub use super::qris_payments::Entity as QrisPayments;
+pub use super::refunds::Entity as Refunds;
 pub use super::saldo::Entity as Saldo;
+pub use super::scheduled_transfer_attempts::Entity as ScheduledTransferAttempts;
+pub use super::scheduled_transfers::Entity as ScheduledTransfers;
+pub use super::screening_results::Entity as ScreeningResults;
+pub use super::settlements::Entity as Settlements;
+pub use super::suspense_payments::Entity as SuspensePayments;
 pub use super::topups::Entity as Topups;
+pub use super::transaction_limits::Entity as TransactionLimits;
+pub use super::transfer_batch_items::Entity as TransferBatchItems;
+pub use super::transfer_batches::Entity as TransferBatches;
 pub use super::transfers::Entity as Transfers;
+pub use super::user_devices::Entity as UserDevices;
 pub use super::users::Entity as Users;
+pub use super::virtual_accounts::Entity as VirtualAccounts;
+pub use super::webhook_deliveries::Entity as WebhookDeliveries;
+pub use super::webhook_endpoints::Entity as WebhookEndpoints;
+pub use super::webhook_events::Entity as WebhookEvents;
 pub use super::withdraws::Entity as Withdraws;
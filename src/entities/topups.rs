@@ -14,6 +14,13 @@ pub struct Model {
     #[sea_orm(column_type = "Text")]
     pub topup_method: String,
     pub topup_time: DateTime,
+    pub status: String,
+    pub payment_reference: Option<String>,
+    #[sea_orm(column_type = "Text", nullable)]
+    pub payment_instructions: Option<String>,
+    pub paid_at: Option<DateTime>,
+    pub fee_amount: i32,
+    pub fee_rule_id: Option<i32>,
     pub created_at: Option<DateTime>,
     pub updated_at: Option<DateTime>,
 }
@@ -11,6 +11,7 @@ pub struct Model {
     pub transfer_to: i32,
     pub transfer_amount: i32,
     pub transfer_time: DateTime,
+    pub fee_amount: i32,
     pub created_at: Option<DateTime>,
     pub updated_at: Option<DateTime>,
 }
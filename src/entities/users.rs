@@ -14,6 +14,8 @@ pub struct Model {
     pub password: String,
     #[sea_orm(unique)]
     pub noc_transfer: String,
+    pub kyc_level: i32,
+    pub role: String,
     pub created_at: Option<DateTime>,
     pub updated_at: Option<DateTime>,
 }
@@ -10,6 +10,13 @@ pub struct Model {
     pub user_id: i32,
     pub withdraw_amount: i32,
     pub withdraw_time: DateTime,
+    pub bank_account_id: Option<i32>,
+    pub status: String,
+    pub provider_reference: Option<String>,
+    pub payout_reference: Option<String>,
+    pub failure_reason: Option<String>,
+    pub fee_amount: i32,
+    pub fee_rule_id: Option<i32>,
     pub created_at: Option<DateTime>,
     pub updated_at: Option<DateTime>,
 }
@@ -0,0 +1,85 @@
+use crate::{
+    domain::{
+        request::audit::FindAuditEventsQuery,
+        response::{
+            audit::{AuditChainVerificationResponse, AuditEventResponse},
+            ApiResponse,
+        },
+    },
+    middleware::{admin, jwt},
+    state::AppState,
+};
+use axum::{
+    extract::{Query, State},
+    http::StatusCode,
+    middleware,
+    response::IntoResponse,
+    routing::get,
+    Json,
+};
+use serde_json::json;
+use std::sync::Arc;
+use tracing::instrument;
+use utoipa_axum::router::OpenApiRouter;
+
+#[utoipa::path(
+    get,
+    path = "/api/admin/audit/events",
+    tag = "Audit",
+    security(
+        ("bearer_auth" = [])
+    ),
+    params(FindAuditEventsQuery),
+    responses(
+        (status = 200, description = "Audit events, newest first", body = ApiResponse<Vec<AuditEventResponse>>),
+        (status = 400, description = "Invalid limit", body = String),
+        (status = 401, description = "Unauthorized access", body = String),
+        (status = 403, description = "Admin access required", body = String),
+    )
+)]
+#[instrument(skip_all)]
+pub async fn get_audit_events(
+    State(data): State<Arc<AppState>>,
+    Query(query): Query<FindAuditEventsQuery>,
+) -> Result<impl IntoResponse, (StatusCode, Json<serde_json::Value>)> {
+    match data.di_container.audit_service.find_events(&query).await {
+        Ok(response) => Ok((StatusCode::OK, Json(json!(response)))),
+        Err(e) => Err((StatusCode::BAD_REQUEST, Json(json!(e)))),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/admin/audit/verify",
+    tag = "Audit",
+    security(
+        ("bearer_auth" = [])
+    ),
+    responses(
+        (status = 200, description = "Result of walking the hash chain", body = ApiResponse<AuditChainVerificationResponse>),
+        (status = 401, description = "Unauthorized access", body = String),
+        (status = 403, description = "Admin access required", body = String),
+        (status = 500, description = "Internal server error", body = String),
+    )
+)]
+#[instrument(skip_all)]
+pub async fn verify_audit_chain(
+    State(data): State<Arc<AppState>>,
+) -> Result<impl IntoResponse, (StatusCode, Json<serde_json::Value>)> {
+    match data.di_container.audit_service.verify_chain().await {
+        Ok(response) => Ok((StatusCode::OK, Json(json!(response)))),
+        Err(e) => Err((StatusCode::INTERNAL_SERVER_ERROR, Json(json!(e)))),
+    }
+}
+
+pub fn admin_audit_routes(app_state: Arc<AppState>) -> OpenApiRouter {
+    OpenApiRouter::new()
+        .route("/api/admin/audit/events", get(get_audit_events))
+        .route("/api/admin/audit/verify", get(verify_audit_chain))
+        .route_layer(middleware::from_fn_with_state(
+            app_state.clone(),
+            admin::admin,
+        ))
+        .route_layer(middleware::from_fn_with_state(app_state.clone(), jwt::auth))
+        .with_state(app_state.clone())
+}
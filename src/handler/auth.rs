@@ -17,6 +17,7 @@ use axum::{
     routing::{get, post}
 };
 use serde_json::{json, Value};
+use tracing::instrument;
 use utoipa_axum::router::OpenApiRouter;
 
 #[utoipa::path(
@@ -27,6 +28,7 @@ use utoipa_axum::router::OpenApiRouter;
         (status = 200, description = "Health check successful", body = Value)
     )
 )]
+#[instrument(skip_all)]
 pub async fn health_checker_handler() -> impl IntoResponse {
     const MESSAGE: &str = "JWT Authentication in Rust using Axum, Postgres, and SQLX";
     Json(serde_json::json!({ "status": "success", "message": MESSAGE }))
@@ -42,6 +44,7 @@ pub async fn health_checker_handler() -> impl IntoResponse {
         (status = 400, description = "Bad request", body = ErrorResponse)
     )
 )]
+#[instrument(skip_all)]
 pub async fn register_user_handler(
     State(data): State<Arc<AppState>>,
     Json(body): Json<RegisterRequest>,
@@ -62,6 +65,7 @@ pub async fn register_user_handler(
         (status = 401, description = "Unauthorized", body = ErrorResponse)
     )
 )]
+#[instrument(skip_all)]
 pub async fn login_user_handler(
     State(data): State<Arc<AppState>>,
     Json(body): Json<LoginRequest>,
@@ -84,6 +88,7 @@ pub async fn login_user_handler(
         (status = 401, description = "Unauthorized", body = ErrorResponse)
     )
 )]
+#[instrument(skip_all)]
 pub async fn get_me_handler(
     State(data): State<Arc<AppState>>,
     Extension(user_id): Extension<i64>,
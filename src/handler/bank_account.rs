@@ -0,0 +1,130 @@
+use crate::{
+    domain::{
+        request::bank_account::CreateBankAccountRequest,
+        response::{bank_account::BankAccountResponse, ApiResponse},
+    },
+    middleware::jwt,
+    state::AppState,
+};
+use axum::{
+    extract::{Extension, Path, State},
+    http::StatusCode,
+    middleware,
+    response::IntoResponse,
+    routing::{delete, get, post},
+    Json,
+};
+use serde_json::json;
+use std::sync::Arc;
+use tracing::instrument;
+use utoipa_axum::router::OpenApiRouter;
+
+#[utoipa::path(
+    get,
+    path = "/api/bank-accounts",
+    tag = "Bank Account",
+    security(
+        ("bearer_auth" = [])
+    ),
+    responses(
+        (status = 200, description = "Registered bank accounts of the current user", body = ApiResponse<Vec<BankAccountResponse>>),
+        (status = 401, description = "Unauthorized access", body = String),
+        (status = 500, description = "Internal server error", body = String),
+    )
+)]
+#[instrument(skip_all)]
+pub async fn get_bank_accounts(
+    State(data): State<Arc<AppState>>,
+    Extension(user_id): Extension<i64>,
+) -> Result<impl IntoResponse, (StatusCode, Json<serde_json::Value>)> {
+    match data
+        .di_container
+        .bank_account_service
+        .get_bank_accounts(user_id as i32)
+        .await
+    {
+        Ok(response) => Ok((StatusCode::OK, Json(json!(response)))),
+        Err(e) => Err((StatusCode::INTERNAL_SERVER_ERROR, Json(json!(e)))),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/bank-accounts",
+    tag = "Bank Account",
+    security(
+        ("bearer_auth" = [])
+    ),
+    request_body = CreateBankAccountRequest,
+    responses(
+        (status = 201, description = "Bank account registered", body = ApiResponse<BankAccountResponse>),
+        (status = 401, description = "Unauthorized access", body = String),
+        (status = 500, description = "Internal server error", body = String),
+    )
+)]
+#[instrument(skip_all)]
+pub async fn create_bank_account(
+    State(data): State<Arc<AppState>>,
+    Extension(user_id): Extension<i64>,
+    Json(mut body): Json<CreateBankAccountRequest>,
+) -> Result<impl IntoResponse, (StatusCode, Json<serde_json::Value>)> {
+    body.user_id = user_id as i32;
+
+    match data
+        .di_container
+        .bank_account_service
+        .create_bank_account(&body)
+        .await
+    {
+        Ok(response) => Ok((StatusCode::CREATED, Json(json!(response)))),
+        Err(e) => Err((StatusCode::INTERNAL_SERVER_ERROR, Json(json!(e)))),
+    }
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/bank-accounts/{id}",
+    tag = "Bank Account",
+    security(
+        ("bearer_auth" = [])
+    ),
+    params(
+        ("id" = i32, Path, description = "Bank account ID")
+    ),
+    responses(
+        (status = 200, description = "Bank account deleted", body = serde_json::Value),
+        (status = 401, description = "Unauthorized access", body = String),
+        (status = 404, description = "Bank account not found", body = String),
+    )
+)]
+#[instrument(skip_all)]
+pub async fn delete_bank_account(
+    State(data): State<Arc<AppState>>,
+    Path(id): Path<i32>,
+    Extension(user_id): Extension<i64>,
+) -> Result<impl IntoResponse, (StatusCode, Json<serde_json::Value>)> {
+    match data
+        .di_container
+        .bank_account_service
+        .delete_bank_account(id, user_id as i32)
+        .await
+    {
+        Ok(_) => Ok((
+            StatusCode::OK,
+            Json(json!({
+                "status": "success",
+                "message": "Bank account deleted successfully"
+            })),
+        )),
+        Err(e) => Err((StatusCode::NOT_FOUND, Json(json!(e)))),
+    }
+}
+
+pub fn bank_account_routes(app_state: Arc<AppState>) -> OpenApiRouter {
+    OpenApiRouter::new()
+        .route("/api/bank-accounts", get(get_bank_accounts))
+        .route("/api/bank-accounts", post(create_bank_account))
+        .route("/api/bank-accounts/{id}", delete(delete_bank_account))
+        .route_layer(middleware::from_fn_with_state(app_state.clone(), jwt::auth))
+        .with_state(app_state.clone())
+}
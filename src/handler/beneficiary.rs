@@ -0,0 +1,203 @@
+use crate::{
+    domain::{
+        request::beneficiary::{CreateBeneficiaryRequest, UpdateBeneficiaryRequest},
+        response::{beneficiary::BeneficiaryResponse, ApiResponse},
+    },
+    middleware::jwt,
+    state::AppState,
+};
+use axum::{
+    extract::{Extension, Path, State},
+    http::StatusCode,
+    middleware,
+    response::IntoResponse,
+    routing::{delete, get, post, put},
+    Json,
+};
+use serde_json::json;
+use std::sync::Arc;
+use tracing::instrument;
+use utoipa_axum::router::OpenApiRouter;
+
+#[utoipa::path(
+    get,
+    path = "/api/beneficiaries",
+    tag = "Beneficiary",
+    security(
+        ("bearer_auth" = [])
+    ),
+    responses(
+        (status = 200, description = "Saved beneficiaries of the current user", body = ApiResponse<Vec<BeneficiaryResponse>>),
+        (status = 401, description = "Unauthorized access", body = String),
+        (status = 500, description = "Internal server error", body = String),
+    )
+)]
+#[instrument(skip_all)]
+pub async fn get_beneficiaries(
+    State(data): State<Arc<AppState>>,
+    Extension(user_id): Extension<i64>,
+) -> Result<impl IntoResponse, (StatusCode, Json<serde_json::Value>)> {
+    match data
+        .di_container
+        .beneficiary_service
+        .get_beneficiaries(user_id as i32)
+        .await
+    {
+        Ok(response) => Ok((StatusCode::OK, Json(json!(response)))),
+        Err(e) => Err((StatusCode::INTERNAL_SERVER_ERROR, Json(json!(e)))),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/beneficiaries/{id}",
+    tag = "Beneficiary",
+    security(
+        ("bearer_auth" = [])
+    ),
+    params(
+        ("id" = i32, Path, description = "Beneficiary ID")
+    ),
+    responses(
+        (status = 200, description = "Beneficiary details", body = ApiResponse<BeneficiaryResponse>),
+        (status = 401, description = "Unauthorized access", body = String),
+        (status = 404, description = "Beneficiary not found", body = String),
+    )
+)]
+#[instrument(skip_all)]
+pub async fn get_beneficiary(
+    State(data): State<Arc<AppState>>,
+    Path(id): Path<i32>,
+    Extension(user_id): Extension<i64>,
+) -> Result<impl IntoResponse, (StatusCode, Json<serde_json::Value>)> {
+    match data
+        .di_container
+        .beneficiary_service
+        .get_beneficiary(id, user_id as i32)
+        .await
+    {
+        Ok(response) => Ok((StatusCode::OK, Json(json!(response)))),
+        Err(e) => Err((StatusCode::NOT_FOUND, Json(json!(e)))),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/beneficiaries",
+    tag = "Beneficiary",
+    security(
+        ("bearer_auth" = [])
+    ),
+    request_body = CreateBeneficiaryRequest,
+    responses(
+        (status = 201, description = "Beneficiary created", body = ApiResponse<BeneficiaryResponse>),
+        (status = 401, description = "Unauthorized access", body = String),
+        (status = 500, description = "Internal server error", body = String),
+    )
+)]
+#[instrument(skip_all)]
+pub async fn create_beneficiary(
+    State(data): State<Arc<AppState>>,
+    Extension(user_id): Extension<i64>,
+    Json(mut body): Json<CreateBeneficiaryRequest>,
+) -> Result<impl IntoResponse, (StatusCode, Json<serde_json::Value>)> {
+    body.user_id = user_id as i32;
+
+    match data
+        .di_container
+        .beneficiary_service
+        .create_beneficiary(&body)
+        .await
+    {
+        Ok(response) => Ok((StatusCode::CREATED, Json(json!(response)))),
+        Err(e) => Err((StatusCode::INTERNAL_SERVER_ERROR, Json(json!(e)))),
+    }
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/beneficiaries/{id}",
+    tag = "Beneficiary",
+    security(
+        ("bearer_auth" = [])
+    ),
+    params(
+        ("id" = i32, Path, description = "Beneficiary ID")
+    ),
+    request_body = UpdateBeneficiaryRequest,
+    responses(
+        (status = 200, description = "Beneficiary updated", body = ApiResponse<BeneficiaryResponse>),
+        (status = 401, description = "Unauthorized access", body = String),
+        (status = 500, description = "Internal server error", body = String),
+    )
+)]
+#[instrument(skip_all)]
+pub async fn update_beneficiary(
+    State(data): State<Arc<AppState>>,
+    Path(id): Path<i32>,
+    Extension(user_id): Extension<i64>,
+    Json(mut body): Json<UpdateBeneficiaryRequest>,
+) -> Result<impl IntoResponse, (StatusCode, Json<serde_json::Value>)> {
+    body.beneficiary_id = id;
+    body.user_id = user_id as i32;
+
+    match data
+        .di_container
+        .beneficiary_service
+        .update_beneficiary(&body)
+        .await
+    {
+        Ok(response) => Ok((StatusCode::OK, Json(json!(response)))),
+        Err(e) => Err((StatusCode::INTERNAL_SERVER_ERROR, Json(json!(e)))),
+    }
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/beneficiaries/{id}",
+    tag = "Beneficiary",
+    security(
+        ("bearer_auth" = [])
+    ),
+    params(
+        ("id" = i32, Path, description = "Beneficiary ID")
+    ),
+    responses(
+        (status = 200, description = "Beneficiary deleted", body = serde_json::Value),
+        (status = 401, description = "Unauthorized access", body = String),
+        (status = 404, description = "Beneficiary not found", body = String),
+    )
+)]
+#[instrument(skip_all)]
+pub async fn delete_beneficiary(
+    State(data): State<Arc<AppState>>,
+    Path(id): Path<i32>,
+    Extension(user_id): Extension<i64>,
+) -> Result<impl IntoResponse, (StatusCode, Json<serde_json::Value>)> {
+    match data
+        .di_container
+        .beneficiary_service
+        .delete_beneficiary(id, user_id as i32)
+        .await
+    {
+        Ok(_) => Ok((
+            StatusCode::OK,
+            Json(json!({
+                "status": "success",
+                "message": "Beneficiary deleted successfully"
+            })),
+        )),
+        Err(e) => Err((StatusCode::NOT_FOUND, Json(json!(e)))),
+    }
+}
+
+pub fn beneficiary_routes(app_state: Arc<AppState>) -> OpenApiRouter {
+    OpenApiRouter::new()
+        .route("/api/beneficiaries", get(get_beneficiaries))
+        .route("/api/beneficiaries", post(create_beneficiary))
+        .route("/api/beneficiaries/{id}", get(get_beneficiary))
+        .route("/api/beneficiaries/{id}", put(update_beneficiary))
+        .route("/api/beneficiaries/{id}", delete(delete_beneficiary))
+        .route_layer(middleware::from_fn_with_state(app_state.clone(), jwt::auth))
+        .with_state(app_state.clone())
+}
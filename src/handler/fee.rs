@@ -0,0 +1,79 @@
+use crate::{
+    domain::{
+        request::fee::FeeQuoteRequest,
+        response::{
+            fee::{FeeQuoteResponse, FeeRuleResponse},
+            ApiResponse,
+        },
+    },
+    middleware::jwt,
+    state::AppState,
+};
+use axum::{
+    extract::State,
+    http::StatusCode,
+    middleware,
+    response::IntoResponse,
+    routing::{get, post},
+    Json,
+};
+use serde_json::json;
+use std::sync::Arc;
+use tracing::instrument;
+use utoipa_axum::router::OpenApiRouter;
+
+#[utoipa::path(
+    get,
+    path = "/api/fees",
+    tag = "Fee",
+    security(
+        ("bearer_auth" = [])
+    ),
+    responses(
+        (status = 200, description = "Active fee rules", body = ApiResponse<Vec<FeeRuleResponse>>),
+        (status = 401, description = "Unauthorized access", body = String),
+        (status = 500, description = "Internal server error", body = String),
+    )
+)]
+#[instrument(skip_all)]
+pub async fn get_fee_rules(
+    State(data): State<Arc<AppState>>,
+) -> Result<impl IntoResponse, (StatusCode, Json<serde_json::Value>)> {
+    match data.di_container.fee_service.get_fee_rules().await {
+        Ok(response) => Ok((StatusCode::OK, Json(json!(response)))),
+        Err(e) => Err((StatusCode::INTERNAL_SERVER_ERROR, Json(json!(e)))),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/fees/quote",
+    tag = "Fee",
+    security(
+        ("bearer_auth" = [])
+    ),
+    request_body = FeeQuoteRequest,
+    responses(
+        (status = 200, description = "Fee that would be charged on the transaction", body = ApiResponse<FeeQuoteResponse>),
+        (status = 400, description = "Invalid quote request", body = String),
+        (status = 401, description = "Unauthorized access", body = String),
+    )
+)]
+#[instrument(skip_all)]
+pub async fn get_fee_quote(
+    State(data): State<Arc<AppState>>,
+    Json(body): Json<FeeQuoteRequest>,
+) -> Result<impl IntoResponse, (StatusCode, Json<serde_json::Value>)> {
+    match data.di_container.fee_service.get_fee_quote(&body).await {
+        Ok(response) => Ok((StatusCode::OK, Json(json!(response)))),
+        Err(e) => Err((StatusCode::BAD_REQUEST, Json(json!(e)))),
+    }
+}
+
+pub fn fee_routes(app_state: Arc<AppState>) -> OpenApiRouter {
+    OpenApiRouter::new()
+        .route("/api/fees", get(get_fee_rules))
+        .route("/api/fees/quote", post(get_fee_quote))
+        .route_layer(middleware::from_fn_with_state(app_state.clone(), jwt::auth))
+        .with_state(app_state.clone())
+}
@@ -0,0 +1,175 @@
+use crate::{
+    domain::{
+        request::fraud::{DecideFraudReviewRequest, FindFraudReviewsQuery},
+        response::{
+            fraud::{FraudReviewResponse, FraudRuleResponse},
+            ApiResponse,
+        },
+    },
+    middleware::{admin, jwt},
+    state::AppState,
+};
+use axum::{
+    extract::{Extension, Path, Query, State},
+    http::StatusCode,
+    middleware,
+    response::IntoResponse,
+    routing::{get, post},
+    Json,
+};
+use serde_json::json;
+use std::sync::Arc;
+use tracing::instrument;
+use utoipa_axum::router::OpenApiRouter;
+
+#[utoipa::path(
+    get,
+    path = "/api/admin/fraud/rules",
+    tag = "Fraud",
+    security(
+        ("bearer_auth" = [])
+    ),
+    responses(
+        (status = 200, description = "Fraud rules run before money moves", body = ApiResponse<Vec<FraudRuleResponse>>),
+        (status = 401, description = "Unauthorized access", body = String),
+        (status = 403, description = "Admin access required", body = String),
+    )
+)]
+#[instrument(skip_all)]
+pub async fn get_fraud_rules(
+    State(data): State<Arc<AppState>>,
+) -> Result<impl IntoResponse, (StatusCode, Json<serde_json::Value>)> {
+    match data.di_container.fraud_review_service.get_rules().await {
+        Ok(response) => Ok((StatusCode::OK, Json(json!(response)))),
+        Err(e) => Err((StatusCode::INTERNAL_SERVER_ERROR, Json(json!(e)))),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/admin/fraud/reviews",
+    tag = "Fraud",
+    security(
+        ("bearer_auth" = [])
+    ),
+    params(FindFraudReviewsQuery),
+    responses(
+        (status = 200, description = "Transactions held for review", body = ApiResponse<Vec<FraudReviewResponse>>),
+        (status = 400, description = "Unknown status", body = String),
+        (status = 401, description = "Unauthorized access", body = String),
+        (status = 403, description = "Admin access required", body = String),
+    )
+)]
+#[instrument(skip_all)]
+pub async fn get_fraud_reviews(
+    State(data): State<Arc<AppState>>,
+    Query(query): Query<FindFraudReviewsQuery>,
+) -> Result<impl IntoResponse, (StatusCode, Json<serde_json::Value>)> {
+    match data
+        .di_container
+        .fraud_review_service
+        .get_reviews(query.status)
+        .await
+    {
+        Ok(response) => Ok((StatusCode::OK, Json(json!(response)))),
+        Err(e) => Err((StatusCode::BAD_REQUEST, Json(json!(e)))),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/admin/fraud/reviews/{id}/approve",
+    tag = "Fraud",
+    security(
+        ("bearer_auth" = [])
+    ),
+    params(
+        ("id" = i32, Path, description = "Fraud review ID")
+    ),
+    request_body = DecideFraudReviewRequest,
+    responses(
+        (status = 200, description = "Held transaction released and carried out", body = ApiResponse<FraudReviewResponse>),
+        (status = 400, description = "Review already decided or transaction could not be carried out", body = String),
+        (status = 401, description = "Unauthorized access", body = String),
+        (status = 403, description = "Admin access required", body = String),
+    )
+)]
+#[instrument(skip_all)]
+pub async fn approve_fraud_review(
+    State(data): State<Arc<AppState>>,
+    Path(id): Path<i32>,
+    Extension(user_id): Extension<i64>,
+    Json(mut body): Json<DecideFraudReviewRequest>,
+) -> Result<impl IntoResponse, (StatusCode, Json<serde_json::Value>)> {
+    body.fraud_review_id = id;
+    body.reviewer_id = user_id as i32;
+
+    match data
+        .di_container
+        .fraud_review_service
+        .approve_review(&body)
+        .await
+    {
+        Ok(response) => Ok((StatusCode::OK, Json(json!(response)))),
+        Err(e) => Err((StatusCode::BAD_REQUEST, Json(json!(e)))),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/admin/fraud/reviews/{id}/decline",
+    tag = "Fraud",
+    security(
+        ("bearer_auth" = [])
+    ),
+    params(
+        ("id" = i32, Path, description = "Fraud review ID")
+    ),
+    request_body = DecideFraudReviewRequest,
+    responses(
+        (status = 200, description = "Held transaction declined, no money moved", body = ApiResponse<FraudReviewResponse>),
+        (status = 400, description = "Missing note or review already decided", body = String),
+        (status = 401, description = "Unauthorized access", body = String),
+        (status = 403, description = "Admin access required", body = String),
+    )
+)]
+#[instrument(skip_all)]
+pub async fn decline_fraud_review(
+    State(data): State<Arc<AppState>>,
+    Path(id): Path<i32>,
+    Extension(user_id): Extension<i64>,
+    Json(mut body): Json<DecideFraudReviewRequest>,
+) -> Result<impl IntoResponse, (StatusCode, Json<serde_json::Value>)> {
+    body.fraud_review_id = id;
+    body.reviewer_id = user_id as i32;
+
+    match data
+        .di_container
+        .fraud_review_service
+        .decline_review(&body)
+        .await
+    {
+        Ok(response) => Ok((StatusCode::OK, Json(json!(response)))),
+        Err(e) => Err((StatusCode::BAD_REQUEST, Json(json!(e)))),
+    }
+}
+
+pub fn admin_fraud_routes(app_state: Arc<AppState>) -> OpenApiRouter {
+    OpenApiRouter::new()
+        .route("/api/admin/fraud/rules", get(get_fraud_rules))
+        .route("/api/admin/fraud/reviews", get(get_fraud_reviews))
+        .route(
+            "/api/admin/fraud/reviews/{id}/approve",
+            post(approve_fraud_review),
+        )
+        .route(
+            "/api/admin/fraud/reviews/{id}/decline",
+            post(decline_fraud_review),
+        )
+        .route_layer(middleware::from_fn_with_state(
+            app_state.clone(),
+            admin::admin,
+        ))
+        .route_layer(middleware::from_fn_with_state(app_state.clone(), jwt::auth))
+        .with_state(app_state.clone())
+}
@@ -0,0 +1,52 @@
+use crate::{
+    domain::response::health::{LivenessResponse, ReadinessResponse},
+    state::AppState,
+};
+use axum::{extract::State, http::StatusCode, response::IntoResponse, routing::get, Json};
+use std::sync::Arc;
+use tracing::instrument;
+use utoipa_axum::router::OpenApiRouter;
+
+#[utoipa::path(
+    get,
+    path = "/health/live",
+    tag = "Health",
+    responses(
+        (status = 200, description = "The process is up", body = LivenessResponse),
+    )
+)]
+#[instrument(skip_all)]
+pub async fn liveness() -> impl IntoResponse {
+    Json(LivenessResponse {
+        status: "alive".to_string(),
+    })
+}
+
+#[utoipa::path(
+    get,
+    path = "/health/ready",
+    tag = "Health",
+    responses(
+        (status = 200, description = "Ready, possibly with degraded background workers", body = ReadinessResponse),
+        (status = 503, description = "The database is unreachable or migrations are pending", body = ReadinessResponse),
+    )
+)]
+#[instrument(skip_all)]
+pub async fn readiness(State(data): State<Arc<AppState>>) -> impl IntoResponse {
+    let response = data.di_container.health_service.readiness().await;
+
+    let status = if response.is_available() {
+        StatusCode::OK
+    } else {
+        StatusCode::SERVICE_UNAVAILABLE
+    };
+
+    (status, Json(response))
+}
+
+pub fn health_routes(app_state: Arc<AppState>) -> OpenApiRouter {
+    OpenApiRouter::new()
+        .route("/health/live", get(liveness))
+        .route("/health/ready", get(readiness))
+        .with_state(app_state.clone())
+}
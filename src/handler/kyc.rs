@@ -0,0 +1,271 @@
+use crate::{
+    domain::{
+        request::kyc::{
+            CreateKycSubmissionRequest, FindKycSubmissionsQuery, KycDocument,
+            ReviewKycSubmissionRequest,
+        },
+        response::{kyc::KycSubmissionResponse, ApiResponse},
+    },
+    middleware::{admin, jwt},
+    state::AppState,
+};
+use axum::{
+    extract::{DefaultBodyLimit, Extension, Multipart, Path, Query, State},
+    http::{header, StatusCode},
+    middleware,
+    response::IntoResponse,
+    routing::{get, post},
+    Json,
+};
+use serde_json::json;
+use std::sync::Arc;
+use utoipa::ToSchema;
+use tracing::instrument;
+use utoipa_axum::router::OpenApiRouter;
+
+/// Multipart form used to submit identity documents.
+#[allow(dead_code)]
+#[derive(ToSchema)]
+pub struct KycSubmissionUpload {
+    /// Photo or scan of the ID card: JPEG, PNG or PDF.
+    #[schema(format = Binary)]
+    id_card: String,
+    /// Selfie holding the ID card: JPEG or PNG.
+    #[schema(format = Binary)]
+    selfie: String,
+}
+
+fn bad_request(message: impl Into<String>) -> (StatusCode, Json<serde_json::Value>) {
+    (
+        StatusCode::BAD_REQUEST,
+        Json(json!({ "status": "fail", "message": message.into() })),
+    )
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/kyc/submissions",
+    tag = "KYC",
+    security(
+        ("bearer_auth" = [])
+    ),
+    request_body(content = KycSubmissionUpload, content_type = "multipart/form-data"),
+    responses(
+        (status = 201, description = "Documents submitted for review", body = ApiResponse<KycSubmissionResponse>),
+        (status = 400, description = "Missing or invalid documents", body = String),
+        (status = 401, description = "Unauthorized access", body = String),
+    )
+)]
+#[instrument(skip_all)]
+pub async fn create_kyc_submission(
+    State(data): State<Arc<AppState>>,
+    Extension(user_id): Extension<i64>,
+    mut multipart: Multipart,
+) -> Result<impl IntoResponse, (StatusCode, Json<serde_json::Value>)> {
+    let mut id_card = None;
+    let mut selfie = None;
+
+    while let Some(field) = multipart
+        .next_field()
+        .await
+        .map_err(|e| bad_request(e.body_text()))?
+    {
+        let name = field.name().map(str::to_owned);
+        let content_type = field.content_type().unwrap_or_default().to_string();
+
+        let document = KycDocument {
+            content_type,
+            bytes: field
+                .bytes()
+                .await
+                .map_err(|e| bad_request(e.body_text()))?
+                .to_vec(),
+        };
+
+        match name.as_deref() {
+            Some("id_card") => id_card = Some(document),
+            Some("selfie") => selfie = Some(document),
+            _ => {}
+        }
+    }
+
+    let (Some(id_card), Some(selfie)) = (id_card, selfie) else {
+        return Err(bad_request(
+            "Both `id_card` and `selfie` files are required",
+        ));
+    };
+
+    let body = CreateKycSubmissionRequest {
+        user_id: user_id as i32,
+        id_card,
+        selfie,
+    };
+
+    match data.di_container.kyc_service.create_submission(&body).await {
+        Ok(response) => Ok((StatusCode::CREATED, Json(json!(response)))),
+        Err(e) => Err((StatusCode::BAD_REQUEST, Json(json!(e)))),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/kyc/submissions",
+    tag = "KYC",
+    security(
+        ("bearer_auth" = [])
+    ),
+    responses(
+        (status = 200, description = "KYC submissions of the current user", body = ApiResponse<Vec<KycSubmissionResponse>>),
+        (status = 401, description = "Unauthorized access", body = String),
+        (status = 500, description = "Internal server error", body = String),
+    )
+)]
+#[instrument(skip_all)]
+pub async fn get_my_kyc_submissions(
+    State(data): State<Arc<AppState>>,
+    Extension(user_id): Extension<i64>,
+) -> Result<impl IntoResponse, (StatusCode, Json<serde_json::Value>)> {
+    match data
+        .di_container
+        .kyc_service
+        .get_my_submissions(user_id as i32)
+        .await
+    {
+        Ok(response) => Ok((StatusCode::OK, Json(json!(response)))),
+        Err(e) => Err((StatusCode::INTERNAL_SERVER_ERROR, Json(json!(e)))),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/admin/kyc/submissions",
+    tag = "KYC",
+    security(
+        ("bearer_auth" = [])
+    ),
+    params(FindKycSubmissionsQuery),
+    responses(
+        (status = 200, description = "KYC submissions awaiting or past review", body = ApiResponse<Vec<KycSubmissionResponse>>),
+        (status = 400, description = "Unknown status", body = String),
+        (status = 401, description = "Unauthorized access", body = String),
+        (status = 403, description = "Admin access required", body = String),
+    )
+)]
+#[instrument(skip_all)]
+pub async fn get_kyc_submissions(
+    State(data): State<Arc<AppState>>,
+    Query(query): Query<FindKycSubmissionsQuery>,
+) -> Result<impl IntoResponse, (StatusCode, Json<serde_json::Value>)> {
+    match data
+        .di_container
+        .kyc_service
+        .get_submissions(query.status)
+        .await
+    {
+        Ok(response) => Ok((StatusCode::OK, Json(json!(response)))),
+        Err(e) => Err((StatusCode::BAD_REQUEST, Json(json!(e)))),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/admin/kyc/submissions/{id}/documents/{document}",
+    tag = "KYC",
+    security(
+        ("bearer_auth" = [])
+    ),
+    params(
+        ("id" = i32, Path, description = "KYC submission ID"),
+        ("document" = String, Path, description = "`id_card` or `selfie`")
+    ),
+    responses(
+        (status = 200, description = "The uploaded document", body = Vec<u8>, content_type = "application/octet-stream"),
+        (status = 401, description = "Unauthorized access", body = String),
+        (status = 403, description = "Admin access required", body = String),
+        (status = 404, description = "Submission or document not found", body = String),
+    )
+)]
+#[instrument(skip_all)]
+pub async fn download_kyc_document(
+    State(data): State<Arc<AppState>>,
+    Path((id, document)): Path<(i32, String)>,
+) -> Result<impl IntoResponse, (StatusCode, Json<serde_json::Value>)> {
+    match data
+        .di_container
+        .kyc_service
+        .get_document(id, &document)
+        .await
+    {
+        Ok(file) => Ok((
+            StatusCode::OK,
+            [(header::CONTENT_TYPE, file.content_type)],
+            file.bytes,
+        )),
+        Err(e) => Err((StatusCode::NOT_FOUND, Json(json!(e)))),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/admin/kyc/submissions/{id}/review",
+    tag = "KYC",
+    security(
+        ("bearer_auth" = [])
+    ),
+    params(
+        ("id" = i32, Path, description = "KYC submission ID")
+    ),
+    request_body = ReviewKycSubmissionRequest,
+    responses(
+        (status = 200, description = "Submission moved to the requested status", body = ApiResponse<KycSubmissionResponse>),
+        (status = 400, description = "Invalid review or status change", body = String),
+        (status = 401, description = "Unauthorized access", body = String),
+        (status = 403, description = "Admin access required", body = String),
+    )
+)]
+#[instrument(skip_all)]
+pub async fn review_kyc_submission(
+    State(data): State<Arc<AppState>>,
+    Path(id): Path<i32>,
+    Extension(user_id): Extension<i64>,
+    Json(mut body): Json<ReviewKycSubmissionRequest>,
+) -> Result<impl IntoResponse, (StatusCode, Json<serde_json::Value>)> {
+    body.kyc_submission_id = id;
+    body.reviewer_id = user_id as i32;
+
+    match data.di_container.kyc_service.review_submission(&body).await {
+        Ok(response) => Ok((StatusCode::OK, Json(json!(response)))),
+        Err(e) => Err((StatusCode::BAD_REQUEST, Json(json!(e)))),
+    }
+}
+
+pub fn kyc_routes(app_state: Arc<AppState>) -> OpenApiRouter {
+    OpenApiRouter::new()
+        .route(
+            "/api/kyc/submissions",
+            post(create_kyc_submission).get(get_my_kyc_submissions),
+        )
+        // Two documents of up to 5 MB each plus the multipart framing
+        .layer(DefaultBodyLimit::max(12 * 1024 * 1024))
+        .route_layer(middleware::from_fn_with_state(app_state.clone(), jwt::auth))
+        .with_state(app_state.clone())
+}
+
+pub fn admin_kyc_routes(app_state: Arc<AppState>) -> OpenApiRouter {
+    OpenApiRouter::new()
+        .route("/api/admin/kyc/submissions", get(get_kyc_submissions))
+        .route(
+            "/api/admin/kyc/submissions/{id}/documents/{document}",
+            get(download_kyc_document),
+        )
+        .route(
+            "/api/admin/kyc/submissions/{id}/review",
+            post(review_kyc_submission),
+        )
+        .route_layer(middleware::from_fn_with_state(
+            app_state.clone(),
+            admin::admin,
+        ))
+        .route_layer(middleware::from_fn_with_state(app_state.clone(), jwt::auth))
+        .with_state(app_state.clone())
+}
@@ -0,0 +1,80 @@
+use crate::{
+    domain::response::{
+        limit::{TransactionLimitResponse, UserLimitsResponse},
+        ApiResponse,
+    },
+    middleware::jwt,
+    state::AppState,
+};
+use axum::{
+    extract::{Extension, State},
+    http::StatusCode,
+    middleware,
+    response::IntoResponse,
+    routing::get,
+    Json,
+};
+use serde_json::json;
+use std::sync::Arc;
+use tracing::instrument;
+use utoipa_axum::router::OpenApiRouter;
+
+#[utoipa::path(
+    get,
+    path = "/api/limits",
+    tag = "Limit",
+    security(
+        ("bearer_auth" = [])
+    ),
+    responses(
+        (status = 200, description = "Transaction limits of every KYC level", body = ApiResponse<Vec<TransactionLimitResponse>>),
+        (status = 401, description = "Unauthorized access", body = String),
+        (status = 500, description = "Internal server error", body = String),
+    )
+)]
+#[instrument(skip_all)]
+pub async fn get_limits(
+    State(data): State<Arc<AppState>>,
+) -> Result<impl IntoResponse, (StatusCode, Json<serde_json::Value>)> {
+    match data.di_container.limit_service.get_limits().await {
+        Ok(response) => Ok((StatusCode::OK, Json(json!(response)))),
+        Err(e) => Err((StatusCode::INTERNAL_SERVER_ERROR, Json(json!(e)))),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/limits/me",
+    tag = "Limit",
+    security(
+        ("bearer_auth" = [])
+    ),
+    responses(
+        (status = 200, description = "KYC level and transaction limits of the current user", body = ApiResponse<UserLimitsResponse>),
+        (status = 401, description = "Unauthorized access", body = String),
+        (status = 404, description = "User not found", body = String),
+    )
+)]
+#[instrument(skip_all)]
+pub async fn get_my_limits(
+    State(data): State<Arc<AppState>>,
+    Extension(user_id): Extension<i64>,
+) -> Result<impl IntoResponse, (StatusCode, Json<serde_json::Value>)> {
+    match data
+        .di_container
+        .limit_service
+        .get_user_limits(user_id as i32)
+        .await
+    {
+        Ok(response) => Ok((StatusCode::OK, Json(json!(response)))),
+        Err(e) => Err((StatusCode::NOT_FOUND, Json(json!(e)))),
+    }
+}
+
+pub fn limit_routes(app_state: Arc<AppState>) -> OpenApiRouter {
+    OpenApiRouter::new()
+        .route("/api/limits", get(get_limits))
+        .route("/api/limits/me", get(get_my_limits))
+        .route_layer(middleware::from_fn_with_state(app_state.clone(), jwt::auth))
+        .with_state(app_state.clone())
+}
@@ -0,0 +1,90 @@
+use crate::{
+    domain::{
+        request::merchant::CreateMerchantRequest,
+        response::{merchant::MerchantResponse, ApiResponse},
+    },
+    middleware::jwt,
+    state::AppState,
+};
+use axum::{
+    extract::{Extension, State},
+    http::StatusCode,
+    middleware,
+    response::IntoResponse,
+    routing::{get, post},
+    Json,
+};
+use serde_json::json;
+use std::sync::Arc;
+use tracing::instrument;
+use utoipa_axum::router::OpenApiRouter;
+
+#[utoipa::path(
+    post,
+    path = "/api/merchants",
+    tag = "Merchant",
+    security(
+        ("bearer_auth" = [])
+    ),
+    request_body = CreateMerchantRequest,
+    responses(
+        (status = 201, description = "Current user registered as a merchant", body = ApiResponse<MerchantResponse>),
+        (status = 401, description = "Unauthorized access", body = String),
+        (status = 500, description = "Internal server error", body = String),
+    )
+)]
+#[instrument(skip_all)]
+pub async fn create_merchant(
+    State(data): State<Arc<AppState>>,
+    Extension(user_id): Extension<i64>,
+    Json(mut body): Json<CreateMerchantRequest>,
+) -> Result<impl IntoResponse, (StatusCode, Json<serde_json::Value>)> {
+    body.user_id = user_id as i32;
+
+    match data
+        .di_container
+        .merchant_service
+        .create_merchant(&body)
+        .await
+    {
+        Ok(response) => Ok((StatusCode::CREATED, Json(json!(response)))),
+        Err(e) => Err((StatusCode::INTERNAL_SERVER_ERROR, Json(json!(e)))),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/merchants/me",
+    tag = "Merchant",
+    security(
+        ("bearer_auth" = [])
+    ),
+    responses(
+        (status = 200, description = "Merchant of the current user", body = ApiResponse<MerchantResponse>),
+        (status = 401, description = "Unauthorized access", body = String),
+        (status = 404, description = "Current user is not a merchant", body = String),
+    )
+)]
+#[instrument(skip_all)]
+pub async fn get_my_merchant(
+    State(data): State<Arc<AppState>>,
+    Extension(user_id): Extension<i64>,
+) -> Result<impl IntoResponse, (StatusCode, Json<serde_json::Value>)> {
+    match data
+        .di_container
+        .merchant_service
+        .get_merchant(user_id as i32)
+        .await
+    {
+        Ok(response) => Ok((StatusCode::OK, Json(json!(response)))),
+        Err(e) => Err((StatusCode::NOT_FOUND, Json(json!(e)))),
+    }
+}
+
+pub fn merchant_routes(app_state: Arc<AppState>) -> OpenApiRouter {
+    OpenApiRouter::new()
+        .route("/api/merchants", post(create_merchant))
+        .route("/api/merchants/me", get(get_my_merchant))
+        .route_layer(middleware::from_fn_with_state(app_state.clone(), jwt::auth))
+        .with_state(app_state.clone())
+}
@@ -0,0 +1,35 @@
+use crate::{
+    config::database::ConnectionManager,
+    state::AppState,
+    utils::metrics::{record_db_pool, METRICS},
+};
+use axum::{extract::State, http::header, response::IntoResponse, routing::get};
+use std::sync::Arc;
+use tracing::instrument;
+use utoipa_axum::router::OpenApiRouter;
+
+#[utoipa::path(
+    get,
+    path = "/metrics",
+    tag = "Metrics",
+    responses(
+        (status = 200, description = "Metrics in the Prometheus text format", body = String, content_type = "text/plain"),
+    )
+)]
+#[instrument(skip_all)]
+pub async fn get_metrics(State(data): State<Arc<AppState>>) -> impl IntoResponse {
+    if let Some(usage) = ConnectionManager::pool_usage(&data.db_pool) {
+        record_db_pool(usage.active, usage.idle, usage.max);
+    }
+
+    (
+        [(header::CONTENT_TYPE, "text/plain; version=0.0.4")],
+        METRICS.render(),
+    )
+}
+
+pub fn metrics_routes(app_state: Arc<AppState>) -> OpenApiRouter {
+    OpenApiRouter::new()
+        .route("/metrics", get(get_metrics))
+        .with_state(app_state.clone())
+}
@@ -1,12 +1,40 @@
+mod audit;
 mod auth;
+mod bank_account;
+mod beneficiary;
+mod fee;
+mod fraud;
+mod health;
+mod kyc;
+mod limit;
+mod merchant;
+mod metrics;
+mod payment_intent;
+mod qris;
+mod refund;
 mod saldo;
+mod scheduled_transfer;
+mod screening;
+mod settlement;
 mod topup;
 mod transfer;
+mod transfer_batch;
 mod user;
+mod virtual_account;
+mod webhook;
 mod withdraw;
 
-use crate::state::AppState;
-use std::sync::Arc;
+use crate::{
+    config::config::Config,
+    middleware::{
+        cors::cors,
+        rate_limit::{rate_limit, RateLimiter},
+    },
+    state::AppState,
+    utils::shutdown::Shutdown,
+};
+use axum::middleware;
+use std::{net::SocketAddr, sync::Arc};
 use tokio::net::TcpListener;
 use utoipa::{
     openapi::security:: SecurityScheme,
@@ -15,19 +43,79 @@ use utoipa::{
 use utoipa_axum::router::OpenApiRouter;
 use utoipa_swagger_ui::SwaggerUi;
 
+pub use self::audit::admin_audit_routes;
 pub use self::auth::auth_routes;
+pub use self::bank_account::bank_account_routes;
+pub use self::beneficiary::beneficiary_routes;
+pub use self::fee::fee_routes;
+pub use self::fraud::admin_fraud_routes;
+pub use self::health::health_routes;
+pub use self::kyc::{admin_kyc_routes, kyc_routes};
+pub use self::limit::limit_routes;
+pub use self::merchant::merchant_routes;
+pub use self::metrics::metrics_routes;
+pub use self::payment_intent::payment_intent_routes;
+pub use self::qris::qris_routes;
+pub use self::refund::refund_routes;
 pub use self::saldo::saldos_routes;
-pub use self::topup::topup_routes;
+pub use self::scheduled_transfer::scheduled_transfer_routes;
+pub use self::screening::admin_screening_routes;
+pub use self::settlement::settlement_routes;
+pub use self::topup::{admin_topup_routes, topup_routes};
 pub use self::transfer::transfers_routes;
+pub use self::transfer_batch::transfer_batch_routes;
 pub use self::user::users_routes;
+pub use self::virtual_account::{admin_virtual_account_routes, virtual_account_routes};
+pub use self::webhook::webhook_routes;
 pub use self::withdraw::withdraw_routes;
 
 #[derive(OpenApi)]
 #[openapi(
     paths(
+        audit::get_audit_events,
+        audit::verify_audit_chain,
         auth::login_user_handler, 
         auth::get_me_handler, 
         auth::register_user_handler,
+        bank_account::get_bank_accounts,
+        bank_account::create_bank_account,
+        bank_account::delete_bank_account,
+        beneficiary::get_beneficiaries,
+        beneficiary::get_beneficiary,
+        beneficiary::create_beneficiary,
+        beneficiary::update_beneficiary,
+        beneficiary::delete_beneficiary,
+        fee::get_fee_rules,
+        fee::get_fee_quote,
+        fraud::get_fraud_rules,
+        fraud::get_fraud_reviews,
+        fraud::approve_fraud_review,
+        fraud::decline_fraud_review,
+        health::liveness,
+        health::readiness,
+        kyc::create_kyc_submission,
+        kyc::get_my_kyc_submissions,
+        kyc::get_kyc_submissions,
+        kyc::download_kyc_document,
+        kyc::review_kyc_submission,
+        limit::get_limits,
+        screening::get_screening_results,
+        screening::review_screening_result,
+        screening::reload_watchlist,
+        limit::get_my_limits,
+        merchant::create_merchant,
+        merchant::get_my_merchant,
+        metrics::get_metrics,
+        payment_intent::create_payment_intent,
+        payment_intent::get_payment_intents,
+        payment_intent::get_payment_intent,
+        payment_intent::confirm_payment_intent,
+        payment_intent::cancel_payment_intent,
+        qris::create_qris,
+        qris::get_qris_image,
+        qris::pay_qris,
+        refund::create_refund,
+        refund::get_refunds,
         saldo::get_saldos,
         saldo::get_saldo,
         saldo::get_saldo_users,
@@ -35,6 +123,11 @@ pub use self::withdraw::withdraw_routes;
         saldo::create_saldo,
         saldo::update_saldo,
         saldo::delete_saldo,
+        scheduled_transfer::get_scheduled_transfers,
+        scheduled_transfer::get_scheduled_transfer,
+        scheduled_transfer::create_scheduled_transfer,
+        scheduled_transfer::update_scheduled_transfer,
+        scheduled_transfer::delete_scheduled_transfer,
         topup::get_topups,
         topup::get_topup,
         topup::get_topup_users,
@@ -42,18 +135,38 @@ pub use self::withdraw::withdraw_routes;
         topup::create_topup,
         topup::update_topup,
         topup::delete_topup,
+        topup::payment_callback,
+        topup::reverse_topup,
         transfer::get_transfers,
         transfer::get_transfer,
         transfer::get_transfer_users,
         transfer::get_transfer_user,
         transfer::create_transfer,
+        transfer::get_transfer_recipient,
+        transfer::create_transfer_by_account,
         transfer::update_transfer,
         transfer::delete_transfer,
+        transfer_batch::create_transfer_batch,
+        transfer_batch::get_transfer_batch,
+        transfer_batch::download_transfer_batch_result,
         user::get_users,
         user::get_user,
         user::create_user,
         user::update_user,
         user::delete_user,
+        virtual_account::get_virtual_accounts,
+        virtual_account::create_virtual_account,
+        virtual_account::get_suspense_payments,
+        virtual_account::resolve_suspense_payment,
+        virtual_account::virtual_account_payment,
+        settlement::get_settlements,
+        settlement::get_settlement,
+        settlement::download_settlement_report,
+        webhook::get_webhook_endpoints,
+        webhook::create_webhook_endpoint,
+        webhook::delete_webhook_endpoint,
+        webhook::get_webhook_deliveries,
+        webhook::replay_webhook_delivery,
         withdraw::get_withdraws,
         withdraw::get_withdraw,
         withdraw::get_withdraw_users,
@@ -65,10 +178,28 @@ pub use self::withdraw::withdraw_routes;
     modifiers(&SecurityAddon),
     tags(
         (name = "Auth", description = "Authentication endpoints"),
+        (name = "Audit", description = "Audit trail query and hash chain verification endpoints"),
         (name = "User", description = "User management endpoints"),
         (name = "Saldo", description = "Balance management endpoints"),
         (name = "Topup", description = "Top up endpoints"),
         (name = "Transfer", description = "Transfer endpoints"),
+        (name = "Beneficiary", description = "Saved beneficiary endpoints"),
+        (name = "Scheduled Transfer", description = "Scheduled and recurring transfer endpoints"),
+        (name = "Bank Account", description = "Payout bank account endpoints"),
+        (name = "Virtual Account", description = "Virtual account top-up endpoints"),
+        (name = "QRIS", description = "Dynamic QRIS merchant payment endpoints"),
+        (name = "Merchant", description = "Merchant registration endpoints"),
+        (name = "Metrics", description = "Prometheus metrics endpoint"),
+        (name = "Payment Intent", description = "Merchant checkout endpoints"),
+        (name = "Refund", description = "Merchant payment refund endpoints"),
+        (name = "Fee", description = "Fee rule and fee quote endpoints"),
+        (name = "Fraud", description = "Fraud rule and held transaction review endpoints"),
+        (name = "Health", description = "Liveness and readiness probe endpoints"),
+        (name = "KYC", description = "KYC document submission and admin review endpoints"),
+        (name = "Limit", description = "KYC tier transaction limit endpoints"),
+        (name = "Screening", description = "Sanctions watchlist match review endpoints"),
+        (name = "Settlement", description = "Daily merchant settlement and report endpoints"),
+        (name = "Webhook", description = "Merchant webhook endpoint and delivery log endpoints"),
         (name = "Withdraw", description = "Withdrawal endpoints")
     )
 )]
@@ -93,29 +224,99 @@ impl Modify for SecurityAddon {
 pub struct AppRouter;
 
 impl AppRouter {
-    pub async fn serve(port: u16, app_state: AppState) -> Result<(), Box<dyn std::error::Error>> {
+    /// Serves until `shutdown` is triggered, then stops accepting connections
+    /// and returns once the requests in flight have been answered.
+    pub async fn serve(
+        config: &Config,
+        app_state: AppState,
+        shutdown: Shutdown,
+    ) -> Result<(), Box<dyn std::error::Error>> {
         let shared_state = Arc::new(app_state);
 
-        let (router, api) = OpenApiRouter::with_openapi(ApiDoc::openapi())
+        let mut routes = OpenApiRouter::with_openapi(ApiDoc::openapi())
             .merge(auth_routes(shared_state.clone()))
             .merge(users_routes(shared_state.clone()))
             .merge(saldos_routes(shared_state.clone()))
             .merge(topup_routes(shared_state.clone()))
+            .merge(admin_topup_routes(shared_state.clone()))
             .merge(transfers_routes(shared_state.clone()))
+            .merge(beneficiary_routes(shared_state.clone()))
+            .merge(transfer_batch_routes(shared_state.clone()))
+            .merge(scheduled_transfer_routes(shared_state.clone()))
+            .merge(bank_account_routes(shared_state.clone()))
             .merge(withdraw_routes(shared_state.clone()))
-            .split_for_parts();
+            .merge(virtual_account_routes(shared_state.clone()))
+            .merge(admin_virtual_account_routes(shared_state.clone()))
+            .merge(qris_routes(shared_state.clone()))
+            .merge(merchant_routes(shared_state.clone()))
+            .merge(payment_intent_routes(shared_state.clone()))
+            .merge(refund_routes(shared_state.clone()))
+            .merge(fee_routes(shared_state.clone()))
+            .merge(admin_fraud_routes(shared_state.clone()))
+            .merge(admin_audit_routes(shared_state.clone()))
+            .merge(kyc_routes(shared_state.clone()))
+            .merge(admin_kyc_routes(shared_state.clone()))
+            .merge(limit_routes(shared_state.clone()))
+            .merge(admin_screening_routes(shared_state.clone()))
+            .merge(settlement_routes(shared_state.clone()))
+            .merge(webhook_routes(shared_state.clone()))
+            .merge(health_routes(shared_state.clone()));
+
+        if config.features.metrics {
+            routes = routes.merge(metrics_routes(shared_state.clone()));
+        }
+
+        let (router, api) = routes.split_for_parts();
+
+        // Runs per route so the matched path is known
+        let router = router.route_layer(middleware::from_fn_with_state(
+            shared_state.clone(),
+            crate::middleware::audit::audit,
+        ));
+
+        let mut app = router;
 
-        let app = router
-            .merge(SwaggerUi::new("/swagger-ui").url("/api-docs/openapi.json", api.clone()));
+        if config.features.swagger_ui {
+            app = app
+                .merge(SwaggerUi::new("/swagger-ui").url("/api-docs/openapi.json", api.clone()));
+        }
 
+        if config.database_replica_url.is_some() {
+            app = app.layer(middleware::from_fn(
+                crate::middleware::read_replica::read_replica,
+            ));
+        }
+
+        if config.rate_limit.requests_per_minute > 0 {
+            let limiter = Arc::new(RateLimiter::new(config.rate_limit.requests_per_minute));
+            app = app.layer(middleware::from_fn_with_state(limiter, rate_limit));
+        }
+
+        if let Some(cors) = cors(&config.cors.allowed_origins) {
+            app = app.layer(cors);
+        }
+
+        let app = app
+            .layer(middleware::from_fn(crate::middleware::metrics::track_http))
+            .layer(middleware::from_fn(crate::middleware::request_id::request_id));
+
+        let port = config.port;
         let addr = format!("0.0.0.0:{}", port);
         let listener = TcpListener::bind(addr).await?;
 
         println!("Server running on http://{}", listener.local_addr()?);
-        println!("API Documentation available at:");
-        println!("- Swagger UI: http://localhost:{}/swagger-ui", port);
 
-        axum::serve(listener, app).await?;
+        if config.features.swagger_ui {
+            println!("API Documentation available at:");
+            println!("- Swagger UI: http://localhost:{}/swagger-ui", port);
+        }
+
+        axum::serve(
+            listener,
+            app.into_make_service_with_connect_info::<SocketAddr>(),
+        )
+        .with_graceful_shutdown(async move { shutdown.triggered().await })
+        .await?;
         Ok(())
     }
 }
@@ -0,0 +1,199 @@
+use crate::{
+    domain::{
+        request::payment_intent::CreatePaymentIntentRequest,
+        response::{payment_intent::PaymentIntentResponse, ApiResponse},
+    },
+    middleware::jwt,
+    state::AppState,
+};
+use axum::{
+    extract::{Extension, Path, State},
+    http::StatusCode,
+    middleware,
+    response::IntoResponse,
+    routing::{get, post},
+    Json,
+};
+use serde_json::json;
+use std::sync::Arc;
+use tracing::instrument;
+use utoipa_axum::router::OpenApiRouter;
+
+#[utoipa::path(
+    post,
+    path = "/api/payment-intents",
+    tag = "Payment Intent",
+    security(
+        ("bearer_auth" = [])
+    ),
+    request_body = CreatePaymentIntentRequest,
+    responses(
+        (status = 201, description = "Payment intent awaiting customer confirmation", body = ApiResponse<PaymentIntentResponse>),
+        (status = 401, description = "Unauthorized access", body = String),
+        (status = 500, description = "Internal server error", body = String),
+    )
+)]
+#[instrument(skip_all)]
+pub async fn create_payment_intent(
+    State(data): State<Arc<AppState>>,
+    Extension(user_id): Extension<i64>,
+    Json(mut body): Json<CreatePaymentIntentRequest>,
+) -> Result<impl IntoResponse, (StatusCode, Json<serde_json::Value>)> {
+    body.user_id = user_id as i32;
+
+    match data
+        .di_container
+        .payment_intent_service
+        .create_payment_intent(&body)
+        .await
+    {
+        Ok(response) => Ok((StatusCode::CREATED, Json(json!(response)))),
+        Err(e) => Err((StatusCode::INTERNAL_SERVER_ERROR, Json(json!(e)))),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/payment-intents",
+    tag = "Payment Intent",
+    security(
+        ("bearer_auth" = [])
+    ),
+    responses(
+        (status = 200, description = "Payment intents of the current user's merchant", body = ApiResponse<Vec<PaymentIntentResponse>>),
+        (status = 401, description = "Unauthorized access", body = String),
+        (status = 500, description = "Internal server error", body = String),
+    )
+)]
+#[instrument(skip_all)]
+pub async fn get_payment_intents(
+    State(data): State<Arc<AppState>>,
+    Extension(user_id): Extension<i64>,
+) -> Result<impl IntoResponse, (StatusCode, Json<serde_json::Value>)> {
+    match data
+        .di_container
+        .payment_intent_service
+        .get_payment_intents(user_id as i32)
+        .await
+    {
+        Ok(response) => Ok((StatusCode::OK, Json(json!(response)))),
+        Err(e) => Err((StatusCode::INTERNAL_SERVER_ERROR, Json(json!(e)))),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/payment-intents/{id}",
+    tag = "Payment Intent",
+    security(
+        ("bearer_auth" = [])
+    ),
+    params(
+        ("id" = i32, Path, description = "Payment intent ID")
+    ),
+    responses(
+        (status = 200, description = "Payment intent details", body = ApiResponse<PaymentIntentResponse>),
+        (status = 401, description = "Unauthorized access", body = String),
+        (status = 404, description = "Payment intent not found", body = String),
+    )
+)]
+#[instrument(skip_all)]
+pub async fn get_payment_intent(
+    State(data): State<Arc<AppState>>,
+    Path(id): Path<i32>,
+) -> Result<impl IntoResponse, (StatusCode, Json<serde_json::Value>)> {
+    match data
+        .di_container
+        .payment_intent_service
+        .get_payment_intent(id)
+        .await
+    {
+        Ok(response) => Ok((StatusCode::OK, Json(json!(response)))),
+        Err(e) => Err((StatusCode::NOT_FOUND, Json(json!(e)))),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/payment-intents/{id}/confirm",
+    tag = "Payment Intent",
+    security(
+        ("bearer_auth" = [])
+    ),
+    params(
+        ("id" = i32, Path, description = "Payment intent ID")
+    ),
+    responses(
+        (status = 200, description = "Payment intent paid from the current user's saldo", body = ApiResponse<PaymentIntentResponse>),
+        (status = 202, description = "Held for fraud review, the intent stays processing", body = String),
+        (status = 401, description = "Unauthorized access", body = String),
+        (status = 500, description = "Internal server error", body = String),
+    )
+)]
+#[instrument(skip_all)]
+pub async fn confirm_payment_intent(
+    State(data): State<Arc<AppState>>,
+    Extension(user_id): Extension<i64>,
+    Path(id): Path<i32>,
+) -> Result<impl IntoResponse, (StatusCode, Json<serde_json::Value>)> {
+    match data
+        .di_container
+        .payment_intent_service
+        .confirm_payment_intent(id, user_id as i32)
+        .await
+    {
+        Ok(response) => Ok((StatusCode::OK, Json(json!(response)))),
+        Err(e) if e.status == "pending" => Ok((StatusCode::ACCEPTED, Json(json!(e)))),
+        Err(e) => Err((StatusCode::INTERNAL_SERVER_ERROR, Json(json!(e)))),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/payment-intents/{id}/cancel",
+    tag = "Payment Intent",
+    security(
+        ("bearer_auth" = [])
+    ),
+    params(
+        ("id" = i32, Path, description = "Payment intent ID")
+    ),
+    responses(
+        (status = 200, description = "Payment intent canceled by its merchant", body = ApiResponse<PaymentIntentResponse>),
+        (status = 401, description = "Unauthorized access", body = String),
+        (status = 500, description = "Internal server error", body = String),
+    )
+)]
+#[instrument(skip_all)]
+pub async fn cancel_payment_intent(
+    State(data): State<Arc<AppState>>,
+    Extension(user_id): Extension<i64>,
+    Path(id): Path<i32>,
+) -> Result<impl IntoResponse, (StatusCode, Json<serde_json::Value>)> {
+    match data
+        .di_container
+        .payment_intent_service
+        .cancel_payment_intent(id, user_id as i32)
+        .await
+    {
+        Ok(response) => Ok((StatusCode::OK, Json(json!(response)))),
+        Err(e) => Err((StatusCode::INTERNAL_SERVER_ERROR, Json(json!(e)))),
+    }
+}
+
+pub fn payment_intent_routes(app_state: Arc<AppState>) -> OpenApiRouter {
+    OpenApiRouter::new()
+        .route("/api/payment-intents", get(get_payment_intents))
+        .route("/api/payment-intents", post(create_payment_intent))
+        .route("/api/payment-intents/{id}", get(get_payment_intent))
+        .route(
+            "/api/payment-intents/{id}/confirm",
+            post(confirm_payment_intent),
+        )
+        .route(
+            "/api/payment-intents/{id}/cancel",
+            post(cancel_payment_intent),
+        )
+        .route_layer(middleware::from_fn_with_state(app_state.clone(), jwt::auth))
+        .with_state(app_state.clone())
+}
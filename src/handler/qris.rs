@@ -0,0 +1,125 @@
+use crate::{
+    domain::{
+        request::qris::{CreateQrisRequest, PayQrisRequest},
+        response::{qris::QrisResponse, ApiResponse},
+    },
+    middleware::jwt,
+    state::AppState,
+};
+use axum::{
+    extract::{Extension, Path, State},
+    http::{header, StatusCode},
+    middleware,
+    response::IntoResponse,
+    routing::{get, post},
+    Json,
+};
+use serde_json::json;
+use std::sync::Arc;
+use tracing::instrument;
+use utoipa_axum::router::OpenApiRouter;
+
+#[utoipa::path(
+    post,
+    path = "/api/qris",
+    tag = "QRIS",
+    security(
+        ("bearer_auth" = [])
+    ),
+    request_body = CreateQrisRequest,
+    responses(
+        (status = 201, description = "Dynamic QRIS issued for the current user's merchant", body = ApiResponse<QrisResponse>),
+        (status = 401, description = "Unauthorized access", body = String),
+        (status = 500, description = "Internal server error", body = String),
+    )
+)]
+#[instrument(skip_all)]
+pub async fn create_qris(
+    State(data): State<Arc<AppState>>,
+    Extension(user_id): Extension<i64>,
+    Json(mut body): Json<CreateQrisRequest>,
+) -> Result<impl IntoResponse, (StatusCode, Json<serde_json::Value>)> {
+    body.user_id = user_id as i32;
+
+    match data.di_container.qris_service.create_qris(&body).await {
+        Ok(response) => Ok((StatusCode::CREATED, Json(json!(response)))),
+        Err(e) => Err((StatusCode::INTERNAL_SERVER_ERROR, Json(json!(e)))),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/qris/{reference}/image/{format}",
+    tag = "QRIS",
+    security(
+        ("bearer_auth" = [])
+    ),
+    params(
+        ("reference" = String, Path, description = "QRIS reference"),
+        ("format" = String, Path, description = "`png` or `svg`")
+    ),
+    responses(
+        (status = 200, description = "PNG or SVG QR code of the payload", body = Vec<u8>, content_type = "image/png"),
+        (status = 401, description = "Unauthorized access", body = String),
+        (status = 404, description = "QRIS not found", body = String),
+    )
+)]
+#[instrument(skip_all)]
+pub async fn get_qris_image(
+    State(data): State<Arc<AppState>>,
+    Path((reference, format)): Path<(String, String)>,
+    Extension(user_id): Extension<i64>,
+) -> Result<impl IntoResponse, (StatusCode, Json<serde_json::Value>)> {
+    match data
+        .di_container
+        .qris_service
+        .get_qris_image(&reference, user_id as i32, &format)
+        .await
+    {
+        Ok(image) => Ok((
+            StatusCode::OK,
+            [(header::CONTENT_TYPE, image.content_type)],
+            image.body,
+        )),
+        Err(e) => Err((StatusCode::NOT_FOUND, Json(json!(e)))),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/qris/pay",
+    tag = "QRIS",
+    security(
+        ("bearer_auth" = [])
+    ),
+    request_body = PayQrisRequest,
+    responses(
+        (status = 200, description = "QRIS paid from the current user's saldo", body = ApiResponse<QrisResponse>),
+        (status = 202, description = "Held for fraud review, the QRIS stays processing", body = String),
+        (status = 401, description = "Unauthorized access", body = String),
+        (status = 500, description = "Internal server error", body = String),
+    )
+)]
+#[instrument(skip_all)]
+pub async fn pay_qris(
+    State(data): State<Arc<AppState>>,
+    Extension(user_id): Extension<i64>,
+    Json(mut body): Json<PayQrisRequest>,
+) -> Result<impl IntoResponse, (StatusCode, Json<serde_json::Value>)> {
+    body.payer_id = user_id as i32;
+
+    match data.di_container.qris_service.pay_qris(&body).await {
+        Ok(response) => Ok((StatusCode::OK, Json(json!(response)))),
+        Err(e) if e.status == "pending" => Ok((StatusCode::ACCEPTED, Json(json!(e)))),
+        Err(e) => Err((StatusCode::INTERNAL_SERVER_ERROR, Json(json!(e)))),
+    }
+}
+
+pub fn qris_routes(app_state: Arc<AppState>) -> OpenApiRouter {
+    OpenApiRouter::new()
+        .route("/api/qris", post(create_qris))
+        .route("/api/qris/pay", post(pay_qris))
+        .route("/api/qris/{reference}/image/{format}", get(get_qris_image))
+        .route_layer(middleware::from_fn_with_state(app_state.clone(), jwt::auth))
+        .with_state(app_state.clone())
+}
@@ -0,0 +1,94 @@
+use crate::{
+    domain::{
+        request::refund::CreateRefundRequest,
+        response::{refund::RefundResponse, ApiResponse},
+    },
+    middleware::jwt,
+    state::AppState,
+};
+use axum::{
+    extract::{Extension, Path, State},
+    http::StatusCode,
+    middleware,
+    response::IntoResponse,
+    routing::{get, post},
+    Json,
+};
+use serde_json::json;
+use std::sync::Arc;
+use tracing::instrument;
+use utoipa_axum::router::OpenApiRouter;
+
+#[utoipa::path(
+    post,
+    path = "/api/payments/{id}/refunds",
+    tag = "Refund",
+    security(
+        ("bearer_auth" = [])
+    ),
+    params(
+        ("id" = i32, Path, description = "Payment intent ID")
+    ),
+    request_body = CreateRefundRequest,
+    responses(
+        (status = 201, description = "Refund paid back to the customer", body = ApiResponse<RefundResponse>),
+        (status = 401, description = "Unauthorized access", body = String),
+        (status = 500, description = "Internal server error", body = String),
+    )
+)]
+#[instrument(skip_all)]
+pub async fn create_refund(
+    State(data): State<Arc<AppState>>,
+    Extension(user_id): Extension<i64>,
+    Path(id): Path<i32>,
+    Json(mut body): Json<CreateRefundRequest>,
+) -> Result<impl IntoResponse, (StatusCode, Json<serde_json::Value>)> {
+    body.payment_intent_id = id;
+    body.user_id = user_id as i32;
+
+    match data.di_container.refund_service.create_refund(&body).await {
+        Ok(response) => Ok((StatusCode::CREATED, Json(json!(response)))),
+        Err(e) => Err((StatusCode::INTERNAL_SERVER_ERROR, Json(json!(e)))),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/payments/{id}/refunds",
+    tag = "Refund",
+    security(
+        ("bearer_auth" = [])
+    ),
+    params(
+        ("id" = i32, Path, description = "Payment intent ID")
+    ),
+    responses(
+        (status = 200, description = "Refunds of the payment", body = ApiResponse<Vec<RefundResponse>>),
+        (status = 401, description = "Unauthorized access", body = String),
+        (status = 404, description = "Payment not found", body = String),
+    )
+)]
+#[instrument(skip_all)]
+pub async fn get_refunds(
+    State(data): State<Arc<AppState>>,
+    Extension(user_id): Extension<i64>,
+    Path(id): Path<i32>,
+) -> Result<impl IntoResponse, (StatusCode, Json<serde_json::Value>)> {
+    match data
+        .di_container
+        .refund_service
+        .get_refunds(id, user_id as i32)
+        .await
+    {
+        Ok(response) => Ok((StatusCode::OK, Json(json!(response)))),
+        Err(e) => Err((StatusCode::NOT_FOUND, Json(json!(e)))),
+    }
+}
+
+pub fn refund_routes(app_state: Arc<AppState>) -> OpenApiRouter {
+    OpenApiRouter::new()
+        .route("/api/payments/{id}/refunds", get(get_refunds))
+        .route("/api/payments/{id}/refunds", post(create_refund))
+        .route_layer(middleware::from_fn_with_state(app_state.clone(), jwt::auth))
+        .with_state(app_state.clone())
+}
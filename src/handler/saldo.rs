@@ -17,6 +17,7 @@ use axum::{
 use serde_json::json;
 use utoipa_axum::router::OpenApiRouter;
 use std::sync::Arc;
+use tracing::instrument;
 
 #[utoipa::path(
     get,
@@ -31,6 +32,7 @@ use std::sync::Arc;
         (status = 500, description = "Internal server error", body = String),
     )
 )]
+#[instrument(skip_all)]
 pub async fn get_saldos(
     State(data): State<Arc<AppState>>,
 ) -> Result<impl IntoResponse, (StatusCode, Json<serde_json::Value>)> {
@@ -56,6 +58,7 @@ pub async fn get_saldos(
         (status = 404, description = "Saldo record not found", body = String),
     )
 )]
+#[instrument(skip_all)]
 pub async fn get_saldo(
     State(data): State<Arc<AppState>>,
     Path(id): Path<i32>,
@@ -84,6 +87,7 @@ pub async fn get_saldo(
         (status = 404, description = "Saldo records not found for the user", body = String),
     )
 )]
+#[instrument(skip_all)]
 pub async fn get_saldo_users(
     State(data): State<Arc<AppState>>,
     Path(id): Path<i32>,
@@ -112,6 +116,7 @@ pub async fn get_saldo_users(
         (status = 500, description = "Internal server error", body = String),
     )
 )]
+#[instrument(skip_all)]
 pub async fn get_saldo_user(
     State(data): State<Arc<AppState>>,
     Path(id): Path<i32>,
@@ -138,6 +143,7 @@ pub async fn get_saldo_user(
         (status = 500, description = "Internal server error", body = String),
     )
 )]
+#[instrument(skip_all)]
 pub async fn create_saldo(
     State(data): State<Arc<AppState>>,
     Json(body): Json<CreateSaldoRequest>,
@@ -165,6 +171,7 @@ pub async fn create_saldo(
         (status = 500, description = "Internal server error", body = String),
     )
 )]
+#[instrument(skip_all)]
 pub async fn update_saldo(
     State(data): State<Arc<AppState>>,
     Path(id): Path<i32>,
@@ -195,6 +202,7 @@ pub async fn update_saldo(
         (status = 500, description = "Internal server error", body = String),
     )
 )]
+#[instrument(skip_all)]
 pub async fn delete_saldo(
     State(data): State<Arc<AppState>>,
     Path(id): Path<i32>,
@@ -0,0 +1,214 @@
+use crate::{
+    domain::{
+        request::scheduled_transfer::{
+            CreateScheduledTransferRequest, UpdateScheduledTransferRequest,
+        },
+        response::{
+            scheduled_transfer::{ScheduledTransferDetailResponse, ScheduledTransferResponse},
+            ApiResponse,
+        },
+    },
+    middleware::jwt,
+    state::AppState,
+};
+use axum::{
+    extract::{Extension, Path, State},
+    http::StatusCode,
+    middleware,
+    response::IntoResponse,
+    routing::{delete, get, post, put},
+    Json,
+};
+use serde_json::json;
+use std::sync::Arc;
+use tracing::instrument;
+use utoipa_axum::router::OpenApiRouter;
+
+#[utoipa::path(
+    get,
+    path = "/api/scheduled-transfers",
+    tag = "Scheduled Transfer",
+    security(
+        ("bearer_auth" = [])
+    ),
+    responses(
+        (status = 200, description = "Scheduled transfers of the current user", body = ApiResponse<Vec<ScheduledTransferResponse>>),
+        (status = 401, description = "Unauthorized access", body = String),
+        (status = 500, description = "Internal server error", body = String),
+    )
+)]
+#[instrument(skip_all)]
+pub async fn get_scheduled_transfers(
+    State(data): State<Arc<AppState>>,
+    Extension(user_id): Extension<i64>,
+) -> Result<impl IntoResponse, (StatusCode, Json<serde_json::Value>)> {
+    match data
+        .di_container
+        .scheduled_transfer_service
+        .get_scheduled_transfers(user_id as i32)
+        .await
+    {
+        Ok(response) => Ok((StatusCode::OK, Json(json!(response)))),
+        Err(e) => Err((StatusCode::INTERNAL_SERVER_ERROR, Json(json!(e)))),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/scheduled-transfers/{id}",
+    tag = "Scheduled Transfer",
+    security(
+        ("bearer_auth" = [])
+    ),
+    params(
+        ("id" = i32, Path, description = "Scheduled transfer ID")
+    ),
+    responses(
+        (status = 200, description = "Scheduled transfer with its run attempts", body = ApiResponse<ScheduledTransferDetailResponse>),
+        (status = 401, description = "Unauthorized access", body = String),
+        (status = 404, description = "Scheduled transfer not found", body = String),
+    )
+)]
+#[instrument(skip_all)]
+pub async fn get_scheduled_transfer(
+    State(data): State<Arc<AppState>>,
+    Path(id): Path<i32>,
+    Extension(user_id): Extension<i64>,
+) -> Result<impl IntoResponse, (StatusCode, Json<serde_json::Value>)> {
+    match data
+        .di_container
+        .scheduled_transfer_service
+        .get_scheduled_transfer(id, user_id as i32)
+        .await
+    {
+        Ok(response) => Ok((StatusCode::OK, Json(json!(response)))),
+        Err(e) => Err((StatusCode::NOT_FOUND, Json(json!(e)))),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/scheduled-transfers",
+    tag = "Scheduled Transfer",
+    security(
+        ("bearer_auth" = [])
+    ),
+    request_body = CreateScheduledTransferRequest,
+    responses(
+        (status = 201, description = "Scheduled transfer created", body = ApiResponse<ScheduledTransferResponse>),
+        (status = 401, description = "Unauthorized access", body = String),
+        (status = 500, description = "Internal server error", body = String),
+    )
+)]
+#[instrument(skip_all)]
+pub async fn create_scheduled_transfer(
+    State(data): State<Arc<AppState>>,
+    Extension(user_id): Extension<i64>,
+    Json(mut body): Json<CreateScheduledTransferRequest>,
+) -> Result<impl IntoResponse, (StatusCode, Json<serde_json::Value>)> {
+    body.transfer_from = user_id as i32;
+
+    match data
+        .di_container
+        .scheduled_transfer_service
+        .create_scheduled_transfer(&body)
+        .await
+    {
+        Ok(response) => Ok((StatusCode::CREATED, Json(json!(response)))),
+        Err(e) => Err((StatusCode::INTERNAL_SERVER_ERROR, Json(json!(e)))),
+    }
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/scheduled-transfers/{id}",
+    tag = "Scheduled Transfer",
+    security(
+        ("bearer_auth" = [])
+    ),
+    params(
+        ("id" = i32, Path, description = "Scheduled transfer ID")
+    ),
+    request_body = UpdateScheduledTransferRequest,
+    responses(
+        (status = 200, description = "Scheduled transfer updated", body = ApiResponse<ScheduledTransferResponse>),
+        (status = 401, description = "Unauthorized access", body = String),
+        (status = 500, description = "Internal server error", body = String),
+    )
+)]
+#[instrument(skip_all)]
+pub async fn update_scheduled_transfer(
+    State(data): State<Arc<AppState>>,
+    Path(id): Path<i32>,
+    Extension(user_id): Extension<i64>,
+    Json(mut body): Json<UpdateScheduledTransferRequest>,
+) -> Result<impl IntoResponse, (StatusCode, Json<serde_json::Value>)> {
+    body.schedule_id = id;
+    body.transfer_from = user_id as i32;
+
+    match data
+        .di_container
+        .scheduled_transfer_service
+        .update_scheduled_transfer(&body)
+        .await
+    {
+        Ok(response) => Ok((StatusCode::OK, Json(json!(response)))),
+        Err(e) => Err((StatusCode::INTERNAL_SERVER_ERROR, Json(json!(e)))),
+    }
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/scheduled-transfers/{id}",
+    tag = "Scheduled Transfer",
+    security(
+        ("bearer_auth" = [])
+    ),
+    params(
+        ("id" = i32, Path, description = "Scheduled transfer ID")
+    ),
+    responses(
+        (status = 200, description = "Scheduled transfer deleted", body = serde_json::Value),
+        (status = 401, description = "Unauthorized access", body = String),
+        (status = 404, description = "Scheduled transfer not found", body = String),
+    )
+)]
+#[instrument(skip_all)]
+pub async fn delete_scheduled_transfer(
+    State(data): State<Arc<AppState>>,
+    Path(id): Path<i32>,
+    Extension(user_id): Extension<i64>,
+) -> Result<impl IntoResponse, (StatusCode, Json<serde_json::Value>)> {
+    match data
+        .di_container
+        .scheduled_transfer_service
+        .delete_scheduled_transfer(id, user_id as i32)
+        .await
+    {
+        Ok(_) => Ok((
+            StatusCode::OK,
+            Json(json!({
+                "status": "success",
+                "message": "Scheduled transfer deleted successfully"
+            })),
+        )),
+        Err(e) => Err((StatusCode::NOT_FOUND, Json(json!(e)))),
+    }
+}
+
+pub fn scheduled_transfer_routes(app_state: Arc<AppState>) -> OpenApiRouter {
+    OpenApiRouter::new()
+        .route("/api/scheduled-transfers", get(get_scheduled_transfers))
+        .route("/api/scheduled-transfers", post(create_scheduled_transfer))
+        .route("/api/scheduled-transfers/{id}", get(get_scheduled_transfer))
+        .route(
+            "/api/scheduled-transfers/{id}",
+            put(update_scheduled_transfer),
+        )
+        .route(
+            "/api/scheduled-transfers/{id}",
+            delete(delete_scheduled_transfer),
+        )
+        .route_layer(middleware::from_fn_with_state(app_state.clone(), jwt::auth))
+        .with_state(app_state.clone())
+}
@@ -0,0 +1,136 @@
+use crate::{
+    domain::{
+        request::screening::{FindScreeningResultsQuery, ReviewScreeningResultRequest},
+        response::{
+            screening::{ScreeningResultResponse, WatchlistStatusResponse},
+            ApiResponse,
+        },
+    },
+    middleware::{admin, jwt},
+    state::AppState,
+};
+use axum::{
+    extract::{Extension, Path, Query, State},
+    http::StatusCode,
+    middleware,
+    response::IntoResponse,
+    routing::{get, post},
+    Json,
+};
+use serde_json::json;
+use std::sync::Arc;
+use tracing::instrument;
+use utoipa_axum::router::OpenApiRouter;
+
+#[utoipa::path(
+    get,
+    path = "/api/admin/screening/results",
+    tag = "Screening",
+    security(
+        ("bearer_auth" = [])
+    ),
+    params(FindScreeningResultsQuery),
+    responses(
+        (status = 200, description = "Watchlist matches of users and beneficiaries", body = ApiResponse<Vec<ScreeningResultResponse>>),
+        (status = 400, description = "Unknown status", body = String),
+        (status = 401, description = "Unauthorized access", body = String),
+        (status = 403, description = "Admin access required", body = String),
+    )
+)]
+#[instrument(skip_all)]
+pub async fn get_screening_results(
+    State(data): State<Arc<AppState>>,
+    Query(query): Query<FindScreeningResultsQuery>,
+) -> Result<impl IntoResponse, (StatusCode, Json<serde_json::Value>)> {
+    match data
+        .di_container
+        .screening_service
+        .get_results(query.status)
+        .await
+    {
+        Ok(response) => Ok((StatusCode::OK, Json(json!(response)))),
+        Err(e) => Err((StatusCode::BAD_REQUEST, Json(json!(e)))),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/admin/screening/results/{id}/review",
+    tag = "Screening",
+    security(
+        ("bearer_auth" = [])
+    ),
+    params(
+        ("id" = i32, Path, description = "Screening result ID")
+    ),
+    request_body = ReviewScreeningResultRequest,
+    responses(
+        (status = 200, description = "Match cleared or confirmed", body = ApiResponse<ScreeningResultResponse>),
+        (status = 400, description = "Missing note or match already reviewed", body = String),
+        (status = 401, description = "Unauthorized access", body = String),
+        (status = 403, description = "Admin access required", body = String),
+    )
+)]
+#[instrument(skip_all)]
+pub async fn review_screening_result(
+    State(data): State<Arc<AppState>>,
+    Path(id): Path<i32>,
+    Extension(user_id): Extension<i64>,
+    Json(mut body): Json<ReviewScreeningResultRequest>,
+) -> Result<impl IntoResponse, (StatusCode, Json<serde_json::Value>)> {
+    body.screening_result_id = id;
+    body.reviewer_id = user_id as i32;
+
+    match data
+        .di_container
+        .screening_service
+        .review_result(&body)
+        .await
+    {
+        Ok(response) => Ok((StatusCode::OK, Json(json!(response)))),
+        Err(e) => Err((StatusCode::BAD_REQUEST, Json(json!(e)))),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/admin/screening/watchlist/reload",
+    tag = "Screening",
+    security(
+        ("bearer_auth" = [])
+    ),
+    responses(
+        (status = 200, description = "Watchlist file read again", body = ApiResponse<WatchlistStatusResponse>),
+        (status = 401, description = "Unauthorized access", body = String),
+        (status = 403, description = "Admin access required", body = String),
+        (status = 500, description = "Watchlist file missing or invalid, the previous list stays in use", body = String),
+    )
+)]
+#[instrument(skip_all)]
+pub async fn reload_watchlist(
+    State(data): State<Arc<AppState>>,
+) -> Result<impl IntoResponse, (StatusCode, Json<serde_json::Value>)> {
+    match data.di_container.screening_service.reload_watchlist().await {
+        Ok(response) => Ok((StatusCode::OK, Json(json!(response)))),
+        Err(e) => Err((StatusCode::INTERNAL_SERVER_ERROR, Json(json!(e)))),
+    }
+}
+
+pub fn admin_screening_routes(app_state: Arc<AppState>) -> OpenApiRouter {
+    OpenApiRouter::new()
+        .route("/api/admin/screening/results", get(get_screening_results))
+        .route(
+            "/api/admin/screening/results/{id}/review",
+            post(review_screening_result),
+        )
+        .route(
+            "/api/admin/screening/watchlist/reload",
+            post(reload_watchlist),
+        )
+        .route_layer(middleware::from_fn_with_state(
+            app_state.clone(),
+            admin::admin,
+        ))
+        .route_layer(middleware::from_fn_with_state(app_state.clone(), jwt::auth))
+        .with_state(app_state.clone())
+}
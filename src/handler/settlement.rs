@@ -0,0 +1,134 @@
+use crate::{
+    domain::response::{settlement::SettlementResponse, ApiResponse},
+    middleware::jwt,
+    state::AppState,
+};
+use axum::{
+    extract::{Extension, Path, State},
+    http::{header, StatusCode},
+    middleware,
+    response::IntoResponse,
+    routing::get,
+    Json,
+};
+use serde_json::json;
+use std::sync::Arc;
+use tracing::instrument;
+use utoipa_axum::router::OpenApiRouter;
+
+#[utoipa::path(
+    get,
+    path = "/api/settlements",
+    tag = "Settlement",
+    security(
+        ("bearer_auth" = [])
+    ),
+    responses(
+        (status = 200, description = "Settlements of the merchant", body = ApiResponse<Vec<SettlementResponse>>),
+        (status = 401, description = "Unauthorized access", body = String),
+        (status = 404, description = "Merchant not found", body = String),
+    )
+)]
+#[instrument(skip_all)]
+pub async fn get_settlements(
+    State(data): State<Arc<AppState>>,
+    Extension(user_id): Extension<i64>,
+) -> Result<impl IntoResponse, (StatusCode, Json<serde_json::Value>)> {
+    match data
+        .di_container
+        .settlement_service
+        .get_settlements(user_id as i32)
+        .await
+    {
+        Ok(response) => Ok((StatusCode::OK, Json(json!(response)))),
+        Err(e) => Err((StatusCode::NOT_FOUND, Json(json!(e)))),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/settlements/{id}",
+    tag = "Settlement",
+    security(
+        ("bearer_auth" = [])
+    ),
+    params(
+        ("id" = i32, Path, description = "Settlement ID")
+    ),
+    responses(
+        (status = 200, description = "Settlement totals and payout status", body = ApiResponse<SettlementResponse>),
+        (status = 401, description = "Unauthorized access", body = String),
+        (status = 404, description = "Settlement not found", body = String),
+    )
+)]
+#[instrument(skip_all)]
+pub async fn get_settlement(
+    State(data): State<Arc<AppState>>,
+    Path(id): Path<i32>,
+    Extension(user_id): Extension<i64>,
+) -> Result<impl IntoResponse, (StatusCode, Json<serde_json::Value>)> {
+    match data
+        .di_container
+        .settlement_service
+        .get_settlement(id, user_id as i32)
+        .await
+    {
+        Ok(response) => Ok((StatusCode::OK, Json(json!(response)))),
+        Err(e) => Err((StatusCode::NOT_FOUND, Json(json!(e)))),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/settlements/{id}/report",
+    tag = "Settlement",
+    security(
+        ("bearer_auth" = [])
+    ),
+    params(
+        ("id" = i32, Path, description = "Settlement ID")
+    ),
+    responses(
+        (status = 200, description = "CSV file listing every payment and refund in the settlement", body = String, content_type = "text/csv"),
+        (status = 401, description = "Unauthorized access", body = String),
+        (status = 404, description = "Settlement not found", body = String),
+    )
+)]
+#[instrument(skip_all)]
+pub async fn download_settlement_report(
+    State(data): State<Arc<AppState>>,
+    Path(id): Path<i32>,
+    Extension(user_id): Extension<i64>,
+) -> Result<impl IntoResponse, (StatusCode, Json<serde_json::Value>)> {
+    match data
+        .di_container
+        .settlement_service
+        .get_settlement_report_csv(id, user_id as i32)
+        .await
+    {
+        Ok(csv) => Ok((
+            StatusCode::OK,
+            [
+                (header::CONTENT_TYPE, "text/csv".to_string()),
+                (
+                    header::CONTENT_DISPOSITION,
+                    format!("attachment; filename=\"settlement-{}.csv\"", id),
+                ),
+            ],
+            csv,
+        )),
+        Err(e) => Err((StatusCode::NOT_FOUND, Json(json!(e)))),
+    }
+}
+
+pub fn settlement_routes(app_state: Arc<AppState>) -> OpenApiRouter {
+    OpenApiRouter::new()
+        .route("/api/settlements", get(get_settlements))
+        .route("/api/settlements/{id}", get(get_settlement))
+        .route(
+            "/api/settlements/{id}/report",
+            get(download_settlement_report),
+        )
+        .route_layer(middleware::from_fn_with_state(app_state.clone(), jwt::auth))
+        .with_state(app_state.clone())
+}
@@ -1,14 +1,15 @@
 use crate::{
     domain::{
-        request::topup::{CreateTopupRequest, UpdateTopupRequest},
+        request::topup::{CreateTopupRequest, PaymentCallbackRequest, UpdateTopupRequest},
         response::{topup::TopupResponse, ApiResponse},
     },
-    middleware::jwt,
+    middleware::{admin, jwt},
     state::AppState,
 };
 use axum::{
+    body::Bytes,
     extract::{Extension, Path, State},
-    http::StatusCode,
+    http::{HeaderMap, StatusCode},
     middleware,
     response::IntoResponse,
     routing::{delete, get, post, put},
@@ -17,6 +18,7 @@ use axum::{
 use serde_json::json;
 use utoipa_axum::router::OpenApiRouter;
 use std::sync::Arc;
+use tracing::instrument;
 
 #[utoipa::path(
     get,
@@ -31,6 +33,7 @@ use std::sync::Arc;
         (status = 500, description = "Internal server error", body = String),
     )
 )]
+#[instrument(skip_all)]
 pub async fn get_topups(
     State(data): State<Arc<AppState>>,
 ) -> Result<impl IntoResponse, (StatusCode, Json<serde_json::Value>)> {
@@ -56,6 +59,7 @@ pub async fn get_topups(
         (status = 404, description = "Topup record not found", body = String),
     )
 )]
+#[instrument(skip_all)]
 pub async fn get_topup(
     State(data): State<Arc<AppState>>,
     Path(id): Path<i32>,
@@ -84,6 +88,7 @@ pub async fn get_topup(
         (status = 404, description = "Topup records not found for the user", body = String),
     )
 )]
+#[instrument(skip_all)]
 pub async fn get_topup_users(
     State(data): State<Arc<AppState>>,
     Path(id): Path<i32>,
@@ -112,6 +117,7 @@ pub async fn get_topup_users(
         (status = 500, description = "Internal server error", body = String),
     )
 )]
+#[instrument(skip_all)]
 pub async fn get_topup_user(
     State(data): State<Arc<AppState>>,
     Path(id): Path<i32>,
@@ -134,16 +140,19 @@ pub async fn get_topup_user(
     request_body = CreateTopupRequest,
     responses(
         (status = 201, description = "Topup record created successfully", body = ApiResponse<TopupResponse>),
+        (status = 202, description = "Held for fraud review, no money moved yet", body = String),
         (status = 401, description = "Unauthorized access", body = String),
         (status = 500, description = "Internal server error", body = String),
     )
 )]
+#[instrument(skip_all)]
 pub async fn create_topup(
     State(data): State<Arc<AppState>>,
     Json(body): Json<CreateTopupRequest>,
 ) -> Result<impl IntoResponse, (StatusCode, Json<serde_json::Value>)> {
     match data.di_container.topup_service.create_topup(&body).await {
         Ok(response) => Ok((StatusCode::CREATED, Json(json!(response)))),
+        Err(e) if e.status == "pending" => Ok((StatusCode::ACCEPTED, Json(json!(e)))),
         Err(e) => Err((StatusCode::INTERNAL_SERVER_ERROR, Json(json!(e)))),
     }
 }
@@ -160,17 +169,20 @@ pub async fn create_topup(
     ),
     request_body = UpdateTopupRequest,
     responses(
-        (status = 200, description = "Topup record updated successfully", body = ApiResponse<TopupResponse>),
+        (status = 200, description = "Amount of a pending topup updated", body = ApiResponse<TopupResponse>),
         (status = 401, description = "Unauthorized access", body = String),
-        (status = 500, description = "Internal server error", body = String),
+        (status = 500, description = "Topup not found, not the caller's, or no longer pending", body = String),
     )
 )]
+#[instrument(skip_all)]
 pub async fn update_topup(
     State(data): State<Arc<AppState>>,
     Path(id): Path<i32>,
+    Extension(user_id): Extension<i64>,
     Json(mut body): Json<UpdateTopupRequest>,
 ) -> Result<impl IntoResponse, (StatusCode, Json<serde_json::Value>)> {
     body.topup_id = id;
+    body.user_id = user_id as i32;
 
     match data.di_container.topup_service.update_topup(&body).await {
         Ok(response) => Ok((StatusCode::OK, Json(json!(response)))),
@@ -195,6 +207,7 @@ pub async fn update_topup(
         (status = 500, description = "Internal server error", body = String),
     )
 )]
+#[instrument(skip_all)]
 pub async fn delete_topup(
     State(data): State<Arc<AppState>>,
     Path(id): Path<i32>,
@@ -212,7 +225,76 @@ pub async fn delete_topup(
     }
 }
 
+#[utoipa::path(
+    post,
+    path = "/api/topups/callback",
+    tag = "Topup",
+    params(
+        ("X-Callback-Signature" = String, Header, description = "Hex HMAC-SHA256 of the raw body")
+    ),
+    request_body = PaymentCallbackRequest,
+    responses(
+        (status = 200, description = "Payment callback processed", body = ApiResponse<TopupResponse>),
+        (status = 400, description = "Invalid signature or callback payload", body = String),
+    )
+)]
+#[instrument(skip_all)]
+pub async fn payment_callback(
+    State(data): State<Arc<AppState>>,
+    headers: HeaderMap,
+    body: Bytes,
+) -> Result<impl IntoResponse, (StatusCode, Json<serde_json::Value>)> {
+    let signature = headers
+        .get("X-Callback-Signature")
+        .and_then(|value| value.to_str().ok())
+        .unwrap_or_default();
+
+    match data
+        .di_container
+        .topup_service
+        .handle_payment_callback(&body, signature)
+        .await
+    {
+        Ok(response) => Ok((StatusCode::OK, Json(json!(response)))),
+        Err(e) => Err((StatusCode::BAD_REQUEST, Json(json!(e)))),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/admin/topups/{id}/reverse",
+    tag = "Topup",
+    security(
+        ("bearer_auth" = [])
+    ),
+    params(
+        ("id" = i32, Path, description = "Topup ID")
+    ),
+    responses(
+        (status = 200, description = "Paid topup taken back out of the saldo", body = ApiResponse<TopupResponse>),
+        (status = 400, description = "Topup not paid or saldo too low to take it back", body = String),
+        (status = 401, description = "Unauthorized access", body = String),
+        (status = 403, description = "Admin access required", body = String),
+    )
+)]
+#[instrument(skip_all)]
+pub async fn reverse_topup(
+    State(data): State<Arc<AppState>>,
+    Path(id): Path<i32>,
+) -> Result<impl IntoResponse, (StatusCode, Json<serde_json::Value>)> {
+    match data.di_container.topup_service.reverse_topup(id).await {
+        Ok(response) => Ok((StatusCode::OK, Json(json!(response)))),
+        Err(e) => Err((StatusCode::BAD_REQUEST, Json(json!(e)))),
+    }
+}
+
 pub fn topup_routes(app_state: Arc<AppState>) -> OpenApiRouter {
+    // The payment provider calls back without a user token; the request is
+    // authenticated by its signature instead.
+    let callback_routes = OpenApiRouter::new()
+        .route("/api/topups/callback", post(payment_callback))
+        .with_state(app_state.clone());
+
     OpenApiRouter::new()
         .route("/api/topups", get(get_topups))
         .route("/api/topups/{id}", get(get_topup))
@@ -223,4 +305,17 @@ pub fn topup_routes(app_state: Arc<AppState>) -> OpenApiRouter {
         .route("/api/topups/{id}", delete(delete_topup))
         .route_layer(middleware::from_fn_with_state(app_state.clone(), jwt::auth))
         .with_state(app_state.clone())
+        .merge(callback_routes)
+}
+
+/// Corrections of paid top-ups, audited like every admin action.
+pub fn admin_topup_routes(app_state: Arc<AppState>) -> OpenApiRouter {
+    OpenApiRouter::new()
+        .route("/api/admin/topups/{id}/reverse", post(reverse_topup))
+        .route_layer(middleware::from_fn_with_state(
+            app_state.clone(),
+            admin::admin,
+        ))
+        .route_layer(middleware::from_fn_with_state(app_state.clone(), jwt::auth))
+        .with_state(app_state.clone())
 }
@@ -1,7 +1,12 @@
 use crate::{
     domain::{
-        request::transfer::{CreateTransferRequest, UpdateTransferRequest},
-        response::{transfer::TransferResponse, ApiResponse},
+        request::transfer::{
+            CreateTransferByAccountRequest, CreateTransferRequest, UpdateTransferRequest,
+        },
+        response::{
+            transfer::{RecipientResponse, TransferResponse},
+            ApiResponse,
+        },
     },
     middleware::jwt,
     state::AppState,
@@ -17,6 +22,7 @@ use axum::{
 use serde_json::json;
 use utoipa_axum::router::OpenApiRouter;
 use std::sync::Arc;
+use tracing::instrument;
 
 #[utoipa::path(
     get,
@@ -31,6 +37,7 @@ use std::sync::Arc;
         (status = 500, description = "Internal server error", body = String),
     )
 )]
+#[instrument(skip_all)]
 pub async fn get_transfers(
     State(data): State<Arc<AppState>>,
 ) -> Result<impl IntoResponse, (StatusCode, Json<serde_json::Value>)> {
@@ -56,6 +63,7 @@ pub async fn get_transfers(
         (status = 404, description = "Transfer record not found", body = String),
     )
 )]
+#[instrument(skip_all)]
 pub async fn get_transfer(
     State(data): State<Arc<AppState>>,
     Path(id): Path<i32>,
@@ -84,6 +92,7 @@ pub async fn get_transfer(
         (status = 404, description = "Transfer records not found for the user", body = String),
     )
 )]
+#[instrument(skip_all)]
 pub async fn get_transfer_users(
     State(data): State<Arc<AppState>>,
     Path(id): Path<i32>,
@@ -117,6 +126,7 @@ pub async fn get_transfer_users(
         (status = 500, description = "Internal server error", body = String),
     )
 )]
+#[instrument(skip_all)]
 pub async fn get_transfer_user(
     State(data): State<Arc<AppState>>,
     Path(id): Path<i32>,
@@ -144,14 +154,18 @@ pub async fn get_transfer_user(
     request_body = CreateTransferRequest,
     responses(
         (status = 201, description = "Transfer record created successfully", body = ApiResponse<TransferResponse>),
+        (status = 202, description = "Held for fraud review, no money moved yet", body = String),
         (status = 401, description = "Unauthorized access", body = String),
         (status = 500, description = "Internal server error", body = String),
     )
 )]
+#[instrument(skip_all)]
 pub async fn create_transfer(
     State(data): State<Arc<AppState>>,
-    Json(body): Json<CreateTransferRequest>,
+    Json(mut body): Json<CreateTransferRequest>,
 ) -> Result<impl IntoResponse, (StatusCode, Json<serde_json::Value>)> {
+    body.merchant_payment = false;
+
     match data
         .di_container
         .transfer_service
@@ -159,6 +173,74 @@ pub async fn create_transfer(
         .await
     {
         Ok(response) => Ok((StatusCode::CREATED, Json(json!(response)))),
+        Err(e) if e.status == "pending" => Ok((StatusCode::ACCEPTED, Json(json!(e)))),
+        Err(e) => Err((StatusCode::INTERNAL_SERVER_ERROR, Json(json!(e)))),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/transfers/recipients/{noc_transfer}",
+    tag = "Transfer",
+    security(
+        ("bearer_auth" = [])
+    ),
+    params(
+        ("noc_transfer" = String, Path, description = "Recipient account number")
+    ),
+    responses(
+        (status = 200, description = "Recipient with masked name for confirmation", body = ApiResponse<RecipientResponse>),
+        (status = 401, description = "Unauthorized access", body = String),
+        (status = 404, description = "Account not found", body = String),
+    )
+)]
+#[instrument(skip_all)]
+pub async fn get_transfer_recipient(
+    State(data): State<Arc<AppState>>,
+    Path(noc_transfer): Path<String>,
+) -> Result<impl IntoResponse, (StatusCode, Json<serde_json::Value>)> {
+    match data
+        .di_container
+        .transfer_service
+        .find_recipient(&noc_transfer)
+        .await
+    {
+        Ok(response) => Ok((StatusCode::OK, Json(json!(response)))),
+        Err(e) => Err((StatusCode::NOT_FOUND, Json(json!(e)))),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/transfers/by-account",
+    tag = "Transfer",
+    security(
+        ("bearer_auth" = [])
+    ),
+    request_body = CreateTransferByAccountRequest,
+    responses(
+        (status = 201, description = "Transfer record created successfully", body = ApiResponse<TransferResponse>),
+        (status = 202, description = "Held for fraud review, no money moved yet", body = String),
+        (status = 401, description = "Unauthorized access", body = String),
+        (status = 500, description = "Internal server error", body = String),
+    )
+)]
+#[instrument(skip_all)]
+pub async fn create_transfer_by_account(
+    State(data): State<Arc<AppState>>,
+    Extension(user_id): Extension<i64>,
+    Json(mut body): Json<CreateTransferByAccountRequest>,
+) -> Result<impl IntoResponse, (StatusCode, Json<serde_json::Value>)> {
+    body.transfer_from = user_id as i32;
+
+    match data
+        .di_container
+        .transfer_service
+        .create_transfer_by_account(&body)
+        .await
+    {
+        Ok(response) => Ok((StatusCode::CREATED, Json(json!(response)))),
+        Err(e) if e.status == "pending" => Ok((StatusCode::ACCEPTED, Json(json!(e)))),
         Err(e) => Err((StatusCode::INTERNAL_SERVER_ERROR, Json(json!(e)))),
     }
 }
@@ -180,6 +262,7 @@ pub async fn create_transfer(
         (status = 500, description = "Internal server error", body = String),
     )
 )]
+#[instrument(skip_all)]
 pub async fn update_transfer(
     State(data): State<Arc<AppState>>,
     Path(id): Path<i32>,
@@ -215,6 +298,7 @@ pub async fn update_transfer(
         (status = 500, description = "Internal server error", body = String),
     )
 )]
+#[instrument(skip_all)]
 pub async fn delete_transfer(
     State(data): State<Arc<AppState>>,
     Path(id): Path<i32>,
@@ -239,6 +323,11 @@ pub fn transfers_routes(app_state: Arc<AppState>) -> OpenApiRouter {
         .route("/api/transfers/users/{id}", get(get_transfer_users))
         .route("/api/transfers/user/{id}", get(get_transfer_user))
         .route("/api/transfers", post(create_transfer))
+        .route(
+            "/api/transfers/recipients/{noc_transfer}",
+            get(get_transfer_recipient),
+        )
+        .route("/api/transfers/by-account", post(create_transfer_by_account))
         .route("/api/transfers/{id}", put(update_transfer))
         .route("/api/transfers/{id}", delete(delete_transfer))
         .route_layer(middleware::from_fn_with_state(app_state.clone(), jwt::auth))
@@ -0,0 +1,209 @@
+use crate::{
+    domain::{
+        request::transfer_batch::{CreateTransferBatchRequest, TransferBatchItemRequest},
+        response::{
+            transfer_batch::{TransferBatchDetailResponse, TransferBatchResponse},
+            ApiResponse,
+        },
+    },
+    middleware::jwt,
+    state::AppState,
+};
+use axum::{
+    body::Body,
+    extract::{Extension, FromRequest, Multipart, Path, Request, State},
+    http::{header, StatusCode},
+    middleware,
+    response::IntoResponse,
+    routing::{get, post},
+    Json,
+};
+use serde_json::json;
+use std::sync::Arc;
+use tracing::instrument;
+use utoipa::ToSchema;
+use utoipa_axum::router::OpenApiRouter;
+
+/// Multipart form used to upload a batch as a CSV file.
+#[allow(dead_code)]
+#[derive(ToSchema)]
+pub struct TransferBatchUpload {
+    /// CSV file with a `transfer_to,transfer_amount` header row.
+    #[schema(format = Binary, content_media_type = "text/csv")]
+    file: String,
+}
+
+fn bad_request(message: impl Into<String>) -> (StatusCode, Json<serde_json::Value>) {
+    (
+        StatusCode::BAD_REQUEST,
+        Json(json!({ "status": "fail", "message": message.into() })),
+    )
+}
+
+async fn read_csv_upload(
+    request: Request<Body>,
+    state: &Arc<AppState>,
+) -> Result<Vec<u8>, (StatusCode, Json<serde_json::Value>)> {
+    let mut multipart = Multipart::from_request(request, state)
+        .await
+        .map_err(|e| bad_request(e.body_text()))?;
+
+    while let Some(field) = multipart
+        .next_field()
+        .await
+        .map_err(|e| bad_request(e.body_text()))?
+    {
+        if field.name() == Some("file") {
+            let data = field
+                .bytes()
+                .await
+                .map_err(|e| bad_request(e.body_text()))?;
+            return Ok(data.to_vec());
+        }
+    }
+
+    Err(bad_request("Missing `file` field in upload"))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/transfers/batch",
+    tag = "Transfer",
+    security(
+        ("bearer_auth" = [])
+    ),
+    request_body(content(
+        (Vec<TransferBatchItemRequest> = "application/json"),
+        (TransferBatchUpload = "multipart/form-data"),
+    )),
+    responses(
+        (status = 202, description = "Transfer batch accepted for processing", body = ApiResponse<TransferBatchResponse>),
+        (status = 400, description = "Malformed batch payload", body = String),
+        (status = 401, description = "Unauthorized access", body = String),
+        (status = 500, description = "Internal server error", body = String),
+    )
+)]
+#[instrument(skip_all)]
+pub async fn create_transfer_batch(
+    State(data): State<Arc<AppState>>,
+    Extension(user_id): Extension<i64>,
+    request: Request<Body>,
+) -> Result<impl IntoResponse, (StatusCode, Json<serde_json::Value>)> {
+    let is_multipart = request
+        .headers()
+        .get(header::CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .is_some_and(|value| value.starts_with("multipart/form-data"));
+
+    let body = if is_multipart {
+        let csv = read_csv_upload(request, &data).await?;
+        CreateTransferBatchRequest::from_csv(user_id as i32, &csv).map_err(bad_request)?
+    } else {
+        let Json(items) = Json::<Vec<TransferBatchItemRequest>>::from_request(request, &data)
+            .await
+            .map_err(|e| bad_request(e.body_text()))?;
+
+        CreateTransferBatchRequest {
+            transfer_from: user_id as i32,
+            items,
+        }
+    };
+
+    match data
+        .di_container
+        .transfer_batch_service
+        .create_batch(&body)
+        .await
+    {
+        Ok(response) => Ok((StatusCode::ACCEPTED, Json(json!(response)))),
+        Err(e) => Err((StatusCode::INTERNAL_SERVER_ERROR, Json(json!(e)))),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/transfers/batch/{id}",
+    tag = "Transfer",
+    security(
+        ("bearer_auth" = [])
+    ),
+    params(
+        ("id" = i32, Path, description = "Transfer batch ID")
+    ),
+    responses(
+        (status = 200, description = "Transfer batch status with per-item results", body = ApiResponse<TransferBatchDetailResponse>),
+        (status = 401, description = "Unauthorized access", body = String),
+        (status = 404, description = "Transfer batch not found", body = String),
+    )
+)]
+#[instrument(skip_all)]
+pub async fn get_transfer_batch(
+    State(data): State<Arc<AppState>>,
+    Path(id): Path<i32>,
+    Extension(user_id): Extension<i64>,
+) -> Result<impl IntoResponse, (StatusCode, Json<serde_json::Value>)> {
+    match data
+        .di_container
+        .transfer_batch_service
+        .get_batch(id, user_id as i32)
+        .await
+    {
+        Ok(response) => Ok((StatusCode::OK, Json(json!(response)))),
+        Err(e) => Err((StatusCode::NOT_FOUND, Json(json!(e)))),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/transfers/batch/{id}/result",
+    tag = "Transfer",
+    security(
+        ("bearer_auth" = [])
+    ),
+    params(
+        ("id" = i32, Path, description = "Transfer batch ID")
+    ),
+    responses(
+        (status = 200, description = "CSV file with the result of every batch item", body = String, content_type = "text/csv"),
+        (status = 401, description = "Unauthorized access", body = String),
+        (status = 404, description = "Transfer batch not found", body = String),
+    )
+)]
+#[instrument(skip_all)]
+pub async fn download_transfer_batch_result(
+    State(data): State<Arc<AppState>>,
+    Path(id): Path<i32>,
+    Extension(user_id): Extension<i64>,
+) -> Result<impl IntoResponse, (StatusCode, Json<serde_json::Value>)> {
+    match data
+        .di_container
+        .transfer_batch_service
+        .get_batch_result_csv(id, user_id as i32)
+        .await
+    {
+        Ok(csv) => Ok((
+            StatusCode::OK,
+            [
+                (header::CONTENT_TYPE, "text/csv".to_string()),
+                (
+                    header::CONTENT_DISPOSITION,
+                    format!("attachment; filename=\"transfer-batch-{}.csv\"", id),
+                ),
+            ],
+            csv,
+        )),
+        Err(e) => Err((StatusCode::NOT_FOUND, Json(json!(e)))),
+    }
+}
+
+pub fn transfer_batch_routes(app_state: Arc<AppState>) -> OpenApiRouter {
+    OpenApiRouter::new()
+        .route("/api/transfers/batch", post(create_transfer_batch))
+        .route("/api/transfers/batch/{id}", get(get_transfer_batch))
+        .route(
+            "/api/transfers/batch/{id}/result",
+            get(download_transfer_batch_result),
+        )
+        .route_layer(middleware::from_fn_with_state(app_state.clone(), jwt::auth))
+        .with_state(app_state.clone())
+}
@@ -17,6 +17,7 @@ use axum::{
 use serde_json::json;
 use utoipa_axum::router::OpenApiRouter;
 use std::sync::Arc;
+use tracing::instrument;
 
 #[utoipa::path(
     get,
@@ -31,6 +32,7 @@ use std::sync::Arc;
         (status = 500, description = "Internal server error", body = String),
     )
 )]
+#[instrument(skip_all)]
 pub async fn get_users(
     State(data): State<Arc<AppState>>,
 ) -> Result<impl IntoResponse, (StatusCode, Json<serde_json::Value>)> {
@@ -56,6 +58,7 @@ pub async fn get_users(
         (status = 404, description = "User not found", body = String),
     )
 )]
+#[instrument(skip_all)]
 pub async fn get_user(
     State(data): State<Arc<AppState>>,
     Path(id): Path<i32>,
@@ -82,6 +85,7 @@ pub async fn get_user(
         (status = 500, description = "Internal server error", body = String),
     )
 )]
+#[instrument(skip_all)]
 pub async fn create_user(
     State(data): State<Arc<AppState>>,
     Json(body): Json<RegisterRequest>,
@@ -109,6 +113,7 @@ pub async fn create_user(
         (status = 500, description = "Internal server error", body = String),
     )
 )]
+#[instrument(skip_all)]
 pub async fn update_user(
     State(data): State<Arc<AppState>>,
     Path(id): Path<i32>,
@@ -139,6 +144,7 @@ pub async fn update_user(
         (status = 500, description = "Internal server error", body = String),
     )
 )]
+#[instrument(skip_all)]
 pub async fn delete_user(
     State(data): State<Arc<AppState>>,
     Path(id): Path<i32>,
@@ -0,0 +1,225 @@
+use crate::{
+    domain::{
+        request::virtual_account::{
+            CreateVirtualAccountRequest, ResolveSuspensePaymentRequest,
+            VirtualAccountPaymentRequest,
+        },
+        response::{
+            virtual_account::{
+                SuspensePaymentResponse, VirtualAccountPaymentResponse, VirtualAccountResponse,
+            },
+            ApiResponse,
+        },
+    },
+    middleware::{admin, jwt},
+    state::AppState,
+};
+use axum::{
+    body::Bytes,
+    extract::{Extension, Path, State},
+    http::{HeaderMap, StatusCode},
+    middleware,
+    response::IntoResponse,
+    routing::{get, post},
+    Json,
+};
+use serde_json::json;
+use std::sync::Arc;
+use tracing::instrument;
+use utoipa_axum::router::OpenApiRouter;
+
+#[utoipa::path(
+    get,
+    path = "/api/virtual-accounts",
+    tag = "Virtual Account",
+    security(
+        ("bearer_auth" = [])
+    ),
+    responses(
+        (status = 200, description = "Virtual accounts of the current user", body = ApiResponse<Vec<VirtualAccountResponse>>),
+        (status = 401, description = "Unauthorized access", body = String),
+        (status = 500, description = "Internal server error", body = String),
+    )
+)]
+#[instrument(skip_all)]
+pub async fn get_virtual_accounts(
+    State(data): State<Arc<AppState>>,
+    Extension(user_id): Extension<i64>,
+) -> Result<impl IntoResponse, (StatusCode, Json<serde_json::Value>)> {
+    match data
+        .di_container
+        .virtual_account_service
+        .get_virtual_accounts(user_id as i32)
+        .await
+    {
+        Ok(response) => Ok((StatusCode::OK, Json(json!(response)))),
+        Err(e) => Err((StatusCode::INTERNAL_SERVER_ERROR, Json(json!(e)))),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/virtual-accounts",
+    tag = "Virtual Account",
+    security(
+        ("bearer_auth" = [])
+    ),
+    request_body = CreateVirtualAccountRequest,
+    responses(
+        (status = 201, description = "Virtual account for the requested bank", body = ApiResponse<VirtualAccountResponse>),
+        (status = 401, description = "Unauthorized access", body = String),
+        (status = 500, description = "Internal server error", body = String),
+    )
+)]
+#[instrument(skip_all)]
+pub async fn create_virtual_account(
+    State(data): State<Arc<AppState>>,
+    Extension(user_id): Extension<i64>,
+    Json(mut body): Json<CreateVirtualAccountRequest>,
+) -> Result<impl IntoResponse, (StatusCode, Json<serde_json::Value>)> {
+    body.user_id = user_id as i32;
+
+    match data
+        .di_container
+        .virtual_account_service
+        .create_virtual_account(&body)
+        .await
+    {
+        Ok(response) => Ok((StatusCode::CREATED, Json(json!(response)))),
+        Err(e) => Err((StatusCode::INTERNAL_SERVER_ERROR, Json(json!(e)))),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/admin/virtual-accounts/suspense",
+    tag = "Virtual Account",
+    security(
+        ("bearer_auth" = [])
+    ),
+    responses(
+        (status = 200, description = "Unmatched payments awaiting review", body = ApiResponse<Vec<SuspensePaymentResponse>>),
+        (status = 401, description = "Unauthorized access", body = String),
+        (status = 403, description = "Admin access required", body = String),
+        (status = 500, description = "Internal server error", body = String),
+    )
+)]
+#[instrument(skip_all)]
+pub async fn get_suspense_payments(
+    State(data): State<Arc<AppState>>,
+) -> Result<impl IntoResponse, (StatusCode, Json<serde_json::Value>)> {
+    match data
+        .di_container
+        .virtual_account_service
+        .get_suspense_payments()
+        .await
+    {
+        Ok(response) => Ok((StatusCode::OK, Json(json!(response)))),
+        Err(e) => Err((StatusCode::INTERNAL_SERVER_ERROR, Json(json!(e)))),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/admin/virtual-accounts/suspense/{id}/resolve",
+    tag = "Virtual Account",
+    security(
+        ("bearer_auth" = [])
+    ),
+    params(
+        ("id" = i32, Path, description = "Suspense payment ID")
+    ),
+    request_body = ResolveSuspensePaymentRequest,
+    responses(
+        (status = 200, description = "Payment credited to the chosen user", body = ApiResponse<SuspensePaymentResponse>),
+        (status = 401, description = "Unauthorized access", body = String),
+        (status = 403, description = "Admin access required", body = String),
+        (status = 404, description = "Suspense payment or user not found", body = String),
+    )
+)]
+#[instrument(skip_all)]
+pub async fn resolve_suspense_payment(
+    State(data): State<Arc<AppState>>,
+    Path(id): Path<i32>,
+    Json(mut body): Json<ResolveSuspensePaymentRequest>,
+) -> Result<impl IntoResponse, (StatusCode, Json<serde_json::Value>)> {
+    body.suspense_payment_id = id;
+
+    match data
+        .di_container
+        .virtual_account_service
+        .resolve_suspense_payment(&body)
+        .await
+    {
+        Ok(response) => Ok((StatusCode::OK, Json(json!(response)))),
+        Err(e) => Err((StatusCode::INTERNAL_SERVER_ERROR, Json(json!(e)))),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/virtual-accounts/payments",
+    tag = "Virtual Account",
+    params(
+        ("X-Callback-Signature" = String, Header, description = "Hex HMAC-SHA256 of the raw body")
+    ),
+    request_body = VirtualAccountPaymentRequest,
+    responses(
+        (status = 200, description = "Payment credited or queued for review", body = ApiResponse<VirtualAccountPaymentResponse>),
+        (status = 400, description = "Invalid signature or payment payload", body = String),
+    )
+)]
+#[instrument(skip_all)]
+pub async fn virtual_account_payment(
+    State(data): State<Arc<AppState>>,
+    headers: HeaderMap,
+    body: Bytes,
+) -> Result<impl IntoResponse, (StatusCode, Json<serde_json::Value>)> {
+    let signature = headers
+        .get("X-Callback-Signature")
+        .and_then(|value| value.to_str().ok())
+        .unwrap_or_default();
+
+    match data
+        .di_container
+        .virtual_account_service
+        .handle_payment(&body, signature)
+        .await
+    {
+        Ok(response) => Ok((StatusCode::OK, Json(json!(response)))),
+        Err(e) => Err((StatusCode::BAD_REQUEST, Json(json!(e)))),
+    }
+}
+
+pub fn virtual_account_routes(app_state: Arc<AppState>) -> OpenApiRouter {
+    // Banks call the payment webhook without a user token; the request is
+    // authenticated by its signature instead.
+    let webhook_routes = OpenApiRouter::new()
+        .route("/api/virtual-accounts/payments", post(virtual_account_payment))
+        .with_state(app_state.clone());
+
+    OpenApiRouter::new()
+        .route("/api/virtual-accounts", get(get_virtual_accounts))
+        .route("/api/virtual-accounts", post(create_virtual_account))
+        .route_layer(middleware::from_fn_with_state(app_state.clone(), jwt::auth))
+        .with_state(app_state.clone())
+        .merge(webhook_routes)
+}
+
+pub fn admin_virtual_account_routes(app_state: Arc<AppState>) -> OpenApiRouter {
+    OpenApiRouter::new()
+        .route(
+            "/api/admin/virtual-accounts/suspense",
+            get(get_suspense_payments),
+        )
+        .route(
+            "/api/admin/virtual-accounts/suspense/{id}/resolve",
+            post(resolve_suspense_payment),
+        )
+        .route_layer(middleware::from_fn_with_state(
+            app_state.clone(),
+            admin::admin,
+        ))
+        .route_layer(middleware::from_fn_with_state(app_state.clone(), jwt::auth))
+        .with_state(app_state.clone())
+}
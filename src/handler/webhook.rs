@@ -0,0 +1,197 @@
+use crate::{
+    domain::{
+        request::webhook::CreateWebhookEndpointRequest,
+        response::{
+            webhook::{WebhookDeliveryResponse, WebhookEndpointResponse},
+            ApiResponse,
+        },
+    },
+    middleware::jwt,
+    state::AppState,
+};
+use axum::{
+    extract::{Extension, Path, State},
+    http::StatusCode,
+    middleware,
+    response::IntoResponse,
+    routing::{delete, get, post},
+    Json,
+};
+use serde_json::json;
+use std::sync::Arc;
+use tracing::instrument;
+use utoipa_axum::router::OpenApiRouter;
+
+#[utoipa::path(
+    get,
+    path = "/api/webhooks/endpoints",
+    tag = "Webhook",
+    security(
+        ("bearer_auth" = [])
+    ),
+    responses(
+        (status = 200, description = "Webhook endpoints of the current user's merchant", body = ApiResponse<Vec<WebhookEndpointResponse>>),
+        (status = 401, description = "Unauthorized access", body = String),
+        (status = 500, description = "Internal server error", body = String),
+    )
+)]
+#[instrument(skip_all)]
+pub async fn get_webhook_endpoints(
+    State(data): State<Arc<AppState>>,
+    Extension(user_id): Extension<i64>,
+) -> Result<impl IntoResponse, (StatusCode, Json<serde_json::Value>)> {
+    match data
+        .di_container
+        .webhook_service
+        .get_endpoints(user_id as i32)
+        .await
+    {
+        Ok(response) => Ok((StatusCode::OK, Json(json!(response)))),
+        Err(e) => Err((StatusCode::INTERNAL_SERVER_ERROR, Json(json!(e)))),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/webhooks/endpoints",
+    tag = "Webhook",
+    security(
+        ("bearer_auth" = [])
+    ),
+    request_body = CreateWebhookEndpointRequest,
+    responses(
+        (status = 201, description = "Webhook endpoint registered; the signing secret is only shown here", body = ApiResponse<WebhookEndpointResponse>),
+        (status = 401, description = "Unauthorized access", body = String),
+        (status = 500, description = "Internal server error", body = String),
+    )
+)]
+#[instrument(skip_all)]
+pub async fn create_webhook_endpoint(
+    State(data): State<Arc<AppState>>,
+    Extension(user_id): Extension<i64>,
+    Json(mut body): Json<CreateWebhookEndpointRequest>,
+) -> Result<impl IntoResponse, (StatusCode, Json<serde_json::Value>)> {
+    body.user_id = user_id as i32;
+
+    match data
+        .di_container
+        .webhook_service
+        .create_endpoint(&body)
+        .await
+    {
+        Ok(response) => Ok((StatusCode::CREATED, Json(json!(response)))),
+        Err(e) => Err((StatusCode::INTERNAL_SERVER_ERROR, Json(json!(e)))),
+    }
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/webhooks/endpoints/{id}",
+    tag = "Webhook",
+    security(
+        ("bearer_auth" = [])
+    ),
+    params(
+        ("id" = i32, Path, description = "Webhook endpoint ID")
+    ),
+    responses(
+        (status = 200, description = "Webhook endpoint deactivated", body = ApiResponse<WebhookEndpointResponse>),
+        (status = 401, description = "Unauthorized access", body = String),
+        (status = 404, description = "Webhook endpoint not found", body = String),
+    )
+)]
+#[instrument(skip_all)]
+pub async fn delete_webhook_endpoint(
+    State(data): State<Arc<AppState>>,
+    Path(id): Path<i32>,
+    Extension(user_id): Extension<i64>,
+) -> Result<impl IntoResponse, (StatusCode, Json<serde_json::Value>)> {
+    match data
+        .di_container
+        .webhook_service
+        .delete_endpoint(id, user_id as i32)
+        .await
+    {
+        Ok(response) => Ok((StatusCode::OK, Json(json!(response)))),
+        Err(e) => Err((StatusCode::NOT_FOUND, Json(json!(e)))),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/webhooks/deliveries",
+    tag = "Webhook",
+    security(
+        ("bearer_auth" = [])
+    ),
+    responses(
+        (status = 200, description = "Delivery log of the current user's merchant", body = ApiResponse<Vec<WebhookDeliveryResponse>>),
+        (status = 401, description = "Unauthorized access", body = String),
+        (status = 500, description = "Internal server error", body = String),
+    )
+)]
+#[instrument(skip_all)]
+pub async fn get_webhook_deliveries(
+    State(data): State<Arc<AppState>>,
+    Extension(user_id): Extension<i64>,
+) -> Result<impl IntoResponse, (StatusCode, Json<serde_json::Value>)> {
+    match data
+        .di_container
+        .webhook_service
+        .get_deliveries(user_id as i32)
+        .await
+    {
+        Ok(response) => Ok((StatusCode::OK, Json(json!(response)))),
+        Err(e) => Err((StatusCode::INTERNAL_SERVER_ERROR, Json(json!(e)))),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/webhooks/deliveries/{id}/replay",
+    tag = "Webhook",
+    security(
+        ("bearer_auth" = [])
+    ),
+    params(
+        ("id" = i32, Path, description = "Webhook delivery ID")
+    ),
+    responses(
+        (status = 201, description = "New delivery of the same event queued", body = ApiResponse<WebhookDeliveryResponse>),
+        (status = 401, description = "Unauthorized access", body = String),
+        (status = 404, description = "Webhook delivery not found", body = String),
+    )
+)]
+#[instrument(skip_all)]
+pub async fn replay_webhook_delivery(
+    State(data): State<Arc<AppState>>,
+    Path(id): Path<i32>,
+    Extension(user_id): Extension<i64>,
+) -> Result<impl IntoResponse, (StatusCode, Json<serde_json::Value>)> {
+    match data
+        .di_container
+        .webhook_service
+        .replay_delivery(id, user_id as i32)
+        .await
+    {
+        Ok(response) => Ok((StatusCode::CREATED, Json(json!(response)))),
+        Err(e) => Err((StatusCode::NOT_FOUND, Json(json!(e)))),
+    }
+}
+
+pub fn webhook_routes(app_state: Arc<AppState>) -> OpenApiRouter {
+    OpenApiRouter::new()
+        .route("/api/webhooks/endpoints", get(get_webhook_endpoints))
+        .route("/api/webhooks/endpoints", post(create_webhook_endpoint))
+        .route(
+            "/api/webhooks/endpoints/{id}",
+            delete(delete_webhook_endpoint),
+        )
+        .route("/api/webhooks/deliveries", get(get_webhook_deliveries))
+        .route(
+            "/api/webhooks/deliveries/{id}/replay",
+            post(replay_webhook_delivery),
+        )
+        .route_layer(middleware::from_fn_with_state(app_state.clone(), jwt::auth))
+        .with_state(app_state.clone())
+}
@@ -17,6 +17,7 @@ use axum::{
 use serde_json::json;
 use utoipa_axum::router::OpenApiRouter;
 use std::sync::Arc;
+use tracing::instrument;
 
 #[utoipa::path(
     get,
@@ -31,6 +32,7 @@ use std::sync::Arc;
         (status = 500, description = "Internal server error", body = String),
     )
 )]
+#[instrument(skip_all)]
 pub async fn get_withdraws(
     State(data): State<Arc<AppState>>,
 ) -> Result<impl IntoResponse, (StatusCode, Json<serde_json::Value>)> {
@@ -56,6 +58,7 @@ pub async fn get_withdraws(
         (status = 404, description = "Withdrawal not found", body = String),
     )
 )]
+#[instrument(skip_all)]
 pub async fn get_withdraw(
     State(data): State<Arc<AppState>>,
     Path(id): Path<i32>,
@@ -84,6 +87,7 @@ pub async fn get_withdraw(
         (status = 404, description = "Withdrawals not found", body = String),
     )
 )]
+#[instrument(skip_all)]
 pub async fn get_withdraw_users(
     State(data): State<Arc<AppState>>,
     Path(id): Path<i32>,
@@ -117,6 +121,7 @@ pub async fn get_withdraw_users(
         (status = 500, description = "Internal server error", body = String),
     )
 )]
+#[instrument(skip_all)]
 pub async fn get_withdraw_user(
     State(data): State<Arc<AppState>>,
     Path(id): Path<i32>,
@@ -143,11 +148,13 @@ pub async fn get_withdraw_user(
     ),
     request_body = CreateWithdrawRequest,
     responses(
-        (status = 201, description = "Withdrawal request created successfully", body = ApiResponse<WithdrawResponse>),
+        (status = 201, description = "Withdrawal created; the payout stays pending until the provider settles it", body = ApiResponse<WithdrawResponse>),
+        (status = 202, description = "Held for fraud review, no money moved yet", body = String),
         (status = 401, description = "Unauthorized access", body = String),
         (status = 500, description = "Internal server error", body = String),
     )
 )]
+#[instrument(skip_all)]
 pub async fn create_withdraw(
     State(data): State<Arc<AppState>>,
     Json(body): Json<CreateWithdrawRequest>,
@@ -159,6 +166,7 @@ pub async fn create_withdraw(
         .await
     {
         Ok(response) => Ok((StatusCode::CREATED, Json(json!(response)))),
+        Err(e) if e.status == "pending" => Ok((StatusCode::ACCEPTED, Json(json!(e)))),
         Err(e) => Err((StatusCode::INTERNAL_SERVER_ERROR, Json(json!(e)))),
     }
 }
@@ -180,6 +188,7 @@ pub async fn create_withdraw(
         (status = 500, description = "Internal server error", body = String),
     )
 )]
+#[instrument(skip_all)]
 pub async fn update_withdraw(
     State(data): State<Arc<AppState>>,
     Path(id): Path<i32>,
@@ -215,6 +224,7 @@ pub async fn update_withdraw(
         (status = 500, description = "Internal server error", body = String),
     )
 )]
+#[instrument(skip_all)]
 pub async fn delete_withdraw(
     State(data): State<Arc<AppState>>,
     Path(id): Path<i32>,
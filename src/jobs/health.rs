@@ -0,0 +1,61 @@
+use std::{
+    collections::BTreeMap,
+    sync::{Arc, RwLock},
+    time::Duration,
+};
+
+use chrono::NaiveDateTime;
+
+use crate::abstract_trait::clock::DynClock;
+
+pub type SharedWorkerHealth = Arc<WorkerHealth>;
+
+/// What a background worker last reported.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WorkerState {
+    pub interval: Duration,
+    /// When the last run finished, or when the worker was registered if it
+    /// has not finished one yet.
+    pub last_seen_at: NaiveDateTime,
+    /// Error of the last run, `None` when it succeeded.
+    pub last_error: Option<String>,
+}
+
+/// Heartbeats of the background workers, read by the readiness probe.
+pub struct WorkerHealth {
+    clock: DynClock,
+    workers: RwLock<BTreeMap<&'static str, WorkerState>>,
+}
+
+impl WorkerHealth {
+    pub fn new(clock: DynClock) -> Self {
+        Self {
+            clock,
+            workers: RwLock::new(BTreeMap::new()),
+        }
+    }
+
+    pub fn register(&self, name: &'static str, interval: Duration) {
+        let state = WorkerState {
+            interval,
+            last_seen_at: self.clock.now(),
+            last_error: None,
+        };
+
+        self.workers.write().unwrap().insert(name, state);
+    }
+
+    /// Records a finished run of `name`.
+    pub fn record_run<T, E: ToString>(&self, name: &'static str, result: &Result<T, E>) {
+        let now = self.clock.now();
+
+        if let Some(state) = self.workers.write().unwrap().get_mut(name) {
+            state.last_seen_at = now;
+            state.last_error = result.as_ref().err().map(ToString::to_string);
+        }
+    }
+
+    pub fn workers(&self) -> BTreeMap<&'static str, WorkerState> {
+        self.workers.read().unwrap().clone()
+    }
+}
@@ -0,0 +1,6 @@
+pub mod health;
+pub mod payout;
+pub mod scheduler;
+pub mod settlement;
+pub mod transfer_batch;
+pub mod webhook;
@@ -0,0 +1,60 @@
+use std::time::Duration;
+
+use tracing::{error, info};
+
+use crate::{
+    abstract_trait::withdraw::DynWithdrawService, jobs::health::SharedWorkerHealth,
+    utils::shutdown::Shutdown,
+};
+
+/// Background task that polls the payout provider for pending withdrawals
+/// and settles or releases them once the provider reports a final status.
+pub struct PayoutSyncWorker {
+    withdraw_service: DynWithdrawService,
+    interval: Duration,
+    worker_health: SharedWorkerHealth,
+}
+
+impl PayoutSyncWorker {
+    /// Name the worker reports its health under.
+    pub const NAME: &str = "payout_sync";
+
+    pub fn new(
+        withdraw_service: DynWithdrawService,
+        interval: Duration,
+        worker_health: SharedWorkerHealth,
+    ) -> Self {
+        worker_health.register(Self::NAME, interval);
+
+        Self {
+            withdraw_service,
+            interval,
+            worker_health,
+        }
+    }
+
+    /// Runs until `shutdown` is triggered, finishing a run in progress first.
+    pub async fn run(self, shutdown: Shutdown) {
+        info!("Payout sync worker started");
+
+        let mut ticker = tokio::time::interval(self.interval);
+
+        loop {
+            tokio::select! {
+                _ = ticker.tick() => {}
+                _ = shutdown.triggered() => break,
+            }
+
+            let result = self.withdraw_service.sync_pending_payouts().await;
+            self.worker_health.record_run(Self::NAME, &result);
+
+            match result {
+                Ok(0) => {}
+                Ok(settled) => info!("Settled {} pending payouts", settled),
+                Err(e) => error!("Payout sync failed: {}", e),
+            }
+        }
+
+        info!("Payout sync worker stopped");
+    }
+}
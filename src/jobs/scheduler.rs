@@ -0,0 +1,59 @@
+use std::time::Duration;
+
+use tracing::{error, info};
+
+use crate::{
+    abstract_trait::scheduled_transfer::DynScheduledTransferService,
+    jobs::health::SharedWorkerHealth, utils::shutdown::Shutdown,
+};
+
+/// Background task that executes scheduled transfers once they fall due.
+pub struct TransferScheduler {
+    scheduled_transfer_service: DynScheduledTransferService,
+    interval: Duration,
+    worker_health: SharedWorkerHealth,
+}
+
+impl TransferScheduler {
+    /// Name the worker reports its health under.
+    pub const NAME: &str = "scheduler";
+
+    pub fn new(
+        scheduled_transfer_service: DynScheduledTransferService,
+        interval: Duration,
+        worker_health: SharedWorkerHealth,
+    ) -> Self {
+        worker_health.register(Self::NAME, interval);
+
+        Self {
+            scheduled_transfer_service,
+            interval,
+            worker_health,
+        }
+    }
+
+    /// Runs until `shutdown` is triggered, finishing a run in progress first.
+    pub async fn run(self, shutdown: Shutdown) {
+        info!("Transfer scheduler started");
+
+        let mut ticker = tokio::time::interval(self.interval);
+
+        loop {
+            tokio::select! {
+                _ = ticker.tick() => {}
+                _ = shutdown.triggered() => break,
+            }
+
+            let result = self.scheduled_transfer_service.run_due_schedules().await;
+            self.worker_health.record_run(Self::NAME, &result);
+
+            match result {
+                Ok(0) => {}
+                Ok(processed) => info!("Ran {} scheduled transfers", processed),
+                Err(e) => error!("Transfer scheduler failed: {}", e),
+            }
+        }
+
+        info!("Transfer scheduler stopped");
+    }
+}
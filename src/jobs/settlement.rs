@@ -0,0 +1,61 @@
+use std::time::Duration;
+
+use tracing::{error, info};
+
+use crate::{
+    abstract_trait::settlement::DynSettlementService, jobs::health::SharedWorkerHealth,
+    utils::shutdown::Shutdown,
+};
+
+/// Background task that settles the previous day for every merchant. Each
+/// merchant is settled once per day, so running it more often than daily
+/// only catches up after downtime.
+pub struct SettlementWorker {
+    settlement_service: DynSettlementService,
+    interval: Duration,
+    worker_health: SharedWorkerHealth,
+}
+
+impl SettlementWorker {
+    /// Name the worker reports its health under.
+    pub const NAME: &str = "settlement";
+
+    pub fn new(
+        settlement_service: DynSettlementService,
+        interval: Duration,
+        worker_health: SharedWorkerHealth,
+    ) -> Self {
+        worker_health.register(Self::NAME, interval);
+
+        Self {
+            settlement_service,
+            interval,
+            worker_health,
+        }
+    }
+
+    /// Runs until `shutdown` is triggered, finishing a run in progress first.
+    pub async fn run(self, shutdown: Shutdown) {
+        info!("Settlement worker started");
+
+        let mut ticker = tokio::time::interval(self.interval);
+
+        loop {
+            tokio::select! {
+                _ = ticker.tick() => {}
+                _ = shutdown.triggered() => break,
+            }
+
+            let result = self.settlement_service.run_daily_settlements().await;
+            self.worker_health.record_run(Self::NAME, &result);
+
+            match result {
+                Ok(0) => {}
+                Ok(created) => info!("Created {} merchant settlements", created),
+                Err(e) => error!("Daily settlement failed: {}", e),
+            }
+        }
+
+        info!("Settlement worker stopped");
+    }
+}
@@ -0,0 +1,59 @@
+use std::time::Duration;
+
+use tracing::{error, info};
+
+use crate::{
+    abstract_trait::transfer_batch::DynTransferBatchService, jobs::health::SharedWorkerHealth,
+    utils::shutdown::Shutdown,
+};
+
+/// Background worker that drains pending transfer batches.
+pub struct TransferBatchWorker {
+    transfer_batch_service: DynTransferBatchService,
+    interval: Duration,
+    worker_health: SharedWorkerHealth,
+}
+
+impl TransferBatchWorker {
+    /// Name the worker reports its health under.
+    pub const NAME: &str = "transfer_batch";
+
+    pub fn new(
+        transfer_batch_service: DynTransferBatchService,
+        interval: Duration,
+        worker_health: SharedWorkerHealth,
+    ) -> Self {
+        worker_health.register(Self::NAME, interval);
+
+        Self {
+            transfer_batch_service,
+            interval,
+            worker_health,
+        }
+    }
+
+    /// Runs until `shutdown` is triggered, finishing a run in progress first.
+    pub async fn run(self, shutdown: Shutdown) {
+        info!("Transfer batch worker started");
+
+        let mut ticker = tokio::time::interval(self.interval);
+
+        loop {
+            tokio::select! {
+                _ = ticker.tick() => {}
+                _ = shutdown.triggered() => break,
+            }
+
+            let result = self.transfer_batch_service.process_pending_batches().await;
+            self.worker_health.record_run(Self::NAME, &result);
+
+            match result {
+                Ok(0) => {}
+                Ok(processed) => info!("Processed {} transfer batches", processed),
+                Err(e) => error!("Transfer batch worker failed: {}", e),
+            }
+        }
+
+        info!("Transfer batch worker stopped");
+    }
+}
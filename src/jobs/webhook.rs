@@ -0,0 +1,60 @@
+use std::time::Duration;
+
+use tracing::{error, info};
+
+use crate::{
+    abstract_trait::webhook::DynWebhookService, jobs::health::SharedWorkerHealth,
+    utils::shutdown::Shutdown,
+};
+
+/// Background task that sends pending webhook deliveries from the outbox
+/// and retries failed ones once their backoff has passed.
+pub struct WebhookDeliveryWorker {
+    webhook_service: DynWebhookService,
+    interval: Duration,
+    worker_health: SharedWorkerHealth,
+}
+
+impl WebhookDeliveryWorker {
+    /// Name the worker reports its health under.
+    pub const NAME: &str = "webhook_dispatcher";
+
+    pub fn new(
+        webhook_service: DynWebhookService,
+        interval: Duration,
+        worker_health: SharedWorkerHealth,
+    ) -> Self {
+        worker_health.register(Self::NAME, interval);
+
+        Self {
+            webhook_service,
+            interval,
+            worker_health,
+        }
+    }
+
+    /// Runs until `shutdown` is triggered, finishing a run in progress first.
+    pub async fn run(self, shutdown: Shutdown) {
+        info!("Webhook delivery worker started");
+
+        let mut ticker = tokio::time::interval(self.interval);
+
+        loop {
+            tokio::select! {
+                _ = ticker.tick() => {}
+                _ = shutdown.triggered() => break,
+            }
+
+            let result = self.webhook_service.deliver_due_webhooks().await;
+            self.worker_health.record_run(Self::NAME, &result);
+
+            match result {
+                Ok(0) => {}
+                Ok(attempted) => info!("Attempted {} webhook deliveries", attempted),
+                Err(e) => error!("Webhook delivery failed: {}", e),
+            }
+        }
+
+        info!("Webhook delivery worker stopped");
+    }
+}
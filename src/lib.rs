@@ -3,7 +3,9 @@ pub mod config;
 pub mod domain;
 pub mod entities;
 pub mod handler;
+pub mod jobs;
 pub mod middleware;
+pub mod providers;
 pub mod migrations;
 pub mod repository;
 pub mod services;
@@ -1,29 +1,165 @@
+use std::{sync::Arc, time::Duration};
+
 use dotenv::dotenv;
+use sea_orm::DatabaseConnection;
+use tokio::task::JoinSet;
+use tracing::{info, warn};
+
+use example_payment_gateway_axum::abstract_trait::audit::AuditServiceTrait;
+use example_payment_gateway_axum::config::clock::SystemClock;
 
-use example_payment_gateway_axum::config::{config::Config, database::ConnectionManager};
+use example_payment_gateway_axum::config::{
+    config::Config,
+    database::{ConnectionManager, DatabasePools},
+};
 use example_payment_gateway_axum::handler::AppRouter;
-use example_payment_gateway_axum::migrations::m20220101_000001_create_table::Migration;
+use example_payment_gateway_axum::jobs::payout::PayoutSyncWorker;
+use example_payment_gateway_axum::jobs::scheduler::TransferScheduler;
+use example_payment_gateway_axum::jobs::settlement::SettlementWorker;
+use example_payment_gateway_axum::jobs::transfer_batch::TransferBatchWorker;
+use example_payment_gateway_axum::jobs::webhook::WebhookDeliveryWorker;
+use example_payment_gateway_axum::migrations::Migrator;
+use example_payment_gateway_axum::repository::audit::AuditRepository;
+use example_payment_gateway_axum::services::audit::AuditService;
 use example_payment_gateway_axum::state::AppState;
 use example_payment_gateway_axum::utils::log_tracing;
+use example_payment_gateway_axum::utils::shutdown::Shutdown;
+
+/// Walks the audit hash chain, exiting with status 1 when it is broken.
+async fn verify_audit_chain(db_pool: DatabaseConnection) -> Result<(), Box<dyn std::error::Error>> {
+    let audit_service = AuditService::new(
+        Arc::new(AuditRepository::new(db_pool)),
+        Arc::new(SystemClock),
+    );
 
+    let response = audit_service
+        .verify_chain()
+        .await
+        .map_err(|e| e.to_string())?;
+
+    println!("{}", serde_json::to_string_pretty(&response.data)?);
+
+    if !response.data.valid {
+        std::process::exit(1);
+    }
+
+    Ok(())
+}
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
     dotenv().ok();
 
-    log_tracing::tracing();
+    let config = match Config::load() {
+        Ok(config) => config,
+        Err(e) => {
+            eprintln!("{}", e);
+            std::process::exit(2);
+        }
+    };
+
+    if std::env::args().nth(1).as_deref() == Some("--print-config") {
+        print!("{}", config.to_redacted_toml());
+        return Ok(());
+    }
 
-    let config = Config::init();
+    let _tracing_guard = log_tracing::tracing(config.log_format, config.trace_exporter);
 
     let db_pool =
-        ConnectionManager::new_pool::<Migration>(&config.database_url, config.run_migrations)
+        ConnectionManager::new_pool::<Migrator>(config.connect_options(), config.run_migrations)
             .await?;
 
-    let port = config.port;
+    if std::env::args().nth(1).as_deref() == Some("verify-audit-chain") {
+        return verify_audit_chain(db_pool).await;
+    }
+
+    let replica = match config.replica_connect_options() {
+        Some(options) => Some(ConnectionManager::new_pool::<Migrator>(options, false).await?),
+        None => None,
+    };
+
+    let db_pools = DatabasePools::new(db_pool, replica);
+
+    let state = AppState::new(db_pools.clone(), &config);
 
-    let state = AppState::new(db_pool, &config.jwt_secret);
+    let shutdown = Shutdown::new();
+
+    tokio::spawn({
+        let shutdown = shutdown.clone();
+        async move { shutdown.trigger_on_signal().await }
+    });
+
+    let mut workers = JoinSet::new();
+
+    if config.features.background_jobs {
+        workers.spawn(
+            TransferBatchWorker::new(
+                state.di_container.transfer_batch_service.clone(),
+                Duration::from_secs(5),
+                state.worker_health.clone(),
+            )
+            .run(shutdown.clone()),
+        );
+
+        workers.spawn(
+            TransferScheduler::new(
+                state.di_container.scheduled_transfer_service.clone(),
+                Duration::from_secs(30),
+                state.worker_health.clone(),
+            )
+            .run(shutdown.clone()),
+        );
+
+        workers.spawn(
+            PayoutSyncWorker::new(
+                state.di_container.withdraw_service.clone(),
+                Duration::from_secs(15),
+                state.worker_health.clone(),
+            )
+            .run(shutdown.clone()),
+        );
+
+        workers.spawn(
+            WebhookDeliveryWorker::new(
+                state.di_container.webhook_service.clone(),
+                Duration::from_secs(5),
+                state.worker_health.clone(),
+            )
+            .run(shutdown.clone()),
+        );
+
+        workers.spawn(
+            SettlementWorker::new(
+                state.di_container.settlement_service.clone(),
+                Duration::from_secs(60 * 60),
+                state.worker_health.clone(),
+            )
+            .run(shutdown.clone()),
+        );
+    }
 
     println!("🚀 Server started successfully");
 
-    AppRouter::serve(port, state).await
+    // Once the server stops, let the workers finish their current run too
+    let drained = shutdown
+        .drain(config.shutdown_timeout(), async {
+            let served = AppRouter::serve(&config, state, shutdown.clone()).await;
+            shutdown.trigger();
+            workers.join_all().await;
+            served
+        })
+        .await;
+
+    match drained {
+        Some(served) => served?,
+        None => warn!(
+            "Shutdown timeout of {:?} elapsed, abandoning requests and jobs in flight",
+            config.shutdown_timeout()
+        ),
+    }
+
+    db_pools.close().await?;
+    info!("Shutdown complete");
+
+    Ok(())
 }
@@ -0,0 +1,55 @@
+use axum::{
+    body::Body,
+    extract::State,
+    http::{Request, StatusCode},
+    middleware::Next,
+    response::IntoResponse,
+    Json,
+};
+use std::sync::Arc;
+
+use crate::{
+    domain::response::{ErrorKind, ErrorResponse},
+    middleware::request_id::current_request_id,
+    state::AppState,
+};
+
+/// Role of staff accounts allowed on the admin endpoints.
+pub const ROLE_ADMIN: &str = "admin";
+
+/// Lets the request through only for admins. Runs after [`super::jwt::auth`],
+/// which puts the caller's user id in the request extensions.
+pub async fn admin(
+    State(data): State<Arc<AppState>>,
+    req: Request<Body>,
+    next: Next,
+) -> Result<impl IntoResponse, (StatusCode, Json<ErrorResponse>)> {
+    let forbidden = || {
+        (
+            StatusCode::FORBIDDEN,
+            Json(ErrorResponse {
+                status: "fail".to_string(),
+                message: "Admin access required".to_string(),
+                request_id: current_request_id(),
+                kind: ErrorKind::Other,
+            }),
+        )
+    };
+
+    let user_id = *req.extensions().get::<i64>().ok_or_else(forbidden)?;
+
+    let is_admin = data
+        .di_container
+        .user_service
+        .find_by_id(user_id as i32)
+        .await
+        .ok()
+        .and_then(|response| response.data)
+        .is_some_and(|user| user.role == ROLE_ADMIN);
+
+    if !is_admin {
+        return Err(forbidden());
+    }
+
+    Ok(next.run(req).await)
+}
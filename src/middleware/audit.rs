@@ -0,0 +1,180 @@
+use axum::{
+    body::{to_bytes, Body},
+    extract::{ConnectInfo, MatchedPath, State},
+    http::{HeaderMap, Method, Request},
+    middleware::Next,
+    response::Response,
+};
+use axum_extra::extract::cookie::CookieJar;
+use std::{
+    net::{IpAddr, SocketAddr},
+    sync::Arc,
+};
+use tracing::error;
+use uuid::Uuid;
+
+use crate::{
+    domain::request::audit::{redact_snapshot, CreateAuditEvent},
+    middleware::jwt::request_token,
+    state::AppState,
+};
+
+fn is_state_changing(method: &Method) -> bool {
+    matches!(
+        *method,
+        Method::POST | Method::PUT | Method::PATCH | Method::DELETE
+    )
+}
+
+/// Resource type and id of a request, e.g. `kyc/submissions` and `5` for
+/// `/api/admin/kyc/submissions/5/review` routed as
+/// `/api/admin/kyc/submissions/{id}/review`.
+fn resource_of(route: &str, path: &str) -> (String, Option<String>) {
+    let mut resource = Vec::new();
+    let mut resource_id = None;
+
+    for (pattern, segment) in route.split('/').zip(path.split('/')) {
+        if matches!(pattern, "" | "api" | "admin") {
+            continue;
+        }
+
+        if pattern.starts_with('{') {
+            resource_id = Some(segment.to_string());
+            break;
+        }
+
+        resource.push(pattern);
+    }
+
+    (resource.join("/"), resource_id)
+}
+
+/// Address of the client that sent a request. Starting at the peer, each
+/// trusted proxy is replaced by the address it appended to
+/// `X-Forwarded-For`, so entries a client wrote itself are never believed.
+pub fn client_ip<B>(req: &Request<B>, trusted_proxies: &[IpAddr]) -> Option<String> {
+    let mut client = req
+        .extensions()
+        .get::<ConnectInfo<SocketAddr>>()
+        .map(|ConnectInfo(addr)| addr.ip())?;
+
+    let forwarded: Vec<&str> = req
+        .headers()
+        .get_all("x-forwarded-for")
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+        .collect();
+
+    for hop in forwarded.iter().rev() {
+        if !trusted_proxies.contains(&client) {
+            break;
+        }
+
+        match hop.trim().parse() {
+            Ok(ip) => client = ip,
+            Err(_) => break,
+        }
+    }
+
+    Some(client.to_string())
+}
+
+fn request_id(headers: &HeaderMap) -> String {
+    headers
+        .get("x-request-id")
+        .and_then(|value| value.to_str().ok())
+        .map(str::to_owned)
+        .unwrap_or_else(|| Uuid::new_v4().to_string())
+}
+
+/// Appends every successful state-changing request to the audit trail,
+/// with snapshots of the resource from before and after the handler ran.
+/// Resources without a snapshot fall back to the `data` of the response.
+pub async fn audit(State(data): State<Arc<AppState>>, req: Request<Body>, next: Next) -> Response {
+    let Some(route) = req
+        .extensions()
+        .get::<MatchedPath>()
+        .map(|path| path.as_str().to_owned())
+        .filter(|_| is_state_changing(req.method()))
+    else {
+        return next.run(req).await;
+    };
+
+    let audit_service = data.di_container.audit_service.clone();
+    let (resource_type, resource_id) = resource_of(&route, req.uri().path());
+    let snapshot_id = resource_id.as_deref().and_then(|id| id.parse::<i32>().ok());
+
+    let actor_id = request_token(&CookieJar::from_headers(req.headers()), req.headers())
+        .and_then(|token| data.jwt_config.verify_token(&token).ok())
+        .map(|user_id| user_id as i32);
+
+    let mut event = CreateAuditEvent {
+        actor_id,
+        action: format!("{} {}", req.method(), route),
+        resource_type,
+        resource_id,
+        before: None,
+        after: None,
+        client_ip: client_ip(&req, &data.trusted_proxies),
+        request_id: request_id(req.headers()),
+    };
+
+    if let Some(id) = snapshot_id {
+        event.before = audit_service
+            .snapshot(&event.resource_type, id)
+            .await
+            .ok()
+            .flatten();
+    }
+
+    let response = next.run(req).await;
+
+    if !response.status().is_success() {
+        return response;
+    }
+
+    let snapshot = match snapshot_id {
+        Some(id) => audit_service
+            .snapshot(&event.resource_type, id)
+            .await
+            .ok()
+            .flatten(),
+        None => None,
+    };
+
+    let response = if event.before.is_some() || snapshot.is_some() {
+        event.after = snapshot;
+        response
+    } else {
+        let (parts, body) = response.into_parts();
+
+        let bytes = match to_bytes(body, usize::MAX).await {
+            Ok(bytes) => bytes,
+            Err(e) => {
+                error!("Failed to read response of {}: {}", event.action, e);
+                return Response::from_parts(parts, Body::empty());
+            }
+        };
+
+        event.after = serde_json::from_slice::<serde_json::Value>(&bytes)
+            .ok()
+            .and_then(|body| body.get("data").cloned())
+            .filter(|data| data.is_object() || data.is_array())
+            .map(|mut data| {
+                redact_snapshot(&mut data);
+                data
+            });
+
+        Response::from_parts(parts, Body::from(bytes))
+    };
+
+    if let Err(e) = audit_service.record(&event).await {
+        error!(
+            "Failed to audit {} by {:?} (request {}): {}",
+            event.action, event.actor_id, event.request_id, e
+        );
+    }
+
+    response
+}
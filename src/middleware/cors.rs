@@ -0,0 +1,33 @@
+use axum::http::{header, HeaderValue, Method};
+use tower_http::cors::{AllowOrigin, CorsLayer};
+
+/// CORS for `allowed_origins`, `None` when no cross-origin caller is
+/// allowed. A single `*` allows any origin.
+pub fn cors(allowed_origins: &[String]) -> Option<CorsLayer> {
+    let allow_origin = match allowed_origins {
+        [] => return None,
+        [origin] if origin == "*" => AllowOrigin::any(),
+        origins => AllowOrigin::list(
+            origins
+                .iter()
+                .filter_map(|origin| HeaderValue::from_str(origin).ok()),
+        ),
+    };
+
+    Some(
+        CorsLayer::new()
+            .allow_origin(allow_origin)
+            .allow_methods([
+                Method::GET,
+                Method::POST,
+                Method::PUT,
+                Method::PATCH,
+                Method::DELETE,
+            ])
+            .allow_headers([header::AUTHORIZATION, header::CONTENT_TYPE])
+            .expose_headers([
+                crate::middleware::request_id::REQUEST_ID_HEADER,
+                crate::middleware::request_id::TRACE_ID_HEADER,
+            ]),
+    )
+}
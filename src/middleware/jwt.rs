@@ -1,7 +1,7 @@
 use axum::{
     body::Body,
     extract::State,
-    http::{header, Request, StatusCode},
+    http::{header, HeaderMap, Request, StatusCode},
     middleware::Next,
     response::IntoResponse,
     Json,
@@ -9,7 +9,24 @@ use axum::{
 use axum_extra::extract::cookie::CookieJar;
 use std::sync::Arc;
 
-use crate::{domain::response::ErrorResponse, state::AppState};
+use crate::{
+    domain::response::{ErrorKind, ErrorResponse},
+    middleware::request_id::current_request_id,
+    state::AppState,
+};
+
+/// Token from the `token` cookie or the bearer `Authorization` header.
+pub fn request_token(cookie_jar: &CookieJar, headers: &HeaderMap) -> Option<String> {
+    cookie_jar
+        .get("token")
+        .map(|cookie| cookie.value().to_string())
+        .or_else(|| {
+            headers
+                .get(header::AUTHORIZATION)
+                .and_then(|auth_header| auth_header.to_str().ok())
+                .and_then(|auth_value| auth_value.strip_prefix("Bearer ").map(str::to_owned))
+        })
+}
 
 pub async fn auth(
     cookie_jar: CookieJar,
@@ -18,21 +35,7 @@ pub async fn auth(
     next: Next,
 ) -> Result<impl IntoResponse, (StatusCode, Json<ErrorResponse>)> {
     
-    let token = cookie_jar
-        .get("token")
-        .map(|cookie| cookie.value().to_string())
-        .or_else(|| {
-            req.headers()
-                .get(header::AUTHORIZATION)
-                .and_then(|auth_header| auth_header.to_str().ok())
-                .and_then(|auth_value| {
-                    if auth_value.starts_with("Bearer ") {
-                        Some(auth_value[7..].to_owned())
-                    } else {
-                        None
-                    }
-                })
-        });
+    let token = request_token(&cookie_jar, req.headers());
 
     // Check if token exists
     let token = match token {
@@ -43,6 +46,8 @@ pub async fn auth(
                 Json(ErrorResponse {
                     status: "fail".to_string(),
                     message: "You are not logged in, please provide token".to_string(),
+                    request_id: current_request_id(),
+                    kind: ErrorKind::Other,
                 }),
             ));
         }
@@ -57,6 +62,8 @@ pub async fn auth(
                 Json(ErrorResponse {
                     status: "fail".to_string(),
                     message: "Invalid token".to_string(),
+                    request_id: current_request_id(),
+                    kind: ErrorKind::Other,
                 }),
             ));
         }
@@ -64,6 +71,7 @@ pub async fn auth(
 
     // Insert user_id into request extensions
     req.extensions_mut().insert(user_id);
+    tracing::Span::current().record("user_id", user_id);
     
    
     Ok(next.run(req).await)
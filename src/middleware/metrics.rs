@@ -0,0 +1,27 @@
+use axum::{body::Body, extract::MatchedPath, http::Request, middleware::Next, response::Response};
+use std::time::Instant;
+
+use crate::utils::metrics::record_http_request;
+
+/// Counts and times every request by method, route and status. Requests
+/// that match no route share one label so paths cannot grow the series.
+pub async fn track_http(req: Request<Body>, next: Next) -> Response {
+    let method = req.method().to_string();
+    let route = req
+        .extensions()
+        .get::<MatchedPath>()
+        .map_or("unmatched", |path| path.as_str())
+        .to_owned();
+
+    let started = Instant::now();
+    let response = next.run(req).await;
+
+    record_http_request(
+        &method,
+        &route,
+        response.status().as_u16(),
+        started.elapsed().as_secs_f64(),
+    );
+
+    response
+}
@@ -1 +1,8 @@
-pub mod jwt;
\ No newline at end of file
+pub mod admin;
+pub mod audit;
+pub mod cors;
+pub mod jwt;
+pub mod metrics;
+pub mod rate_limit;
+pub mod read_replica;
+pub mod request_id;
\ No newline at end of file
@@ -0,0 +1,108 @@
+use axum::{
+    body::Body,
+    extract::{ConnectInfo, State},
+    http::{header, HeaderValue, Request, StatusCode},
+    middleware::Next,
+    response::{IntoResponse, Response},
+    Json,
+};
+use std::{
+    collections::HashMap,
+    net::{IpAddr, SocketAddr},
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+use crate::{
+    domain::response::{ErrorKind, ErrorResponse},
+    middleware::request_id::current_request_id,
+};
+
+const WINDOW: Duration = Duration::from_secs(60);
+
+/// Clients tracked before windows that already ended are dropped.
+const PRUNE_ABOVE: usize = 10_000;
+
+struct Window {
+    started: Instant,
+    requests: u32,
+}
+
+/// Fixed one minute window request counter per client IP.
+pub struct RateLimiter {
+    requests_per_minute: u32,
+    clients: Mutex<HashMap<IpAddr, Window>>,
+}
+
+impl RateLimiter {
+    pub fn new(requests_per_minute: u32) -> Self {
+        Self {
+            requests_per_minute,
+            clients: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Counts a request of `client`, returning how long it has to wait when
+    /// it is over the limit.
+    pub fn check(&self, client: IpAddr, now: Instant) -> Result<(), Duration> {
+        let mut clients = self.clients.lock().unwrap();
+
+        if clients.len() > PRUNE_ABOVE {
+            clients.retain(|_, window| now.duration_since(window.started) < WINDOW);
+        }
+
+        let window = clients.entry(client).or_insert(Window {
+            started: now,
+            requests: 0,
+        });
+
+        if now.duration_since(window.started) >= WINDOW {
+            window.started = now;
+            window.requests = 0;
+        }
+
+        if window.requests >= self.requests_per_minute {
+            return Err(WINDOW.saturating_sub(now.duration_since(window.started)));
+        }
+
+        window.requests += 1;
+        Ok(())
+    }
+}
+
+/// Answers `429 Too Many Requests` once a client IP is over its limit.
+/// Requests without a peer address are let through.
+pub async fn rate_limit(
+    State(limiter): State<Arc<RateLimiter>>,
+    req: Request<Body>,
+    next: Next,
+) -> Response {
+    let client = req
+        .extensions()
+        .get::<ConnectInfo<SocketAddr>>()
+        .map(|ConnectInfo(addr)| addr.ip());
+
+    if let Some(client) = client {
+        if let Err(retry_after) = limiter.check(client, Instant::now()) {
+            let mut response = (
+                StatusCode::TOO_MANY_REQUESTS,
+                Json(ErrorResponse {
+                    status: "fail".to_string(),
+                    message: "Too many requests, please try again later".to_string(),
+                    request_id: current_request_id(),
+                    kind: ErrorKind::Other,
+                }),
+            )
+                .into_response();
+
+            response.headers_mut().insert(
+                header::RETRY_AFTER,
+                HeaderValue::from(retry_after.as_secs().max(1)),
+            );
+
+            return response;
+        }
+    }
+
+    next.run(req).await
+}
@@ -0,0 +1,18 @@
+use axum::{
+    body::Body,
+    http::{Method, Request},
+    middleware::Next,
+    response::Response,
+};
+
+use crate::config::database::reading_from_replica;
+
+/// Lets GET and HEAD requests read from the replica. Other requests change
+/// data, so their reads stay on the primary and see their own writes.
+pub async fn read_replica(req: Request<Body>, next: Next) -> Response {
+    if matches!(*req.method(), Method::GET | Method::HEAD) {
+        reading_from_replica(next.run(req)).await
+    } else {
+        next.run(req).await
+    }
+}
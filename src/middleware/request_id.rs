@@ -0,0 +1,114 @@
+use axum::{
+    body::Body,
+    extract::MatchedPath,
+    http::{HeaderName, HeaderValue, Request},
+    middleware::Next,
+    response::Response,
+};
+use opentelemetry::trace::{TraceContextExt, TraceId};
+use std::time::Instant;
+use tracing::{field, info, Instrument};
+use tracing_opentelemetry::OpenTelemetrySpanExt;
+use uuid::Uuid;
+
+pub const REQUEST_ID_HEADER: HeaderName = HeaderName::from_static("x-request-id");
+pub const TRACE_ID_HEADER: HeaderName = HeaderName::from_static("x-trace-id");
+
+/// Longest client supplied request id that is kept, longer ones are replaced.
+const MAX_REQUEST_ID_LEN: usize = 128;
+
+tokio::task_local! {
+    static REQUEST_ID: String;
+}
+
+/// Id of the request being handled by the current task, if any.
+pub fn current_request_id() -> Option<String> {
+    REQUEST_ID.try_with(Clone::clone).ok()
+}
+
+/// Runs `future` as part of the request `request_id`.
+pub async fn with_request_id<F: std::future::Future>(request_id: String, future: F) -> F::Output {
+    REQUEST_ID.scope(request_id, future).await
+}
+
+fn accepted_request_id(value: &HeaderValue) -> Option<String> {
+    value
+        .to_str()
+        .ok()
+        .map(str::trim)
+        .filter(|id| !id.is_empty() && id.len() <= MAX_REQUEST_ID_LEN)
+        .filter(|id| {
+            id.chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.' | ':'))
+        })
+        .map(str::to_owned)
+}
+
+/// Accepts the caller's `X-Request-Id` or generates one, handles the request
+/// inside an `http_request` span carrying it and echoes it in the response.
+/// When spans are exported the trace id is logged and returned as
+/// `X-Trace-Id`, and the exported trace carries the request id.
+pub async fn request_id(mut req: Request<Body>, next: Next) -> Response {
+    let request_id = req
+        .headers()
+        .get(&REQUEST_ID_HEADER)
+        .and_then(accepted_request_id)
+        .unwrap_or_else(|| Uuid::new_v4().to_string());
+
+    let header_value =
+        HeaderValue::from_str(&request_id).expect("request ids are valid header values");
+    req.headers_mut()
+        .insert(REQUEST_ID_HEADER, header_value.clone());
+
+    let method = req.method().clone();
+    let route = req
+        .extensions()
+        .get::<MatchedPath>()
+        .map(|path| path.as_str().to_owned())
+        .unwrap_or_else(|| req.uri().path().to_owned());
+
+    let span = tracing::info_span!(
+        "http_request",
+        %method,
+        route = %route,
+        request_id = %request_id,
+        trace_id = field::Empty,
+        user_id = field::Empty,
+        status = field::Empty,
+        latency_ms = field::Empty,
+    );
+
+    let trace_id = span.context().span().span_context().trace_id();
+    let trace_id = (trace_id != TraceId::INVALID).then(|| trace_id.to_string());
+
+    if let Some(trace_id) = &trace_id {
+        span.record("trace_id", trace_id.as_str());
+    }
+
+    let started = Instant::now();
+
+    let mut response = with_request_id(request_id, next.run(req))
+        .instrument(span.clone())
+        .await;
+
+    let latency_ms = started.elapsed().as_millis() as u64;
+    span.record("status", response.status().as_u16());
+    span.record("latency_ms", latency_ms);
+
+    span.in_scope(|| {
+        info!(
+            status = response.status().as_u16(),
+            latency_ms, "{} {} finished", method, route
+        )
+    });
+
+    response
+        .headers_mut()
+        .insert(REQUEST_ID_HEADER, header_value);
+
+    if let Some(value) = trace_id.and_then(|id| HeaderValue::from_str(&id).ok()) {
+        response.headers_mut().insert(TRACE_ID_HEADER, value);
+    }
+
+    response
+}
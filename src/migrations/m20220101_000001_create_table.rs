@@ -288,6 +288,7 @@ enum Topups {
 }
 
 #[derive(Iden)]
+#[allow(clippy::enum_variant_names)]
 enum Saldo {
     Table,
     SaldoId,
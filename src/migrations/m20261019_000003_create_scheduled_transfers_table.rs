@@ -0,0 +1,205 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Create Scheduled Transfers Table
+        let scheduled_transfers_table = Table::create()
+            .table(ScheduledTransfers::Table)
+            .if_not_exists()
+            .col(
+                ColumnDef::new(ScheduledTransfers::ScheduleId)
+                    .integer()
+                    .not_null()
+                    .auto_increment()
+                    .primary_key(),
+            )
+            .col(
+                ColumnDef::new(ScheduledTransfers::TransferFrom)
+                    .integer()
+                    .not_null(),
+            )
+            .col(
+                ColumnDef::new(ScheduledTransfers::TransferTo)
+                    .integer()
+                    .not_null(),
+            )
+            .col(
+                ColumnDef::new(ScheduledTransfers::TransferAmount)
+                    .integer()
+                    .not_null(),
+            )
+            .col(
+                ColumnDef::new(ScheduledTransfers::Frequency)
+                    .string_len(20)
+                    .not_null(),
+            )
+            .col(ColumnDef::new(ScheduledTransfers::DayOfMonth).integer())
+            .col(
+                ColumnDef::new(ScheduledTransfers::StartAt)
+                    .timestamp()
+                    .not_null(),
+            )
+            .col(
+                ColumnDef::new(ScheduledTransfers::NextRunAt)
+                    .timestamp()
+                    .not_null(),
+            )
+            .col(
+                ColumnDef::new(ScheduledTransfers::Status)
+                    .string_len(20)
+                    .not_null()
+                    .default("active"),
+            )
+            .col(
+                ColumnDef::new(ScheduledTransfers::RetryCount)
+                    .integer()
+                    .not_null()
+                    .default(0),
+            )
+            .col(ColumnDef::new(ScheduledTransfers::LastRunAt).timestamp())
+            .col(
+                ColumnDef::new(ScheduledTransfers::CreatedAt)
+                    .timestamp()
+                    .default(Expr::current_timestamp()),
+            )
+            .col(
+                ColumnDef::new(ScheduledTransfers::UpdatedAt)
+                    .timestamp()
+                    .default(Expr::current_timestamp()),
+            )
+            .foreign_key(
+                ForeignKey::create()
+                    .name("fk-scheduled_transfers-transfer_from")
+                    .from(ScheduledTransfers::Table, ScheduledTransfers::TransferFrom)
+                    .to(Users::Table, Users::UserId)
+                    .on_update(ForeignKeyAction::Cascade)
+                    .on_delete(ForeignKeyAction::Cascade),
+            )
+            .foreign_key(
+                ForeignKey::create()
+                    .name("fk-scheduled_transfers-transfer_to")
+                    .from(ScheduledTransfers::Table, ScheduledTransfers::TransferTo)
+                    .to(Users::Table, Users::UserId)
+                    .on_update(ForeignKeyAction::Cascade)
+                    .on_delete(ForeignKeyAction::Cascade),
+            )
+            .to_owned();
+        manager.create_table(scheduled_transfers_table).await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name("idx-scheduled_transfers-status-next_run_at")
+                    .table(ScheduledTransfers::Table)
+                    .col(ScheduledTransfers::Status)
+                    .col(ScheduledTransfers::NextRunAt)
+                    .to_owned(),
+            )
+            .await?;
+
+        // Create Scheduled Transfer Attempts Table
+        let attempts_table = Table::create()
+            .table(ScheduledTransferAttempts::Table)
+            .if_not_exists()
+            .col(
+                ColumnDef::new(ScheduledTransferAttempts::AttemptId)
+                    .integer()
+                    .not_null()
+                    .auto_increment()
+                    .primary_key(),
+            )
+            .col(
+                ColumnDef::new(ScheduledTransferAttempts::ScheduleId)
+                    .integer()
+                    .not_null(),
+            )
+            .col(
+                ColumnDef::new(ScheduledTransferAttempts::Status)
+                    .string_len(20)
+                    .not_null(),
+            )
+            .col(ColumnDef::new(ScheduledTransferAttempts::TransferId).integer())
+            .col(ColumnDef::new(ScheduledTransferAttempts::ErrorMessage).text())
+            .col(
+                ColumnDef::new(ScheduledTransferAttempts::AttemptedAt)
+                    .timestamp()
+                    .not_null(),
+            )
+            .col(
+                ColumnDef::new(ScheduledTransferAttempts::CreatedAt)
+                    .timestamp()
+                    .default(Expr::current_timestamp()),
+            )
+            .foreign_key(
+                ForeignKey::create()
+                    .name("fk-scheduled_transfer_attempts-schedule_id")
+                    .from(
+                        ScheduledTransferAttempts::Table,
+                        ScheduledTransferAttempts::ScheduleId,
+                    )
+                    .to(ScheduledTransfers::Table, ScheduledTransfers::ScheduleId)
+                    .on_update(ForeignKeyAction::Cascade)
+                    .on_delete(ForeignKeyAction::Cascade),
+            )
+            .to_owned();
+        manager.create_table(attempts_table).await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(
+                Table::drop()
+                    .table(ScheduledTransferAttempts::Table)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .drop_table(Table::drop().table(ScheduledTransfers::Table).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(Iden)]
+enum Users {
+    Table,
+    UserId,
+}
+
+#[derive(Iden)]
+enum ScheduledTransfers {
+    Table,
+    ScheduleId,
+    TransferFrom,
+    TransferTo,
+    TransferAmount,
+    Frequency,
+    DayOfMonth,
+    StartAt,
+    NextRunAt,
+    Status,
+    RetryCount,
+    LastRunAt,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[derive(Iden)]
+enum ScheduledTransferAttempts {
+    Table,
+    AttemptId,
+    ScheduleId,
+    Status,
+    TransferId,
+    ErrorMessage,
+    AttemptedAt,
+    CreatedAt,
+}
@@ -0,0 +1,103 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Create Beneficiaries Table
+        let beneficiaries_table = Table::create()
+            .table(Beneficiaries::Table)
+            .if_not_exists()
+            .col(
+                ColumnDef::new(Beneficiaries::BeneficiaryId)
+                    .integer()
+                    .not_null()
+                    .auto_increment()
+                    .primary_key(),
+            )
+            .col(ColumnDef::new(Beneficiaries::UserId).integer().not_null())
+            .col(
+                ColumnDef::new(Beneficiaries::Alias)
+                    .string_len(100)
+                    .not_null(),
+            )
+            .col(
+                ColumnDef::new(Beneficiaries::BeneficiaryType)
+                    .string_len(20)
+                    .not_null(),
+            )
+            .col(ColumnDef::new(Beneficiaries::NocTransfer).string_len(255))
+            .col(ColumnDef::new(Beneficiaries::BankCode).string_len(20))
+            .col(ColumnDef::new(Beneficiaries::BankAccountNumber).string_len(34))
+            .col(
+                ColumnDef::new(Beneficiaries::Verified)
+                    .boolean()
+                    .not_null()
+                    .default(false),
+            )
+            .col(
+                ColumnDef::new(Beneficiaries::CreatedAt)
+                    .timestamp()
+                    .default(Expr::current_timestamp()),
+            )
+            .col(
+                ColumnDef::new(Beneficiaries::UpdatedAt)
+                    .timestamp()
+                    .default(Expr::current_timestamp()),
+            )
+            .foreign_key(
+                ForeignKey::create()
+                    .name("fk-beneficiaries-user_id")
+                    .from(Beneficiaries::Table, Beneficiaries::UserId)
+                    .to(Users::Table, Users::UserId)
+                    .on_update(ForeignKeyAction::Cascade)
+                    .on_delete(ForeignKeyAction::Cascade),
+            )
+            .to_owned();
+        manager.create_table(beneficiaries_table).await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name("idx-beneficiaries-user_id")
+                    .table(Beneficiaries::Table)
+                    .col(Beneficiaries::UserId)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(Beneficiaries::Table).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(Iden)]
+enum Users {
+    Table,
+    UserId,
+}
+
+#[derive(Iden)]
+enum Beneficiaries {
+    Table,
+    BeneficiaryId,
+    UserId,
+    Alias,
+    BeneficiaryType,
+    NocTransfer,
+    BankCode,
+    BankAccountNumber,
+    Verified,
+    CreatedAt,
+    UpdatedAt,
+}
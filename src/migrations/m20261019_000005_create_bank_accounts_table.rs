@@ -0,0 +1,171 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Create Bank Accounts Table
+        let bank_accounts_table = Table::create()
+            .table(BankAccounts::Table)
+            .if_not_exists()
+            .col(
+                ColumnDef::new(BankAccounts::BankAccountId)
+                    .integer()
+                    .not_null()
+                    .auto_increment()
+                    .primary_key(),
+            )
+            .col(ColumnDef::new(BankAccounts::UserId).integer().not_null())
+            .col(
+                ColumnDef::new(BankAccounts::BankCode)
+                    .string_len(20)
+                    .not_null(),
+            )
+            .col(
+                ColumnDef::new(BankAccounts::AccountNumber)
+                    .string_len(34)
+                    .not_null(),
+            )
+            .col(
+                ColumnDef::new(BankAccounts::HolderName)
+                    .string_len(100)
+                    .not_null(),
+            )
+            .col(
+                ColumnDef::new(BankAccounts::CreatedAt)
+                    .timestamp()
+                    .default(Expr::current_timestamp()),
+            )
+            .col(
+                ColumnDef::new(BankAccounts::UpdatedAt)
+                    .timestamp()
+                    .default(Expr::current_timestamp()),
+            )
+            .foreign_key(
+                ForeignKey::create()
+                    .name("fk-bank_accounts-user_id")
+                    .from(BankAccounts::Table, BankAccounts::UserId)
+                    .to(Users::Table, Users::UserId)
+                    .on_update(ForeignKeyAction::Cascade)
+                    .on_delete(ForeignKeyAction::Cascade),
+            )
+            .to_owned();
+        manager.create_table(bank_accounts_table).await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name("idx-bank_accounts-user_id")
+                    .table(BankAccounts::Table)
+                    .col(BankAccounts::UserId)
+                    .to_owned(),
+            )
+            .await?;
+
+        // Payout tracking on Withdraws. Existing rows were paid out
+        // immediately, so they default to succeeded.
+        let withdraw_columns = [
+            ColumnDef::new(Withdraws::BankAccountId)
+                .integer()
+                .to_owned(),
+            ColumnDef::new(Withdraws::Status)
+                .string_len(20)
+                .not_null()
+                .default("succeeded")
+                .to_owned(),
+            ColumnDef::new(Withdraws::ProviderReference)
+                .string_len(100)
+                .to_owned(),
+            ColumnDef::new(Withdraws::FailureReason)
+                .string_len(255)
+                .to_owned(),
+        ];
+
+        for mut column in withdraw_columns {
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(Withdraws::Table)
+                        .add_column(&mut column)
+                        .to_owned(),
+                )
+                .await?;
+        }
+
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name("idx-withdraws-status")
+                    .table(Withdraws::Table)
+                    .col(Withdraws::Status)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("idx-withdraws-status")
+                    .table(Withdraws::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        for column in [
+            Withdraws::FailureReason,
+            Withdraws::ProviderReference,
+            Withdraws::Status,
+            Withdraws::BankAccountId,
+        ] {
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(Withdraws::Table)
+                        .drop_column(column)
+                        .to_owned(),
+                )
+                .await?;
+        }
+
+        manager
+            .drop_table(Table::drop().table(BankAccounts::Table).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(Iden)]
+enum Users {
+    Table,
+    UserId,
+}
+
+#[derive(Iden)]
+enum Withdraws {
+    Table,
+    BankAccountId,
+    Status,
+    ProviderReference,
+    FailureReason,
+}
+
+#[derive(Iden)]
+enum BankAccounts {
+    Table,
+    BankAccountId,
+    UserId,
+    BankCode,
+    AccountNumber,
+    HolderName,
+    CreatedAt,
+    UpdatedAt,
+}
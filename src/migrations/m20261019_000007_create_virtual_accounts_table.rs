@@ -0,0 +1,195 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Create Virtual Accounts Table
+        let virtual_accounts_table = Table::create()
+            .table(VirtualAccounts::Table)
+            .if_not_exists()
+            .col(
+                ColumnDef::new(VirtualAccounts::VirtualAccountId)
+                    .integer()
+                    .not_null()
+                    .auto_increment()
+                    .primary_key(),
+            )
+            .col(ColumnDef::new(VirtualAccounts::UserId).integer().not_null())
+            .col(
+                ColumnDef::new(VirtualAccounts::BankCode)
+                    .string_len(20)
+                    .not_null(),
+            )
+            .col(
+                ColumnDef::new(VirtualAccounts::VaNumber)
+                    .string_len(34)
+                    .not_null(),
+            )
+            .col(
+                ColumnDef::new(VirtualAccounts::CreatedAt)
+                    .timestamp()
+                    .default(Expr::current_timestamp()),
+            )
+            .col(
+                ColumnDef::new(VirtualAccounts::UpdatedAt)
+                    .timestamp()
+                    .default(Expr::current_timestamp()),
+            )
+            .foreign_key(
+                ForeignKey::create()
+                    .name("fk-virtual_accounts-user_id")
+                    .from(VirtualAccounts::Table, VirtualAccounts::UserId)
+                    .to(Users::Table, Users::UserId)
+                    .on_update(ForeignKeyAction::Cascade)
+                    .on_delete(ForeignKeyAction::Cascade),
+            )
+            .to_owned();
+        manager.create_table(virtual_accounts_table).await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name("idx-virtual_accounts-bank_code-va_number")
+                    .table(VirtualAccounts::Table)
+                    .col(VirtualAccounts::BankCode)
+                    .col(VirtualAccounts::VaNumber)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name("idx-virtual_accounts-user_id-bank_code")
+                    .table(VirtualAccounts::Table)
+                    .col(VirtualAccounts::UserId)
+                    .col(VirtualAccounts::BankCode)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        // Create Suspense Payments Table
+        let suspense_payments_table = Table::create()
+            .table(SuspensePayments::Table)
+            .if_not_exists()
+            .col(
+                ColumnDef::new(SuspensePayments::SuspensePaymentId)
+                    .integer()
+                    .not_null()
+                    .auto_increment()
+                    .primary_key(),
+            )
+            .col(
+                ColumnDef::new(SuspensePayments::PaymentId)
+                    .string_len(100)
+                    .not_null(),
+            )
+            .col(
+                ColumnDef::new(SuspensePayments::BankCode)
+                    .string_len(20)
+                    .not_null(),
+            )
+            .col(
+                ColumnDef::new(SuspensePayments::VaNumber)
+                    .string_len(34)
+                    .not_null(),
+            )
+            .col(ColumnDef::new(SuspensePayments::Amount).integer().not_null())
+            .col(
+                ColumnDef::new(SuspensePayments::Status)
+                    .string_len(20)
+                    .not_null()
+                    .default("unresolved"),
+            )
+            .col(ColumnDef::new(SuspensePayments::ResolvedUserId).integer())
+            .col(ColumnDef::new(SuspensePayments::TopupId).integer())
+            .col(
+                ColumnDef::new(SuspensePayments::CreatedAt)
+                    .timestamp()
+                    .default(Expr::current_timestamp()),
+            )
+            .col(
+                ColumnDef::new(SuspensePayments::UpdatedAt)
+                    .timestamp()
+                    .default(Expr::current_timestamp()),
+            )
+            .to_owned();
+        manager.create_table(suspense_payments_table).await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name("idx-suspense_payments-payment_id")
+                    .table(SuspensePayments::Table)
+                    .col(SuspensePayments::PaymentId)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name("idx-suspense_payments-status")
+                    .table(SuspensePayments::Table)
+                    .col(SuspensePayments::Status)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(SuspensePayments::Table).to_owned())
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(VirtualAccounts::Table).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(Iden)]
+enum Users {
+    Table,
+    UserId,
+}
+
+#[derive(Iden)]
+enum VirtualAccounts {
+    Table,
+    VirtualAccountId,
+    UserId,
+    BankCode,
+    VaNumber,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[derive(Iden)]
+enum SuspensePayments {
+    Table,
+    SuspensePaymentId,
+    PaymentId,
+    BankCode,
+    VaNumber,
+    Amount,
+    Status,
+    ResolvedUserId,
+    TopupId,
+    CreatedAt,
+    UpdatedAt,
+}
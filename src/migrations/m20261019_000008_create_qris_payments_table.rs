@@ -0,0 +1,113 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Create Qris Payments Table
+        let qris_payments_table = Table::create()
+            .table(QrisPayments::Table)
+            .if_not_exists()
+            .col(
+                ColumnDef::new(QrisPayments::QrisPaymentId)
+                    .integer()
+                    .not_null()
+                    .auto_increment()
+                    .primary_key(),
+            )
+            .col(ColumnDef::new(QrisPayments::MerchantId).integer().not_null())
+            .col(
+                ColumnDef::new(QrisPayments::Reference)
+                    .string_len(25)
+                    .not_null(),
+            )
+            .col(ColumnDef::new(QrisPayments::Amount).integer().not_null())
+            .col(ColumnDef::new(QrisPayments::Payload).text().not_null())
+            .col(
+                ColumnDef::new(QrisPayments::Status)
+                    .string_len(20)
+                    .not_null()
+                    .default("pending"),
+            )
+            .col(ColumnDef::new(QrisPayments::PayerId).integer())
+            .col(ColumnDef::new(QrisPayments::TransferId).integer())
+            .col(ColumnDef::new(QrisPayments::PaidAt).timestamp())
+            .col(
+                ColumnDef::new(QrisPayments::CreatedAt)
+                    .timestamp()
+                    .default(Expr::current_timestamp()),
+            )
+            .col(
+                ColumnDef::new(QrisPayments::UpdatedAt)
+                    .timestamp()
+                    .default(Expr::current_timestamp()),
+            )
+            .foreign_key(
+                ForeignKey::create()
+                    .name("fk-qris_payments-merchant_id")
+                    .from(QrisPayments::Table, QrisPayments::MerchantId)
+                    .to(Users::Table, Users::UserId)
+                    .on_update(ForeignKeyAction::Cascade)
+                    .on_delete(ForeignKeyAction::Cascade),
+            )
+            .to_owned();
+        manager.create_table(qris_payments_table).await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name("idx-qris_payments-reference")
+                    .table(QrisPayments::Table)
+                    .col(QrisPayments::Reference)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name("idx-qris_payments-merchant_id")
+                    .table(QrisPayments::Table)
+                    .col(QrisPayments::MerchantId)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(QrisPayments::Table).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(Iden)]
+enum Users {
+    Table,
+    UserId,
+}
+
+#[derive(Iden)]
+enum QrisPayments {
+    Table,
+    QrisPaymentId,
+    MerchantId,
+    Reference,
+    Amount,
+    Payload,
+    Status,
+    PayerId,
+    TransferId,
+    PaidAt,
+    CreatedAt,
+    UpdatedAt,
+}
@@ -0,0 +1,183 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Create Merchants Table
+        let merchants_table = Table::create()
+            .table(Merchants::Table)
+            .if_not_exists()
+            .col(
+                ColumnDef::new(Merchants::MerchantId)
+                    .integer()
+                    .not_null()
+                    .auto_increment()
+                    .primary_key(),
+            )
+            .col(ColumnDef::new(Merchants::UserId).integer().not_null())
+            .col(ColumnDef::new(Merchants::Name).string_len(100).not_null())
+            .col(
+                ColumnDef::new(Merchants::CreatedAt)
+                    .timestamp()
+                    .default(Expr::current_timestamp()),
+            )
+            .col(
+                ColumnDef::new(Merchants::UpdatedAt)
+                    .timestamp()
+                    .default(Expr::current_timestamp()),
+            )
+            .foreign_key(
+                ForeignKey::create()
+                    .name("fk-merchants-user_id")
+                    .from(Merchants::Table, Merchants::UserId)
+                    .to(Users::Table, Users::UserId)
+                    .on_update(ForeignKeyAction::Cascade)
+                    .on_delete(ForeignKeyAction::Cascade),
+            )
+            .to_owned();
+        manager.create_table(merchants_table).await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name("idx-merchants-user_id")
+                    .table(Merchants::Table)
+                    .col(Merchants::UserId)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        // Create Payment Intents Table
+        let payment_intents_table = Table::create()
+            .table(PaymentIntents::Table)
+            .if_not_exists()
+            .col(
+                ColumnDef::new(PaymentIntents::PaymentIntentId)
+                    .integer()
+                    .not_null()
+                    .auto_increment()
+                    .primary_key(),
+            )
+            .col(
+                ColumnDef::new(PaymentIntents::MerchantId)
+                    .integer()
+                    .not_null(),
+            )
+            .col(ColumnDef::new(PaymentIntents::Amount).integer().not_null())
+            .col(
+                ColumnDef::new(PaymentIntents::Currency)
+                    .string_len(3)
+                    .not_null(),
+            )
+            .col(
+                ColumnDef::new(PaymentIntents::Reference)
+                    .string_len(64)
+                    .not_null(),
+            )
+            .col(
+                ColumnDef::new(PaymentIntents::Status)
+                    .string_len(30)
+                    .not_null()
+                    .default("requires_confirmation"),
+            )
+            .col(
+                ColumnDef::new(PaymentIntents::CallbackUrl)
+                    .string_len(255)
+                    .not_null(),
+            )
+            .col(
+                ColumnDef::new(PaymentIntents::ExpiresAt)
+                    .timestamp()
+                    .not_null(),
+            )
+            .col(ColumnDef::new(PaymentIntents::CustomerId).integer())
+            .col(ColumnDef::new(PaymentIntents::TransferId).integer())
+            .col(ColumnDef::new(PaymentIntents::SucceededAt).timestamp())
+            .col(
+                ColumnDef::new(PaymentIntents::CreatedAt)
+                    .timestamp()
+                    .default(Expr::current_timestamp()),
+            )
+            .col(
+                ColumnDef::new(PaymentIntents::UpdatedAt)
+                    .timestamp()
+                    .default(Expr::current_timestamp()),
+            )
+            .foreign_key(
+                ForeignKey::create()
+                    .name("fk-payment_intents-merchant_id")
+                    .from(PaymentIntents::Table, PaymentIntents::MerchantId)
+                    .to(Merchants::Table, Merchants::MerchantId)
+                    .on_update(ForeignKeyAction::Cascade)
+                    .on_delete(ForeignKeyAction::Cascade),
+            )
+            .to_owned();
+        manager.create_table(payment_intents_table).await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name("idx-payment_intents-merchant_id-reference")
+                    .table(PaymentIntents::Table)
+                    .col(PaymentIntents::MerchantId)
+                    .col(PaymentIntents::Reference)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(PaymentIntents::Table).to_owned())
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(Merchants::Table).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(Iden)]
+enum Users {
+    Table,
+    UserId,
+}
+
+#[derive(Iden)]
+enum Merchants {
+    Table,
+    MerchantId,
+    UserId,
+    Name,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[derive(Iden)]
+enum PaymentIntents {
+    Table,
+    PaymentIntentId,
+    MerchantId,
+    Amount,
+    Currency,
+    Reference,
+    Status,
+    CallbackUrl,
+    ExpiresAt,
+    CustomerId,
+    TransferId,
+    SucceededAt,
+    CreatedAt,
+    UpdatedAt,
+}
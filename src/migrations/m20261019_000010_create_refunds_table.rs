@@ -0,0 +1,123 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Running total of refunds on each payment, reserved before the
+        // money moves so concurrent refunds cannot exceed the amount.
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(PaymentIntents::Table)
+                    .add_column(
+                        ColumnDef::new(PaymentIntents::AmountRefunded)
+                            .integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // Create Refunds Table
+        let refunds_table = Table::create()
+            .table(Refunds::Table)
+            .if_not_exists()
+            .col(
+                ColumnDef::new(Refunds::RefundId)
+                    .integer()
+                    .not_null()
+                    .auto_increment()
+                    .primary_key(),
+            )
+            .col(
+                ColumnDef::new(Refunds::PaymentIntentId)
+                    .integer()
+                    .not_null(),
+            )
+            .col(ColumnDef::new(Refunds::Amount).integer().not_null())
+            .col(ColumnDef::new(Refunds::Reason).string_len(255).not_null())
+            .col(
+                ColumnDef::new(Refunds::Status)
+                    .string_len(20)
+                    .not_null()
+                    .default("pending"),
+            )
+            .col(ColumnDef::new(Refunds::FailureReason).string_len(255))
+            .col(ColumnDef::new(Refunds::RefundedAt).timestamp())
+            .col(
+                ColumnDef::new(Refunds::CreatedAt)
+                    .timestamp()
+                    .default(Expr::current_timestamp()),
+            )
+            .col(
+                ColumnDef::new(Refunds::UpdatedAt)
+                    .timestamp()
+                    .default(Expr::current_timestamp()),
+            )
+            .foreign_key(
+                ForeignKey::create()
+                    .name("fk-refunds-payment_intent_id")
+                    .from(Refunds::Table, Refunds::PaymentIntentId)
+                    .to(PaymentIntents::Table, PaymentIntents::PaymentIntentId)
+                    .on_update(ForeignKeyAction::Cascade)
+                    .on_delete(ForeignKeyAction::Cascade),
+            )
+            .to_owned();
+        manager.create_table(refunds_table).await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name("idx-refunds-payment_intent_id")
+                    .table(Refunds::Table)
+                    .col(Refunds::PaymentIntentId)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(Refunds::Table).to_owned())
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(PaymentIntents::Table)
+                    .drop_column(PaymentIntents::AmountRefunded)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(Iden)]
+enum PaymentIntents {
+    Table,
+    PaymentIntentId,
+    AmountRefunded,
+}
+
+#[derive(Iden)]
+enum Refunds {
+    Table,
+    RefundId,
+    PaymentIntentId,
+    Amount,
+    Reason,
+    Status,
+    FailureReason,
+    RefundedAt,
+    CreatedAt,
+    UpdatedAt,
+}
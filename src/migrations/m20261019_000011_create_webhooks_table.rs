@@ -0,0 +1,255 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Create Webhook Endpoints Table
+        let webhook_endpoints_table = Table::create()
+            .table(WebhookEndpoints::Table)
+            .if_not_exists()
+            .col(
+                ColumnDef::new(WebhookEndpoints::WebhookEndpointId)
+                    .integer()
+                    .not_null()
+                    .auto_increment()
+                    .primary_key(),
+            )
+            .col(
+                ColumnDef::new(WebhookEndpoints::MerchantId)
+                    .integer()
+                    .not_null(),
+            )
+            .col(
+                ColumnDef::new(WebhookEndpoints::Url)
+                    .string_len(255)
+                    .not_null(),
+            )
+            .col(
+                ColumnDef::new(WebhookEndpoints::Secret)
+                    .string_len(64)
+                    .not_null(),
+            )
+            .col(
+                ColumnDef::new(WebhookEndpoints::IsActive)
+                    .boolean()
+                    .not_null()
+                    .default(true),
+            )
+            .col(
+                ColumnDef::new(WebhookEndpoints::CreatedAt)
+                    .timestamp()
+                    .default(Expr::current_timestamp()),
+            )
+            .col(
+                ColumnDef::new(WebhookEndpoints::UpdatedAt)
+                    .timestamp()
+                    .default(Expr::current_timestamp()),
+            )
+            .foreign_key(
+                ForeignKey::create()
+                    .name("fk-webhook_endpoints-merchant_id")
+                    .from(WebhookEndpoints::Table, WebhookEndpoints::MerchantId)
+                    .to(Merchants::Table, Merchants::MerchantId)
+                    .on_update(ForeignKeyAction::Cascade)
+                    .on_delete(ForeignKeyAction::Cascade),
+            )
+            .to_owned();
+        manager.create_table(webhook_endpoints_table).await?;
+
+        // Create Webhook Events Table, the outbox written alongside the
+        // business change that raised the event
+        let webhook_events_table = Table::create()
+            .table(WebhookEvents::Table)
+            .if_not_exists()
+            .col(
+                ColumnDef::new(WebhookEvents::WebhookEventId)
+                    .integer()
+                    .not_null()
+                    .auto_increment()
+                    .primary_key(),
+            )
+            .col(
+                ColumnDef::new(WebhookEvents::MerchantId)
+                    .integer()
+                    .not_null(),
+            )
+            .col(
+                ColumnDef::new(WebhookEvents::EventType)
+                    .string_len(50)
+                    .not_null(),
+            )
+            .col(ColumnDef::new(WebhookEvents::Payload).text().not_null())
+            .col(
+                ColumnDef::new(WebhookEvents::CreatedAt)
+                    .timestamp()
+                    .default(Expr::current_timestamp()),
+            )
+            .foreign_key(
+                ForeignKey::create()
+                    .name("fk-webhook_events-merchant_id")
+                    .from(WebhookEvents::Table, WebhookEvents::MerchantId)
+                    .to(Merchants::Table, Merchants::MerchantId)
+                    .on_update(ForeignKeyAction::Cascade)
+                    .on_delete(ForeignKeyAction::Cascade),
+            )
+            .to_owned();
+        manager.create_table(webhook_events_table).await?;
+
+        // Create Webhook Deliveries Table
+        let webhook_deliveries_table = Table::create()
+            .table(WebhookDeliveries::Table)
+            .if_not_exists()
+            .col(
+                ColumnDef::new(WebhookDeliveries::WebhookDeliveryId)
+                    .integer()
+                    .not_null()
+                    .auto_increment()
+                    .primary_key(),
+            )
+            .col(
+                ColumnDef::new(WebhookDeliveries::WebhookEventId)
+                    .integer()
+                    .not_null(),
+            )
+            .col(
+                ColumnDef::new(WebhookDeliveries::WebhookEndpointId)
+                    .integer()
+                    .not_null(),
+            )
+            .col(
+                ColumnDef::new(WebhookDeliveries::MerchantId)
+                    .integer()
+                    .not_null(),
+            )
+            .col(
+                ColumnDef::new(WebhookDeliveries::Status)
+                    .string_len(20)
+                    .not_null()
+                    .default("pending"),
+            )
+            .col(
+                ColumnDef::new(WebhookDeliveries::Attempts)
+                    .integer()
+                    .not_null()
+                    .default(0),
+            )
+            .col(
+                ColumnDef::new(WebhookDeliveries::NextAttemptAt)
+                    .timestamp()
+                    .not_null()
+                    .default(Expr::current_timestamp()),
+            )
+            .col(ColumnDef::new(WebhookDeliveries::LastResponseStatus).integer())
+            .col(ColumnDef::new(WebhookDeliveries::LastError).string_len(255))
+            .col(ColumnDef::new(WebhookDeliveries::DeliveredAt).timestamp())
+            .col(
+                ColumnDef::new(WebhookDeliveries::CreatedAt)
+                    .timestamp()
+                    .default(Expr::current_timestamp()),
+            )
+            .col(
+                ColumnDef::new(WebhookDeliveries::UpdatedAt)
+                    .timestamp()
+                    .default(Expr::current_timestamp()),
+            )
+            .foreign_key(
+                ForeignKey::create()
+                    .name("fk-webhook_deliveries-webhook_event_id")
+                    .from(WebhookDeliveries::Table, WebhookDeliveries::WebhookEventId)
+                    .to(WebhookEvents::Table, WebhookEvents::WebhookEventId)
+                    .on_update(ForeignKeyAction::Cascade)
+                    .on_delete(ForeignKeyAction::Cascade),
+            )
+            .foreign_key(
+                ForeignKey::create()
+                    .name("fk-webhook_deliveries-webhook_endpoint_id")
+                    .from(
+                        WebhookDeliveries::Table,
+                        WebhookDeliveries::WebhookEndpointId,
+                    )
+                    .to(WebhookEndpoints::Table, WebhookEndpoints::WebhookEndpointId)
+                    .on_update(ForeignKeyAction::Cascade)
+                    .on_delete(ForeignKeyAction::Cascade),
+            )
+            .to_owned();
+        manager.create_table(webhook_deliveries_table).await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name("idx-webhook_deliveries-status-next_attempt_at")
+                    .table(WebhookDeliveries::Table)
+                    .col(WebhookDeliveries::Status)
+                    .col(WebhookDeliveries::NextAttemptAt)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(WebhookDeliveries::Table).to_owned())
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(WebhookEvents::Table).to_owned())
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(WebhookEndpoints::Table).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(Iden)]
+enum Merchants {
+    Table,
+    MerchantId,
+}
+
+#[derive(Iden)]
+enum WebhookEndpoints {
+    Table,
+    WebhookEndpointId,
+    MerchantId,
+    Url,
+    Secret,
+    IsActive,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[derive(Iden)]
+enum WebhookEvents {
+    Table,
+    WebhookEventId,
+    MerchantId,
+    EventType,
+    Payload,
+    CreatedAt,
+}
+
+#[derive(Iden)]
+enum WebhookDeliveries {
+    Table,
+    WebhookDeliveryId,
+    WebhookEventId,
+    WebhookEndpointId,
+    MerchantId,
+    Status,
+    Attempts,
+    NextAttemptAt,
+    LastResponseStatus,
+    LastError,
+    DeliveredAt,
+    CreatedAt,
+    UpdatedAt,
+}
@@ -0,0 +1,168 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Create Settlements Table
+        let settlements_table = Table::create()
+            .table(Settlements::Table)
+            .if_not_exists()
+            .col(
+                ColumnDef::new(Settlements::SettlementId)
+                    .integer()
+                    .not_null()
+                    .auto_increment()
+                    .primary_key(),
+            )
+            .col(ColumnDef::new(Settlements::MerchantId).integer().not_null())
+            .col(
+                ColumnDef::new(Settlements::PeriodStart)
+                    .timestamp()
+                    .not_null(),
+            )
+            .col(ColumnDef::new(Settlements::PeriodEnd).timestamp().not_null())
+            .col(
+                ColumnDef::new(Settlements::PaymentCount)
+                    .integer()
+                    .not_null()
+                    .default(0),
+            )
+            .col(
+                ColumnDef::new(Settlements::GrossAmount)
+                    .integer()
+                    .not_null()
+                    .default(0),
+            )
+            .col(
+                ColumnDef::new(Settlements::RefundedAmount)
+                    .integer()
+                    .not_null()
+                    .default(0),
+            )
+            .col(
+                ColumnDef::new(Settlements::FeeAmount)
+                    .integer()
+                    .not_null()
+                    .default(0),
+            )
+            .col(
+                ColumnDef::new(Settlements::NetAmount)
+                    .integer()
+                    .not_null()
+                    .default(0),
+            )
+            .col(
+                ColumnDef::new(Settlements::Status)
+                    .string_len(20)
+                    .not_null()
+                    .default("pending"),
+            )
+            .col(ColumnDef::new(Settlements::WithdrawId).integer())
+            .col(ColumnDef::new(Settlements::FailureReason).string_len(255))
+            .col(
+                ColumnDef::new(Settlements::CreatedAt)
+                    .timestamp()
+                    .default(Expr::current_timestamp()),
+            )
+            .col(
+                ColumnDef::new(Settlements::UpdatedAt)
+                    .timestamp()
+                    .default(Expr::current_timestamp()),
+            )
+            .foreign_key(
+                ForeignKey::create()
+                    .name("fk-settlements-merchant_id")
+                    .from(Settlements::Table, Settlements::MerchantId)
+                    .to(Merchants::Table, Merchants::MerchantId)
+                    .on_update(ForeignKeyAction::Cascade)
+                    .on_delete(ForeignKeyAction::Cascade),
+            )
+            .to_owned();
+        manager.create_table(settlements_table).await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name("idx-settlements-merchant_id-period_start")
+                    .table(Settlements::Table)
+                    .col(Settlements::MerchantId)
+                    .col(Settlements::PeriodStart)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        // Payments and refunds point at the settlement that included them,
+        // so nothing is settled twice.
+        for table in [PaymentIntents::Table.into_iden(), Refunds::Table.into_iden()] {
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(table)
+                        .add_column(ColumnDef::new(Settlements::SettlementId).integer())
+                        .to_owned(),
+                )
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        for table in [PaymentIntents::Table.into_iden(), Refunds::Table.into_iden()] {
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(table)
+                        .drop_column(Settlements::SettlementId)
+                        .to_owned(),
+                )
+                .await?;
+        }
+
+        manager
+            .drop_table(Table::drop().table(Settlements::Table).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(Iden)]
+enum Merchants {
+    Table,
+    MerchantId,
+}
+
+#[derive(Iden)]
+enum PaymentIntents {
+    Table,
+}
+
+#[derive(Iden)]
+enum Refunds {
+    Table,
+}
+
+#[derive(Iden)]
+enum Settlements {
+    Table,
+    SettlementId,
+    MerchantId,
+    PeriodStart,
+    PeriodEnd,
+    PaymentCount,
+    GrossAmount,
+    RefundedAmount,
+    FeeAmount,
+    NetAmount,
+    Status,
+    WithdrawId,
+    FailureReason,
+    CreatedAt,
+    UpdatedAt,
+}
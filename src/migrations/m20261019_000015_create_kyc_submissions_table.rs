@@ -0,0 +1,205 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Staff accounts are promoted by setting the role to `admin`
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Users::Table)
+                    .add_column(
+                        ColumnDef::new(Users::Role)
+                            .string_len(20)
+                            .not_null()
+                            .default("user"),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // Create KYC Submissions Table
+        let kyc_submissions_table = Table::create()
+            .table(KycSubmissions::Table)
+            .if_not_exists()
+            .col(
+                ColumnDef::new(KycSubmissions::KycSubmissionId)
+                    .integer()
+                    .not_null()
+                    .auto_increment()
+                    .primary_key(),
+            )
+            .col(ColumnDef::new(KycSubmissions::UserId).integer().not_null())
+            .col(
+                ColumnDef::new(KycSubmissions::RequestedLevel)
+                    .integer()
+                    .not_null(),
+            )
+            .col(
+                ColumnDef::new(KycSubmissions::IdCardKey)
+                    .string_len(255)
+                    .not_null(),
+            )
+            .col(
+                ColumnDef::new(KycSubmissions::SelfieKey)
+                    .string_len(255)
+                    .not_null(),
+            )
+            .col(
+                ColumnDef::new(KycSubmissions::Status)
+                    .string_len(20)
+                    .not_null()
+                    .default("submitted"),
+            )
+            .col(ColumnDef::new(KycSubmissions::ReviewerId).integer())
+            .col(ColumnDef::new(KycSubmissions::ReviewNote).text())
+            .col(ColumnDef::new(KycSubmissions::ReviewedAt).timestamp())
+            .col(
+                ColumnDef::new(KycSubmissions::CreatedAt)
+                    .timestamp()
+                    .default(Expr::current_timestamp()),
+            )
+            .col(
+                ColumnDef::new(KycSubmissions::UpdatedAt)
+                    .timestamp()
+                    .default(Expr::current_timestamp()),
+            )
+            .foreign_key(
+                ForeignKey::create()
+                    .name("fk-kyc_submissions-user_id")
+                    .from(KycSubmissions::Table, KycSubmissions::UserId)
+                    .to(Users::Table, Users::UserId)
+                    .on_update(ForeignKeyAction::Cascade)
+                    .on_delete(ForeignKeyAction::Cascade),
+            )
+            .foreign_key(
+                ForeignKey::create()
+                    .name("fk-kyc_submissions-reviewer_id")
+                    .from(KycSubmissions::Table, KycSubmissions::ReviewerId)
+                    .to(Users::Table, Users::UserId)
+                    .on_update(ForeignKeyAction::Cascade)
+                    .on_delete(ForeignKeyAction::SetNull),
+            )
+            .to_owned();
+        manager.create_table(kyc_submissions_table).await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name("idx-kyc_submissions-status")
+                    .table(KycSubmissions::Table)
+                    .col(KycSubmissions::Status)
+                    .to_owned(),
+            )
+            .await?;
+
+        // Create KYC Submission Events Table, one row per status change
+        let kyc_submission_events_table = Table::create()
+            .table(KycSubmissionEvents::Table)
+            .if_not_exists()
+            .col(
+                ColumnDef::new(KycSubmissionEvents::KycSubmissionEventId)
+                    .integer()
+                    .not_null()
+                    .auto_increment()
+                    .primary_key(),
+            )
+            .col(
+                ColumnDef::new(KycSubmissionEvents::KycSubmissionId)
+                    .integer()
+                    .not_null(),
+            )
+            .col(
+                ColumnDef::new(KycSubmissionEvents::ActorId)
+                    .integer()
+                    .not_null(),
+            )
+            .col(ColumnDef::new(KycSubmissionEvents::FromStatus).string_len(20))
+            .col(
+                ColumnDef::new(KycSubmissionEvents::ToStatus)
+                    .string_len(20)
+                    .not_null(),
+            )
+            .col(ColumnDef::new(KycSubmissionEvents::Note).text())
+            .col(
+                ColumnDef::new(KycSubmissionEvents::CreatedAt)
+                    .timestamp()
+                    .default(Expr::current_timestamp()),
+            )
+            .foreign_key(
+                ForeignKey::create()
+                    .name("fk-kyc_submission_events-kyc_submission_id")
+                    .from(
+                        KycSubmissionEvents::Table,
+                        KycSubmissionEvents::KycSubmissionId,
+                    )
+                    .to(KycSubmissions::Table, KycSubmissions::KycSubmissionId)
+                    .on_update(ForeignKeyAction::Cascade)
+                    .on_delete(ForeignKeyAction::Cascade),
+            )
+            .to_owned();
+        manager.create_table(kyc_submission_events_table).await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(KycSubmissionEvents::Table).to_owned())
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(KycSubmissions::Table).to_owned())
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Users::Table)
+                    .drop_column(Users::Role)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(Iden)]
+enum Users {
+    Table,
+    UserId,
+    Role,
+}
+
+#[derive(Iden)]
+enum KycSubmissions {
+    Table,
+    KycSubmissionId,
+    UserId,
+    RequestedLevel,
+    IdCardKey,
+    SelfieKey,
+    Status,
+    ReviewerId,
+    ReviewNote,
+    ReviewedAt,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[derive(Iden)]
+enum KycSubmissionEvents {
+    Table,
+    KycSubmissionEventId,
+    KycSubmissionId,
+    ActorId,
+    FromStatus,
+    ToStatus,
+    Note,
+    CreatedAt,
+}
@@ -0,0 +1,61 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // A settlement whose payout is held for fraud review waits in
+        // `in_review`, linked to the review that decides the withdrawal.
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Settlements::Table)
+                    .add_column(ColumnDef::new(Settlements::FraudReviewId).integer())
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name("idx-settlements-fraud_review_id")
+                    .table(Settlements::Table)
+                    .col(Settlements::FraudReviewId)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("idx-settlements-fraud_review_id")
+                    .table(Settlements::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Settlements::Table)
+                    .drop_column(Settlements::FraudReviewId)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(Iden)]
+enum Settlements {
+    Table,
+    FraudReviewId,
+}
@@ -0,0 +1,54 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Top-up and withdraw fees are posted to revenue after the provider
+        // confirms them, so the rule that priced them is kept until then.
+        for table in [Topups::Table.into_iden(), Withdraws::Table.into_iden()] {
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(table)
+                        .add_column(ColumnDef::new(FeeRules::FeeRuleId).integer())
+                        .to_owned(),
+                )
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        for table in [Topups::Table.into_iden(), Withdraws::Table.into_iden()] {
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(table)
+                        .drop_column(FeeRules::FeeRuleId)
+                        .to_owned(),
+                )
+                .await?;
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Iden)]
+enum FeeRules {
+    FeeRuleId,
+}
+
+#[derive(Iden)]
+enum Topups {
+    Table,
+}
+
+#[derive(Iden)]
+enum Withdraws {
+    Table,
+}
@@ -0,0 +1,61 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // A batch item whose transfer is held for fraud review waits in
+        // `held`, linked to the review that decides the transfer.
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(TransferBatchItems::Table)
+                    .add_column(ColumnDef::new(TransferBatchItems::FraudReviewId).integer())
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name("idx-transfer_batch_items-fraud_review_id")
+                    .table(TransferBatchItems::Table)
+                    .col(TransferBatchItems::FraudReviewId)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("idx-transfer_batch_items-fraud_review_id")
+                    .table(TransferBatchItems::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(TransferBatchItems::Table)
+                    .drop_column(TransferBatchItems::FraudReviewId)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(Iden)]
+enum TransferBatchItems {
+    Table,
+    FraudReviewId,
+}
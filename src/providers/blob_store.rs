@@ -0,0 +1,83 @@
+use std::{
+    io::ErrorKind,
+    path::{Component, Path, PathBuf},
+};
+
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::{abstract_trait::blob_store::BlobStoreTrait, utils::errors::AppError};
+
+/// Keeps blobs as files below a root directory on the local filesystem.
+pub struct LocalBlobStore {
+    root: PathBuf,
+}
+
+impl LocalBlobStore {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+
+    /// Path of a key, refusing keys that would escape the root directory.
+    fn path(&self, key: &str) -> Result<PathBuf, AppError> {
+        let relative = Path::new(key);
+
+        let is_plain = !key.is_empty()
+            && relative
+                .components()
+                .all(|component| matches!(component, Component::Normal(_)));
+
+        if !is_plain {
+            return Err(AppError::BlobStoreError(format!("Invalid key {}", key)));
+        }
+
+        Ok(self.root.join(relative))
+    }
+}
+
+#[async_trait]
+impl BlobStoreTrait for LocalBlobStore {
+    async fn put(&self, key: &str, bytes: &[u8]) -> Result<(), AppError> {
+        let path = self.path(key)?;
+
+        if let Some(parent) = path.parent() {
+            tokio::fs::create_dir_all(parent)
+                .await
+                .map_err(|e| AppError::BlobStoreError(e.to_string()))?;
+        }
+
+        // Writing to a temporary file first means readers never see a
+        // partially written blob.
+        let temp_path = path.with_extension(format!("{}.tmp", Uuid::new_v4().simple()));
+
+        tokio::fs::write(&temp_path, bytes)
+            .await
+            .map_err(|e| AppError::BlobStoreError(e.to_string()))?;
+
+        if let Err(e) = tokio::fs::rename(&temp_path, &path).await {
+            let _ = tokio::fs::remove_file(&temp_path).await;
+            return Err(AppError::BlobStoreError(e.to_string()));
+        }
+
+        Ok(())
+    }
+
+    async fn get(&self, key: &str) -> Result<Vec<u8>, AppError> {
+        let path = self.path(key)?;
+
+        tokio::fs::read(&path).await.map_err(|e| match e.kind() {
+            ErrorKind::NotFound => AppError::NotFound(format!("File {} not found", key)),
+            _ => AppError::BlobStoreError(e.to_string()),
+        })
+    }
+
+    async fn delete(&self, key: &str) -> Result<(), AppError> {
+        let path = self.path(key)?;
+
+        match tokio::fs::remove_file(&path).await {
+            Ok(()) => Ok(()),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(()),
+            Err(e) => Err(AppError::BlobStoreError(e.to_string())),
+        }
+    }
+}
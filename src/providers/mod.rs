@@ -0,0 +1,5 @@
+pub mod blob_store;
+pub mod payment_channel;
+pub mod payout;
+pub mod webhook_sender;
+pub mod watchlist;
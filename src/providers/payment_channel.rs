@@ -0,0 +1,69 @@
+use async_trait::async_trait;
+
+use crate::{
+    abstract_trait::payment_channel::{PaymentChannelRequest, PaymentChannelTrait},
+    domain::response::topup::PaymentInstructions,
+    utils::{errors::AppError, signature::verify_hmac_sha256},
+};
+
+const VIRTUAL_ACCOUNT_METHODS: [&str; 6] = ["bri", "mandiri", "bca", "bni", "bukopin", "e-banking"];
+const REDIRECT_METHODS: [&str; 5] = [
+    "visa",
+    "mastercard",
+    "discover",
+    "american express",
+    "paypal",
+];
+
+/// Payment channel for local development. It hands out instructions without
+/// contacting any provider; payments are confirmed by posting a callback
+/// signed with the shared secret.
+pub struct SimulatedPaymentChannel {
+    callback_secret: String,
+}
+
+impl SimulatedPaymentChannel {
+    pub fn new(callback_secret: &str) -> Self {
+        Self {
+            callback_secret: callback_secret.to_string(),
+        }
+    }
+}
+
+#[async_trait]
+impl PaymentChannelTrait for SimulatedPaymentChannel {
+    async fn create_payment(
+        &self,
+        input: &PaymentChannelRequest,
+    ) -> Result<PaymentInstructions, AppError> {
+        let method = input.method.as_str();
+
+        let instructions = if VIRTUAL_ACCOUNT_METHODS.contains(&method) {
+            let digits: String = input
+                .reference
+                .chars()
+                .filter(|c| c.is_ascii_digit())
+                .take(10)
+                .collect();
+
+            PaymentInstructions::VirtualAccount {
+                bank_code: method.to_string(),
+                account_number: format!("8808{:0>10}", digits),
+            }
+        } else if REDIRECT_METHODS.contains(&method) {
+            PaymentInstructions::Redirect {
+                url: format!("https://checkout.simulated.local/pay/{}", input.reference),
+            }
+        } else {
+            PaymentInstructions::Qr {
+                payload: format!("SIMPAY|{}|{}|{}", method, input.reference, input.amount),
+            }
+        };
+
+        Ok(instructions)
+    }
+
+    fn verify_signature(&self, payload: &[u8], signature: &str) -> bool {
+        verify_hmac_sha256(&self.callback_secret, payload, signature)
+    }
+}
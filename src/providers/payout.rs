@@ -0,0 +1,78 @@
+use std::{collections::HashMap, sync::Mutex};
+
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::{
+    abstract_trait::payout::{PayoutProviderTrait, PayoutRequest, PayoutResult, PayoutStatus},
+    utils::errors::AppError,
+};
+
+/// In-memory payout provider for local development.
+///
+/// Every payout is accepted as pending and settles on the first status
+/// check. Account numbers ending in `000` are rejected by the "bank", which
+/// makes the release path easy to exercise by hand.
+#[derive(Default)]
+pub struct SimulatedPayoutProvider {
+    payouts: Mutex<HashMap<String, PayoutRequest>>,
+}
+
+impl SimulatedPayoutProvider {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+fn settled_status(payout: &PayoutRequest) -> PayoutStatus {
+    if payout.account_number.ends_with("000") {
+        PayoutStatus::Failed("Account rejected by beneficiary bank".to_string())
+    } else {
+        PayoutStatus::Succeeded
+    }
+}
+
+#[async_trait]
+impl PayoutProviderTrait for SimulatedPayoutProvider {
+    async fn create_payout(&self, input: &PayoutRequest) -> Result<PayoutResult, AppError> {
+        let mut payouts = self.payouts.lock().unwrap();
+
+        let existing = payouts
+            .iter()
+            .find(|(_, payout)| payout.reference == input.reference)
+            .map(|(provider_reference, _)| provider_reference.clone());
+
+        let provider_reference = existing.unwrap_or_else(|| {
+            let provider_reference = format!("SIM-{}", Uuid::new_v4());
+            payouts.insert(provider_reference.clone(), input.clone());
+            provider_reference
+        });
+
+        Ok(PayoutResult {
+            provider_reference,
+            status: PayoutStatus::Pending,
+        })
+    }
+
+    async fn get_payout_status(&self, provider_reference: &str) -> Result<PayoutStatus, AppError> {
+        let payouts = self.payouts.lock().unwrap();
+
+        let payout = payouts.get(provider_reference).ok_or_else(|| {
+            AppError::PayoutProviderError(format!("Unknown payout {}", provider_reference))
+        })?;
+
+        Ok(settled_status(payout))
+    }
+
+    async fn find_payout(&self, reference: &str) -> Result<Option<PayoutResult>, AppError> {
+        let payouts = self.payouts.lock().unwrap();
+
+        Ok(payouts
+            .iter()
+            .find(|(_, payout)| payout.reference == reference)
+            .map(|(provider_reference, payout)| PayoutResult {
+                provider_reference: provider_reference.clone(),
+                status: settled_status(payout),
+            }))
+    }
+}
@@ -0,0 +1,111 @@
+use std::{
+    path::{Path, PathBuf},
+    sync::{Arc, RwLock},
+};
+
+use async_trait::async_trait;
+use tracing::{error, info};
+
+use crate::{
+    abstract_trait::watchlist::WatchlistTrait,
+    utils::{
+        errors::AppError,
+        watchlist::{WatchlistIndex, WatchlistMatch},
+    },
+};
+
+/// Watchlist loaded from a local CSV or JSON file, picked by extension.
+/// Searches use the index loaded last, a reload swaps it in one step.
+pub struct FileWatchlist {
+    path: PathBuf,
+    index: RwLock<Arc<WatchlistIndex>>,
+}
+
+impl FileWatchlist {
+    /// Loads the file right away. A missing or broken file leaves the list
+    /// empty until the next successful reload.
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        let path = path.into();
+
+        let index = match std::fs::read_to_string(&path)
+            .map_err(|e| AppError::WatchlistError(e.to_string()))
+            .and_then(|content| parse(&path, &content))
+        {
+            Ok(index) => {
+                info!(
+                    "Loaded {} watchlist entries from {}",
+                    index.len(),
+                    path.display()
+                );
+                index
+            }
+            Err(e) => {
+                error!(
+                    "Screening against an empty watchlist, {} could not be loaded: {}",
+                    path.display(),
+                    e
+                );
+                WatchlistIndex::default()
+            }
+        };
+
+        Self {
+            path,
+            index: RwLock::new(Arc::new(index)),
+        }
+    }
+
+    fn current(&self) -> Arc<WatchlistIndex> {
+        self.index
+            .read()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .clone()
+    }
+}
+
+fn parse(path: &Path, content: &str) -> Result<WatchlistIndex, AppError> {
+    let is_json = path
+        .extension()
+        .is_some_and(|extension| extension.eq_ignore_ascii_case("json"));
+
+    let parsed = if is_json {
+        WatchlistIndex::from_json(content)
+    } else {
+        WatchlistIndex::from_csv(content)
+    };
+
+    parsed.map_err(|e| AppError::WatchlistError(format!("{}: {}", path.display(), e)))
+}
+
+#[async_trait]
+impl WatchlistTrait for FileWatchlist {
+    fn search(&self, name: &str, min_score: i32) -> Vec<WatchlistMatch> {
+        self.current().search(name, min_score)
+    }
+
+    fn entry_count(&self) -> usize {
+        self.current().len()
+    }
+
+    async fn reload(&self) -> Result<usize, AppError> {
+        let content = tokio::fs::read_to_string(&self.path)
+            .await
+            .map_err(|e| AppError::WatchlistError(format!("{}: {}", self.path.display(), e)))?;
+
+        let index = parse(&self.path, &content)?;
+        let count = index.len();
+
+        *self
+            .index
+            .write()
+            .unwrap_or_else(|poisoned| poisoned.into_inner()) = Arc::new(index);
+
+        info!(
+            "Reloaded {} watchlist entries from {}",
+            count,
+            self.path.display()
+        );
+
+        Ok(count)
+    }
+}
@@ -0,0 +1,43 @@
+use std::time::Duration;
+
+use async_trait::async_trait;
+
+use crate::{
+    abstract_trait::webhook_sender::{WebhookRequest, WebhookSenderTrait},
+    utils::errors::AppError,
+};
+
+/// Posts webhooks over HTTP with the signature and event in headers.
+pub struct HttpWebhookSender {
+    client: reqwest::Client,
+}
+
+impl HttpWebhookSender {
+    pub fn new(timeout: Duration) -> Self {
+        let client = reqwest::Client::builder()
+            .timeout(timeout)
+            .build()
+            .expect("HTTP client configuration is valid");
+
+        Self { client }
+    }
+}
+
+#[async_trait]
+impl WebhookSenderTrait for HttpWebhookSender {
+    async fn send(&self, request: &WebhookRequest) -> Result<u16, AppError> {
+        let response = self
+            .client
+            .post(&request.url)
+            .header("Content-Type", "application/json")
+            .header("X-Webhook-Event", &request.event_type)
+            .header("X-Webhook-Delivery", request.delivery_id.to_string())
+            .header("X-Webhook-Signature", &request.signature)
+            .body(request.body.clone())
+            .send()
+            .await
+            .map_err(|e| AppError::WebhookDeliveryError(e.to_string()))?;
+
+        Ok(response.status().as_u16())
+    }
+}
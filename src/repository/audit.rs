@@ -0,0 +1,161 @@
+use async_trait::async_trait;
+use chrono::NaiveDateTime;
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, DatabaseConnection, DbErr, EntityTrait, PrimaryKeyTrait,
+    QueryFilter, QueryOrder, QuerySelect, Set,
+};
+
+use crate::{
+    abstract_trait::audit::AuditRepositoryTrait,
+    domain::request::audit::{
+        redact_snapshot, CreateAuditEvent, FindAuditEventsQuery, DEFAULT_AUDIT_EVENT_LIMIT,
+    },
+    entities::{
+        audit_events, bank_accounts, beneficiaries, fraud_reviews, kyc_submissions,
+        payment_intents, saldo, scheduled_transfers, screening_results, settlements,
+        suspense_payments, topups, transfer_batches, transfers, users, webhook_deliveries,
+        webhook_endpoints, withdraws,
+    },
+};
+use tracing::instrument;
+
+pub struct AuditRepository {
+    db_pool: DatabaseConnection,
+}
+
+impl AuditRepository {
+    pub fn new(db_pool: DatabaseConnection) -> Self {
+        Self { db_pool }
+    }
+}
+
+async fn find_json<E>(db: &DatabaseConnection, id: i32) -> Result<Option<serde_json::Value>, DbErr>
+where
+    E: EntityTrait,
+    <E::PrimaryKey as PrimaryKeyTrait>::ValueType: From<i32>,
+{
+    E::find_by_id(id).into_json().one(db).await
+}
+
+#[async_trait]
+impl AuditRepositoryTrait for AuditRepository {
+    #[instrument(name = "AuditRepository::find_last", skip_all)]
+    async fn find_last(&self) -> Result<Option<audit_events::Model>, DbErr> {
+        audit_events::Entity::find()
+            .order_by_desc(audit_events::Column::AuditEventId)
+            .one(&self.db_pool)
+            .await
+    }
+
+    #[instrument(name = "AuditRepository::create", skip_all)]
+    async fn create(
+        &self,
+        input: &CreateAuditEvent,
+        created_at: NaiveDateTime,
+        prev_hash: &str,
+        hash: &str,
+    ) -> Result<audit_events::Model, DbErr> {
+        audit_events::ActiveModel {
+            actor_id: Set(input.actor_id),
+            action: Set(input.action.clone()),
+            resource_type: Set(input.resource_type.clone()),
+            resource_id: Set(input.resource_id.clone()),
+            before: Set(input.before.as_ref().map(|value| value.to_string())),
+            after: Set(input.after.as_ref().map(|value| value.to_string())),
+            client_ip: Set(input.client_ip.clone()),
+            request_id: Set(input.request_id.clone()),
+            created_at: Set(created_at),
+            prev_hash: Set(prev_hash.to_string()),
+            hash: Set(hash.to_string()),
+            ..Default::default()
+        }
+        .insert(&self.db_pool)
+        .await
+    }
+
+    #[instrument(name = "AuditRepository::find", skip_all)]
+    async fn find(&self, query: &FindAuditEventsQuery) -> Result<Vec<audit_events::Model>, DbErr> {
+        let mut select = audit_events::Entity::find();
+
+        if let Some(actor_id) = query.actor_id {
+            select = select.filter(audit_events::Column::ActorId.eq(actor_id));
+        }
+
+        if let Some(resource_type) = &query.resource_type {
+            select = select.filter(audit_events::Column::ResourceType.eq(resource_type));
+        }
+
+        if let Some(resource_id) = &query.resource_id {
+            select = select.filter(audit_events::Column::ResourceId.eq(resource_id));
+        }
+
+        if let Some(request_id) = &query.request_id {
+            select = select.filter(audit_events::Column::RequestId.eq(request_id));
+        }
+
+        if let Some(before_id) = query.before_id {
+            select = select.filter(audit_events::Column::AuditEventId.lt(before_id));
+        }
+
+        select
+            .order_by_desc(audit_events::Column::AuditEventId)
+            .limit(query.limit.unwrap_or(DEFAULT_AUDIT_EVENT_LIMIT))
+            .all(&self.db_pool)
+            .await
+    }
+
+    #[instrument(name = "AuditRepository::find_after", skip_all)]
+    async fn find_after(
+        &self,
+        after_id: i32,
+        limit: u64,
+    ) -> Result<Vec<audit_events::Model>, DbErr> {
+        audit_events::Entity::find()
+            .filter(audit_events::Column::AuditEventId.gt(after_id))
+            .order_by_asc(audit_events::Column::AuditEventId)
+            .limit(limit)
+            .all(&self.db_pool)
+            .await
+    }
+
+    #[instrument(name = "AuditRepository::snapshot", skip_all)]
+    async fn snapshot(
+        &self,
+        resource_type: &str,
+        resource_id: i32,
+    ) -> Result<Option<serde_json::Value>, DbErr> {
+        let db = &self.db_pool;
+
+        let snapshot = match resource_type {
+            "users" => find_json::<users::Entity>(db, resource_id).await?,
+            "saldos" => find_json::<saldo::Entity>(db, resource_id).await?,
+            "topups" => find_json::<topups::Entity>(db, resource_id).await?,
+            "transfers" => find_json::<transfers::Entity>(db, resource_id).await?,
+            "transfers/batch" => find_json::<transfer_batches::Entity>(db, resource_id).await?,
+            "withdraws" => find_json::<withdraws::Entity>(db, resource_id).await?,
+            "beneficiaries" => find_json::<beneficiaries::Entity>(db, resource_id).await?,
+            "bank-accounts" => find_json::<bank_accounts::Entity>(db, resource_id).await?,
+            "scheduled-transfers" => {
+                find_json::<scheduled_transfers::Entity>(db, resource_id).await?
+            }
+            "payment-intents" => find_json::<payment_intents::Entity>(db, resource_id).await?,
+            "settlements" => find_json::<settlements::Entity>(db, resource_id).await?,
+            "virtual-accounts/suspense" => {
+                find_json::<suspense_payments::Entity>(db, resource_id).await?
+            }
+            "webhooks/endpoints" => find_json::<webhook_endpoints::Entity>(db, resource_id).await?,
+            "webhooks/deliveries" => {
+                find_json::<webhook_deliveries::Entity>(db, resource_id).await?
+            }
+            "kyc/submissions" => find_json::<kyc_submissions::Entity>(db, resource_id).await?,
+            "fraud/reviews" => find_json::<fraud_reviews::Entity>(db, resource_id).await?,
+            "screening/results" => find_json::<screening_results::Entity>(db, resource_id).await?,
+            _ => None,
+        };
+
+        Ok(snapshot.map(|mut value| {
+            redact_snapshot(&mut value);
+            value
+        }))
+    }
+}
@@ -0,0 +1,65 @@
+use async_trait::async_trait;
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, DatabaseConnection, DbErr, EntityTrait, QueryFilter, QueryOrder,
+    Set,
+};
+
+use crate::{
+    abstract_trait::bank_account::BankAccountRepositoryTrait,
+    domain::request::bank_account::CreateBankAccountRequest, entities::bank_accounts,
+};
+use tracing::instrument;
+
+pub struct BankAccountRepository {
+    db_pool: DatabaseConnection,
+}
+
+impl BankAccountRepository {
+    pub fn new(db_pool: DatabaseConnection) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl BankAccountRepositoryTrait for BankAccountRepository {
+    #[instrument(name = "BankAccountRepository::find_by_user", skip_all)]
+    async fn find_by_user(&self, user_id: i32) -> Result<Vec<bank_accounts::Model>, DbErr> {
+        bank_accounts::Entity::find()
+            .filter(bank_accounts::Column::UserId.eq(user_id))
+            .order_by_asc(bank_accounts::Column::BankAccountId)
+            .all(&self.db_pool)
+            .await
+    }
+
+    #[instrument(name = "BankAccountRepository::find_by_id", skip_all)]
+    async fn find_by_id(&self, id: i32) -> Result<Option<bank_accounts::Model>, DbErr> {
+        bank_accounts::Entity::find_by_id(id)
+            .one(&self.db_pool)
+            .await
+    }
+
+    #[instrument(name = "BankAccountRepository::create", skip_all)]
+    async fn create(
+        &self,
+        input: &CreateBankAccountRequest,
+    ) -> Result<bank_accounts::Model, DbErr> {
+        let bank_account = bank_accounts::ActiveModel {
+            user_id: Set(input.user_id),
+            bank_code: Set(input.bank_code.clone()),
+            account_number: Set(input.account_number.clone()),
+            holder_name: Set(input.holder_name.trim().to_string()),
+            ..Default::default()
+        };
+
+        bank_account.insert(&self.db_pool).await
+    }
+
+    #[instrument(name = "BankAccountRepository::delete", skip_all)]
+    async fn delete(&self, id: i32) -> Result<(), DbErr> {
+        bank_accounts::Entity::delete_by_id(id)
+            .exec(&self.db_pool)
+            .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,89 @@
+use async_trait::async_trait;
+use chrono::Utc;
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, DatabaseConnection, DbErr, EntityTrait, QueryFilter, QueryOrder,
+    Set,
+};
+
+use crate::{
+    abstract_trait::beneficiary::BeneficiaryRepositoryTrait,
+    domain::request::beneficiary::{CreateBeneficiaryRequest, UpdateBeneficiaryRequest},
+    entities::beneficiaries,
+};
+use tracing::instrument;
+
+pub struct BeneficiaryRepository {
+    db_pool: DatabaseConnection,
+}
+
+impl BeneficiaryRepository {
+    pub fn new(db_pool: DatabaseConnection) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl BeneficiaryRepositoryTrait for BeneficiaryRepository {
+    #[instrument(name = "BeneficiaryRepository::find_by_user", skip_all)]
+    async fn find_by_user(&self, user_id: i32) -> Result<Vec<beneficiaries::Model>, DbErr> {
+        beneficiaries::Entity::find()
+            .filter(beneficiaries::Column::UserId.eq(user_id))
+            .order_by_asc(beneficiaries::Column::Alias)
+            .all(&self.db_pool)
+            .await
+    }
+
+    #[instrument(name = "BeneficiaryRepository::find_by_id", skip_all)]
+    async fn find_by_id(&self, id: i32) -> Result<Option<beneficiaries::Model>, DbErr> {
+        beneficiaries::Entity::find_by_id(id)
+            .one(&self.db_pool)
+            .await
+    }
+
+    #[instrument(name = "BeneficiaryRepository::create", skip_all)]
+    async fn create(
+        &self,
+        input: &CreateBeneficiaryRequest,
+        verified: bool,
+    ) -> Result<beneficiaries::Model, DbErr> {
+        let beneficiary = beneficiaries::ActiveModel {
+            user_id: Set(input.user_id),
+            alias: Set(input.alias.trim().to_string()),
+            beneficiary_type: Set(input.beneficiary_type.clone()),
+            noc_transfer: Set(input.noc_transfer.clone()),
+            bank_code: Set(input.bank_code.clone()),
+            bank_account_number: Set(input.bank_account_number.clone()),
+            verified: Set(verified),
+            ..Default::default()
+        };
+
+        beneficiary.insert(&self.db_pool).await
+    }
+
+    #[instrument(name = "BeneficiaryRepository::update", skip_all)]
+    async fn update(
+        &self,
+        input: &UpdateBeneficiaryRequest,
+    ) -> Result<beneficiaries::Model, DbErr> {
+        let mut beneficiary: beneficiaries::ActiveModel =
+            beneficiaries::Entity::find_by_id(input.beneficiary_id)
+                .one(&self.db_pool)
+                .await?
+                .ok_or(DbErr::RecordNotFound("Beneficiary not found".to_owned()))?
+                .into();
+
+        beneficiary.alias = Set(input.alias.trim().to_string());
+        beneficiary.updated_at = Set(Some(Utc::now().naive_utc()));
+
+        beneficiary.update(&self.db_pool).await
+    }
+
+    #[instrument(name = "BeneficiaryRepository::delete", skip_all)]
+    async fn delete(&self, id: i32) -> Result<(), DbErr> {
+        beneficiaries::Entity::delete_by_id(id)
+            .exec(&self.db_pool)
+            .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,90 @@
+use async_trait::async_trait;
+use sea_orm::{
+    sea_query::Expr, ActiveModelTrait, ColumnTrait, DatabaseConnection, DbErr, EntityTrait,
+    QueryFilter, QueryOrder, Set, TransactionTrait,
+};
+
+use crate::{
+    abstract_trait::fee::FeeRepositoryTrait,
+    domain::request::fee::RecordFeeRevenue,
+    entities::{fee_revenues, fee_rules, saldo},
+};
+use tracing::instrument;
+
+pub struct FeeRepository {
+    db_pool: DatabaseConnection,
+}
+
+impl FeeRepository {
+    pub fn new(db_pool: DatabaseConnection) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl FeeRepositoryTrait for FeeRepository {
+    #[instrument(name = "FeeRepository::find_active_rules", skip_all)]
+    async fn find_active_rules(&self) -> Result<Vec<fee_rules::Model>, DbErr> {
+        fee_rules::Entity::find()
+            .filter(fee_rules::Column::IsActive.eq(true))
+            .order_by_asc(fee_rules::Column::TransactionType)
+            .order_by_asc(fee_rules::Column::FeeRuleId)
+            .all(&self.db_pool)
+            .await
+    }
+
+    #[instrument(name = "FeeRepository::find_active_rules_by_type", skip_all)]
+    async fn find_active_rules_by_type(
+        &self,
+        transaction_type: &str,
+    ) -> Result<Vec<fee_rules::Model>, DbErr> {
+        fee_rules::Entity::find()
+            .filter(fee_rules::Column::IsActive.eq(true))
+            .filter(fee_rules::Column::TransactionType.eq(transaction_type))
+            .order_by_asc(fee_rules::Column::FeeRuleId)
+            .all(&self.db_pool)
+            .await
+    }
+
+    #[instrument(name = "FeeRepository::record_revenue", skip_all)]
+    async fn record_revenue(
+        &self,
+        input: &RecordFeeRevenue,
+        revenue_user_id: i32,
+    ) -> Result<fee_revenues::Model, DbErr> {
+        let txn = self.db_pool.begin().await?;
+
+        let revenue = fee_revenues::ActiveModel {
+            transaction_type: Set(input.transaction_type.clone()),
+            transaction_id: Set(input.transaction_id),
+            fee_rule_id: Set(input.fee_rule_id),
+            amount: Set(input.amount),
+            ..Default::default()
+        }
+        .insert(&txn)
+        .await?;
+
+        let credited = saldo::Entity::update_many()
+            .col_expr(
+                saldo::Column::TotalBalance,
+                Expr::col(saldo::Column::TotalBalance).add(input.amount),
+            )
+            .filter(saldo::Column::UserId.eq(revenue_user_id))
+            .exec(&txn)
+            .await?;
+
+        if credited.rows_affected == 0 {
+            saldo::ActiveModel {
+                user_id: Set(revenue_user_id),
+                total_balance: Set(input.amount),
+                ..Default::default()
+            }
+            .insert(&txn)
+            .await?;
+        }
+
+        txn.commit().await?;
+
+        Ok(revenue)
+    }
+}
@@ -0,0 +1,290 @@
+use async_trait::async_trait;
+use chrono::{NaiveDateTime, Utc};
+use sea_orm::{
+    sea_query::Expr, ActiveModelTrait, ColumnTrait, Condition, DatabaseConnection, DbErr,
+    EntityTrait, PaginatorTrait, QueryFilter, QueryOrder, QuerySelect, Set,
+};
+
+use crate::{
+    abstract_trait::fraud::FraudRepositoryTrait,
+    domain::request::fraud::CreateFraudReview,
+    entities::{fraud_reviews, fraud_rules, topups, transfers, user_devices, withdraws},
+};
+use tracing::instrument;
+
+pub struct FraudRepository {
+    db_pool: DatabaseConnection,
+}
+
+impl FraudRepository {
+    pub fn new(db_pool: DatabaseConnection) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl FraudRepositoryTrait for FraudRepository {
+    #[instrument(name = "FraudRepository::find_rules", skip_all)]
+    async fn find_rules(&self) -> Result<Vec<fraud_rules::Model>, DbErr> {
+        fraud_rules::Entity::find()
+            .order_by_asc(fraud_rules::Column::FraudRuleId)
+            .all(&self.db_pool)
+            .await
+    }
+
+    #[instrument(name = "FraudRepository::find_active_rules", skip_all)]
+    async fn find_active_rules(
+        &self,
+        transaction_type: &str,
+    ) -> Result<Vec<fraud_rules::Model>, DbErr> {
+        fraud_rules::Entity::find()
+            .filter(fraud_rules::Column::IsActive.eq(true))
+            .filter(
+                Condition::any()
+                    .add(fraud_rules::Column::TransactionType.eq(transaction_type))
+                    .add(fraud_rules::Column::TransactionType.is_null()),
+            )
+            .order_by_asc(fraud_rules::Column::FraudRuleId)
+            .all(&self.db_pool)
+            .await
+    }
+
+    #[instrument(name = "FraudRepository::count_user_transactions_since", skip_all)]
+    async fn count_user_transactions_since(
+        &self,
+        transaction_type: &str,
+        user_id: i32,
+        since: NaiveDateTime,
+    ) -> Result<i64, DbErr> {
+        let (count, _) = self
+            .user_amount_stats_since(transaction_type, user_id, since)
+            .await?;
+
+        Ok(count)
+    }
+
+    #[instrument(name = "FraudRepository::user_amount_stats_since", skip_all)]
+    async fn user_amount_stats_since(
+        &self,
+        transaction_type: &str,
+        user_id: i32,
+        since: NaiveDateTime,
+    ) -> Result<(i64, i64), DbErr> {
+        let stats: Option<(i64, Option<i64>)> = match transaction_type {
+            "topup" => {
+                topups::Entity::find()
+                    .select_only()
+                    .column_as(topups::Column::TopupId.count(), "count")
+                    .column_as(topups::Column::TopupAmount.sum(), "total")
+                    .filter(topups::Column::UserId.eq(user_id))
+                    .filter(topups::Column::TopupTime.gte(since))
+                    .filter(topups::Column::Status.ne("failed"))
+                    .into_tuple()
+                    .one(&self.db_pool)
+                    .await?
+            }
+            "transfer" => {
+                transfers::Entity::find()
+                    .select_only()
+                    .column_as(transfers::Column::TransferId.count(), "count")
+                    .column_as(transfers::Column::TransferAmount.sum(), "total")
+                    .filter(transfers::Column::TransferFrom.eq(user_id))
+                    .filter(transfers::Column::TransferTime.gte(since))
+                    .into_tuple()
+                    .one(&self.db_pool)
+                    .await?
+            }
+            "withdraw" => {
+                withdraws::Entity::find()
+                    .select_only()
+                    .column_as(withdraws::Column::WithdrawId.count(), "count")
+                    .column_as(withdraws::Column::WithdrawAmount.sum(), "total")
+                    .filter(withdraws::Column::UserId.eq(user_id))
+                    .filter(withdraws::Column::WithdrawTime.gte(since))
+                    .filter(withdraws::Column::Status.ne("failed"))
+                    .into_tuple()
+                    .one(&self.db_pool)
+                    .await?
+            }
+            other => {
+                return Err(DbErr::Custom(format!(
+                    "Unknown fraud transaction type {}",
+                    other
+                )))
+            }
+        };
+
+        Ok(stats.map_or((0, 0), |(count, total)| (count, total.unwrap_or(0))))
+    }
+
+    #[instrument(name = "FraudRepository::count_to_counterparty", skip_all)]
+    async fn count_to_counterparty(
+        &self,
+        transaction_type: &str,
+        user_id: i32,
+        counterparty_id: i32,
+    ) -> Result<i64, DbErr> {
+        let count = match transaction_type {
+            "transfer" => {
+                transfers::Entity::find()
+                    .filter(transfers::Column::TransferFrom.eq(user_id))
+                    .filter(transfers::Column::TransferTo.eq(counterparty_id))
+                    .count(&self.db_pool)
+                    .await?
+            }
+            "withdraw" => {
+                withdraws::Entity::find()
+                    .filter(withdraws::Column::UserId.eq(user_id))
+                    .filter(withdraws::Column::BankAccountId.eq(counterparty_id))
+                    .filter(withdraws::Column::Status.ne("failed"))
+                    .count(&self.db_pool)
+                    .await?
+            }
+            _ => 0,
+        };
+
+        Ok(count as i64)
+    }
+
+    #[instrument(name = "FraudRepository::count_transfers_since", skip_all)]
+    async fn count_transfers_since(
+        &self,
+        transfer_from: i32,
+        transfer_to: i32,
+        since: NaiveDateTime,
+    ) -> Result<i64, DbErr> {
+        let count = transfers::Entity::find()
+            .filter(transfers::Column::TransferFrom.eq(transfer_from))
+            .filter(transfers::Column::TransferTo.eq(transfer_to))
+            .filter(transfers::Column::TransferTime.gte(since))
+            .count(&self.db_pool)
+            .await?;
+
+        Ok(count as i64)
+    }
+
+    #[instrument(name = "FraudRepository::touch_device", skip_all)]
+    async fn touch_device(
+        &self,
+        user_id: i32,
+        device_id: &str,
+    ) -> Result<user_devices::Model, DbErr> {
+        let now = Utc::now().naive_utc();
+
+        let existing = user_devices::Entity::find()
+            .filter(user_devices::Column::UserId.eq(user_id))
+            .filter(user_devices::Column::DeviceId.eq(device_id))
+            .one(&self.db_pool)
+            .await?;
+
+        match existing {
+            Some(device) => {
+                let mut device: user_devices::ActiveModel = device.into();
+                device.last_seen_at = Set(now);
+                device.update(&self.db_pool).await
+            }
+            None => {
+                user_devices::ActiveModel {
+                    user_id: Set(user_id),
+                    device_id: Set(device_id.to_string()),
+                    first_seen_at: Set(now),
+                    last_seen_at: Set(now),
+                    ..Default::default()
+                }
+                .insert(&self.db_pool)
+                .await
+            }
+        }
+    }
+
+    #[instrument(name = "FraudRepository::create_review", skip_all)]
+    async fn create_review(
+        &self,
+        input: &CreateFraudReview,
+    ) -> Result<fraud_reviews::Model, DbErr> {
+        fraud_reviews::ActiveModel {
+            user_id: Set(input.user_id),
+            transaction_type: Set(input.transaction_type.clone()),
+            amount: Set(input.amount),
+            counterparty_id: Set(input.counterparty_id),
+            payload: Set(input.payload.clone()),
+            triggered_rules: Set(input.triggered_rules.join(",")),
+            status: Set("pending".to_string()),
+            ..Default::default()
+        }
+        .insert(&self.db_pool)
+        .await
+    }
+
+    #[instrument(name = "FraudRepository::find_review_by_id", skip_all)]
+    async fn find_review_by_id(&self, id: i32) -> Result<Option<fraud_reviews::Model>, DbErr> {
+        fraud_reviews::Entity::find_by_id(id)
+            .one(&self.db_pool)
+            .await
+    }
+
+    #[instrument(name = "FraudRepository::find_reviews", skip_all)]
+    async fn find_reviews(
+        &self,
+        status: Option<String>,
+    ) -> Result<Vec<fraud_reviews::Model>, DbErr> {
+        let mut query = fraud_reviews::Entity::find();
+
+        if let Some(status) = status {
+            query = query.filter(fraud_reviews::Column::Status.eq(status));
+        }
+
+        query
+            .order_by_asc(fraud_reviews::Column::FraudReviewId)
+            .all(&self.db_pool)
+            .await
+    }
+
+    #[instrument(name = "FraudRepository::transition_review", skip_all)]
+    async fn transition_review(
+        &self,
+        id: i32,
+        from_status: &str,
+        to_status: &str,
+        reviewer_id: i32,
+        note: Option<String>,
+    ) -> Result<bool, DbErr> {
+        let now = Utc::now().naive_utc();
+
+        let mut update = fraud_reviews::Entity::update_many()
+            .col_expr(fraud_reviews::Column::Status, Expr::value(to_status))
+            .col_expr(fraud_reviews::Column::ReviewerId, Expr::value(reviewer_id))
+            .col_expr(fraud_reviews::Column::ReviewedAt, Expr::value(now))
+            .col_expr(fraud_reviews::Column::UpdatedAt, Expr::value(now));
+
+        if note.is_some() {
+            update = update.col_expr(fraud_reviews::Column::ReviewNote, Expr::value(note));
+        }
+
+        let result = update
+            .filter(fraud_reviews::Column::FraudReviewId.eq(id))
+            .filter(fraud_reviews::Column::Status.eq(from_status))
+            .exec(&self.db_pool)
+            .await?;
+
+        Ok(result.rows_affected == 1)
+    }
+
+    #[instrument(name = "FraudRepository::set_review_transaction", skip_all)]
+    async fn set_review_transaction(&self, id: i32, transaction_id: i32) -> Result<(), DbErr> {
+        fraud_reviews::Entity::update_many()
+            .col_expr(
+                fraud_reviews::Column::TransactionId,
+                Expr::value(transaction_id),
+            )
+            .col_expr(
+                fraud_reviews::Column::UpdatedAt,
+                Expr::value(Utc::now().naive_utc()),
+            )
+            .filter(fraud_reviews::Column::FraudReviewId.eq(id))
+            .exec(&self.db_pool)
+            .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,41 @@
+use async_trait::async_trait;
+use sea_orm::DbErr;
+use sea_orm_migration::MigratorTrait;
+use tracing::instrument;
+
+use crate::{
+    abstract_trait::health::HealthRepositoryTrait, config::database::DatabasePools,
+    migrations::Migrator,
+};
+
+pub struct HealthRepository {
+    db_pools: DatabasePools,
+}
+
+impl HealthRepository {
+    pub fn new(db_pools: DatabasePools) -> Self {
+        Self { db_pools }
+    }
+}
+
+#[async_trait]
+impl HealthRepositoryTrait for HealthRepository {
+    #[instrument(name = "HealthRepository::ping", skip_all)]
+    async fn ping(&self) -> Result<(), DbErr> {
+        self.db_pools.primary().ping().await
+    }
+
+    #[instrument(name = "HealthRepository::ping_replica", skip_all)]
+    async fn ping_replica(&self) -> Option<Result<(), DbErr>> {
+        Some(self.db_pools.replica()?.ping().await)
+    }
+
+    #[instrument(name = "HealthRepository::pending_migrations", skip_all)]
+    async fn pending_migrations(&self) -> Result<Vec<String>, DbErr> {
+        Ok(Migrator::get_pending_migrations(self.db_pools.primary())
+            .await?
+            .iter()
+            .map(|migration| migration.name().to_string())
+            .collect())
+    }
+}
@@ -0,0 +1,146 @@
+use async_trait::async_trait;
+use chrono::Utc;
+use sea_orm::{
+    sea_query::Expr, ActiveModelTrait, ColumnTrait, DatabaseConnection, DbErr, EntityTrait,
+    QueryFilter, QueryOrder, Set,
+};
+
+use crate::{
+    abstract_trait::kyc::KycRepositoryTrait,
+    domain::request::kyc::RecordKycSubmissionEvent,
+    entities::{kyc_submission_events, kyc_submissions},
+};
+use tracing::instrument;
+
+pub struct KycRepository {
+    db_pool: DatabaseConnection,
+}
+
+impl KycRepository {
+    pub fn new(db_pool: DatabaseConnection) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl KycRepositoryTrait for KycRepository {
+    #[instrument(name = "KycRepository::create_submission", skip_all)]
+    async fn create_submission(
+        &self,
+        user_id: i32,
+        requested_level: i32,
+        id_card_key: &str,
+        selfie_key: &str,
+    ) -> Result<kyc_submissions::Model, DbErr> {
+        kyc_submissions::ActiveModel {
+            user_id: Set(user_id),
+            requested_level: Set(requested_level),
+            id_card_key: Set(id_card_key.to_string()),
+            selfie_key: Set(selfie_key.to_string()),
+            status: Set("submitted".to_string()),
+            ..Default::default()
+        }
+        .insert(&self.db_pool)
+        .await
+    }
+
+    #[instrument(name = "KycRepository::find_by_id", skip_all)]
+    async fn find_by_id(&self, id: i32) -> Result<Option<kyc_submissions::Model>, DbErr> {
+        kyc_submissions::Entity::find_by_id(id)
+            .one(&self.db_pool)
+            .await
+    }
+
+    #[instrument(name = "KycRepository::find_by_user", skip_all)]
+    async fn find_by_user(&self, user_id: i32) -> Result<Vec<kyc_submissions::Model>, DbErr> {
+        kyc_submissions::Entity::find()
+            .filter(kyc_submissions::Column::UserId.eq(user_id))
+            .order_by_desc(kyc_submissions::Column::KycSubmissionId)
+            .all(&self.db_pool)
+            .await
+    }
+
+    #[instrument(name = "KycRepository::find_by_status", skip_all)]
+    async fn find_by_status(
+        &self,
+        status: Option<String>,
+    ) -> Result<Vec<kyc_submissions::Model>, DbErr> {
+        let mut query = kyc_submissions::Entity::find();
+
+        if let Some(status) = status {
+            query = query.filter(kyc_submissions::Column::Status.eq(status));
+        }
+
+        query
+            .order_by_asc(kyc_submissions::Column::KycSubmissionId)
+            .all(&self.db_pool)
+            .await
+    }
+
+    #[instrument(name = "KycRepository::find_open_by_user", skip_all)]
+    async fn find_open_by_user(
+        &self,
+        user_id: i32,
+    ) -> Result<Option<kyc_submissions::Model>, DbErr> {
+        kyc_submissions::Entity::find()
+            .filter(kyc_submissions::Column::UserId.eq(user_id))
+            .filter(kyc_submissions::Column::Status.is_in(["submitted", "in_review"]))
+            .one(&self.db_pool)
+            .await
+    }
+
+    #[instrument(name = "KycRepository::transition_status", skip_all)]
+    async fn transition_status(
+        &self,
+        id: i32,
+        from_status: &str,
+        to_status: &str,
+        reviewer_id: i32,
+        note: Option<String>,
+    ) -> Result<bool, DbErr> {
+        let now = Utc::now().naive_utc();
+
+        let mut update = kyc_submissions::Entity::update_many()
+            .col_expr(kyc_submissions::Column::Status, Expr::value(to_status))
+            .col_expr(
+                kyc_submissions::Column::ReviewerId,
+                Expr::value(reviewer_id),
+            )
+            .col_expr(kyc_submissions::Column::UpdatedAt, Expr::value(now));
+
+        if note.is_some() {
+            update = update.col_expr(kyc_submissions::Column::ReviewNote, Expr::value(note));
+        }
+
+        let reviewed_at = matches!(to_status, "approved" | "rejected").then_some(now);
+        update = update.col_expr(
+            kyc_submissions::Column::ReviewedAt,
+            Expr::value(reviewed_at),
+        );
+
+        let result = update
+            .filter(kyc_submissions::Column::KycSubmissionId.eq(id))
+            .filter(kyc_submissions::Column::Status.eq(from_status))
+            .exec(&self.db_pool)
+            .await?;
+
+        Ok(result.rows_affected == 1)
+    }
+
+    #[instrument(name = "KycRepository::record_event", skip_all)]
+    async fn record_event(
+        &self,
+        input: &RecordKycSubmissionEvent,
+    ) -> Result<kyc_submission_events::Model, DbErr> {
+        kyc_submission_events::ActiveModel {
+            kyc_submission_id: Set(input.kyc_submission_id),
+            actor_id: Set(input.actor_id),
+            from_status: Set(input.from_status.clone()),
+            to_status: Set(input.to_status.clone()),
+            note: Set(input.note.clone()),
+            ..Default::default()
+        }
+        .insert(&self.db_pool)
+        .await
+    }
+}
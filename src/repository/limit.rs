@@ -0,0 +1,106 @@
+use async_trait::async_trait;
+use chrono::NaiveDateTime;
+use sea_orm::{
+    ColumnTrait, DatabaseConnection, DbErr, EntityTrait, QueryFilter, QueryOrder, QuerySelect,
+};
+
+use crate::{
+    abstract_trait::limit::LimitRepositoryTrait,
+    entities::{topups, transaction_limits, transfers, withdraws},
+};
+use tracing::instrument;
+
+pub struct LimitRepository {
+    db_pool: DatabaseConnection,
+}
+
+impl LimitRepository {
+    pub fn new(db_pool: DatabaseConnection) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl LimitRepositoryTrait for LimitRepository {
+    #[instrument(name = "LimitRepository::find_all", skip_all)]
+    async fn find_all(&self) -> Result<Vec<transaction_limits::Model>, DbErr> {
+        transaction_limits::Entity::find()
+            .order_by_asc(transaction_limits::Column::KycLevel)
+            .order_by_asc(transaction_limits::Column::TransactionType)
+            .all(&self.db_pool)
+            .await
+    }
+
+    #[instrument(name = "LimitRepository::find_by_level", skip_all)]
+    async fn find_by_level(&self, kyc_level: i32) -> Result<Vec<transaction_limits::Model>, DbErr> {
+        transaction_limits::Entity::find()
+            .filter(transaction_limits::Column::KycLevel.eq(kyc_level))
+            .order_by_asc(transaction_limits::Column::TransactionType)
+            .all(&self.db_pool)
+            .await
+    }
+
+    #[instrument(name = "LimitRepository::find_limit", skip_all)]
+    async fn find_limit(
+        &self,
+        kyc_level: i32,
+        transaction_type: &str,
+    ) -> Result<Option<transaction_limits::Model>, DbErr> {
+        transaction_limits::Entity::find()
+            .filter(transaction_limits::Column::KycLevel.eq(kyc_level))
+            .filter(transaction_limits::Column::TransactionType.eq(transaction_type))
+            .one(&self.db_pool)
+            .await
+    }
+
+    #[instrument(name = "LimitRepository::sum_user_amount_since", skip_all)]
+    async fn sum_user_amount_since(
+        &self,
+        transaction_type: &str,
+        user_id: i32,
+        since: NaiveDateTime,
+    ) -> Result<i64, DbErr> {
+        let total: Option<Option<i64>> = match transaction_type {
+            "topup" => {
+                topups::Entity::find()
+                    .select_only()
+                    .column_as(topups::Column::TopupAmount.sum(), "total")
+                    .filter(topups::Column::UserId.eq(user_id))
+                    .filter(topups::Column::TopupTime.gte(since))
+                    .filter(topups::Column::Status.ne("failed"))
+                    .into_tuple()
+                    .one(&self.db_pool)
+                    .await?
+            }
+            "transfer" => {
+                transfers::Entity::find()
+                    .select_only()
+                    .column_as(transfers::Column::TransferAmount.sum(), "total")
+                    .filter(transfers::Column::TransferFrom.eq(user_id))
+                    .filter(transfers::Column::TransferTime.gte(since))
+                    .into_tuple()
+                    .one(&self.db_pool)
+                    .await?
+            }
+            "withdraw" => {
+                withdraws::Entity::find()
+                    .select_only()
+                    .column_as(withdraws::Column::WithdrawAmount.sum(), "total")
+                    .filter(withdraws::Column::UserId.eq(user_id))
+                    .filter(withdraws::Column::WithdrawTime.gte(since))
+                    .filter(withdraws::Column::Status.ne("failed"))
+                    .into_tuple()
+                    .one(&self.db_pool)
+                    .await?
+            }
+            other => {
+                return Err(DbErr::Custom(format!(
+                    "Unknown limit transaction type {}",
+                    other
+                )))
+            }
+        };
+
+        Ok(total.flatten().unwrap_or(0))
+    }
+}
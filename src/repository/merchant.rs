@@ -0,0 +1,56 @@
+use async_trait::async_trait;
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, DatabaseConnection, DbErr, EntityTrait, QueryFilter, QueryOrder,
+    Set,
+};
+
+use crate::{
+    abstract_trait::merchant::MerchantRepositoryTrait,
+    domain::request::merchant::CreateMerchantRequest, entities::merchants,
+};
+use tracing::instrument;
+
+pub struct MerchantRepository {
+    db_pool: DatabaseConnection,
+}
+
+impl MerchantRepository {
+    pub fn new(db_pool: DatabaseConnection) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl MerchantRepositoryTrait for MerchantRepository {
+    #[instrument(name = "MerchantRepository::find_all", skip_all)]
+    async fn find_all(&self) -> Result<Vec<merchants::Model>, DbErr> {
+        merchants::Entity::find()
+            .order_by_asc(merchants::Column::MerchantId)
+            .all(&self.db_pool)
+            .await
+    }
+
+    #[instrument(name = "MerchantRepository::find_by_id", skip_all)]
+    async fn find_by_id(&self, id: i32) -> Result<Option<merchants::Model>, DbErr> {
+        merchants::Entity::find_by_id(id).one(&self.db_pool).await
+    }
+
+    #[instrument(name = "MerchantRepository::find_by_user", skip_all)]
+    async fn find_by_user(&self, user_id: i32) -> Result<Option<merchants::Model>, DbErr> {
+        merchants::Entity::find()
+            .filter(merchants::Column::UserId.eq(user_id))
+            .one(&self.db_pool)
+            .await
+    }
+
+    #[instrument(name = "MerchantRepository::create", skip_all)]
+    async fn create(&self, input: &CreateMerchantRequest) -> Result<merchants::Model, DbErr> {
+        let merchant = merchants::ActiveModel {
+            user_id: Set(input.user_id),
+            name: Set(input.name.trim().to_string()),
+            ..Default::default()
+        };
+
+        merchant.insert(&self.db_pool).await
+    }
+}
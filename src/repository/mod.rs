@@ -1,5 +1,23 @@
+pub mod audit;
+pub mod bank_account;
+pub mod beneficiary;
+pub mod fee;
+pub mod fraud;
+pub mod health;
+pub mod kyc;
+pub mod limit;
+pub mod merchant;
+pub mod payment_intent;
+pub mod qris;
+pub mod refund;
 pub mod user;
 pub mod transfer;
+pub mod transfer_batch;
 pub mod saldo;
+pub mod scheduled_transfer;
+pub mod screening;
+pub mod settlement;
 pub mod topup;
+pub mod virtual_account;
+pub mod webhook;
 pub mod withdraw;
\ No newline at end of file
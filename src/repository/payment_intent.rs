@@ -0,0 +1,225 @@
+use async_trait::async_trait;
+use chrono::Utc;
+use sea_orm::{
+    sea_query::Expr, ActiveModelTrait, ColumnTrait, DatabaseConnection, DbErr, EntityTrait,
+    QueryFilter, QueryOrder, Set, TransactionTrait,
+};
+
+use crate::{
+    abstract_trait::payment_intent::PaymentIntentRepositoryTrait,
+    domain::{
+        request::payment_intent::CreatePaymentIntentRequest,
+        response::payment_intent::PaymentIntentResponse,
+    },
+    entities::payment_intents,
+    repository::webhook::enqueue_webhook_event,
+};
+use tracing::instrument;
+
+pub struct PaymentIntentRepository {
+    db_pool: DatabaseConnection,
+}
+
+impl PaymentIntentRepository {
+    pub fn new(db_pool: DatabaseConnection) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl PaymentIntentRepositoryTrait for PaymentIntentRepository {
+    #[instrument(name = "PaymentIntentRepository::find_by_id", skip_all)]
+    async fn find_by_id(&self, id: i32) -> Result<Option<payment_intents::Model>, DbErr> {
+        payment_intents::Entity::find_by_id(id)
+            .one(&self.db_pool)
+            .await
+    }
+
+    #[instrument(name = "PaymentIntentRepository::find_by_merchant", skip_all)]
+    async fn find_by_merchant(
+        &self,
+        merchant_id: i32,
+    ) -> Result<Vec<payment_intents::Model>, DbErr> {
+        payment_intents::Entity::find()
+            .filter(payment_intents::Column::MerchantId.eq(merchant_id))
+            .order_by_desc(payment_intents::Column::PaymentIntentId)
+            .all(&self.db_pool)
+            .await
+    }
+
+    #[instrument(name = "PaymentIntentRepository::find_by_reference", skip_all)]
+    async fn find_by_reference(
+        &self,
+        merchant_id: i32,
+        reference: &str,
+    ) -> Result<Option<payment_intents::Model>, DbErr> {
+        payment_intents::Entity::find()
+            .filter(payment_intents::Column::MerchantId.eq(merchant_id))
+            .filter(payment_intents::Column::Reference.eq(reference))
+            .one(&self.db_pool)
+            .await
+    }
+
+    #[instrument(name = "PaymentIntentRepository::find_by_fraud_review", skip_all)]
+    async fn find_by_fraud_review(
+        &self,
+        fraud_review_id: i32,
+    ) -> Result<Option<payment_intents::Model>, DbErr> {
+        payment_intents::Entity::find()
+            .filter(payment_intents::Column::FraudReviewId.eq(fraud_review_id))
+            .one(&self.db_pool)
+            .await
+    }
+
+    #[instrument(name = "PaymentIntentRepository::create", skip_all)]
+    async fn create(
+        &self,
+        input: &CreatePaymentIntentRequest,
+        merchant_id: i32,
+    ) -> Result<payment_intents::Model, DbErr> {
+        let payment_intent = payment_intents::ActiveModel {
+            merchant_id: Set(merchant_id),
+            amount: Set(input.amount),
+            currency: Set(input.currency.clone()),
+            reference: Set(input.reference.trim().to_string()),
+            status: Set("requires_confirmation".to_string()),
+            expires_at: Set(input.expires_at.naive_utc()),
+            ..Default::default()
+        };
+
+        payment_intent.insert(&self.db_pool).await
+    }
+
+    #[instrument(name = "PaymentIntentRepository::transition_status", skip_all)]
+    async fn transition_status(
+        &self,
+        id: i32,
+        from_status: &str,
+        to_status: &str,
+    ) -> Result<bool, DbErr> {
+        let result = payment_intents::Entity::update_many()
+            .col_expr(payment_intents::Column::Status, Expr::value(to_status))
+            .col_expr(
+                payment_intents::Column::UpdatedAt,
+                Expr::value(Utc::now().naive_utc()),
+            )
+            .filter(payment_intents::Column::PaymentIntentId.eq(id))
+            .filter(payment_intents::Column::Status.eq(from_status))
+            .exec(&self.db_pool)
+            .await?;
+
+        Ok(result.rows_affected == 1)
+    }
+
+    #[instrument(name = "PaymentIntentRepository::hold_for_review", skip_all)]
+    async fn hold_for_review(
+        &self,
+        id: i32,
+        customer_id: i32,
+        fraud_review_id: i32,
+    ) -> Result<bool, DbErr> {
+        let result = payment_intents::Entity::update_many()
+            .col_expr(
+                payment_intents::Column::CustomerId,
+                Expr::value(customer_id),
+            )
+            .col_expr(
+                payment_intents::Column::FraudReviewId,
+                Expr::value(fraud_review_id),
+            )
+            .col_expr(
+                payment_intents::Column::UpdatedAt,
+                Expr::value(Utc::now().naive_utc()),
+            )
+            .filter(payment_intents::Column::PaymentIntentId.eq(id))
+            .filter(payment_intents::Column::Status.eq("processing"))
+            .exec(&self.db_pool)
+            .await?;
+
+        Ok(result.rows_affected == 1)
+    }
+
+    #[instrument(name = "PaymentIntentRepository::record_success", skip_all)]
+    async fn record_success(
+        &self,
+        id: i32,
+        customer_id: i32,
+        transfer_id: i32,
+    ) -> Result<payment_intents::Model, DbErr> {
+        let txn = self.db_pool.begin().await?;
+
+        let mut payment_intent: payment_intents::ActiveModel =
+            payment_intents::Entity::find_by_id(id)
+                .one(&txn)
+                .await?
+                .ok_or(DbErr::RecordNotFound("Payment intent not found".to_owned()))?
+                .into();
+
+        let now = Utc::now().naive_utc();
+
+        payment_intent.status = Set("succeeded".to_string());
+        payment_intent.customer_id = Set(Some(customer_id));
+        payment_intent.transfer_id = Set(Some(transfer_id));
+        payment_intent.succeeded_at = Set(Some(now));
+        payment_intent.updated_at = Set(Some(now));
+
+        let payment_intent = payment_intent.update(&txn).await?;
+
+        let payload = serde_json::to_value(PaymentIntentResponse::from(payment_intent.clone()))
+            .map_err(|e| DbErr::Custom(e.to_string()))?;
+        enqueue_webhook_event(
+            &txn,
+            payment_intent.merchant_id,
+            "payment_intent.succeeded",
+            &payload,
+        )
+        .await?;
+
+        txn.commit().await?;
+
+        Ok(payment_intent)
+    }
+
+    #[instrument(name = "PaymentIntentRepository::reserve_refund", skip_all)]
+    async fn reserve_refund(&self, id: i32, amount: i32) -> Result<bool, DbErr> {
+        // A single conditional UPDATE, so two refunds racing for the last
+        // refundable amount cannot both pass the check.
+        let result = payment_intents::Entity::update_many()
+            .col_expr(
+                payment_intents::Column::AmountRefunded,
+                Expr::col(payment_intents::Column::AmountRefunded).add(amount),
+            )
+            .col_expr(
+                payment_intents::Column::UpdatedAt,
+                Expr::value(Utc::now().naive_utc()),
+            )
+            .filter(payment_intents::Column::PaymentIntentId.eq(id))
+            .filter(payment_intents::Column::Status.eq("succeeded"))
+            .filter(
+                Expr::col(payment_intents::Column::AmountRefunded)
+                    .lte(Expr::col(payment_intents::Column::Amount).sub(amount)),
+            )
+            .exec(&self.db_pool)
+            .await?;
+
+        Ok(result.rows_affected == 1)
+    }
+
+    #[instrument(name = "PaymentIntentRepository::release_refund", skip_all)]
+    async fn release_refund(&self, id: i32, amount: i32) -> Result<(), DbErr> {
+        payment_intents::Entity::update_many()
+            .col_expr(
+                payment_intents::Column::AmountRefunded,
+                Expr::col(payment_intents::Column::AmountRefunded).sub(amount),
+            )
+            .col_expr(
+                payment_intents::Column::UpdatedAt,
+                Expr::value(Utc::now().naive_utc()),
+            )
+            .filter(payment_intents::Column::PaymentIntentId.eq(id))
+            .exec(&self.db_pool)
+            .await?;
+
+        Ok(())
+    }
+}
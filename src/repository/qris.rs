@@ -0,0 +1,138 @@
+use async_trait::async_trait;
+use chrono::Utc;
+use sea_orm::{
+    sea_query::Expr, ActiveModelTrait, ColumnTrait, DatabaseConnection, DbErr, EntityTrait,
+    QueryFilter, Set,
+};
+
+use crate::{
+    abstract_trait::qris::QrisRepositoryTrait, domain::request::qris::CreateQrisRequest,
+    entities::qris_payments,
+};
+use tracing::instrument;
+
+pub struct QrisRepository {
+    db_pool: DatabaseConnection,
+}
+
+impl QrisRepository {
+    pub fn new(db_pool: DatabaseConnection) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl QrisRepositoryTrait for QrisRepository {
+    #[instrument(name = "QrisRepository::find_by_reference", skip_all)]
+    async fn find_by_reference(
+        &self,
+        reference: &str,
+    ) -> Result<Option<qris_payments::Model>, DbErr> {
+        qris_payments::Entity::find()
+            .filter(qris_payments::Column::Reference.eq(reference))
+            .one(&self.db_pool)
+            .await
+    }
+
+    #[instrument(name = "QrisRepository::find_by_fraud_review", skip_all)]
+    async fn find_by_fraud_review(
+        &self,
+        fraud_review_id: i32,
+    ) -> Result<Option<qris_payments::Model>, DbErr> {
+        qris_payments::Entity::find()
+            .filter(qris_payments::Column::FraudReviewId.eq(fraud_review_id))
+            .one(&self.db_pool)
+            .await
+    }
+
+    #[instrument(name = "QrisRepository::create", skip_all)]
+    async fn create(
+        &self,
+        input: &CreateQrisRequest,
+        merchant_id: i32,
+        reference: &str,
+        payload: &str,
+    ) -> Result<qris_payments::Model, DbErr> {
+        let qris_payment = qris_payments::ActiveModel {
+            user_id: Set(input.user_id),
+            merchant_id: Set(Some(merchant_id)),
+            reference: Set(reference.to_string()),
+            amount: Set(input.amount),
+            payload: Set(payload.to_string()),
+            status: Set("pending".to_string()),
+            ..Default::default()
+        };
+
+        qris_payment.insert(&self.db_pool).await
+    }
+
+    #[instrument(name = "QrisRepository::transition_status", skip_all)]
+    async fn transition_status(
+        &self,
+        id: i32,
+        from_status: &str,
+        to_status: &str,
+    ) -> Result<bool, DbErr> {
+        let result = qris_payments::Entity::update_many()
+            .col_expr(qris_payments::Column::Status, Expr::value(to_status))
+            .col_expr(
+                qris_payments::Column::UpdatedAt,
+                Expr::value(Utc::now().naive_utc()),
+            )
+            .filter(qris_payments::Column::QrisPaymentId.eq(id))
+            .filter(qris_payments::Column::Status.eq(from_status))
+            .exec(&self.db_pool)
+            .await?;
+
+        Ok(result.rows_affected == 1)
+    }
+
+    #[instrument(name = "QrisRepository::hold_for_review", skip_all)]
+    async fn hold_for_review(
+        &self,
+        id: i32,
+        payer_id: i32,
+        fraud_review_id: i32,
+    ) -> Result<bool, DbErr> {
+        let result = qris_payments::Entity::update_many()
+            .col_expr(qris_payments::Column::PayerId, Expr::value(payer_id))
+            .col_expr(
+                qris_payments::Column::FraudReviewId,
+                Expr::value(fraud_review_id),
+            )
+            .col_expr(
+                qris_payments::Column::UpdatedAt,
+                Expr::value(Utc::now().naive_utc()),
+            )
+            .filter(qris_payments::Column::QrisPaymentId.eq(id))
+            .filter(qris_payments::Column::Status.eq("processing"))
+            .exec(&self.db_pool)
+            .await?;
+
+        Ok(result.rows_affected == 1)
+    }
+
+    #[instrument(name = "QrisRepository::record_payment", skip_all)]
+    async fn record_payment(
+        &self,
+        id: i32,
+        payer_id: i32,
+        transfer_id: i32,
+    ) -> Result<qris_payments::Model, DbErr> {
+        let mut qris_payment: qris_payments::ActiveModel = qris_payments::Entity::find_by_id(id)
+            .one(&self.db_pool)
+            .await?
+            .ok_or(DbErr::RecordNotFound("Qris payment not found".to_owned()))?
+            .into();
+
+        let now = Utc::now().naive_utc();
+
+        qris_payment.status = Set("paid".to_string());
+        qris_payment.payer_id = Set(Some(payer_id));
+        qris_payment.transfer_id = Set(Some(transfer_id));
+        qris_payment.paid_at = Set(Some(now));
+        qris_payment.updated_at = Set(Some(now));
+
+        qris_payment.update(&self.db_pool).await
+    }
+}
@@ -0,0 +1,81 @@
+use async_trait::async_trait;
+use chrono::Utc;
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, DatabaseConnection, DbErr, EntityTrait, QueryFilter, QueryOrder,
+    Set,
+};
+
+use crate::{
+    abstract_trait::refund::RefundRepositoryTrait, domain::request::refund::CreateRefundRequest,
+    entities::refunds,
+};
+use tracing::instrument;
+
+pub struct RefundRepository {
+    db_pool: DatabaseConnection,
+}
+
+impl RefundRepository {
+    pub fn new(db_pool: DatabaseConnection) -> Self {
+        Self { db_pool }
+    }
+
+    async fn find_active(&self, id: i32) -> Result<refunds::ActiveModel, DbErr> {
+        refunds::Entity::find_by_id(id)
+            .one(&self.db_pool)
+            .await?
+            .ok_or(DbErr::RecordNotFound("Refund not found".to_owned()))
+            .map(Into::into)
+    }
+}
+
+#[async_trait]
+impl RefundRepositoryTrait for RefundRepository {
+    #[instrument(name = "RefundRepository::find_by_payment_intent", skip_all)]
+    async fn find_by_payment_intent(
+        &self,
+        payment_intent_id: i32,
+    ) -> Result<Vec<refunds::Model>, DbErr> {
+        refunds::Entity::find()
+            .filter(refunds::Column::PaymentIntentId.eq(payment_intent_id))
+            .order_by_asc(refunds::Column::RefundId)
+            .all(&self.db_pool)
+            .await
+    }
+
+    #[instrument(name = "RefundRepository::create", skip_all)]
+    async fn create(&self, input: &CreateRefundRequest) -> Result<refunds::Model, DbErr> {
+        let refund = refunds::ActiveModel {
+            payment_intent_id: Set(input.payment_intent_id),
+            amount: Set(input.amount),
+            reason: Set(input.reason.trim().to_string()),
+            status: Set("pending".to_string()),
+            ..Default::default()
+        };
+
+        refund.insert(&self.db_pool).await
+    }
+
+    #[instrument(name = "RefundRepository::mark_succeeded", skip_all)]
+    async fn mark_succeeded(&self, id: i32) -> Result<refunds::Model, DbErr> {
+        let mut refund = self.find_active(id).await?;
+        let now = Utc::now().naive_utc();
+
+        refund.status = Set("succeeded".to_string());
+        refund.refunded_at = Set(Some(now));
+        refund.updated_at = Set(Some(now));
+
+        refund.update(&self.db_pool).await
+    }
+
+    #[instrument(name = "RefundRepository::mark_failed", skip_all)]
+    async fn mark_failed(&self, id: i32, failure_reason: &str) -> Result<refunds::Model, DbErr> {
+        let mut refund = self.find_active(id).await?;
+
+        refund.status = Set("failed".to_string());
+        refund.failure_reason = Set(Some(failure_reason.chars().take(255).collect()));
+        refund.updated_at = Set(Some(Utc::now().naive_utc()));
+
+        refund.update(&self.db_pool).await
+    }
+}
@@ -1,10 +1,11 @@
 use async_trait::async_trait;
 use chrono::Utc;
 use sea_orm::{
-    ActiveModelTrait, ColumnTrait, DatabaseConnection, DbErr, EntityTrait, ModelTrait, QueryFilter,
+    ActiveModelTrait, ColumnTrait, DbErr, EntityTrait, ModelTrait, QueryFilter,
     Set,
 };
 
+use crate::config::database::DatabasePools;
 use crate::{
     abstract_trait::saldo::SaldoRepositoryTrait,
     domain::request::saldo::{
@@ -12,57 +13,64 @@ use crate::{
     },
     entities::saldo,
 };
+use tracing::instrument;
 
 pub struct SaldoRepository {
-    db_pool: DatabaseConnection,
+    db_pools: DatabasePools,
 }
 
 impl SaldoRepository {
-    pub fn new(db_pool: DatabaseConnection) -> Self {
-        Self { db_pool }
+    pub fn new(db_pools: DatabasePools) -> Self {
+        Self { db_pools }
     }
 }
 
 #[async_trait]
 impl SaldoRepositoryTrait for SaldoRepository {
+    #[instrument(name = "SaldoRepository::find_all", skip_all)]
     async fn find_all(&self) -> Result<Vec<saldo::Model>, DbErr> {
-        saldo::Entity::find().all(&self.db_pool).await
+        saldo::Entity::find().all(self.db_pools.reader()).await
     }
 
+    #[instrument(name = "SaldoRepository::find_by_id", skip_all)]
     async fn find_by_id(&self, id: i32) -> Result<Option<saldo::Model>, DbErr> {
         saldo::Entity::find()
             .filter(saldo::Column::SaldoId.eq(id))
-            .one(&self.db_pool)
+            .one(self.db_pools.reader())
             .await
     }
 
+    #[instrument(name = "SaldoRepository::find_by_user_id", skip_all)]
     async fn find_by_user_id(&self, id: i32) -> Result<Option<saldo::Model>, DbErr> {
         saldo::Entity::find()
             .filter(saldo::Column::UserId.eq(id))
-            .one(&self.db_pool)
+            .one(self.db_pools.primary())
             .await
     }
 
+    #[instrument(name = "SaldoRepository::find_by_users_id", skip_all)]
     async fn find_by_users_id(&self, id: i32) -> Result<Vec<Option<saldo::Model>>, DbErr> {
         saldo::Entity::find()
             .filter(saldo::Column::UserId.eq(id))
-            .all(&self.db_pool)
+            .all(self.db_pools.reader())
             .await
             .map(|res| res.into_iter().map(Some).collect()) // Wrap each result in `Some` to match `Vec<Option<saldo::Model>>`
     }
 
+    #[instrument(name = "SaldoRepository::create", skip_all)]
     async fn create(&self, input: &CreateSaldoRequest) -> Result<saldo::Model, DbErr> {
         let new_saldo = saldo::ActiveModel {
             user_id: Set(input.user_id),
             total_balance: Set(input.total_balance),
             ..Default::default()
         };
-        new_saldo.insert(&self.db_pool).await
+        new_saldo.insert(self.db_pools.primary()).await
     }
 
+    #[instrument(name = "SaldoRepository::update", skip_all)]
     async fn update(&self, input: &UpdateSaldoRequest) -> Result<saldo::Model, DbErr> {
         let mut saldo_record: saldo::ActiveModel = saldo::Entity::find_by_id(input.saldo_id)
-            .one(&self.db_pool)
+            .one(self.db_pools.primary())
             .await?
             .ok_or(DbErr::RecordNotFound("Saldo not found".to_owned()))?
             .into();
@@ -84,29 +92,31 @@ impl SaldoRepositoryTrait for SaldoRepository {
         saldo_record.withdraw_time =
             Set(Some(input.withdraw_time.unwrap_or(Utc::now().naive_utc())));
 
-        saldo_record.update(&self.db_pool).await
+        saldo_record.update(self.db_pools.primary()).await
     }
 
+    #[instrument(name = "SaldoRepository::update_balance", skip_all)]
     async fn update_balance(&self, input: &UpdateSaldoBalance) -> Result<saldo::Model, DbErr> {
         let mut saldo_record: saldo::ActiveModel = saldo::Entity::find()
             .filter(saldo::Column::UserId.eq(input.user_id))
-            .one(&self.db_pool)
+            .one(self.db_pools.primary())
             .await?
             .ok_or(DbErr::RecordNotFound("Saldo not found".to_owned()))?
             .into();
 
         saldo_record.total_balance = Set(input.total_balance);
 
-        saldo_record.update(&self.db_pool).await
+        saldo_record.update(self.db_pools.primary()).await
     }
 
+    #[instrument(name = "SaldoRepository::update_saldo_withdraw", skip_all)]
     async fn update_saldo_withdraw(
         &self,
         input: &UpdateSaldoWithdraw,
     ) -> Result<saldo::Model, DbErr> {
         let mut saldo_record: saldo::ActiveModel = saldo::Entity::find()
             .filter(saldo::Column::UserId.eq(input.user_id))
-            .one(&self.db_pool)
+            .one(self.db_pools.primary())
             .await?
             .ok_or(DbErr::RecordNotFound("Saldo not found".to_owned()))?
             .into();
@@ -120,19 +130,20 @@ impl SaldoRepositoryTrait for SaldoRepository {
 
             saldo_record.total_balance = Set(current_balance - withdraw_amount);
             saldo_record.withdraw_amount = Set(Some(withdraw_amount));
-            saldo_record.withdraw_time = Set(input.withdraw_time.clone());
+            saldo_record.withdraw_time = Set(input.withdraw_time);
         }
 
-        saldo_record.update(&self.db_pool).await
+        saldo_record.update(self.db_pools.primary()).await
     }
 
+    #[instrument(name = "SaldoRepository::delete", skip_all)]
     async fn delete(&self, id: i32) -> Result<(), DbErr> {
         let saldo_record = saldo::Entity::find()
             .filter(saldo::Column::UserId.eq(id))
-            .one(&self.db_pool)
+            .one(self.db_pools.primary())
             .await?
             .ok_or(DbErr::RecordNotFound("Saldo not found".to_owned()))?;
 
-        saldo_record.delete(&self.db_pool).await.map(|_| ())
+        saldo_record.delete(self.db_pools.primary()).await.map(|_| ())
     }
 }
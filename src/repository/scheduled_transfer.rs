@@ -0,0 +1,245 @@
+use async_trait::async_trait;
+use chrono::{NaiveDateTime, Utc};
+use sea_orm::{
+    sea_query::Expr, ActiveModelTrait, ColumnTrait, DatabaseConnection, DbErr, EntityTrait,
+    QueryFilter, QueryOrder, QuerySelect, Set,
+};
+
+use crate::{
+    abstract_trait::scheduled_transfer::ScheduledTransferRepositoryTrait,
+    domain::request::scheduled_transfer::{
+        CreateScheduledTransferAttempt, CreateScheduledTransferRequest,
+        UpdateScheduledTransferRequest, UpdateScheduledTransferRun,
+    },
+    entities::{scheduled_transfer_attempts, scheduled_transfers},
+};
+use tracing::instrument;
+
+pub struct ScheduledTransferRepository {
+    db_pool: DatabaseConnection,
+}
+
+impl ScheduledTransferRepository {
+    pub fn new(db_pool: DatabaseConnection) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl ScheduledTransferRepositoryTrait for ScheduledTransferRepository {
+    #[instrument(name = "ScheduledTransferRepository::find_by_user", skip_all)]
+    async fn find_by_user(&self, user_id: i32) -> Result<Vec<scheduled_transfers::Model>, DbErr> {
+        scheduled_transfers::Entity::find()
+            .filter(scheduled_transfers::Column::TransferFrom.eq(user_id))
+            .order_by_asc(scheduled_transfers::Column::ScheduleId)
+            .all(&self.db_pool)
+            .await
+    }
+
+    #[instrument(name = "ScheduledTransferRepository::find_by_id", skip_all)]
+    async fn find_by_id(&self, id: i32) -> Result<Option<scheduled_transfers::Model>, DbErr> {
+        scheduled_transfers::Entity::find_by_id(id)
+            .one(&self.db_pool)
+            .await
+    }
+
+    #[instrument(name = "ScheduledTransferRepository::find_due", skip_all)]
+    async fn find_due(
+        &self,
+        now: NaiveDateTime,
+        limit: u64,
+    ) -> Result<Vec<scheduled_transfers::Model>, DbErr> {
+        scheduled_transfers::Entity::find()
+            .filter(scheduled_transfers::Column::Status.eq("active"))
+            .filter(scheduled_transfers::Column::NextRunAt.lte(now))
+            .order_by_asc(scheduled_transfers::Column::NextRunAt)
+            .limit(limit)
+            .all(&self.db_pool)
+            .await
+    }
+
+    #[instrument(name = "ScheduledTransferRepository::create", skip_all)]
+    async fn create(
+        &self,
+        input: &CreateScheduledTransferRequest,
+        next_run_at: NaiveDateTime,
+    ) -> Result<scheduled_transfers::Model, DbErr> {
+        let schedule = scheduled_transfers::ActiveModel {
+            transfer_from: Set(input.transfer_from),
+            transfer_to: Set(input.transfer_to),
+            transfer_amount: Set(input.transfer_amount),
+            frequency: Set(input.frequency.clone()),
+            day_of_month: Set(input.day_of_month),
+            start_at: Set(input.start_at.naive_utc()),
+            next_run_at: Set(next_run_at),
+            status: Set("active".to_string()),
+            retry_count: Set(0),
+            ..Default::default()
+        };
+
+        schedule.insert(&self.db_pool).await
+    }
+
+    #[instrument(name = "ScheduledTransferRepository::update", skip_all)]
+    async fn update(
+        &self,
+        input: &UpdateScheduledTransferRequest,
+    ) -> Result<scheduled_transfers::Model, DbErr> {
+        let mut schedule: scheduled_transfers::ActiveModel =
+            scheduled_transfers::Entity::find_by_id(input.schedule_id)
+                .one(&self.db_pool)
+                .await?
+                .ok_or(DbErr::RecordNotFound(
+                    "Scheduled transfer not found".to_owned(),
+                ))?
+                .into();
+
+        if let Some(amount) = input.transfer_amount {
+            schedule.transfer_amount = Set(amount);
+        }
+
+        if let Some(status) = &input.status {
+            schedule.status = Set(status.clone());
+        }
+
+        schedule.updated_at = Set(Some(Utc::now().naive_utc()));
+
+        schedule.update(&self.db_pool).await
+    }
+
+    #[instrument(name = "ScheduledTransferRepository::claim_run", skip_all)]
+    async fn claim_run(
+        &self,
+        input: &UpdateScheduledTransferRun,
+        due_at: NaiveDateTime,
+    ) -> Result<bool, DbErr> {
+        let result = scheduled_transfers::Entity::update_many()
+            .col_expr(
+                scheduled_transfers::Column::Status,
+                Expr::value(input.status.clone()),
+            )
+            .col_expr(
+                scheduled_transfers::Column::NextRunAt,
+                Expr::value(input.next_run_at),
+            )
+            .col_expr(
+                scheduled_transfers::Column::RetryCount,
+                Expr::value(input.retry_count),
+            )
+            .col_expr(
+                scheduled_transfers::Column::LastRunAt,
+                Expr::value(input.last_run_at),
+            )
+            .col_expr(
+                scheduled_transfers::Column::UpdatedAt,
+                Expr::value(Utc::now().naive_utc()),
+            )
+            .filter(scheduled_transfers::Column::ScheduleId.eq(input.schedule_id))
+            .filter(scheduled_transfers::Column::Status.eq("active"))
+            .filter(scheduled_transfers::Column::NextRunAt.eq(due_at))
+            .exec(&self.db_pool)
+            .await?;
+
+        Ok(result.rows_affected == 1)
+    }
+
+    #[instrument(name = "ScheduledTransferRepository::update_run", skip_all)]
+    async fn update_run(
+        &self,
+        input: &UpdateScheduledTransferRun,
+    ) -> Result<scheduled_transfers::Model, DbErr> {
+        let schedule = scheduled_transfers::ActiveModel {
+            schedule_id: Set(input.schedule_id),
+            status: Set(input.status.clone()),
+            next_run_at: Set(input.next_run_at),
+            retry_count: Set(input.retry_count),
+            last_run_at: Set(Some(input.last_run_at)),
+            updated_at: Set(Some(Utc::now().naive_utc())),
+            ..Default::default()
+        };
+
+        schedule.update(&self.db_pool).await
+    }
+
+    #[instrument(name = "ScheduledTransferRepository::delete", skip_all)]
+    async fn delete(&self, id: i32) -> Result<(), DbErr> {
+        scheduled_transfers::Entity::delete_by_id(id)
+            .exec(&self.db_pool)
+            .await?;
+
+        Ok(())
+    }
+
+    #[instrument(name = "ScheduledTransferRepository::create_attempt", skip_all)]
+    async fn create_attempt(
+        &self,
+        input: &CreateScheduledTransferAttempt,
+    ) -> Result<scheduled_transfer_attempts::Model, DbErr> {
+        let attempt = scheduled_transfer_attempts::ActiveModel {
+            schedule_id: Set(input.schedule_id),
+            status: Set(input.status.clone()),
+            transfer_id: Set(input.transfer_id),
+            error_message: Set(input.error_message.clone()),
+            attempted_at: Set(input.attempted_at),
+            fraud_review_id: Set(input.fraud_review_id),
+            ..Default::default()
+        };
+
+        attempt.insert(&self.db_pool).await
+    }
+
+    #[instrument(name = "ScheduledTransferRepository::find_attempts", skip_all)]
+    async fn find_attempts(
+        &self,
+        schedule_id: i32,
+    ) -> Result<Vec<scheduled_transfer_attempts::Model>, DbErr> {
+        scheduled_transfer_attempts::Entity::find()
+            .filter(scheduled_transfer_attempts::Column::ScheduleId.eq(schedule_id))
+            .order_by_asc(scheduled_transfer_attempts::Column::AttemptId)
+            .all(&self.db_pool)
+            .await
+    }
+
+    #[instrument(
+        name = "ScheduledTransferRepository::find_attempt_by_fraud_review",
+        skip_all
+    )]
+    async fn find_attempt_by_fraud_review(
+        &self,
+        fraud_review_id: i32,
+    ) -> Result<Option<scheduled_transfer_attempts::Model>, DbErr> {
+        scheduled_transfer_attempts::Entity::find()
+            .filter(scheduled_transfer_attempts::Column::FraudReviewId.eq(fraud_review_id))
+            .one(&self.db_pool)
+            .await
+    }
+
+    #[instrument(name = "ScheduledTransferRepository::resolve_held_attempt", skip_all)]
+    async fn resolve_held_attempt(
+        &self,
+        attempt_id: i32,
+        status: &str,
+        transfer_id: Option<i32>,
+        error_message: Option<String>,
+    ) -> Result<bool, DbErr> {
+        let result = scheduled_transfer_attempts::Entity::update_many()
+            .col_expr(
+                scheduled_transfer_attempts::Column::Status,
+                Expr::value(status),
+            )
+            .col_expr(
+                scheduled_transfer_attempts::Column::TransferId,
+                Expr::value(transfer_id),
+            )
+            .col_expr(
+                scheduled_transfer_attempts::Column::ErrorMessage,
+                Expr::value(error_message),
+            )
+            .filter(scheduled_transfer_attempts::Column::AttemptId.eq(attempt_id))
+            .filter(scheduled_transfer_attempts::Column::Status.eq("held"))
+            .exec(&self.db_pool)
+            .await?;
+
+        Ok(result.rows_affected == 1)
+    }
+}
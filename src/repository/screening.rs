@@ -0,0 +1,98 @@
+use async_trait::async_trait;
+use chrono::Utc;
+use sea_orm::{
+    sea_query::Expr, ActiveModelTrait, ColumnTrait, DatabaseConnection, DbErr, EntityTrait,
+    QueryFilter, QueryOrder, Set,
+};
+
+use crate::{
+    abstract_trait::screening::ScreeningRepositoryTrait,
+    domain::request::screening::CreateScreeningResult, entities::screening_results,
+};
+use tracing::instrument;
+
+pub struct ScreeningRepository {
+    db_pool: DatabaseConnection,
+}
+
+impl ScreeningRepository {
+    pub fn new(db_pool: DatabaseConnection) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl ScreeningRepositoryTrait for ScreeningRepository {
+    #[instrument(name = "ScreeningRepository::create_result", skip_all)]
+    async fn create_result(
+        &self,
+        input: &CreateScreeningResult,
+    ) -> Result<screening_results::Model, DbErr> {
+        screening_results::ActiveModel {
+            subject_type: Set(input.subject_type.clone()),
+            subject_id: Set(input.subject_id),
+            user_id: Set(input.user_id),
+            screened_name: Set(input.screened_name.clone()),
+            watchlist_entry_id: Set(input.watchlist_entry_id.clone()),
+            watchlist_name: Set(input.watchlist_name.clone()),
+            matched_name: Set(input.matched_name.clone()),
+            score: Set(input.score),
+            status: Set("pending".to_string()),
+            ..Default::default()
+        }
+        .insert(&self.db_pool)
+        .await
+    }
+
+    #[instrument(name = "ScreeningRepository::find_by_id", skip_all)]
+    async fn find_by_id(&self, id: i32) -> Result<Option<screening_results::Model>, DbErr> {
+        screening_results::Entity::find_by_id(id)
+            .one(&self.db_pool)
+            .await
+    }
+
+    #[instrument(name = "ScreeningRepository::find_by_status", skip_all)]
+    async fn find_by_status(
+        &self,
+        status: Option<String>,
+    ) -> Result<Vec<screening_results::Model>, DbErr> {
+        let mut query = screening_results::Entity::find();
+
+        if let Some(status) = status {
+            query = query.filter(screening_results::Column::Status.eq(status));
+        }
+
+        query
+            .order_by_asc(screening_results::Column::ScreeningResultId)
+            .all(&self.db_pool)
+            .await
+    }
+
+    #[instrument(name = "ScreeningRepository::transition_status", skip_all)]
+    async fn transition_status(
+        &self,
+        id: i32,
+        from_status: &str,
+        to_status: &str,
+        reviewer_id: i32,
+        note: Option<String>,
+    ) -> Result<bool, DbErr> {
+        let now = Utc::now().naive_utc();
+
+        let result = screening_results::Entity::update_many()
+            .col_expr(screening_results::Column::Status, Expr::value(to_status))
+            .col_expr(
+                screening_results::Column::ReviewerId,
+                Expr::value(reviewer_id),
+            )
+            .col_expr(screening_results::Column::ReviewNote, Expr::value(note))
+            .col_expr(screening_results::Column::ReviewedAt, Expr::value(now))
+            .col_expr(screening_results::Column::UpdatedAt, Expr::value(now))
+            .filter(screening_results::Column::ScreeningResultId.eq(id))
+            .filter(screening_results::Column::Status.eq(from_status))
+            .exec(&self.db_pool)
+            .await?;
+
+        Ok(result.rows_affected == 1)
+    }
+}
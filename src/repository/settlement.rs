@@ -0,0 +1,283 @@
+use async_trait::async_trait;
+use chrono::{NaiveDateTime, Utc};
+use sea_orm::{
+    sea_query::{Expr, Query},
+    ActiveModelTrait, ColumnTrait, DbErr, EntityTrait, QueryFilter, QueryOrder,
+    Set, TransactionTrait,
+};
+
+use crate::config::database::DatabasePools;
+use crate::{
+    abstract_trait::settlement::SettlementRepositoryTrait,
+    entities::{payment_intents, qris_payments, refunds, settlements},
+};
+use tracing::instrument;
+
+pub struct SettlementRepository {
+    db_pools: DatabasePools,
+}
+
+impl SettlementRepository {
+    pub fn new(db_pools: DatabasePools) -> Self {
+        Self { db_pools }
+    }
+}
+
+#[async_trait]
+impl SettlementRepositoryTrait for SettlementRepository {
+    #[instrument(name = "SettlementRepository::find_by_merchant", skip_all)]
+    async fn find_by_merchant(&self, merchant_id: i32) -> Result<Vec<settlements::Model>, DbErr> {
+        settlements::Entity::find()
+            .filter(settlements::Column::MerchantId.eq(merchant_id))
+            .order_by_desc(settlements::Column::PeriodStart)
+            .all(self.db_pools.reader())
+            .await
+    }
+
+    #[instrument(name = "SettlementRepository::find_by_id", skip_all)]
+    async fn find_by_id(&self, id: i32) -> Result<Option<settlements::Model>, DbErr> {
+        settlements::Entity::find_by_id(id).one(self.db_pools.reader()).await
+    }
+
+    #[instrument(name = "SettlementRepository::find_retryable", skip_all)]
+    async fn find_retryable(&self) -> Result<Vec<settlements::Model>, DbErr> {
+        settlements::Entity::find()
+            .filter(settlements::Column::Status.is_in(["held", "failed"]))
+            .order_by_asc(settlements::Column::PeriodStart)
+            .all(self.db_pools.primary())
+            .await
+    }
+
+    #[instrument(name = "SettlementRepository::find_by_fraud_review", skip_all)]
+    async fn find_by_fraud_review(
+        &self,
+        fraud_review_id: i32,
+    ) -> Result<Option<settlements::Model>, DbErr> {
+        settlements::Entity::find()
+            .filter(settlements::Column::FraudReviewId.eq(fraud_review_id))
+            .one(self.db_pools.primary())
+            .await
+    }
+
+    #[instrument(name = "SettlementRepository::create_settlement", skip_all)]
+    async fn create_settlement(
+        &self,
+        merchant_id: i32,
+        period_start: NaiveDateTime,
+        period_end: NaiveDateTime,
+    ) -> Result<Option<settlements::Model>, DbErr> {
+        let already_settled = settlements::Entity::find()
+            .filter(settlements::Column::MerchantId.eq(merchant_id))
+            .filter(settlements::Column::PeriodStart.eq(period_start))
+            .one(self.db_pools.primary())
+            .await?;
+
+        if already_settled.is_some() {
+            return Ok(None);
+        }
+
+        let txn = self.db_pools.primary().begin().await?;
+
+        let settlement = settlements::ActiveModel {
+            merchant_id: Set(merchant_id),
+            period_start: Set(period_start),
+            period_end: Set(period_end),
+            status: Set("pending".to_string()),
+            ..Default::default()
+        }
+        .insert(&txn)
+        .await?;
+
+        // The `settlement_id IS NULL` filters make the claim safe against a
+        // concurrent run: a row can only be taken by one settlement.
+        payment_intents::Entity::update_many()
+            .col_expr(
+                payment_intents::Column::SettlementId,
+                Expr::value(settlement.settlement_id),
+            )
+            .filter(payment_intents::Column::MerchantId.eq(merchant_id))
+            .filter(payment_intents::Column::Status.eq("succeeded"))
+            .filter(payment_intents::Column::SettlementId.is_null())
+            .filter(payment_intents::Column::SucceededAt.lt(period_end))
+            .exec(&txn)
+            .await?;
+
+        qris_payments::Entity::update_many()
+            .col_expr(
+                qris_payments::Column::SettlementId,
+                Expr::value(settlement.settlement_id),
+            )
+            .filter(qris_payments::Column::MerchantId.eq(merchant_id))
+            .filter(qris_payments::Column::Status.eq("paid"))
+            .filter(qris_payments::Column::SettlementId.is_null())
+            .filter(qris_payments::Column::PaidAt.lt(period_end))
+            .exec(&txn)
+            .await?;
+
+        refunds::Entity::update_many()
+            .col_expr(
+                refunds::Column::SettlementId,
+                Expr::value(settlement.settlement_id),
+            )
+            .filter(
+                refunds::Column::PaymentIntentId.in_subquery(
+                    Query::select()
+                        .column(payment_intents::Column::PaymentIntentId)
+                        .from(payment_intents::Entity)
+                        .and_where(payment_intents::Column::MerchantId.eq(merchant_id))
+                        .to_owned(),
+                ),
+            )
+            .filter(refunds::Column::Status.eq("succeeded"))
+            .filter(refunds::Column::SettlementId.is_null())
+            .filter(refunds::Column::RefundedAt.lt(period_end))
+            .exec(&txn)
+            .await?;
+
+        let payments = payment_intents::Entity::find()
+            .filter(payment_intents::Column::SettlementId.eq(settlement.settlement_id))
+            .all(&txn)
+            .await?;
+
+        let qris_payments = qris_payments::Entity::find()
+            .filter(qris_payments::Column::SettlementId.eq(settlement.settlement_id))
+            .all(&txn)
+            .await?;
+
+        let refunds = refunds::Entity::find()
+            .filter(refunds::Column::SettlementId.eq(settlement.settlement_id))
+            .all(&txn)
+            .await?;
+
+        if payments.is_empty() && qris_payments.is_empty() && refunds.is_empty() {
+            txn.rollback().await?;
+            return Ok(None);
+        }
+
+        let gross_amount: i32 = payments.iter().map(|payment| payment.amount).sum::<i32>()
+            + qris_payments.iter().map(|payment| payment.amount).sum::<i32>();
+        let refunded_amount: i32 = refunds.iter().map(|refund| refund.amount).sum();
+
+        let mut settlement: settlements::ActiveModel = settlement.into();
+        settlement.payment_count = Set((payments.len() + qris_payments.len()) as i32);
+        settlement.gross_amount = Set(gross_amount);
+        settlement.refunded_amount = Set(refunded_amount);
+        settlement.fee_amount = Set(0);
+        settlement.net_amount = Set(gross_amount - refunded_amount);
+
+        let settlement = settlement.update(&txn).await?;
+
+        txn.commit().await?;
+
+        Ok(Some(settlement))
+    }
+
+    #[instrument(name = "SettlementRepository::find_payments", skip_all)]
+    async fn find_payments(
+        &self,
+        settlement_id: i32,
+    ) -> Result<Vec<payment_intents::Model>, DbErr> {
+        payment_intents::Entity::find()
+            .filter(payment_intents::Column::SettlementId.eq(settlement_id))
+            .order_by_asc(payment_intents::Column::SucceededAt)
+            .all(self.db_pools.reader())
+            .await
+    }
+
+    #[instrument(name = "SettlementRepository::find_qris_payments", skip_all)]
+    async fn find_qris_payments(
+        &self,
+        settlement_id: i32,
+    ) -> Result<Vec<qris_payments::Model>, DbErr> {
+        qris_payments::Entity::find()
+            .filter(qris_payments::Column::SettlementId.eq(settlement_id))
+            .order_by_asc(qris_payments::Column::PaidAt)
+            .all(self.db_pools.reader())
+            .await
+    }
+
+    #[instrument(name = "SettlementRepository::find_refunds", skip_all)]
+    async fn find_refunds(&self, settlement_id: i32) -> Result<Vec<refunds::Model>, DbErr> {
+        refunds::Entity::find()
+            .filter(refunds::Column::SettlementId.eq(settlement_id))
+            .order_by_asc(refunds::Column::RefundedAt)
+            .all(self.db_pools.reader())
+            .await
+    }
+
+    #[instrument(name = "SettlementRepository::update_fee", skip_all)]
+    async fn update_fee(&self, id: i32, fee_amount: i32) -> Result<settlements::Model, DbErr> {
+        let settlement = settlements::Entity::find_by_id(id)
+            .one(self.db_pools.primary())
+            .await?
+            .ok_or(DbErr::RecordNotFound("Settlement not found".to_owned()))?;
+
+        let net_amount = settlement.gross_amount - settlement.refunded_amount - fee_amount;
+
+        let mut settlement: settlements::ActiveModel = settlement.into();
+        settlement.fee_amount = Set(fee_amount);
+        settlement.net_amount = Set(net_amount);
+        settlement.updated_at = Set(Some(Utc::now().naive_utc()));
+
+        settlement.update(self.db_pools.primary()).await
+    }
+
+    #[instrument(name = "SettlementRepository::update_payout", skip_all)]
+    async fn update_payout(
+        &self,
+        id: i32,
+        status: &str,
+        withdraw_id: Option<i32>,
+        failure_reason: Option<String>,
+    ) -> Result<settlements::Model, DbErr> {
+        let mut settlement: settlements::ActiveModel = settlements::Entity::find_by_id(id)
+            .one(self.db_pools.primary())
+            .await?
+            .ok_or(DbErr::RecordNotFound("Settlement not found".to_owned()))?
+            .into();
+
+        settlement.status = Set(status.to_string());
+        settlement.withdraw_id = Set(withdraw_id);
+        settlement.failure_reason = Set(failure_reason);
+        settlement.updated_at = Set(Some(Utc::now().naive_utc()));
+
+        settlement.update(self.db_pools.primary()).await
+    }
+
+    #[instrument(name = "SettlementRepository::claim_retry", skip_all)]
+    async fn claim_retry(&self, id: i32, from_status: &str) -> Result<bool, DbErr> {
+        let result = settlements::Entity::update_many()
+            .col_expr(settlements::Column::Status, Expr::value("pending"))
+            .col_expr(
+                settlements::Column::UpdatedAt,
+                Expr::value(Some(Utc::now().naive_utc())),
+            )
+            .filter(settlements::Column::SettlementId.eq(id))
+            .filter(settlements::Column::Status.eq(from_status))
+            .exec(self.db_pools.primary())
+            .await?;
+
+        Ok(result.rows_affected == 1)
+    }
+
+    #[instrument(name = "SettlementRepository::hold_for_review", skip_all)]
+    async fn hold_for_review(
+        &self,
+        id: i32,
+        fraud_review_id: i32,
+    ) -> Result<settlements::Model, DbErr> {
+        let mut settlement: settlements::ActiveModel = settlements::Entity::find_by_id(id)
+            .one(self.db_pools.primary())
+            .await?
+            .ok_or(DbErr::RecordNotFound("Settlement not found".to_owned()))?
+            .into();
+
+        settlement.status = Set("in_review".to_string());
+        settlement.withdraw_id = Set(None);
+        settlement.failure_reason = Set(Some("Payout held for fraud review".to_string()));
+        settlement.fraud_review_id = Set(Some(fraud_review_id));
+        settlement.updated_at = Set(Some(Utc::now().naive_utc()));
+
+        settlement.update(self.db_pools.primary()).await
+    }
+}
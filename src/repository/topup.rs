@@ -1,67 +1,143 @@
 use async_trait::async_trait;
 use chrono::Utc;
 use sea_orm::{
-    ActiveModelTrait, ColumnTrait, DatabaseConnection, DbErr, EntityTrait, QueryFilter, Set
+    sea_query::Expr, ActiveModelTrait, ColumnTrait, DbErr, EntityTrait,
+    QueryFilter, Set,
 };
 
-use crate::{abstract_trait::topup::TopupRepositoryTrait, domain::request::topup::{CreateTopupRequest, UpdateTopupAmount, UpdateTopupRequest}, entities::topups};
+use crate::config::database::DatabasePools;
+use crate::{abstract_trait::{fee::FeeQuote, topup::TopupRepositoryTrait}, domain::request::topup::{CreateTopupRequest, UpdateTopupAmount, UpdateTopupRequest}, entities::topups};
+use tracing::instrument;
 
 
 
 
 pub struct TopupRepository {
-    db_pool: DatabaseConnection,
+    db_pools: DatabasePools,
 }
 
 impl TopupRepository {
-    pub fn new(db_pool: DatabaseConnection) -> Self {
-        Self { db_pool }
+    pub fn new(db_pools: DatabasePools) -> Self {
+        Self { db_pools }
     }
 }
 
 #[async_trait]
 impl TopupRepositoryTrait for TopupRepository {
+    #[instrument(name = "TopupRepository::find_all", skip_all)]
     async fn find_all(&self) -> Result<Vec<topups::Model>, DbErr> {
-        topups::Entity::find().all(&self.db_pool).await
+        topups::Entity::find().all(self.db_pools.reader()).await
     }
 
+    #[instrument(name = "TopupRepository::find_by_id", skip_all)]
     async fn find_by_id(&self, id: i32) -> Result<Option<topups::Model>, DbErr> {
-        topups::Entity::find_by_id(id).one(&self.db_pool).await
+        topups::Entity::find_by_id(id).one(self.db_pools.reader()).await
     }
 
+    #[instrument(name = "TopupRepository::find_by_users", skip_all)]
     async fn find_by_users(&self, id: i32) -> Result<Vec<Option<topups::Model>>, DbErr>  {
         topups::Entity::find()
         .filter(topups::Column::UserId.eq(id))
-        .all(&self.db_pool)
+        .all(self.db_pools.reader())
         .await
         .map(|res| res.into_iter().map(Some).collect()) 
     }
 
     
+    #[instrument(name = "TopupRepository::find_by_user", skip_all)]
     async fn find_by_user(&self, id: i32) -> Result<Option<topups::Model>, DbErr> {
         topups::Entity::find()
             .filter(topups::Column::UserId.eq(id))
-            .one(&self.db_pool)
+            .one(self.db_pools.primary())
             .await
     }
 
     
-    async fn create(&self, input: &CreateTopupRequest) -> Result<topups::Model, DbErr> {
+    #[instrument(name = "TopupRepository::find_by_payment_reference", skip_all)]
+    async fn find_by_payment_reference(
+        &self,
+        payment_reference: &str,
+    ) -> Result<Option<topups::Model>, DbErr> {
+        topups::Entity::find()
+            .filter(topups::Column::PaymentReference.eq(payment_reference))
+            .one(self.db_pools.primary())
+            .await
+    }
+
+    #[instrument(name = "TopupRepository::create", skip_all)]
+    async fn create(
+        &self,
+        input: &CreateTopupRequest,
+        payment_reference: &str,
+        fee: FeeQuote,
+    ) -> Result<topups::Model, DbErr> {
         let new_topup = topups::ActiveModel {
             user_id: Set(input.user_id),
             topup_no: Set(input.topup_no.clone()),
             topup_amount: Set(input.topup_amount),
             topup_method: Set(input.topup_method.clone()),
             topup_time: Set(Utc::now().naive_utc()),
+            status: Set("pending".to_string()),
+            payment_reference: Set(Some(payment_reference.to_string())),
+            fee_amount: Set(fee.fee_amount),
+            fee_rule_id: Set(fee.fee_rule_id),
             ..Default::default()
         };
-        new_topup.insert(&self.db_pool).await
+        new_topup.insert(self.db_pools.primary()).await
+    }
+
+    #[instrument(name = "TopupRepository::update_payment_instructions", skip_all)]
+    async fn update_payment_instructions(
+        &self,
+        topup_id: i32,
+        payment_instructions: &str,
+    ) -> Result<topups::Model, DbErr> {
+        let mut topup_record: topups::ActiveModel = topups::Entity::find_by_id(topup_id)
+            .one(self.db_pools.primary())
+            .await?
+            .ok_or(DbErr::RecordNotFound("Topup not found".to_owned()))?
+            .into();
+
+        topup_record.payment_instructions = Set(Some(payment_instructions.to_string()));
+
+        topup_record.update(self.db_pools.primary()).await
+    }
+
+    #[instrument(name = "TopupRepository::transition_status", skip_all)]
+    async fn transition_status(
+        &self,
+        topup_id: i32,
+        from_status: &str,
+        to_status: &str,
+    ) -> Result<bool, DbErr> {
+        let now = Utc::now().naive_utc();
+
+        let mut update = topups::Entity::update_many()
+            .col_expr(topups::Column::Status, Expr::value(to_status))
+            .col_expr(topups::Column::UpdatedAt, Expr::value(now));
+
+        // Reversing a topup, or restoring it, keeps the time it was paid
+        if to_status == "pending" || from_status == "pending" {
+            let paid_at = (to_status == "paid").then_some(now);
+            update = update.col_expr(topups::Column::PaidAt, Expr::value(paid_at));
+        }
+
+        // Guarding on the current status makes concurrent callbacks race
+        // safely: only one of them sees a row change.
+        let result = update
+            .filter(topups::Column::TopupId.eq(topup_id))
+            .filter(topups::Column::Status.eq(from_status))
+            .exec(self.db_pools.primary())
+            .await?;
+
+        Ok(result.rows_affected == 1)
     }
 
     
+    #[instrument(name = "TopupRepository::update", skip_all)]
     async fn update(&self, input: &UpdateTopupRequest) -> Result<topups::Model, DbErr> {
         let mut topup_record: topups::ActiveModel = topups::Entity::find_by_id(input.topup_id)
-            .one(&self.db_pool)
+            .one(self.db_pools.primary())
             .await?
             .ok_or(DbErr::RecordNotFound("Topup not found".to_owned()))?
             .into();
@@ -70,27 +146,29 @@ impl TopupRepositoryTrait for TopupRepository {
         topup_record.topup_method = Set(input.topup_method.clone());
         topup_record.topup_time = Set(Utc::now().naive_utc());
 
-        topup_record.update(&self.db_pool).await
+        topup_record.update(self.db_pools.primary()).await
     }
 
+    #[instrument(name = "TopupRepository::update_amount", skip_all)]
     async fn update_amount(&self, input: &UpdateTopupAmount) -> Result<topups::Model, DbErr>{
         let mut topup_record: topups::ActiveModel = topups::Entity::find_by_id(input.topup_id)
-            .one(&self.db_pool)
+            .one(self.db_pools.primary())
             .await?
             .ok_or(DbErr::RecordNotFound("Topup not found".to_owned()))?
             .into();
 
         topup_record.topup_amount = Set(input.topup_amount);
 
-        topup_record.update(&self.db_pool).await
+        topup_record.update(self.db_pools.primary()).await
     }
     
 
     // Delete a topup record by user ID
+    #[instrument(name = "TopupRepository::delete", skip_all)]
     async fn delete(&self, id: i32) -> Result<(), DbErr> {
         let result = topups::Entity::delete_many()
             .filter(topups::Column::UserId.eq(id))
-            .exec(&self.db_pool)
+            .exec(self.db_pools.primary())
             .await?;
         if result.rows_affected > 0 {
             Ok(())
@@ -1,37 +1,42 @@
 use async_trait::async_trait;
-use chrono::Utc;
-use sea_orm::{ActiveModelTrait, ColumnTrait, DatabaseConnection, DbErr, EntityTrait, QueryFilter, Set}; 
+use chrono::{NaiveDateTime, Utc};
+use sea_orm::{ActiveModelTrait, ColumnTrait, DbErr, EntityTrait, QueryFilter, QuerySelect, Set}; 
+use crate::config::database::DatabasePools;
 use crate::{
     abstract_trait::transfer::TransferRepositoryTrait, domain::request::transfer::{CreateTransferRequest, UpdateTransferAmountRequest, UpdateTransferRequest}, entities::{transfers, Transfer}
 };
+use tracing::instrument;
 
 pub struct TransferRepository {
-    db_pool: DatabaseConnection,
+    db_pools: DatabasePools,
 }
 
 impl TransferRepository {
-    pub fn new(db_pool: DatabaseConnection) -> Self {
-        Self { db_pool }
+    pub fn new(db_pools: DatabasePools) -> Self {
+        Self { db_pools }
     }
 }
 
 #[async_trait]
 impl TransferRepositoryTrait for TransferRepository {
+    #[instrument(name = "TransferRepository::find_all", skip_all)]
     async fn find_all(&self) -> Result<Vec<transfers::Model>, DbErr> {
-        Transfer::find().all(&self.db_pool).await
+        Transfer::find().all(self.db_pools.reader()).await
     }
 
    
+    #[instrument(name = "TransferRepository::find_by_id", skip_all)]
     async fn find_by_id(&self, id: i32) -> Result<Option<transfers::Model>, DbErr> {
-        Transfer::find_by_id(id).one(&self.db_pool).await
+        Transfer::find_by_id(id).one(self.db_pools.reader()).await
     }
 
    
+    #[instrument(name = "TransferRepository::find_by_users", skip_all)]
     async fn find_by_users(&self, id: i32) -> Result<Option<Vec<transfers::Model>>, DbErr> {
         let transfers = Transfer::find()
             .filter(transfers::Column::TransferFrom.eq(id)
                     .or(transfers::Column::TransferTo.eq(id)))
-            .all(&self.db_pool)
+            .all(self.db_pools.reader())
             .await?;
         
         if transfers.is_empty() {
@@ -41,26 +46,34 @@ impl TransferRepositoryTrait for TransferRepository {
         }
     }
 
+    #[instrument(name = "TransferRepository::find_by_user", skip_all)]
     async fn find_by_user(&self, id: i32) -> Result<Option<transfers::Model>, DbErr> {
         Transfer::find()
             .filter(transfers::Column::TransferFrom.eq(id)
                     .or(transfers::Column::TransferTo.eq(id)))
-            .one(&self.db_pool)
+            .one(self.db_pools.primary())
             .await
     }
 
-    async fn create(&self, input: &CreateTransferRequest) -> Result<transfers::Model, DbErr> {
+    #[instrument(name = "TransferRepository::create", skip_all)]
+    async fn create(
+        &self,
+        input: &CreateTransferRequest,
+        fee_amount: i32,
+    ) -> Result<transfers::Model, DbErr> {
         let new_transfer = transfers::ActiveModel {
             transfer_from: Set(input.transfer_from),
             transfer_to: Set(input.transfer_to),
             transfer_amount: Set(input.transfer_amount),
             transfer_time: Set(Utc::now().naive_utc()),
+            fee_amount: Set(fee_amount),
             ..Default::default()
         };
-        new_transfer.insert(&self.db_pool).await
+        new_transfer.insert(self.db_pools.primary()).await
     }
 
     
+    #[instrument(name = "TransferRepository::update", skip_all)]
     async fn update(&self, input: &UpdateTransferRequest) -> Result<transfers::Model, DbErr> {
         let transfer = transfers::ActiveModel {
             transfer_id: Set(input.transfer_id),
@@ -70,9 +83,10 @@ impl TransferRepositoryTrait for TransferRepository {
             transfer_time: Set(Utc::now().naive_utc()),
             ..Default::default()
         };
-        transfer.update(&self.db_pool).await
+        transfer.update(self.db_pools.primary()).await
     }
 
+    #[instrument(name = "TransferRepository::update_amount", skip_all)]
     async fn update_amount(&self, input: &UpdateTransferAmountRequest) -> Result<transfers::Model, DbErr>{
         let transfer = transfers::ActiveModel{
             transfer_id: Set(input.transfer_id),
@@ -80,11 +94,50 @@ impl TransferRepositoryTrait for TransferRepository {
             ..Default::default()
         };
 
-        transfer.update(&self.db_pool).await
+        transfer.update(self.db_pools.primary()).await
     }
 
   
+    #[instrument(name = "TransferRepository::delete", skip_all)]
     async fn delete(&self, id: i32) -> Result<(), DbErr> {
-        Transfer::delete_by_id(id).exec(&self.db_pool).await.map(|_| ())
+        Transfer::delete_by_id(id).exec(self.db_pools.primary()).await.map(|_| ())
     }
-}
\ No newline at end of file
+
+    #[instrument(name = "TransferRepository::find_first_transfer_time", skip_all)]
+    async fn find_first_transfer_time(
+        &self,
+        transfer_from: i32,
+        transfer_to: i32,
+    ) -> Result<Option<NaiveDateTime>, DbErr> {
+        let first: Option<Option<NaiveDateTime>> = Transfer::find()
+            .select_only()
+            .column_as(transfers::Column::TransferTime.min(), "first")
+            .filter(transfers::Column::TransferFrom.eq(transfer_from))
+            .filter(transfers::Column::TransferTo.eq(transfer_to))
+            .into_tuple()
+            .one(self.db_pools.primary())
+            .await?;
+
+        Ok(first.flatten())
+    }
+
+    #[instrument(name = "TransferRepository::sum_amount_since", skip_all)]
+    async fn sum_amount_since(
+        &self,
+        transfer_from: i32,
+        transfer_to: i32,
+        since: NaiveDateTime,
+    ) -> Result<i64, DbErr> {
+        let total: Option<Option<i64>> = Transfer::find()
+            .select_only()
+            .column_as(transfers::Column::TransferAmount.sum(), "total")
+            .filter(transfers::Column::TransferFrom.eq(transfer_from))
+            .filter(transfers::Column::TransferTo.eq(transfer_to))
+            .filter(transfers::Column::TransferTime.gte(since))
+            .into_tuple()
+            .one(self.db_pools.primary())
+            .await?;
+
+        Ok(total.flatten().unwrap_or(0))
+    }
+}
@@ -0,0 +1,209 @@
+use async_trait::async_trait;
+use chrono::Utc;
+use sea_orm::{
+    sea_query::Expr, ActiveModelTrait, ColumnTrait, DatabaseConnection, DbErr, EntityTrait,
+    QueryFilter, QueryOrder, QuerySelect, Set, TransactionTrait,
+};
+
+use crate::{
+    abstract_trait::transfer_batch::TransferBatchRepositoryTrait,
+    domain::request::transfer_batch::{
+        CreateTransferBatchRequest, UpdateTransferBatchItemResult, UpdateTransferBatchStatus,
+    },
+    entities::{transfer_batch_items, transfer_batches},
+};
+use tracing::instrument;
+
+pub struct TransferBatchRepository {
+    db_pool: DatabaseConnection,
+}
+
+impl TransferBatchRepository {
+    pub fn new(db_pool: DatabaseConnection) -> Self {
+        Self { db_pool }
+    }
+
+    /// Records an item outcome if the item is still in `from_status`.
+    async fn set_item_result(
+        &self,
+        input: &UpdateTransferBatchItemResult,
+        from_status: &str,
+    ) -> Result<bool, DbErr> {
+        let result = transfer_batch_items::Entity::update_many()
+            .col_expr(
+                transfer_batch_items::Column::Status,
+                Expr::value(input.status.clone()),
+            )
+            .col_expr(
+                transfer_batch_items::Column::TransferId,
+                Expr::value(input.transfer_id),
+            )
+            .col_expr(
+                transfer_batch_items::Column::ErrorMessage,
+                Expr::value(input.error_message.clone()),
+            )
+            .col_expr(
+                transfer_batch_items::Column::FraudReviewId,
+                Expr::value(input.fraud_review_id),
+            )
+            .col_expr(
+                transfer_batch_items::Column::UpdatedAt,
+                Expr::value(Utc::now().naive_utc()),
+            )
+            .filter(transfer_batch_items::Column::ItemId.eq(input.item_id))
+            .filter(transfer_batch_items::Column::Status.eq(from_status))
+            .exec(&self.db_pool)
+            .await?;
+
+        Ok(result.rows_affected == 1)
+    }
+}
+
+#[async_trait]
+impl TransferBatchRepositoryTrait for TransferBatchRepository {
+    #[instrument(name = "TransferBatchRepository::find_by_id", skip_all)]
+    async fn find_by_id(&self, id: i32) -> Result<Option<transfer_batches::Model>, DbErr> {
+        transfer_batches::Entity::find_by_id(id)
+            .one(&self.db_pool)
+            .await
+    }
+
+    #[instrument(name = "TransferBatchRepository::find_items", skip_all)]
+    async fn find_items(&self, batch_id: i32) -> Result<Vec<transfer_batch_items::Model>, DbErr> {
+        transfer_batch_items::Entity::find()
+            .filter(transfer_batch_items::Column::BatchId.eq(batch_id))
+            .order_by_asc(transfer_batch_items::Column::LineNo)
+            .all(&self.db_pool)
+            .await
+    }
+
+    #[instrument(name = "TransferBatchRepository::find_item_by_fraud_review", skip_all)]
+    async fn find_item_by_fraud_review(
+        &self,
+        fraud_review_id: i32,
+    ) -> Result<Option<transfer_batch_items::Model>, DbErr> {
+        transfer_batch_items::Entity::find()
+            .filter(transfer_batch_items::Column::FraudReviewId.eq(fraud_review_id))
+            .one(&self.db_pool)
+            .await
+    }
+
+    #[instrument(name = "TransferBatchRepository::find_by_status", skip_all)]
+    async fn find_by_status(
+        &self,
+        status: &str,
+        limit: u64,
+    ) -> Result<Vec<transfer_batches::Model>, DbErr> {
+        transfer_batches::Entity::find()
+            .filter(transfer_batches::Column::Status.eq(status))
+            .order_by_asc(transfer_batches::Column::BatchId)
+            .limit(limit)
+            .all(&self.db_pool)
+            .await
+    }
+
+    #[instrument(name = "TransferBatchRepository::create", skip_all)]
+    async fn create(
+        &self,
+        input: &CreateTransferBatchRequest,
+    ) -> Result<transfer_batches::Model, DbErr> {
+        let txn = self.db_pool.begin().await?;
+
+        let batch = transfer_batches::ActiveModel {
+            user_id: Set(input.transfer_from),
+            status: Set("pending".to_string()),
+            total_items: Set(input.items.len() as i32),
+            total_amount: Set(input.total_amount()),
+            succeeded_items: Set(0),
+            failed_items: Set(0),
+            ..Default::default()
+        }
+        .insert(&txn)
+        .await?;
+
+        let items =
+            input
+                .items
+                .iter()
+                .enumerate()
+                .map(|(index, item)| transfer_batch_items::ActiveModel {
+                    batch_id: Set(batch.batch_id),
+                    line_no: Set(index as i32 + 1),
+                    transfer_to: Set(item.transfer_to),
+                    transfer_amount: Set(item.transfer_amount),
+                    status: Set("pending".to_string()),
+                    ..Default::default()
+                });
+
+        transfer_batch_items::Entity::insert_many(items)
+            .exec(&txn)
+            .await?;
+
+        txn.commit().await?;
+
+        Ok(batch)
+    }
+
+    #[instrument(name = "TransferBatchRepository::update_status", skip_all)]
+    async fn update_status(
+        &self,
+        input: &UpdateTransferBatchStatus,
+    ) -> Result<transfer_batches::Model, DbErr> {
+        let mut batch: transfer_batches::ActiveModel =
+            transfer_batches::Entity::find_by_id(input.batch_id)
+                .one(&self.db_pool)
+                .await?
+                .ok_or(DbErr::RecordNotFound("Transfer batch not found".to_owned()))?
+                .into();
+
+        let now = Utc::now().naive_utc();
+
+        batch.status = Set(input.status.clone());
+        batch.succeeded_items = Set(input.succeeded_items);
+        batch.failed_items = Set(input.failed_items);
+        batch.updated_at = Set(Some(now));
+
+        if input.status != "pending" && input.status != "processing" {
+            batch.completed_at = Set(Some(now));
+        }
+
+        batch.update(&self.db_pool).await
+    }
+
+    #[instrument(name = "TransferBatchRepository::claim_item", skip_all)]
+    async fn claim_item(&self, item_id: i32) -> Result<bool, DbErr> {
+        // Guarding on `pending` means an item is handed to at most one run,
+        // so a resumed batch never transfers it a second time.
+        let result = transfer_batch_items::Entity::update_many()
+            .col_expr(
+                transfer_batch_items::Column::Status,
+                Expr::value("processing"),
+            )
+            .col_expr(
+                transfer_batch_items::Column::UpdatedAt,
+                Expr::value(Utc::now().naive_utc()),
+            )
+            .filter(transfer_batch_items::Column::ItemId.eq(item_id))
+            .filter(transfer_batch_items::Column::Status.eq("pending"))
+            .exec(&self.db_pool)
+            .await?;
+
+        Ok(result.rows_affected == 1)
+    }
+
+    #[instrument(name = "TransferBatchRepository::update_item_result", skip_all)]
+    async fn update_item_result(
+        &self,
+        input: &UpdateTransferBatchItemResult,
+    ) -> Result<bool, DbErr> {
+        self.set_item_result(input, "processing").await
+    }
+
+    #[instrument(name = "TransferBatchRepository::resolve_held_item", skip_all)]
+    async fn resolve_held_item(
+        &self,
+        input: &UpdateTransferBatchItemResult,
+    ) -> Result<bool, DbErr> {
+        self.set_item_result(input, "held").await
+    }
+}
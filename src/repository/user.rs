@@ -1,49 +1,68 @@
 use async_trait::async_trait;
+use chrono::Utc;
 use sea_orm::{prelude::*, Set};
-use sea_orm::{DatabaseConnection, DbErr};
+use sea_orm::DbErr;
 
+use crate::config::database::DatabasePools;
 use crate::abstract_trait::user::UserRepositoryTrait;
 use crate::domain::request::user::{CreateUserRequest, UpdateUserRequest};
 use crate::entities::{users, Users};
+use tracing::instrument;
 
 
 pub struct UserRepository {
-    db_pool: DatabaseConnection,
+    db_pools: DatabasePools,
 }
 
 impl UserRepository {
-    pub fn new(db_pool: DatabaseConnection) -> Self {
-        Self { db_pool }
+    pub fn new(db_pools: DatabasePools) -> Self {
+        Self { db_pools }
     }
 }
 
 #[async_trait]
 impl UserRepositoryTrait for UserRepository {
+    #[instrument(name = "UserRepository::find_by_email", skip_all)]
     async fn find_by_email(&self, email: &str) -> Result<Option<users::Model>, DbErr> {
         users::Entity::find()
             .filter(users::Column::Email.eq(email))
-            .one(&self.db_pool)
+            .one(self.db_pools.primary())
             .await
     }
 
 
+    #[instrument(name = "UserRepository::find_by_email_exists", skip_all)]
     async fn find_by_email_exists(&self, email: &str) -> Result<bool, DbErr> {
         let user_count = users::Entity::find()
             .filter(users::Column::Email.eq(email))
-            .count(&self.db_pool)
+            .count(self.db_pools.primary())
             .await?;
         Ok(user_count > 0)
     }
 
 
+    #[instrument(name = "UserRepository::find_all", skip_all)]
     async fn find_all(&self) -> Result<Vec<users::Model>, DbErr> {
-        Users::find().all(&self.db_pool).await
+        Users::find().all(self.db_pools.reader()).await
     }
 
+    #[instrument(name = "UserRepository::find_by_id", skip_all)]
     async fn find_by_id(&self, id: i32) -> Result<Option<users::Model>, DbErr> {
-        Users::find_by_id(id).one(&self.db_pool).await
+        Users::find_by_id(id).one(self.db_pools.reader()).await
     }
 
+    #[instrument(name = "UserRepository::find_by_noc_transfer", skip_all)]
+    async fn find_by_noc_transfer(
+        &self,
+        noc_transfer: &str,
+    ) -> Result<Option<users::Model>, DbErr> {
+        users::Entity::find()
+            .filter(users::Column::NocTransfer.eq(noc_transfer))
+            .one(self.db_pools.primary())
+            .await
+    }
+
+    #[instrument(name = "UserRepository::create_user", skip_all)]
     async fn create_user(&self, input: &CreateUserRequest) -> Result<users::Model, DbErr> {
         let user = users::ActiveModel {
             firstname: Set(input.firstname.clone()),
@@ -51,16 +70,15 @@ impl UserRepositoryTrait for UserRepository {
             email: Set(input.email.clone()),
             password: Set(input.password.clone()),
             noc_transfer: Set(input
-                .noc_transfer
-                .as_ref()
-                .map(|s| s.clone())
+                .noc_transfer.clone()
                 .unwrap_or_default()),
             ..Default::default()
         };
 
-        user.insert(&self.db_pool).await
+        user.insert(self.db_pools.primary()).await
     }
 
+    #[instrument(name = "UserRepository::update_user", skip_all)]
     async fn update_user(&self, input: &UpdateUserRequest) -> Result<users::Model, DbErr> {
         let id = match input.id {
             Some(id) => id,
@@ -68,7 +86,7 @@ impl UserRepositoryTrait for UserRepository {
         };
 
         let mut user: users::ActiveModel = users::Entity::find_by_id(id)
-            .one(&self.db_pool)
+            .one(self.db_pools.primary())
             .await?
             .ok_or(DbErr::Custom("User not found".to_string()))?
             .into();
@@ -86,17 +104,32 @@ impl UserRepositoryTrait for UserRepository {
         }
 
        
-        user.update(&self.db_pool).await
+        user.update(self.db_pools.primary()).await
+    }
+
+    #[instrument(name = "UserRepository::update_kyc_level", skip_all)]
+    async fn update_kyc_level(&self, id: i32, kyc_level: i32) -> Result<users::Model, DbErr> {
+        let mut user: users::ActiveModel = users::Entity::find_by_id(id)
+            .one(self.db_pools.primary())
+            .await?
+            .ok_or(DbErr::Custom("User not found".to_string()))?
+            .into();
+
+        user.kyc_level = Set(kyc_level);
+        user.updated_at = Set(Some(Utc::now().naive_utc()));
+
+        user.update(self.db_pools.primary()).await
     }
 
+    #[instrument(name = "UserRepository::delete_user", skip_all)]
     async fn delete_user(&self, id: i32) -> Result<(), DbErr> {
         let user: users::ActiveModel = users::Entity::find()
             .filter(users::Column::UserId.eq(id))
-            .one(&self.db_pool)
+            .one(self.db_pools.primary())
             .await?
             .ok_or(DbErr::Custom("User not found".to_string()))?
             .into();
 
-        user.delete(&self.db_pool).await.map(|_| ())
+        user.delete(self.db_pools.primary()).await.map(|_| ())
     }
 }
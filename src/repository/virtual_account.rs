@@ -0,0 +1,137 @@
+use async_trait::async_trait;
+use chrono::Utc;
+use sea_orm::{
+    sea_query::Expr, ActiveModelTrait, ColumnTrait, DatabaseConnection, DbErr, EntityTrait,
+    QueryFilter, QueryOrder, Set,
+};
+
+use crate::{
+    abstract_trait::virtual_account::VirtualAccountRepositoryTrait,
+    domain::request::virtual_account::{CreateVirtualAccountRequest, VirtualAccountPaymentRequest},
+    entities::{suspense_payments, virtual_accounts},
+};
+use tracing::instrument;
+
+pub struct VirtualAccountRepository {
+    db_pool: DatabaseConnection,
+}
+
+impl VirtualAccountRepository {
+    pub fn new(db_pool: DatabaseConnection) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl VirtualAccountRepositoryTrait for VirtualAccountRepository {
+    #[instrument(name = "VirtualAccountRepository::find_by_user", skip_all)]
+    async fn find_by_user(&self, user_id: i32) -> Result<Vec<virtual_accounts::Model>, DbErr> {
+        virtual_accounts::Entity::find()
+            .filter(virtual_accounts::Column::UserId.eq(user_id))
+            .order_by_asc(virtual_accounts::Column::VirtualAccountId)
+            .all(&self.db_pool)
+            .await
+    }
+
+    #[instrument(name = "VirtualAccountRepository::find_by_number", skip_all)]
+    async fn find_by_number(
+        &self,
+        bank_code: &str,
+        va_number: &str,
+    ) -> Result<Option<virtual_accounts::Model>, DbErr> {
+        virtual_accounts::Entity::find()
+            .filter(virtual_accounts::Column::BankCode.eq(bank_code))
+            .filter(virtual_accounts::Column::VaNumber.eq(va_number))
+            .one(&self.db_pool)
+            .await
+    }
+
+    #[instrument(name = "VirtualAccountRepository::create", skip_all)]
+    async fn create(
+        &self,
+        input: &CreateVirtualAccountRequest,
+        va_number: &str,
+    ) -> Result<virtual_accounts::Model, DbErr> {
+        let virtual_account = virtual_accounts::ActiveModel {
+            user_id: Set(input.user_id),
+            bank_code: Set(input.bank_code.clone()),
+            va_number: Set(va_number.to_string()),
+            ..Default::default()
+        };
+
+        virtual_account.insert(&self.db_pool).await
+    }
+
+    #[instrument(name = "VirtualAccountRepository::find_suspense_by_id", skip_all)]
+    async fn find_suspense_by_id(
+        &self,
+        id: i32,
+    ) -> Result<Option<suspense_payments::Model>, DbErr> {
+        suspense_payments::Entity::find_by_id(id)
+            .one(&self.db_pool)
+            .await
+    }
+
+    #[instrument(name = "VirtualAccountRepository::find_suspense_by_payment_id", skip_all)]
+    async fn find_suspense_by_payment_id(
+        &self,
+        payment_id: &str,
+    ) -> Result<Option<suspense_payments::Model>, DbErr> {
+        suspense_payments::Entity::find()
+            .filter(suspense_payments::Column::PaymentId.eq(payment_id))
+            .one(&self.db_pool)
+            .await
+    }
+
+    #[instrument(name = "VirtualAccountRepository::find_suspense_by_status", skip_all)]
+    async fn find_suspense_by_status(
+        &self,
+        status: &str,
+    ) -> Result<Vec<suspense_payments::Model>, DbErr> {
+        suspense_payments::Entity::find()
+            .filter(suspense_payments::Column::Status.eq(status))
+            .order_by_asc(suspense_payments::Column::SuspensePaymentId)
+            .all(&self.db_pool)
+            .await
+    }
+
+    #[instrument(name = "VirtualAccountRepository::create_suspense", skip_all)]
+    async fn create_suspense(
+        &self,
+        input: &VirtualAccountPaymentRequest,
+    ) -> Result<suspense_payments::Model, DbErr> {
+        let suspense_payment = suspense_payments::ActiveModel {
+            payment_id: Set(input.payment_id.clone()),
+            bank_code: Set(input.bank_code.clone()),
+            va_number: Set(input.va_number.clone()),
+            amount: Set(input.amount),
+            status: Set("unresolved".to_string()),
+            ..Default::default()
+        };
+
+        suspense_payment.insert(&self.db_pool).await
+    }
+
+    #[instrument(name = "VirtualAccountRepository::resolve_suspense", skip_all)]
+    async fn resolve_suspense(
+        &self,
+        id: i32,
+        user_id: i32,
+        topup_id: i32,
+    ) -> Result<bool, DbErr> {
+        let result = suspense_payments::Entity::update_many()
+            .col_expr(suspense_payments::Column::Status, Expr::value("resolved"))
+            .col_expr(suspense_payments::Column::ResolvedUserId, Expr::value(user_id))
+            .col_expr(suspense_payments::Column::TopupId, Expr::value(topup_id))
+            .col_expr(
+                suspense_payments::Column::UpdatedAt,
+                Expr::value(Utc::now().naive_utc()),
+            )
+            .filter(suspense_payments::Column::SuspensePaymentId.eq(id))
+            .filter(suspense_payments::Column::Status.eq("unresolved"))
+            .exec(&self.db_pool)
+            .await?;
+
+        Ok(result.rows_affected == 1)
+    }
+}
@@ -0,0 +1,235 @@
+use async_trait::async_trait;
+use chrono::{NaiveDateTime, Utc};
+use sea_orm::{
+    sea_query::Expr, ActiveModelTrait, ColumnTrait, ConnectionTrait, DatabaseConnection, DbErr,
+    EntityTrait, QueryFilter, QueryOrder, QuerySelect, Set,
+};
+
+use crate::{
+    abstract_trait::webhook::{DeliveryAttempt, WebhookRepositoryTrait},
+    domain::request::webhook::CreateWebhookEndpointRequest,
+    entities::{webhook_deliveries, webhook_endpoints, webhook_events},
+};
+use tracing::instrument;
+
+/// Writes an event to the webhook outbox with one pending delivery per
+/// active endpoint of the merchant. Call it with the transaction that makes
+/// the business change, so the event exists if and only if the change does.
+pub async fn enqueue_webhook_event<C: ConnectionTrait>(
+    db: &C,
+    merchant_id: i32,
+    event_type: &str,
+    payload: &serde_json::Value,
+) -> Result<(), DbErr> {
+    let endpoints = webhook_endpoints::Entity::find()
+        .filter(webhook_endpoints::Column::MerchantId.eq(merchant_id))
+        .filter(webhook_endpoints::Column::IsActive.eq(true))
+        .all(db)
+        .await?;
+
+    let event = webhook_events::ActiveModel {
+        merchant_id: Set(merchant_id),
+        event_type: Set(event_type.to_string()),
+        payload: Set(payload.to_string()),
+        ..Default::default()
+    }
+    .insert(db)
+    .await?;
+
+    for endpoint in endpoints {
+        webhook_deliveries::ActiveModel {
+            webhook_event_id: Set(event.webhook_event_id),
+            webhook_endpoint_id: Set(endpoint.webhook_endpoint_id),
+            merchant_id: Set(merchant_id),
+            status: Set("pending".to_string()),
+            attempts: Set(0),
+            next_attempt_at: Set(Utc::now().naive_utc()),
+            ..Default::default()
+        }
+        .insert(db)
+        .await?;
+    }
+
+    Ok(())
+}
+
+pub struct WebhookRepository {
+    db_pool: DatabaseConnection,
+}
+
+impl WebhookRepository {
+    pub fn new(db_pool: DatabaseConnection) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl WebhookRepositoryTrait for WebhookRepository {
+    #[instrument(name = "WebhookRepository::find_endpoints_by_merchant", skip_all)]
+    async fn find_endpoints_by_merchant(
+        &self,
+        merchant_id: i32,
+    ) -> Result<Vec<webhook_endpoints::Model>, DbErr> {
+        webhook_endpoints::Entity::find()
+            .filter(webhook_endpoints::Column::MerchantId.eq(merchant_id))
+            .order_by_asc(webhook_endpoints::Column::WebhookEndpointId)
+            .all(&self.db_pool)
+            .await
+    }
+
+    #[instrument(name = "WebhookRepository::find_endpoint_by_id", skip_all)]
+    async fn find_endpoint_by_id(
+        &self,
+        id: i32,
+    ) -> Result<Option<webhook_endpoints::Model>, DbErr> {
+        webhook_endpoints::Entity::find_by_id(id)
+            .one(&self.db_pool)
+            .await
+    }
+
+    #[instrument(name = "WebhookRepository::create_endpoint", skip_all)]
+    async fn create_endpoint(
+        &self,
+        input: &CreateWebhookEndpointRequest,
+        merchant_id: i32,
+        secret: &str,
+    ) -> Result<webhook_endpoints::Model, DbErr> {
+        let endpoint = webhook_endpoints::ActiveModel {
+            merchant_id: Set(merchant_id),
+            url: Set(input.url.clone()),
+            secret: Set(secret.to_string()),
+            is_active: Set(true),
+            ..Default::default()
+        };
+
+        endpoint.insert(&self.db_pool).await
+    }
+
+    #[instrument(name = "WebhookRepository::deactivate_endpoint", skip_all)]
+    async fn deactivate_endpoint(&self, id: i32) -> Result<webhook_endpoints::Model, DbErr> {
+        let mut endpoint: webhook_endpoints::ActiveModel =
+            webhook_endpoints::Entity::find_by_id(id)
+                .one(&self.db_pool)
+                .await?
+                .ok_or(DbErr::RecordNotFound(
+                    "Webhook endpoint not found".to_owned(),
+                ))?
+                .into();
+
+        endpoint.is_active = Set(false);
+        endpoint.updated_at = Set(Some(Utc::now().naive_utc()));
+
+        endpoint.update(&self.db_pool).await
+    }
+
+    #[instrument(name = "WebhookRepository::find_event_by_id", skip_all)]
+    async fn find_event_by_id(&self, id: i32) -> Result<Option<webhook_events::Model>, DbErr> {
+        webhook_events::Entity::find_by_id(id)
+            .one(&self.db_pool)
+            .await
+    }
+
+    #[instrument(name = "WebhookRepository::find_deliveries_by_merchant", skip_all)]
+    async fn find_deliveries_by_merchant(
+        &self,
+        merchant_id: i32,
+    ) -> Result<Vec<webhook_deliveries::Model>, DbErr> {
+        webhook_deliveries::Entity::find()
+            .filter(webhook_deliveries::Column::MerchantId.eq(merchant_id))
+            .order_by_desc(webhook_deliveries::Column::WebhookDeliveryId)
+            .all(&self.db_pool)
+            .await
+    }
+
+    #[instrument(name = "WebhookRepository::find_delivery_by_id", skip_all)]
+    async fn find_delivery_by_id(
+        &self,
+        id: i32,
+    ) -> Result<Option<webhook_deliveries::Model>, DbErr> {
+        webhook_deliveries::Entity::find_by_id(id)
+            .one(&self.db_pool)
+            .await
+    }
+
+    #[instrument(name = "WebhookRepository::find_due_deliveries", skip_all)]
+    async fn find_due_deliveries(
+        &self,
+        now: NaiveDateTime,
+        limit: u64,
+    ) -> Result<Vec<webhook_deliveries::Model>, DbErr> {
+        webhook_deliveries::Entity::find()
+            .filter(webhook_deliveries::Column::Status.eq("pending"))
+            .filter(webhook_deliveries::Column::NextAttemptAt.lte(now))
+            .order_by_asc(webhook_deliveries::Column::NextAttemptAt)
+            .limit(limit)
+            .all(&self.db_pool)
+            .await
+    }
+
+    #[instrument(name = "WebhookRepository::create_delivery", skip_all)]
+    async fn create_delivery(
+        &self,
+        webhook_event_id: i32,
+        webhook_endpoint_id: i32,
+        merchant_id: i32,
+    ) -> Result<webhook_deliveries::Model, DbErr> {
+        let delivery = webhook_deliveries::ActiveModel {
+            webhook_event_id: Set(webhook_event_id),
+            webhook_endpoint_id: Set(webhook_endpoint_id),
+            merchant_id: Set(merchant_id),
+            status: Set("pending".to_string()),
+            attempts: Set(0),
+            next_attempt_at: Set(Utc::now().naive_utc()),
+            ..Default::default()
+        };
+
+        delivery.insert(&self.db_pool).await
+    }
+
+    #[instrument(name = "WebhookRepository::record_attempt", skip_all)]
+    async fn record_attempt(
+        &self,
+        id: i32,
+        attempt: &DeliveryAttempt,
+    ) -> Result<webhook_deliveries::Model, DbErr> {
+        let now = Utc::now().naive_utc();
+        let delivered_at = (attempt.status == "succeeded").then_some(now);
+
+        webhook_deliveries::Entity::update_many()
+            .col_expr(
+                webhook_deliveries::Column::Attempts,
+                Expr::col(webhook_deliveries::Column::Attempts).add(1),
+            )
+            .col_expr(
+                webhook_deliveries::Column::Status,
+                Expr::value(attempt.status.clone()),
+            )
+            .col_expr(
+                webhook_deliveries::Column::NextAttemptAt,
+                Expr::value(attempt.next_attempt_at),
+            )
+            .col_expr(
+                webhook_deliveries::Column::LastResponseStatus,
+                Expr::value(attempt.response_status),
+            )
+            .col_expr(
+                webhook_deliveries::Column::LastError,
+                Expr::value(attempt.error.clone()),
+            )
+            .col_expr(
+                webhook_deliveries::Column::DeliveredAt,
+                Expr::value(delivered_at),
+            )
+            .col_expr(webhook_deliveries::Column::UpdatedAt, Expr::value(now))
+            .filter(webhook_deliveries::Column::WebhookDeliveryId.eq(id))
+            .exec(&self.db_pool)
+            .await?;
+
+        webhook_deliveries::Entity::find_by_id(id)
+            .one(&self.db_pool)
+            .await?
+            .ok_or(DbErr::RecordNotFound(
+                "Webhook delivery not found".to_owned(),
+            ))
+    }
+}
@@ -1,66 +1,115 @@
 use async_trait::async_trait;
+use chrono::Utc;
 use sea_orm::{
-    ActiveModelTrait, ColumnTrait, DatabaseConnection, DbErr, EntityTrait, QueryFilter, Set,
+    sea_query::Expr, ActiveModelTrait, ColumnTrait, DbErr, EntityTrait, QueryFilter, QueryOrder,
+    QuerySelect, Set, TransactionTrait,
 };
 
+use crate::config::database::DatabasePools;
 use crate::{
-    abstract_trait::withdraw::WithdrawRepositoryTrait,
-    domain::request::withdraw::{CreateWithdrawRequest, UpdateWithdrawRequest},
-    entities::withdraws,
+    abstract_trait::{fee::FeeQuote, withdraw::WithdrawRepositoryTrait},
+    domain::{
+        request::withdraw::{CreateWithdrawRequest, UpdateWithdrawRequest, UpdateWithdrawStatus},
+        response::withdraw::WithdrawResponse,
+    },
+    entities::{merchants, withdraws},
+    repository::webhook::enqueue_webhook_event,
 };
+use tracing::instrument;
 
 pub struct WithdrawRepository {
-    db_pool: DatabaseConnection,
+    db_pools: DatabasePools,
 }
 
 impl WithdrawRepository {
-    pub fn new(db_pool: DatabaseConnection) -> Self {
-        Self { db_pool }
+    pub fn new(db_pools: DatabasePools) -> Self {
+        Self { db_pools }
     }
 }
 
 #[async_trait]
 impl WithdrawRepositoryTrait for WithdrawRepository {
+    #[instrument(name = "WithdrawRepository::find_all", skip_all)]
     async fn find_all(&self) -> Result<Vec<withdraws::Model>, DbErr> {
-        withdraws::Entity::find().all(&self.db_pool).await
+        withdraws::Entity::find().all(self.db_pools.reader()).await
     }
 
+    #[instrument(name = "WithdrawRepository::find_by_id", skip_all)]
     async fn find_by_id(&self, id: i32) -> Result<Option<withdraws::Model>, DbErr> {
-        withdraws::Entity::find_by_id(id).one(&self.db_pool).await
+        withdraws::Entity::find_by_id(id).one(self.db_pools.reader()).await
     }
 
+    #[instrument(name = "WithdrawRepository::find_by_users", skip_all)]
     async fn find_by_users(&self, id: i32) -> Result<Option<Vec<withdraws::Model>>, DbErr> {
         let results = withdraws::Entity::find()
             .filter(withdraws::Column::UserId.eq(id))
-            .all(&self.db_pool)
+            .all(self.db_pools.reader())
             .await?;
         Ok(Some(results))
     }
 
+    #[instrument(name = "WithdrawRepository::find_by_user", skip_all)]
     async fn find_by_user(&self, id: i32) -> Result<Option<withdraws::Model>, DbErr> {
         withdraws::Entity::find()
             .filter(withdraws::Column::UserId.eq(id))
-            .one(&self.db_pool)
+            .one(self.db_pools.primary())
             .await
     }
 
-    async fn create(&self, input: &CreateWithdrawRequest) -> Result<withdraws::Model, DbErr> {
+    #[instrument(name = "WithdrawRepository::find_by_status", skip_all)]
+    async fn find_by_status(
+        &self,
+        status: &str,
+        limit: u64,
+    ) -> Result<Vec<withdraws::Model>, DbErr> {
+        withdraws::Entity::find()
+            .filter(withdraws::Column::Status.eq(status))
+            .order_by_asc(withdraws::Column::WithdrawId)
+            .limit(limit)
+            .all(self.db_pools.primary())
+            .await
+    }
+
+    #[instrument(name = "WithdrawRepository::create", skip_all)]
+    async fn create(
+        &self,
+        input: &CreateWithdrawRequest,
+        fee: FeeQuote,
+    ) -> Result<withdraws::Model, DbErr> {
         let withdraw_time_naive = input.withdraw_time.naive_utc();
 
         let new_withdraw = withdraws::ActiveModel {
             user_id: Set(input.user_id),
             withdraw_amount: Set(input.withdraw_amount),
             withdraw_time: Set(withdraw_time_naive),
+            bank_account_id: Set(Some(input.bank_account_id)),
+            status: Set("pending".to_string()),
+            fee_amount: Set(fee.fee_amount),
+            fee_rule_id: Set(fee.fee_rule_id),
             ..Default::default()
         };
 
-        new_withdraw.insert(&self.db_pool).await
+        let txn = self.db_pools.primary().begin().await?;
+
+        let withdraw = new_withdraw.insert(&txn).await?;
+
+        // Stored before the payout is requested, so it can be looked up
+        // even when the request never returns.
+        let payout_reference = format!("WD-{}", withdraw.withdraw_id);
+        let mut withdraw: withdraws::ActiveModel = withdraw.into();
+        withdraw.payout_reference = Set(Some(payout_reference));
+        let withdraw = withdraw.update(&txn).await?;
+
+        txn.commit().await?;
+
+        Ok(withdraw)
     }
 
+    #[instrument(name = "WithdrawRepository::update", skip_all)]
     async fn update(&self, input: &UpdateWithdrawRequest) -> Result<withdraws::Model, DbErr> {
         let mut withdraw_record: withdraws::ActiveModel =
             withdraws::Entity::find_by_id(input.withdraw_id)
-                .one(&self.db_pool)
+                .one(self.db_pools.primary())
                 .await?
                 .ok_or(DbErr::RecordNotFound("Withdraw not found".to_owned()))?
                 .into();
@@ -71,13 +120,76 @@ impl WithdrawRepositoryTrait for WithdrawRepository {
 
         withdraw_record.withdraw_time = Set(withdraw_time_naive);
 
-        withdraw_record.update(&self.db_pool).await
+        withdraw_record.update(self.db_pools.primary()).await
+    }
+
+    #[instrument(name = "WithdrawRepository::update_status", skip_all)]
+    async fn update_status(
+        &self,
+        input: &UpdateWithdrawStatus,
+    ) -> Result<Option<withdraws::Model>, DbErr> {
+        let txn = self.db_pools.primary().begin().await?;
+
+        let mut update = withdraws::Entity::update_many()
+            .col_expr(withdraws::Column::Status, Expr::value(input.status.clone()))
+            .col_expr(
+                withdraws::Column::FailureReason,
+                Expr::value(input.failure_reason.clone()),
+            )
+            .col_expr(
+                withdraws::Column::UpdatedAt,
+                Expr::value(Some(Utc::now().naive_utc())),
+            );
+
+        if input.provider_reference.is_some() {
+            update = update.col_expr(
+                withdraws::Column::ProviderReference,
+                Expr::value(input.provider_reference.clone()),
+            );
+        }
+
+        // Only a pending withdraw still holds the user's funds, so guarding
+        // on it lets exactly one of two racing updates settle the hold.
+        let result = update
+            .filter(withdraws::Column::WithdrawId.eq(input.withdraw_id))
+            .filter(withdraws::Column::Status.eq("pending"))
+            .exec(&txn)
+            .await?;
+
+        if result.rows_affected != 1 {
+            return Ok(None);
+        }
+
+        let withdraw = withdraws::Entity::find_by_id(input.withdraw_id)
+            .one(&txn)
+            .await?
+            .ok_or(DbErr::RecordNotFound("Withdraw not found".to_owned()))?;
+
+        // Users who are merchants hear about payouts that bounced.
+        if withdraw.status == "failed" {
+            let merchant = merchants::Entity::find()
+                .filter(merchants::Column::UserId.eq(withdraw.user_id))
+                .one(&txn)
+                .await?;
+
+            if let Some(merchant) = merchant {
+                let payload = serde_json::to_value(WithdrawResponse::from(withdraw.clone()))
+                    .map_err(|e| DbErr::Custom(e.to_string()))?;
+                enqueue_webhook_event(&txn, merchant.merchant_id, "payout.failed", &payload)
+                    .await?;
+            }
+        }
+
+        txn.commit().await?;
+
+        Ok(Some(withdraw))
     }
 
+    #[instrument(name = "WithdrawRepository::delete", skip_all)]
     async fn delete(&self, id: i32) -> Result<(), DbErr> {
         let result = withdraws::Entity::delete_many()
             .filter(withdraws::Column::UserId.eq(id))
-            .exec(&self.db_pool)
+            .exec(self.db_pools.primary())
             .await?;
         if result.rows_affected > 0 {
             Ok(())
@@ -0,0 +1,245 @@
+use async_trait::async_trait;
+use chrono::{NaiveDateTime, Timelike};
+use rand::Rng;
+use sea_orm::SqlErr;
+use sha2::{Digest, Sha256};
+use std::time::Duration;
+use tracing::{error, info, instrument, warn};
+
+use crate::{
+    abstract_trait::{
+        audit::{AuditServiceTrait, DynAuditRepository},
+        clock::DynClock,
+    },
+    domain::{
+        request::audit::{CreateAuditEvent, FindAuditEventsQuery},
+        response::{
+            audit::{AuditChainVerificationResponse, AuditEventResponse},
+            ApiResponse, ErrorResponse,
+        },
+    },
+    entities::audit_events,
+    utils::errors::AppError,
+};
+
+/// `prev_hash` of the first event in the chain.
+pub const GENESIS_HASH: &str = "0000000000000000000000000000000000000000000000000000000000000000";
+
+/// Times an append is tried while other writers keep extending the chain
+/// first.
+const APPEND_ATTEMPTS: u32 = 10;
+
+/// Upper bound of the random pause before an append is retried, so writers
+/// that collided do not collide again.
+const APPEND_RETRY_JITTER_MS: u64 = 20;
+
+/// Events read per page while verifying the chain.
+const VERIFY_PAGE_SIZE: u64 = 500;
+
+/// Hash of an event over its link and contents. The id and the stored hash
+/// are not part of it.
+pub fn audit_event_hash(event: &audit_events::Model) -> String {
+    let content = serde_json::json!([
+        event.prev_hash,
+        event.actor_id,
+        event.action,
+        event.resource_type,
+        event.resource_id,
+        event.before,
+        event.after,
+        event.client_ip,
+        event.request_id,
+        event.created_at.format("%Y-%m-%dT%H:%M:%S%.6f").to_string(),
+    ]);
+
+    hex::encode(Sha256::digest(content.to_string().as_bytes()))
+}
+
+/// Appends are serialized by the database: `prev_hash` is unique, so of
+/// several writers linking to the same event, from this or any other
+/// instance, one wins and the others retry on the new last event.
+pub struct AuditService {
+    audit_repository: DynAuditRepository,
+    clock: DynClock,
+}
+
+impl AuditService {
+    pub fn new(audit_repository: DynAuditRepository, clock: DynClock) -> Self {
+        Self {
+            audit_repository,
+            clock,
+        }
+    }
+
+    /// The clock truncated to what a database timestamp keeps, so the hash
+    /// still matches once the row is read back.
+    fn now(&self) -> NaiveDateTime {
+        let now = self.clock.now();
+        now.with_nanosecond(now.nanosecond() / 1_000 * 1_000)
+            .unwrap_or(now)
+    }
+}
+
+#[async_trait]
+impl AuditServiceTrait for AuditService {
+    #[instrument(name = "AuditService::record", skip_all)]
+    async fn record(&self, input: &CreateAuditEvent) -> Result<audit_events::Model, ErrorResponse> {
+        let mut attempt = 1;
+
+        loop {
+            let prev_hash = self
+                .audit_repository
+                .find_last()
+                .await
+                .map_err(AppError::from)
+                .map_err(ErrorResponse::from)?
+                .map_or_else(|| GENESIS_HASH.to_string(), |last| last.hash);
+
+            let mut event = audit_events::Model {
+                audit_event_id: 0,
+                actor_id: input.actor_id,
+                action: input.action.clone(),
+                resource_type: input.resource_type.clone(),
+                resource_id: input.resource_id.clone(),
+                before: input.before.as_ref().map(|value| value.to_string()),
+                after: input.after.as_ref().map(|value| value.to_string()),
+                client_ip: input.client_ip.clone(),
+                request_id: input.request_id.clone(),
+                created_at: self.now(),
+                prev_hash,
+                hash: String::new(),
+            };
+            event.hash = audit_event_hash(&event);
+
+            match self
+                .audit_repository
+                .create(input, event.created_at, &event.prev_hash, &event.hash)
+                .await
+            {
+                Ok(event) => return Ok(event),
+                Err(e)
+                    if attempt < APPEND_ATTEMPTS
+                        && matches!(e.sql_err(), Some(SqlErr::UniqueConstraintViolation(_))) =>
+                {
+                    warn!(
+                        "Audit chain moved on while appending {}, retrying",
+                        input.action
+                    );
+                    attempt += 1;
+
+                    let jitter = rand::rng().random_range(0..=APPEND_RETRY_JITTER_MS);
+                    tokio::time::sleep(Duration::from_millis(jitter)).await;
+                }
+                Err(e) => {
+                    error!("Failed to append audit event {}: {}", input.action, e);
+                    return Err(ErrorResponse::from(AppError::from(e)));
+                }
+            }
+        }
+    }
+
+    #[instrument(name = "AuditService::snapshot", skip_all)]
+    async fn snapshot(
+        &self,
+        resource_type: &str,
+        resource_id: i32,
+    ) -> Result<Option<serde_json::Value>, ErrorResponse> {
+        self.audit_repository
+            .snapshot(resource_type, resource_id)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)
+    }
+
+    #[instrument(name = "AuditService::find_events", skip_all)]
+    async fn find_events(
+        &self,
+        query: &FindAuditEventsQuery,
+    ) -> Result<ApiResponse<Vec<AuditEventResponse>>, ErrorResponse> {
+        if let Err(validation_err) = query.validate() {
+            return Err(ErrorResponse::from(AppError::ValidationError(
+                validation_err,
+            )));
+        }
+
+        let events = self
+            .audit_repository
+            .find(query)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?;
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "Audit events retrieved successfully".to_string(),
+            data: events.into_iter().map(AuditEventResponse::from).collect(),
+        })
+    }
+
+    #[instrument(name = "AuditService::verify_chain", skip_all)]
+    async fn verify_chain(
+        &self,
+    ) -> Result<ApiResponse<AuditChainVerificationResponse>, ErrorResponse> {
+        let mut expected_prev_hash = GENESIS_HASH.to_string();
+        let mut last_id = 0;
+        let mut events_checked = 0;
+
+        loop {
+            let events = self
+                .audit_repository
+                .find_after(last_id, VERIFY_PAGE_SIZE)
+                .await
+                .map_err(AppError::from)
+                .map_err(ErrorResponse::from)?;
+
+            if events.is_empty() {
+                break;
+            }
+
+            for event in events {
+                let reason = if event.prev_hash != expected_prev_hash {
+                    Some("does not link to the previous event")
+                } else if audit_event_hash(&event) != event.hash {
+                    Some("hash does not match its contents")
+                } else {
+                    None
+                };
+
+                if let Some(reason) = reason {
+                    error!(
+                        "Audit chain broken at event {}: {}",
+                        event.audit_event_id, reason
+                    );
+
+                    return Ok(ApiResponse {
+                        status: "success".to_string(),
+                        message: format!("Audit chain broken at event {}", event.audit_event_id),
+                        data: AuditChainVerificationResponse {
+                            events_checked,
+                            valid: false,
+                            first_invalid_event_id: Some(event.audit_event_id),
+                            reason: Some(reason.to_string()),
+                        },
+                    });
+                }
+
+                events_checked += 1;
+                last_id = event.audit_event_id;
+                expected_prev_hash = event.hash;
+            }
+        }
+
+        info!("Audit chain verified over {} events", events_checked);
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "Audit chain is intact".to_string(),
+            data: AuditChainVerificationResponse {
+                events_checked,
+                valid: true,
+                first_invalid_event_id: None,
+                reason: None,
+            },
+        })
+    }
+}
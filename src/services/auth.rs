@@ -1,24 +1,28 @@
 use async_trait::async_trait;
-use tracing::{error, info};
+use tracing::{error, info, instrument};
 
 use crate::{
     abstract_trait::{
-        auth::AuthServiceTrait, hashing::DynHashing, jwt::DynJwtService, user::DynUserRepository,
+        auth::AuthServiceTrait, hashing::DynHashing, jwt::DynJwtService,
+        screening::DynScreeningService, user::DynUserRepository,
     },
     domain::{
         request::{
             auth::{LoginRequest, RegisterRequest},
+            screening::ScreenNameRequest,
             user::CreateUserRequest,
         },
         response::{user::UserResponse, ApiResponse, ErrorResponse},
     },
-    utils::{errors::AppError, random_vcc::random_vcc},
+    services::user::create_user_with_noc_transfer,
+    utils::{errors::AppError, metrics::record_login_failure},
 };
 
 pub struct AuthService {
     repository: DynUserRepository,
     hashing: DynHashing,
     jwt_config: DynJwtService,
+    screening_service: DynScreeningService,
 }
 
 impl AuthService {
@@ -26,17 +30,20 @@ impl AuthService {
         repository: DynUserRepository,
         hashing: DynHashing,
         jwt_config: DynJwtService,
+        screening_service: DynScreeningService,
     ) -> Self {
         Self {
             repository,
             hashing,
             jwt_config,
+            screening_service,
         }
     }
 }
 
 #[async_trait]
 impl AuthServiceTrait for AuthService {
+    #[instrument(name = "AuthService::register_user", skip_all)]
     async fn register_user(
         &self,
         input: &RegisterRequest,
@@ -71,25 +78,36 @@ impl AuthServiceTrait for AuthService {
             .await
             .map_err(|e| ErrorResponse::from(AppError::HashingError(e)))?;
 
-        let noc_transfer = random_vcc().map(|value| Some(value)).unwrap_or(None);
-
         let request = CreateUserRequest {
             firstname: input.firstname.clone(),
             lastname: input.lastname.clone(),
             email: input.email.clone(),
             password: hashed_password,
             confirm_password: input.confirm_password.clone(),
-            noc_transfer: noc_transfer.to_owned(),
+            noc_transfer: None,
         };
 
         info!("Creating user with email: {}", input.email);
-        let create_user = self
-            .repository
-            .create_user(&request)
+        let create_user = create_user_with_noc_transfer(&self.repository, request)
             .await
             .map_err(AppError::from)
             .map_err(ErrorResponse::from)?;
 
+        // Matches are flagged for compliance review, registration goes ahead
+        let screening = ScreenNameRequest {
+            subject_type: "user".to_string(),
+            subject_id: create_user.user_id,
+            user_id: create_user.user_id,
+            names: vec![format!("{} {}", create_user.firstname, create_user.lastname)],
+        };
+
+        if let Err(e) = self.screening_service.screen(&screening).await {
+            error!(
+                "Failed to screen user {} against the watchlist: {}",
+                create_user.user_id, e
+            );
+        }
+
         info!("User registered successfully with email: {}", input.email);
 
         Ok(ApiResponse {
@@ -99,6 +117,7 @@ impl AuthServiceTrait for AuthService {
         })
     }
 
+    #[instrument(name = "AuthService::login_user", skip_all)]
     async fn login_user(&self, input: &LoginRequest) -> Result<ApiResponse<String>, ErrorResponse> {
         info!("Attempting to login user with email: {}", input.email);
 
@@ -108,10 +127,14 @@ impl AuthServiceTrait for AuthService {
             .await
             .map_err(AppError::from)
             .map_err(ErrorResponse::from)?
-            .ok_or_else(|| ErrorResponse::from(AppError::NotFound("User not found".to_string())))?;
+            .ok_or_else(|| {
+                record_login_failure("unknown_email");
+                ErrorResponse::from(AppError::NotFound("User not found".to_string()))
+            })?;
 
         if let Err(validation_err) = input.validate() {
             error!("Validation failed for user login: {}", validation_err);
+            record_login_failure("invalid_request");
             return Err(ErrorResponse::from(AppError::ValidationError(
                 validation_err,
             )));
@@ -124,6 +147,7 @@ impl AuthServiceTrait for AuthService {
             .is_err()
         {
             error!("Invalid credentials for email: {}", input.email);
+            record_login_failure("invalid_credentials");
             return Err(ErrorResponse::from(AppError::InvalidCredentials));
         }
 
@@ -0,0 +1,132 @@
+use async_trait::async_trait;
+use tracing::{error, info, instrument};
+
+use crate::{
+    abstract_trait::bank_account::{BankAccountServiceTrait, DynBankAccountRepository},
+    domain::{
+        request::bank_account::CreateBankAccountRequest,
+        response::{bank_account::BankAccountResponse, ApiResponse, ErrorResponse},
+    },
+    utils::errors::AppError,
+};
+
+pub struct BankAccountService {
+    bank_account_repository: DynBankAccountRepository,
+}
+
+impl BankAccountService {
+    pub fn new(bank_account_repository: DynBankAccountRepository) -> Self {
+        Self {
+            bank_account_repository,
+        }
+    }
+}
+
+#[async_trait]
+impl BankAccountServiceTrait for BankAccountService {
+    #[instrument(name = "BankAccountService::get_bank_accounts", skip_all)]
+    async fn get_bank_accounts(
+        &self,
+        user_id: i32,
+    ) -> Result<ApiResponse<Vec<BankAccountResponse>>, ErrorResponse> {
+        let bank_accounts = self
+            .bank_account_repository
+            .find_by_user(user_id)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?;
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "Bank accounts retrieved successfully".to_string(),
+            data: bank_accounts
+                .into_iter()
+                .map(BankAccountResponse::from)
+                .collect(),
+        })
+    }
+
+    #[instrument(name = "BankAccountService::create_bank_account", skip_all)]
+    async fn create_bank_account(
+        &self,
+        input: &CreateBankAccountRequest,
+    ) -> Result<ApiResponse<BankAccountResponse>, ErrorResponse> {
+        if let Err(validation_err) = input.validate() {
+            error!(
+                "Validation failed for bank account create: {}",
+                validation_err
+            );
+            return Err(ErrorResponse::from(AppError::ValidationError(
+                validation_err,
+            )));
+        }
+
+        let existing = self
+            .bank_account_repository
+            .find_by_user(input.user_id)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?;
+
+        if existing.iter().any(|account| {
+            account.bank_code == input.bank_code && account.account_number == input.account_number
+        }) {
+            return Err(ErrorResponse::from(AppError::ValidationError(
+                "Bank account already registered".to_string(),
+            )));
+        }
+
+        let bank_account = self
+            .bank_account_repository
+            .create(input)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?;
+
+        info!(
+            "Bank account {} registered for user {}",
+            bank_account.bank_account_id, input.user_id
+        );
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "Bank account registered successfully".to_string(),
+            data: BankAccountResponse::from(bank_account),
+        })
+    }
+
+    #[instrument(name = "BankAccountService::delete_bank_account", skip_all)]
+    async fn delete_bank_account(
+        &self,
+        id: i32,
+        user_id: i32,
+    ) -> Result<ApiResponse<()>, ErrorResponse> {
+        let bank_account = self
+            .bank_account_repository
+            .find_by_id(id)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?
+            .filter(|account| account.user_id == user_id)
+            .ok_or_else(|| {
+                ErrorResponse::from(AppError::NotFound(format!(
+                    "Bank account with id {} not found",
+                    id
+                )))
+            })?;
+
+        self.bank_account_repository
+            .delete(bank_account.bank_account_id)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?;
+
+        info!("Bank account {} deleted", id);
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "Bank account deleted successfully".to_string(),
+            data: (),
+        })
+    }
+}
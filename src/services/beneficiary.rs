@@ -0,0 +1,255 @@
+use async_trait::async_trait;
+use tracing::{error, info, instrument};
+
+use crate::{
+    abstract_trait::{
+        beneficiary::{BeneficiaryServiceTrait, DynBeneficiaryRepository},
+        screening::DynScreeningService,
+        user::DynUserRepository,
+    },
+    domain::{
+        request::{
+            beneficiary::{CreateBeneficiaryRequest, UpdateBeneficiaryRequest},
+            screening::ScreenNameRequest,
+        },
+        response::{beneficiary::BeneficiaryResponse, ApiResponse, ErrorResponse},
+    },
+    entities::beneficiaries,
+    utils::errors::AppError,
+};
+
+pub struct BeneficiaryService {
+    beneficiary_repository: DynBeneficiaryRepository,
+    user_repository: DynUserRepository,
+    screening_service: DynScreeningService,
+}
+
+impl BeneficiaryService {
+    pub fn new(
+        beneficiary_repository: DynBeneficiaryRepository,
+        user_repository: DynUserRepository,
+        screening_service: DynScreeningService,
+    ) -> Self {
+        Self {
+            beneficiary_repository,
+            user_repository,
+            screening_service,
+        }
+    }
+
+    async fn find_owned_beneficiary(
+        &self,
+        id: i32,
+        user_id: i32,
+    ) -> Result<beneficiaries::Model, ErrorResponse> {
+        self.beneficiary_repository
+            .find_by_id(id)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?
+            .filter(|beneficiary| beneficiary.user_id == user_id)
+            .ok_or_else(|| {
+                ErrorResponse::from(AppError::NotFound(format!(
+                    "Beneficiary with id {} not found",
+                    id
+                )))
+            })
+    }
+}
+
+#[async_trait]
+impl BeneficiaryServiceTrait for BeneficiaryService {
+    #[instrument(name = "BeneficiaryService::get_beneficiaries", skip_all)]
+    async fn get_beneficiaries(
+        &self,
+        user_id: i32,
+    ) -> Result<ApiResponse<Vec<BeneficiaryResponse>>, ErrorResponse> {
+        let beneficiaries = self
+            .beneficiary_repository
+            .find_by_user(user_id)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?;
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "Beneficiaries retrieved successfully".to_string(),
+            data: beneficiaries
+                .into_iter()
+                .map(BeneficiaryResponse::from)
+                .collect(),
+        })
+    }
+
+    #[instrument(name = "BeneficiaryService::get_beneficiary", skip_all)]
+    async fn get_beneficiary(
+        &self,
+        id: i32,
+        user_id: i32,
+    ) -> Result<ApiResponse<BeneficiaryResponse>, ErrorResponse> {
+        let beneficiary = self.find_owned_beneficiary(id, user_id).await?;
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "Beneficiary retrieved successfully".to_string(),
+            data: BeneficiaryResponse::from(beneficiary),
+        })
+    }
+
+    #[instrument(name = "BeneficiaryService::create_beneficiary", skip_all)]
+    async fn create_beneficiary(
+        &self,
+        input: &CreateBeneficiaryRequest,
+    ) -> Result<ApiResponse<BeneficiaryResponse>, ErrorResponse> {
+        if let Err(validation_err) = input.validate() {
+            error!(
+                "Validation failed for beneficiary create: {}",
+                validation_err
+            );
+            return Err(ErrorResponse::from(AppError::ValidationError(
+                validation_err,
+            )));
+        }
+
+        let existing = self
+            .beneficiary_repository
+            .find_by_user(input.user_id)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?;
+
+        let duplicate = existing.iter().any(|beneficiary| {
+            beneficiary.beneficiary_type == input.beneficiary_type
+                && beneficiary.noc_transfer == input.noc_transfer
+                && beneficiary.bank_code == input.bank_code
+                && beneficiary.bank_account_number == input.bank_account_number
+        });
+
+        if duplicate {
+            return Err(ErrorResponse::from(AppError::ValidationError(
+                "Beneficiary already exists".to_string(),
+            )));
+        }
+
+        // Internal accounts are verified against our own users; external bank
+        // accounts stay unverified until a payout confirms them.
+        let (verified, recipient_name) = match input.noc_transfer.as_deref() {
+            Some(noc_transfer) => {
+                let recipient = self
+                    .user_repository
+                    .find_by_noc_transfer(noc_transfer)
+                    .await
+                    .map_err(AppError::from)
+                    .map_err(ErrorResponse::from)?
+                    .ok_or_else(|| {
+                        ErrorResponse::from(AppError::NotFound(format!(
+                            "Account {} not found",
+                            noc_transfer
+                        )))
+                    })?;
+
+                if recipient.user_id == input.user_id {
+                    return Err(ErrorResponse::from(AppError::ValidationError(
+                        "Cannot add your own account as a beneficiary".to_string(),
+                    )));
+                }
+
+                (
+                    true,
+                    Some(format!("{} {}", recipient.firstname, recipient.lastname)),
+                )
+            }
+            None => (false, None),
+        };
+
+        let beneficiary = self
+            .beneficiary_repository
+            .create(input, verified)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?;
+
+        // Matches are flagged for compliance review without telling the user
+        let screening = ScreenNameRequest {
+            subject_type: "beneficiary".to_string(),
+            subject_id: beneficiary.beneficiary_id,
+            user_id: input.user_id,
+            names: std::iter::once(input.alias.clone())
+                .chain(recipient_name)
+                .collect(),
+        };
+
+        if let Err(e) = self.screening_service.screen(&screening).await {
+            error!(
+                "Failed to screen beneficiary {} against the watchlist: {}",
+                beneficiary.beneficiary_id, e
+            );
+        }
+
+        info!(
+            "Beneficiary {} created for user {}",
+            beneficiary.beneficiary_id, input.user_id
+        );
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "Beneficiary created successfully".to_string(),
+            data: BeneficiaryResponse::from(beneficiary),
+        })
+    }
+
+    #[instrument(name = "BeneficiaryService::update_beneficiary", skip_all)]
+    async fn update_beneficiary(
+        &self,
+        input: &UpdateBeneficiaryRequest,
+    ) -> Result<ApiResponse<BeneficiaryResponse>, ErrorResponse> {
+        if let Err(validation_err) = input.validate() {
+            error!(
+                "Validation failed for beneficiary update: {}",
+                validation_err
+            );
+            return Err(ErrorResponse::from(AppError::ValidationError(
+                validation_err,
+            )));
+        }
+
+        self.find_owned_beneficiary(input.beneficiary_id, input.user_id)
+            .await?;
+
+        let beneficiary = self
+            .beneficiary_repository
+            .update(input)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?;
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "Beneficiary updated successfully".to_string(),
+            data: BeneficiaryResponse::from(beneficiary),
+        })
+    }
+
+    #[instrument(name = "BeneficiaryService::delete_beneficiary", skip_all)]
+    async fn delete_beneficiary(
+        &self,
+        id: i32,
+        user_id: i32,
+    ) -> Result<ApiResponse<()>, ErrorResponse> {
+        let beneficiary = self.find_owned_beneficiary(id, user_id).await?;
+
+        self.beneficiary_repository
+            .delete(beneficiary.beneficiary_id)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?;
+
+        info!("Beneficiary {} deleted", id);
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "Beneficiary deleted successfully".to_string(),
+            data: (),
+        })
+    }
+}
@@ -0,0 +1,204 @@
+use async_trait::async_trait;
+use tracing::{error, info, instrument};
+
+use crate::{
+    abstract_trait::fee::{DynFeeRepository, FeeQuote, FeeServiceTrait},
+    domain::{
+        request::fee::{FeeQuoteRequest, RecordFeeRevenue},
+        response::{
+            fee::{FeeQuoteResponse, FeeRuleResponse, FeeTier},
+            ApiResponse, ErrorResponse,
+        },
+    },
+    entities::fee_rules,
+    utils::errors::AppError,
+};
+
+pub struct FeeService {
+    fee_repository: DynFeeRepository,
+    /// User whose saldo receives the fees, `None` when fees cannot be taken.
+    revenue_user_id: Option<i32>,
+}
+
+impl FeeService {
+    pub fn new(fee_repository: DynFeeRepository, revenue_user_id: Option<i32>) -> Self {
+        Self {
+            fee_repository,
+            revenue_user_id,
+        }
+    }
+
+    fn no_revenue_account() -> ErrorResponse {
+        ErrorResponse::from(AppError::FeeRuleError(
+            "no fee revenue account is configured".to_string(),
+        ))
+    }
+}
+
+/// A rule applies when its method and merchant are unset or equal to the
+/// transaction's.
+fn rule_matches(rule: &fee_rules::Model, method: Option<&str>, merchant_id: Option<i32>) -> bool {
+    let method_matches = match (rule.method.as_deref(), method) {
+        (None, _) => true,
+        (Some(rule_method), Some(method)) => rule_method.eq_ignore_ascii_case(method),
+        (Some(_), None) => false,
+    };
+
+    let merchant_matches = rule.merchant_id.is_none() || rule.merchant_id == merchant_id;
+
+    method_matches && merchant_matches
+}
+
+/// Merchant-specific rules beat method-specific rules, which beat the
+/// catch-all rule of the transaction type.
+fn rule_specificity(rule: &fee_rules::Model) -> u8 {
+    (rule.merchant_id.is_some() as u8) * 2 + rule.method.is_some() as u8
+}
+
+/// Basis points of `amount`, rounded half up.
+fn percentage_of(amount: i32, bps: i32) -> i32 {
+    ((amount as i64 * bps as i64 + 5_000) / 10_000) as i32
+}
+
+fn rule_fee(rule: &fee_rules::Model, amount: i32) -> Result<i32, String> {
+    let fee = match rule.fee_type.as_str() {
+        "flat" => rule.flat_amount,
+        "percentage" => percentage_of(amount, rule.percentage_bps),
+        "tiered" => {
+            let tiers: Vec<FeeTier> = rule
+                .tiers
+                .as_deref()
+                .map(serde_json::from_str)
+                .transpose()
+                .map_err(|e| format!("rule {} has invalid tiers: {}", rule.fee_rule_id, e))?
+                .unwrap_or_default();
+
+            let tier = tiers
+                .iter()
+                .find(|tier| tier.up_to.is_none_or(|up_to| amount <= up_to))
+                .ok_or_else(|| {
+                    format!(
+                        "rule {} has no tier for amount {}",
+                        rule.fee_rule_id, amount
+                    )
+                })?;
+
+            tier.flat_amount + percentage_of(amount, tier.percentage_bps)
+        }
+        other => {
+            return Err(format!(
+                "rule {} has unknown fee type {}",
+                rule.fee_rule_id, other
+            ))
+        }
+    };
+
+    let fee = rule.min_fee.map_or(fee, |min_fee| fee.max(min_fee));
+    let fee = rule.max_fee.map_or(fee, |max_fee| fee.min(max_fee));
+
+    Ok(fee.max(0))
+}
+
+#[async_trait]
+impl FeeServiceTrait for FeeService {
+    #[instrument(name = "FeeService::get_fee_rules", skip_all)]
+    async fn get_fee_rules(&self) -> Result<ApiResponse<Vec<FeeRuleResponse>>, ErrorResponse> {
+        let rules = self
+            .fee_repository
+            .find_active_rules()
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?;
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "Fee rules retrieved successfully".to_string(),
+            data: rules.into_iter().map(FeeRuleResponse::from).collect(),
+        })
+    }
+
+    #[instrument(name = "FeeService::get_fee_quote", skip_all)]
+    async fn get_fee_quote(
+        &self,
+        input: &FeeQuoteRequest,
+    ) -> Result<ApiResponse<FeeQuoteResponse>, ErrorResponse> {
+        let quote = self.calculate_fee(input).await?;
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "Fee calculated successfully".to_string(),
+            data: FeeQuoteResponse {
+                transaction_type: input.transaction_type.clone(),
+                amount: input.amount,
+                fee_amount: quote.fee_amount,
+                fee_rule_id: quote.fee_rule_id,
+            },
+        })
+    }
+
+    #[instrument(name = "FeeService::calculate_fee", skip_all)]
+    async fn calculate_fee(&self, input: &FeeQuoteRequest) -> Result<FeeQuote, ErrorResponse> {
+        if let Err(validation_err) = input.validate() {
+            return Err(ErrorResponse::from(AppError::ValidationError(
+                validation_err,
+            )));
+        }
+
+        let rules = self
+            .fee_repository
+            .find_active_rules_by_type(&input.transaction_type)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?;
+
+        // Among equally specific rules the newest one wins.
+        let Some(rule) = rules
+            .iter()
+            .filter(|rule| rule_matches(rule, input.method.as_deref(), input.merchant_id))
+            .max_by_key(|rule| (rule_specificity(rule), rule.fee_rule_id))
+        else {
+            return Ok(FeeQuote::default());
+        };
+
+        let fee_amount = rule_fee(rule, input.amount).map_err(|e| {
+            error!("Failed to calculate {} fee: {}", input.transaction_type, e);
+            ErrorResponse::from(AppError::FeeRuleError(e))
+        })?;
+
+        // Refused before any money moves, as the fee could not be posted
+        if fee_amount > 0 && self.revenue_user_id.is_none() {
+            error!(
+                "Rule {} charges a {} fee but no revenue account is configured",
+                rule.fee_rule_id, input.transaction_type
+            );
+            return Err(Self::no_revenue_account());
+        }
+
+        Ok(FeeQuote {
+            fee_rule_id: Some(rule.fee_rule_id),
+            fee_amount,
+        })
+    }
+
+    #[instrument(name = "FeeService::post_fee", skip_all)]
+    async fn post_fee(&self, input: &RecordFeeRevenue) -> Result<(), ErrorResponse> {
+        if input.amount == 0 {
+            return Ok(());
+        }
+
+        let revenue_user_id = self.revenue_user_id.ok_or_else(Self::no_revenue_account)?;
+
+        self.fee_repository
+            .record_revenue(input, revenue_user_id)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?;
+
+        info!(
+            "Posted fee of {} for {} {} to revenue",
+            input.amount, input.transaction_type, input.transaction_id
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,207 @@
+use async_trait::async_trait;
+use chrono::{Duration, NaiveDateTime};
+use tracing::{error, info, instrument, warn};
+
+use crate::{
+    abstract_trait::{
+        clock::DynClock,
+        fraud::{DynFraudRepository, FraudEngineTrait},
+    },
+    domain::{
+        request::fraud::{CreateFraudReview, ScreenTransactionRequest},
+        response::ErrorResponse,
+    },
+    entities::{fraud_rules, user_devices},
+    utils::errors::AppError,
+};
+
+/// Users need this many earlier transactions before their amounts are
+/// compared against their average.
+pub const AMOUNT_ANOMALY_MIN_HISTORY: i64 = 3;
+
+/// Window used by rules that do not configure one.
+const DEFAULT_WINDOW_SECONDS: i32 = 60;
+
+pub struct FraudEngine {
+    fraud_repository: DynFraudRepository,
+    clock: DynClock,
+}
+
+impl FraudEngine {
+    pub fn new(fraud_repository: DynFraudRepository, clock: DynClock) -> Self {
+        Self {
+            fraud_repository,
+            clock,
+        }
+    }
+
+    fn window_start(&self, rule: &fraud_rules::Model) -> NaiveDateTime {
+        let seconds = rule.window_seconds.unwrap_or(DEFAULT_WINDOW_SECONDS);
+        self.clock.now() - Duration::seconds(seconds as i64)
+    }
+
+    /// Whether the rule fires for the transaction.
+    async fn evaluate(
+        &self,
+        rule: &fraud_rules::Model,
+        input: &ScreenTransactionRequest,
+        device: Option<&user_devices::Model>,
+    ) -> Result<bool, AppError> {
+        let since = self.window_start(rule);
+
+        let triggered = match rule.rule_type.as_str() {
+            "velocity" => {
+                let count = self
+                    .fraud_repository
+                    .count_user_transactions_since(&input.transaction_type, input.user_id, since)
+                    .await?;
+
+                count + 1 > rule.threshold as i64
+            }
+            "amount_anomaly" => {
+                let (count, total) = self
+                    .fraud_repository
+                    .user_amount_stats_since(&input.transaction_type, input.user_id, since)
+                    .await?;
+
+                count >= AMOUNT_ANOMALY_MIN_HISTORY
+                    && input.amount as i64 * count > total * rule.threshold as i64
+            }
+            "new_device_beneficiary" => {
+                let (Some(device), Some(counterparty_id)) = (device, input.counterparty_id) else {
+                    return Ok(false);
+                };
+
+                if device.first_seen_at < since {
+                    return Ok(false);
+                }
+
+                let earlier = self
+                    .fraud_repository
+                    .count_to_counterparty(&input.transaction_type, input.user_id, counterparty_id)
+                    .await?;
+
+                earlier < rule.threshold as i64
+            }
+            "round_trip" => {
+                let Some(counterparty_id) = input.counterparty_id else {
+                    return Ok(false);
+                };
+
+                if input.transaction_type != "transfer" {
+                    return Ok(false);
+                }
+
+                let received = self
+                    .fraud_repository
+                    .count_transfers_since(counterparty_id, input.user_id, since)
+                    .await?;
+
+                received >= rule.threshold as i64
+            }
+            other => {
+                warn!(
+                    "Skipping fraud rule {} of unknown type {}",
+                    rule.name, other
+                );
+                false
+            }
+        };
+
+        Ok(triggered)
+    }
+}
+
+#[async_trait]
+impl FraudEngineTrait for FraudEngine {
+    #[instrument(name = "FraudEngine::screen", skip_all)]
+    async fn screen(&self, input: &ScreenTransactionRequest) -> Result<(), ErrorResponse> {
+        let rules = self
+            .fraud_repository
+            .find_active_rules(&input.transaction_type)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?;
+
+        // Devices are recorded even when no rule looks at them, so a device
+        // is only new the first time it is used
+        let device = match &input.device_id {
+            Some(device_id) => Some(
+                self.fraud_repository
+                    .touch_device(input.user_id, device_id)
+                    .await
+                    .map_err(AppError::from)
+                    .map_err(ErrorResponse::from)?,
+            ),
+            None => None,
+        };
+
+        let mut denied_by = Vec::new();
+        let mut reviewed_by = Vec::new();
+
+        for rule in &rules {
+            let triggered = self
+                .evaluate(rule, input, device.as_ref())
+                .await
+                .map_err(ErrorResponse::from)?;
+
+            if !triggered {
+                continue;
+            }
+
+            match rule.action.as_str() {
+                "deny" => denied_by.push(rule.name.clone()),
+                _ => reviewed_by.push(rule.name.clone()),
+            }
+        }
+
+        if !denied_by.is_empty() {
+            error!(
+                "{} of {} by user {} denied by fraud rules {}",
+                input.transaction_type,
+                input.amount,
+                input.user_id,
+                denied_by.join(", ")
+            );
+            return Err(ErrorResponse::from(AppError::FraudDenied(format!(
+                "The {} was declined by fraud screening",
+                input.transaction_type
+            ))));
+        }
+
+        if reviewed_by.is_empty() {
+            return Ok(());
+        }
+
+        let review = self
+            .fraud_repository
+            .create_review(&CreateFraudReview {
+                user_id: input.user_id,
+                transaction_type: input.transaction_type.clone(),
+                amount: input.amount,
+                counterparty_id: input.counterparty_id,
+                payload: input.payload.clone(),
+                triggered_rules: reviewed_by.clone(),
+            })
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?;
+
+        info!(
+            "{} of {} by user {} held as fraud review {} by rules {}",
+            input.transaction_type,
+            input.amount,
+            input.user_id,
+            review.fraud_review_id,
+            reviewed_by.join(", ")
+        );
+
+        Err(ErrorResponse::from(AppError::HeldForReview(
+            review.fraud_review_id,
+            format!(
+                "The {} is held for review under fraud review {}",
+                input.transaction_type, review.fraud_review_id
+            ),
+        )))
+    }
+}
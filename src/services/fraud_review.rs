@@ -0,0 +1,601 @@
+use async_trait::async_trait;
+use serde::de::DeserializeOwned;
+use tracing::{error, info, instrument};
+
+use crate::{
+    abstract_trait::{
+        fraud::{DynFraudRepository, FraudReviewServiceTrait},
+        payment_intent::DynPaymentIntentRepository,
+        qris::DynQrisRepository,
+        scheduled_transfer::DynScheduledTransferRepository,
+        settlement::DynSettlementRepository,
+        topup::DynTopupService,
+        transfer::DynTransferService,
+        transfer_batch::DynTransferBatchRepository,
+        withdraw::DynWithdrawService,
+    },
+    domain::{
+        request::{
+            fraud::{DecideFraudReviewRequest, FRAUD_REVIEW_STATUSES},
+            topup::CreateTopupRequest,
+            transfer::CreateTransferRequest,
+            transfer_batch::UpdateTransferBatchItemResult,
+            withdraw::CreateWithdrawRequest,
+        },
+        response::{
+            fraud::{FraudReviewResponse, FraudRuleResponse},
+            ApiResponse, ErrorResponse,
+        },
+    },
+    entities::fraud_reviews,
+    utils::errors::AppError,
+};
+
+pub struct FraudReviewService {
+    fraud_repository: DynFraudRepository,
+    topup_service: DynTopupService,
+    transfer_service: DynTransferService,
+    withdraw_service: DynWithdrawService,
+    payment_intent_repository: DynPaymentIntentRepository,
+    qris_repository: DynQrisRepository,
+    settlement_repository: DynSettlementRepository,
+    transfer_batch_repository: DynTransferBatchRepository,
+    scheduled_transfer_repository: DynScheduledTransferRepository,
+}
+
+fn parse_payload<T: DeserializeOwned>(review: &fraud_reviews::Model) -> Result<T, ErrorResponse> {
+    serde_json::from_str(&review.payload).map_err(|e| {
+        ErrorResponse::from(AppError::ValidationError(format!(
+            "Held {} of fraud review {} cannot be read: {}",
+            review.transaction_type, review.fraud_review_id, e
+        )))
+    })
+}
+
+impl FraudReviewService {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        fraud_repository: DynFraudRepository,
+        topup_service: DynTopupService,
+        transfer_service: DynTransferService,
+        withdraw_service: DynWithdrawService,
+        payment_intent_repository: DynPaymentIntentRepository,
+        qris_repository: DynQrisRepository,
+        settlement_repository: DynSettlementRepository,
+        transfer_batch_repository: DynTransferBatchRepository,
+        scheduled_transfer_repository: DynScheduledTransferRepository,
+    ) -> Self {
+        Self {
+            fraud_repository,
+            topup_service,
+            transfer_service,
+            withdraw_service,
+            payment_intent_repository,
+            qris_repository,
+            settlement_repository,
+            transfer_batch_repository,
+            scheduled_transfer_repository,
+        }
+    }
+
+    async fn find_review(&self, id: i32) -> Result<fraud_reviews::Model, ErrorResponse> {
+        self.fraud_repository
+            .find_review_by_id(id)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?
+            .ok_or_else(|| {
+                ErrorResponse::from(AppError::NotFound(format!(
+                    "Fraud review with id {} not found",
+                    id
+                )))
+            })
+    }
+
+    /// Moves a pending review to `to_status`, failing when someone else
+    /// decided it first.
+    async fn decide(
+        &self,
+        input: &DecideFraudReviewRequest,
+        to_status: &str,
+    ) -> Result<fraud_reviews::Model, ErrorResponse> {
+        let review = self.find_review(input.fraud_review_id).await?;
+
+        let transitioned = review.status == "pending"
+            && self
+                .fraud_repository
+                .transition_review(
+                    review.fraud_review_id,
+                    "pending",
+                    to_status,
+                    input.reviewer_id,
+                    input.note.clone(),
+                )
+                .await
+                .map_err(AppError::from)
+                .map_err(ErrorResponse::from)?;
+
+        if !transitioned {
+            return Err(ErrorResponse::from(AppError::ValidationError(format!(
+                "Fraud review {} is {} and cannot be {}",
+                review.fraud_review_id, review.status, to_status
+            ))));
+        }
+
+        Ok(review)
+    }
+
+    /// Carries out the held transaction, returning the id it was created
+    /// under.
+    async fn release(&self, review: &fraud_reviews::Model) -> Result<i32, ErrorResponse> {
+        match review.transaction_type.as_str() {
+            "topup" => {
+                let input: CreateTopupRequest = parse_payload(review)?;
+                let response = self.topup_service.create_approved_topup(&input).await?;
+                Ok(response.data.topup_id)
+            }
+            "transfer" => {
+                let input: CreateTransferRequest = parse_payload(review)?;
+                let response = self
+                    .transfer_service
+                    .create_approved_transfer(&input)
+                    .await?;
+                Ok(response.data.transfer_id)
+            }
+            "withdraw" => {
+                let input: CreateWithdrawRequest = parse_payload(review)?;
+                let response = self
+                    .withdraw_service
+                    .create_approved_withdraw(&input)
+                    .await?;
+                Ok(response.data.withdraw_id)
+            }
+            other => Err(ErrorResponse::from(AppError::ValidationError(format!(
+                "Unknown held transaction type {}",
+                other
+            )))),
+        }
+    }
+
+    /// Finishes a payment intent or QRIS left processing while its transfer
+    /// was held: paid with the released transfer, or reopened when no money
+    /// moved.
+    async fn resolve_merchant_payment(
+        &self,
+        review: &fraud_reviews::Model,
+        transfer_id: Option<i32>,
+    ) {
+        if review.transaction_type != "transfer" {
+            return;
+        }
+
+        self.resolve_payment_intent(review, transfer_id).await;
+        self.resolve_qris_payment(review, transfer_id).await;
+    }
+
+    async fn resolve_payment_intent(
+        &self,
+        review: &fraud_reviews::Model,
+        transfer_id: Option<i32>,
+    ) {
+        let payment_intent = match self
+            .payment_intent_repository
+            .find_by_fraud_review(review.fraud_review_id)
+            .await
+        {
+            Ok(Some(payment_intent)) if payment_intent.status == "processing" => payment_intent,
+            Ok(_) => return,
+            Err(db_err) => {
+                error!(
+                    "Failed to look up payment intent of fraud review {}: {}",
+                    review.fraud_review_id, db_err
+                );
+                return;
+            }
+        };
+
+        let id = payment_intent.payment_intent_id;
+        let result = match transfer_id {
+            Some(transfer_id) => self
+                .payment_intent_repository
+                .record_success(id, review.user_id, transfer_id)
+                .await
+                .map(|_| ()),
+            None => self
+                .payment_intent_repository
+                .transition_status(id, "processing", "requires_confirmation")
+                .await
+                .map(|_| ()),
+        };
+
+        match result {
+            Ok(()) => info!(
+                "Payment intent {} resolved by fraud review {}",
+                id, review.fraud_review_id
+            ),
+            Err(db_err) => error!(
+                "Failed to resolve payment intent {} of fraud review {}: {}",
+                id, review.fraud_review_id, db_err
+            ),
+        }
+    }
+
+    async fn resolve_qris_payment(&self, review: &fraud_reviews::Model, transfer_id: Option<i32>) {
+        let qris_payment = match self
+            .qris_repository
+            .find_by_fraud_review(review.fraud_review_id)
+            .await
+        {
+            Ok(Some(qris_payment)) if qris_payment.status == "processing" => qris_payment,
+            Ok(_) => return,
+            Err(db_err) => {
+                error!(
+                    "Failed to look up QRIS of fraud review {}: {}",
+                    review.fraud_review_id, db_err
+                );
+                return;
+            }
+        };
+
+        let id = qris_payment.qris_payment_id;
+        let result = match transfer_id {
+            Some(transfer_id) => self
+                .qris_repository
+                .record_payment(id, review.user_id, transfer_id)
+                .await
+                .map(|_| ()),
+            None => self
+                .qris_repository
+                .transition_status(id, "processing", "pending")
+                .await
+                .map(|_| ()),
+        };
+
+        match result {
+            Ok(()) => info!(
+                "QRIS {} resolved by fraud review {}",
+                qris_payment.reference, review.fraud_review_id
+            ),
+            Err(db_err) => error!(
+                "Failed to resolve QRIS {} of fraud review {}: {}",
+                qris_payment.reference, review.fraud_review_id, db_err
+            ),
+        }
+    }
+
+    /// Records the outcome of a batch item whose transfer was held for
+    /// review: `succeeded` with the released transfer, otherwise `failed`.
+    async fn resolve_batch_item(
+        &self,
+        review: &fraud_reviews::Model,
+        transfer_id: Option<i32>,
+        failure_reason: Option<String>,
+    ) {
+        if review.transaction_type != "transfer" {
+            return;
+        }
+
+        let item = match self
+            .transfer_batch_repository
+            .find_item_by_fraud_review(review.fraud_review_id)
+            .await
+        {
+            Ok(Some(item)) if item.status == "held" => item,
+            Ok(_) => return,
+            Err(db_err) => {
+                error!(
+                    "Failed to look up transfer batch item of fraud review {}: {}",
+                    review.fraud_review_id, db_err
+                );
+                return;
+            }
+        };
+
+        let status = match transfer_id {
+            Some(_) => "succeeded",
+            None => "failed",
+        };
+
+        match self
+            .transfer_batch_repository
+            .resolve_held_item(&UpdateTransferBatchItemResult {
+                item_id: item.item_id,
+                status: status.to_string(),
+                transfer_id,
+                error_message: failure_reason,
+                fraud_review_id: Some(review.fraud_review_id),
+            })
+            .await
+        {
+            Ok(_) => info!(
+                "Transfer batch {} line {} is {} after fraud review {}",
+                item.batch_id, item.line_no, status, review.fraud_review_id
+            ),
+            Err(db_err) => error!(
+                "Failed to resolve transfer batch item {} of fraud review {}: {}",
+                item.item_id, review.fraud_review_id, db_err
+            ),
+        }
+    }
+
+    /// Records the outcome of a scheduled run whose transfer was held for
+    /// review on its `held` attempt.
+    async fn resolve_scheduled_attempt(
+        &self,
+        review: &fraud_reviews::Model,
+        transfer_id: Option<i32>,
+        failure_reason: Option<String>,
+    ) {
+        if review.transaction_type != "transfer" {
+            return;
+        }
+
+        let attempt = match self
+            .scheduled_transfer_repository
+            .find_attempt_by_fraud_review(review.fraud_review_id)
+            .await
+        {
+            Ok(Some(attempt)) if attempt.status == "held" => attempt,
+            Ok(_) => return,
+            Err(db_err) => {
+                error!(
+                    "Failed to look up scheduled transfer attempt of fraud review {}: {}",
+                    review.fraud_review_id, db_err
+                );
+                return;
+            }
+        };
+
+        let status = match transfer_id {
+            Some(_) => "succeeded",
+            None => "failed",
+        };
+
+        match self
+            .scheduled_transfer_repository
+            .resolve_held_attempt(attempt.attempt_id, status, transfer_id, failure_reason)
+            .await
+        {
+            Ok(_) => info!(
+                "Scheduled transfer {} attempt {} is {} after fraud review {}",
+                attempt.schedule_id, attempt.attempt_id, status, review.fraud_review_id
+            ),
+            Err(db_err) => error!(
+                "Failed to resolve scheduled transfer attempt {} of fraud review {}: {}",
+                attempt.attempt_id, review.fraud_review_id, db_err
+            ),
+        }
+    }
+
+    /// Records the outcome of a settlement payout held for review: paid out
+    /// with the released withdraw, `failed` so the next daily run retries
+    /// it, or `declined`.
+    async fn resolve_settlement(
+        &self,
+        review: &fraud_reviews::Model,
+        status: &str,
+        withdraw_id: Option<i32>,
+        failure_reason: Option<String>,
+    ) {
+        if review.transaction_type != "withdraw" {
+            return;
+        }
+
+        let settlement = match self
+            .settlement_repository
+            .find_by_fraud_review(review.fraud_review_id)
+            .await
+        {
+            Ok(Some(settlement)) if settlement.status == "in_review" => settlement,
+            Ok(_) => return,
+            Err(db_err) => {
+                error!(
+                    "Failed to look up settlement of fraud review {}: {}",
+                    review.fraud_review_id, db_err
+                );
+                return;
+            }
+        };
+
+        match self
+            .settlement_repository
+            .update_payout(
+                settlement.settlement_id,
+                status,
+                withdraw_id,
+                failure_reason,
+            )
+            .await
+        {
+            Ok(_) => info!(
+                "Settlement {} is {} after fraud review {}",
+                settlement.settlement_id, status, review.fraud_review_id
+            ),
+            Err(db_err) => error!(
+                "Failed to resolve settlement {} of fraud review {}: {}",
+                settlement.settlement_id, review.fraud_review_id, db_err
+            ),
+        }
+    }
+}
+
+#[async_trait]
+impl FraudReviewServiceTrait for FraudReviewService {
+    #[instrument(name = "FraudReviewService::get_rules", skip_all)]
+    async fn get_rules(&self) -> Result<ApiResponse<Vec<FraudRuleResponse>>, ErrorResponse> {
+        let rules = self
+            .fraud_repository
+            .find_rules()
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?;
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "Fraud rules retrieved successfully".to_string(),
+            data: rules.into_iter().map(FraudRuleResponse::from).collect(),
+        })
+    }
+
+    #[instrument(name = "FraudReviewService::get_reviews", skip_all)]
+    async fn get_reviews(
+        &self,
+        status: Option<String>,
+    ) -> Result<ApiResponse<Vec<FraudReviewResponse>>, ErrorResponse> {
+        if let Some(status) = &status {
+            if !FRAUD_REVIEW_STATUSES.contains(&status.as_str()) {
+                return Err(ErrorResponse::from(AppError::ValidationError(format!(
+                    "Status must be one of {}",
+                    FRAUD_REVIEW_STATUSES.join(", ")
+                ))));
+            }
+        }
+
+        let reviews = self
+            .fraud_repository
+            .find_reviews(status)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?;
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "Fraud reviews retrieved successfully".to_string(),
+            data: reviews.into_iter().map(FraudReviewResponse::from).collect(),
+        })
+    }
+
+    #[instrument(name = "FraudReviewService::approve_review", skip_all)]
+    async fn approve_review(
+        &self,
+        input: &DecideFraudReviewRequest,
+    ) -> Result<ApiResponse<FraudReviewResponse>, ErrorResponse> {
+        if let Err(validation_err) = input.validate(true) {
+            error!("Validation failed for fraud review: {}", validation_err);
+            return Err(ErrorResponse::from(AppError::ValidationError(
+                validation_err,
+            )));
+        }
+
+        let review = self.decide(input, "approved").await?;
+
+        let transaction_id = match self.release(&review).await {
+            Ok(transaction_id) => transaction_id,
+            Err(e) => {
+                error!(
+                    "Approved {} of fraud review {} could not be carried out: {}",
+                    review.transaction_type, review.fraud_review_id, e.message
+                );
+
+                if let Err(db_err) = self
+                    .fraud_repository
+                    .transition_review(
+                        review.fraud_review_id,
+                        "approved",
+                        "failed",
+                        input.reviewer_id,
+                        Some(e.message.clone()),
+                    )
+                    .await
+                {
+                    error!(
+                        "Failed to mark fraud review {} as failed: {}",
+                        review.fraud_review_id, db_err
+                    );
+                }
+
+                self.resolve_merchant_payment(&review, None).await;
+                self.resolve_batch_item(&review, None, Some(e.message.clone()))
+                    .await;
+                self.resolve_scheduled_attempt(&review, None, Some(e.message.clone()))
+                    .await;
+                self.resolve_settlement(&review, "failed", None, Some(e.message.clone()))
+                    .await;
+
+                return Err(e);
+            }
+        };
+
+        if let Err(db_err) = self
+            .fraud_repository
+            .set_review_transaction(review.fraud_review_id, transaction_id)
+            .await
+        {
+            error!(
+                "Failed to link {} {} to fraud review {}: {}",
+                review.transaction_type, transaction_id, review.fraud_review_id, db_err
+            );
+        }
+
+        self.resolve_merchant_payment(&review, Some(transaction_id))
+            .await;
+        self.resolve_batch_item(&review, Some(transaction_id), None)
+            .await;
+        self.resolve_scheduled_attempt(&review, Some(transaction_id), None)
+            .await;
+        self.resolve_settlement(&review, "paid_out", Some(transaction_id), None)
+            .await;
+
+        info!(
+            "Fraud review {} approved by {}, released {} {}",
+            review.fraud_review_id, input.reviewer_id, review.transaction_type, transaction_id
+        );
+
+        let review = self.find_review(review.fraud_review_id).await?;
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: format!("Held {} released", review.transaction_type),
+            data: FraudReviewResponse::from(review),
+        })
+    }
+
+    #[instrument(name = "FraudReviewService::decline_review", skip_all)]
+    async fn decline_review(
+        &self,
+        input: &DecideFraudReviewRequest,
+    ) -> Result<ApiResponse<FraudReviewResponse>, ErrorResponse> {
+        if let Err(validation_err) = input.validate(false) {
+            error!("Validation failed for fraud review: {}", validation_err);
+            return Err(ErrorResponse::from(AppError::ValidationError(
+                validation_err,
+            )));
+        }
+
+        let review = self.decide(input, "declined").await?;
+
+        self.resolve_merchant_payment(&review, None).await;
+        self.resolve_batch_item(
+            &review,
+            None,
+            Some("Transfer declined by fraud review".to_string()),
+        )
+        .await;
+        self.resolve_scheduled_attempt(
+            &review,
+            None,
+            Some("Transfer declined by fraud review".to_string()),
+        )
+        .await;
+        self.resolve_settlement(
+            &review,
+            "declined",
+            None,
+            Some("Payout declined by fraud review".to_string()),
+        )
+        .await;
+
+        info!(
+            "Fraud review {} declined by {}",
+            review.fraud_review_id, input.reviewer_id
+        );
+
+        let review = self.find_review(review.fraud_review_id).await?;
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: format!("Held {} declined", review.transaction_type),
+            data: FraudReviewResponse::from(review),
+        })
+    }
+}
@@ -0,0 +1,111 @@
+use std::collections::BTreeMap;
+
+use async_trait::async_trait;
+use tracing::{error, instrument};
+
+use crate::{
+    abstract_trait::{
+        clock::DynClock,
+        health::{DynHealthRepository, HealthServiceTrait},
+    },
+    domain::response::health::{ComponentHealth, ReadinessResponse},
+    jobs::health::{SharedWorkerHealth, WorkerState},
+};
+
+/// Missed runs after which a worker counts as stalled.
+const STALE_AFTER_INTERVALS: u32 = 3;
+
+pub struct HealthService {
+    health_repository: DynHealthRepository,
+    worker_health: SharedWorkerHealth,
+    clock: DynClock,
+}
+
+impl HealthService {
+    pub fn new(
+        health_repository: DynHealthRepository,
+        worker_health: SharedWorkerHealth,
+        clock: DynClock,
+    ) -> Self {
+        Self {
+            health_repository,
+            worker_health,
+            clock,
+        }
+    }
+
+    async fn database(&self) -> ComponentHealth {
+        match self.health_repository.ping().await {
+            Ok(()) => ComponentHealth::up(true, None),
+            Err(err) => {
+                error!("Database health check failed: {}", err);
+                ComponentHealth::down(true, "Database is unreachable".to_string())
+            }
+        }
+    }
+
+    /// Reads sent to the replica fail while it is down but writes keep
+    /// working, so it only degrades the service.
+    async fn replica(&self) -> Option<ComponentHealth> {
+        let health = match self.health_repository.ping_replica().await? {
+            Ok(()) => ComponentHealth::up(false, None),
+            Err(err) => {
+                error!("Read replica health check failed: {}", err);
+                ComponentHealth::down(false, "Read replica is unreachable".to_string())
+            }
+        };
+
+        Some(health)
+    }
+
+    async fn migrations(&self) -> ComponentHealth {
+        match self.health_repository.pending_migrations().await {
+            Ok(pending) if pending.is_empty() => ComponentHealth::up(true, None),
+            Ok(pending) => {
+                ComponentHealth::down(true, format!("Pending migrations: {}", pending.join(", ")))
+            }
+            Err(err) => {
+                error!("Migration health check failed: {}", err);
+                ComponentHealth::down(true, "Migration status is unknown".to_string())
+            }
+        }
+    }
+
+    fn worker(&self, state: &WorkerState) -> ComponentHealth {
+        let silent_for = self.clock.now() - state.last_seen_at;
+        let stale_after = state.interval * STALE_AFTER_INTERVALS;
+
+        if silent_for.to_std().unwrap_or_default() > stale_after {
+            return ComponentHealth::down(
+                false,
+                format!("No run for {} seconds", silent_for.num_seconds()),
+            );
+        }
+
+        match &state.last_error {
+            Some(err) => ComponentHealth::down(false, format!("Last run failed: {}", err)),
+            None => ComponentHealth::up(false, None),
+        }
+    }
+}
+
+#[async_trait]
+impl HealthServiceTrait for HealthService {
+    #[instrument(name = "HealthService::readiness", skip_all)]
+    async fn readiness(&self) -> ReadinessResponse {
+        let mut components = BTreeMap::new();
+
+        components.insert("database".to_string(), self.database().await);
+        components.insert("migrations".to_string(), self.migrations().await);
+
+        if let Some(replica) = self.replica().await {
+            components.insert("database_replica".to_string(), replica);
+        }
+
+        for (name, state) in self.worker_health.workers() {
+            components.insert(name.to_string(), self.worker(&state));
+        }
+
+        ReadinessResponse::new(components)
+    }
+}
@@ -0,0 +1,381 @@
+use async_trait::async_trait;
+use tracing::{error, info, instrument};
+use uuid::Uuid;
+
+use crate::{
+    abstract_trait::{
+        blob_store::DynBlobStore,
+        kyc::{DynKycRepository, KycServiceTrait},
+        user::DynUserRepository,
+    },
+    domain::{
+        request::{
+            kyc::{
+                kyc_document_content_type, kyc_document_extension, CreateKycSubmissionRequest,
+                RecordKycSubmissionEvent, ReviewKycSubmissionRequest, KYC_SUBMISSION_STATUSES,
+            },
+            limit::KYC_LEVEL_VERIFIED,
+        },
+        response::{
+            kyc::{KycDocumentFile, KycSubmissionResponse},
+            ApiResponse, ErrorResponse,
+        },
+    },
+    entities::kyc_submissions,
+    utils::errors::AppError,
+};
+
+pub struct KycService {
+    kyc_repository: DynKycRepository,
+    user_repository: DynUserRepository,
+    blob_store: DynBlobStore,
+}
+
+/// Status a submission must be in before it can move to `to_status`.
+fn required_status(to_status: &str) -> &'static str {
+    match to_status {
+        "in_review" => "submitted",
+        _ => "in_review",
+    }
+}
+
+impl KycService {
+    pub fn new(
+        kyc_repository: DynKycRepository,
+        user_repository: DynUserRepository,
+        blob_store: DynBlobStore,
+    ) -> Self {
+        Self {
+            kyc_repository,
+            user_repository,
+            blob_store,
+        }
+    }
+
+    async fn find_submission(&self, id: i32) -> Result<kyc_submissions::Model, ErrorResponse> {
+        self.kyc_repository
+            .find_by_id(id)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?
+            .ok_or_else(|| {
+                ErrorResponse::from(AppError::NotFound(format!(
+                    "KYC submission with id {} not found",
+                    id
+                )))
+            })
+    }
+
+    /// Removes stored documents of a submission that was not recorded.
+    async fn discard_documents(&self, keys: &[String]) {
+        for key in keys {
+            if let Err(e) = self.blob_store.delete(key).await {
+                error!("Failed to remove orphaned KYC document {}: {}", key, e);
+            }
+        }
+    }
+
+    async fn record_event(&self, input: RecordKycSubmissionEvent) {
+        if let Err(e) = self.kyc_repository.record_event(&input).await {
+            error!(
+                "Failed to record {} event of KYC submission {}: {}",
+                input.to_status, input.kyc_submission_id, e
+            );
+        }
+    }
+}
+
+#[async_trait]
+impl KycServiceTrait for KycService {
+    #[instrument(name = "KycService::create_submission", skip_all)]
+    async fn create_submission(
+        &self,
+        input: &CreateKycSubmissionRequest,
+    ) -> Result<ApiResponse<KycSubmissionResponse>, ErrorResponse> {
+        if let Err(validation_err) = input.validate() {
+            error!("Validation failed for KYC submission: {}", validation_err);
+            return Err(ErrorResponse::from(AppError::ValidationError(
+                validation_err,
+            )));
+        }
+
+        let user = self
+            .user_repository
+            .find_by_id(input.user_id)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?
+            .ok_or_else(|| {
+                ErrorResponse::from(AppError::NotFound(format!(
+                    "User with id {} not found",
+                    input.user_id
+                )))
+            })?;
+
+        if user.kyc_level >= KYC_LEVEL_VERIFIED {
+            return Err(ErrorResponse::from(AppError::ValidationError(
+                "User is already verified".to_string(),
+            )));
+        }
+
+        if let Some(open) = self
+            .kyc_repository
+            .find_open_by_user(input.user_id)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?
+        {
+            return Err(ErrorResponse::from(AppError::ValidationError(format!(
+                "KYC submission {} is still {}",
+                open.kyc_submission_id, open.status
+            ))));
+        }
+
+        let folder = format!("kyc/{}/{}", input.user_id, Uuid::new_v4().simple());
+        let mut stored = Vec::new();
+
+        for (name, document) in [("id_card", &input.id_card), ("selfie", &input.selfie)] {
+            let extension = kyc_document_extension(&document.content_type).unwrap_or("bin");
+            let key = format!("{}/{}.{}", folder, name, extension);
+
+            if let Err(e) = self.blob_store.put(&key, &document.bytes).await {
+                error!(
+                    "Failed to store KYC {} of user {}: {}",
+                    name, input.user_id, e
+                );
+                self.discard_documents(&stored).await;
+                return Err(ErrorResponse::from(e));
+            }
+
+            stored.push(key);
+        }
+
+        let submission = match self
+            .kyc_repository
+            .create_submission(input.user_id, KYC_LEVEL_VERIFIED, &stored[0], &stored[1])
+            .await
+        {
+            Ok(submission) => submission,
+            Err(e) => {
+                error!(
+                    "Failed to record KYC submission of user {}: {}",
+                    input.user_id, e
+                );
+                self.discard_documents(&stored).await;
+                return Err(ErrorResponse::from(AppError::from(e)));
+            }
+        };
+
+        self.record_event(RecordKycSubmissionEvent {
+            kyc_submission_id: submission.kyc_submission_id,
+            actor_id: input.user_id,
+            from_status: None,
+            to_status: submission.status.clone(),
+            note: None,
+        })
+        .await;
+
+        info!(
+            "KYC submission {} created for user {}",
+            submission.kyc_submission_id, input.user_id
+        );
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "KYC documents submitted for review".to_string(),
+            data: KycSubmissionResponse::from(submission),
+        })
+    }
+
+    #[instrument(name = "KycService::get_my_submissions", skip_all)]
+    async fn get_my_submissions(
+        &self,
+        user_id: i32,
+    ) -> Result<ApiResponse<Vec<KycSubmissionResponse>>, ErrorResponse> {
+        let submissions = self
+            .kyc_repository
+            .find_by_user(user_id)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?;
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "KYC submissions retrieved successfully".to_string(),
+            data: submissions
+                .into_iter()
+                .map(KycSubmissionResponse::from)
+                .collect(),
+        })
+    }
+
+    #[instrument(name = "KycService::get_submissions", skip_all)]
+    async fn get_submissions(
+        &self,
+        status: Option<String>,
+    ) -> Result<ApiResponse<Vec<KycSubmissionResponse>>, ErrorResponse> {
+        if let Some(status) = &status {
+            if !KYC_SUBMISSION_STATUSES.contains(&status.as_str()) {
+                return Err(ErrorResponse::from(AppError::ValidationError(format!(
+                    "Status must be one of {}",
+                    KYC_SUBMISSION_STATUSES.join(", ")
+                ))));
+            }
+        }
+
+        let submissions = self
+            .kyc_repository
+            .find_by_status(status)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?;
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "KYC submissions retrieved successfully".to_string(),
+            data: submissions
+                .into_iter()
+                .map(KycSubmissionResponse::from)
+                .collect(),
+        })
+    }
+
+    #[instrument(name = "KycService::get_document", skip_all)]
+    async fn get_document(
+        &self,
+        id: i32,
+        document: &str,
+    ) -> Result<KycDocumentFile, ErrorResponse> {
+        let submission = self.find_submission(id).await?;
+
+        let key = match document {
+            "id_card" => submission.id_card_key,
+            "selfie" => submission.selfie_key,
+            other => {
+                return Err(ErrorResponse::from(AppError::NotFound(format!(
+                    "KYC document {} not found",
+                    other
+                ))))
+            }
+        };
+
+        let bytes = self
+            .blob_store
+            .get(&key)
+            .await
+            .map_err(ErrorResponse::from)?;
+
+        Ok(KycDocumentFile {
+            content_type: kyc_document_content_type(&key).to_string(),
+            bytes,
+        })
+    }
+
+    #[instrument(name = "KycService::review_submission", skip_all)]
+    async fn review_submission(
+        &self,
+        input: &ReviewKycSubmissionRequest,
+    ) -> Result<ApiResponse<KycSubmissionResponse>, ErrorResponse> {
+        if let Err(validation_err) = input.validate() {
+            error!("Validation failed for KYC review: {}", validation_err);
+            return Err(ErrorResponse::from(AppError::ValidationError(
+                validation_err,
+            )));
+        }
+
+        let submission = self.find_submission(input.kyc_submission_id).await?;
+        let from_status = required_status(&input.status);
+
+        let transitioned = submission.status == from_status
+            && self
+                .kyc_repository
+                .transition_status(
+                    submission.kyc_submission_id,
+                    from_status,
+                    &input.status,
+                    input.reviewer_id,
+                    input.note.clone(),
+                )
+                .await
+                .map_err(AppError::from)
+                .map_err(ErrorResponse::from)?;
+
+        if !transitioned {
+            return Err(ErrorResponse::from(AppError::ValidationError(format!(
+                "KYC submission {} is {} and cannot move to {}",
+                submission.kyc_submission_id, submission.status, input.status
+            ))));
+        }
+
+        if input.status == "approved" {
+            // Approval only ever raises the tier
+            let user = self
+                .user_repository
+                .find_by_id(submission.user_id)
+                .await
+                .map_err(AppError::from)
+                .map_err(ErrorResponse::from)?;
+
+            let current_level = user.map_or(0, |user| user.kyc_level);
+
+            if submission.requested_level > current_level {
+                if let Err(e) = self
+                    .user_repository
+                    .update_kyc_level(submission.user_id, submission.requested_level)
+                    .await
+                {
+                    error!(
+                        "Failed to raise KYC level of user {}: {}",
+                        submission.user_id, e
+                    );
+
+                    if let Err(rb_err) = self
+                        .kyc_repository
+                        .transition_status(
+                            submission.kyc_submission_id,
+                            "approved",
+                            from_status,
+                            input.reviewer_id,
+                            None,
+                        )
+                        .await
+                    {
+                        error!(
+                            "Failed to reopen KYC submission {}: {}",
+                            submission.kyc_submission_id, rb_err
+                        );
+                    }
+
+                    return Err(ErrorResponse::from(AppError::from(e)));
+                }
+            }
+
+            info!(
+                "User {} raised to KYC level {}",
+                submission.user_id, submission.requested_level
+            );
+        }
+
+        self.record_event(RecordKycSubmissionEvent {
+            kyc_submission_id: submission.kyc_submission_id,
+            actor_id: input.reviewer_id,
+            from_status: Some(from_status.to_string()),
+            to_status: input.status.clone(),
+            note: input.note.clone(),
+        })
+        .await;
+
+        let submission = self.find_submission(submission.kyc_submission_id).await?;
+
+        info!(
+            "KYC submission {} moved to {} by {}",
+            submission.kyc_submission_id, submission.status, input.reviewer_id
+        );
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: format!("KYC submission {}", submission.status.replace('_', " ")),
+            data: KycSubmissionResponse::from(submission),
+        })
+    }
+}
@@ -0,0 +1,237 @@
+use async_trait::async_trait;
+use chrono::{Datelike, NaiveDateTime};
+use tracing::{error, info, instrument};
+
+use crate::{
+    abstract_trait::{
+        clock::DynClock,
+        limit::{DynLimitRepository, LimitServiceTrait},
+        saldo::DynSaldoRepository,
+        user::DynUserRepository,
+    },
+    domain::{
+        request::limit::CheckLimitRequest,
+        response::{
+            limit::{TransactionLimitResponse, UserLimitsResponse},
+            ApiResponse, ErrorResponse,
+        },
+    },
+    entities::{transaction_limits, users},
+    utils::errors::AppError,
+};
+
+pub struct LimitService {
+    limit_repository: DynLimitRepository,
+    user_repository: DynUserRepository,
+    saldo_repository: DynSaldoRepository,
+    clock: DynClock,
+}
+
+impl LimitService {
+    pub fn new(
+        limit_repository: DynLimitRepository,
+        user_repository: DynUserRepository,
+        saldo_repository: DynSaldoRepository,
+        clock: DynClock,
+    ) -> Self {
+        Self {
+            limit_repository,
+            user_repository,
+            saldo_repository,
+            clock,
+        }
+    }
+
+    async fn find_user(&self, user_id: i32) -> Result<users::Model, ErrorResponse> {
+        self.user_repository
+            .find_by_id(user_id)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?
+            .ok_or_else(|| {
+                ErrorResponse::from(AppError::NotFound(format!(
+                    "User with id {} not found",
+                    user_id
+                )))
+            })
+    }
+
+    /// Limits of the user's tier for the transaction type, `None` when the
+    /// tier has no limits for it.
+    async fn find_limit(
+        &self,
+        input: &CheckLimitRequest,
+    ) -> Result<Option<(users::Model, transaction_limits::Model)>, ErrorResponse> {
+        let user = self.find_user(input.user_id).await?;
+
+        let limit = self
+            .limit_repository
+            .find_limit(user.kyc_level, &input.transaction_type)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?;
+
+        Ok(limit.map(|limit| (user, limit)))
+    }
+
+    /// Fails when the amount on top of what the user already moved since
+    /// `since` goes over `allowed`.
+    async fn check_cumulative(
+        &self,
+        input: &CheckLimitRequest,
+        kyc_level: i32,
+        period: &str,
+        since: NaiveDateTime,
+        allowed: i32,
+    ) -> Result<(), ErrorResponse> {
+        let used = self
+            .limit_repository
+            .sum_user_amount_since(&input.transaction_type, input.user_id, since)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?;
+
+        if used + input.amount as i64 > allowed as i64 {
+            error!(
+                "User {} would exceed the {} {} limit: used {}, requested {}",
+                input.user_id, period, input.transaction_type, used, input.amount
+            );
+            return Err(ErrorResponse::from(AppError::LimitExceeded(format!(
+                "{} {} limit of {} for KYC level {} would be exceeded, {} already used",
+                period, input.transaction_type, allowed, kyc_level, used
+            ))));
+        }
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl LimitServiceTrait for LimitService {
+    #[instrument(name = "LimitService::get_limits", skip_all)]
+    async fn get_limits(
+        &self,
+    ) -> Result<ApiResponse<Vec<TransactionLimitResponse>>, ErrorResponse> {
+        let limits = self
+            .limit_repository
+            .find_all()
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?;
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "Transaction limits retrieved successfully".to_string(),
+            data: limits
+                .into_iter()
+                .map(TransactionLimitResponse::from)
+                .collect(),
+        })
+    }
+
+    #[instrument(name = "LimitService::get_user_limits", skip_all)]
+    async fn get_user_limits(
+        &self,
+        user_id: i32,
+    ) -> Result<ApiResponse<UserLimitsResponse>, ErrorResponse> {
+        let user = self.find_user(user_id).await?;
+
+        let limits = self
+            .limit_repository
+            .find_by_level(user.kyc_level)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?;
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "Transaction limits retrieved successfully".to_string(),
+            data: UserLimitsResponse {
+                user_id: user.user_id,
+                kyc_level: user.kyc_level,
+                limits: limits
+                    .into_iter()
+                    .map(TransactionLimitResponse::from)
+                    .collect(),
+            },
+        })
+    }
+
+    #[instrument(name = "LimitService::check_transaction_limits", skip_all)]
+    async fn check_transaction_limits(
+        &self,
+        input: &CheckLimitRequest,
+    ) -> Result<(), ErrorResponse> {
+        let Some((user, limit)) = self.find_limit(input).await? else {
+            return Ok(());
+        };
+
+        if let Some(max_amount) = limit.max_transaction_amount {
+            if input.amount > max_amount {
+                error!(
+                    "User {} requested a {} of {} over the limit of {}",
+                    input.user_id, input.transaction_type, input.amount, max_amount
+                );
+                return Err(ErrorResponse::from(AppError::LimitExceeded(format!(
+                    "Amount {} is over the single {} limit of {} for KYC level {}",
+                    input.amount, input.transaction_type, max_amount, user.kyc_level
+                ))));
+            }
+        }
+
+        let today = self.clock.now().date();
+
+        if let Some(daily_amount) = limit.daily_amount {
+            let since = today.and_hms_opt(0, 0, 0).unwrap();
+
+            self.check_cumulative(input, user.kyc_level, "Daily", since, daily_amount)
+                .await?;
+        }
+
+        if let Some(monthly_amount) = limit.monthly_amount {
+            let since = today.with_day(1).unwrap().and_hms_opt(0, 0, 0).unwrap();
+
+            self.check_cumulative(input, user.kyc_level, "Monthly", since, monthly_amount)
+                .await?;
+        }
+
+        info!(
+            "{} of {} for user {} is within the limits of KYC level {}",
+            input.transaction_type, input.amount, input.user_id, user.kyc_level
+        );
+
+        Ok(())
+    }
+
+    #[instrument(name = "LimitService::check_balance_limit", skip_all)]
+    async fn check_balance_limit(&self, input: &CheckLimitRequest) -> Result<(), ErrorResponse> {
+        let Some((user, limit)) = self.find_limit(input).await? else {
+            return Ok(());
+        };
+
+        let Some(max_balance) = limit.max_balance else {
+            return Ok(());
+        };
+
+        let balance = self
+            .saldo_repository
+            .find_by_user_id(input.user_id)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?
+            .map_or(0, |saldo| saldo.total_balance);
+
+        if balance as i64 + input.amount as i64 > max_balance as i64 {
+            error!(
+                "Crediting {} to user {} would take the balance {} over {}",
+                input.amount, input.user_id, balance, max_balance
+            );
+            return Err(ErrorResponse::from(AppError::LimitExceeded(format!(
+                "Receiving {} would take the balance of user {} over the maximum of {} for KYC level {}",
+                input.amount, input.user_id, max_balance, user.kyc_level
+            ))));
+        }
+
+        Ok(())
+    }
+}
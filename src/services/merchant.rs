@@ -0,0 +1,115 @@
+use async_trait::async_trait;
+use tracing::{error, info, instrument};
+
+use crate::{
+    abstract_trait::{
+        merchant::{DynMerchantRepository, MerchantServiceTrait},
+        user::DynUserRepository,
+    },
+    domain::{
+        request::merchant::CreateMerchantRequest,
+        response::{merchant::MerchantResponse, ApiResponse, ErrorResponse},
+    },
+    utils::errors::AppError,
+};
+
+pub struct MerchantService {
+    merchant_repository: DynMerchantRepository,
+    user_repository: DynUserRepository,
+}
+
+impl MerchantService {
+    pub fn new(
+        merchant_repository: DynMerchantRepository,
+        user_repository: DynUserRepository,
+    ) -> Self {
+        Self {
+            merchant_repository,
+            user_repository,
+        }
+    }
+}
+
+#[async_trait]
+impl MerchantServiceTrait for MerchantService {
+    #[instrument(name = "MerchantService::create_merchant", skip_all)]
+    async fn create_merchant(
+        &self,
+        input: &CreateMerchantRequest,
+    ) -> Result<ApiResponse<MerchantResponse>, ErrorResponse> {
+        if let Err(validation_err) = input.validate() {
+            error!("Validation failed for merchant create: {}", validation_err);
+            return Err(ErrorResponse::from(AppError::ValidationError(
+                validation_err,
+            )));
+        }
+
+        self.user_repository
+            .find_by_id(input.user_id)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?
+            .ok_or_else(|| {
+                ErrorResponse::from(AppError::NotFound(format!(
+                    "User with id {} not found",
+                    input.user_id
+                )))
+            })?;
+
+        let existing = self
+            .merchant_repository
+            .find_by_user(input.user_id)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?;
+
+        if existing.is_some() {
+            return Err(ErrorResponse::from(AppError::ValidationError(
+                "User is already registered as a merchant".to_string(),
+            )));
+        }
+
+        let merchant = self
+            .merchant_repository
+            .create(input)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?;
+
+        info!(
+            "Merchant {} registered for user {}",
+            merchant.merchant_id, input.user_id
+        );
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "Merchant created successfully".to_string(),
+            data: MerchantResponse::from(merchant),
+        })
+    }
+
+    #[instrument(name = "MerchantService::get_merchant", skip_all)]
+    async fn get_merchant(
+        &self,
+        user_id: i32,
+    ) -> Result<ApiResponse<MerchantResponse>, ErrorResponse> {
+        let merchant = self
+            .merchant_repository
+            .find_by_user(user_id)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?
+            .ok_or_else(|| {
+                ErrorResponse::from(AppError::NotFound(format!(
+                    "Merchant for user {} not found",
+                    user_id
+                )))
+            })?;
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "Merchant retrieved successfully".to_string(),
+            data: MerchantResponse::from(merchant),
+        })
+    }
+}
@@ -0,0 +1,377 @@
+use async_trait::async_trait;
+use tracing::{error, info, instrument};
+
+use crate::{
+    abstract_trait::{
+        clock::DynClock,
+        merchant::DynMerchantRepository,
+        payment_intent::{DynPaymentIntentRepository, PaymentIntentServiceTrait},
+        transfer::DynTransferService,
+    },
+    domain::{
+        request::{
+            limit::MinimumAmounts, payment_intent::CreatePaymentIntentRequest,
+            transfer::CreateTransferRequest,
+        },
+        response::{payment_intent::PaymentIntentResponse, ApiResponse, ErrorKind, ErrorResponse},
+    },
+    entities::{merchants, payment_intents},
+    utils::errors::AppError,
+};
+
+pub struct PaymentIntentService {
+    payment_intent_repository: DynPaymentIntentRepository,
+    merchant_repository: DynMerchantRepository,
+    transfer_service: DynTransferService,
+    clock: DynClock,
+    minimum_amounts: MinimumAmounts,
+}
+
+impl PaymentIntentService {
+    pub fn new(
+        payment_intent_repository: DynPaymentIntentRepository,
+        merchant_repository: DynMerchantRepository,
+        transfer_service: DynTransferService,
+        clock: DynClock,
+        minimum_amounts: MinimumAmounts,
+    ) -> Self {
+        Self {
+            payment_intent_repository,
+            merchant_repository,
+            transfer_service,
+            clock,
+            minimum_amounts,
+        }
+    }
+
+    async fn merchant_for_user(&self, user_id: i32) -> Result<merchants::Model, ErrorResponse> {
+        self.merchant_repository
+            .find_by_user(user_id)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?
+            .ok_or_else(|| {
+                ErrorResponse::from(AppError::NotFound(format!(
+                    "Merchant for user {} not found",
+                    user_id
+                )))
+            })
+    }
+
+    /// Loads an intent, first expiring it if its deadline has passed while it
+    /// was still awaiting confirmation.
+    async fn load_payment_intent(&self, id: i32) -> Result<payment_intents::Model, ErrorResponse> {
+        let payment_intent = self
+            .payment_intent_repository
+            .find_by_id(id)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?
+            .ok_or_else(|| {
+                ErrorResponse::from(AppError::NotFound(format!(
+                    "Payment intent with id {} not found",
+                    id
+                )))
+            })?;
+
+        if payment_intent.status != "requires_confirmation"
+            || payment_intent.expires_at > self.clock.now()
+        {
+            return Ok(payment_intent);
+        }
+
+        let expired = self
+            .payment_intent_repository
+            .transition_status(id, "requires_confirmation", "expired")
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?;
+
+        if expired {
+            info!("Payment intent {} expired", id);
+        }
+
+        self.payment_intent_repository
+            .find_by_id(id)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?
+            .ok_or_else(|| {
+                ErrorResponse::from(AppError::NotFound(format!(
+                    "Payment intent with id {} not found",
+                    id
+                )))
+            })
+    }
+
+    fn ensure_awaiting_confirmation(
+        payment_intent: &payment_intents::Model,
+    ) -> Result<(), ErrorResponse> {
+        if payment_intent.status == "requires_confirmation" {
+            return Ok(());
+        }
+
+        Err(ErrorResponse::from(AppError::ValidationError(format!(
+            "Payment intent is {}",
+            payment_intent.status
+        ))))
+    }
+}
+
+#[async_trait]
+impl PaymentIntentServiceTrait for PaymentIntentService {
+    #[instrument(name = "PaymentIntentService::create_payment_intent", skip_all)]
+    async fn create_payment_intent(
+        &self,
+        input: &CreatePaymentIntentRequest,
+    ) -> Result<ApiResponse<PaymentIntentResponse>, ErrorResponse> {
+        if let Err(validation_err) = input.validate(self.minimum_amounts.transfer) {
+            error!(
+                "Validation failed for payment intent create: {}",
+                validation_err
+            );
+            return Err(ErrorResponse::from(AppError::ValidationError(
+                validation_err,
+            )));
+        }
+
+        if input.expires_at.naive_utc() <= self.clock.now() {
+            return Err(ErrorResponse::from(AppError::ValidationError(
+                "Expiry must be in the future".to_string(),
+            )));
+        }
+
+        let merchant = self.merchant_for_user(input.user_id).await?;
+
+        let existing = self
+            .payment_intent_repository
+            .find_by_reference(merchant.merchant_id, input.reference.trim())
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?;
+
+        if existing.is_some() {
+            return Err(ErrorResponse::from(AppError::ValidationError(format!(
+                "Payment intent with reference {} already exists",
+                input.reference.trim()
+            ))));
+        }
+
+        let payment_intent = self
+            .payment_intent_repository
+            .create(input, merchant.merchant_id)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?;
+
+        info!(
+            "Payment intent {} created by merchant {} for {} {}",
+            payment_intent.payment_intent_id,
+            merchant.merchant_id,
+            payment_intent.amount,
+            payment_intent.currency
+        );
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "Payment intent created successfully".to_string(),
+            data: PaymentIntentResponse::from(payment_intent),
+        })
+    }
+
+    #[instrument(name = "PaymentIntentService::get_payment_intents", skip_all)]
+    async fn get_payment_intents(
+        &self,
+        user_id: i32,
+    ) -> Result<ApiResponse<Vec<PaymentIntentResponse>>, ErrorResponse> {
+        let merchant = self.merchant_for_user(user_id).await?;
+
+        let payment_intents = self
+            .payment_intent_repository
+            .find_by_merchant(merchant.merchant_id)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?;
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "Payment intents retrieved successfully".to_string(),
+            data: payment_intents
+                .into_iter()
+                .map(PaymentIntentResponse::from)
+                .collect(),
+        })
+    }
+
+    #[instrument(name = "PaymentIntentService::get_payment_intent", skip_all)]
+    async fn get_payment_intent(
+        &self,
+        id: i32,
+    ) -> Result<ApiResponse<PaymentIntentResponse>, ErrorResponse> {
+        let payment_intent = self.load_payment_intent(id).await?;
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "Payment intent retrieved successfully".to_string(),
+            data: PaymentIntentResponse::from(payment_intent),
+        })
+    }
+
+    #[instrument(name = "PaymentIntentService::confirm_payment_intent", skip_all)]
+    async fn confirm_payment_intent(
+        &self,
+        id: i32,
+        customer_id: i32,
+    ) -> Result<ApiResponse<PaymentIntentResponse>, ErrorResponse> {
+        let payment_intent = self.load_payment_intent(id).await?;
+        Self::ensure_awaiting_confirmation(&payment_intent)?;
+
+        let merchant = self
+            .merchant_repository
+            .find_by_id(payment_intent.merchant_id)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?
+            .ok_or_else(|| {
+                ErrorResponse::from(AppError::NotFound(format!(
+                    "Merchant with id {} not found",
+                    payment_intent.merchant_id
+                )))
+            })?;
+
+        if merchant.user_id == customer_id {
+            return Err(ErrorResponse::from(AppError::ValidationError(
+                "Merchants cannot pay their own payment intents".to_string(),
+            )));
+        }
+
+        let claimed = self
+            .payment_intent_repository
+            .transition_status(id, "requires_confirmation", "processing")
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?;
+
+        if !claimed {
+            return Err(ErrorResponse::from(AppError::ValidationError(
+                "Payment intent is no longer awaiting confirmation".to_string(),
+            )));
+        }
+
+        let transfer = match self
+            .transfer_service
+            .create_transfer(&CreateTransferRequest {
+                transfer_from: customer_id,
+                transfer_to: merchant.user_id,
+                transfer_amount: payment_intent.amount,
+                beneficiary_id: None,
+                device_id: None,
+                merchant_payment: true,
+            })
+            .await
+        {
+            Ok(transfer) => transfer,
+            Err(err) => {
+                if let ErrorKind::HeldForReview(review_id) = err.kind {
+                    // No money has moved yet, but it will if the review is
+                    // approved, so the intent stays processing until then.
+                    match self
+                        .payment_intent_repository
+                        .hold_for_review(id, customer_id, review_id)
+                        .await
+                    {
+                        Ok(_) => info!(
+                            "Payment intent {} from user {} held under fraud review {}",
+                            id, customer_id, review_id
+                        ),
+                        Err(db_err) => error!(
+                            "Failed to link payment intent {} to fraud review {}: {}",
+                            id, review_id, db_err
+                        ),
+                    }
+
+                    return Err(err);
+                }
+
+                error!(
+                    "Transfer for payment intent {} from user {} failed: {}",
+                    id, customer_id, err.message
+                );
+
+                if let Err(db_err) = self
+                    .payment_intent_repository
+                    .transition_status(id, "processing", "requires_confirmation")
+                    .await
+                {
+                    error!("Failed to reopen payment intent {}: {}", id, db_err);
+                }
+
+                return Err(err);
+            }
+        };
+
+        let payment_intent = self
+            .payment_intent_repository
+            .record_success(id, customer_id, transfer.data.transfer_id)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?;
+
+        info!(
+            "Payment intent {} confirmed by user {} with transfer {}",
+            id, customer_id, transfer.data.transfer_id
+        );
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "Payment intent confirmed successfully".to_string(),
+            data: PaymentIntentResponse::from(payment_intent),
+        })
+    }
+
+    #[instrument(name = "PaymentIntentService::cancel_payment_intent", skip_all)]
+    async fn cancel_payment_intent(
+        &self,
+        id: i32,
+        user_id: i32,
+    ) -> Result<ApiResponse<PaymentIntentResponse>, ErrorResponse> {
+        let merchant = self.merchant_for_user(user_id).await?;
+
+        let payment_intent = self.load_payment_intent(id).await?;
+
+        if payment_intent.merchant_id != merchant.merchant_id {
+            return Err(ErrorResponse::from(AppError::NotFound(format!(
+                "Payment intent with id {} not found",
+                id
+            ))));
+        }
+
+        Self::ensure_awaiting_confirmation(&payment_intent)?;
+
+        let canceled = self
+            .payment_intent_repository
+            .transition_status(id, "requires_confirmation", "canceled")
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?;
+
+        if !canceled {
+            return Err(ErrorResponse::from(AppError::ValidationError(
+                "Payment intent is no longer awaiting confirmation".to_string(),
+            )));
+        }
+
+        info!(
+            "Payment intent {} canceled by merchant {}",
+            id, merchant.merchant_id
+        );
+
+        let payment_intent = self.load_payment_intent(id).await?;
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "Payment intent canceled successfully".to_string(),
+            data: PaymentIntentResponse::from(payment_intent),
+        })
+    }
+}
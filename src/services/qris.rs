@@ -0,0 +1,325 @@
+use async_trait::async_trait;
+use tracing::{error, info, instrument};
+use uuid::Uuid;
+
+use crate::{
+    abstract_trait::{
+        merchant::DynMerchantRepository,
+        qris::{DynQrisRepository, QrisServiceTrait},
+        transfer::DynTransferService,
+        user::DynUserRepository,
+    },
+    domain::{
+        request::{
+            limit::MinimumAmounts,
+            qris::{CreateQrisRequest, PayQrisRequest},
+            transfer::CreateTransferRequest,
+        },
+        response::{
+            qris::{QrisImage, QrisResponse},
+            ApiResponse, ErrorKind, ErrorResponse,
+        },
+    },
+    utils::{
+        errors::AppError,
+        qr_image::{render_png, render_svg},
+        qris::{build_dynamic_qris, parse_qris, QrisMerchant},
+    },
+};
+
+const MERCHANT_CITY: &str = "JAKARTA";
+const MERCHANT_POSTAL_CODE: &str = "10110";
+const PNG_SCALE: usize = 8;
+
+pub struct QrisService {
+    qris_repository: DynQrisRepository,
+    merchant_repository: DynMerchantRepository,
+    user_repository: DynUserRepository,
+    transfer_service: DynTransferService,
+    minimum_amounts: MinimumAmounts,
+}
+
+impl QrisService {
+    pub fn new(
+        qris_repository: DynQrisRepository,
+        merchant_repository: DynMerchantRepository,
+        user_repository: DynUserRepository,
+        transfer_service: DynTransferService,
+        minimum_amounts: MinimumAmounts,
+    ) -> Self {
+        Self {
+            qris_repository,
+            merchant_repository,
+            user_repository,
+            transfer_service,
+            minimum_amounts,
+        }
+    }
+}
+
+#[async_trait]
+impl QrisServiceTrait for QrisService {
+    #[instrument(name = "QrisService::create_qris", skip_all)]
+    async fn create_qris(
+        &self,
+        input: &CreateQrisRequest,
+    ) -> Result<ApiResponse<QrisResponse>, ErrorResponse> {
+        if let Err(validation_err) = input.validate(self.minimum_amounts.transfer) {
+            error!("Validation failed for QRIS create: {}", validation_err);
+            return Err(ErrorResponse::from(AppError::ValidationError(
+                validation_err,
+            )));
+        }
+
+        let merchant = self
+            .merchant_repository
+            .find_by_user(input.user_id)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?
+            .ok_or_else(|| {
+                ErrorResponse::from(AppError::NotFound(format!(
+                    "Merchant for user {} not found",
+                    input.user_id
+                )))
+            })?;
+
+        let user = self
+            .user_repository
+            .find_by_id(input.user_id)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?
+            .ok_or_else(|| {
+                ErrorResponse::from(AppError::NotFound(format!(
+                    "User with id {} not found",
+                    input.user_id
+                )))
+            })?;
+
+        let reference = format!(
+            "QR{}",
+            Uuid::new_v4().simple().to_string()[..20].to_uppercase()
+        );
+
+        let payload = build_dynamic_qris(
+            &QrisMerchant {
+                merchant_pan: user.noc_transfer.clone(),
+                merchant_id: format!("ID{:0>13}", merchant.merchant_id),
+                name: merchant.name.clone(),
+                city: MERCHANT_CITY.to_string(),
+                postal_code: MERCHANT_POSTAL_CODE.to_string(),
+            },
+            input.amount,
+            &reference,
+        )
+        .map_err(|e| ErrorResponse::from(AppError::ValidationError(e)))?;
+
+        let qris_payment = self
+            .qris_repository
+            .create(input, merchant.merchant_id, &reference, &payload)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?;
+
+        info!(
+            "QRIS {} issued for merchant {} amount {}",
+            reference, merchant.merchant_id, input.amount
+        );
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "QRIS created successfully".to_string(),
+            data: QrisResponse::from(qris_payment),
+        })
+    }
+
+    #[instrument(name = "QrisService::get_qris_image", skip_all)]
+    async fn get_qris_image(
+        &self,
+        reference: &str,
+        user_id: i32,
+        format: &str,
+    ) -> Result<QrisImage, ErrorResponse> {
+        let qris_payment = self
+            .qris_repository
+            .find_by_reference(reference)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?
+            .filter(|qris_payment| qris_payment.user_id == user_id)
+            .ok_or_else(|| {
+                ErrorResponse::from(AppError::NotFound(format!(
+                    "QRIS with reference {} not found",
+                    reference
+                )))
+            })?;
+
+        let image = match format {
+            "png" => render_png(&qris_payment.payload, PNG_SCALE).map(|body| QrisImage {
+                content_type: "image/png".to_string(),
+                body,
+            }),
+            "svg" => render_svg(&qris_payment.payload).map(|svg| QrisImage {
+                content_type: "image/svg+xml".to_string(),
+                body: svg.into_bytes(),
+            }),
+            _ => {
+                return Err(ErrorResponse::from(AppError::ValidationError(
+                    "Image format must be png or svg".to_string(),
+                )))
+            }
+        };
+
+        image.map_err(|e| {
+            error!("Failed to render QRIS {}: {}", reference, e);
+            ErrorResponse::from(AppError::ValidationError(e))
+        })
+    }
+
+    #[instrument(name = "QrisService::pay_qris", skip_all)]
+    async fn pay_qris(
+        &self,
+        input: &PayQrisRequest,
+    ) -> Result<ApiResponse<QrisResponse>, ErrorResponse> {
+        if let Err(validation_err) = input.validate() {
+            return Err(ErrorResponse::from(AppError::ValidationError(
+                validation_err,
+            )));
+        }
+
+        let decoded = parse_qris(&input.payload).map_err(|e| {
+            error!("Rejected QRIS payload from user {}: {}", input.payer_id, e);
+            ErrorResponse::from(AppError::ValidationError(e))
+        })?;
+
+        let reference = match (decoded.dynamic, decoded.reference) {
+            (true, Some(reference)) => reference,
+            _ => {
+                return Err(ErrorResponse::from(AppError::ValidationError(
+                    "Only dynamic QRIS issued by this gateway can be paid".to_string(),
+                )))
+            }
+        };
+
+        let qris_payment = self
+            .qris_repository
+            .find_by_reference(&reference)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?
+            .ok_or_else(|| {
+                ErrorResponse::from(AppError::NotFound(format!(
+                    "QRIS with reference {} not found",
+                    reference
+                )))
+            })?;
+
+        // Anything but the exact payload we issued has been altered, even
+        // when its CRC was recomputed.
+        if qris_payment.payload != input.payload.trim() {
+            return Err(ErrorResponse::from(AppError::ValidationError(
+                "Payload does not match the issued QRIS".to_string(),
+            )));
+        }
+
+        if qris_payment.merchant_id.is_none() {
+            return Err(ErrorResponse::from(AppError::ValidationError(
+                "QRIS was not issued by a registered merchant".to_string(),
+            )));
+        }
+
+        if qris_payment.user_id == input.payer_id {
+            return Err(ErrorResponse::from(AppError::ValidationError(
+                "Merchants cannot pay their own QRIS".to_string(),
+            )));
+        }
+
+        let claimed = self
+            .qris_repository
+            .transition_status(qris_payment.qris_payment_id, "pending", "processing")
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?;
+
+        if !claimed {
+            return Err(ErrorResponse::from(AppError::ValidationError(
+                "QRIS has already been paid".to_string(),
+            )));
+        }
+
+        let transfer = match self
+            .transfer_service
+            .create_transfer(&CreateTransferRequest {
+                transfer_from: input.payer_id,
+                transfer_to: qris_payment.user_id,
+                transfer_amount: qris_payment.amount,
+                beneficiary_id: None,
+                device_id: None,
+                merchant_payment: true,
+            })
+            .await
+        {
+            Ok(transfer) => transfer,
+            Err(err) => {
+                if let ErrorKind::HeldForReview(review_id) = err.kind {
+                    // No money has moved yet, but it will if the review is
+                    // approved, so the QRIS cannot be paid again meanwhile.
+                    match self
+                        .qris_repository
+                        .hold_for_review(qris_payment.qris_payment_id, input.payer_id, review_id)
+                        .await
+                    {
+                        Ok(_) => info!(
+                            "QRIS {} from user {} held under fraud review {}",
+                            reference, input.payer_id, review_id
+                        ),
+                        Err(db_err) => error!(
+                            "Failed to link QRIS {} to fraud review {}: {}",
+                            reference, review_id, db_err
+                        ),
+                    }
+
+                    return Err(err);
+                }
+
+                error!(
+                    "Transfer for QRIS {} from user {} failed: {}",
+                    reference, input.payer_id, err.message
+                );
+
+                if let Err(db_err) = self
+                    .qris_repository
+                    .transition_status(qris_payment.qris_payment_id, "processing", "pending")
+                    .await
+                {
+                    error!("Failed to reopen QRIS {}: {}", reference, db_err);
+                }
+
+                return Err(err);
+            }
+        };
+
+        let qris_payment = self
+            .qris_repository
+            .record_payment(
+                qris_payment.qris_payment_id,
+                input.payer_id,
+                transfer.data.transfer_id,
+            )
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?;
+
+        info!(
+            "QRIS {} paid by user {} with transfer {}",
+            reference, input.payer_id, transfer.data.transfer_id
+        );
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "QRIS paid successfully".to_string(),
+            data: QrisResponse::from(qris_payment),
+        })
+    }
+}
@@ -0,0 +1,253 @@
+use async_trait::async_trait;
+use tracing::{error, info, instrument};
+
+use crate::{
+    abstract_trait::{
+        merchant::DynMerchantRepository,
+        payment_intent::DynPaymentIntentRepository,
+        refund::{DynRefundRepository, RefundServiceTrait},
+        saldo::DynSaldoRepository,
+    },
+    domain::{
+        request::{refund::CreateRefundRequest, saldo::UpdateSaldoBalance},
+        response::{refund::RefundResponse, ApiResponse, ErrorResponse},
+    },
+    entities::payment_intents,
+    utils::{errors::AppError, metrics::record_insufficient_funds},
+};
+
+pub struct RefundService {
+    refund_repository: DynRefundRepository,
+    payment_intent_repository: DynPaymentIntentRepository,
+    merchant_repository: DynMerchantRepository,
+    saldo_repository: DynSaldoRepository,
+}
+
+impl RefundService {
+    pub fn new(
+        refund_repository: DynRefundRepository,
+        payment_intent_repository: DynPaymentIntentRepository,
+        merchant_repository: DynMerchantRepository,
+        saldo_repository: DynSaldoRepository,
+    ) -> Self {
+        Self {
+            refund_repository,
+            payment_intent_repository,
+            merchant_repository,
+            saldo_repository,
+        }
+    }
+
+    /// Loads a payment that belongs to the merchant owned by `user_id`.
+    async fn merchant_payment(
+        &self,
+        payment_intent_id: i32,
+        user_id: i32,
+    ) -> Result<payment_intents::Model, ErrorResponse> {
+        let not_found = || {
+            ErrorResponse::from(AppError::NotFound(format!(
+                "Payment with id {} not found",
+                payment_intent_id
+            )))
+        };
+
+        let merchant = self
+            .merchant_repository
+            .find_by_user(user_id)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?
+            .ok_or_else(not_found)?;
+
+        self.payment_intent_repository
+            .find_by_id(payment_intent_id)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?
+            .filter(|payment| payment.merchant_id == merchant.merchant_id)
+            .ok_or_else(not_found)
+    }
+
+    async fn adjust_balance(&self, user_id: i32, delta: i32) -> Result<(), ErrorResponse> {
+        let saldo = self
+            .saldo_repository
+            .find_by_user_id(user_id)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?
+            .ok_or_else(|| {
+                ErrorResponse::from(AppError::NotFound(format!(
+                    "Saldo with User id {} not found",
+                    user_id
+                )))
+            })?;
+
+        if saldo.total_balance + delta < 0 {
+            record_insufficient_funds("refund");
+            return Err(ErrorResponse::from(AppError::InsufficientBalance));
+        }
+
+        self.saldo_repository
+            .update_balance(&UpdateSaldoBalance {
+                user_id,
+                total_balance: saldo.total_balance + delta,
+            })
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?;
+
+        Ok(())
+    }
+
+    /// Debits the merchant and credits the customer, putting the merchant's
+    /// money back if the credit fails.
+    async fn move_funds(
+        &self,
+        merchant_user_id: i32,
+        customer_id: i32,
+        amount: i32,
+    ) -> Result<(), ErrorResponse> {
+        self.adjust_balance(merchant_user_id, -amount).await?;
+
+        if let Err(err) = self.adjust_balance(customer_id, amount).await {
+            if let Err(rollback_err) = self.adjust_balance(merchant_user_id, amount).await {
+                error!(
+                    "Failed to restore {} to merchant user {}: {}",
+                    amount, merchant_user_id, rollback_err.message
+                );
+            }
+
+            return Err(err);
+        }
+
+        Ok(())
+    }
+
+    /// Gives a reserved refund amount back to the payment.
+    async fn release(&self, payment: &payment_intents::Model, amount: i32) {
+        if let Err(db_err) = self
+            .payment_intent_repository
+            .release_refund(payment.payment_intent_id, amount)
+            .await
+        {
+            error!(
+                "Failed to release refund reservation on payment {}: {}",
+                payment.payment_intent_id, db_err
+            );
+        }
+    }
+}
+
+#[async_trait]
+impl RefundServiceTrait for RefundService {
+    #[instrument(name = "RefundService::create_refund", skip_all)]
+    async fn create_refund(
+        &self,
+        input: &CreateRefundRequest,
+    ) -> Result<ApiResponse<RefundResponse>, ErrorResponse> {
+        if let Err(validation_err) = input.validate() {
+            error!("Validation failed for refund create: {}", validation_err);
+            return Err(ErrorResponse::from(AppError::ValidationError(
+                validation_err,
+            )));
+        }
+
+        let payment = self
+            .merchant_payment(input.payment_intent_id, input.user_id)
+            .await?;
+
+        let customer_id = match (payment.status.as_str(), payment.customer_id) {
+            ("succeeded", Some(customer_id)) => customer_id,
+            _ => {
+                return Err(ErrorResponse::from(AppError::ValidationError(
+                    "Only succeeded payments can be refunded".to_string(),
+                )))
+            }
+        };
+
+        let reserved = self
+            .payment_intent_repository
+            .reserve_refund(payment.payment_intent_id, input.amount)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?;
+
+        if !reserved {
+            return Err(ErrorResponse::from(AppError::ValidationError(
+                "Refund amount exceeds the refundable amount".to_string(),
+            )));
+        }
+
+        let refund = match self.refund_repository.create(input).await {
+            Ok(refund) => refund,
+            Err(db_err) => {
+                self.release(&payment, input.amount).await;
+                return Err(ErrorResponse::from(AppError::from(db_err)));
+            }
+        };
+
+        if let Err(err) = self
+            .move_funds(input.user_id, customer_id, input.amount)
+            .await
+        {
+            error!(
+                "Refund {} of payment {} failed: {}",
+                refund.refund_id, payment.payment_intent_id, err.message
+            );
+
+            if let Err(db_err) = self
+                .refund_repository
+                .mark_failed(refund.refund_id, &err.message)
+                .await
+            {
+                error!(
+                    "Failed to mark refund {} failed: {}",
+                    refund.refund_id, db_err
+                );
+            }
+            self.release(&payment, input.amount).await;
+
+            return Err(err);
+        }
+
+        let refund = self
+            .refund_repository
+            .mark_succeeded(refund.refund_id)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?;
+
+        info!(
+            "Refunded {} of payment {} to user {}",
+            refund.amount, payment.payment_intent_id, customer_id
+        );
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "Refund created successfully".to_string(),
+            data: RefundResponse::from(refund),
+        })
+    }
+
+    #[instrument(name = "RefundService::get_refunds", skip_all)]
+    async fn get_refunds(
+        &self,
+        payment_intent_id: i32,
+        user_id: i32,
+    ) -> Result<ApiResponse<Vec<RefundResponse>>, ErrorResponse> {
+        let payment = self.merchant_payment(payment_intent_id, user_id).await?;
+
+        let refunds = self
+            .refund_repository
+            .find_by_payment_intent(payment.payment_intent_id)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?;
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "Refunds retrieved successfully".to_string(),
+            data: refunds.into_iter().map(RefundResponse::from).collect(),
+        })
+    }
+}
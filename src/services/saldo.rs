@@ -1,4 +1,4 @@
-use tracing::{error, info};
+use tracing::{error, info, instrument};
 
 use async_trait::async_trait;
 
@@ -30,6 +30,7 @@ impl SaldoService {
 
 #[async_trait]
 impl SaldoServiceTrait for SaldoService {
+    #[instrument(name = "SaldoService::get_saldos", skip_all)]
     async fn get_saldos(&self) -> Result<ApiResponse<Vec<SaldoResponse>>, ErrorResponse> {
         let saldo = self
             .saldo_repository
@@ -40,7 +41,7 @@ impl SaldoServiceTrait for SaldoService {
 
         let saldo_response: Vec<SaldoResponse> = saldo
             .into_iter()
-            .map(|saldo| SaldoResponse::from(saldo))
+            .map(SaldoResponse::from)
             .collect();
 
         Ok(ApiResponse {
@@ -50,6 +51,7 @@ impl SaldoServiceTrait for SaldoService {
         })
     }
 
+    #[instrument(name = "SaldoService::get_saldo", skip_all)]
     async fn get_saldo(
         &self,
         id: i32,
@@ -75,6 +77,7 @@ impl SaldoServiceTrait for SaldoService {
         }
     }
 
+    #[instrument(name = "SaldoService::get_saldo_users", skip_all)]
     async fn get_saldo_users(
         &self,
         id: i32,
@@ -120,6 +123,7 @@ impl SaldoServiceTrait for SaldoService {
         Ok(response)
     }
 
+    #[instrument(name = "SaldoService::get_saldo_user", skip_all)]
     async fn get_saldo_user(
         &self,
         id: i32,
@@ -156,6 +160,7 @@ impl SaldoServiceTrait for SaldoService {
         Ok(response)
     }
 
+    #[instrument(name = "SaldoService::create_saldo", skip_all)]
     async fn create_saldo(
         &self,
         input: &CreateSaldoRequest,
@@ -182,7 +187,7 @@ impl SaldoServiceTrait for SaldoService {
 
         let saldo = self
             .saldo_repository
-            .create(&input)
+            .create(input)
             .await
             .map_err(AppError::from)
             .map_err(ErrorResponse::from)?;
@@ -194,6 +199,7 @@ impl SaldoServiceTrait for SaldoService {
         })
     }
 
+    #[instrument(name = "SaldoService::update_saldo", skip_all)]
     async fn update_saldo(
         &self,
         input: &UpdateSaldoRequest,
@@ -250,6 +256,7 @@ impl SaldoServiceTrait for SaldoService {
         }
     }
 
+    #[instrument(name = "SaldoService::delete_saldo", skip_all)]
     async fn delete_saldo(&self, id: i32) -> Result<ApiResponse<()>, ErrorResponse> {
         let user = self.user_repository.find_by_id(id).await.map_err(|_| {
             ErrorResponse::from(AppError::NotFound(format!("User with id {} not found", id)))
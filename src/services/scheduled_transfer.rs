@@ -0,0 +1,475 @@
+use async_trait::async_trait;
+use chrono::{Duration, NaiveDateTime};
+use tracing::{error, info, instrument};
+
+use crate::{
+    abstract_trait::{
+        clock::DynClock,
+        scheduled_transfer::{DynScheduledTransferRepository, ScheduledTransferServiceTrait},
+        transfer::DynTransferService,
+        user::DynUserRepository,
+    },
+    domain::{
+        request::{
+            limit::MinimumAmounts,
+            scheduled_transfer::{
+                CreateScheduledTransferAttempt, CreateScheduledTransferRequest,
+                UpdateScheduledTransferRequest, UpdateScheduledTransferRun,
+            },
+            transfer::CreateTransferRequest,
+        },
+        response::{
+            scheduled_transfer::{
+                ScheduledTransferAttemptResponse, ScheduledTransferDetailResponse,
+                ScheduledTransferResponse,
+            },
+            ApiResponse, ErrorKind, ErrorResponse,
+        },
+    },
+    entities::scheduled_transfers,
+    utils::{
+        errors::AppError,
+        schedule::{first_run_at, next_run_after},
+    },
+};
+
+const SCHEDULES_PER_RUN: u64 = 50;
+
+/// How insufficient-funds failures are retried before a run is given up.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RetryPolicy {
+    pub max_retries: i32,
+    pub retry_interval: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            retry_interval: Duration::hours(1),
+        }
+    }
+}
+
+pub struct ScheduledTransferService {
+    scheduled_transfer_repository: DynScheduledTransferRepository,
+    transfer_service: DynTransferService,
+    user_repository: DynUserRepository,
+    clock: DynClock,
+    retry_policy: RetryPolicy,
+    minimum_amounts: MinimumAmounts,
+}
+
+impl ScheduledTransferService {
+    pub fn new(
+        scheduled_transfer_repository: DynScheduledTransferRepository,
+        transfer_service: DynTransferService,
+        user_repository: DynUserRepository,
+        clock: DynClock,
+        retry_policy: RetryPolicy,
+        minimum_amounts: MinimumAmounts,
+    ) -> Self {
+        Self {
+            scheduled_transfer_repository,
+            transfer_service,
+            user_repository,
+            clock,
+            retry_policy,
+            minimum_amounts,
+        }
+    }
+
+    async fn find_owned_schedule(
+        &self,
+        id: i32,
+        user_id: i32,
+    ) -> Result<scheduled_transfers::Model, ErrorResponse> {
+        self.scheduled_transfer_repository
+            .find_by_id(id)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?
+            .filter(|schedule| schedule.transfer_from == user_id)
+            .ok_or_else(|| {
+                ErrorResponse::from(AppError::NotFound(format!(
+                    "Scheduled transfer with id {} not found",
+                    id
+                )))
+            })
+    }
+
+    /// Runs one due occurrence. Returns `false` when another scheduler
+    /// already claimed it.
+    async fn run_schedule(
+        &self,
+        schedule: scheduled_transfers::Model,
+        now: NaiveDateTime,
+    ) -> Result<bool, ErrorResponse> {
+        let next_run = next_run_after(
+            &schedule.frequency,
+            schedule.start_at,
+            schedule.day_of_month,
+            now,
+        );
+
+        // The occurrence is advanced before any money moves, guarded on the
+        // run it was due for, so overlapping schedulers cannot both pay it and
+        // no write after the transfer can make it due again. A crash in
+        // between skips the occurrence rather than paying it twice.
+        let advanced = UpdateScheduledTransferRun {
+            schedule_id: schedule.schedule_id,
+            status: if next_run.is_some() {
+                "active"
+            } else {
+                "completed"
+            }
+            .to_string(),
+            next_run_at: next_run.unwrap_or(schedule.next_run_at),
+            retry_count: 0,
+            last_run_at: now,
+        };
+
+        let claimed = self
+            .scheduled_transfer_repository
+            .claim_run(&advanced, schedule.next_run_at)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?;
+
+        if !claimed {
+            info!(
+                "Scheduled transfer {} run due at {} was already claimed",
+                schedule.schedule_id, schedule.next_run_at
+            );
+            return Ok(false);
+        }
+
+        let outcome = self
+            .transfer_service
+            .create_transfer(&CreateTransferRequest {
+                transfer_from: schedule.transfer_from,
+                transfer_to: schedule.transfer_to,
+                transfer_amount: schedule.transfer_amount,
+                beneficiary_id: None,
+                device_id: None,
+                merchant_payment: false,
+            })
+            .await
+            .map(|response| response.data.transfer_id);
+
+        // Only outcomes that differ from the claimed run update it again
+        let (attempt, run) = match outcome {
+            Ok(transfer_id) => {
+                info!(
+                    "Scheduled transfer {} created transfer {}",
+                    schedule.schedule_id, transfer_id
+                );
+                (
+                    CreateScheduledTransferAttempt {
+                        schedule_id: schedule.schedule_id,
+                        status: "succeeded".to_string(),
+                        transfer_id: Some(transfer_id),
+                        error_message: None,
+                        attempted_at: now,
+                        fraud_review_id: None,
+                    },
+                    None,
+                )
+            }
+            Err(ErrorResponse {
+                kind: ErrorKind::HeldForReview(review_id),
+                message,
+                ..
+            }) => {
+                // The transfer still runs if the review is approved, so the
+                // occurrence stays claimed and is not retried.
+                info!(
+                    "Scheduled transfer {} held under fraud review {}",
+                    schedule.schedule_id, review_id
+                );
+                (
+                    CreateScheduledTransferAttempt {
+                        schedule_id: schedule.schedule_id,
+                        status: "held".to_string(),
+                        transfer_id: None,
+                        error_message: Some(message),
+                        attempted_at: now,
+                        fraud_review_id: Some(review_id),
+                    },
+                    None,
+                )
+            }
+            Err(err)
+                if err.kind == ErrorKind::InsufficientBalance
+                    && schedule.retry_count < self.retry_policy.max_retries =>
+            {
+                info!(
+                    "Scheduled transfer {} has insufficient balance, retry {} of {}",
+                    schedule.schedule_id,
+                    schedule.retry_count + 1,
+                    self.retry_policy.max_retries
+                );
+                (
+                    CreateScheduledTransferAttempt {
+                        schedule_id: schedule.schedule_id,
+                        status: "retry_scheduled".to_string(),
+                        transfer_id: None,
+                        error_message: Some(err.message),
+                        attempted_at: now,
+                        fraud_review_id: None,
+                    },
+                    Some(UpdateScheduledTransferRun {
+                        schedule_id: schedule.schedule_id,
+                        status: "active".to_string(),
+                        next_run_at: now + self.retry_policy.retry_interval,
+                        retry_count: schedule.retry_count + 1,
+                        last_run_at: now,
+                    }),
+                )
+            }
+            Err(err) => {
+                error!(
+                    "Scheduled transfer {} failed: {}",
+                    schedule.schedule_id, err.message
+                );
+                (
+                    CreateScheduledTransferAttempt {
+                        schedule_id: schedule.schedule_id,
+                        status: "failed".to_string(),
+                        transfer_id: None,
+                        error_message: Some(err.message),
+                        attempted_at: now,
+                        fraud_review_id: None,
+                    },
+                    next_run.is_none().then(|| UpdateScheduledTransferRun {
+                        status: "failed".to_string(),
+                        ..advanced
+                    }),
+                )
+            }
+        };
+
+        self.scheduled_transfer_repository
+            .create_attempt(&attempt)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?;
+
+        if let Some(run) = run {
+            self.scheduled_transfer_repository
+                .update_run(&run)
+                .await
+                .map_err(AppError::from)
+                .map_err(ErrorResponse::from)?;
+        }
+
+        Ok(true)
+    }
+}
+
+#[async_trait]
+impl ScheduledTransferServiceTrait for ScheduledTransferService {
+    #[instrument(name = "ScheduledTransferService::get_scheduled_transfers", skip_all)]
+    async fn get_scheduled_transfers(
+        &self,
+        user_id: i32,
+    ) -> Result<ApiResponse<Vec<ScheduledTransferResponse>>, ErrorResponse> {
+        let schedules = self
+            .scheduled_transfer_repository
+            .find_by_user(user_id)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?;
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "Scheduled transfers retrieved successfully".to_string(),
+            data: schedules
+                .into_iter()
+                .map(ScheduledTransferResponse::from)
+                .collect(),
+        })
+    }
+
+    #[instrument(name = "ScheduledTransferService::get_scheduled_transfer", skip_all)]
+    async fn get_scheduled_transfer(
+        &self,
+        id: i32,
+        user_id: i32,
+    ) -> Result<ApiResponse<ScheduledTransferDetailResponse>, ErrorResponse> {
+        let schedule = self.find_owned_schedule(id, user_id).await?;
+
+        let attempts = self
+            .scheduled_transfer_repository
+            .find_attempts(schedule.schedule_id)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?;
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "Scheduled transfer retrieved successfully".to_string(),
+            data: ScheduledTransferDetailResponse {
+                schedule: ScheduledTransferResponse::from(schedule),
+                attempts: attempts
+                    .into_iter()
+                    .map(ScheduledTransferAttemptResponse::from)
+                    .collect(),
+            },
+        })
+    }
+
+    #[instrument(name = "ScheduledTransferService::create_scheduled_transfer", skip_all)]
+    async fn create_scheduled_transfer(
+        &self,
+        input: &CreateScheduledTransferRequest,
+    ) -> Result<ApiResponse<ScheduledTransferResponse>, ErrorResponse> {
+        if let Err(validation_err) = input.validate(self.minimum_amounts.transfer) {
+            error!(
+                "Validation failed for scheduled transfer: {}",
+                validation_err
+            );
+            return Err(ErrorResponse::from(AppError::ValidationError(
+                validation_err,
+            )));
+        }
+
+        let start_at = input.start_at.naive_utc();
+
+        if start_at < self.clock.now() {
+            return Err(ErrorResponse::from(AppError::ValidationError(
+                "Start time must be in the future".to_string(),
+            )));
+        }
+
+        let next_run_at =
+            first_run_at(&input.frequency, start_at, input.day_of_month).ok_or_else(|| {
+                ErrorResponse::from(AppError::ValidationError(
+                    "Unable to compute the first run of the schedule".to_string(),
+                ))
+            })?;
+
+        self.user_repository
+            .find_by_id(input.transfer_to)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?
+            .ok_or_else(|| {
+                ErrorResponse::from(AppError::NotFound(format!(
+                    "User with id {} not found",
+                    input.transfer_to
+                )))
+            })?;
+
+        let schedule = self
+            .scheduled_transfer_repository
+            .create(input, next_run_at)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?;
+
+        info!(
+            "Scheduled transfer {} created, first run at {}",
+            schedule.schedule_id, schedule.next_run_at
+        );
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "Scheduled transfer created successfully".to_string(),
+            data: ScheduledTransferResponse::from(schedule),
+        })
+    }
+
+    #[instrument(name = "ScheduledTransferService::update_scheduled_transfer", skip_all)]
+    async fn update_scheduled_transfer(
+        &self,
+        input: &UpdateScheduledTransferRequest,
+    ) -> Result<ApiResponse<ScheduledTransferResponse>, ErrorResponse> {
+        if let Err(validation_err) = input.validate(self.minimum_amounts.transfer) {
+            error!(
+                "Validation failed for scheduled transfer update: {}",
+                validation_err
+            );
+            return Err(ErrorResponse::from(AppError::ValidationError(
+                validation_err,
+            )));
+        }
+
+        let schedule = self
+            .find_owned_schedule(input.schedule_id, input.transfer_from)
+            .await?;
+
+        if schedule.status != "active" && schedule.status != "paused" {
+            return Err(ErrorResponse::from(AppError::ValidationError(format!(
+                "Scheduled transfer is already {}",
+                schedule.status
+            ))));
+        }
+
+        let updated = self
+            .scheduled_transfer_repository
+            .update(input)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?;
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "Scheduled transfer updated successfully".to_string(),
+            data: ScheduledTransferResponse::from(updated),
+        })
+    }
+
+    #[instrument(name = "ScheduledTransferService::delete_scheduled_transfer", skip_all)]
+    async fn delete_scheduled_transfer(
+        &self,
+        id: i32,
+        user_id: i32,
+    ) -> Result<ApiResponse<()>, ErrorResponse> {
+        let schedule = self.find_owned_schedule(id, user_id).await?;
+
+        self.scheduled_transfer_repository
+            .delete(schedule.schedule_id)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?;
+
+        info!("Scheduled transfer {} deleted", id);
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "Scheduled transfer deleted successfully".to_string(),
+            data: (),
+        })
+    }
+
+    #[instrument(name = "ScheduledTransferService::run_due_schedules", skip_all)]
+    async fn run_due_schedules(&self) -> Result<usize, ErrorResponse> {
+        let now = self.clock.now();
+
+        let schedules = self
+            .scheduled_transfer_repository
+            .find_due(now, SCHEDULES_PER_RUN)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?;
+
+        let mut processed = 0;
+
+        // One schedule failing to record its run must not hold up the others.
+        for schedule in schedules {
+            let schedule_id = schedule.schedule_id;
+
+            match self.run_schedule(schedule, now).await {
+                Ok(true) => processed += 1,
+                Ok(false) => {}
+                Err(err) => error!(
+                    "Scheduled transfer {} could not be run: {}",
+                    schedule_id, err
+                ),
+            }
+        }
+
+        Ok(processed)
+    }
+}
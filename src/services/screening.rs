@@ -0,0 +1,208 @@
+use async_trait::async_trait;
+use tracing::{error, info, instrument, warn};
+
+use crate::{
+    abstract_trait::{
+        screening::{DynScreeningRepository, ScreeningServiceTrait},
+        watchlist::DynWatchlist,
+    },
+    domain::{
+        request::screening::{
+            CreateScreeningResult, ReviewScreeningResultRequest, ScreenNameRequest,
+            SCREENING_RESULT_STATUSES,
+        },
+        response::{
+            screening::{ScreeningResultResponse, WatchlistStatusResponse},
+            ApiResponse, ErrorResponse,
+        },
+    },
+    entities::screening_results,
+    utils::{errors::AppError, watchlist::WatchlistMatch},
+};
+
+/// Lowest similarity, from 0 to 100, flagged as a possible match.
+pub const SCREENING_MATCH_THRESHOLD: i32 = 85;
+
+pub struct ScreeningService {
+    screening_repository: DynScreeningRepository,
+    watchlist: DynWatchlist,
+}
+
+impl ScreeningService {
+    pub fn new(screening_repository: DynScreeningRepository, watchlist: DynWatchlist) -> Self {
+        Self {
+            screening_repository,
+            watchlist,
+        }
+    }
+
+    async fn find_result(&self, id: i32) -> Result<screening_results::Model, ErrorResponse> {
+        self.screening_repository
+            .find_by_id(id)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?
+            .ok_or_else(|| {
+                ErrorResponse::from(AppError::NotFound(format!(
+                    "Screening result with id {} not found",
+                    id
+                )))
+            })
+    }
+}
+
+#[async_trait]
+impl ScreeningServiceTrait for ScreeningService {
+    #[instrument(name = "ScreeningService::screen", skip_all)]
+    async fn screen(
+        &self,
+        input: &ScreenNameRequest,
+    ) -> Result<Vec<ScreeningResultResponse>, ErrorResponse> {
+        // Best match of each entry over all the subject's names
+        let mut matches: Vec<(&String, WatchlistMatch)> = Vec::new();
+
+        for name in &input.names {
+            for found in self.watchlist.search(name, SCREENING_MATCH_THRESHOLD) {
+                match matches
+                    .iter_mut()
+                    .find(|(_, known)| known.entry.entry_id == found.entry.entry_id)
+                {
+                    Some(known) if known.1.score < found.score => *known = (name, found),
+                    Some(_) => {}
+                    None => matches.push((name, found)),
+                }
+            }
+        }
+
+        let mut results = Vec::with_capacity(matches.len());
+
+        for (name, found) in matches {
+            let result = self
+                .screening_repository
+                .create_result(&CreateScreeningResult {
+                    subject_type: input.subject_type.clone(),
+                    subject_id: input.subject_id,
+                    user_id: input.user_id,
+                    screened_name: name.clone(),
+                    watchlist_entry_id: found.entry.entry_id,
+                    watchlist_name: found.entry.list,
+                    matched_name: found.matched_name,
+                    score: found.score,
+                })
+                .await
+                .map_err(AppError::from)
+                .map_err(ErrorResponse::from)?;
+
+            warn!(
+                "{} {} flagged for review: {} matched watchlist entry {} with score {}",
+                input.subject_type,
+                input.subject_id,
+                result.screened_name,
+                result.watchlist_entry_id,
+                result.score
+            );
+
+            results.push(ScreeningResultResponse::from(result));
+        }
+
+        Ok(results)
+    }
+
+    #[instrument(name = "ScreeningService::get_results", skip_all)]
+    async fn get_results(
+        &self,
+        status: Option<String>,
+    ) -> Result<ApiResponse<Vec<ScreeningResultResponse>>, ErrorResponse> {
+        if let Some(status) = &status {
+            if !SCREENING_RESULT_STATUSES.contains(&status.as_str()) {
+                return Err(ErrorResponse::from(AppError::ValidationError(format!(
+                    "Status must be one of {}",
+                    SCREENING_RESULT_STATUSES.join(", ")
+                ))));
+            }
+        }
+
+        let results = self
+            .screening_repository
+            .find_by_status(status)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?;
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "Screening results retrieved successfully".to_string(),
+            data: results
+                .into_iter()
+                .map(ScreeningResultResponse::from)
+                .collect(),
+        })
+    }
+
+    #[instrument(name = "ScreeningService::review_result", skip_all)]
+    async fn review_result(
+        &self,
+        input: &ReviewScreeningResultRequest,
+    ) -> Result<ApiResponse<ScreeningResultResponse>, ErrorResponse> {
+        if let Err(validation_err) = input.validate() {
+            error!("Validation failed for screening review: {}", validation_err);
+            return Err(ErrorResponse::from(AppError::ValidationError(
+                validation_err,
+            )));
+        }
+
+        let result = self.find_result(input.screening_result_id).await?;
+
+        let transitioned = result.status == "pending"
+            && self
+                .screening_repository
+                .transition_status(
+                    result.screening_result_id,
+                    "pending",
+                    &input.status,
+                    input.reviewer_id,
+                    input.note.clone(),
+                )
+                .await
+                .map_err(AppError::from)
+                .map_err(ErrorResponse::from)?;
+
+        if !transitioned {
+            return Err(ErrorResponse::from(AppError::ValidationError(format!(
+                "Screening result {} is {} and cannot be {}",
+                result.screening_result_id, result.status, input.status
+            ))));
+        }
+
+        let result = self.find_result(result.screening_result_id).await?;
+
+        info!(
+            "Screening result {} {} by {}",
+            result.screening_result_id, result.status, input.reviewer_id
+        );
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: format!("Screening match {}", result.status),
+            data: ScreeningResultResponse::from(result),
+        })
+    }
+
+    #[instrument(name = "ScreeningService::reload_watchlist", skip_all)]
+    async fn reload_watchlist(
+        &self,
+    ) -> Result<ApiResponse<WatchlistStatusResponse>, ErrorResponse> {
+        let entries = self.watchlist.reload().await.map_err(|e| {
+            error!("Failed to reload the watchlist: {}", e);
+            ErrorResponse::from(e)
+        })?;
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "Watchlist reloaded successfully".to_string(),
+            data: WatchlistStatusResponse {
+                entries: entries as i32,
+            },
+        })
+    }
+}
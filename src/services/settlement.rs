@@ -0,0 +1,660 @@
+use async_trait::async_trait;
+use chrono::{Duration, NaiveDateTime, TimeZone, Utc};
+use tracing::{error, info, instrument, warn};
+
+use crate::{
+    abstract_trait::{
+        bank_account::DynBankAccountRepository,
+        clock::DynClock,
+        fee::{DynFeeService, FeeQuote},
+        merchant::DynMerchantRepository,
+        saldo::DynSaldoRepository,
+        settlement::{DynSettlementRepository, SettlementServiceTrait},
+        withdraw::DynWithdrawService,
+    },
+    domain::{
+        request::{
+            fee::{FeeQuoteRequest, RecordFeeRevenue},
+            limit::MinimumAmounts,
+            saldo::UpdateSaldoBalance,
+            withdraw::CreateWithdrawRequest,
+        },
+        response::{settlement::SettlementResponse, ApiResponse, ErrorKind, ErrorResponse},
+    },
+    entities::{merchants, settlements},
+    utils::errors::AppError,
+};
+
+pub struct SettlementService {
+    settlement_repository: DynSettlementRepository,
+    merchant_repository: DynMerchantRepository,
+    bank_account_repository: DynBankAccountRepository,
+    saldo_repository: DynSaldoRepository,
+    withdraw_service: DynWithdrawService,
+    fee_service: DynFeeService,
+    clock: DynClock,
+    minimum_amounts: MinimumAmounts,
+}
+
+impl SettlementService {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        settlement_repository: DynSettlementRepository,
+        merchant_repository: DynMerchantRepository,
+        bank_account_repository: DynBankAccountRepository,
+        saldo_repository: DynSaldoRepository,
+        withdraw_service: DynWithdrawService,
+        fee_service: DynFeeService,
+        clock: DynClock,
+        minimum_amounts: MinimumAmounts,
+    ) -> Self {
+        Self {
+            settlement_repository,
+            merchant_repository,
+            bank_account_repository,
+            saldo_repository,
+            withdraw_service,
+            fee_service,
+            clock,
+            minimum_amounts,
+        }
+    }
+
+    /// Loads a settlement that belongs to the merchant owned by `user_id`.
+    async fn merchant_settlement(
+        &self,
+        id: i32,
+        user_id: i32,
+    ) -> Result<settlements::Model, ErrorResponse> {
+        let not_found = || {
+            ErrorResponse::from(AppError::NotFound(format!(
+                "Settlement with id {} not found",
+                id
+            )))
+        };
+
+        let merchant = self
+            .merchant_repository
+            .find_by_user(user_id)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?
+            .ok_or_else(not_found)?;
+
+        self.settlement_repository
+            .find_by_id(id)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?
+            .filter(|settlement| settlement.merchant_id == merchant.merchant_id)
+            .ok_or_else(not_found)
+    }
+
+    /// The previous UTC day, `[yesterday 00:00, today 00:00)`.
+    fn previous_period(&self) -> (NaiveDateTime, NaiveDateTime) {
+        let period_end = self.clock.now().date().and_hms_opt(0, 0, 0).unwrap();
+
+        (period_end - Duration::days(1), period_end)
+    }
+
+    /// Charges the payment fee of every payment and QRIS in the settlement
+    /// to the merchant's saldo and posts it to revenue. QRIS payments are
+    /// priced under the `qris` method.
+    async fn charge_fees(
+        &self,
+        merchant: &merchants::Model,
+        settlement: settlements::Model,
+    ) -> Result<settlements::Model, ErrorResponse> {
+        let payments = self
+            .settlement_repository
+            .find_payments(settlement.settlement_id)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?;
+
+        let qris_payments = self
+            .settlement_repository
+            .find_qris_payments(settlement.settlement_id)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?;
+
+        let charges = payments
+            .iter()
+            .map(|payment| ("payment", payment.payment_intent_id, None, payment.amount))
+            .chain(qris_payments.iter().map(|payment| {
+                (
+                    "qris_payment",
+                    payment.qris_payment_id,
+                    Some("qris".to_string()),
+                    payment.amount,
+                )
+            }));
+
+        let mut fees = Vec::with_capacity(payments.len() + qris_payments.len());
+
+        for (transaction_type, transaction_id, method, amount) in charges {
+            let quote = self
+                .fee_service
+                .calculate_fee(&FeeQuoteRequest {
+                    transaction_type: "payment".to_string(),
+                    method,
+                    merchant_id: Some(merchant.merchant_id),
+                    amount,
+                })
+                .await?;
+
+            fees.push((transaction_type, transaction_id, quote));
+        }
+
+        let fee_amount: i32 = fees.iter().map(|(_, _, quote)| quote.fee_amount).sum();
+
+        if fee_amount == 0 {
+            return Ok(settlement);
+        }
+
+        let saldo = self
+            .saldo_repository
+            .find_by_user_id(merchant.user_id)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?
+            .ok_or_else(|| {
+                ErrorResponse::from(AppError::NotFound(format!(
+                    "Saldo with User id {} not found",
+                    merchant.user_id
+                )))
+            })?;
+
+        if saldo.total_balance < fee_amount {
+            return Err(ErrorResponse::from(AppError::InsufficientBalance));
+        }
+
+        self.saldo_repository
+            .update_balance(&UpdateSaldoBalance {
+                user_id: merchant.user_id,
+                total_balance: saldo.total_balance - fee_amount,
+            })
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?;
+
+        // Fees are posted before the settlement records them, so a failed
+        // post leaves the settlement uncharged for the next retry.
+        for (posted, (transaction_type, transaction_id, quote)) in fees.iter().enumerate() {
+            if let Err(e) = self
+                .fee_service
+                .post_fee(&RecordFeeRevenue {
+                    transaction_type: transaction_type.to_string(),
+                    transaction_id: *transaction_id,
+                    fee_rule_id: quote.fee_rule_id,
+                    amount: quote.fee_amount,
+                })
+                .await
+            {
+                error!(
+                    "Failed to post fee of {} {} to revenue, refunding settlement {}: {}",
+                    transaction_type, transaction_id, settlement.settlement_id, e
+                );
+
+                self.refund_fees(merchant, saldo.total_balance, &fees[..posted])
+                    .await;
+
+                return Err(e);
+            }
+        }
+
+        match self
+            .settlement_repository
+            .update_fee(settlement.settlement_id, fee_amount)
+            .await
+        {
+            Ok(settlement) => Ok(settlement),
+            Err(db_err) => {
+                error!(
+                    "Failed to record fees of settlement {}, refunding them: {}",
+                    settlement.settlement_id, db_err
+                );
+
+                self.refund_fees(merchant, saldo.total_balance, &fees).await;
+
+                Err(ErrorResponse::from(AppError::from(db_err)))
+            }
+        }
+    }
+
+    /// Takes `posted` fees back out of revenue and restores the merchant's
+    /// saldo to `total_balance`, undoing a partly charged settlement.
+    async fn refund_fees(
+        &self,
+        merchant: &merchants::Model,
+        total_balance: i32,
+        posted: &[(&str, i32, FeeQuote)],
+    ) {
+        for (transaction_type, transaction_id, quote) in posted {
+            if let Err(e) = self
+                .fee_service
+                .post_fee(&RecordFeeRevenue {
+                    transaction_type: transaction_type.to_string(),
+                    transaction_id: *transaction_id,
+                    fee_rule_id: quote.fee_rule_id,
+                    amount: -quote.fee_amount,
+                })
+                .await
+            {
+                error!(
+                    "Failed to reverse fee of {} {}: {}",
+                    transaction_type, transaction_id, e
+                );
+            }
+        }
+
+        if let Err(e) = self
+            .saldo_repository
+            .update_balance(&UpdateSaldoBalance {
+                user_id: merchant.user_id,
+                total_balance,
+            })
+            .await
+        {
+            error!("Failed to refund settlement fees: {}", e);
+        }
+    }
+
+    /// Pays the settlement's net amount out to the merchant's first bank
+    /// account and records the outcome on the settlement. A payout held for
+    /// fraud review leaves the settlement `in_review` until the review
+    /// decides it.
+    async fn pay_out(
+        &self,
+        merchant: &merchants::Model,
+        settlement: settlements::Model,
+    ) -> Result<settlements::Model, ErrorResponse> {
+        // The withdrawal fee comes out of the payout, so settling never draws
+        // on the rest of the merchant's saldo.
+        let withdraw_fee = if settlement.net_amount > 0 {
+            self.fee_service
+                .calculate_fee(&FeeQuoteRequest {
+                    transaction_type: "withdraw".to_string(),
+                    method: Some("bank_transfer".to_string()),
+                    merchant_id: Some(merchant.merchant_id),
+                    amount: settlement.net_amount,
+                })
+                .await?
+                .fee_amount
+        } else {
+            0
+        };
+
+        let payout_amount = settlement.net_amount - withdraw_fee;
+
+        // Smaller settlements stay in the merchant's saldo until they can be paid out by hand.
+        let (status, withdraw_id, failure_reason) = if payout_amount < self.minimum_amounts.withdraw
+        {
+            (
+                "held",
+                None,
+                Some(format!(
+                    "Payout amount {} is below the minimum payout",
+                    payout_amount
+                )),
+            )
+        } else {
+            let bank_account = self
+                .bank_account_repository
+                .find_by_user(merchant.user_id)
+                .await
+                .map_err(AppError::from)
+                .map_err(ErrorResponse::from)?
+                .into_iter()
+                .next();
+
+            match bank_account {
+                None => (
+                    "held",
+                    None,
+                    Some("Merchant has no bank account".to_string()),
+                ),
+                Some(bank_account) => {
+                    let request = CreateWithdrawRequest {
+                        user_id: merchant.user_id,
+                        bank_account_id: bank_account.bank_account_id,
+                        withdraw_amount: payout_amount,
+                        withdraw_time: Utc::now(),
+                        device_id: None,
+                    };
+
+                    match self.withdraw_service.create_withdraw(&request).await {
+                        Ok(response) => ("paid_out", Some(response.data.withdraw_id), None),
+                        Err(err) => {
+                            if let ErrorKind::HeldForReview(review_id) = err.kind {
+                                return self
+                                    .settlement_repository
+                                    .hold_for_review(settlement.settlement_id, review_id)
+                                    .await
+                                    .map_err(AppError::from)
+                                    .map_err(ErrorResponse::from);
+                            }
+
+                            ("failed", None, Some(err.message))
+                        }
+                    }
+                }
+            }
+        };
+
+        self.settlement_repository
+            .update_payout(
+                settlement.settlement_id,
+                status,
+                withdraw_id,
+                failure_reason,
+            )
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)
+    }
+
+    /// Charges the settlement's fees unless it already carries them, then
+    /// pays it out. Failures are recorded on the settlement so the next run
+    /// retries it.
+    async fn settle(&self, merchant: &merchants::Model, settlement: settlements::Model) {
+        // A retried settlement keeps the fees charged before its payout failed
+        let settlement = if settlement.fee_amount > 0 {
+            settlement
+        } else {
+            match self.charge_fees(merchant, settlement.clone()).await {
+                Ok(settlement) => settlement,
+                Err(e) => {
+                    error!(
+                        "Failed to charge fees of settlement {}: {}",
+                        settlement.settlement_id, e
+                    );
+
+                    if let Err(db_err) = self
+                        .settlement_repository
+                        .update_payout(settlement.settlement_id, "failed", None, Some(e.message))
+                        .await
+                    {
+                        error!(
+                            "Failed to mark settlement {} as failed: {}",
+                            settlement.settlement_id, db_err
+                        );
+                    }
+                    return;
+                }
+            }
+        };
+
+        let settlement_id = settlement.settlement_id;
+
+        match self.pay_out(merchant, settlement).await {
+            Ok(settlement) if settlement.status == "paid_out" => info!(
+                "Settlement {} for merchant {} is paid_out",
+                settlement.settlement_id, merchant.merchant_id
+            ),
+            Ok(settlement) => warn!(
+                "Settlement {} for merchant {} is {}: {}",
+                settlement.settlement_id,
+                merchant.merchant_id,
+                settlement.status,
+                settlement.failure_reason.unwrap_or_default()
+            ),
+            Err(e) => {
+                error!(
+                    "Failed to pay out settlement {} for merchant {}: {}",
+                    settlement_id, merchant.merchant_id, e
+                );
+
+                if let Err(db_err) = self
+                    .settlement_repository
+                    .update_payout(settlement_id, "failed", None, Some(e.message))
+                    .await
+                {
+                    error!(
+                        "Failed to mark settlement {} as failed: {}",
+                        settlement_id, db_err
+                    );
+                }
+            }
+        }
+    }
+
+    /// Pays out every held or failed settlement again, claiming each one
+    /// first so concurrent runs cannot pay the same settlement twice.
+    async fn retry_unpaid_settlements(&self) -> Result<(), ErrorResponse> {
+        let settlements = self
+            .settlement_repository
+            .find_retryable()
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?;
+
+        for settlement in settlements {
+            let merchant = match self
+                .merchant_repository
+                .find_by_id(settlement.merchant_id)
+                .await
+            {
+                Ok(Some(merchant)) => merchant,
+                Ok(None) => {
+                    error!(
+                        "Merchant {} of settlement {} not found",
+                        settlement.merchant_id, settlement.settlement_id
+                    );
+                    continue;
+                }
+                Err(e) => {
+                    error!(
+                        "Failed to load merchant {} of settlement {}: {}",
+                        settlement.merchant_id, settlement.settlement_id, e
+                    );
+                    continue;
+                }
+            };
+
+            match self
+                .settlement_repository
+                .claim_retry(settlement.settlement_id, &settlement.status)
+                .await
+            {
+                Ok(true) => {}
+                Ok(false) => continue,
+                Err(e) => {
+                    error!(
+                        "Failed to claim settlement {} for retry: {}",
+                        settlement.settlement_id, e
+                    );
+                    continue;
+                }
+            }
+
+            info!(
+                "Retrying {} settlement {} for merchant {}",
+                settlement.status, settlement.settlement_id, merchant.merchant_id
+            );
+
+            self.settle(&merchant, settlement).await;
+        }
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl SettlementServiceTrait for SettlementService {
+    #[instrument(name = "SettlementService::get_settlements", skip_all)]
+    async fn get_settlements(
+        &self,
+        user_id: i32,
+    ) -> Result<ApiResponse<Vec<SettlementResponse>>, ErrorResponse> {
+        let merchant = self
+            .merchant_repository
+            .find_by_user(user_id)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?
+            .ok_or_else(|| {
+                ErrorResponse::from(AppError::NotFound(format!(
+                    "Merchant for user id {} not found",
+                    user_id
+                )))
+            })?;
+
+        let settlements = self
+            .settlement_repository
+            .find_by_merchant(merchant.merchant_id)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?;
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "Settlements retrieved successfully".to_string(),
+            data: settlements
+                .into_iter()
+                .map(SettlementResponse::from)
+                .collect(),
+        })
+    }
+
+    #[instrument(name = "SettlementService::get_settlement", skip_all)]
+    async fn get_settlement(
+        &self,
+        id: i32,
+        user_id: i32,
+    ) -> Result<ApiResponse<SettlementResponse>, ErrorResponse> {
+        let settlement = self.merchant_settlement(id, user_id).await?;
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "Settlement retrieved successfully".to_string(),
+            data: SettlementResponse::from(settlement),
+        })
+    }
+
+    #[instrument(name = "SettlementService::get_settlement_report_csv", skip_all)]
+    async fn get_settlement_report_csv(
+        &self,
+        id: i32,
+        user_id: i32,
+    ) -> Result<String, ErrorResponse> {
+        let settlement = self.merchant_settlement(id, user_id).await?;
+
+        let payments = self
+            .settlement_repository
+            .find_payments(settlement.settlement_id)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?;
+
+        let qris_payments = self
+            .settlement_repository
+            .find_qris_payments(settlement.settlement_id)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?;
+
+        let refunds = self
+            .settlement_repository
+            .find_refunds(settlement.settlement_id)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?;
+
+        let format_time = |time: Option<NaiveDateTime>| {
+            time.map(|dt| Utc.from_utc_datetime(&dt).to_rfc3339())
+                .unwrap_or_default()
+        };
+
+        let mut writer = csv::Writer::from_writer(Vec::new());
+
+        writer
+            .write_record(["type", "id", "reference", "amount", "occurred_at"])
+            .map_err(|err| ErrorResponse::from(AppError::ValidationError(err.to_string())))?;
+
+        for payment in payments {
+            writer
+                .write_record([
+                    "payment".to_string(),
+                    payment.payment_intent_id.to_string(),
+                    payment.reference,
+                    payment.amount.to_string(),
+                    format_time(payment.succeeded_at),
+                ])
+                .map_err(|err| ErrorResponse::from(AppError::ValidationError(err.to_string())))?;
+        }
+
+        for payment in qris_payments {
+            writer
+                .write_record([
+                    "qris".to_string(),
+                    payment.qris_payment_id.to_string(),
+                    payment.reference,
+                    payment.amount.to_string(),
+                    format_time(payment.paid_at),
+                ])
+                .map_err(|err| ErrorResponse::from(AppError::ValidationError(err.to_string())))?;
+        }
+
+        for refund in refunds {
+            writer
+                .write_record([
+                    "refund".to_string(),
+                    refund.refund_id.to_string(),
+                    refund.payment_intent_id.to_string(),
+                    (-refund.amount).to_string(),
+                    format_time(refund.refunded_at),
+                ])
+                .map_err(|err| ErrorResponse::from(AppError::ValidationError(err.to_string())))?;
+        }
+
+        let data = writer
+            .into_inner()
+            .map_err(|err| ErrorResponse::from(AppError::ValidationError(err.to_string())))?;
+
+        String::from_utf8(data)
+            .map_err(|err| ErrorResponse::from(AppError::ValidationError(err.to_string())))
+    }
+
+    #[instrument(name = "SettlementService::run_daily_settlements", skip_all)]
+    async fn run_daily_settlements(&self) -> Result<usize, ErrorResponse> {
+        let (period_start, period_end) = self.previous_period();
+
+        // Retried before today's settlements, so a payout that fails today
+        // is only tried again tomorrow
+        if let Err(e) = self.retry_unpaid_settlements().await {
+            error!("Failed to retry unpaid settlements: {}", e);
+        }
+
+        let merchants = self
+            .merchant_repository
+            .find_all()
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?;
+
+        let mut created = 0;
+
+        for merchant in merchants {
+            let settlement = match self
+                .settlement_repository
+                .create_settlement(merchant.merchant_id, period_start, period_end)
+                .await
+            {
+                Ok(Some(settlement)) => settlement,
+                Ok(None) => continue,
+                Err(e) => {
+                    error!("Failed to settle merchant {}: {}", merchant.merchant_id, e);
+                    continue;
+                }
+            };
+
+            created += 1;
+
+            self.settle(&merchant, settlement).await;
+        }
+
+        Ok(created)
+    }
+}
@@ -1,19 +1,31 @@
 use crate::{
     abstract_trait::{
+        fee::{DynFeeService, FeeQuote},
+        fraud::DynFraudEngine,
+        limit::DynLimitService,
+        payment_channel::{DynPaymentChannel, PaymentChannelRequest},
         saldo::DynSaldoRepository,
         topup::{DynTopupRepository, TopupServiceTrait},
         user::DynUserRepository,
     },
     domain::{
         request::{
+            fee::{FeeQuoteRequest, RecordFeeRevenue},
+            fraud::ScreenTransactionRequest,
+            limit::{CheckLimitRequest, MinimumAmounts},
             saldo::{CreateSaldoRequest, UpdateSaldoBalance},
-            topup::{CreateTopupRequest, UpdateTopupAmount, UpdateTopupRequest},
+            topup::{
+                CreateTopupRequest, PaymentCallbackRequest, UpdateTopupAmount, UpdateTopupRequest,
+            },
         },
         response::{topup::TopupResponse, ApiResponse, ErrorResponse},
     },
-    utils::errors::AppError,
+    entities::topups,
+    utils::{errors::AppError, metrics::record_transaction},
 };
-use tracing::{error, info};
+use sea_orm::DbErr;
+use tracing::{error, info, instrument};
+use uuid::Uuid;
 
 use async_trait::async_trait;
 
@@ -21,24 +33,294 @@ pub struct TopupService {
     topup_repository: DynTopupRepository,
     saldo_repository: DynSaldoRepository,
     user_repository: DynUserRepository,
+    payment_channel: DynPaymentChannel,
+    fee_service: DynFeeService,
+    limit_service: DynLimitService,
+    fraud_engine: DynFraudEngine,
+    minimum_amounts: MinimumAmounts,
 }
 
 impl TopupService {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         topup_repository: DynTopupRepository,
         saldo_repository: DynSaldoRepository,
         user_repository: DynUserRepository,
+        payment_channel: DynPaymentChannel,
+        fee_service: DynFeeService,
+        limit_service: DynLimitService,
+        fraud_engine: DynFraudEngine,
+        minimum_amounts: MinimumAmounts,
     ) -> Self {
         Self {
             topup_repository,
             saldo_repository,
             user_repository,
+            payment_channel,
+            fee_service,
+            limit_service,
+            fraud_engine,
+            minimum_amounts,
         }
     }
+
+    /// Fee of a new top-up, which must leave something to credit.
+    async fn topup_fee(&self, input: &CreateTopupRequest) -> Result<FeeQuote, ErrorResponse> {
+        let fee = self
+            .fee_service
+            .calculate_fee(&FeeQuoteRequest {
+                transaction_type: "topup".to_string(),
+                method: Some(input.topup_method.clone()),
+                merchant_id: None,
+                amount: input.topup_amount,
+            })
+            .await?;
+
+        if fee.fee_amount >= input.topup_amount {
+            return Err(ErrorResponse::from(AppError::ValidationError(format!(
+                "Topup amount must be more than the fee of {}",
+                fee.fee_amount
+            ))));
+        }
+
+        Ok(fee)
+    }
+
+    async fn credit_saldo(&self, user_id: i32, amount: i32) -> Result<(), DbErr> {
+        match self.saldo_repository.find_by_user_id(user_id).await? {
+            Some(current_saldo) => {
+                let new_balance = current_saldo.total_balance + amount;
+
+                self.saldo_repository
+                    .update_balance(&UpdateSaldoBalance {
+                        user_id,
+                        total_balance: new_balance,
+                    })
+                    .await?;
+
+                info!(
+                    "Saldo updated successfully for user {}. New balance: {}",
+                    user_id, new_balance
+                );
+            }
+            None => {
+                self.saldo_repository
+                    .create(&CreateSaldoRequest {
+                        user_id,
+                        total_balance: amount,
+                    })
+                    .await?;
+
+                info!(
+                    "Initial saldo created for user {} with balance {}",
+                    user_id, amount
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Moves a topup back from `paid` to `pending` after it could not be
+    /// credited, so that a later callback can credit it again.
+    async fn reopen_topup(&self, topup_id: i32) {
+        if let Err(rb_err) = self
+            .topup_repository
+            .transition_status(topup_id, "paid", "pending")
+            .await
+        {
+            error!(
+                "Failed to reopen topup {} after credit failure: {}",
+                topup_id, rb_err
+            );
+        }
+    }
+
+    /// Moves a topup back to `paid` after its reversal could not be
+    /// completed.
+    async fn restore_paid_topup(&self, topup_id: i32) {
+        if let Err(rb_err) = self
+            .topup_repository
+            .transition_status(topup_id, "reversed", "paid")
+            .await
+        {
+            error!(
+                "Failed to restore topup {} after reversal failure: {}",
+                topup_id, rb_err
+            );
+        }
+    }
+
+    /// Credits a topup that was just moved to `paid`, less its fee, and
+    /// posts the fee to revenue. The topup is reopened as `pending` when
+    /// either the saldo or the fee could not be updated.
+    async fn credit_paid_topup(&self, topup: &topups::Model) -> Result<(), ErrorResponse> {
+        let credit_amount = topup.topup_amount - topup.fee_amount;
+
+        if let Err(db_err) = self.credit_saldo(topup.user_id, credit_amount).await {
+            error!(
+                "Failed to credit topup {} for user {}: {}",
+                topup.topup_id, topup.user_id, db_err
+            );
+
+            self.reopen_topup(topup.topup_id).await;
+
+            return Err(ErrorResponse::from(AppError::from(db_err)));
+        }
+
+        if let Err(e) = self
+            .fee_service
+            .post_fee(&RecordFeeRevenue {
+                transaction_type: "topup".to_string(),
+                transaction_id: topup.topup_id,
+                fee_rule_id: topup.fee_rule_id,
+                amount: topup.fee_amount,
+            })
+            .await
+        {
+            error!(
+                "Failed to post fee of topup {} to revenue: {}",
+                topup.topup_id, e
+            );
+
+            if let Err(rb_err) = self.credit_saldo(topup.user_id, -credit_amount).await {
+                error!(
+                    "Failed to take back credit of topup {} from user {}: {}",
+                    topup.topup_id, topup.user_id, rb_err
+                );
+            }
+
+            self.reopen_topup(topup.topup_id).await;
+
+            return Err(e);
+        }
+
+        Ok(())
+    }
+
+    /// Carries out a top-up, running it past the fraud engine first when
+    /// `screen` is set.
+    async fn process_topup(
+        &self,
+        input: &CreateTopupRequest,
+        screen: bool,
+    ) -> Result<ApiResponse<TopupResponse>, ErrorResponse> {
+        if let Err(validation_err) = input.validate(self.minimum_amounts.topup) {
+            error!("Validation failed for topup create: {}", validation_err);
+            return Err(ErrorResponse::from(AppError::ValidationError(
+                validation_err,
+            )));
+        }
+
+        let _user = self
+            .user_repository
+            .find_by_id(input.user_id)
+            .await
+            .map_err(|_| {
+                error!("User with id {} not found", input.user_id);
+                ErrorResponse::from(AppError::NotFound(format!(
+                    "User with id {} not found",
+                    input.user_id
+                )))
+            })?;
+
+        info!(
+            "User with id {} found, proceeding with topup creation",
+            input.user_id
+        );
+
+        let limit_request = CheckLimitRequest {
+            user_id: input.user_id,
+            transaction_type: "topup".to_string(),
+            amount: input.topup_amount,
+        };
+
+        self.limit_service
+            .check_transaction_limits(&limit_request)
+            .await?;
+        self.limit_service.check_balance_limit(&limit_request).await?;
+
+        let fee = self.topup_fee(input).await?;
+
+        if screen {
+            let payload = serde_json::to_string(input)
+                .map_err(|e| ErrorResponse::from(AppError::ValidationError(e.to_string())))?;
+
+            self.fraud_engine
+                .screen(&ScreenTransactionRequest {
+                    transaction_type: "topup".to_string(),
+                    user_id: input.user_id,
+                    amount: input.topup_amount,
+                    counterparty_id: None,
+                    device_id: None,
+                    payload,
+                })
+                .await?;
+        }
+
+        let payment_reference = format!("TOP-{}", Uuid::new_v4().simple());
+
+        let topup = self
+            .topup_repository
+            .create(input, &payment_reference, fee)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?;
+
+        let instructions = match self
+            .payment_channel
+            .create_payment(&PaymentChannelRequest {
+                reference: payment_reference.clone(),
+                method: input.topup_method.clone(),
+                amount: topup.topup_amount,
+            })
+            .await
+        {
+            Ok(instructions) => instructions,
+            Err(err) => {
+                error!(
+                    "Payment channel rejected topup {}: {}",
+                    topup.topup_id, err
+                );
+
+                if let Err(db_err) = self
+                    .topup_repository
+                    .transition_status(topup.topup_id, "pending", "failed")
+                    .await
+                {
+                    error!("Failed to mark topup {} as failed: {}", topup.topup_id, db_err);
+                }
+
+                return Err(ErrorResponse::from(err));
+            }
+        };
+
+        let instructions = serde_json::to_string(&instructions)
+            .map_err(|e| ErrorResponse::from(AppError::PaymentChannelError(e.to_string())))?;
+
+        let topup = self
+            .topup_repository
+            .update_payment_instructions(topup.topup_id, &instructions)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?;
+
+        info!(
+            "Topup {} created for user {}, awaiting payment {}",
+            topup.topup_id, input.user_id, payment_reference
+        );
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "Topup created, awaiting payment".to_string(),
+            data: TopupResponse::from(topup),
+        })
+    }
 }
 
 #[async_trait]
 impl TopupServiceTrait for TopupService {
+    #[instrument(name = "TopupService::get_topups", skip_all)]
     async fn get_topups(&self) -> Result<ApiResponse<Vec<TopupResponse>>, ErrorResponse> {
         let topup = self
             .topup_repository
@@ -51,7 +333,7 @@ impl TopupServiceTrait for TopupService {
             Ok(topup) => {
                 let topup_response: Vec<TopupResponse> = topup
                     .into_iter()
-                    .map(|topup| TopupResponse::from(topup))
+                    .map(TopupResponse::from)
                     .collect();
 
                 info!("Successfully retrieved {} topups.", topup_response.len());
@@ -69,6 +351,7 @@ impl TopupServiceTrait for TopupService {
         }
     }
 
+    #[instrument(name = "TopupService::get_topup", skip_all)]
     async fn get_topup(
         &self,
         id: i32,
@@ -105,6 +388,7 @@ impl TopupServiceTrait for TopupService {
         }
     }
 
+    #[instrument(name = "TopupService::get_topup_users", skip_all)]
     async fn get_topup_users(
         &self,
         id: i32,
@@ -160,6 +444,7 @@ impl TopupServiceTrait for TopupService {
         }
     }
 
+    #[instrument(name = "TopupService::get_topup_user", skip_all)]
     async fn get_topup_user(
         &self,
         id: i32,
@@ -197,135 +482,32 @@ impl TopupServiceTrait for TopupService {
         }
     }
 
+    #[instrument(name = "TopupService::create_topup", skip_all)]
     async fn create_topup(
         &self,
         input: &CreateTopupRequest,
     ) -> Result<ApiResponse<TopupResponse>, ErrorResponse> {
-        if let Err(validation_err) = input.validate() {
-            error!("Validation failed for topup create: {}", validation_err);
-            return Err(ErrorResponse::from(AppError::ValidationError(
-                validation_err,
-            )));
-        }
-
-        let _user = self
-            .user_repository
-            .find_by_id(input.user_id)
-            .await
-            .map_err(|_| {
-                error!("User with id {} not found", input.user_id);
-                ErrorResponse::from(AppError::NotFound(format!(
-                    "User with id {} not found",
-                    input.user_id
-                )))
-            })?;
-
-        info!(
-            "User with id {} found, proceeding with topup creation",
-            input.user_id
-        );
-
-        let topup = self
-            .topup_repository
-            .create(&input)
-            .await
-            .map_err(AppError::from)
-            .map_err(ErrorResponse::from)?;
-
-        info!(
-            "Topup created for user with id {}: topup amount {}",
-            input.user_id, topup.topup_amount
-        );
-
-        match self.saldo_repository.find_by_user_id(input.user_id).await {
-            Ok(Some(current_saldo)) => {
-                let new_balance = current_saldo.total_balance + topup.topup_amount;
-                let request = UpdateSaldoBalance {
-                    user_id: input.user_id,
-                    total_balance: new_balance,
-                };
-
-                if let Err(db_err) = self.saldo_repository.update_balance(&request).await {
-                    error!(
-                        "Failed to update saldo balance for user {}: {}",
-                        input.user_id, db_err
-                    );
-
-                    if let Err(rb_err) = self.topup_repository.delete(topup.topup_id).await {
-                        error!(
-                            "Failed to rollback topup creation for user {}: {}",
-                            input.user_id, rb_err
-                        );
-                    }
-
-                    return Err(ErrorResponse::from(AppError::from(db_err)));
-                }
-
-                info!(
-                    "Saldo updated successfully for user {}. New balance: {}",
-                    input.user_id, new_balance
-                );
-            }
-            Ok(None) => {
-                let create_saldo_request = CreateSaldoRequest {
-                    user_id: input.user_id,
-                    total_balance: topup.topup_amount,
-                };
-
-                if let Err(db_err) = self.saldo_repository.create(&create_saldo_request).await {
-                    error!(
-                        "Failed to create initial saldo for user {}: {}",
-                        input.user_id, db_err
-                    );
-
-                    if let Err(rb_err) = self.topup_repository.delete(topup.topup_id).await {
-                        error!(
-                            "Failed to rollback topup creation for user {}: {}",
-                            input.user_id, rb_err
-                        );
-                    }
-
-                    return Err(ErrorResponse::from(AppError::from(db_err)));
-                }
-
-                info!(
-                    "Initial saldo created for user {} with balance {}",
-                    input.user_id, topup.topup_amount
-                );
-            }
-            Err(_) => {
-                error!("Failed to retrieve saldo for user {}", input.user_id);
-
-                if let Err(rb_err) = self.topup_repository.delete(topup.topup_id).await {
-                    error!(
-                        "Failed to rollback topup creation for user {}: {}",
-                        input.user_id, rb_err
-                    );
-                }
-
-                return Err(ErrorResponse::from(AppError::NotFound(format!(
-                    "Saldo with user_id {} not found",
-                    input.user_id
-                ))));
-            }
-        }
+        let result = self.process_topup(input, true).await;
+        record_transaction("topup", &input.topup_method, input.topup_amount, &result);
+        result
+    }
 
-        info!(
-            "Topup successfully created for user {}. Total balance updated.",
-            input.user_id
-        );
-        Ok(ApiResponse {
-            status: "success".to_string(),
-            message: "Topup created successfully".to_string(),
-            data: TopupResponse::from(topup),
-        })
+    #[instrument(name = "TopupService::create_approved_topup", skip_all)]
+    async fn create_approved_topup(
+        &self,
+        input: &CreateTopupRequest,
+    ) -> Result<ApiResponse<TopupResponse>, ErrorResponse> {
+        let result = self.process_topup(input, false).await;
+        record_transaction("topup", &input.topup_method, input.topup_amount, &result);
+        result
     }
 
+    #[instrument(name = "TopupService::update_topup", skip_all)]
     async fn update_topup(
         &self,
         input: &UpdateTopupRequest,
     ) -> Result<ApiResponse<Option<TopupResponse>>, ErrorResponse> {
-        if let Err(validation_err) = input.validate() {
+        if let Err(validation_err) = input.validate(self.minimum_amounts.topup) {
             error!("Validation failed for topup update: {}", validation_err);
             return Err(ErrorResponse::from(AppError::ValidationError(
                 validation_err,
@@ -374,81 +556,48 @@ impl TopupServiceTrait for TopupService {
             )))
         })?;
 
-        let topup_difference = input.topup_amount - existing_topup.topup_amount;
+        if existing_topup.user_id != input.user_id {
+            error!(
+                "Topup {} does not belong to user {}",
+                existing_topup.topup_id, input.user_id
+            );
+            return Err(ErrorResponse::from(AppError::NotFound(format!(
+                "Topup with id {} not found",
+                input.topup_id
+            ))));
+        }
 
-        info!(
-            "Calculating topup difference: new amount {} - old amount {} = difference {}",
-            input.topup_amount, existing_topup.topup_amount, topup_difference
-        );
+        // Only the amount the provider has yet to confirm may change. A paid
+        // topup has reached the saldo and is corrected by an admin reversal.
+        if existing_topup.status != "pending" {
+            error!(
+                "Topup {} is {} and cannot be updated",
+                existing_topup.topup_id, existing_topup.status
+            );
+            return Err(ErrorResponse::from(AppError::ValidationError(format!(
+                "Topup with status {} cannot be updated",
+                existing_topup.status
+            ))));
+        }
 
-        let update_topup = UpdateTopupAmount {
-            topup_id: input.topup_id,
-            topup_amount: input.topup_amount,
-        };
+        if input.topup_amount <= existing_topup.fee_amount {
+            return Err(ErrorResponse::from(AppError::ValidationError(format!(
+                "Topup amount must be more than the fee of {}",
+                existing_topup.fee_amount
+            ))));
+        }
 
         self.topup_repository
-            .update_amount(&update_topup)
+            .update_amount(&UpdateTopupAmount {
+                topup_id: input.topup_id,
+                topup_amount: input.topup_amount,
+            })
             .await
             .map_err(|e| {
                 error!("Failed to update topup amount: {}", e);
                 ErrorResponse::from(AppError::from(e))
             })?;
 
-        match self.saldo_repository.find_by_user_id(input.user_id).await {
-            Ok(Some(current_saldo)) => {
-                let new_balance = current_saldo.total_balance + topup_difference;
-
-                info!(
-                    "Updating saldo: current balance {} + topup difference {} = new balance {}",
-                    current_saldo.total_balance, topup_difference, new_balance
-                );
-
-                let request = UpdateSaldoBalance {
-                    user_id: input.user_id,
-                    total_balance: new_balance,
-                };
-
-                // Update saldo balance
-                if let Err(db_err) = self.saldo_repository.update_balance(&request).await {
-                    error!(
-                        "Failed to update saldo balance for user {}: {}",
-                        input.user_id, db_err
-                    );
-
-                    // Rollback topup update on failure
-                    let rollback = UpdateTopupAmount {
-                        topup_id: existing_topup.topup_id,
-                        topup_amount: existing_topup.topup_amount,
-                    };
-
-                    if let Err(rb_err) = self.topup_repository.update_amount(&rollback).await {
-                        error!(
-                            "Failed to rollback topup update for user {}: {}",
-                            input.user_id, rb_err
-                        );
-                    }
-
-                    return Err(ErrorResponse::from(AppError::from(db_err)));
-                }
-
-                info!(
-                    "Saldo updated successfully for user {}. New balance: {}",
-                    input.user_id, new_balance
-                );
-            }
-            Ok(None) => {
-                error!("No saldo found for user {} to update", input.user_id);
-                return Err(ErrorResponse::from(AppError::NotFound(format!(
-                    "Saldo for user {} not found",
-                    input.user_id
-                ))));
-            }
-            Err(e) => {
-                error!("Failed to retrieve saldo for user {}: {}", input.user_id, e);
-                return Err(ErrorResponse::from(AppError::from(e)));
-            }
-        }
-
         let updated_topup = self
             .topup_repository
             .find_by_id(input.topup_id)
@@ -472,6 +621,116 @@ impl TopupServiceTrait for TopupService {
         }
     }
 
+    #[instrument(name = "TopupService::reverse_topup", skip_all)]
+    async fn reverse_topup(&self, id: i32) -> Result<ApiResponse<TopupResponse>, ErrorResponse> {
+        let topup = self
+            .topup_repository
+            .find_by_id(id)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?
+            .ok_or_else(|| {
+                ErrorResponse::from(AppError::NotFound(format!(
+                    "Topup with id {} not found",
+                    id
+                )))
+            })?;
+
+        if topup.status != "paid" {
+            return Err(ErrorResponse::from(AppError::ValidationError(format!(
+                "Topup with status {} cannot be reversed",
+                topup.status
+            ))));
+        }
+
+        let credited = topup.topup_amount - topup.fee_amount;
+
+        let saldo = self
+            .saldo_repository
+            .find_by_user_id(topup.user_id)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?
+            .ok_or_else(|| {
+                ErrorResponse::from(AppError::NotFound(format!(
+                    "Saldo for user {} not found",
+                    topup.user_id
+                )))
+            })?;
+
+        if saldo.total_balance < credited {
+            error!(
+                "Cannot reverse topup {}: user {} has {} of the {} credited",
+                topup.topup_id, topup.user_id, saldo.total_balance, credited
+            );
+            return Err(ErrorResponse::from(AppError::InsufficientBalance));
+        }
+
+        let reversed = self
+            .topup_repository
+            .transition_status(topup.topup_id, "paid", "reversed")
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?;
+
+        if !reversed {
+            return Err(ErrorResponse::from(AppError::ValidationError(format!(
+                "Topup {} was changed by another request",
+                topup.topup_id
+            ))));
+        }
+
+        let reverse_fee = |amount| RecordFeeRevenue {
+            transaction_type: "topup".to_string(),
+            transaction_id: topup.topup_id,
+            fee_rule_id: topup.fee_rule_id,
+            amount,
+        };
+
+        if let Err(e) = self
+            .fee_service
+            .post_fee(&reverse_fee(-topup.fee_amount))
+            .await
+        {
+            error!("Failed to reverse fee of topup {}: {}", topup.topup_id, e);
+            self.restore_paid_topup(topup.topup_id).await;
+            return Err(e);
+        }
+
+        if let Err(db_err) = self.credit_saldo(topup.user_id, -credited).await {
+            error!(
+                "Failed to debit reversed topup {} from user {}: {}",
+                topup.topup_id, topup.user_id, db_err
+            );
+
+            if let Err(e) = self
+                .fee_service
+                .post_fee(&reverse_fee(topup.fee_amount))
+                .await
+            {
+                error!("Failed to repost fee of topup {}: {}", topup.topup_id, e);
+            }
+            self.restore_paid_topup(topup.topup_id).await;
+
+            return Err(ErrorResponse::from(AppError::from(db_err)));
+        }
+
+        info!(
+            "Reversed topup {}, debiting {} from user {}",
+            topup.topup_id, credited, topup.user_id
+        );
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "Topup reversed successfully".to_string(),
+            data: TopupResponse::from(topups::Model {
+                status: "reversed".to_string(),
+                ..topup
+            }),
+        })
+    }
+
+    #[instrument(name = "TopupService::delete_topup", skip_all)]
     async fn delete_topup(&self, id: i32) -> Result<ApiResponse<()>, ErrorResponse> {
         let user = self.user_repository.find_by_id(id).await.map_err(|_| {
             ErrorResponse::from(AppError::NotFound(format!("User with id {} not found", id)))
@@ -509,4 +768,166 @@ impl TopupServiceTrait for TopupService {
             }
         }
     }
+
+    #[instrument(name = "TopupService::handle_payment_callback", skip_all)]
+    async fn handle_payment_callback(
+        &self,
+        payload: &[u8],
+        signature: &str,
+    ) -> Result<ApiResponse<TopupResponse>, ErrorResponse> {
+        if !self.payment_channel.verify_signature(payload, signature) {
+            error!("Rejected payment callback with an invalid signature");
+            return Err(ErrorResponse::from(AppError::InvalidSignature));
+        }
+
+        let callback: PaymentCallbackRequest = serde_json::from_slice(payload).map_err(|e| {
+            ErrorResponse::from(AppError::ValidationError(format!(
+                "Invalid callback payload: {}",
+                e
+            )))
+        })?;
+
+        if let Err(validation_err) = callback.validate() {
+            return Err(ErrorResponse::from(AppError::ValidationError(
+                validation_err,
+            )));
+        }
+
+        let topup = self
+            .topup_repository
+            .find_by_payment_reference(&callback.payment_reference)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?
+            .ok_or_else(|| {
+                ErrorResponse::from(AppError::NotFound(format!(
+                    "Topup with payment reference {} not found",
+                    callback.payment_reference
+                )))
+            })?;
+
+        if callback.status == "paid" && callback.amount != topup.topup_amount {
+            error!(
+                "Payment {} reported {} but topup {} expects {}",
+                callback.payment_reference, callback.amount, topup.topup_id, topup.topup_amount
+            );
+            return Err(ErrorResponse::from(AppError::ValidationError(
+                "Paid amount does not match the topup amount".to_string(),
+            )));
+        }
+
+        let transitioned = self
+            .topup_repository
+            .transition_status(topup.topup_id, "pending", &callback.status)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?;
+
+        if !transitioned && callback.status == "paid" && topup.status != "paid" {
+            error!(
+                "Payment {} arrived for topup {} which is already {}",
+                callback.payment_reference, topup.topup_id, topup.status
+            );
+        }
+
+        // Providers retry callbacks, so a topup that already left `pending`
+        // is acknowledged without touching the saldo again.
+        if transitioned && callback.status == "paid" {
+            self.credit_paid_topup(&topup).await?;
+        }
+
+        let topup = self
+            .topup_repository
+            .find_by_id(topup.topup_id)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?
+            .unwrap_or(topup);
+
+        info!(
+            "Payment callback for topup {} processed, status {}",
+            topup.topup_id, topup.status
+        );
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: if transitioned {
+                "Payment callback processed".to_string()
+            } else {
+                "Payment callback already processed".to_string()
+            },
+            data: TopupResponse::from(topup),
+        })
+    }
+
+    #[instrument(name = "TopupService::create_paid_topup", skip_all)]
+    async fn create_paid_topup(
+        &self,
+        input: &CreateTopupRequest,
+        payment_reference: &str,
+    ) -> Result<ApiResponse<TopupResponse>, ErrorResponse> {
+        if input.topup_amount <= 0 {
+            return Err(ErrorResponse::from(AppError::ValidationError(
+                "Topup amount must be a positive integer".to_string(),
+            )));
+        }
+
+        if let Some(existing) = self
+            .topup_repository
+            .find_by_payment_reference(payment_reference)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?
+        {
+            info!(
+                "Payment {} already recorded as topup {}",
+                payment_reference, existing.topup_id
+            );
+
+            return Ok(ApiResponse {
+                status: "success".to_string(),
+                message: "Topup already recorded".to_string(),
+                data: TopupResponse::from(existing),
+            });
+        }
+
+        let fee = self.topup_fee(input).await?;
+
+        let topup = self
+            .topup_repository
+            .create(input, payment_reference, fee)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?;
+
+        let transitioned = self
+            .topup_repository
+            .transition_status(topup.topup_id, "pending", "paid")
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?;
+
+        if transitioned {
+            self.credit_paid_topup(&topup).await?;
+        }
+
+        let topup = self
+            .topup_repository
+            .find_by_id(topup.topup_id)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?
+            .unwrap_or(topup);
+
+        info!(
+            "Paid topup {} recorded for user {} from payment {}",
+            topup.topup_id, input.user_id, payment_reference
+        );
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "Topup recorded successfully".to_string(),
+            data: TopupResponse::from(topup),
+        })
+    }
 }
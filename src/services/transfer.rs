@@ -1,152 +1,237 @@
 use async_trait::async_trait;
-use tracing::{error, info};
+use chrono::Duration;
+use tracing::{error, info, instrument};
 
 use crate::{
     abstract_trait::{
+        beneficiary::DynBeneficiaryRepository,
+        clock::DynClock,
+        fee::{DynFeeService, FeeQuote},
+        fraud::DynFraudEngine,
+        limit::DynLimitService,
         saldo::DynSaldoRepository,
         transfer::{DynTransferRepository, TransferServiceTrait},
         user::DynUserRepository,
     },
     domain::{
         request::{
+            fee::{FeeQuoteRequest, RecordFeeRevenue},
+            fraud::ScreenTransactionRequest,
+            limit::{CheckLimitRequest, MinimumAmounts},
             saldo::UpdateSaldoBalance,
-            transfer::{CreateTransferRequest, UpdateTransferRequest},
+            transfer::{
+                CreateTransferByAccountRequest, CreateTransferRequest, UpdateTransferRequest,
+            },
         },
-        response::{transfer::TransferResponse, ApiResponse, ErrorResponse},
+        response::{
+            transfer::{RecipientResponse, TransferResponse},
+            ApiResponse, ErrorResponse,
+        },
+    },
+    entities::users,
+    utils::{
+        errors::AppError,
+        metrics::{record_insufficient_funds, record_transaction},
+        random_vcc::is_valid_account_number,
     },
-    utils::errors::AppError,
 };
 
+/// A recipient can only receive `limit` in total from a sender during the
+/// first `hours` hours after that sender first paid them.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BeneficiaryCoolingOff {
+    pub limit: i64,
+    pub hours: i64,
+}
+
+impl Default for BeneficiaryCoolingOff {
+    fn default() -> Self {
+        Self {
+            limit: 1_000_000,
+            hours: 24,
+        }
+    }
+}
+
+/// Method label of a transfer in the metrics.
+fn transfer_method(input: &CreateTransferRequest) -> &'static str {
+    if input.beneficiary_id.is_some() {
+        "beneficiary"
+    } else {
+        "wallet"
+    }
+}
+
 pub struct TransferService {
     transfer_repository: DynTransferRepository,
     saldo_repository: DynSaldoRepository,
     user_repository: DynUserRepository,
+    beneficiary_repository: DynBeneficiaryRepository,
+    fee_service: DynFeeService,
+    limit_service: DynLimitService,
+    fraud_engine: DynFraudEngine,
+    clock: DynClock,
+    cooling_off: BeneficiaryCoolingOff,
+    minimum_amounts: MinimumAmounts,
 }
 
 impl TransferService {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         transfer_repository: DynTransferRepository,
         saldo_repository: DynSaldoRepository,
         user_repository: DynUserRepository,
+        beneficiary_repository: DynBeneficiaryRepository,
+        fee_service: DynFeeService,
+        limit_service: DynLimitService,
+        fraud_engine: DynFraudEngine,
+        clock: DynClock,
+        cooling_off: BeneficiaryCoolingOff,
+        minimum_amounts: MinimumAmounts,
     ) -> Self {
         Self {
             transfer_repository,
             saldo_repository,
             user_repository,
+            beneficiary_repository,
+            fee_service,
+            limit_service,
+            fraud_engine,
+            clock,
+            cooling_off,
+            minimum_amounts,
         }
     }
-}
 
-#[async_trait]
-impl TransferServiceTrait for TransferService {
-    async fn get_transfers(&self) -> Result<ApiResponse<Vec<TransferResponse>>, ErrorResponse> {
-        let transfer = self
-            .transfer_repository
-            .find_all()
+    /// Turns a transfer addressed to a saved beneficiary into one addressed
+    /// to the beneficiary's user.
+    async fn resolve_beneficiary(
+        &self,
+        input: &CreateTransferRequest,
+        beneficiary_id: i32,
+    ) -> Result<CreateTransferRequest, ErrorResponse> {
+        let beneficiary = self
+            .beneficiary_repository
+            .find_by_id(beneficiary_id)
             .await
             .map_err(AppError::from)
-            .map_err(ErrorResponse::from)?;
-
-        let transfer_response: Vec<TransferResponse> = transfer
-            .into_iter()
-            .map(|transfer| TransferResponse::from(transfer))
-            .collect();
+            .map_err(ErrorResponse::from)?
+            .filter(|beneficiary| beneficiary.user_id == input.transfer_from)
+            .ok_or_else(|| {
+                ErrorResponse::from(AppError::NotFound(format!(
+                    "Beneficiary with id {} not found",
+                    beneficiary_id
+                )))
+            })?;
 
-        Ok(ApiResponse {
-            status: "success".to_string(),
-            message: "Transfer retrieved successfully".to_string(),
-            data: transfer_response,
-        })
-    }
+        let noc_transfer = beneficiary.noc_transfer.as_deref().ok_or_else(|| {
+            ErrorResponse::from(AppError::ValidationError(
+                "Bank account beneficiaries cannot receive internal transfers".to_string(),
+            ))
+        })?;
 
-    async fn get_transfer(
-        &self,
-        id: i32,
-    ) -> Result<ApiResponse<Option<TransferResponse>>, ErrorResponse> {
-        let transfer = self
-            .transfer_repository
-            .find_by_id(id)
-            .await
-            .map_err(AppError::from)
-            .map_err(ErrorResponse::from)?;
+        let recipient = self.find_recipient_by_account(noc_transfer).await?;
 
-        if let Some(transfer) = transfer {
-            Ok(ApiResponse {
-                status: "success".to_string(),
-                message: "Transfer retrieved successfully".to_string(),
-                data: Some(TransferResponse::from(transfer)),
-            })
-        } else {
-            Err(ErrorResponse::from(AppError::NotFound(format!(
-                "Transfer with id {} not found",
-                id
-            ))))
-        }
+        Ok(CreateTransferRequest {
+            transfer_from: input.transfer_from,
+            transfer_to: recipient.user_id,
+            transfer_amount: input.transfer_amount,
+            beneficiary_id: Some(beneficiary_id),
+            device_id: input.device_id.clone(),
+            merchant_payment: input.merchant_payment,
+        })
     }
 
-    async fn get_transfer_users(
+    /// Enforces the cooling-off limit when the sender first paid the
+    /// recipient within the window, however the recipient is addressed.
+    async fn check_cooling_off(
         &self,
-        id: i32,
-    ) -> Result<ApiResponse<Option<Vec<TransferResponse>>>, ErrorResponse> {
-        let _user = self.user_repository.find_by_id(id).await.map_err(|_| {
-            ErrorResponse::from(AppError::NotFound(format!("User with id {} not found", id)))
-        })?;
+        input: &CreateTransferRequest,
+        recipient: &users::Model,
+    ) -> Result<(), ErrorResponse> {
+        let window_start = self.clock.now() - Duration::hours(self.cooling_off.hours);
 
-        let transfer = self
+        let first_paid_at = self
             .transfer_repository
-            .find_by_users(id)
+            .find_first_transfer_time(input.transfer_from, recipient.user_id)
             .await
             .map_err(AppError::from)
             .map_err(ErrorResponse::from)?;
 
-        let transfer_response: Option<Vec<TransferResponse>> =
-            transfer.map(|transfers| transfers.into_iter().map(TransferResponse::from).collect());
-
-        let response = ApiResponse {
-            status: "success".to_string(),
-            data: transfer_response,
-            message: "Success".to_string(),
+        let sent = match first_paid_at {
+            Some(first_paid_at) if first_paid_at < window_start => return Ok(()),
+            Some(first_paid_at) => self
+                .transfer_repository
+                .sum_amount_since(input.transfer_from, recipient.user_id, first_paid_at)
+                .await
+                .map_err(AppError::from)
+                .map_err(ErrorResponse::from)?,
+            None => 0,
         };
 
-        Ok(response)
+        if sent + input.transfer_amount as i64 > self.cooling_off.limit {
+            error!(
+                "Cooling-off limit exceeded for recipient {} of user {}",
+                recipient.user_id, input.transfer_from
+            );
+            return Err(ErrorResponse::from(AppError::ValidationError(format!(
+                "Transfers to a recipient first paid in the last {} hours are limited to {}",
+                self.cooling_off.hours, self.cooling_off.limit
+            ))));
+        }
+
+        Ok(())
     }
 
-    async fn get_transfer_user(
+    async fn find_recipient_by_account(
         &self,
-        id: i32,
-    ) -> Result<ApiResponse<Option<TransferResponse>>, ErrorResponse> {
-        let _user = self.user_repository.find_by_id(id).await.map_err(|_| {
-            ErrorResponse::from(AppError::NotFound(format!("User with id {} not found", id)))
-        })?;
+        noc_transfer: &str,
+    ) -> Result<users::Model, ErrorResponse> {
+        if !is_valid_account_number(noc_transfer) {
+            return Err(ErrorResponse::from(AppError::ValidationError(
+                "Invalid recipient account number".to_string(),
+            )));
+        }
 
-        let transfer: Option<TransferResponse> = self
-            .transfer_repository
-            .find_by_user(id)
+        self.user_repository
+            .find_by_noc_transfer(noc_transfer)
             .await
             .map_err(AppError::from)
             .map_err(ErrorResponse::from)?
-            .map(TransferResponse::from);
-
-        let response = ApiResponse {
-            status: "success".to_string(),
-            data: transfer,
-            message: "Success".to_string(),
-        };
-
-        Ok(response)
+            .ok_or_else(|| {
+                ErrorResponse::from(AppError::NotFound(format!(
+                    "Account {} not found",
+                    noc_transfer
+                )))
+            })
     }
 
-    async fn create_transfer(
+    /// Carries out a transfer, running it past the fraud engine first when
+    /// `screen` is set.
+    async fn process_transfer(
         &self,
         input: &CreateTransferRequest,
+        screen: bool,
     ) -> Result<ApiResponse<TransferResponse>, ErrorResponse> {
-        if let Err(validation_err) = input.validate() {
+        if let Err(validation_err) = input.validate(self.minimum_amounts.transfer) {
             error!("Validation failed for transfer create: {}", validation_err);
             return Err(ErrorResponse::from(AppError::ValidationError(
                 validation_err,
             )));
         }
 
+        // Held transfers are replayed from the request as it was received
+        let request = input;
+
+        let resolved;
+        let input = match input.beneficiary_id {
+            Some(beneficiary_id) => {
+                resolved = self.resolve_beneficiary(input, beneficiary_id).await?;
+                &resolved
+            }
+            None => input,
+        };
+
         // Check if sender and receiver exist
         self.user_repository
             .find_by_id(input.transfer_from)
@@ -158,7 +243,8 @@ impl TransferServiceTrait for TransferService {
                 )))
             })?;
 
-        self.user_repository
+        let recipient = self
+            .user_repository
             .find_by_id(input.transfer_to)
             .await
             .map_err(|_| {
@@ -168,15 +254,46 @@ impl TransferServiceTrait for TransferService {
                 )))
             })?;
 
-        // Create the transfer
-        let transfer = self
-            .transfer_repository
-            .create(&input)
-            .await
-            .map_err(AppError::from)
-            .map_err(ErrorResponse::from)?;
+        if let Some(recipient) = &recipient {
+            self.check_cooling_off(input, recipient).await?;
+        }
+
+        // The sender's tier caps what leaves the wallet, the receiver's tier
+        // caps the balance it may reach
+        self.limit_service
+            .check_transaction_limits(&CheckLimitRequest {
+                user_id: input.transfer_from,
+                transaction_type: "transfer".to_string(),
+                amount: input.transfer_amount,
+            })
+            .await?;
+
+        self.limit_service
+            .check_balance_limit(&CheckLimitRequest {
+                user_id: input.transfer_to,
+                transaction_type: "transfer".to_string(),
+                amount: input.transfer_amount,
+            })
+            .await?;
+
+        // Merchant payments are charged to the merchant when they settle
+        let fee = if input.merchant_payment {
+            FeeQuote::default()
+        } else {
+            self.fee_service
+                .calculate_fee(&FeeQuoteRequest {
+                    transaction_type: "transfer".to_string(),
+                    method: None,
+                    merchant_id: None,
+                    amount: input.transfer_amount,
+                })
+                .await?
+        };
+
+        // The sender pays the fee on top of the amount the receiver gets
+        let total_debit = input.transfer_amount + fee.fee_amount;
 
-        // Sender's saldo adjustment
+        // Sender's saldo must cover the transfer before anything is written
         let sender_saldo = self
             .saldo_repository
             .find_by_user_id(input.transfer_from)
@@ -186,9 +303,48 @@ impl TransferServiceTrait for TransferService {
                     "Saldo with User id {} not found",
                     input.transfer_from
                 )))
+            })?
+            .ok_or_else(|| {
+                ErrorResponse::from(AppError::NotFound(format!(
+                    "Saldo with User id {} not found",
+                    input.transfer_from
+                )))
             })?;
 
-        let sender_balance = sender_saldo.unwrap().total_balance - input.transfer_amount;
+        if sender_saldo.total_balance < total_debit {
+            error!(
+                "Insufficient balance for user {}. Attempted transfer: {} plus fee {}",
+                input.transfer_from, input.transfer_amount, fee.fee_amount
+            );
+            record_insufficient_funds("transfer");
+            return Err(ErrorResponse::from(AppError::InsufficientBalance));
+        }
+
+        if screen {
+            let payload = serde_json::to_string(request)
+                .map_err(|e| ErrorResponse::from(AppError::ValidationError(e.to_string())))?;
+
+            self.fraud_engine
+                .screen(&ScreenTransactionRequest {
+                    transaction_type: "transfer".to_string(),
+                    user_id: input.transfer_from,
+                    amount: input.transfer_amount,
+                    counterparty_id: Some(input.transfer_to),
+                    device_id: input.device_id.clone(),
+                    payload,
+                })
+                .await?;
+        }
+
+        // Create the transfer
+        let transfer = self
+            .transfer_repository
+            .create(input, fee.fee_amount)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?;
+
+        let sender_balance = sender_saldo.total_balance - total_debit;
 
         let request_sender_balance = UpdateSaldoBalance {
             user_id: input.transfer_from,
@@ -243,19 +399,233 @@ impl TransferServiceTrait for TransferService {
             return Err(ErrorResponse::from(AppError::from(db_err)));
         }
 
+        if let Err(e) = self
+            .fee_service
+            .post_fee(&RecordFeeRevenue {
+                transaction_type: "transfer".to_string(),
+                transaction_id: transfer.transfer_id,
+                fee_rule_id: fee.fee_rule_id,
+                amount: fee.fee_amount,
+            })
+            .await
+        {
+            error!(
+                "Failed to post fee of transfer {} to revenue: {}",
+                transfer.transfer_id, e
+            );
+
+            // Undo both legs so no money moves without its fee
+            for (user_id, total_balance) in [
+                (input.transfer_from, sender_saldo.total_balance),
+                (input.transfer_to, receiver_balance - input.transfer_amount),
+            ] {
+                if let Err(rb_err) = self
+                    .saldo_repository
+                    .update_balance(&UpdateSaldoBalance {
+                        user_id,
+                        total_balance,
+                    })
+                    .await
+                {
+                    error!(
+                        "Failed to restore saldo of user {} after transfer {}: {}",
+                        user_id, transfer.transfer_id, rb_err
+                    );
+                }
+            }
+
+            self.transfer_repository
+                .delete(transfer.transfer_id)
+                .await
+                .map_err(AppError::from)
+                .map_err(ErrorResponse::from)?;
+
+            return Err(e);
+        }
+
         Ok(ApiResponse {
             status: "success".to_string(),
             message: "Transfer created successfully".to_string(),
             data: TransferResponse::from(transfer),
         })
     }
+}
+
+#[async_trait]
+impl TransferServiceTrait for TransferService {
+    #[instrument(name = "TransferService::get_transfers", skip_all)]
+    async fn get_transfers(&self) -> Result<ApiResponse<Vec<TransferResponse>>, ErrorResponse> {
+        let transfer = self
+            .transfer_repository
+            .find_all()
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?;
+
+        let transfer_response: Vec<TransferResponse> = transfer
+            .into_iter()
+            .map(TransferResponse::from)
+            .collect();
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "Transfer retrieved successfully".to_string(),
+            data: transfer_response,
+        })
+    }
+
+    #[instrument(name = "TransferService::get_transfer", skip_all)]
+    async fn get_transfer(
+        &self,
+        id: i32,
+    ) -> Result<ApiResponse<Option<TransferResponse>>, ErrorResponse> {
+        let transfer = self
+            .transfer_repository
+            .find_by_id(id)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?;
+
+        if let Some(transfer) = transfer {
+            Ok(ApiResponse {
+                status: "success".to_string(),
+                message: "Transfer retrieved successfully".to_string(),
+                data: Some(TransferResponse::from(transfer)),
+            })
+        } else {
+            Err(ErrorResponse::from(AppError::NotFound(format!(
+                "Transfer with id {} not found",
+                id
+            ))))
+        }
+    }
+
+    #[instrument(name = "TransferService::get_transfer_users", skip_all)]
+    async fn get_transfer_users(
+        &self,
+        id: i32,
+    ) -> Result<ApiResponse<Option<Vec<TransferResponse>>>, ErrorResponse> {
+        let _user = self.user_repository.find_by_id(id).await.map_err(|_| {
+            ErrorResponse::from(AppError::NotFound(format!("User with id {} not found", id)))
+        })?;
+
+        let transfer = self
+            .transfer_repository
+            .find_by_users(id)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?;
+
+        let transfer_response: Option<Vec<TransferResponse>> =
+            transfer.map(|transfers| transfers.into_iter().map(TransferResponse::from).collect());
+
+        let response = ApiResponse {
+            status: "success".to_string(),
+            data: transfer_response,
+            message: "Success".to_string(),
+        };
+
+        Ok(response)
+    }
+
+    #[instrument(name = "TransferService::get_transfer_user", skip_all)]
+    async fn get_transfer_user(
+        &self,
+        id: i32,
+    ) -> Result<ApiResponse<Option<TransferResponse>>, ErrorResponse> {
+        let _user = self.user_repository.find_by_id(id).await.map_err(|_| {
+            ErrorResponse::from(AppError::NotFound(format!("User with id {} not found", id)))
+        })?;
+
+        let transfer: Option<TransferResponse> = self
+            .transfer_repository
+            .find_by_user(id)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?
+            .map(TransferResponse::from);
+
+        let response = ApiResponse {
+            status: "success".to_string(),
+            data: transfer,
+            message: "Success".to_string(),
+        };
+
+        Ok(response)
+    }
+
+    #[instrument(name = "TransferService::create_transfer", skip_all)]
+    async fn create_transfer(
+        &self,
+        input: &CreateTransferRequest,
+    ) -> Result<ApiResponse<TransferResponse>, ErrorResponse> {
+        let result = self.process_transfer(input, true).await;
+        record_transaction("transfer", transfer_method(input), input.transfer_amount, &result);
+        result
+    }
+
+    #[instrument(name = "TransferService::create_approved_transfer", skip_all)]
+    async fn create_approved_transfer(
+        &self,
+        input: &CreateTransferRequest,
+    ) -> Result<ApiResponse<TransferResponse>, ErrorResponse> {
+        let result = self.process_transfer(input, false).await;
+        record_transaction("transfer", transfer_method(input), input.transfer_amount, &result);
+        result
+    }
+
+    #[instrument(name = "TransferService::find_recipient", skip_all)]
+    async fn find_recipient(
+        &self,
+        noc_transfer: &str,
+    ) -> Result<ApiResponse<RecipientResponse>, ErrorResponse> {
+        let recipient = self.find_recipient_by_account(noc_transfer).await?;
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "Recipient retrieved successfully".to_string(),
+            data: RecipientResponse::from(recipient),
+        })
+    }
+
+    #[instrument(name = "TransferService::create_transfer_by_account", skip_all)]
+    async fn create_transfer_by_account(
+        &self,
+        input: &CreateTransferByAccountRequest,
+    ) -> Result<ApiResponse<TransferResponse>, ErrorResponse> {
+        if let Err(validation_err) = input.validate(self.minimum_amounts.transfer) {
+            error!("Validation failed for transfer create: {}", validation_err);
+            return Err(ErrorResponse::from(AppError::ValidationError(
+                validation_err,
+            )));
+        }
+
+        let recipient = self.find_recipient_by_account(&input.noc_transfer).await?;
+
+        if recipient.user_id == input.transfer_from {
+            return Err(ErrorResponse::from(AppError::ValidationError(
+                "Cannot transfer to your own account".to_string(),
+            )));
+        }
+
+        self.create_transfer(&CreateTransferRequest {
+            transfer_from: input.transfer_from,
+            transfer_to: recipient.user_id,
+            transfer_amount: input.transfer_amount,
+            beneficiary_id: None,
+            device_id: input.device_id.clone(),
+            merchant_payment: false,
+        })
+        .await
+    }
 
+    #[instrument(name = "TransferService::update_transfer", skip_all)]
     async fn update_transfer(
         &self,
         input: &UpdateTransferRequest,
     ) -> Result<ApiResponse<TransferResponse>, ErrorResponse> {
         // Validate input
-        if let Err(validation_err) = input.validate() {
+        if let Err(validation_err) = input.validate(self.minimum_amounts.transfer) {
             error!("Validation failed for transfer update: {}", validation_err);
             return Err(ErrorResponse::from(AppError::ValidationError(
                 validation_err,
@@ -387,6 +757,7 @@ impl TransferServiceTrait for TransferService {
         })
     }
 
+    #[instrument(name = "TransferService::delete_transfer", skip_all)]
     async fn delete_transfer(&self, id: i32) -> Result<ApiResponse<()>, ErrorResponse> {
         let user = self.user_repository.find_by_id(id).await.map_err(|_| {
             ErrorResponse::from(AppError::NotFound(format!("User with id {} not found", id)))
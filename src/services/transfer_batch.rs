@@ -0,0 +1,464 @@
+use async_trait::async_trait;
+use tracing::{error, info, instrument};
+
+use crate::{
+    abstract_trait::{
+        saldo::DynSaldoRepository,
+        transfer::DynTransferService,
+        transfer_batch::{DynTransferBatchRepository, TransferBatchServiceTrait},
+        user::DynUserRepository,
+    },
+    domain::{
+        request::{
+            limit::MinimumAmounts,
+            transfer::CreateTransferRequest,
+            transfer_batch::{
+                CreateTransferBatchRequest, UpdateTransferBatchItemResult,
+                UpdateTransferBatchStatus,
+            },
+        },
+        response::{
+            transfer_batch::{
+                TransferBatchDetailResponse, TransferBatchItemResponse, TransferBatchResponse,
+            },
+            ApiResponse, ErrorKind, ErrorResponse,
+        },
+    },
+    entities::transfer_batches,
+    utils::errors::AppError,
+};
+
+const BATCHES_PER_RUN: u64 = 10;
+
+/// Recorded on items a crashed run claimed but never finished. Their
+/// transfer may or may not have gone through, so they are not retried.
+const INTERRUPTED_ITEM_MESSAGE: &str =
+    "Interrupted before the result was recorded; check the transfer history before retrying";
+
+pub struct TransferBatchService {
+    transfer_batch_repository: DynTransferBatchRepository,
+    transfer_service: DynTransferService,
+    saldo_repository: DynSaldoRepository,
+    user_repository: DynUserRepository,
+    minimum_amounts: MinimumAmounts,
+}
+
+impl TransferBatchService {
+    pub fn new(
+        transfer_batch_repository: DynTransferBatchRepository,
+        transfer_service: DynTransferService,
+        saldo_repository: DynSaldoRepository,
+        user_repository: DynUserRepository,
+        minimum_amounts: MinimumAmounts,
+    ) -> Self {
+        Self {
+            transfer_batch_repository,
+            transfer_service,
+            saldo_repository,
+            user_repository,
+            minimum_amounts,
+        }
+    }
+
+    async fn find_owned_batch(
+        &self,
+        id: i32,
+        user_id: i32,
+    ) -> Result<transfer_batches::Model, ErrorResponse> {
+        self.transfer_batch_repository
+            .find_by_id(id)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?
+            .filter(|batch| batch.user_id == user_id)
+            .ok_or_else(|| {
+                ErrorResponse::from(AppError::NotFound(format!(
+                    "Transfer batch with id {} not found",
+                    id
+                )))
+            })
+    }
+
+    /// Stores an item outcome. A failed write is logged rather than returned
+    /// so the rest of the batch still runs; the item stays `processing` and
+    /// is reported as interrupted when the batch is resumed.
+    async fn record_item_result(&self, result: UpdateTransferBatchItemResult) {
+        if let Err(err) = self
+            .transfer_batch_repository
+            .update_item_result(&result)
+            .await
+        {
+            error!(
+                "Failed to record result of transfer batch item {}: {}",
+                result.item_id, err
+            );
+        }
+    }
+
+    async fn process_batch(&self, batch: transfer_batches::Model) -> Result<(), ErrorResponse> {
+        info!("Processing transfer batch {}", batch.batch_id);
+
+        self.transfer_batch_repository
+            .update_status(&UpdateTransferBatchStatus {
+                batch_id: batch.batch_id,
+                status: "processing".to_string(),
+                succeeded_items: batch.succeeded_items,
+                failed_items: batch.failed_items,
+            })
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?;
+
+        let items = self
+            .transfer_batch_repository
+            .find_items(batch.batch_id)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?;
+
+        let mut succeeded_items = 0;
+        let mut failed_items = 0;
+        // Items whose transfer may still go through, held for fraud review
+        // or being run by another worker
+        let mut in_flight_items = 0;
+
+        for item in items {
+            match item.status.as_str() {
+                "pending" => {}
+                "processing" => {
+                    error!(
+                        "Transfer batch {} line {} was interrupted mid-transfer",
+                        batch.batch_id, item.line_no
+                    );
+                    failed_items += 1;
+                    self.record_item_result(UpdateTransferBatchItemResult {
+                        item_id: item.item_id,
+                        status: "interrupted".to_string(),
+                        transfer_id: None,
+                        error_message: Some(INTERRUPTED_ITEM_MESSAGE.to_string()),
+                        fraud_review_id: None,
+                    })
+                    .await;
+                    continue;
+                }
+                "succeeded" => {
+                    succeeded_items += 1;
+                    continue;
+                }
+                "held" => {
+                    in_flight_items += 1;
+                    continue;
+                }
+                _ => {
+                    failed_items += 1;
+                    continue;
+                }
+            }
+
+            match self
+                .transfer_batch_repository
+                .claim_item(item.item_id)
+                .await
+            {
+                Ok(true) => {}
+                Ok(false) => {
+                    info!(
+                        "Transfer batch {} line {} was claimed by another run",
+                        batch.batch_id, item.line_no
+                    );
+                    in_flight_items += 1;
+                    continue;
+                }
+                Err(err) => {
+                    error!(
+                        "Failed to claim transfer batch {} line {}: {}",
+                        batch.batch_id, item.line_no, err
+                    );
+                    failed_items += 1;
+                    continue;
+                }
+            }
+
+            let outcome = self
+                .transfer_service
+                .create_transfer(&CreateTransferRequest {
+                    transfer_from: batch.user_id,
+                    transfer_to: item.transfer_to,
+                    transfer_amount: item.transfer_amount,
+                    beneficiary_id: None,
+                    device_id: None,
+                    merchant_payment: false,
+                })
+                .await
+                .map(|response| response.data.transfer_id);
+
+            let result = match outcome {
+                Ok(transfer_id) => {
+                    succeeded_items += 1;
+                    UpdateTransferBatchItemResult {
+                        item_id: item.item_id,
+                        status: "succeeded".to_string(),
+                        transfer_id: Some(transfer_id),
+                        error_message: None,
+                        fraud_review_id: None,
+                    }
+                }
+                Err(err) => {
+                    if let ErrorKind::HeldForReview(review_id) = err.kind {
+                        // The transfer still runs if the review is approved
+                        info!(
+                            "Transfer batch {} line {} held under fraud review {}",
+                            batch.batch_id, item.line_no, review_id
+                        );
+                        in_flight_items += 1;
+                        UpdateTransferBatchItemResult {
+                            item_id: item.item_id,
+                            status: "held".to_string(),
+                            transfer_id: None,
+                            error_message: Some(err.message),
+                            fraud_review_id: Some(review_id),
+                        }
+                    } else {
+                        error!(
+                            "Transfer batch {} line {} failed: {}",
+                            batch.batch_id, item.line_no, err.message
+                        );
+                        failed_items += 1;
+                        UpdateTransferBatchItemResult {
+                            item_id: item.item_id,
+                            status: "failed".to_string(),
+                            transfer_id: None,
+                            error_message: Some(err.message),
+                            fraud_review_id: None,
+                        }
+                    }
+                }
+            };
+
+            self.record_item_result(result).await;
+        }
+
+        // The batch is picked up again until every item has an outcome
+        let status = if in_flight_items > 0 {
+            "processing"
+        } else if failed_items == 0 {
+            "completed"
+        } else if succeeded_items == 0 {
+            "failed"
+        } else {
+            "partially_completed"
+        };
+
+        self.transfer_batch_repository
+            .update_status(&UpdateTransferBatchStatus {
+                batch_id: batch.batch_id,
+                status: status.to_string(),
+                succeeded_items,
+                failed_items,
+            })
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?;
+
+        info!(
+            "Transfer batch {} left {}: {} succeeded, {} failed, {} in flight",
+            batch.batch_id, status, succeeded_items, failed_items, in_flight_items
+        );
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl TransferBatchServiceTrait for TransferBatchService {
+    #[instrument(name = "TransferBatchService::create_batch", skip_all)]
+    async fn create_batch(
+        &self,
+        input: &CreateTransferBatchRequest,
+    ) -> Result<ApiResponse<TransferBatchResponse>, ErrorResponse> {
+        if let Err(validation_err) = input.validate(self.minimum_amounts.transfer) {
+            error!("Validation failed for transfer batch: {}", validation_err);
+            return Err(ErrorResponse::from(AppError::ValidationError(
+                validation_err,
+            )));
+        }
+
+        let sender_saldo = self
+            .saldo_repository
+            .find_by_user_id(input.transfer_from)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?
+            .ok_or_else(|| {
+                ErrorResponse::from(AppError::NotFound(format!(
+                    "Saldo with User id {} not found",
+                    input.transfer_from
+                )))
+            })?;
+
+        if (sender_saldo.total_balance as i64) < input.total_amount() {
+            error!(
+                "Insufficient balance for transfer batch from user {}",
+                input.transfer_from
+            );
+            return Err(ErrorResponse::from(AppError::ValidationError(
+                "Insufficient balance for batch total".to_string(),
+            )));
+        }
+
+        let mut errors = Vec::new();
+
+        for (index, item) in input.items.iter().enumerate() {
+            let recipient = self
+                .user_repository
+                .find_by_id(item.transfer_to)
+                .await
+                .map_err(AppError::from)
+                .map_err(ErrorResponse::from)?;
+
+            if recipient.is_none() {
+                errors.push(format!(
+                    "Item {}: User with id {} not found",
+                    index + 1,
+                    item.transfer_to
+                ));
+            }
+        }
+
+        if !errors.is_empty() {
+            return Err(ErrorResponse::from(AppError::ValidationError(
+                errors.join("; "),
+            )));
+        }
+
+        let batch = self
+            .transfer_batch_repository
+            .create(input)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?;
+
+        info!(
+            "Transfer batch {} accepted with {} items",
+            batch.batch_id, batch.total_items
+        );
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "Transfer batch accepted for processing".to_string(),
+            data: TransferBatchResponse::from(batch),
+        })
+    }
+
+    #[instrument(name = "TransferBatchService::get_batch", skip_all)]
+    async fn get_batch(
+        &self,
+        id: i32,
+        user_id: i32,
+    ) -> Result<ApiResponse<TransferBatchDetailResponse>, ErrorResponse> {
+        let batch = self.find_owned_batch(id, user_id).await?;
+
+        let items = self
+            .transfer_batch_repository
+            .find_items(batch.batch_id)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?;
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "Transfer batch retrieved successfully".to_string(),
+            data: TransferBatchDetailResponse {
+                batch: TransferBatchResponse::from(batch),
+                items: items
+                    .into_iter()
+                    .map(TransferBatchItemResponse::from)
+                    .collect(),
+            },
+        })
+    }
+
+    #[instrument(name = "TransferBatchService::get_batch_result_csv", skip_all)]
+    async fn get_batch_result_csv(&self, id: i32, user_id: i32) -> Result<String, ErrorResponse> {
+        let batch = self.find_owned_batch(id, user_id).await?;
+
+        let items = self
+            .transfer_batch_repository
+            .find_items(batch.batch_id)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?;
+
+        let mut writer = csv::Writer::from_writer(Vec::new());
+
+        writer
+            .write_record([
+                "line_no",
+                "transfer_to",
+                "transfer_amount",
+                "status",
+                "transfer_id",
+                "error_message",
+            ])
+            .map_err(|err| ErrorResponse::from(AppError::ValidationError(err.to_string())))?;
+
+        for item in items {
+            writer
+                .write_record([
+                    item.line_no.to_string(),
+                    item.transfer_to.to_string(),
+                    item.transfer_amount.to_string(),
+                    item.status,
+                    item.transfer_id
+                        .map(|id| id.to_string())
+                        .unwrap_or_default(),
+                    item.error_message.unwrap_or_default(),
+                ])
+                .map_err(|err| ErrorResponse::from(AppError::ValidationError(err.to_string())))?;
+        }
+
+        let data = writer
+            .into_inner()
+            .map_err(|err| ErrorResponse::from(AppError::ValidationError(err.to_string())))?;
+
+        String::from_utf8(data)
+            .map_err(|err| ErrorResponse::from(AppError::ValidationError(err.to_string())))
+    }
+
+    #[instrument(name = "TransferBatchService::process_pending_batches", skip_all)]
+    async fn process_pending_batches(&self) -> Result<usize, ErrorResponse> {
+        // Batches left in `processing` were interrupted mid-run; only their
+        // pending items are picked up again.
+        let mut batches = self
+            .transfer_batch_repository
+            .find_by_status("processing", BATCHES_PER_RUN)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?;
+
+        batches.extend(
+            self.transfer_batch_repository
+                .find_by_status("pending", BATCHES_PER_RUN)
+                .await
+                .map_err(AppError::from)
+                .map_err(ErrorResponse::from)?,
+        );
+
+        let mut processed = 0;
+
+        // One batch failing to finish must not hold up the others.
+        for batch in batches {
+            let batch_id = batch.batch_id;
+
+            match self.process_batch(batch).await {
+                Ok(()) => processed += 1,
+                Err(err) => error!(
+                    "Transfer batch {} could not be processed: {}",
+                    batch_id, err
+                ),
+            }
+        }
+
+        Ok(processed)
+    }
+}
@@ -1,4 +1,5 @@
-use tracing::{error, info};
+use sea_orm::{DbErr, SqlErr};
+use tracing::{error, info, instrument, warn};
 
 use crate::{
     abstract_trait::{
@@ -12,11 +13,47 @@ use crate::{
         },
         response::{user::UserResponse, ApiResponse, ErrorResponse},
     },
+    entities::users,
     utils::{errors::AppError, random_vcc::random_vcc},
 };
 
 use async_trait::async_trait;
 
+const MAX_NOC_TRANSFER_ATTEMPTS: usize = 5;
+
+fn is_noc_transfer_collision(err: &DbErr) -> bool {
+    matches!(
+        err.sql_err(),
+        Some(SqlErr::UniqueConstraintViolation(message)) if message.contains("noc_transfer")
+    )
+}
+
+/// Inserts a user with a freshly generated `noc_transfer`, generating a new
+/// one whenever it collides with an existing account number.
+pub(crate) async fn create_user_with_noc_transfer(
+    repository: &DynUserRepository,
+    mut request: CreateUserRequest,
+) -> Result<users::Model, DbErr> {
+    let mut attempt = 1;
+
+    loop {
+        request.noc_transfer = random_vcc().ok();
+
+        match repository.create_user(&request).await {
+            Err(err)
+                if attempt < MAX_NOC_TRANSFER_ATTEMPTS && is_noc_transfer_collision(&err) =>
+            {
+                warn!(
+                    "Generated noc_transfer already exists, retrying (attempt {})",
+                    attempt
+                );
+                attempt += 1;
+            }
+            result => return result,
+        }
+    }
+}
+
 pub struct UserService {
     repository: DynUserRepository,
     hashing: DynHashing,
@@ -33,6 +70,7 @@ impl UserService {
 
 #[async_trait]
 impl UserServiceTrait for UserService {
+    #[instrument(name = "UserService::get_users", skip_all)]
     async fn get_users(&self) -> Result<ApiResponse<Vec<UserResponse>>, ErrorResponse> {
         let users = self
             .repository
@@ -43,7 +81,7 @@ impl UserServiceTrait for UserService {
 
         let users_response: Vec<UserResponse> = users
             .into_iter()
-            .map(|users| UserResponse::from(users))
+            .map(UserResponse::from)
             .collect();
 
         Ok(ApiResponse {
@@ -53,6 +91,7 @@ impl UserServiceTrait for UserService {
         })
     }
 
+    #[instrument(name = "UserService::find_by_id", skip_all)]
     async fn find_by_id(
         &self,
         id: i32,
@@ -78,6 +117,7 @@ impl UserServiceTrait for UserService {
         }
     }
 
+    #[instrument(name = "UserService::create_user", skip_all)]
     async fn create_user(
         &self,
         input: &RegisterRequest,
@@ -109,21 +149,17 @@ impl UserServiceTrait for UserService {
             .await
             .map_err(|e| ErrorResponse::from(AppError::HashingError(e)))?;
 
-        let noc_transfer = random_vcc().map(|value| Some(value)).unwrap_or(None);
-
         let request = CreateUserRequest {
             firstname: input.firstname.clone(),
             lastname: input.lastname.clone(),
             email: input.email.clone(),
             password: hashed_password,
             confirm_password: input.confirm_password.clone(),
-            noc_transfer: noc_transfer.to_owned(),
+            noc_transfer: None,
         };
 
         info!("Creating user with email: {}", input.email);
-        let create_user = self
-            .repository
-            .create_user(&request)
+        let create_user = create_user_with_noc_transfer(&self.repository, request)
             .await
             .map_err(AppError::from)
             .map_err(ErrorResponse::from)?;
@@ -137,6 +173,7 @@ impl UserServiceTrait for UserService {
         })
     }
 
+    #[instrument(name = "UserService::update_user", skip_all)]
     async fn update_user(
         &self,
         input: &UpdateUserRequest,
@@ -162,6 +199,7 @@ impl UserServiceTrait for UserService {
         }))
     }
 
+    #[instrument(name = "UserService::delete_user", skip_all)]
     async fn delete_user(&self, id: i32) -> Result<ApiResponse<()>, ErrorResponse> {
         self.repository
             .delete_user(id)
@@ -0,0 +1,369 @@
+use async_trait::async_trait;
+use tracing::{error, info, instrument};
+
+use crate::{
+    abstract_trait::{
+        payment_channel::DynPaymentChannel,
+        topup::DynTopupService,
+        user::DynUserRepository,
+        virtual_account::{DynVirtualAccountRepository, VirtualAccountServiceTrait},
+    },
+    domain::{
+        request::{
+            topup::CreateTopupRequest,
+            virtual_account::{
+                CreateVirtualAccountRequest, ResolveSuspensePaymentRequest,
+                VirtualAccountPaymentRequest,
+            },
+        },
+        response::{
+            virtual_account::{
+                SuspensePaymentResponse, VirtualAccountPaymentResponse, VirtualAccountResponse,
+            },
+            ApiResponse, ErrorResponse,
+        },
+    },
+    utils::errors::AppError,
+};
+
+pub struct VirtualAccountService {
+    virtual_account_repository: DynVirtualAccountRepository,
+    topup_service: DynTopupService,
+    user_repository: DynUserRepository,
+    payment_channel: DynPaymentChannel,
+}
+
+impl VirtualAccountService {
+    pub fn new(
+        virtual_account_repository: DynVirtualAccountRepository,
+        topup_service: DynTopupService,
+        user_repository: DynUserRepository,
+        payment_channel: DynPaymentChannel,
+    ) -> Self {
+        Self {
+            virtual_account_repository,
+            topup_service,
+            user_repository,
+            payment_channel,
+        }
+    }
+
+    /// Company prefix the bank assigns us, followed by the zero-padded user
+    /// id, so every user has exactly one number per bank.
+    fn va_number(bank_code: &str, user_id: i32) -> String {
+        let prefix = match bank_code {
+            "bca" => "39358",
+            "mandiri" => "89608",
+            _ => "98812",
+        };
+
+        format!("{}{:0>11}", prefix, user_id)
+    }
+
+    fn topup_request(user_id: i32, payment: &VirtualAccountPaymentRequest) -> CreateTopupRequest {
+        CreateTopupRequest {
+            user_id,
+            topup_no: payment.payment_id.clone(),
+            topup_amount: payment.amount,
+            topup_method: payment.bank_code.clone(),
+        }
+    }
+
+    fn payment_reference(payment_id: &str) -> String {
+        format!("VA-{}", payment_id)
+    }
+}
+
+#[async_trait]
+impl VirtualAccountServiceTrait for VirtualAccountService {
+    #[instrument(name = "VirtualAccountService::get_virtual_accounts", skip_all)]
+    async fn get_virtual_accounts(
+        &self,
+        user_id: i32,
+    ) -> Result<ApiResponse<Vec<VirtualAccountResponse>>, ErrorResponse> {
+        let virtual_accounts = self
+            .virtual_account_repository
+            .find_by_user(user_id)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?;
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "Virtual accounts retrieved successfully".to_string(),
+            data: virtual_accounts
+                .into_iter()
+                .map(VirtualAccountResponse::from)
+                .collect(),
+        })
+    }
+
+    #[instrument(name = "VirtualAccountService::create_virtual_account", skip_all)]
+    async fn create_virtual_account(
+        &self,
+        input: &CreateVirtualAccountRequest,
+    ) -> Result<ApiResponse<VirtualAccountResponse>, ErrorResponse> {
+        if let Err(validation_err) = input.validate() {
+            error!(
+                "Validation failed for virtual account create: {}",
+                validation_err
+            );
+            return Err(ErrorResponse::from(AppError::ValidationError(
+                validation_err,
+            )));
+        }
+
+        let existing = self
+            .virtual_account_repository
+            .find_by_user(input.user_id)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?
+            .into_iter()
+            .find(|account| account.bank_code == input.bank_code);
+
+        if let Some(virtual_account) = existing {
+            return Ok(ApiResponse {
+                status: "success".to_string(),
+                message: "Virtual account retrieved successfully".to_string(),
+                data: VirtualAccountResponse::from(virtual_account),
+            });
+        }
+
+        let va_number = Self::va_number(&input.bank_code, input.user_id);
+
+        let virtual_account = self
+            .virtual_account_repository
+            .create(input, &va_number)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?;
+
+        info!(
+            "Virtual account {} at {} created for user {}",
+            virtual_account.va_number, virtual_account.bank_code, input.user_id
+        );
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "Virtual account created successfully".to_string(),
+            data: VirtualAccountResponse::from(virtual_account),
+        })
+    }
+
+    #[instrument(name = "VirtualAccountService::handle_payment", skip_all)]
+    async fn handle_payment(
+        &self,
+        payload: &[u8],
+        signature: &str,
+    ) -> Result<ApiResponse<VirtualAccountPaymentResponse>, ErrorResponse> {
+        if !self.payment_channel.verify_signature(payload, signature) {
+            error!("Rejected virtual account payment with an invalid signature");
+            return Err(ErrorResponse::from(AppError::InvalidSignature));
+        }
+
+        let payment: VirtualAccountPaymentRequest =
+            serde_json::from_slice(payload).map_err(|e| {
+                ErrorResponse::from(AppError::ValidationError(format!(
+                    "Invalid payment payload: {}",
+                    e
+                )))
+            })?;
+
+        if let Err(validation_err) = payment.validate() {
+            return Err(ErrorResponse::from(AppError::ValidationError(
+                validation_err,
+            )));
+        }
+
+        // A retried webhook for a payment already parked in suspense stays
+        // there until it is resolved by hand.
+        if let Some(suspense_payment) = self
+            .virtual_account_repository
+            .find_suspense_by_payment_id(&payment.payment_id)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?
+        {
+            return Ok(ApiResponse {
+                status: "success".to_string(),
+                message: "Payment already queued for review".to_string(),
+                data: VirtualAccountPaymentResponse {
+                    matched: false,
+                    topup: None,
+                    suspense_payment: Some(SuspensePaymentResponse::from(suspense_payment)),
+                },
+            });
+        }
+
+        let virtual_account = self
+            .virtual_account_repository
+            .find_by_number(&payment.bank_code, &payment.va_number)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?;
+
+        match virtual_account {
+            Some(virtual_account) => {
+                let topup = self
+                    .topup_service
+                    .create_paid_topup(
+                        &Self::topup_request(virtual_account.user_id, &payment),
+                        &Self::payment_reference(&payment.payment_id),
+                    )
+                    .await?;
+
+                info!(
+                    "Payment {} to {} {} credited to user {}",
+                    payment.payment_id,
+                    payment.bank_code,
+                    payment.va_number,
+                    virtual_account.user_id
+                );
+
+                Ok(ApiResponse {
+                    status: "success".to_string(),
+                    message: "Payment matched to virtual account".to_string(),
+                    data: VirtualAccountPaymentResponse {
+                        matched: true,
+                        topup: Some(topup.data),
+                        suspense_payment: None,
+                    },
+                })
+            }
+            None => {
+                let suspense_payment = self
+                    .virtual_account_repository
+                    .create_suspense(&payment)
+                    .await
+                    .map_err(AppError::from)
+                    .map_err(ErrorResponse::from)?;
+
+                error!(
+                    "Payment {} to unknown virtual account {} {} moved to suspense",
+                    payment.payment_id, payment.bank_code, payment.va_number
+                );
+
+                Ok(ApiResponse {
+                    status: "success".to_string(),
+                    message: "Payment queued for review".to_string(),
+                    data: VirtualAccountPaymentResponse {
+                        matched: false,
+                        topup: None,
+                        suspense_payment: Some(SuspensePaymentResponse::from(suspense_payment)),
+                    },
+                })
+            }
+        }
+    }
+
+    #[instrument(name = "VirtualAccountService::get_suspense_payments", skip_all)]
+    async fn get_suspense_payments(
+        &self,
+    ) -> Result<ApiResponse<Vec<SuspensePaymentResponse>>, ErrorResponse> {
+        let suspense_payments = self
+            .virtual_account_repository
+            .find_suspense_by_status("unresolved")
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?;
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "Suspense payments retrieved successfully".to_string(),
+            data: suspense_payments
+                .into_iter()
+                .map(SuspensePaymentResponse::from)
+                .collect(),
+        })
+    }
+
+    #[instrument(name = "VirtualAccountService::resolve_suspense_payment", skip_all)]
+    async fn resolve_suspense_payment(
+        &self,
+        input: &ResolveSuspensePaymentRequest,
+    ) -> Result<ApiResponse<SuspensePaymentResponse>, ErrorResponse> {
+        if let Err(validation_err) = input.validate() {
+            return Err(ErrorResponse::from(AppError::ValidationError(
+                validation_err,
+            )));
+        }
+
+        let suspense_payment = self
+            .virtual_account_repository
+            .find_suspense_by_id(input.suspense_payment_id)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?
+            .ok_or_else(|| {
+                ErrorResponse::from(AppError::NotFound(format!(
+                    "Suspense payment with id {} not found",
+                    input.suspense_payment_id
+                )))
+            })?;
+
+        if suspense_payment.status != "unresolved" {
+            return Err(ErrorResponse::from(AppError::ValidationError(
+                "Suspense payment is already resolved".to_string(),
+            )));
+        }
+
+        self.user_repository
+            .find_by_id(input.user_id)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?
+            .ok_or_else(|| {
+                ErrorResponse::from(AppError::NotFound(format!(
+                    "User with id {} not found",
+                    input.user_id
+                )))
+            })?;
+
+        let payment = VirtualAccountPaymentRequest {
+            payment_id: suspense_payment.payment_id.clone(),
+            bank_code: suspense_payment.bank_code.clone(),
+            va_number: suspense_payment.va_number.clone(),
+            amount: suspense_payment.amount,
+        };
+
+        // The payment reference makes the top-up idempotent, so a resolve
+        // retried after a partial failure does not credit twice.
+        let topup = self
+            .topup_service
+            .create_paid_topup(
+                &Self::topup_request(input.user_id, &payment),
+                &Self::payment_reference(&payment.payment_id),
+            )
+            .await?;
+
+        self.virtual_account_repository
+            .resolve_suspense(
+                suspense_payment.suspense_payment_id,
+                input.user_id,
+                topup.data.topup_id,
+            )
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?;
+
+        let suspense_payment = self
+            .virtual_account_repository
+            .find_suspense_by_id(suspense_payment.suspense_payment_id)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?
+            .unwrap_or(suspense_payment);
+
+        info!(
+            "Suspense payment {} resolved to user {} as topup {}",
+            suspense_payment.suspense_payment_id, input.user_id, topup.data.topup_id
+        );
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "Suspense payment resolved successfully".to_string(),
+            data: SuspensePaymentResponse::from(suspense_payment),
+        })
+    }
+}
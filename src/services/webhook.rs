@@ -0,0 +1,387 @@
+use async_trait::async_trait;
+use chrono::{Duration, TimeZone, Utc};
+use rand::Rng;
+use serde_json::json;
+use tracing::{error, info, instrument};
+
+use crate::{
+    abstract_trait::{
+        clock::DynClock,
+        merchant::DynMerchantRepository,
+        webhook::{DeliveryAttempt, DynWebhookRepository, WebhookServiceTrait},
+        webhook_sender::{DynWebhookSender, WebhookRequest},
+    },
+    domain::{
+        request::webhook::CreateWebhookEndpointRequest,
+        response::{
+            webhook::{WebhookDeliveryResponse, WebhookEndpointResponse},
+            ApiResponse, ErrorResponse,
+        },
+    },
+    entities::{merchants, webhook_deliveries},
+    utils::{errors::AppError, signature::sign_hmac_sha256},
+};
+
+const DELIVERIES_PER_RUN: u64 = 50;
+
+/// How often a failing delivery is retried. The wait doubles after every
+/// failed attempt, starting at `base_delay`.
+#[derive(Debug, Clone, Copy)]
+pub struct WebhookRetryPolicy {
+    pub max_attempts: i32,
+    pub base_delay: Duration,
+}
+
+impl Default for WebhookRetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 6,
+            base_delay: Duration::seconds(30),
+        }
+    }
+}
+
+impl WebhookRetryPolicy {
+    /// Wait before the attempt after `attempts` failed ones.
+    pub fn backoff(&self, attempts: i32) -> Duration {
+        let exponent = (attempts - 1).clamp(0, 16) as u32;
+        self.base_delay * 2_i32.pow(exponent)
+    }
+}
+
+pub struct WebhookService {
+    webhook_repository: DynWebhookRepository,
+    merchant_repository: DynMerchantRepository,
+    webhook_sender: DynWebhookSender,
+    clock: DynClock,
+    retry_policy: WebhookRetryPolicy,
+}
+
+impl WebhookService {
+    pub fn new(
+        webhook_repository: DynWebhookRepository,
+        merchant_repository: DynMerchantRepository,
+        webhook_sender: DynWebhookSender,
+        clock: DynClock,
+        retry_policy: WebhookRetryPolicy,
+    ) -> Self {
+        Self {
+            webhook_repository,
+            merchant_repository,
+            webhook_sender,
+            clock,
+            retry_policy,
+        }
+    }
+
+    async fn merchant_for_user(&self, user_id: i32) -> Result<merchants::Model, ErrorResponse> {
+        self.merchant_repository
+            .find_by_user(user_id)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?
+            .ok_or_else(|| {
+                ErrorResponse::from(AppError::NotFound(format!(
+                    "Merchant for user {} not found",
+                    user_id
+                )))
+            })
+    }
+
+    fn generate_secret() -> String {
+        let bytes: [u8; 32] = rand::rng().random();
+        hex::encode(bytes)
+    }
+
+    /// Makes one attempt at a delivery and records the outcome.
+    async fn attempt_delivery(
+        &self,
+        delivery: &webhook_deliveries::Model,
+    ) -> Result<webhook_deliveries::Model, ErrorResponse> {
+        let now = self.clock.now();
+
+        let endpoint = self
+            .webhook_repository
+            .find_endpoint_by_id(delivery.webhook_endpoint_id)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?
+            .filter(|endpoint| endpoint.is_active);
+
+        let event = self
+            .webhook_repository
+            .find_event_by_id(delivery.webhook_event_id)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?;
+
+        let (endpoint, event) = match (endpoint, event) {
+            (Some(endpoint), Some(event)) => (endpoint, event),
+            _ => {
+                return self
+                    .webhook_repository
+                    .record_attempt(
+                        delivery.webhook_delivery_id,
+                        &DeliveryAttempt {
+                            status: "failed".to_string(),
+                            response_status: None,
+                            error: Some("Endpoint is disabled".to_string()),
+                            next_attempt_at: now,
+                        },
+                    )
+                    .await
+                    .map_err(AppError::from)
+                    .map_err(ErrorResponse::from);
+            }
+        };
+
+        let data: serde_json::Value =
+            serde_json::from_str(&event.payload).unwrap_or(json!(event.payload));
+        let body = json!({
+            "id": event.webhook_event_id,
+            "type": event.event_type,
+            "created_at": event.created_at.map(|dt| Utc.from_utc_datetime(&dt)),
+            "data": data,
+        })
+        .to_string();
+
+        let result = self
+            .webhook_sender
+            .send(&WebhookRequest {
+                url: endpoint.url.clone(),
+                delivery_id: delivery.webhook_delivery_id,
+                event_type: event.event_type.clone(),
+                signature: sign_hmac_sha256(&endpoint.secret, body.as_bytes()),
+                body,
+            })
+            .await;
+
+        let attempts = delivery.attempts + 1;
+        let (response_status, error) = match result {
+            Ok(code) if (200..300).contains(&code) => (Some(code as i32), None),
+            Ok(code) => (
+                Some(code as i32),
+                Some(format!("Endpoint answered {}", code)),
+            ),
+            Err(e) => (None, Some(e.to_string())),
+        };
+
+        let status = match (&error, attempts >= self.retry_policy.max_attempts) {
+            (None, _) => "succeeded",
+            (Some(_), true) => "failed",
+            (Some(_), false) => "pending",
+        };
+
+        if let Some(error) = &error {
+            error!(
+                "Webhook delivery {} attempt {} to {} failed: {}",
+                delivery.webhook_delivery_id, attempts, endpoint.url, error
+            );
+        }
+
+        self.webhook_repository
+            .record_attempt(
+                delivery.webhook_delivery_id,
+                &DeliveryAttempt {
+                    status: status.to_string(),
+                    response_status,
+                    error: error.map(|e| e.chars().take(255).collect()),
+                    next_attempt_at: now + self.retry_policy.backoff(attempts),
+                },
+            )
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)
+    }
+}
+
+#[async_trait]
+impl WebhookServiceTrait for WebhookService {
+    #[instrument(name = "WebhookService::create_endpoint", skip_all)]
+    async fn create_endpoint(
+        &self,
+        input: &CreateWebhookEndpointRequest,
+    ) -> Result<ApiResponse<WebhookEndpointResponse>, ErrorResponse> {
+        if let Err(validation_err) = input.validate() {
+            error!(
+                "Validation failed for webhook endpoint create: {}",
+                validation_err
+            );
+            return Err(ErrorResponse::from(AppError::ValidationError(
+                validation_err,
+            )));
+        }
+
+        let merchant = self.merchant_for_user(input.user_id).await?;
+        let secret = Self::generate_secret();
+
+        let endpoint = self
+            .webhook_repository
+            .create_endpoint(input, merchant.merchant_id, &secret)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?;
+
+        info!(
+            "Webhook endpoint {} registered for merchant {}",
+            endpoint.webhook_endpoint_id, merchant.merchant_id
+        );
+
+        let mut response = WebhookEndpointResponse::from(endpoint);
+        response.secret = Some(secret);
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "Webhook endpoint created successfully".to_string(),
+            data: response,
+        })
+    }
+
+    #[instrument(name = "WebhookService::get_endpoints", skip_all)]
+    async fn get_endpoints(
+        &self,
+        user_id: i32,
+    ) -> Result<ApiResponse<Vec<WebhookEndpointResponse>>, ErrorResponse> {
+        let merchant = self.merchant_for_user(user_id).await?;
+
+        let endpoints = self
+            .webhook_repository
+            .find_endpoints_by_merchant(merchant.merchant_id)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?;
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "Webhook endpoints retrieved successfully".to_string(),
+            data: endpoints
+                .into_iter()
+                .map(WebhookEndpointResponse::from)
+                .collect(),
+        })
+    }
+
+    #[instrument(name = "WebhookService::delete_endpoint", skip_all)]
+    async fn delete_endpoint(
+        &self,
+        id: i32,
+        user_id: i32,
+    ) -> Result<ApiResponse<WebhookEndpointResponse>, ErrorResponse> {
+        let merchant = self.merchant_for_user(user_id).await?;
+
+        self.webhook_repository
+            .find_endpoint_by_id(id)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?
+            .filter(|endpoint| endpoint.merchant_id == merchant.merchant_id)
+            .ok_or_else(|| {
+                ErrorResponse::from(AppError::NotFound(format!(
+                    "Webhook endpoint with id {} not found",
+                    id
+                )))
+            })?;
+
+        // Deactivated rather than deleted so the delivery log keeps its
+        // endpoint.
+        let endpoint = self
+            .webhook_repository
+            .deactivate_endpoint(id)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?;
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "Webhook endpoint deleted successfully".to_string(),
+            data: WebhookEndpointResponse::from(endpoint),
+        })
+    }
+
+    #[instrument(name = "WebhookService::get_deliveries", skip_all)]
+    async fn get_deliveries(
+        &self,
+        user_id: i32,
+    ) -> Result<ApiResponse<Vec<WebhookDeliveryResponse>>, ErrorResponse> {
+        let merchant = self.merchant_for_user(user_id).await?;
+
+        let deliveries = self
+            .webhook_repository
+            .find_deliveries_by_merchant(merchant.merchant_id)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?;
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "Webhook deliveries retrieved successfully".to_string(),
+            data: deliveries
+                .into_iter()
+                .map(WebhookDeliveryResponse::from)
+                .collect(),
+        })
+    }
+
+    #[instrument(name = "WebhookService::replay_delivery", skip_all)]
+    async fn replay_delivery(
+        &self,
+        id: i32,
+        user_id: i32,
+    ) -> Result<ApiResponse<WebhookDeliveryResponse>, ErrorResponse> {
+        let merchant = self.merchant_for_user(user_id).await?;
+
+        let delivery = self
+            .webhook_repository
+            .find_delivery_by_id(id)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?
+            .filter(|delivery| delivery.merchant_id == merchant.merchant_id)
+            .ok_or_else(|| {
+                ErrorResponse::from(AppError::NotFound(format!(
+                    "Webhook delivery with id {} not found",
+                    id
+                )))
+            })?;
+
+        let replay = self
+            .webhook_repository
+            .create_delivery(
+                delivery.webhook_event_id,
+                delivery.webhook_endpoint_id,
+                merchant.merchant_id,
+            )
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?;
+
+        info!(
+            "Webhook delivery {} replayed as {}",
+            id, replay.webhook_delivery_id
+        );
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "Webhook delivery queued for replay".to_string(),
+            data: WebhookDeliveryResponse::from(replay),
+        })
+    }
+
+    #[instrument(name = "WebhookService::deliver_due_webhooks", skip_all)]
+    async fn deliver_due_webhooks(&self) -> Result<usize, ErrorResponse> {
+        let deliveries = self
+            .webhook_repository
+            .find_due_deliveries(self.clock.now(), DELIVERIES_PER_RUN)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?;
+
+        let attempted = deliveries.len();
+
+        for delivery in deliveries {
+            self.attempt_delivery(&delivery).await?;
+        }
+
+        Ok(attempted)
+    }
+}
@@ -1,44 +1,416 @@
 use crate::{
     abstract_trait::{
+        bank_account::DynBankAccountRepository,
+        fee::DynFeeService,
+        fraud::DynFraudEngine,
+        limit::DynLimitService,
+        payout::{DynPayoutProvider, PayoutRequest, PayoutStatus},
         saldo::DynSaldoRepository,
         user::DynUserRepository,
         withdraw::{DynWithdrawRepository, WithdrawServiceTrait},
     },
     domain::{
         request::{
-            saldo::UpdateSaldoWithdraw,
-            withdraw::{CreateWithdrawRequest, UpdateWithdrawRequest},
+            fee::{FeeQuoteRequest, RecordFeeRevenue},
+            fraud::ScreenTransactionRequest,
+            limit::{CheckLimitRequest, MinimumAmounts},
+            saldo::{UpdateSaldoBalance, UpdateSaldoWithdraw},
+            withdraw::{CreateWithdrawRequest, UpdateWithdrawRequest, UpdateWithdrawStatus},
         },
         response::{withdraw::WithdrawResponse, ApiResponse, ErrorResponse},
     },
-    utils::errors::AppError,
+    entities::withdraws,
+    utils::{
+        errors::AppError,
+        metrics::{record_insufficient_funds, record_transaction},
+    },
 };
 use async_trait::async_trait;
-use chrono::Utc;
-use tracing::{error, info};
+use chrono::{Duration, Utc};
+use tracing::{error, info, instrument, warn};
+
+const PAYOUTS_PER_SYNC: u64 = 50;
+
+/// How long a payout the provider has no record of may still be in flight
+/// before its withdraw is failed and the hold released.
+const UNKNOWN_PAYOUT_GRACE_MINUTES: i64 = 30;
+
+/// Withdrawals are all paid out by bank transfer.
+const WITHDRAW_METHOD: &str = "bank_transfer";
 
 pub struct WithdrawService {
     withdraw_repository: DynWithdrawRepository,
     saldo_repository: DynSaldoRepository,
     user_repository: DynUserRepository,
+    bank_account_repository: DynBankAccountRepository,
+    payout_provider: DynPayoutProvider,
+    fee_service: DynFeeService,
+    limit_service: DynLimitService,
+    fraud_engine: DynFraudEngine,
+    minimum_amounts: MinimumAmounts,
 }
 
 impl WithdrawService {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         withdraw_repository: DynWithdrawRepository,
         saldo_repository: DynSaldoRepository,
         user_repository: DynUserRepository,
+        bank_account_repository: DynBankAccountRepository,
+        payout_provider: DynPayoutProvider,
+        fee_service: DynFeeService,
+        limit_service: DynLimitService,
+        fraud_engine: DynFraudEngine,
+        minimum_amounts: MinimumAmounts,
     ) -> Self {
         Self {
             withdraw_repository,
             saldo_repository,
             user_repository,
+            bank_account_repository,
+            payout_provider,
+            fee_service,
+            limit_service,
+            fraud_engine,
+            minimum_amounts,
+        }
+    }
+
+    /// Credits a held withdrawal amount back to the user's saldo.
+    async fn release_hold(&self, user_id: i32, amount: i32) -> Result<(), ErrorResponse> {
+        let saldo = self
+            .saldo_repository
+            .find_by_user_id(user_id)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?
+            .ok_or_else(|| {
+                ErrorResponse::from(AppError::NotFound(format!(
+                    "Saldo with user_id {} not found",
+                    user_id
+                )))
+            })?;
+
+        self.saldo_repository
+            .update_balance(&UpdateSaldoBalance {
+                user_id,
+                total_balance: saldo.total_balance + amount,
+            })
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?;
+
+        info!("Released withdraw hold of {} for user {}", amount, user_id);
+
+        Ok(())
+    }
+
+    /// Looks up a withdraw whose payout request never returned by the
+    /// reference it was sent under. The provider reference is returned so it
+    /// gets recorded. A payout the provider still has no record of after the
+    /// grace period never arrived, and its withdraw is failed.
+    async fn find_unreferenced_payout(
+        &self,
+        withdraw: &withdraws::Model,
+    ) -> Result<Option<(Option<String>, PayoutStatus)>, AppError> {
+        let reference = withdraw
+            .payout_reference
+            .clone()
+            .unwrap_or_else(|| format!("WD-{}", withdraw.withdraw_id));
+
+        if let Some(payout) = self.payout_provider.find_payout(&reference).await? {
+            return Ok(Some((Some(payout.provider_reference), payout.status)));
+        }
+
+        let grace_ended = withdraw.created_at.is_none_or(|created_at| {
+            Utc::now().naive_utc() - created_at > Duration::minutes(UNKNOWN_PAYOUT_GRACE_MINUTES)
+        });
+
+        Ok(grace_ended.then(|| {
+            (
+                None,
+                PayoutStatus::Failed("Payout never reached the provider".to_string()),
+            )
+        }))
+    }
+
+    /// Posts the fee of a withdraw to revenue, or takes it back out when
+    /// `amount` is negative.
+    async fn post_withdraw_fee(
+        &self,
+        withdraw: &withdraws::Model,
+        amount: i32,
+    ) -> Result<(), ErrorResponse> {
+        self.fee_service
+            .post_fee(&RecordFeeRevenue {
+                transaction_type: "withdraw".to_string(),
+                transaction_id: withdraw.withdraw_id,
+                fee_rule_id: withdraw.fee_rule_id,
+                amount,
+            })
+            .await
+    }
+
+    /// Records the payout status reported by the provider. Succeeded payouts
+    /// keep the held amount and post the fee to revenue; failed payouts give
+    /// both back. Returns `None` when the withdraw was already settled by a
+    /// concurrent update, which then owns the hold.
+    ///
+    /// The fee is posted before the withdraw leaves `pending`, so a failed
+    /// post is returned and leaves the withdraw to be synced again.
+    async fn apply_payout_status(
+        &self,
+        withdraw: &withdraws::Model,
+        provider_reference: Option<String>,
+        status: PayoutStatus,
+    ) -> Result<Option<withdraws::Model>, ErrorResponse> {
+        let (status_name, failure_reason) = match &status {
+            PayoutStatus::Pending => ("pending", None),
+            PayoutStatus::Succeeded => ("succeeded", None),
+            PayoutStatus::Failed(reason) => ("failed", Some(reason.clone())),
+        };
+
+        let fee_posted = matches!(status, PayoutStatus::Succeeded);
+
+        if fee_posted {
+            if let Err(e) = self.post_withdraw_fee(withdraw, withdraw.fee_amount).await {
+                error!(
+                    "Failed to post fee of withdraw {} to revenue: {}",
+                    withdraw.withdraw_id, e
+                );
+                return Err(e);
+            }
+        }
+
+        let updated = self
+            .withdraw_repository
+            .update_status(&UpdateWithdrawStatus {
+                withdraw_id: withdraw.withdraw_id,
+                status: status_name.to_string(),
+                provider_reference,
+                failure_reason,
+            })
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from);
+
+        let updated = match updated {
+            Ok(Some(updated)) => updated,
+            result => {
+                // The fee belongs to whichever update settles the withdraw
+                if fee_posted {
+                    if let Err(e) = self
+                        .post_withdraw_fee(withdraw, -withdraw.fee_amount)
+                        .await
+                    {
+                        error!(
+                            "Failed to reverse fee of withdraw {}: {}",
+                            withdraw.withdraw_id, e
+                        );
+                    }
+                }
+
+                result?;
+
+                info!(
+                    "Withdraw {} is no longer pending, leaving its hold alone",
+                    withdraw.withdraw_id
+                );
+                return Ok(None);
+            }
+        };
+
+        match status {
+            PayoutStatus::Pending | PayoutStatus::Succeeded => {}
+            PayoutStatus::Failed(_) => {
+                self.release_hold(
+                    withdraw.user_id,
+                    withdraw.withdraw_amount + withdraw.fee_amount,
+                )
+                .await?;
+            }
         }
+
+        Ok(Some(updated))
+    }
+
+    /// Carries out a withdraw, running it past the fraud engine first when
+    /// `screen` is set.
+    async fn process_withdraw(
+        &self,
+        input: &CreateWithdrawRequest,
+        screen: bool,
+    ) -> Result<ApiResponse<WithdrawResponse>, ErrorResponse> {
+        info!("Creating withdraw for user_id: {}", input.user_id);
+
+        if let Err(validation_err) = input.validate(self.minimum_amounts.withdraw) {
+            error!("Validation failed for withdraw create: {}", validation_err);
+            return Err(ErrorResponse::from(AppError::ValidationError(
+                validation_err,
+            )));
+        }
+        info!("Validation passed for withdraw creation");
+
+        let bank_account = self
+            .bank_account_repository
+            .find_by_id(input.bank_account_id)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?
+            .filter(|account| account.user_id == input.user_id)
+            .ok_or_else(|| {
+                ErrorResponse::from(AppError::NotFound(format!(
+                    "Bank account with id {} not found",
+                    input.bank_account_id
+                )))
+            })?;
+
+        self.limit_service
+            .check_transaction_limits(&CheckLimitRequest {
+                user_id: input.user_id,
+                transaction_type: "withdraw".to_string(),
+                amount: input.withdraw_amount,
+            })
+            .await?;
+
+        let fee = self
+            .fee_service
+            .calculate_fee(&FeeQuoteRequest {
+                transaction_type: "withdraw".to_string(),
+                method: Some(WITHDRAW_METHOD.to_string()),
+                merchant_id: None,
+                amount: input.withdraw_amount,
+            })
+            .await?;
+
+        let total_debit = input.withdraw_amount + fee.fee_amount;
+
+        let saldo = self
+            .saldo_repository
+            .find_by_user_id(input.user_id)
+            .await
+            .map_err(|_| {
+                error!("Saldo with user_id {} not found", input.user_id);
+                ErrorResponse::from(AppError::NotFound(format!(
+                    "Saldo with user_id {} not found",
+                    input.user_id
+                )))
+            })?;
+
+        let saldo_ref = saldo.as_ref().ok_or_else(|| {
+            error!("Saldo not found for user_id: {}", input.user_id);
+            ErrorResponse::from(AppError::NotFound("Saldo not found".to_string()))
+        })?;
+
+        info!(
+            "Saldo found for user_id: {}. Current balance: {}",
+            input.user_id, saldo_ref.total_balance
+        );
+
+        if saldo_ref.total_balance < total_debit {
+            error!(
+                "Insufficient balance for user_id: {}. Attempted withdrawal: {} plus fee {}",
+                input.user_id, input.withdraw_amount, fee.fee_amount
+            );
+            record_insufficient_funds("withdraw");
+            return Err(ErrorResponse::from(AppError::InsufficientBalance));
+        }
+        info!("User has sufficient balance for withdrawal");
+
+        if screen {
+            let payload = serde_json::to_string(input)
+                .map_err(|e| ErrorResponse::from(AppError::ValidationError(e.to_string())))?;
+
+            self.fraud_engine
+                .screen(&ScreenTransactionRequest {
+                    transaction_type: "withdraw".to_string(),
+                    user_id: input.user_id,
+                    amount: input.withdraw_amount,
+                    counterparty_id: Some(input.bank_account_id),
+                    device_id: input.device_id.clone(),
+                    payload,
+                })
+                .await?;
+        }
+
+        // The saldo is debited up front, fee included, and acts as the hold
+        // while the payout is pending; a failed payout credits it back.
+        let new_total_balance = saldo_ref.total_balance - total_debit;
+
+        let _update_saldo_balance = self
+            .saldo_repository
+            .update_saldo_withdraw(&UpdateSaldoWithdraw {
+                user_id: input.user_id,
+                withdraw_amount: Some(input.withdraw_amount),
+                withdraw_time: Some(Utc::now().naive_utc()),
+                total_balance: new_total_balance,
+            })
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?;
+
+        info!(
+            "Saldo balance updated for user_id: {}. New balance: {}",
+            input.user_id, new_total_balance
+        );
+
+        let withdraw = match self.withdraw_repository.create(input, fee).await {
+            Ok(withdraw) => withdraw,
+            Err(err) => {
+                error!("Failed to create withdraw, releasing hold: {}", err);
+                self.release_hold(input.user_id, total_debit).await?;
+                return Err(ErrorResponse::from(AppError::from(err)));
+            }
+        };
+
+        let payout_reference = withdraw
+            .payout_reference
+            .clone()
+            .unwrap_or_else(|| format!("WD-{}", withdraw.withdraw_id));
+
+        let payout = self
+            .payout_provider
+            .create_payout(&PayoutRequest {
+                reference: payout_reference,
+                bank_code: bank_account.bank_code,
+                account_number: bank_account.account_number,
+                holder_name: bank_account.holder_name,
+                amount: withdraw.withdraw_amount,
+            })
+            .await;
+
+        let withdraw = match payout {
+            Ok(result) => self
+                .apply_payout_status(&withdraw, Some(result.provider_reference), result.status)
+                .await?
+                .unwrap_or(withdraw),
+            // The provider may have accepted the payout before the error, so
+            // the hold stays until a sync finds out what happened to it.
+            Err(err) => {
+                warn!(
+                    "Payout request for withdraw {} did not complete, leaving it pending: {}",
+                    withdraw.withdraw_id, err
+                );
+                withdraw
+            }
+        };
+
+        info!(
+            "Withdraw {} created for user_id: {} with status {}",
+            withdraw.withdraw_id, input.user_id, withdraw.status
+        );
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "Withdraw created successfully".to_string(),
+            data: withdraw.into(),
+        })
     }
 }
 
 #[async_trait]
 impl WithdrawServiceTrait for WithdrawService {
+    #[instrument(name = "WithdrawService::get_withdraws", skip_all)]
     async fn get_withdraws(&self) -> Result<ApiResponse<Vec<WithdrawResponse>>, ErrorResponse> {
         let withdraw = self
             .withdraw_repository
@@ -49,7 +421,7 @@ impl WithdrawServiceTrait for WithdrawService {
 
         let withdraw_response: Vec<WithdrawResponse> = withdraw
             .into_iter()
-            .map(|withdraw| WithdrawResponse::from(withdraw))
+            .map(WithdrawResponse::from)
             .collect();
 
         info!(
@@ -64,6 +436,7 @@ impl WithdrawServiceTrait for WithdrawService {
         })
     }
 
+    #[instrument(name = "WithdrawService::get_withdraw", skip_all)]
     async fn get_withdraw(
         &self,
         id: i32,
@@ -91,6 +464,7 @@ impl WithdrawServiceTrait for WithdrawService {
         }
     }
 
+    #[instrument(name = "WithdrawService::get_withdraw_users", skip_all)]
     async fn get_withdraw_users(
         &self,
         id: i32,
@@ -135,6 +509,7 @@ impl WithdrawServiceTrait for WithdrawService {
         Ok(response)
     }
 
+    #[instrument(name = "WithdrawService::get_withdraw_user", skip_all)]
     async fn get_withdraw_user(
         &self,
         id: i32,
@@ -171,96 +546,32 @@ impl WithdrawServiceTrait for WithdrawService {
         }
     }
 
+    #[instrument(name = "WithdrawService::create_withdraw", skip_all)]
     async fn create_withdraw(
         &self,
         input: &CreateWithdrawRequest,
     ) -> Result<ApiResponse<WithdrawResponse>, ErrorResponse> {
-        info!("Creating withdraw for user_id: {}", input.user_id);
-
-        if let Err(validation_err) = input.validate() {
-            error!("Validation failed for withdraw create: {}", validation_err);
-            return Err(ErrorResponse::from(AppError::ValidationError(
-                validation_err,
-            )));
-        }
-        info!("Validation passed for withdraw creation");
-
-        let saldo = self
-            .saldo_repository
-            .find_by_user_id(input.user_id)
-            .await
-            .map_err(|_| {
-                error!("Saldo with user_id {} not found", input.user_id);
-                ErrorResponse::from(AppError::NotFound(format!(
-                    "Saldo with user_id {} not found",
-                    input.user_id
-                )))
-            })?;
-
-        let saldo_ref = saldo.as_ref().ok_or_else(|| {
-            error!("Saldo not found for user_id: {}", input.user_id);
-            ErrorResponse::from(AppError::NotFound("Saldo not found".to_string()))
-        })?;
-
-        info!(
-            "Saldo found for user_id: {}. Current balance: {}",
-            input.user_id, saldo_ref.total_balance
-        );
-
-        if saldo_ref.total_balance < input.withdraw_amount {
-            error!(
-                "Insufficient balance for user_id: {}. Attempted withdrawal: {}",
-                input.user_id, input.withdraw_amount
-            );
-            return Err(ErrorResponse::from(AppError::ValidationError(
-                "Insufficient balance".to_string(),
-            )));
-        }
-        info!("User has sufficient balance for withdrawal");
-
-        let new_total_balance = saldo_ref.total_balance - input.withdraw_amount;
-
-        let _update_saldo_balance = self
-            .saldo_repository
-            .update_saldo_withdraw(&UpdateSaldoWithdraw {
-                user_id: input.user_id,
-                withdraw_amount: Some(input.withdraw_amount),
-                withdraw_time: Some(Utc::now().naive_utc()),
-                total_balance: new_total_balance,
-            })
-            .await
-            .map_err(AppError::from)
-            .map_err(ErrorResponse::from)?;
-
-        info!(
-            "Saldo balance updated for user_id: {}. New balance: {}",
-            input.user_id, new_total_balance
-        );
-
-        let withdraw_create_result = self
-            .withdraw_repository
-            .create(&input)
-            .await
-            .map_err(AppError::from)
-            .map_err(ErrorResponse::from)?;
-
-        info!(
-            "Withdraw created successfully for user_id: {}",
-            input.user_id
-        );
+        let result = self.process_withdraw(input, true).await;
+        record_transaction("withdraw", WITHDRAW_METHOD, input.withdraw_amount, &result);
+        result
+    }
 
-        Ok(ApiResponse {
-            status: "success".to_string(),
-            message: "Withdraw created successfully".to_string(),
-            data: withdraw_create_result.into(),
-        })
+    #[instrument(name = "WithdrawService::create_approved_withdraw", skip_all)]
+    async fn create_approved_withdraw(
+        &self,
+        input: &CreateWithdrawRequest,
+    ) -> Result<ApiResponse<WithdrawResponse>, ErrorResponse> {
+        let result = self.process_withdraw(input, false).await;
+        record_transaction("withdraw", WITHDRAW_METHOD, input.withdraw_amount, &result);
+        result
     }
 
+    #[instrument(name = "WithdrawService::update_withdraw", skip_all)]
     async fn update_withdraw(
         &self,
         input: &UpdateWithdrawRequest,
     ) -> Result<ApiResponse<Option<WithdrawResponse>>, ErrorResponse> {
-        if let Err(validation_err) = input.validate() {
+        if let Err(validation_err) = input.validate(self.minimum_amounts.withdraw) {
             error!("Validation failed for withdraw update: {}", validation_err);
             return Err(ErrorResponse::from(AppError::ValidationError(
                 validation_err,
@@ -297,7 +608,7 @@ impl WithdrawServiceTrait for WithdrawService {
 
         let updated_withdraw = self
             .withdraw_repository
-            .update(&input)
+            .update(input)
             .await
             .map_err(AppError::from)
             .map_err(ErrorResponse::from);
@@ -339,6 +650,7 @@ impl WithdrawServiceTrait for WithdrawService {
         })
     }
 
+    #[instrument(name = "WithdrawService::delete_withdraw", skip_all)]
     async fn delete_withdraw(&self, id: i32) -> Result<ApiResponse<()>, ErrorResponse> {
         let user = self.user_repository.find_by_id(id).await.map_err(|_| {
             ErrorResponse::from(AppError::NotFound(format!("User with id {} not found", id)))
@@ -376,4 +688,66 @@ impl WithdrawServiceTrait for WithdrawService {
             }
         }
     }
+
+    #[instrument(name = "WithdrawService::sync_pending_payouts", skip_all)]
+    async fn sync_pending_payouts(&self) -> Result<usize, ErrorResponse> {
+        let pending = self
+            .withdraw_repository
+            .find_by_status("pending", PAYOUTS_PER_SYNC)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?;
+
+        let mut settled = 0;
+
+        for withdraw in pending {
+            let payout = match &withdraw.provider_reference {
+                Some(provider_reference) => self
+                    .payout_provider
+                    .get_payout_status(provider_reference)
+                    .await
+                    .map(|status| Some((None, status))),
+                None => self.find_unreferenced_payout(&withdraw).await,
+            };
+
+            let (provider_reference, status) = match payout {
+                Ok(Some(payout)) => payout,
+                Ok(None) => continue,
+                Err(err) => {
+                    error!(
+                        "Failed to fetch payout status for withdraw {}: {}",
+                        withdraw.withdraw_id, err
+                    );
+                    continue;
+                }
+            };
+
+            // Nothing new to record until the payout is settled
+            if status == PayoutStatus::Pending && provider_reference.is_none() {
+                continue;
+            }
+
+            // A withdraw that cannot be settled now stays pending for the next sync
+            let updated = match self
+                .apply_payout_status(&withdraw, provider_reference, status)
+                .await
+            {
+                Ok(Some(updated)) if updated.status != "pending" => updated,
+                Ok(_) => continue,
+                Err(err) => {
+                    error!(
+                        "Failed to settle payout of withdraw {}: {}",
+                        withdraw.withdraw_id, err
+                    );
+                    continue;
+                }
+            };
+
+            info!("Withdraw {} payout {}", updated.withdraw_id, updated.status);
+
+            settled += 1;
+        }
+
+        Ok(settled)
+    }
 }
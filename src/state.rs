@@ -1,29 +1,66 @@
-use std::sync::Arc;
+use std::{net::IpAddr, sync::Arc};
 
 use sea_orm::DatabaseConnection;
 
 use crate::{
-    abstract_trait::{hashing::DynHashing, jwt::DynJwtService},
-    config::{hashing::Hashing, jwt_config::JwtConfig},
+    abstract_trait::{
+        blob_store::DynBlobStore, hashing::DynHashing, jwt::DynJwtService,
+        payment_channel::DynPaymentChannel, watchlist::DynWatchlist,
+    },
+    config::{
+        clock::SystemClock, config::Config, database::DatabasePools, hashing::Hashing,
+        jwt_config::JwtConfig,
+    },
+    jobs::health::{SharedWorkerHealth, WorkerHealth},
+    providers::{
+        blob_store::LocalBlobStore, payment_channel::SimulatedPaymentChannel,
+        watchlist::FileWatchlist,
+    },
     utils::di::DependenciesInject,
 };
 
 #[derive(Clone)]
 pub struct AppState {
+    pub db_pool: DatabaseConnection,
     pub di_container: DependenciesInject,
     pub jwt_config: DynJwtService,
+    /// Heartbeats the background workers report to the readiness probe.
+    pub worker_health: SharedWorkerHealth,
+    /// Peers whose `X-Forwarded-For` names the client.
+    pub trusted_proxies: Vec<IpAddr>,
 }
 
 impl AppState {
-    pub fn new(pool: DatabaseConnection, jwt_secret: &str) -> Self {
-        let jwt_config = Arc::new(JwtConfig::new(jwt_secret)) as DynJwtService;
+    pub fn new(db_pools: DatabasePools, config: &Config) -> Self {
+        let jwt_config =
+            Arc::new(JwtConfig::new(&config.jwt_secret, config.jwt_ttl())) as DynJwtService;
         let hashing = Arc::new(Hashing::new()) as DynHashing;
+        let payment_channel = Arc::new(SimulatedPaymentChannel::new(
+            &config.payment_callback_secret,
+        )) as DynPaymentChannel;
+        let blob_store = Arc::new(LocalBlobStore::new(&config.blob_storage_dir)) as DynBlobStore;
+        let watchlist = Arc::new(FileWatchlist::new(&config.watchlist_path)) as DynWatchlist;
+        let worker_health = Arc::new(WorkerHealth::new(Arc::new(SystemClock)));
 
-        let di_container = DependenciesInject::new(pool, hashing, jwt_config.clone());
+        let di_container = DependenciesInject::new(
+            db_pools.clone(),
+            hashing,
+            jwt_config.clone(),
+            payment_channel,
+            blob_store,
+            watchlist,
+            worker_health.clone(),
+            config.minimum_amounts(),
+            config.beneficiary_cooling_off(),
+            config.transactions.fee_revenue_user_id,
+        );
 
         Self {
+            db_pool: db_pools.primary().clone(),
             di_container,
             jwt_config,
+            worker_health,
+            trusted_proxies: config.trusted_proxies.clone(),
         }
     }
 }
@@ -1,59 +1,236 @@
-use std::sync::Arc;
-
-use sea_orm::DatabaseConnection;
+use std::{sync::Arc, time::Duration};
 
 use crate::{
     abstract_trait::{
+        audit::{DynAuditRepository, DynAuditService},
         auth::DynAuthService,
+        bank_account::{DynBankAccountRepository, DynBankAccountService},
+        beneficiary::{DynBeneficiaryRepository, DynBeneficiaryService},
+        blob_store::DynBlobStore,
+        clock::DynClock,
+        fee::{DynFeeRepository, DynFeeService},
+        fraud::{DynFraudEngine, DynFraudRepository, DynFraudReviewService},
+        health::{DynHealthRepository, DynHealthService},
+        kyc::{DynKycRepository, DynKycService},
+        limit::{DynLimitRepository, DynLimitService},
         hashing::DynHashing,
         jwt::DynJwtService,
+        merchant::{DynMerchantRepository, DynMerchantService},
+        payment_channel::DynPaymentChannel,
+        payment_intent::{DynPaymentIntentRepository, DynPaymentIntentService},
+        payout::DynPayoutProvider,
+        qris::{DynQrisRepository, DynQrisService},
+        refund::{DynRefundRepository, DynRefundService},
         saldo::{DynSaldoRepository, DynSaldoService},
+        scheduled_transfer::{DynScheduledTransferRepository, DynScheduledTransferService},
+        screening::{DynScreeningRepository, DynScreeningService},
+        settlement::{DynSettlementRepository, DynSettlementService},
         topup::{DynTopupRepository, DynTopupService},
         transfer::{DynTransferRepository, DynTransferService},
+        transfer_batch::{DynTransferBatchRepository, DynTransferBatchService},
         user::{DynUserRepository, DynUserService},
+        virtual_account::{DynVirtualAccountRepository, DynVirtualAccountService},
+        watchlist::DynWatchlist,
+        webhook::{DynWebhookRepository, DynWebhookService},
+        webhook_sender::DynWebhookSender,
         withdraw::DynWithdrawService,
     },
+    config::{clock::SystemClock, database::DatabasePools},
+    domain::request::limit::MinimumAmounts,
+    jobs::health::SharedWorkerHealth,
+    providers::{payout::SimulatedPayoutProvider, webhook_sender::HttpWebhookSender},
     repository::{
-        saldo::SaldoRepository, topup::TopupRepository, transfer::TransferRepository,
-        user::UserRepository, withdraw::WithdrawRepository,
+        audit::AuditRepository,
+        bank_account::BankAccountRepository,
+        beneficiary::BeneficiaryRepository,
+        fee::FeeRepository,
+        fraud::FraudRepository,
+        health::HealthRepository,
+        kyc::KycRepository,
+        limit::LimitRepository,
+        merchant::MerchantRepository,
+        payment_intent::PaymentIntentRepository,
+        qris::QrisRepository,
+        refund::RefundRepository,
+        saldo::SaldoRepository, scheduled_transfer::ScheduledTransferRepository,
+        screening::ScreeningRepository, topup::TopupRepository, transfer::TransferRepository,
+        transfer_batch::TransferBatchRepository, user::UserRepository,
+        virtual_account::VirtualAccountRepository,
+        settlement::SettlementRepository,
+        webhook::WebhookRepository,
+        withdraw::WithdrawRepository,
     },
     services::{
-        auth::AuthService, saldo::SaldoService, topup::TopupService, transfer::TransferService,
-        user::UserService, withdraw::WithdrawService,
+        audit::AuditService,
+        auth::AuthService,
+        bank_account::BankAccountService,
+        beneficiary::BeneficiaryService,
+        fee::FeeService,
+        fraud::FraudEngine,
+        fraud_review::FraudReviewService,
+        health::HealthService,
+        kyc::KycService,
+        limit::LimitService,
+        merchant::MerchantService,
+        payment_intent::PaymentIntentService,
+        qris::QrisService,
+        refund::RefundService,
+        saldo::SaldoService,
+        scheduled_transfer::{RetryPolicy, ScheduledTransferService},
+        screening::ScreeningService, topup::TopupService,
+        transfer::{BeneficiaryCoolingOff, TransferService},
+        transfer_batch::TransferBatchService, user::UserService,
+        settlement::SettlementService,
+        virtual_account::VirtualAccountService,
+        webhook::{WebhookRetryPolicy, WebhookService},
+        withdraw::WithdrawService,
     },
 };
 
 #[derive(Clone)]
 pub struct DependenciesInject {
+    pub audit_service: DynAuditService,
     pub auth_service: DynAuthService,
     pub user_service: DynUserService,
     pub saldo_service: DynSaldoService,
     pub topup_service: DynTopupService,
     pub transfer_service: DynTransferService,
+    pub beneficiary_service: DynBeneficiaryService,
+    pub transfer_batch_service: DynTransferBatchService,
+    pub scheduled_transfer_service: DynScheduledTransferService,
+    pub bank_account_service: DynBankAccountService,
     pub withdraw_service: DynWithdrawService,
+    pub virtual_account_service: DynVirtualAccountService,
+    pub qris_service: DynQrisService,
+    pub merchant_service: DynMerchantService,
+    pub payment_intent_service: DynPaymentIntentService,
+    pub refund_service: DynRefundService,
+    pub webhook_service: DynWebhookService,
+    pub settlement_service: DynSettlementService,
+    pub fee_service: DynFeeService,
+    pub limit_service: DynLimitService,
+    pub kyc_service: DynKycService,
+    pub fraud_review_service: DynFraudReviewService,
+    pub screening_service: DynScreeningService,
+    pub health_service: DynHealthService,
 }
 
 impl DependenciesInject {
-    pub fn new(pool: DatabaseConnection, hashing: DynHashing, jwt_config: DynJwtService) -> Self {
-        let user_repository = Arc::new(UserRepository::new(pool.clone())) as DynUserRepository;
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        db_pools: DatabasePools,
+        hashing: DynHashing,
+        jwt_config: DynJwtService,
+        payment_channel: DynPaymentChannel,
+        blob_store: DynBlobStore,
+        watchlist: DynWatchlist,
+        worker_health: SharedWorkerHealth,
+        minimum_amounts: MinimumAmounts,
+        cooling_off: BeneficiaryCoolingOff,
+        fee_revenue_user_id: Option<i32>,
+    ) -> Self {
+        let pool = db_pools.primary().clone();
+
+        let user_repository = Arc::new(UserRepository::new(db_pools.clone())) as DynUserRepository;
 
         let user_service =
             Arc::new(UserService::new(user_repository.clone(), hashing.clone())) as DynUserService;
 
+        let audit_repository =
+            Arc::new(AuditRepository::new(pool.clone())) as DynAuditRepository;
+
+        let audit_service = Arc::new(AuditService::new(
+            audit_repository,
+            Arc::new(SystemClock) as DynClock,
+        )) as DynAuditService;
+
+        let screening_repository =
+            Arc::new(ScreeningRepository::new(pool.clone())) as DynScreeningRepository;
+
+        let screening_service = Arc::new(ScreeningService::new(
+            screening_repository.clone(),
+            watchlist,
+        )) as DynScreeningService;
+
         let auth_service = Arc::new(AuthService::new(
             user_repository.clone(),
             hashing.clone(),
             jwt_config,
+            screening_service.clone(),
         ));
 
-        let saldo_repository = Arc::new(SaldoRepository::new(pool.clone())) as DynSaldoRepository;
+        let saldo_repository = Arc::new(SaldoRepository::new(db_pools.clone())) as DynSaldoRepository;
 
-        let topup_repository = Arc::new(TopupRepository::new(pool.clone())) as DynTopupRepository;
+        let topup_repository = Arc::new(TopupRepository::new(db_pools.clone())) as DynTopupRepository;
 
         let transfer_repository =
-            Arc::new(TransferRepository::new(pool.clone())) as DynTransferRepository;
+            Arc::new(TransferRepository::new(db_pools.clone())) as DynTransferRepository;
+
+        let withdraw_repository = Arc::new(WithdrawRepository::new(db_pools.clone()));
+
+        let beneficiary_repository =
+            Arc::new(BeneficiaryRepository::new(pool.clone())) as DynBeneficiaryRepository;
+
+        let transfer_batch_repository =
+            Arc::new(TransferBatchRepository::new(pool.clone())) as DynTransferBatchRepository;
+
+        let bank_account_repository =
+            Arc::new(BankAccountRepository::new(pool.clone())) as DynBankAccountRepository;
+
+        let virtual_account_repository =
+            Arc::new(VirtualAccountRepository::new(pool.clone())) as DynVirtualAccountRepository;
+
+        let qris_repository = Arc::new(QrisRepository::new(pool.clone())) as DynQrisRepository;
+
+        let merchant_repository =
+            Arc::new(MerchantRepository::new(pool.clone())) as DynMerchantRepository;
+
+        let payment_intent_repository =
+            Arc::new(PaymentIntentRepository::new(pool.clone())) as DynPaymentIntentRepository;
+
+        let refund_repository =
+            Arc::new(RefundRepository::new(pool.clone())) as DynRefundRepository;
+
+        let webhook_repository =
+            Arc::new(WebhookRepository::new(pool.clone())) as DynWebhookRepository;
+
+        let settlement_repository =
+            Arc::new(SettlementRepository::new(db_pools.clone())) as DynSettlementRepository;
+
+        let scheduled_transfer_repository =
+            Arc::new(ScheduledTransferRepository::new(pool.clone()))
+                as DynScheduledTransferRepository;
+
+        let fee_repository = Arc::new(FeeRepository::new(pool.clone())) as DynFeeRepository;
+
+        let fee_service = Arc::new(FeeService::new(
+            fee_repository.clone(),
+            fee_revenue_user_id,
+        )) as DynFeeService;
+
+        let limit_repository = Arc::new(LimitRepository::new(pool.clone())) as DynLimitRepository;
+
+        let limit_service = Arc::new(LimitService::new(
+            limit_repository.clone(),
+            user_repository.clone(),
+            saldo_repository.clone(),
+            Arc::new(SystemClock) as DynClock,
+        )) as DynLimitService;
+
+        let kyc_repository = Arc::new(KycRepository::new(pool.clone())) as DynKycRepository;
+
+        let kyc_service = Arc::new(KycService::new(
+            kyc_repository.clone(),
+            user_repository.clone(),
+            blob_store,
+        )) as DynKycService;
 
-        let withdraw_repository = Arc::new(WithdrawRepository::new(pool.clone()));
+        let fraud_repository = Arc::new(FraudRepository::new(pool.clone())) as DynFraudRepository;
+
+        let fraud_engine = Arc::new(FraudEngine::new(
+            fraud_repository.clone(),
+            Arc::new(SystemClock) as DynClock,
+        )) as DynFraudEngine;
 
         let saldo_service = Arc::new(SaldoService::new(
             user_repository.clone(),
@@ -64,27 +241,170 @@ impl DependenciesInject {
             topup_repository.clone(),
             saldo_repository.clone(),
             user_repository.clone(),
+            payment_channel.clone(),
+            fee_service.clone(),
+            limit_service.clone(),
+            fraud_engine.clone(),
+            minimum_amounts,
         )) as DynTopupService;
 
         let transfer_service = Arc::new(TransferService::new(
             transfer_repository.clone(),
             saldo_repository.clone(),
             user_repository.clone(),
+            beneficiary_repository.clone(),
+            fee_service.clone(),
+            limit_service.clone(),
+            fraud_engine.clone(),
+            Arc::new(SystemClock) as DynClock,
+            cooling_off,
+            minimum_amounts,
         )) as DynTransferService;
 
+        let beneficiary_service = Arc::new(BeneficiaryService::new(
+            beneficiary_repository.clone(),
+            user_repository.clone(),
+            screening_service.clone(),
+        )) as DynBeneficiaryService;
+
+        let transfer_batch_service = Arc::new(TransferBatchService::new(
+            transfer_batch_repository.clone(),
+            transfer_service.clone(),
+            saldo_repository.clone(),
+            user_repository.clone(),
+            minimum_amounts,
+        )) as DynTransferBatchService;
+
+        let scheduled_transfer_service = Arc::new(ScheduledTransferService::new(
+            scheduled_transfer_repository.clone(),
+            transfer_service.clone(),
+            user_repository.clone(),
+            Arc::new(SystemClock) as DynClock,
+            RetryPolicy::default(),
+            minimum_amounts,
+        )) as DynScheduledTransferService;
+
+        let bank_account_service = Arc::new(BankAccountService::new(
+            bank_account_repository.clone(),
+        )) as DynBankAccountService;
+
+        let payout_provider = Arc::new(SimulatedPayoutProvider::new()) as DynPayoutProvider;
+
         let withdraw_service = Arc::new(WithdrawService::new(
             withdraw_repository.clone(),
             saldo_repository.clone(),
             user_repository.clone(),
+            bank_account_repository.clone(),
+            payout_provider,
+            fee_service.clone(),
+            limit_service.clone(),
+            fraud_engine,
+            minimum_amounts,
         )) as DynWithdrawService;
 
+        let fraud_review_service = Arc::new(FraudReviewService::new(
+            fraud_repository.clone(),
+            topup_service.clone(),
+            transfer_service.clone(),
+            withdraw_service.clone(),
+            payment_intent_repository.clone(),
+            qris_repository.clone(),
+            settlement_repository.clone(),
+            transfer_batch_repository.clone(),
+            scheduled_transfer_repository.clone(),
+        )) as DynFraudReviewService;
+
+        let virtual_account_service = Arc::new(VirtualAccountService::new(
+            virtual_account_repository.clone(),
+            topup_service.clone(),
+            user_repository.clone(),
+            payment_channel,
+        )) as DynVirtualAccountService;
+
+        let qris_service = Arc::new(QrisService::new(
+            qris_repository.clone(),
+            merchant_repository.clone(),
+            user_repository.clone(),
+            transfer_service.clone(),
+            minimum_amounts,
+        )) as DynQrisService;
+
+        let merchant_service = Arc::new(MerchantService::new(
+            merchant_repository.clone(),
+            user_repository.clone(),
+        )) as DynMerchantService;
+
+        let payment_intent_service = Arc::new(PaymentIntentService::new(
+            payment_intent_repository.clone(),
+            merchant_repository.clone(),
+            transfer_service.clone(),
+            Arc::new(SystemClock) as DynClock,
+            minimum_amounts,
+        )) as DynPaymentIntentService;
+
+        let refund_service = Arc::new(RefundService::new(
+            refund_repository.clone(),
+            payment_intent_repository.clone(),
+            merchant_repository.clone(),
+            saldo_repository.clone(),
+        )) as DynRefundService;
+
+        let webhook_sender =
+            Arc::new(HttpWebhookSender::new(Duration::from_secs(10))) as DynWebhookSender;
+
+        let webhook_service = Arc::new(WebhookService::new(
+            webhook_repository.clone(),
+            merchant_repository.clone(),
+            webhook_sender,
+            Arc::new(SystemClock) as DynClock,
+            WebhookRetryPolicy::default(),
+        )) as DynWebhookService;
+
+        let settlement_service = Arc::new(SettlementService::new(
+            settlement_repository.clone(),
+            merchant_repository.clone(),
+            bank_account_repository.clone(),
+            saldo_repository.clone(),
+            withdraw_service.clone(),
+            fee_service.clone(),
+            Arc::new(SystemClock) as DynClock,
+            minimum_amounts,
+        )) as DynSettlementService;
+
+        let health_repository =
+            Arc::new(HealthRepository::new(db_pools.clone())) as DynHealthRepository;
+
+        let health_service = Arc::new(HealthService::new(
+            health_repository,
+            worker_health,
+            Arc::new(SystemClock) as DynClock,
+        )) as DynHealthService;
+
         Self {
+            audit_service,
             auth_service,
             user_service,
             saldo_service,
             topup_service,
             transfer_service,
+            beneficiary_service,
+            transfer_batch_service,
+            scheduled_transfer_service,
+            bank_account_service,
             withdraw_service,
+            virtual_account_service,
+            qris_service,
+            merchant_service,
+            payment_intent_service,
+            refund_service,
+            webhook_service,
+            settlement_service,
+            fee_service,
+            limit_service,
+            kyc_service,
+            fraud_review_service,
+            screening_service,
+            health_service,
         }
     }
 }
@@ -40,6 +40,40 @@ pub enum AppError {
 
     #[error("Email already exists")]
     EmailAlreadyExists,
+
+    #[error("Insufficient balance")]
+    InsufficientBalance,
+
+    #[error("Payout provider error: {0}")]
+    PayoutProviderError(String),
+
+    #[error("Payment channel error: {0}")]
+    PaymentChannelError(String),
+
+    #[error("Invalid callback signature")]
+    InvalidSignature,
+
+    #[error("Webhook delivery error: {0}")]
+    WebhookDeliveryError(String),
+
+    #[error("Invalid fee rule: {0}")]
+    FeeRuleError(String),
+
+    #[error("Transaction limit exceeded: {0}")]
+    LimitExceeded(String),
+
+    #[error("Blob store error: {0}")]
+    BlobStoreError(String),
+
+    #[error("Declined by fraud screening: {0}")]
+    FraudDenied(String),
+
+    /// The fraud review id and a message for the caller.
+    #[error("Held for fraud review: {1}")]
+    HeldForReview(i32, String),
+
+    #[error("Watchlist error: {0}")]
+    WatchlistError(String),
 }
 
 impl Serialize for AppError {
@@ -65,3 +99,8 @@ impl ConnectionManagerError {
         ConnectionManagerError::MigrationError(err)
     }
 }
+
+/// Every problem found while loading the configuration.
+#[derive(Debug, Error)]
+#[error("Invalid configuration:\n  - {}", .0.join("\n  - "))]
+pub struct ConfigError(pub Vec<String>);
@@ -1,19 +1,167 @@
+use std::str::FromStr;
+
+use opentelemetry::{global, trace::TracerProvider as _};
+use opentelemetry_sdk::{trace::SdkTracerProvider, Resource};
+use serde::{Deserialize, Serialize};
+use tracing::{Level, Subscriber};
+use tracing_subscriber::filter::Targets;
 use tracing_subscriber::prelude::*;
-use tracing_subscriber::{fmt, EnvFilter};
+use tracing_subscriber::registry::LookupSpan;
+use tracing_subscriber::{fmt, EnvFilter, Layer};
+
+/// Name of the tracer spans of this crate are exported under.
+pub const TRACER_NAME: &str = env!("CARGO_PKG_NAME");
+
+/// How log lines are written to stdout.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LogFormat {
+    Text,
+    /// One JSON object per line with the fields of the enclosing spans,
+    /// such as the request id.
+    Json,
+}
+
+impl FromStr for LogFormat {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "text" => Ok(LogFormat::Text),
+            "json" => Ok(LogFormat::Json),
+            _ => Err(format!("Unknown log format {}", value)),
+        }
+    }
+}
 
-pub fn tracing(){
-    let fmt_layer = fmt::layer()
-        .without_time()
-        .with_line_number(true)
-        .with_level(true)
-        .with_target(true);
+/// Where finished spans are exported to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TraceExporter {
+    None,
+    /// Prints spans to stdout, for local debugging.
+    Stdout,
+    /// Sends spans over OTLP/HTTP to `OTEL_EXPORTER_OTLP_ENDPOINT`.
+    Otlp,
+}
 
-    let filter_layer = EnvFilter::try_from_default_env()
+impl FromStr for TraceExporter {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "none" => Ok(TraceExporter::None),
+            "stdout" => Ok(TraceExporter::Stdout),
+            "otlp" => Ok(TraceExporter::Otlp),
+            _ => Err(format!("Unknown trace exporter {}", value)),
+        }
+    }
+}
+
+/// Boxed layer so the layers below can be picked at runtime and stacked.
+pub type BoxedLayer<S> = Box<dyn Layer<S> + Send + Sync + 'static>;
+
+/// Levels from `RUST_LOG`, everything when it is unset.
+pub fn filter_layer() -> EnvFilter {
+    EnvFilter::try_from_default_env()
         .or_else(|_| EnvFilter::try_new("trace"))
-        .unwrap();
+        .unwrap()
+}
+
+pub fn fmt_layer<S>(format: LogFormat) -> BoxedLayer<S>
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+{
+    match format {
+        LogFormat::Text => fmt::layer()
+            .with_line_number(true)
+            .with_level(true)
+            .with_target(true)
+            .boxed(),
+        LogFormat::Json => fmt::layer()
+            .json()
+            .with_current_span(true)
+            .with_span_list(false)
+            .with_line_number(true)
+            .with_target(true)
+            .boxed(),
+    }
+}
+
+/// Turns the spans of this crate into OpenTelemetry spans. Spans of
+/// dependencies, the exporter's HTTP client among them, are left out.
+pub fn otel_layer<S>(provider: &SdkTracerProvider) -> BoxedLayer<S>
+where
+    S: Subscriber + for<'a> LookupSpan<'a> + Send + Sync,
+{
+    tracing_opentelemetry::layer()
+        .with_tracer(provider.tracer(TRACER_NAME))
+        .with_filter(Targets::new().with_target(env!("CARGO_CRATE_NAME"), Level::TRACE))
+        .boxed()
+}
+
+/// Tracer provider for `exporter`, `None` when spans are not exported.
+pub fn tracer_provider(exporter: TraceExporter) -> Result<Option<SdkTracerProvider>, String> {
+    let service_name =
+        std::env::var("OTEL_SERVICE_NAME").unwrap_or_else(|_| TRACER_NAME.to_string());
+    let builder = SdkTracerProvider::builder()
+        .with_resource(Resource::builder().with_service_name(service_name).build());
+
+    let provider = match exporter {
+        TraceExporter::None => return Ok(None),
+        TraceExporter::Stdout => builder
+            .with_simple_exporter(opentelemetry_stdout::SpanExporter::default())
+            .build(),
+        TraceExporter::Otlp => {
+            let exporter = opentelemetry_otlp::SpanExporter::builder()
+                .with_http()
+                .build()
+                .map_err(|e| e.to_string())?;
+
+            builder.with_batch_exporter(exporter).build()
+        }
+    };
+
+    Ok(Some(provider))
+}
+
+/// Flushes and stops span export when dropped.
+pub struct TracingGuard {
+    provider: Option<SdkTracerProvider>,
+}
+
+impl Drop for TracingGuard {
+    fn drop(&mut self) {
+        if let Some(provider) = self.provider.take() {
+            if let Err(e) = provider.shutdown() {
+                eprintln!("Failed to flush exported spans: {}", e);
+            }
+        }
+    }
+}
+
+/// Installs the global subscriber: logs on stdout and, when an exporter is
+/// configured, OpenTelemetry spans. Keep the guard until the process exits.
+pub fn tracing(format: LogFormat, exporter: TraceExporter) -> TracingGuard {
+    let provider = tracer_provider(exporter).unwrap_or_else(|e| {
+        eprintln!(
+            "Span export disabled, failed to set up {:?}: {}",
+            exporter, e
+        );
+        None
+    });
+
+    let mut layers = vec![fmt_layer(format)];
+
+    if let Some(provider) = &provider {
+        global::set_tracer_provider(provider.clone());
+        layers.push(otel_layer(provider));
+    }
 
     tracing_subscriber::registry()
-        .with(filter_layer)
-        .with(fmt_layer)
+        .with(layers)
+        .with(filter_layer())
         .init();
-}
\ No newline at end of file
+
+    TracingGuard { provider }
+}
@@ -0,0 +1,169 @@
+use std::sync::LazyLock;
+
+use prometheus::{
+    Encoder, HistogramOpts, HistogramVec, IntCounterVec, IntGaugeVec, Opts, Registry, TextEncoder,
+};
+
+use crate::domain::response::ErrorResponse;
+
+/// Process wide metrics, scraped from `/metrics`.
+pub static METRICS: LazyLock<Metrics> = LazyLock::new(Metrics::new);
+
+pub struct Metrics {
+    registry: Registry,
+    /// `http_requests_total{method, route, status}`
+    pub http_requests: IntCounterVec,
+    /// `http_request_duration_seconds{method, route, status}`
+    pub http_request_duration: HistogramVec,
+    /// `db_pool_connections{state}` with `active`, `idle` and `max`.
+    pub db_pool_connections: IntGaugeVec,
+    /// `transactions_total{kind, method, outcome}`
+    pub transactions: IntCounterVec,
+    /// `transaction_amount_total{kind, method, outcome}` in rupiah.
+    pub transaction_amount: IntCounterVec,
+    /// `login_failures_total{reason}`
+    pub login_failures: IntCounterVec,
+    /// `insufficient_funds_rejections_total{kind}`
+    pub insufficient_funds: IntCounterVec,
+}
+
+impl Metrics {
+    fn new() -> Self {
+        let registry = Registry::new();
+
+        let http_requests = IntCounterVec::new(
+            Opts::new("http_requests_total", "HTTP requests handled"),
+            &["method", "route", "status"],
+        )
+        .unwrap();
+        let http_request_duration = HistogramVec::new(
+            HistogramOpts::new(
+                "http_request_duration_seconds",
+                "Time taken to handle HTTP requests",
+            ),
+            &["method", "route", "status"],
+        )
+        .unwrap();
+        let db_pool_connections = IntGaugeVec::new(
+            Opts::new("db_pool_connections", "Database pool connections by state"),
+            &["state"],
+        )
+        .unwrap();
+        let transactions = IntCounterVec::new(
+            Opts::new("transactions_total", "Top-ups, transfers and withdrawals"),
+            &["kind", "method", "outcome"],
+        )
+        .unwrap();
+        let transaction_amount = IntCounterVec::new(
+            Opts::new(
+                "transaction_amount_total",
+                "Rupiah amount of top-ups, transfers and withdrawals",
+            ),
+            &["kind", "method", "outcome"],
+        )
+        .unwrap();
+        let login_failures = IntCounterVec::new(
+            Opts::new("login_failures_total", "Rejected login attempts"),
+            &["reason"],
+        )
+        .unwrap();
+        let insufficient_funds = IntCounterVec::new(
+            Opts::new(
+                "insufficient_funds_rejections_total",
+                "Transactions rejected for an insufficient balance",
+            ),
+            &["kind"],
+        )
+        .unwrap();
+
+        registry.register(Box::new(http_requests.clone())).unwrap();
+        registry
+            .register(Box::new(http_request_duration.clone()))
+            .unwrap();
+        registry
+            .register(Box::new(db_pool_connections.clone()))
+            .unwrap();
+        registry.register(Box::new(transactions.clone())).unwrap();
+        registry
+            .register(Box::new(transaction_amount.clone()))
+            .unwrap();
+        registry.register(Box::new(login_failures.clone())).unwrap();
+        registry
+            .register(Box::new(insufficient_funds.clone()))
+            .unwrap();
+
+        Self {
+            registry,
+            http_requests,
+            http_request_duration,
+            db_pool_connections,
+            transactions,
+            transaction_amount,
+            login_failures,
+            insufficient_funds,
+        }
+    }
+
+    /// Every metric in the Prometheus text format.
+    pub fn render(&self) -> String {
+        let mut buffer = Vec::new();
+
+        if let Err(e) = TextEncoder::new().encode(&self.registry.gather(), &mut buffer) {
+            tracing::error!("Failed to encode metrics: {}", e);
+        }
+
+        String::from_utf8(buffer).unwrap_or_default()
+    }
+}
+
+/// Outcome label of a finished transaction. Transactions held by fraud
+/// screening fail with a `pending` status.
+pub fn outcome_of<T>(result: &Result<T, ErrorResponse>) -> &'static str {
+    match result {
+        Ok(_) => "succeeded",
+        Err(e) if e.status == "pending" => "held",
+        Err(_) => "failed",
+    }
+}
+
+pub fn record_transaction<T>(
+    kind: &str,
+    method: &str,
+    amount: i32,
+    result: &Result<T, ErrorResponse>,
+) {
+    let labels = [kind, method, outcome_of(result)];
+
+    METRICS.transactions.with_label_values(&labels).inc();
+    METRICS
+        .transaction_amount
+        .with_label_values(&labels)
+        .inc_by(amount.max(0) as u64);
+}
+
+pub fn record_login_failure(reason: &str) {
+    METRICS.login_failures.with_label_values(&[reason]).inc();
+}
+
+pub fn record_insufficient_funds(kind: &str) {
+    METRICS.insufficient_funds.with_label_values(&[kind]).inc();
+}
+
+pub fn record_http_request(method: &str, route: &str, status: u16, seconds: f64) {
+    let status = status.to_string();
+    let labels = [method, route, status.as_str()];
+
+    METRICS.http_requests.with_label_values(&labels).inc();
+    METRICS
+        .http_request_duration
+        .with_label_values(&labels)
+        .observe(seconds);
+}
+
+pub fn record_db_pool(active: u32, idle: u32, max: u32) {
+    let pool = &METRICS.db_pool_connections;
+
+    pool.with_label_values(&["active"]).set(active.into());
+    pool.with_label_values(&["idle"]).set(idle.into());
+    pool.with_label_values(&["max"]).set(max.into());
+}
@@ -1,6 +1,14 @@
 pub mod random_vcc;
+pub mod qris;
+pub mod qr_image;
 pub mod payment_method_validator;
 pub mod rupiah;
 pub mod errors;
 pub mod di;
 pub mod log_tracing;
+pub mod schedule;
+pub mod signature;
+pub mod watchlist;
+pub mod metrics;
+
+pub mod shutdown;
\ No newline at end of file
@@ -25,3 +25,17 @@ pub fn payment_method_validator(payment_method: &str) -> bool {
 
     !payment_rules.contains(&payment_method)
 }
+
+/// Banks from the list above that can receive withdrawal payouts.
+pub const BANK_CODES: [&str; 5] = ["bri", "mandiri", "bca", "bni", "bukopin"];
+
+pub fn bank_code_validator(bank_code: &str) -> bool {
+    BANK_CODES.contains(&bank_code) && !payment_method_validator(bank_code)
+}
+
+/// Banks that issue per-user virtual accounts for top-ups.
+pub const VIRTUAL_ACCOUNT_BANK_CODES: [&str; 3] = ["bca", "mandiri", "bni"];
+
+pub fn virtual_account_bank_validator(bank_code: &str) -> bool {
+    VIRTUAL_ACCOUNT_BANK_CODES.contains(&bank_code)
+}
@@ -0,0 +1,73 @@
+use std::io::Write;
+
+use flate2::{write::ZlibEncoder, Compression};
+use qrcode::{render::svg, Color, QrCode};
+
+/// Light modules around the symbol, as required by ISO/IEC 18004.
+const QUIET_ZONE: usize = 4;
+
+pub fn render_svg(data: &str) -> Result<String, String> {
+    let code = QrCode::new(data.as_bytes()).map_err(|e| e.to_string())?;
+
+    Ok(code
+        .render::<svg::Color>()
+        .min_dimensions(256, 256)
+        .build())
+}
+
+/// Renders `data` as an 8-bit grayscale PNG with `scale` pixels per module.
+pub fn render_png(data: &str, scale: usize) -> Result<Vec<u8>, String> {
+    let code = QrCode::new(data.as_bytes()).map_err(|e| e.to_string())?;
+    let modules = code.width();
+    let colors = code.to_colors();
+    let size = (modules + QUIET_ZONE * 2) * scale;
+
+    let mut raw = Vec::with_capacity((size + 1) * size);
+    for y in 0..size {
+        // Each scanline starts with filter type 0 (none).
+        raw.push(0);
+
+        for x in 0..size {
+            let module_x = (x / scale).checked_sub(QUIET_ZONE);
+            let module_y = (y / scale).checked_sub(QUIET_ZONE);
+
+            let dark = match (module_x, module_y) {
+                (Some(mx), Some(my)) if mx < modules && my < modules => {
+                    colors[my * modules + mx] == Color::Dark
+                }
+                _ => false,
+            };
+
+            raw.push(if dark { 0x00 } else { 0xFF });
+        }
+    }
+
+    let mut encoder = ZlibEncoder::new(Vec::new(), Compression::default());
+    encoder.write_all(&raw).map_err(|e| e.to_string())?;
+    let compressed = encoder.finish().map_err(|e| e.to_string())?;
+
+    let mut header = Vec::with_capacity(13);
+    header.extend_from_slice(&(size as u32).to_be_bytes());
+    header.extend_from_slice(&(size as u32).to_be_bytes());
+    // Bit depth 8, color type 0 (grayscale), default compression, filter
+    // and no interlacing.
+    header.extend_from_slice(&[8, 0, 0, 0, 0]);
+
+    let mut png = vec![0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A];
+    write_chunk(&mut png, b"IHDR", &header);
+    write_chunk(&mut png, b"IDAT", &compressed);
+    write_chunk(&mut png, b"IEND", &[]);
+
+    Ok(png)
+}
+
+fn write_chunk(png: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    png.extend_from_slice(&(data.len() as u32).to_be_bytes());
+    png.extend_from_slice(kind);
+    png.extend_from_slice(data);
+
+    let mut hasher = crc32fast::Hasher::new();
+    hasher.update(kind);
+    hasher.update(data);
+    png.extend_from_slice(&hasher.finalize().to_be_bytes());
+}
@@ -0,0 +1,241 @@
+/// Reverse-domain identifier of this gateway inside the merchant account template.
+const GLOBALLY_UNIQUE_ID: &str = "ID.CO.PAYMENTGATEWAY.WWW";
+const QRIS_GLOBALLY_UNIQUE_ID: &str = "ID.CO.QRIS.WWW";
+const CURRENCY_IDR: &str = "360";
+const COUNTRY_CODE: &str = "ID";
+const CRC_TAG: &str = "63";
+
+/// One EMVCo tag-length-value data object. Templates such as tag 26 or 62
+/// keep their nested objects TLV-encoded inside `value`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TlvField {
+    pub tag: String,
+    pub value: String,
+}
+
+impl TlvField {
+    pub fn new(tag: &str, value: impl Into<String>) -> Self {
+        Self {
+            tag: tag.to_string(),
+            value: value.into(),
+        }
+    }
+}
+
+pub fn encode_tlv(fields: &[TlvField]) -> Result<String, String> {
+    let mut encoded = String::new();
+
+    for field in fields {
+        if field.tag.len() != 2 || !field.tag.chars().all(|c| c.is_ascii_digit()) {
+            return Err(format!("Invalid tag {}", field.tag));
+        }
+
+        let length = field.value.chars().count();
+        if length == 0 || length > 99 {
+            return Err(format!("Tag {} must be 1 to 99 characters", field.tag));
+        }
+
+        encoded.push_str(&field.tag);
+        encoded.push_str(&format!("{:02}", length));
+        encoded.push_str(&field.value);
+    }
+
+    Ok(encoded)
+}
+
+pub fn decode_tlv(data: &str) -> Result<Vec<TlvField>, String> {
+    let chars: Vec<char> = data.chars().collect();
+    let mut fields = Vec::new();
+    let mut position = 0;
+
+    while position < chars.len() {
+        if position + 4 > chars.len() {
+            return Err("Truncated data object".to_string());
+        }
+
+        let tag: String = chars[position..position + 2].iter().collect();
+        let length: String = chars[position + 2..position + 4].iter().collect();
+
+        if !tag.chars().all(|c| c.is_ascii_digit()) {
+            return Err(format!("Invalid tag {}", tag));
+        }
+
+        let length: usize = length
+            .parse()
+            .map_err(|_| format!("Invalid length for tag {}", tag))?;
+
+        let start = position + 4;
+        if start + length > chars.len() {
+            return Err(format!("Value of tag {} is truncated", tag));
+        }
+
+        fields.push(TlvField {
+            tag,
+            value: chars[start..start + length].iter().collect(),
+        });
+        position = start + length;
+    }
+
+    Ok(fields)
+}
+
+/// CRC-16/CCITT-FALSE (polynomial 0x1021, initial value 0xFFFF), the
+/// checksum EMVCo requires in tag 63.
+pub fn crc16_ccitt(data: &[u8]) -> u16 {
+    let mut crc: u16 = 0xFFFF;
+
+    for byte in data {
+        crc ^= (*byte as u16) << 8;
+        for _ in 0..8 {
+            crc = if crc & 0x8000 != 0 {
+                (crc << 1) ^ 0x1021
+            } else {
+                crc << 1
+            };
+        }
+    }
+
+    crc
+}
+
+/// The merchant a QRIS payload pays into.
+#[derive(Debug, Clone, PartialEq)]
+pub struct QrisMerchant {
+    /// Account the payment is credited to, the merchant's `noc_transfer`.
+    pub merchant_pan: String,
+    pub merchant_id: String,
+    pub name: String,
+    pub city: String,
+    pub postal_code: String,
+}
+
+/// The parts of a scanned payload needed to route a payment.
+#[derive(Debug, Clone, PartialEq)]
+pub struct QrisPayload {
+    pub dynamic: bool,
+    pub merchant_pan: Option<String>,
+    pub merchant_name: Option<String>,
+    pub amount: Option<i32>,
+    pub reference: Option<String>,
+}
+
+/// Builds a dynamic (single-use, fixed amount) QRIS payload ending in its
+/// CRC.
+pub fn build_dynamic_qris(
+    merchant: &QrisMerchant,
+    amount: i32,
+    reference: &str,
+) -> Result<String, String> {
+    if amount <= 0 {
+        return Err("Amount must be a positive integer".to_string());
+    }
+
+    let merchant_account = encode_tlv(&[
+        TlvField::new("00", GLOBALLY_UNIQUE_ID),
+        TlvField::new("01", merchant.merchant_pan.as_str()),
+        TlvField::new("02", merchant.merchant_id.as_str()),
+        TlvField::new("03", "UMI"),
+    ])?;
+
+    let qris_account = encode_tlv(&[
+        TlvField::new("00", QRIS_GLOBALLY_UNIQUE_ID),
+        TlvField::new("02", merchant.merchant_id.as_str()),
+        TlvField::new("03", "UMI"),
+    ])?;
+
+    let additional_data = encode_tlv(&[TlvField::new("05", reference)])?;
+
+    let body = encode_tlv(&[
+        TlvField::new("00", "01"),
+        TlvField::new("01", "12"),
+        TlvField::new("26", merchant_account),
+        TlvField::new("51", qris_account),
+        TlvField::new("52", "5999"),
+        TlvField::new("53", CURRENCY_IDR),
+        TlvField::new("54", amount.to_string()),
+        TlvField::new("58", COUNTRY_CODE),
+        TlvField::new("59", truncate(&merchant.name, 25)),
+        TlvField::new("60", truncate(&merchant.city, 15)),
+        TlvField::new("61", merchant.postal_code.as_str()),
+        TlvField::new("62", additional_data),
+    ])?;
+
+    // The checksum covers everything up to and including "6304".
+    let unsigned = format!("{}{}04", body, CRC_TAG);
+    let crc = crc16_ccitt(unsigned.as_bytes());
+
+    Ok(format!("{}{:04X}", unsigned, crc))
+}
+
+/// Decodes a QRIS payload after checking its CRC.
+pub fn parse_qris(payload: &str) -> Result<QrisPayload, String> {
+    let payload = payload.trim();
+
+    if payload.len() < 8 || !payload.is_char_boundary(payload.len() - 4) {
+        return Err("Payload is too short".to_string());
+    }
+
+    let (unsigned, checksum) = payload.split_at(payload.len() - 4);
+
+    if !unsigned.ends_with("6304") {
+        return Err("Payload must end with a CRC".to_string());
+    }
+
+    let expected = format!("{:04X}", crc16_ccitt(unsigned.as_bytes()));
+    if !checksum.eq_ignore_ascii_case(&expected) {
+        return Err("Payload CRC does not match".to_string());
+    }
+
+    let fields = decode_tlv(payload)?;
+
+    let value_of = |tag: &str| {
+        fields
+            .iter()
+            .find(|field| field.tag == tag)
+            .map(|field| field.value.clone())
+    };
+
+    if value_of("00").as_deref() != Some("01") {
+        return Err("Unsupported payload format".to_string());
+    }
+
+    if value_of("53").as_deref() != Some(CURRENCY_IDR) {
+        return Err("Only IDR payloads are supported".to_string());
+    }
+
+    let merchant_pan = match value_of("26") {
+        Some(template) => decode_tlv(&template)?
+            .into_iter()
+            .find(|field| field.tag == "01")
+            .map(|field| field.value),
+        None => None,
+    };
+
+    let reference = match value_of("62") {
+        Some(template) => decode_tlv(&template)?
+            .into_iter()
+            .find(|field| field.tag == "05")
+            .map(|field| field.value),
+        None => None,
+    };
+
+    let amount = value_of("54")
+        .map(|amount| {
+            amount
+                .parse::<i32>()
+                .map_err(|_| "Amount must be a whole number of rupiah".to_string())
+        })
+        .transpose()?;
+
+    Ok(QrisPayload {
+        dynamic: value_of("01").as_deref() == Some("12"),
+        merchant_pan,
+        merchant_name: value_of("59"),
+        amount,
+        reference,
+    })
+}
+
+fn truncate(value: &str, max_chars: usize) -> String {
+    value.trim().chars().take(max_chars).collect()
+}
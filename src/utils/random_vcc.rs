@@ -3,24 +3,28 @@ use rand::Rng;
 pub fn random_vcc() -> Result<String, &'static str> {
     let mut rng = rand::rng();
 
-    // Generate a random 15-digit number as a string
+    // Generate a random 14-digit number as a string, leaving room for the
+    // leading "4" and the check digit in a 16-digit card number
     let mut random_number = String::new();
-    for _ in 0..15 {
+    for _ in 0..14 {
         random_number.push_str(&rng.random_range(0..10).to_string());
     }
 
     // Prepend "4" to signify a Visa-like card
     let partial_card_number = format!("4{}", random_number);
-    let check_digit = calculate_check_digit(&partial_card_number);
+    let check_digit = calculate_check_digit(&partial_card_number, true);
 
     // Final credit card number with check digit
     let credit_card_number = format!("{}{}", partial_card_number, check_digit);
     Ok(credit_card_number)
 }
 
-fn calculate_check_digit(number: &str) -> u32 {
+/// Luhn check digit of `number`. The check digit is appended on the right,
+/// so the rightmost digit of `number` is the first one to be doubled;
+/// legacy account numbers were computed without doubling it.
+fn calculate_check_digit(number: &str, double_rightmost: bool) -> u32 {
     let mut sum = 0;
-    let mut alternate = false;
+    let mut alternate = double_rightmost;
 
     for digit_char in number.chars().rev() {
         let mut digit = digit_char.to_digit(10).expect("Invalid digit in number");
@@ -37,4 +41,38 @@ fn calculate_check_digit(number: &str) -> u32 {
     }
 
     (10 - (sum % 10)) % 10
-}
\ No newline at end of file
+}
+
+/// Checks that `number` looks like an account number produced by [`random_vcc`]:
+/// 16 digits with a valid Luhn check digit.
+pub fn is_valid_vcc(number: &str) -> bool {
+    if number.len() != 16 || !number.chars().all(|c| c.is_ascii_digit()) {
+        return false;
+    }
+
+    let (partial, check) = number.split_at(15);
+
+    check.parse::<u32>().ok() == Some(calculate_check_digit(partial, true))
+}
+
+/// Checks that `number` looks like an account number from the generator used
+/// before [`random_vcc`]: 17 digits starting with `4`, whose check digit was
+/// computed with the other doubling parity.
+pub fn is_legacy_vcc(number: &str) -> bool {
+    if number.len() != 17
+        || !number.starts_with('4')
+        || !number.chars().all(|c| c.is_ascii_digit())
+    {
+        return false;
+    }
+
+    let (partial, check) = number.split_at(16);
+
+    check.parse::<u32>().ok() == Some(calculate_check_digit(partial, false))
+}
+
+/// Checks that `number` can address an existing account, whichever
+/// generator issued it. New numbers are always [`random_vcc`]'s format.
+pub fn is_valid_account_number(number: &str) -> bool {
+    is_valid_vcc(number) || is_legacy_vcc(number)
+}
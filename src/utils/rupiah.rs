@@ -6,4 +6,23 @@ pub fn rupiah_format(digit: &str) -> String {
         Ok(digit_number) => format!("Rp.{:.0}", digit_number),
         Err(_) => "Rp 0".to_string(),
     }
-}
\ No newline at end of file
+}
+
+/// Formats an amount with thousands separators, e.g. `50,000`.
+pub fn thousands(amount: i32) -> String {
+    let digits = amount.unsigned_abs().to_string();
+    let mut grouped = String::new();
+
+    for (index, digit) in digits.chars().enumerate() {
+        if index > 0 && (digits.len() - index).is_multiple_of(3) {
+            grouped.push(',');
+        }
+        grouped.push(digit);
+    }
+
+    if amount < 0 {
+        format!("-{}", grouped)
+    } else {
+        grouped
+    }
+}
@@ -0,0 +1,100 @@
+use chrono::{Datelike, Duration, NaiveDate, NaiveDateTime};
+
+pub const FREQUENCIES: [&str; 3] = ["once", "weekly", "monthly"];
+
+fn last_day_of_month(year: i32, month: u32) -> u32 {
+    let (next_year, next_month) = if month == 12 {
+        (year + 1, 1)
+    } else {
+        (year, month + 1)
+    };
+
+    NaiveDate::from_ymd_opt(next_year, next_month, 1)
+        .and_then(|date| date.pred_opt())
+        .map(|date| date.day())
+        .unwrap_or(28)
+}
+
+/// Day `day_of_month` of the given month, clamped to the month's last day
+/// so that e.g. day 31 runs on the 30th in April and the 28th/29th in February.
+fn monthly_occurrence(
+    year: i32,
+    month: u32,
+    day_of_month: u32,
+    start_at: NaiveDateTime,
+) -> Option<NaiveDateTime> {
+    let day = day_of_month.min(last_day_of_month(year, month));
+
+    NaiveDate::from_ymd_opt(year, month, day).map(|date| date.and_time(start_at.time()))
+}
+
+fn first_monthly_after(
+    start_at: NaiveDateTime,
+    day_of_month: u32,
+    after: NaiveDateTime,
+    inclusive: bool,
+) -> Option<NaiveDateTime> {
+    let from = start_at.max(after);
+    let (mut year, mut month) = (from.year(), from.month());
+
+    loop {
+        let candidate = monthly_occurrence(year, month, day_of_month, start_at)?;
+
+        let past_after = if inclusive {
+            candidate >= after
+        } else {
+            candidate > after
+        };
+
+        if candidate >= start_at && past_after {
+            return Some(candidate);
+        }
+
+        if month == 12 {
+            year += 1;
+            month = 1;
+        } else {
+            month += 1;
+        }
+    }
+}
+
+/// First time a schedule starting at `start_at` should run.
+pub fn first_run_at(
+    frequency: &str,
+    start_at: NaiveDateTime,
+    day_of_month: Option<i32>,
+) -> Option<NaiveDateTime> {
+    match (frequency, day_of_month) {
+        ("once", _) | ("weekly", _) => Some(start_at),
+        ("monthly", Some(day)) if day > 0 => {
+            first_monthly_after(start_at, day as u32, start_at, true)
+        }
+        _ => None,
+    }
+}
+
+/// Next occurrence strictly after `after`, or `None` once a schedule has no
+/// further runs. Occurrences are anchored on `start_at`, so retries and late
+/// runs never shift the schedule.
+pub fn next_run_after(
+    frequency: &str,
+    start_at: NaiveDateTime,
+    day_of_month: Option<i32>,
+    after: NaiveDateTime,
+) -> Option<NaiveDateTime> {
+    match (frequency, day_of_month) {
+        ("weekly", _) => {
+            if after < start_at {
+                return Some(start_at);
+            }
+
+            let weeks = (after - start_at).num_weeks() + 1;
+            Some(start_at + Duration::weeks(weeks))
+        }
+        ("monthly", Some(day)) if day > 0 => {
+            first_monthly_after(start_at, day as u32, after, false)
+        }
+        _ => None,
+    }
+}
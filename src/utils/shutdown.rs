@@ -0,0 +1,80 @@
+use std::{future::Future, sync::Arc, time::Duration};
+
+use tokio::sync::watch;
+use tracing::info;
+
+/// Tells the server and the background workers that the process is shutting
+/// down. Clones share the same signal.
+#[derive(Clone)]
+pub struct Shutdown {
+    sender: Arc<watch::Sender<bool>>,
+}
+
+impl Default for Shutdown {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Shutdown {
+    pub fn new() -> Self {
+        Self {
+            sender: Arc::new(watch::channel(false).0),
+        }
+    }
+
+    pub fn trigger(&self) {
+        self.sender.send_replace(true);
+    }
+
+    pub fn is_triggered(&self) -> bool {
+        *self.sender.borrow()
+    }
+
+    /// Resolves once the shutdown is triggered, immediately if it already was.
+    pub async fn triggered(&self) {
+        let mut receiver = self.sender.subscribe();
+        let _ = receiver.wait_for(|triggered| *triggered).await;
+    }
+
+    /// Triggers the shutdown on SIGTERM or SIGINT.
+    pub async fn trigger_on_signal(&self) {
+        let ctrl_c = async {
+            tokio::signal::ctrl_c()
+                .await
+                .expect("failed to listen for SIGINT");
+        };
+
+        #[cfg(unix)]
+        let terminate = async {
+            tokio::signal::unix::signal(tokio::signal::unix::SignalKind::terminate())
+                .expect("failed to listen for SIGTERM")
+                .recv()
+                .await;
+        };
+
+        #[cfg(not(unix))]
+        let terminate = std::future::pending::<()>();
+
+        tokio::select! {
+            _ = ctrl_c => info!("Received SIGINT"),
+            _ = terminate => info!("Received SIGTERM"),
+        }
+
+        self.trigger();
+    }
+
+    /// Runs `work` to completion, but gives up on it once `timeout` has passed
+    /// since the shutdown was triggered. `None` when it was cut off.
+    pub async fn drain<F: Future>(&self, timeout: Duration, work: F) -> Option<F::Output> {
+        let deadline = async {
+            self.triggered().await;
+            tokio::time::sleep(timeout).await;
+        };
+
+        tokio::select! {
+            output = work => Some(output),
+            _ = deadline => None,
+        }
+    }
+}
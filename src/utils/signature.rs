@@ -0,0 +1,24 @@
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// Hex-encoded HMAC-SHA256 of `payload`.
+pub fn sign_hmac_sha256(secret: &str, payload: &[u8]) -> String {
+    let mut mac =
+        HmacSha256::new_from_slice(secret.as_bytes()).expect("HMAC accepts keys of any length");
+    mac.update(payload);
+    hex::encode(mac.finalize().into_bytes())
+}
+
+/// Checks a hex-encoded HMAC-SHA256 signature in constant time.
+pub fn verify_hmac_sha256(secret: &str, payload: &[u8], signature: &str) -> bool {
+    let Ok(signature) = hex::decode(signature.trim()) else {
+        return false;
+    };
+
+    let mut mac =
+        HmacSha256::new_from_slice(secret.as_bytes()).expect("HMAC accepts keys of any length");
+    mac.update(payload);
+    mac.verify_slice(&signature).is_ok()
+}
@@ -0,0 +1,229 @@
+use std::collections::{HashMap, HashSet};
+
+use serde::{Deserialize, Serialize};
+
+/// A sanctioned or watched party from a list file.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WatchlistEntry {
+    /// Identifier of the entry on its source list.
+    pub entry_id: String,
+    pub name: String,
+    /// Source list, such as `UN` or `OFAC`.
+    pub list: String,
+    #[serde(default)]
+    pub aliases: Vec<String>,
+}
+
+/// Row of a CSV list file, aliases are separated by `;`.
+#[derive(Debug, Deserialize)]
+struct WatchlistCsvRow {
+    entry_id: String,
+    name: String,
+    list: String,
+    #[serde(default)]
+    aliases: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct WatchlistMatch {
+    pub entry: WatchlistEntry,
+    /// Name or alias of the entry that matched.
+    pub matched_name: String,
+    /// Similarity from 0 to 100.
+    pub score: i32,
+}
+
+/// Lowercases a name, drops punctuation and sorts its words so that
+/// "DOE, John" and "john doe" compare equal.
+pub fn normalize_name(name: &str) -> String {
+    let cleaned: String = name
+        .chars()
+        .map(|c| {
+            if c.is_alphanumeric() {
+                c.to_lowercase().next().unwrap_or(c)
+            } else {
+                ' '
+            }
+        })
+        .collect();
+
+    let mut words: Vec<&str> = cleaned.split_whitespace().collect();
+    words.sort_unstable();
+    words.join(" ")
+}
+
+fn levenshtein(a: &[char], b: &[char]) -> usize {
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    let mut current = vec![0; b.len() + 1];
+
+    for (i, ca) in a.iter().enumerate() {
+        current[0] = i + 1;
+
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(ca != cb);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+
+        std::mem::swap(&mut previous, &mut current);
+    }
+
+    previous[b.len()]
+}
+
+/// Similarity of two normalized names from 0 to 100, based on their edit
+/// distance.
+pub fn name_similarity(a: &str, b: &str) -> i32 {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let longest = a.len().max(b.len());
+
+    if longest == 0 {
+        return 0;
+    }
+
+    let distance = levenshtein(&a, &b);
+    (100 * (longest - distance) / longest) as i32
+}
+
+fn trigrams(normalized: &str) -> HashSet<String> {
+    let padded: Vec<char> = format!("  {} ", normalized).chars().collect();
+
+    padded
+        .windows(3)
+        .map(|window| window.iter().collect())
+        .collect()
+}
+
+/// Watchlist held in memory for fuzzy name lookups. Names are bucketed by
+/// trigram so a search only scores names sharing part of the spelling.
+#[derive(Debug, Default)]
+pub struct WatchlistIndex {
+    entries: Vec<WatchlistEntry>,
+    /// Every name and alias as (entry position, original, normalized).
+    names: Vec<(usize, String, String)>,
+    trigrams: HashMap<String, Vec<usize>>,
+}
+
+impl WatchlistIndex {
+    pub fn new(entries: Vec<WatchlistEntry>) -> Self {
+        let mut names = Vec::new();
+        let mut index: HashMap<String, Vec<usize>> = HashMap::new();
+
+        for (position, entry) in entries.iter().enumerate() {
+            for name in std::iter::once(&entry.name).chain(entry.aliases.iter()) {
+                let normalized = normalize_name(name);
+
+                if normalized.is_empty() {
+                    continue;
+                }
+
+                for trigram in trigrams(&normalized) {
+                    index.entry(trigram).or_default().push(names.len());
+                }
+
+                names.push((position, name.clone(), normalized));
+            }
+        }
+
+        Self {
+            entries,
+            names,
+            trigrams: index,
+        }
+    }
+
+    /// Parses a list with an `entry_id,name,list,aliases` header.
+    pub fn from_csv(content: &str) -> Result<Self, String> {
+        let mut reader = csv::ReaderBuilder::new()
+            .trim(csv::Trim::All)
+            .from_reader(content.as_bytes());
+
+        let mut entries = Vec::new();
+
+        for (line, row) in reader.deserialize::<WatchlistCsvRow>().enumerate() {
+            let row = row.map_err(|e| format!("Row {}: {}", line + 1, e))?;
+
+            entries.push(WatchlistEntry {
+                entry_id: row.entry_id,
+                name: row.name,
+                list: row.list,
+                aliases: row
+                    .aliases
+                    .unwrap_or_default()
+                    .split(';')
+                    .map(str::trim)
+                    .filter(|alias| !alias.is_empty())
+                    .map(str::to_string)
+                    .collect(),
+            });
+        }
+
+        Ok(Self::new(entries))
+    }
+
+    /// Parses a JSON array of entries.
+    pub fn from_json(content: &str) -> Result<Self, String> {
+        let entries: Vec<WatchlistEntry> =
+            serde_json::from_str(content).map_err(|e| e.to_string())?;
+
+        Ok(Self::new(entries))
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Entries whose name or an alias scores at least `min_score` against
+    /// `name`, best match first and one match per entry.
+    pub fn search(&self, name: &str, min_score: i32) -> Vec<WatchlistMatch> {
+        let normalized = normalize_name(name);
+
+        if normalized.is_empty() {
+            return Vec::new();
+        }
+
+        let candidates: HashSet<usize> = trigrams(&normalized)
+            .iter()
+            .filter_map(|trigram| self.trigrams.get(trigram))
+            .flatten()
+            .copied()
+            .collect();
+
+        let mut best: HashMap<usize, (i32, &str)> = HashMap::new();
+
+        for candidate in candidates {
+            let (position, original, candidate_name) = &self.names[candidate];
+            let score = name_similarity(&normalized, candidate_name);
+
+            if score < min_score {
+                continue;
+            }
+
+            let current = best.entry(*position).or_insert((score, original));
+            if score > current.0 {
+                *current = (score, original);
+            }
+        }
+
+        let mut matches: Vec<WatchlistMatch> = best
+            .into_iter()
+            .map(|(position, (score, matched_name))| WatchlistMatch {
+                entry: self.entries[position].clone(),
+                matched_name: matched_name.to_string(),
+                score,
+            })
+            .collect();
+
+        matches.sort_by(|a, b| {
+            b.score
+                .cmp(&a.score)
+                .then_with(|| a.entry.entry_id.cmp(&b.entry.entry_id))
+        });
+
+        matches
+    }
+}
@@ -0,0 +1,109 @@
+use std::sync::Arc;
+
+use axum::Router;
+use example_payment_gateway_axum::{
+    abstract_trait::user::UserRepositoryTrait,
+    config::{config::Config, database::ConnectionManager},
+    domain::request::user::CreateUserRequest,
+    entities::users,
+    handler::admin_virtual_account_routes,
+    migrations::Migrator,
+    repository::user::UserRepository,
+    state::AppState,
+};
+use sea_orm::{sea_query::Expr, ColumnTrait, EntityTrait, QueryFilter};
+use tokio::net::TcpListener;
+
+/// Starts the admin virtual account routes and returns their base url with
+/// a token for a regular user and one for an admin.
+async fn start_app() -> (String, String, String) {
+    let pool = ConnectionManager::new_pool::<Migrator>("sqlite::memory:", true)
+        .await
+        .unwrap();
+
+    let vars = [
+        ("DATABASE_URL", "sqlite::memory:"),
+        ("JWT_SECRET", "jwt-secret"),
+        ("PAYMENT_CALLBACK_SECRET", "callback-secret"),
+    ];
+    let config = Config::from_sources(None, &|name: &str| {
+        vars.iter()
+            .find(|(key, _)| *key == name)
+            .map(|(_, value)| value.to_string())
+    })
+    .unwrap();
+
+    let users = UserRepository::new(pool.clone().into());
+    let mut user_ids = Vec::new();
+    for (email, noc_transfer) in [
+        ("customer@example.com", "4000000000000001"),
+        ("staff@example.com", "4000000000000002"),
+    ] {
+        let user = users
+            .create_user(&CreateUserRequest {
+                firstname: "Test".to_string(),
+                lastname: "User".to_string(),
+                email: email.to_string(),
+                password: "hash".to_string(),
+                noc_transfer: Some(noc_transfer.to_string()),
+                confirm_password: "hash".to_string(),
+            })
+            .await
+            .unwrap();
+        user_ids.push(user.user_id);
+    }
+
+    users::Entity::update_many()
+        .col_expr(users::Column::Role, Expr::value("admin"))
+        .filter(users::Column::UserId.eq(user_ids[1]))
+        .exec(&pool)
+        .await
+        .unwrap();
+
+    let state = Arc::new(AppState::new(pool.into(), &config));
+    let customer_token = state.jwt_config.generate_token(user_ids[0] as i64).unwrap();
+    let admin_token = state.jwt_config.generate_token(user_ids[1] as i64).unwrap();
+
+    let app: Router = admin_virtual_account_routes(state).into();
+
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+    tokio::spawn(async move { axum::serve(listener, app).await });
+
+    (url, customer_token, admin_token)
+}
+
+#[tokio::test]
+async fn test_suspense_queue_is_admin_only() {
+    let (url, customer_token, admin_token) = start_app().await;
+    let client = reqwest::Client::new();
+
+    let list = client
+        .get(format!("{}/api/admin/virtual-accounts/suspense", url))
+        .bearer_auth(&customer_token)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(list.status(), 403);
+
+    let resolve = client
+        .post(format!(
+            "{}/api/admin/virtual-accounts/suspense/1/resolve",
+            url
+        ))
+        .bearer_auth(&customer_token)
+        .header("Content-Type", "application/json")
+        .body(r#"{"user_id": 1}"#)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resolve.status(), 403);
+
+    let list = client
+        .get(format!("{}/api/admin/virtual-accounts/suspense", url))
+        .bearer_auth(&admin_token)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(list.status(), 200);
+}
@@ -0,0 +1,105 @@
+use std::{
+    net::{IpAddr, SocketAddr},
+    sync::Arc,
+};
+
+use axum::{body::Body, extract::ConnectInfo, http::Request};
+use example_payment_gateway_axum::{
+    abstract_trait::{audit::AuditServiceTrait, clock::DynClock},
+    config::{clock::SystemClock, database::ConnectionManager},
+    domain::request::audit::CreateAuditEvent,
+    middleware::audit::client_ip,
+    migrations::Migrator,
+    repository::audit::AuditRepository,
+    services::audit::AuditService,
+};
+
+fn event(instance: usize, n: usize) -> CreateAuditEvent {
+    CreateAuditEvent {
+        actor_id: Some(1),
+        action: "PUT /api/saldos/{id}".to_string(),
+        resource_type: "saldos".to_string(),
+        resource_id: Some(n.to_string()),
+        before: None,
+        after: None,
+        client_ip: None,
+        request_id: format!("instance-{}-req-{}", instance, n),
+    }
+}
+
+#[tokio::test]
+async fn test_instances_sharing_a_database_keep_one_chain() {
+    let pool = ConnectionManager::new_pool::<Migrator>("sqlite::memory:", true)
+        .await
+        .unwrap();
+
+    // Two app instances, each with its own service, writing at once
+    let instances: Vec<Arc<AuditService>> = (0..2)
+        .map(|_| {
+            Arc::new(AuditService::new(
+                Arc::new(AuditRepository::new(pool.clone())),
+                Arc::new(SystemClock) as DynClock,
+            ))
+        })
+        .collect();
+
+    let writers: Vec<_> = instances
+        .iter()
+        .enumerate()
+        .map(|(instance, service)| {
+            let service = service.clone();
+            tokio::spawn(async move {
+                for n in 0..5 {
+                    service.record(&event(instance, n)).await.unwrap();
+                }
+            })
+        })
+        .collect();
+
+    for writer in writers {
+        writer.await.unwrap();
+    }
+
+    let verification = instances[0].verify_chain().await.unwrap().data;
+
+    assert!(verification.valid, "{:?}", verification.reason);
+    assert_eq!(verification.events_checked, 10);
+}
+
+fn request(peer: &str, forwarded_for: Option<&str>) -> Request<Body> {
+    let mut builder = Request::builder().uri("/api/users/5");
+
+    if let Some(forwarded_for) = forwarded_for {
+        builder = builder.header("x-forwarded-for", forwarded_for);
+    }
+
+    let mut req = builder.body(Body::empty()).unwrap();
+    req.extensions_mut()
+        .insert(ConnectInfo(SocketAddr::new(peer.parse().unwrap(), 443)));
+    req
+}
+
+#[test]
+fn test_forwarded_for_is_ignored_from_untrusted_peers() {
+    let trusted: Vec<IpAddr> = vec!["10.0.0.2".parse().unwrap()];
+
+    let req = request("203.0.113.7", Some("198.51.100.1"));
+
+    assert_eq!(client_ip(&req, &trusted).as_deref(), Some("203.0.113.7"));
+    assert_eq!(client_ip(&req, &[]).as_deref(), Some("203.0.113.7"));
+}
+
+#[test]
+fn test_forwarded_for_is_followed_through_trusted_proxies_only() {
+    let trusted: Vec<IpAddr> = vec!["10.0.0.2".parse().unwrap(), "10.0.0.3".parse().unwrap()];
+
+    // The client forged the first entry; the proxies appended the rest
+    let req = request("10.0.0.2", Some("198.51.100.1, 203.0.113.7, 10.0.0.3"));
+    assert_eq!(client_ip(&req, &trusted).as_deref(), Some("203.0.113.7"));
+
+    let req = request("10.0.0.2", Some("not-an-ip"));
+    assert_eq!(client_ip(&req, &trusted).as_deref(), Some("10.0.0.2"));
+
+    let req = request("10.0.0.2", None);
+    assert_eq!(client_ip(&req, &trusted).as_deref(), Some("10.0.0.2"));
+}
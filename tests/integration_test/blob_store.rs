@@ -0,0 +1,38 @@
+use example_payment_gateway_axum::{
+    abstract_trait::blob_store::BlobStoreTrait, providers::blob_store::LocalBlobStore,
+};
+use uuid::Uuid;
+
+#[tokio::test]
+async fn test_local_blob_store_round_trip() {
+    let root = std::env::temp_dir().join(format!("blob-store-{}", Uuid::new_v4().simple()));
+    let store = LocalBlobStore::new(&root);
+
+    store.put("kyc/1/id_card.png", b"first").await.unwrap();
+    store.put("kyc/1/id_card.png", b"second").await.unwrap();
+
+    assert_eq!(store.get("kyc/1/id_card.png").await.unwrap(), b"second");
+    assert_eq!(std::fs::read_dir(root.join("kyc/1")).unwrap().count(), 1);
+
+    store.delete("kyc/1/id_card.png").await.unwrap();
+    store.delete("kyc/1/id_card.png").await.unwrap();
+    assert!(store.get("kyc/1/id_card.png").await.is_err());
+
+    std::fs::remove_dir_all(&root).unwrap();
+}
+
+#[tokio::test]
+async fn test_local_blob_store_rejects_keys_outside_root() {
+    let root = std::env::temp_dir().join(format!("blob-store-{}", Uuid::new_v4().simple()));
+    let store = LocalBlobStore::new(&root);
+
+    for key in ["../escape.png", "/etc/passwd", "kyc/../../escape.png", ""] {
+        assert!(
+            store.put(key, b"data").await.is_err(),
+            "{} was accepted",
+            key
+        );
+    }
+
+    assert!(!root.exists());
+}
@@ -0,0 +1,62 @@
+use axum::{middleware, routing::get, Router};
+use example_payment_gateway_axum::{
+    middleware::metrics::track_http,
+    utils::metrics::{record_login_failure, METRICS},
+};
+use tokio::net::TcpListener;
+
+/// Starts an app on a free local port with one parameterised route.
+async fn start_app() -> String {
+    let app = Router::new()
+        .route("/metrics-test/{id}", get(|| async { "ok" }))
+        .layer(middleware::from_fn(track_http));
+
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+    tokio::spawn(async move { axum::serve(listener, app).await });
+
+    url
+}
+
+#[tokio::test]
+async fn test_http_requests_are_labelled_by_route_pattern() {
+    let url = start_app().await;
+    let client = reqwest::Client::new();
+
+    for id in [1, 2] {
+        let response = client
+            .get(format!("{}/metrics-test/{}", url, id))
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(response.status(), 200);
+    }
+
+    let response = client
+        .get(format!("{}/metrics-missing", url))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), 404);
+
+    let rendered = METRICS.render();
+
+    assert!(rendered.contains(
+        r#"http_requests_total{method="GET",route="/metrics-test/{id}",status="200"} 2"#
+    ));
+    assert!(rendered.contains(
+        r#"http_request_duration_seconds_count{method="GET",route="/metrics-test/{id}",status="200"} 2"#
+    ));
+    assert!(rendered.contains(r#"route="unmatched",status="404""#));
+    assert!(!rendered.contains("/metrics-test/1"));
+}
+
+#[test]
+fn test_render_uses_prometheus_text_format() {
+    record_login_failure("invalid_credentials");
+
+    let rendered = METRICS.render();
+
+    assert!(rendered.contains("# TYPE login_failures_total counter"));
+    assert!(rendered.contains(r#"login_failures_total{reason="invalid_credentials"}"#));
+}
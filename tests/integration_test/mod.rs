@@ -0,0 +1,12 @@
+mod admin;
+mod audit;
+mod blob_store;
+mod config;
+mod metrics;
+mod rate_limit;
+mod read_replica;
+mod request_id;
+mod shutdown;
+mod tracing;
+mod watchlist;
+mod webhook;
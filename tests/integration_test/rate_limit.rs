@@ -0,0 +1,58 @@
+use std::{
+    net::{IpAddr, Ipv4Addr, SocketAddr},
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+use axum::{middleware, routing::get, Router};
+use example_payment_gateway_axum::middleware::rate_limit::{rate_limit, RateLimiter};
+use tokio::net::TcpListener;
+
+#[test]
+fn test_limit_resets_with_the_next_window() {
+    let limiter = RateLimiter::new(2);
+    let client = IpAddr::V4(Ipv4Addr::new(10, 0, 0, 1));
+    let other = IpAddr::V4(Ipv4Addr::new(10, 0, 0, 2));
+    let start = Instant::now();
+
+    assert!(limiter.check(client, start).is_ok());
+    assert!(limiter.check(client, start).is_ok());
+    assert_eq!(
+        limiter.check(client, start + Duration::from_secs(20)),
+        Err(Duration::from_secs(40))
+    );
+    assert!(limiter.check(other, start).is_ok());
+    assert!(limiter
+        .check(client, start + Duration::from_secs(60))
+        .is_ok());
+}
+
+#[tokio::test]
+async fn test_requests_over_the_limit_get_429() {
+    let limiter = Arc::new(RateLimiter::new(1));
+    let app = Router::new()
+        .route("/limited", get(|| async { "ok" }))
+        .layer(middleware::from_fn_with_state(limiter, rate_limit));
+
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("http://{}/limited", listener.local_addr().unwrap());
+    tokio::spawn(async move {
+        axum::serve(
+            listener,
+            app.into_make_service_with_connect_info::<SocketAddr>(),
+        )
+        .await
+    });
+
+    let client = reqwest::Client::new();
+
+    let response = client.get(&url).send().await.unwrap();
+    assert_eq!(response.status(), 200);
+
+    let response = client.get(&url).send().await.unwrap();
+    assert_eq!(response.status(), 429);
+    assert!(response.headers().contains_key("retry-after"));
+
+    let body: serde_json::Value = serde_json::from_slice(&response.bytes().await.unwrap()).unwrap();
+    assert_eq!(body["status"], "fail");
+}
@@ -0,0 +1,105 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, State},
+    middleware,
+    routing::get,
+    Router,
+};
+use example_payment_gateway_axum::{
+    abstract_trait::user::{DynUserRepository, UserRepositoryTrait},
+    config::database::{reading_from_replica, ConnectionManager, DatabasePools},
+    domain::request::user::CreateUserRequest,
+    middleware::read_replica::read_replica,
+    migrations::Migrator,
+    repository::user::UserRepository,
+};
+use sea_orm::DatabaseConnection;
+use tokio::net::TcpListener;
+
+async fn sqlite_pool() -> DatabaseConnection {
+    ConnectionManager::new_pool::<Migrator>("sqlite::memory:", true)
+        .await
+        .unwrap()
+}
+
+/// Separate primary and replica databases, with one user only on the
+/// replica so it shows which one a read went to.
+async fn pools_with_user_on_replica() -> (DatabasePools, i32) {
+    let primary = sqlite_pool().await;
+    let replica = sqlite_pool().await;
+
+    let user = UserRepository::new(DatabasePools::from(replica.clone()))
+        .create_user(&CreateUserRequest {
+            firstname: "Replica".to_string(),
+            lastname: "Only".to_string(),
+            email: "replica@example.com".to_string(),
+            password: "hash".to_string(),
+            noc_transfer: Some("4242".to_string()),
+            confirm_password: "hash".to_string(),
+        })
+        .await
+        .unwrap();
+
+    (DatabasePools::new(primary, Some(replica)), user.user_id)
+}
+
+#[tokio::test]
+async fn test_listed_reads_use_the_replica_only_when_asked() {
+    let (db_pools, user_id) = pools_with_user_on_replica().await;
+    let repository = UserRepository::new(db_pools);
+
+    assert!(repository.find_by_id(user_id).await.unwrap().is_none());
+
+    reading_from_replica(async {
+        assert!(repository.find_by_id(user_id).await.unwrap().is_some());
+        assert_eq!(repository.find_all().await.unwrap().len(), 1);
+
+        // Lookups outside find_all, find_by_id and reports stay on the primary
+        assert!(repository
+            .find_by_email("replica@example.com")
+            .await
+            .unwrap()
+            .is_none());
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn test_only_get_requests_read_from_the_replica() {
+    let (db_pools, user_id) = pools_with_user_on_replica().await;
+    let repository = Arc::new(UserRepository::new(db_pools)) as DynUserRepository;
+
+    async fn find(State(repository): State<DynUserRepository>, Path(id): Path<i32>) -> String {
+        let user = repository.find_by_id(id).await.unwrap();
+        user.map_or("missing".to_string(), |user| user.email)
+    }
+
+    let app = Router::new()
+        .route("/users/{id}", get(find).post(find))
+        .layer(middleware::from_fn(read_replica))
+        .with_state(repository);
+
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!(
+        "http://{}/users/{}",
+        listener.local_addr().unwrap(),
+        user_id
+    );
+    tokio::spawn(async move { axum::serve(listener, app).await });
+
+    let client = reqwest::Client::new();
+
+    let read = client.get(&url).send().await.unwrap().text().await.unwrap();
+    assert_eq!(read, "replica@example.com");
+
+    let write = client
+        .post(&url)
+        .send()
+        .await
+        .unwrap()
+        .text()
+        .await
+        .unwrap();
+    assert_eq!(write, "missing");
+}
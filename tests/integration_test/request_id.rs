@@ -0,0 +1,82 @@
+use axum::{http::StatusCode, middleware, routing::get, Json, Router};
+use example_payment_gateway_axum::{
+    domain::response::ErrorResponse, middleware::request_id::request_id, utils::errors::AppError,
+};
+use tokio::net::TcpListener;
+
+/// Starts an app on a free local port whose route always fails.
+async fn start_app() -> String {
+    let app = Router::new()
+        .route(
+            "/fail",
+            get(|| async {
+                (
+                    StatusCode::NOT_FOUND,
+                    Json(ErrorResponse::from(AppError::NotFound(
+                        "Nothing here".to_string(),
+                    ))),
+                )
+            }),
+        )
+        .layer(middleware::from_fn(request_id));
+
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("http://{}/fail", listener.local_addr().unwrap());
+    tokio::spawn(async move { axum::serve(listener, app).await });
+
+    url
+}
+
+#[tokio::test]
+async fn test_request_id_from_caller_is_echoed_in_header_and_error() {
+    let url = start_app().await;
+
+    let response = reqwest::Client::new()
+        .get(url)
+        .header("X-Request-Id", "support-ticket-42")
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(response.headers()["x-request-id"], "support-ticket-42");
+
+    let body: serde_json::Value = serde_json::from_slice(&response.bytes().await.unwrap()).unwrap();
+    assert_eq!(body["request_id"], "support-ticket-42");
+    assert_eq!(body["message"], "Nothing here");
+}
+
+#[tokio::test]
+async fn test_request_id_is_generated_when_missing_or_invalid() {
+    let url = start_app().await;
+    let client = reqwest::Client::new();
+
+    for header in [None, Some("not valid!"), Some("")] {
+        let mut request = client.get(&url);
+        if let Some(header) = header {
+            request = request.header("X-Request-Id", header);
+        }
+
+        let response = request.send().await.unwrap();
+        let request_id = response.headers()["x-request-id"]
+            .to_str()
+            .unwrap()
+            .to_string();
+
+        assert!(uuid::Uuid::parse_str(&request_id).is_ok());
+
+        let body: serde_json::Value =
+            serde_json::from_slice(&response.bytes().await.unwrap()).unwrap();
+        assert_eq!(body["request_id"], request_id.as_str());
+    }
+}
+
+#[test]
+fn test_error_response_outside_a_request_has_no_request_id() {
+    let error = ErrorResponse::from(AppError::InsufficientBalance);
+
+    assert_eq!(error.request_id, None);
+    assert!(serde_json::to_value(&error)
+        .unwrap()
+        .get("request_id")
+        .is_none());
+}
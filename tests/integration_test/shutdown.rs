@@ -0,0 +1,106 @@
+use std::{
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+
+use example_payment_gateway_axum::{
+    abstract_trait::{
+        clock::MockClockTrait, scheduled_transfer::MockScheduledTransferServiceTrait,
+    },
+    jobs::{health::WorkerHealth, scheduler::TransferScheduler},
+    utils::shutdown::Shutdown,
+};
+use tokio::time::timeout;
+
+#[tokio::test]
+async fn test_drain_waits_for_work_that_finishes_in_time() {
+    let shutdown = Shutdown::new();
+    shutdown.trigger();
+
+    let drained = shutdown
+        .drain(Duration::from_secs(5), async {
+            tokio::time::sleep(Duration::from_millis(50)).await;
+            "done"
+        })
+        .await;
+
+    assert_eq!(drained, Some("done"));
+}
+
+#[tokio::test]
+async fn test_drain_only_counts_the_timeout_from_the_shutdown() {
+    let shutdown = Shutdown::new();
+
+    let drained = shutdown
+        .drain(Duration::from_millis(10), async {
+            tokio::time::sleep(Duration::from_millis(100)).await;
+            "done"
+        })
+        .await;
+
+    assert_eq!(drained, Some("done"));
+}
+
+#[tokio::test]
+async fn test_drain_gives_up_on_work_past_the_timeout() {
+    let shutdown = Shutdown::new();
+
+    let trigger = shutdown.clone();
+    tokio::spawn(async move {
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        trigger.trigger();
+    });
+
+    let drained = timeout(
+        Duration::from_secs(5),
+        shutdown.drain(Duration::from_millis(50), std::future::pending::<()>()),
+    )
+    .await
+    .expect("drain returned after the timeout");
+
+    assert_eq!(drained, None);
+    assert!(shutdown.is_triggered());
+}
+
+#[tokio::test]
+async fn test_worker_stops_after_shutdown() {
+    let runs = Arc::new(AtomicUsize::new(0));
+
+    let mut service = MockScheduledTransferServiceTrait::new();
+    let counter = runs.clone();
+    service.expect_run_due_schedules().returning(move || {
+        counter.fetch_add(1, Ordering::SeqCst);
+        Ok(0)
+    });
+
+    let mut clock = MockClockTrait::new();
+    clock
+        .expect_now()
+        .returning(|| chrono::Utc::now().naive_utc());
+
+    let worker = TransferScheduler::new(
+        Arc::new(service),
+        Duration::from_millis(10),
+        Arc::new(WorkerHealth::new(Arc::new(clock))),
+    );
+
+    let shutdown = Shutdown::new();
+    let handle = tokio::spawn(worker.run(shutdown.clone()));
+
+    tokio::time::sleep(Duration::from_millis(50)).await;
+    shutdown.trigger();
+
+    timeout(Duration::from_secs(5), handle)
+        .await
+        .expect("worker stopped after shutdown")
+        .unwrap();
+
+    let stopped_at = runs.load(Ordering::SeqCst);
+    assert!(stopped_at > 0);
+
+    tokio::time::sleep(Duration::from_millis(50)).await;
+    assert_eq!(runs.load(Ordering::SeqCst), stopped_at);
+}
@@ -0,0 +1,128 @@
+use std::{sync::LazyLock, time::Duration};
+
+use axum::{middleware, routing::get, Router};
+use example_payment_gateway_axum::{
+    abstract_trait::user::UserRepositoryTrait, config::database::ConnectionManager,
+    middleware::request_id::request_id, migrations::Migrator, repository::user::UserRepository,
+    utils::log_tracing::otel_layer,
+};
+use opentelemetry::{global, Value};
+use opentelemetry_sdk::trace::{InMemorySpanExporter, SdkTracerProvider, SpanData};
+use tokio::net::TcpListener;
+use tracing_subscriber::prelude::*;
+
+/// Provider shared by the tests, query spans go through the global one.
+static EXPORT: LazyLock<(SdkTracerProvider, InMemorySpanExporter)> = LazyLock::new(|| {
+    let exporter = InMemorySpanExporter::default();
+    let provider = SdkTracerProvider::builder()
+        .with_simple_exporter(exporter.clone())
+        .build();
+
+    global::set_tracer_provider(provider.clone());
+
+    (provider, exporter)
+});
+
+fn finished_spans(name: &str) -> Vec<SpanData> {
+    let (provider, exporter) = &*EXPORT;
+    provider.force_flush().unwrap();
+
+    exporter
+        .get_finished_spans()
+        .unwrap()
+        .into_iter()
+        .filter(|span| span.name == name)
+        .collect()
+}
+
+/// Waits for a span named `name`. A span is exported when its last handle
+/// drops, which for a repository call may be the database worker's.
+async fn wait_for_span(name: &str) -> Option<SpanData> {
+    for _ in 0..100 {
+        if let Some(span) = finished_spans(name).pop() {
+            return Some(span);
+        }
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+
+    None
+}
+
+fn attribute(span: &SpanData, key: &str) -> Option<Value> {
+    span.attributes
+        .iter()
+        .find(|attribute| attribute.key.as_str() == key)
+        .map(|attribute| attribute.value.clone())
+}
+
+#[tokio::test]
+async fn test_trace_id_is_returned_and_linked_to_request_id() {
+    let subscriber = tracing_subscriber::registry().with(otel_layer(&EXPORT.0));
+    let _guard = tracing::subscriber::set_default(subscriber);
+
+    let app = Router::new()
+        .route("/traced", get(|| async { "ok" }))
+        .layer(middleware::from_fn(request_id));
+
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("http://{}/traced", listener.local_addr().unwrap());
+    tokio::spawn(async move { axum::serve(listener, app).await });
+
+    let response = reqwest::Client::new()
+        .get(url)
+        .header("X-Request-Id", "trace-link-test")
+        .send()
+        .await
+        .unwrap();
+
+    let trace_id = response.headers()["x-trace-id"]
+        .to_str()
+        .unwrap()
+        .to_string();
+
+    let span = finished_spans("http_request")
+        .into_iter()
+        .find(|span| span.span_context.trace_id().to_string() == trace_id)
+        .expect("request span exported under the returned trace id");
+
+    assert_eq!(
+        attribute(&span, "request_id"),
+        Some(Value::from("trace-link-test"))
+    );
+    assert_eq!(attribute(&span, "route"), Some(Value::from("/traced")));
+}
+
+#[tokio::test]
+async fn test_each_query_is_a_span_below_the_repository_call() {
+    let subscriber = tracing_subscriber::registry().with(otel_layer(&EXPORT.0));
+    let _guard = tracing::subscriber::set_default(subscriber);
+
+    let pool = ConnectionManager::new_pool::<Migrator>("sqlite::memory:", true)
+        .await
+        .unwrap();
+
+    let user = UserRepository::new(pool.into())
+        .find_by_email("nobody@example.com")
+        .await
+        .unwrap();
+    assert!(user.is_none());
+
+    let repository_span = wait_for_span("UserRepository::find_by_email")
+        .await
+        .expect("repository span exported");
+    let query = finished_spans("db.query")
+        .into_iter()
+        .find(|span| span.parent_span_id == repository_span.span_context.span_id())
+        .expect("query span exported below the repository span");
+
+    assert_eq!(
+        query.span_context.trace_id(),
+        repository_span.span_context.trace_id()
+    );
+    assert_eq!(attribute(&query, "db.system"), Some(Value::from("sqlite")));
+    assert!(attribute(&query, "db.statement")
+        .unwrap()
+        .as_str()
+        .contains(r#"FROM "users""#));
+    assert!(query.start_time <= query.end_time);
+}
@@ -0,0 +1,80 @@
+use example_payment_gateway_axum::{
+    abstract_trait::watchlist::WatchlistTrait, providers::watchlist::FileWatchlist,
+};
+use uuid::Uuid;
+
+#[tokio::test]
+async fn test_file_watchlist_fuzzy_matches_names_and_aliases() {
+    let path = std::env::temp_dir().join(format!("watchlist-{}.csv", Uuid::new_v4().simple()));
+    std::fs::write(
+        &path,
+        "entry_id,name,list,aliases\n\
+         UN-1,John Doe,UN,Johnny Doh;J. Doe\n\
+         OFAC-7,Maria Fernanda Lopez,OFAC,\n",
+    )
+    .unwrap();
+
+    let watchlist = FileWatchlist::new(&path);
+    assert_eq!(watchlist.entry_count(), 2);
+
+    // Word order, case and a typo still match
+    let matches = watchlist.search("DOE, Jon", 85);
+    assert_eq!(matches.len(), 1);
+    assert_eq!(matches[0].entry.entry_id, "UN-1");
+    assert!(matches[0].score >= 85 && matches[0].score < 100);
+
+    let matches = watchlist.search("johnny doh", 85);
+    assert_eq!(matches[0].matched_name, "Johnny Doh");
+    assert_eq!(matches[0].score, 100);
+
+    assert!(watchlist.search("Siti Aminah", 85).is_empty());
+
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[tokio::test]
+async fn test_file_watchlist_reloads_without_restart() {
+    let path = std::env::temp_dir().join(format!("watchlist-{}.json", Uuid::new_v4().simple()));
+    std::fs::write(
+        &path,
+        r#"[{"entry_id": "UN-1", "name": "John Doe", "list": "UN"}]"#,
+    )
+    .unwrap();
+
+    let watchlist = FileWatchlist::new(&path);
+    assert!(watchlist.search("Maria Lopez", 85).is_empty());
+
+    std::fs::write(
+        &path,
+        r#"[
+            {"entry_id": "UN-1", "name": "John Doe", "list": "UN"},
+            {"entry_id": "UN-2", "name": "Maria Lopez", "list": "UN", "aliases": ["M. Lopez"]}
+        ]"#,
+    )
+    .unwrap();
+
+    assert_eq!(watchlist.reload().await.unwrap(), 2);
+    assert_eq!(
+        watchlist.search("Maria Lopez", 85)[0].entry.entry_id,
+        "UN-2"
+    );
+
+    // A broken file keeps the list loaded last
+    std::fs::write(&path, "not json").unwrap();
+
+    assert!(watchlist.reload().await.is_err());
+    assert_eq!(watchlist.entry_count(), 2);
+
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[tokio::test]
+async fn test_missing_watchlist_file_screens_against_empty_list() {
+    let path = std::env::temp_dir().join(format!("watchlist-{}.csv", Uuid::new_v4().simple()));
+
+    let watchlist = FileWatchlist::new(&path);
+
+    assert_eq!(watchlist.entry_count(), 0);
+    assert!(watchlist.search("John Doe", 85).is_empty());
+    assert!(watchlist.reload().await.is_err());
+}
@@ -0,0 +1,85 @@
+use std::{sync::Arc, time::Duration};
+
+use axum::{body::Bytes, http::HeaderMap, routing::post, Router};
+use example_payment_gateway_axum::{
+    abstract_trait::webhook_sender::{WebhookRequest, WebhookSenderTrait},
+    providers::webhook_sender::HttpWebhookSender,
+    utils::signature::{sign_hmac_sha256, verify_hmac_sha256},
+};
+use tokio::{net::TcpListener, sync::Mutex};
+
+type Received = Arc<Mutex<Vec<(HeaderMap, Bytes)>>>;
+
+/// Starts a receiver on a free local port that records every request.
+async fn start_receiver() -> (String, Received) {
+    let received: Received = Arc::default();
+    let store = received.clone();
+
+    let app = Router::new().route(
+        "/webhooks",
+        post(move |headers: HeaderMap, body: Bytes| {
+            let store = store.clone();
+            async move {
+                store.lock().await.push((headers, body));
+                "ok"
+            }
+        }),
+    );
+
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("http://{}/webhooks", listener.local_addr().unwrap());
+    tokio::spawn(async move { axum::serve(listener, app).await });
+
+    (url, received)
+}
+
+#[tokio::test]
+async fn test_http_sender_delivers_signed_webhook_to_local_receiver() {
+    let (url, received) = start_receiver().await;
+    let body = r#"{"id":4,"type":"payment_intent.succeeded","data":{}}"#.to_string();
+
+    let status = HttpWebhookSender::new(Duration::from_secs(5))
+        .send(&WebhookRequest {
+            url,
+            delivery_id: 7,
+            event_type: "payment_intent.succeeded".to_string(),
+            signature: sign_hmac_sha256("endpoint-secret", body.as_bytes()),
+            body: body.clone(),
+        })
+        .await
+        .unwrap();
+
+    assert_eq!(status, 200);
+
+    let received = received.lock().await;
+    let (headers, received_body) = &received[0];
+    let signature = headers["X-Webhook-Signature"].to_str().unwrap();
+
+    assert_eq!(received_body.as_ref(), body.as_bytes());
+    assert_eq!(headers["X-Webhook-Delivery"], "7");
+    assert!(verify_hmac_sha256(
+        "endpoint-secret",
+        received_body,
+        signature
+    ));
+}
+
+#[tokio::test]
+async fn test_http_sender_reports_unreachable_endpoint() {
+    // Bind and drop a listener so the port is known to be closed.
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("http://{}/webhooks", listener.local_addr().unwrap());
+    drop(listener);
+
+    let result = HttpWebhookSender::new(Duration::from_secs(5))
+        .send(&WebhookRequest {
+            url,
+            delivery_id: 7,
+            event_type: "payout.failed".to_string(),
+            signature: String::new(),
+            body: "{}".to_string(),
+        })
+        .await;
+
+    assert!(result.is_err());
+}
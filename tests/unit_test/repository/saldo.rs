@@ -1,11 +1,10 @@
-use chrono::{DateTime, Utc};
+use chrono::Utc;
 use example_payment_gateway_axum::{
     abstract_trait::saldo::{MockSaldoRepositoryTrait, SaldoRepositoryTrait},
     domain::request::saldo::{CreateSaldoRequest, UpdateSaldoRequest},
     entities::saldo,
 };
 use mockall::predicate::*;
-use tokio;
 
 #[tokio::test]
 async fn test_find_all() {
@@ -16,8 +15,8 @@ async fn test_find_all() {
         total_balance: 1000,
         withdraw_amount: None,
         withdraw_time: None,
-        created_at: Some(DateTime::<Utc>::from(Utc::now()).naive_utc()),
-        updated_at: Some(DateTime::<Utc>::from(Utc::now()).naive_utc()),
+        created_at: Some(Utc::now().naive_utc()),
+        updated_at: Some(Utc::now().naive_utc()),
     };
 
     mock_repo
@@ -42,8 +41,8 @@ async fn test_find_by_id() {
         total_balance: 1000,
         withdraw_amount: None,
         withdraw_time: None,
-        created_at: Some(DateTime::<Utc>::from(Utc::now()).naive_utc()),
-        updated_at: Some(DateTime::<Utc>::from(Utc::now()).naive_utc()),
+        created_at: Some(Utc::now().naive_utc()),
+        updated_at: Some(Utc::now().naive_utc()),
     };
 
     mock_repo
@@ -72,8 +71,8 @@ async fn test_create_saldo() {
         total_balance: mock_request.total_balance,
         withdraw_amount: None,
         withdraw_time: None,
-        created_at: Some(DateTime::<Utc>::from(Utc::now()).naive_utc()),
-        updated_at: Some(DateTime::<Utc>::from(Utc::now()).naive_utc()),
+        created_at: Some(Utc::now().naive_utc()),
+        updated_at: Some(Utc::now().naive_utc()),
     };
 
     mock_repo
@@ -107,8 +106,8 @@ async fn test_update_saldo() {
         total_balance: mock_request.total_balance,
         withdraw_amount: mock_request.withdraw_amount,
         withdraw_time: mock_request.withdraw_time,
-        created_at: Some(DateTime::<Utc>::from(Utc::now()).naive_utc()),
-        updated_at: Some(DateTime::<Utc>::from(Utc::now()).naive_utc()),
+        created_at: Some(Utc::now().naive_utc()),
+        updated_at: Some(Utc::now().naive_utc()),
     };
 
     mock_repo
@@ -134,7 +133,7 @@ async fn test_update_balance() {
         user_id: 1,
         total_balance: 3000,
         withdraw_amount: Some(500),
-        withdraw_time: Some(DateTime::<Utc>::from(Utc::now()).naive_utc()),
+        withdraw_time: Some(Utc::now().naive_utc()),
     };
 
     let mock_saldo = saldo::Model {
@@ -143,8 +142,8 @@ async fn test_update_balance() {
         total_balance: mock_request.total_balance,
         withdraw_amount: mock_request.withdraw_amount,
         withdraw_time: mock_request.withdraw_time,
-        created_at: Some(DateTime::<Utc>::from(Utc::now()).naive_utc()),
-        updated_at: Some(DateTime::<Utc>::from(Utc::now()).naive_utc()),
+        created_at: Some(Utc::now().naive_utc()),
+        updated_at: Some(Utc::now().naive_utc()),
     };
 
     mock_repo
@@ -1,11 +1,13 @@
 use chrono::{DateTime, Utc};
 use example_payment_gateway_axum::{
-    abstract_trait::topup::{MockTopupRepositoryTrait, TopupRepositoryTrait},
+    abstract_trait::{
+        fee::FeeQuote,
+        topup::{MockTopupRepositoryTrait, TopupRepositoryTrait},
+    },
     domain::request::topup::{CreateTopupRequest, UpdateTopupRequest},
     entities::topups,
 };
 use mockall::predicate::*;
-use tokio;
 
 #[tokio::test]
 async fn test_find_all_topups() {
@@ -20,6 +22,12 @@ async fn test_find_all_topups() {
         topup_time: DateTime::from_timestamp(1_634_944_800, 0)
             .unwrap()
             .naive_utc(),
+        status: "paid".to_string(),
+        payment_reference: None,
+        payment_instructions: None,
+        paid_at: None,
+        fee_amount: 0,
+        fee_rule_id: None,
         created_at: None,
         updated_at: None,
     };
@@ -47,7 +55,13 @@ async fn test_find_topup_by_id() {
         topup_no: "TOP12345".to_string(),
         topup_amount: 1000,
         topup_method: "Bank Transfer".to_string(),
-        topup_time: DateTime::<Utc>::from(Utc::now()).naive_utc(),
+        topup_time: Utc::now().naive_utc(),
+        status: "paid".to_string(),
+        payment_reference: None,
+        payment_instructions: None,
+        paid_at: None,
+        fee_amount: 0,
+        fee_rule_id: None,
         created_at: None,
         updated_at: None,
     };
@@ -84,17 +98,25 @@ async fn test_create_topup() {
         topup_no: mock_request.topup_no.clone(),
         topup_amount: mock_request.topup_amount,
         topup_method: mock_request.topup_method.clone(),
-        topup_time: DateTime::<Utc>::from(Utc::now()).naive_utc(),
+        topup_time: Utc::now().naive_utc(),
+        status: "paid".to_string(),
+        payment_reference: None,
+        payment_instructions: None,
+        paid_at: None,
+        fee_amount: 0,
+        fee_rule_id: None,
         created_at: None,
         updated_at: None,
     };
 
     mock_repo
         .expect_create()
-        .with(always())
-        .return_once(move |_| Ok(mock_topup.clone()));
+        .with(always(), always(), always())
+        .return_once(move |_, _, _| Ok(mock_topup.clone()));
 
-    let result = mock_repo.create(&mock_request).await;
+    let result = mock_repo
+        .create(&mock_request, "TOP-1", FeeQuote::default())
+        .await;
 
     assert!(result.is_ok());
     let topup = result.unwrap();
@@ -119,7 +141,13 @@ async fn test_update_topup() {
         topup_no: "TOP12345".to_string(),
         topup_amount: mock_request.topup_amount,
         topup_method: mock_request.topup_method.clone(),
-        topup_time: DateTime::<Utc>::from(Utc::now()).naive_utc(),
+        topup_time: Utc::now().naive_utc(),
+        status: "paid".to_string(),
+        payment_reference: None,
+        payment_instructions: None,
+        paid_at: None,
+        fee_amount: 0,
+        fee_rule_id: None,
         created_at: None,
         updated_at: None,
     };
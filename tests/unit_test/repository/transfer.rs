@@ -1,4 +1,4 @@
-use chrono::{DateTime, Utc};
+use chrono::Utc;
 use example_payment_gateway_axum::{
     abstract_trait::transfer::{MockTransferRepositoryTrait, TransferRepositoryTrait},
     domain::request::transfer::{
@@ -7,7 +7,6 @@ use example_payment_gateway_axum::{
     entities::transfers,
 };
 use mockall::predicate::*;
-use tokio;
 
 #[tokio::test]
 async fn test_find_all_transfers() {
@@ -18,9 +17,10 @@ async fn test_find_all_transfers() {
         transfer_from: 1001,
         transfer_to: 1002,
         transfer_amount: 5000,
-        transfer_time: DateTime::<Utc>::from(Utc::now()).naive_utc(),
-        created_at: Some(DateTime::<Utc>::from(Utc::now()).naive_utc()),
-        updated_at: Some(DateTime::<Utc>::from(Utc::now()).naive_utc()),
+        transfer_time: Utc::now().naive_utc(),
+        fee_amount: 0,
+        created_at: Some(Utc::now().naive_utc()),
+        updated_at: Some(Utc::now().naive_utc()),
     };
 
     mock_repo
@@ -44,9 +44,10 @@ async fn test_find_transfer_by_id() {
         transfer_from: 1001,
         transfer_to: 1002,
         transfer_amount: 5000,
-        transfer_time: DateTime::<Utc>::from(Utc::now()).naive_utc(),
-        created_at: Some(DateTime::<Utc>::from(Utc::now()).naive_utc()),
-        updated_at: Some(DateTime::<Utc>::from(Utc::now()).naive_utc()),
+        transfer_time: Utc::now().naive_utc(),
+        fee_amount: 0,
+        created_at: Some(Utc::now().naive_utc()),
+        updated_at: Some(Utc::now().naive_utc()),
     };
 
     mock_repo
@@ -70,6 +71,9 @@ async fn test_create_transfer() {
         transfer_from: 1001,
         transfer_to: 1002,
         transfer_amount: 5000,
+        beneficiary_id: None,
+        device_id: None,
+        merchant_payment: false,
     };
 
     let mock_transfer = transfers::Model {
@@ -77,21 +81,22 @@ async fn test_create_transfer() {
         transfer_from: 1001,
         transfer_to: 1002,
         transfer_amount: 5000,
-        transfer_time: DateTime::<Utc>::from(Utc::now()).naive_utc(),
-        created_at: Some(DateTime::<Utc>::from(Utc::now()).naive_utc()),
-        updated_at: Some(DateTime::<Utc>::from(Utc::now()).naive_utc()),
+        transfer_time: Utc::now().naive_utc(),
+        fee_amount: 0,
+        created_at: Some(Utc::now().naive_utc()),
+        updated_at: Some(Utc::now().naive_utc()),
     };
 
     mock_repo
         .expect_create()
-        .withf(move |input| {
+        .withf(move |input, _| {
             input.transfer_from == mock_request.transfer_from
                 && input.transfer_to == mock_request.transfer_to
                 && input.transfer_amount == mock_request.transfer_amount
         })
-        .returning(move |_| Ok(mock_transfer.clone()));
+        .returning(move |_, _| Ok(mock_transfer.clone()));
 
-    let result = mock_repo.create(&mock_request).await;
+    let result = mock_repo.create(&mock_request, 0).await;
 
     assert!(result.is_ok());
     let transfer = result.unwrap();
@@ -115,9 +120,10 @@ async fn test_update_transfer() {
         transfer_from: 1001,
         transfer_to: 1002,
         transfer_amount: 7000,
-        transfer_time: DateTime::<Utc>::from(Utc::now()).naive_utc(),
-        created_at: Some(DateTime::<Utc>::from(Utc::now()).naive_utc()),
-        updated_at: Some(DateTime::<Utc>::from(Utc::now()).naive_utc()),
+        transfer_time: Utc::now().naive_utc(),
+        fee_amount: 0,
+        created_at: Some(Utc::now().naive_utc()),
+        updated_at: Some(Utc::now().naive_utc()),
     };
 
     mock_repo
@@ -154,9 +160,10 @@ async fn test_update_transfer_amount() {
         transfer_from: 1001,
         transfer_to: 1002,
         transfer_amount: 7000,
-        transfer_time: DateTime::<Utc>::from(Utc::now()).naive_utc(),
-        created_at: Some(DateTime::<Utc>::from(Utc::now()).naive_utc()),
-        updated_at: Some(DateTime::<Utc>::from(Utc::now()).naive_utc()),
+        transfer_time: Utc::now().naive_utc(),
+        fee_amount: 0,
+        created_at: Some(Utc::now().naive_utc()),
+        updated_at: Some(Utc::now().naive_utc()),
     };
 
     mock_repo
@@ -1,11 +1,10 @@
-use chrono::{DateTime, Utc};
+use chrono::Utc;
 use example_payment_gateway_axum::{
     abstract_trait::user::{MockUserRepositoryTrait, UserRepositoryTrait},
     domain::request::user::{CreateUserRequest, UpdateUserRequest},
     entities::users,
 };
 use mockall::predicate::*;
-use tokio;
 
 #[tokio::test]
 async fn test_find_all_users() {
@@ -19,8 +18,10 @@ async fn test_find_all_users() {
             email: "johndoe@example.com".to_string(),
             password: "hashed_password".to_string(),
             noc_transfer: "12345".to_string(),
-            created_at: Some(DateTime::<Utc>::from(Utc::now()).naive_utc()),
-            updated_at: Some(DateTime::<Utc>::from(Utc::now()).naive_utc()),
+            kyc_level: 0,
+            role: "user".to_string(),
+            created_at: Some(Utc::now().naive_utc()),
+            updated_at: Some(Utc::now().naive_utc()),
         },
         users::Model {
             user_id: 1,
@@ -29,8 +30,10 @@ async fn test_find_all_users() {
             email: "janesmith@example.com".to_string(),
             password: "hashed_password".to_string(),
             noc_transfer: "12345".to_string(),
-            created_at: Some(DateTime::<Utc>::from(Utc::now()).naive_utc()),
-            updated_at: Some(DateTime::<Utc>::from(Utc::now()).naive_utc()),
+            kyc_level: 0,
+            role: "user".to_string(),
+            created_at: Some(Utc::now().naive_utc()),
+            updated_at: Some(Utc::now().naive_utc()),
         },
     ];
 
@@ -61,7 +64,7 @@ async fn test_find_by_email_exists() {
     let result = mock_repo.find_by_email_exists(email).await;
 
     assert!(result.is_ok());
-    assert_eq!(result.unwrap(), true);
+    assert!(result.unwrap());
 }
 
 #[tokio::test]
@@ -84,8 +87,10 @@ async fn test_create_user() {
         email: create_request.email.clone(),
         password: create_request.password.clone(),
         noc_transfer: "12345".to_string(),
-        created_at: Some(DateTime::<Utc>::from(Utc::now()).naive_utc()),
-        updated_at: Some(DateTime::<Utc>::from(Utc::now()).naive_utc()),
+        kyc_level: 0,
+        role: "user".to_string(),
+        created_at: Some(Utc::now().naive_utc()),
+        updated_at: Some(Utc::now().naive_utc()),
     };
 
     mock_repo
@@ -113,8 +118,10 @@ async fn test_find_by_email() {
         email: email.to_string(),
         password: "hashed_password".to_string(),
         noc_transfer: "12345".to_string(),
-        created_at: Some(DateTime::<Utc>::from(Utc::now()).naive_utc()),
-        updated_at: Some(DateTime::<Utc>::from(Utc::now()).naive_utc()),
+        kyc_level: 0,
+        role: "user".to_string(),
+        created_at: Some(Utc::now().naive_utc()),
+        updated_at: Some(Utc::now().naive_utc()),
     });
 
     mock_repo
@@ -150,8 +157,10 @@ async fn test_update_user() {
         email: update_request.email.clone().unwrap(),
         password: "hashed_password".to_string(),
         noc_transfer: "12345".to_string(),
-        created_at: Some(DateTime::<Utc>::from(Utc::now()).naive_utc()),
-        updated_at: Some(DateTime::<Utc>::from(Utc::now()).naive_utc()),
+        kyc_level: 0,
+        role: "user".to_string(),
+        created_at: Some(Utc::now().naive_utc()),
+        updated_at: Some(Utc::now().naive_utc()),
     };
 
     mock_repo
@@ -1,11 +1,13 @@
-use chrono::{DateTime, Utc};
+use chrono::Utc;
 use example_payment_gateway_axum::{
-    abstract_trait::withdraw::{MockWithdrawRepositoryTrait, WithdrawRepositoryTrait},
+    abstract_trait::{
+        fee::FeeQuote,
+        withdraw::{MockWithdrawRepositoryTrait, WithdrawRepositoryTrait},
+    },
     domain::request::withdraw::{CreateWithdrawRequest, UpdateWithdrawRequest},
     entities::withdraws,
 };
 use mockall::predicate::*;
-use tokio;
 
 #[tokio::test]
 async fn test_find_all_withdraws() {
@@ -15,9 +17,16 @@ async fn test_find_all_withdraws() {
         withdraw_id: 1,
         user_id: 1001,
         withdraw_amount: 2000,
-        withdraw_time: DateTime::<Utc>::from(Utc::now()).naive_utc(),
-        created_at: Some(DateTime::<Utc>::from(Utc::now()).naive_utc()),
-        updated_at: Some(DateTime::<Utc>::from(Utc::now()).naive_utc()),
+        withdraw_time: Utc::now().naive_utc(),
+        bank_account_id: None,
+        status: "succeeded".to_string(),
+        provider_reference: None,
+        payout_reference: None,
+        failure_reason: None,
+        fee_amount: 0,
+        fee_rule_id: None,
+        created_at: Some(Utc::now().naive_utc()),
+        updated_at: Some(Utc::now().naive_utc()),
     };
 
     mock_repo
@@ -40,9 +49,16 @@ async fn test_find_withdraw_by_id() {
         withdraw_id: 1,
         user_id: 1001,
         withdraw_amount: 2000,
-        withdraw_time: DateTime::<Utc>::from(Utc::now()).naive_utc(),
-        created_at: Some(DateTime::<Utc>::from(Utc::now()).naive_utc()),
-        updated_at: Some(DateTime::<Utc>::from(Utc::now()).naive_utc()),
+        withdraw_time: Utc::now().naive_utc(),
+        bank_account_id: None,
+        status: "succeeded".to_string(),
+        provider_reference: None,
+        payout_reference: None,
+        failure_reason: None,
+        fee_amount: 0,
+        fee_rule_id: None,
+        created_at: Some(Utc::now().naive_utc()),
+        updated_at: Some(Utc::now().naive_utc()),
     };
 
     mock_repo
@@ -64,8 +80,10 @@ async fn test_create_withdraw() {
 
     let mock_request = CreateWithdrawRequest {
         user_id: 1001,
+        bank_account_id: 1,
         withdraw_amount: 2000,
-        withdraw_time: DateTime::<Utc>::from(Utc::now()),
+        withdraw_time: Utc::now(),
+        device_id: None,
     };
 
     let mock_withdraw = withdraws::Model {
@@ -73,20 +91,27 @@ async fn test_create_withdraw() {
         user_id: mock_request.user_id,
         withdraw_amount: mock_request.withdraw_amount,
         withdraw_time: mock_request.withdraw_time.naive_utc(),
-        created_at: Some(DateTime::<Utc>::from(Utc::now()).naive_utc()),
-        updated_at: Some(DateTime::<Utc>::from(Utc::now()).naive_utc()),
+        bank_account_id: None,
+        status: "succeeded".to_string(),
+        provider_reference: None,
+        payout_reference: None,
+        failure_reason: None,
+        fee_amount: 0,
+        fee_rule_id: None,
+        created_at: Some(Utc::now().naive_utc()),
+        updated_at: Some(Utc::now().naive_utc()),
     };
 
     mock_repo
         .expect_create()
-        .withf(move |input| {
+        .withf(move |input, _| {
             input.user_id == mock_request.user_id
                 && input.withdraw_amount == mock_request.withdraw_amount
                 && input.withdraw_time == mock_request.withdraw_time
         })
-        .returning(move |_| Ok(mock_withdraw.clone()));
+        .returning(move |_, _| Ok(mock_withdraw.clone()));
 
-    let result = mock_repo.create(&mock_request).await;
+    let result = mock_repo.create(&mock_request, FeeQuote::default()).await;
 
     assert!(result.is_ok());
     let withdraw = result.unwrap();
@@ -102,7 +127,7 @@ async fn test_update_withdraw() {
         user_id: 1001,
         withdraw_id: 1,
         withdraw_amount: 3000,
-        withdraw_time: DateTime::<Utc>::from(Utc::now()),
+        withdraw_time: Utc::now(),
     };
 
     let mock_withdraw = withdraws::Model {
@@ -110,8 +135,15 @@ async fn test_update_withdraw() {
         user_id: mock_request.user_id,
         withdraw_amount: mock_request.withdraw_amount,
         withdraw_time: mock_request.withdraw_time.naive_utc(),
-        created_at: Some(DateTime::<Utc>::from(Utc::now()).naive_utc()),
-        updated_at: Some(DateTime::<Utc>::from(Utc::now()).naive_utc()),
+        bank_account_id: None,
+        status: "succeeded".to_string(),
+        provider_reference: None,
+        payout_reference: None,
+        failure_reason: None,
+        fee_amount: 0,
+        fee_rule_id: None,
+        created_at: Some(Utc::now().naive_utc()),
+        updated_at: Some(Utc::now().naive_utc()),
     };
 
     mock_repo
@@ -0,0 +1,253 @@
+use std::sync::Arc;
+
+use chrono::NaiveDate;
+use example_payment_gateway_axum::{
+    abstract_trait::{
+        audit::{AuditServiceTrait, MockAuditRepositoryTrait},
+        clock::MockClockTrait,
+    },
+    domain::request::audit::{redact_snapshot, CreateAuditEvent, FindAuditEventsQuery},
+    entities::audit_events,
+    services::audit::{audit_event_hash, AuditService, GENESIS_HASH},
+};
+use mockall::predicate;
+use sea_orm::DbErr;
+use serde_json::json;
+
+fn clock() -> MockClockTrait {
+    let mut clock = MockClockTrait::new();
+    clock.expect_now().returning(|| {
+        NaiveDate::from_ymd_opt(2026, 10, 19)
+            .unwrap()
+            .and_hms_nano_opt(9, 30, 0, 123_456_789)
+            .unwrap()
+    });
+    clock
+}
+
+fn delete_user() -> CreateAuditEvent {
+    CreateAuditEvent {
+        actor_id: Some(99),
+        action: "DELETE /api/users/{id}".to_string(),
+        resource_type: "users".to_string(),
+        resource_id: Some("5".to_string()),
+        before: Some(json!({ "user_id": 5, "email": "siti@test.com" })),
+        after: None,
+        client_ip: Some("10.0.0.1".to_string()),
+        request_id: "req-1".to_string(),
+    }
+}
+
+/// A chain of `length` valid events.
+fn chain(length: i32) -> Vec<audit_events::Model> {
+    let mut prev_hash = GENESIS_HASH.to_string();
+
+    (1..=length)
+        .map(|id| {
+            let mut event = audit_events::Model {
+                audit_event_id: id,
+                actor_id: Some(99),
+                action: "PUT /api/saldos/{id}".to_string(),
+                resource_type: "saldos".to_string(),
+                resource_id: Some(id.to_string()),
+                before: Some(json!({ "total_balance": id * 100 }).to_string()),
+                after: Some(json!({ "total_balance": id * 200 }).to_string()),
+                client_ip: None,
+                request_id: format!("req-{}", id),
+                created_at: NaiveDate::from_ymd_opt(2026, 10, 19)
+                    .unwrap()
+                    .and_hms_opt(9, 0, id as u32)
+                    .unwrap(),
+                prev_hash: prev_hash.clone(),
+                hash: String::new(),
+            };
+            event.hash = audit_event_hash(&event);
+            prev_hash = event.hash.clone();
+            event
+        })
+        .collect()
+}
+
+fn stored(
+    input: &CreateAuditEvent,
+    created_at: chrono::NaiveDateTime,
+    prev_hash: &str,
+    hash: &str,
+) -> audit_events::Model {
+    audit_events::Model {
+        audit_event_id: 4,
+        actor_id: input.actor_id,
+        action: input.action.clone(),
+        resource_type: input.resource_type.clone(),
+        resource_id: input.resource_id.clone(),
+        before: input.before.as_ref().map(|value| value.to_string()),
+        after: input.after.as_ref().map(|value| value.to_string()),
+        client_ip: input.client_ip.clone(),
+        request_id: input.request_id.clone(),
+        created_at,
+        prev_hash: prev_hash.to_string(),
+        hash: hash.to_string(),
+    }
+}
+
+#[tokio::test]
+async fn test_record_links_to_the_last_event() {
+    let mut mock_repo = MockAuditRepositoryTrait::new();
+    let last = chain(3).pop().unwrap();
+    let last_hash = last.hash.clone();
+
+    mock_repo
+        .expect_find_last()
+        .return_once(move || Ok(Some(last)));
+
+    mock_repo
+        .expect_create()
+        .withf(move |_, created_at, prev_hash, _| {
+            prev_hash == last_hash && created_at.and_utc().timestamp_subsec_nanos() == 123_456_000
+        })
+        .return_once(|input, created_at, prev_hash, hash| {
+            Ok(stored(input, created_at, prev_hash, hash))
+        });
+
+    let service = AuditService::new(Arc::new(mock_repo), Arc::new(clock()));
+
+    let event = service.record(&delete_user()).await.unwrap();
+
+    // The stored row hashes to what was written, so it verifies later
+    assert_eq!(audit_event_hash(&event), event.hash);
+}
+
+#[tokio::test]
+async fn test_first_event_links_to_genesis() {
+    let mut mock_repo = MockAuditRepositoryTrait::new();
+
+    mock_repo.expect_find_last().return_once(|| Ok(None));
+
+    mock_repo
+        .expect_create()
+        .withf(|_, _, prev_hash, _| prev_hash == GENESIS_HASH)
+        .return_once(|input, created_at, prev_hash, hash| {
+            Ok(stored(input, created_at, prev_hash, hash))
+        });
+
+    let service = AuditService::new(Arc::new(mock_repo), Arc::new(clock()));
+
+    let event = service.record(&delete_user()).await.unwrap();
+
+    assert_eq!(event.prev_hash, GENESIS_HASH);
+}
+
+#[tokio::test]
+async fn test_record_reports_storage_failure() {
+    let mut mock_repo = MockAuditRepositoryTrait::new();
+
+    mock_repo.expect_find_last().return_once(|| Ok(None));
+    mock_repo
+        .expect_create()
+        .times(1)
+        .return_once(|_, _, _, _| Err(DbErr::Custom("disk full".to_string())));
+
+    let service = AuditService::new(Arc::new(mock_repo), Arc::new(clock()));
+
+    let error = service.record(&delete_user()).await.unwrap_err();
+
+    assert_eq!(error.message, "Database error occurred");
+}
+
+#[tokio::test]
+async fn test_verify_intact_chain() {
+    let mut mock_repo = MockAuditRepositoryTrait::new();
+
+    mock_repo
+        .expect_find_after()
+        .with(predicate::eq(0), predicate::always())
+        .return_once(|_, _| Ok(chain(3)));
+
+    mock_repo
+        .expect_find_after()
+        .with(predicate::eq(3), predicate::always())
+        .return_once(|_, _| Ok(vec![]));
+
+    let service = AuditService::new(Arc::new(mock_repo), Arc::new(clock()));
+
+    let response = service.verify_chain().await.unwrap();
+
+    assert!(response.data.valid);
+    assert_eq!(response.data.events_checked, 3);
+}
+
+#[tokio::test]
+async fn test_verify_detects_edited_event() {
+    let mut mock_repo = MockAuditRepositoryTrait::new();
+    let mut events = chain(3);
+    events[1].after = Some(json!({ "total_balance": 1_000_000 }).to_string());
+
+    mock_repo
+        .expect_find_after()
+        .return_once(move |_, _| Ok(events));
+
+    let service = AuditService::new(Arc::new(mock_repo), Arc::new(clock()));
+
+    let response = service.verify_chain().await.unwrap();
+
+    assert!(!response.data.valid);
+    assert_eq!(response.data.events_checked, 1);
+    assert_eq!(response.data.first_invalid_event_id, Some(2));
+    assert_eq!(
+        response.data.reason.as_deref(),
+        Some("hash does not match its contents")
+    );
+}
+
+#[tokio::test]
+async fn test_verify_detects_removed_event() {
+    let mut mock_repo = MockAuditRepositoryTrait::new();
+    let mut events = chain(3);
+    events.remove(1);
+
+    mock_repo
+        .expect_find_after()
+        .return_once(move |_, _| Ok(events));
+
+    let service = AuditService::new(Arc::new(mock_repo), Arc::new(clock()));
+
+    let response = service.verify_chain().await.unwrap();
+
+    assert!(!response.data.valid);
+    assert_eq!(response.data.first_invalid_event_id, Some(3));
+    assert_eq!(
+        response.data.reason.as_deref(),
+        Some("does not link to the previous event")
+    );
+}
+
+#[tokio::test]
+async fn test_find_events_rejects_oversized_limit() {
+    let service = AuditService::new(Arc::new(MockAuditRepositoryTrait::new()), Arc::new(clock()));
+
+    let error = service
+        .find_events(&FindAuditEventsQuery {
+            limit: Some(5000),
+            ..Default::default()
+        })
+        .await
+        .unwrap_err();
+
+    assert_eq!(error.message, "Limit must be between 1 and 1000");
+}
+
+#[test]
+fn test_redact_snapshot_removes_secrets_at_any_depth() {
+    let mut snapshot = json!({
+        "user_id": 5,
+        "password": "hash",
+        "endpoints": [{ "url": "https://example.com", "secret": "whsec" }],
+    });
+
+    redact_snapshot(&mut snapshot);
+
+    assert_eq!(
+        snapshot,
+        json!({ "user_id": 5, "endpoints": [{ "url": "https://example.com" }] })
+    );
+}
@@ -0,0 +1,61 @@
+use std::sync::Arc;
+
+use chrono::Utc;
+use example_payment_gateway_axum::{
+    abstract_trait::bank_account::{BankAccountServiceTrait, MockBankAccountRepositoryTrait},
+    domain::request::bank_account::CreateBankAccountRequest,
+    entities::bank_accounts,
+    services::bank_account::BankAccountService,
+};
+
+fn bank_account_request(bank_code: &str) -> CreateBankAccountRequest {
+    CreateBankAccountRequest {
+        user_id: 1,
+        bank_code: bank_code.to_string(),
+        account_number: "1234567890".to_string(),
+        holder_name: "John Doe".to_string(),
+    }
+}
+
+#[test]
+fn test_bank_account_requires_supported_bank_code() {
+    assert!(bank_account_request("bca").validate().is_ok());
+    assert_eq!(
+        bank_account_request("ovo").validate().unwrap_err(),
+        "Bank code is not supported"
+    );
+    assert_eq!(
+        bank_account_request("chase").validate().unwrap_err(),
+        "Bank code is not supported"
+    );
+}
+
+#[tokio::test]
+async fn test_create_bank_account_rejects_duplicate() {
+    let mut mock_bank_account_repo = MockBankAccountRepositoryTrait::new();
+
+    mock_bank_account_repo
+        .expect_find_by_user()
+        .return_once(|user_id| {
+            Ok(vec![bank_accounts::Model {
+                bank_account_id: 1,
+                user_id,
+                bank_code: "bca".to_string(),
+                account_number: "1234567890".to_string(),
+                holder_name: "John Doe".to_string(),
+                created_at: Some(Utc::now().naive_utc()),
+                updated_at: Some(Utc::now().naive_utc()),
+            }])
+        });
+
+    mock_bank_account_repo.expect_create().never();
+
+    let service = BankAccountService::new(Arc::new(mock_bank_account_repo));
+
+    let error = service
+        .create_bank_account(&bank_account_request("bca"))
+        .await
+        .unwrap_err();
+
+    assert_eq!(error.message, "Bank account already registered");
+}
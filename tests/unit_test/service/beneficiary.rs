@@ -0,0 +1,138 @@
+use std::sync::Arc;
+
+use chrono::Utc;
+use example_payment_gateway_axum::{
+    abstract_trait::{
+        beneficiary::{BeneficiaryServiceTrait, MockBeneficiaryRepositoryTrait},
+        screening::MockScreeningServiceTrait,
+        user::MockUserRepositoryTrait,
+    },
+    domain::request::beneficiary::CreateBeneficiaryRequest,
+    entities::{beneficiaries, users},
+    services::beneficiary::BeneficiaryService,
+};
+use mockall::predicate;
+
+fn mock_beneficiary(request: &CreateBeneficiaryRequest, verified: bool) -> beneficiaries::Model {
+    beneficiaries::Model {
+        beneficiary_id: 1,
+        user_id: request.user_id,
+        alias: request.alias.clone(),
+        beneficiary_type: request.beneficiary_type.clone(),
+        noc_transfer: request.noc_transfer.clone(),
+        bank_code: request.bank_code.clone(),
+        bank_account_number: request.bank_account_number.clone(),
+        verified,
+        created_at: Some(Utc::now().naive_utc()),
+        updated_at: Some(Utc::now().naive_utc()),
+    }
+}
+
+fn account_request() -> CreateBeneficiaryRequest {
+    CreateBeneficiaryRequest {
+        user_id: 1,
+        alias: "Mom".to_string(),
+        beneficiary_type: "account".to_string(),
+        noc_transfer: Some("4111111111111111".to_string()),
+        bank_code: None,
+        bank_account_number: None,
+    }
+}
+
+#[test]
+fn test_bank_beneficiary_requires_bank_details() {
+    let request = CreateBeneficiaryRequest {
+        user_id: 1,
+        alias: "Landlord".to_string(),
+        beneficiary_type: "bank".to_string(),
+        noc_transfer: None,
+        bank_code: Some("BCA".to_string()),
+        bank_account_number: None,
+    };
+
+    assert_eq!(
+        request.validate().unwrap_err(),
+        "Bank account number is required for bank beneficiaries"
+    );
+}
+
+#[tokio::test]
+async fn test_create_account_beneficiary_is_verified() {
+    let mut mock_beneficiary_repo = MockBeneficiaryRepositoryTrait::new();
+    let mut mock_user_repo = MockUserRepositoryTrait::new();
+    let mut mock_screening_service = MockScreeningServiceTrait::new();
+
+    mock_beneficiary_repo
+        .expect_find_by_user()
+        .with(predicate::eq(1))
+        .return_once(|_| Ok(vec![]));
+
+    mock_user_repo
+        .expect_find_by_noc_transfer()
+        .with(predicate::eq("4111111111111111"))
+        .return_once(|noc_transfer| {
+            Ok(Some(users::Model {
+                user_id: 2,
+                firstname: "Siti".to_string(),
+                lastname: "Aminah".to_string(),
+                email: "siti@test.com".to_string(),
+                password: "hash".to_string(),
+                noc_transfer: noc_transfer.to_string(),
+                kyc_level: 0,
+                role: "user".to_string(),
+                created_at: Some(Utc::now().naive_utc()),
+                updated_at: Some(Utc::now().naive_utc()),
+            }))
+        });
+
+    mock_beneficiary_repo
+        .expect_create()
+        .withf(|_, verified| *verified)
+        .return_once(|input, verified| Ok(mock_beneficiary(input, verified)));
+
+    mock_screening_service
+        .expect_screen()
+        .withf(|input| {
+            input.subject_type == "beneficiary"
+                && input.subject_id == 1
+                && input.names == ["Mom", "Siti Aminah"]
+        })
+        .times(1)
+        .return_once(|_| Ok(vec![]));
+
+    let service = BeneficiaryService::new(
+        Arc::new(mock_beneficiary_repo),
+        Arc::new(mock_user_repo),
+        Arc::new(mock_screening_service),
+    );
+
+    let response = service
+        .create_beneficiary(&account_request())
+        .await
+        .unwrap();
+
+    assert_eq!(response.data.alias, "Mom");
+    assert!(response.data.verified);
+}
+
+#[tokio::test]
+async fn test_create_duplicate_beneficiary() {
+    let mut mock_beneficiary_repo = MockBeneficiaryRepositoryTrait::new();
+
+    mock_beneficiary_repo
+        .expect_find_by_user()
+        .return_once(|_| Ok(vec![mock_beneficiary(&account_request(), true)]));
+
+    let service = BeneficiaryService::new(
+        Arc::new(mock_beneficiary_repo),
+        Arc::new(MockUserRepositoryTrait::new()),
+        Arc::new(MockScreeningServiceTrait::new()),
+    );
+
+    let error = service
+        .create_beneficiary(&account_request())
+        .await
+        .unwrap_err();
+
+    assert_eq!(error.message, "Beneficiary already exists");
+}
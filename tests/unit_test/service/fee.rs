@@ -0,0 +1,255 @@
+use std::sync::Arc;
+
+use example_payment_gateway_axum::{
+    abstract_trait::fee::{FeeQuote, FeeServiceTrait, MockFeeRepositoryTrait},
+    domain::request::fee::{FeeQuoteRequest, RecordFeeRevenue},
+    entities::{fee_revenues, fee_rules},
+    services::fee::FeeService,
+};
+use mockall::predicate;
+
+fn rule(fee_rule_id: i32, fee_type: &str) -> fee_rules::Model {
+    fee_rules::Model {
+        fee_rule_id,
+        transaction_type: "withdraw".to_string(),
+        method: None,
+        merchant_id: None,
+        fee_type: fee_type.to_string(),
+        flat_amount: 0,
+        percentage_bps: 0,
+        tiers: None,
+        min_fee: None,
+        max_fee: None,
+        is_active: true,
+        created_at: None,
+        updated_at: None,
+    }
+}
+
+fn quote_request(transaction_type: &str, amount: i32) -> FeeQuoteRequest {
+    FeeQuoteRequest {
+        transaction_type: transaction_type.to_string(),
+        method: None,
+        merchant_id: None,
+        amount,
+    }
+}
+
+const REVENUE_USER_ID: i32 = 99;
+
+fn service_with_rules(rules: Vec<fee_rules::Model>) -> FeeService {
+    let mut fee_repo = MockFeeRepositoryTrait::new();
+    fee_repo
+        .expect_find_active_rules_by_type()
+        .returning(move |_| Ok(rules.clone()));
+
+    FeeService::new(Arc::new(fee_repo), Some(REVENUE_USER_ID))
+}
+
+#[tokio::test]
+async fn test_flat_fee() {
+    let mut flat = rule(1, "flat");
+    flat.flat_amount = 2500;
+
+    let service = service_with_rules(vec![flat]);
+
+    let quote = service
+        .calculate_fee(&quote_request("withdraw", 100000))
+        .await
+        .unwrap();
+
+    assert_eq!(
+        quote,
+        FeeQuote {
+            fee_rule_id: Some(1),
+            fee_amount: 2500,
+        }
+    );
+}
+
+#[tokio::test]
+async fn test_percentage_fee_rounds_and_respects_caps() {
+    let mut percentage = rule(1, "percentage");
+    percentage.transaction_type = "payment".to_string();
+    percentage.percentage_bps = 70;
+    percentage.min_fee = Some(500);
+    percentage.max_fee = Some(10000);
+
+    let service = service_with_rules(vec![percentage]);
+
+    let fee = |amount| {
+        let request = quote_request("payment", amount);
+        let service = &service;
+        async move { service.calculate_fee(&request).await.unwrap().fee_amount }
+    };
+
+    assert_eq!(fee(150_050).await, 1050);
+    assert_eq!(fee(20_000).await, 500);
+    assert_eq!(fee(5_000_000).await, 10000);
+}
+
+#[tokio::test]
+async fn test_tiered_fee_uses_first_matching_tier() {
+    let mut tiered = rule(1, "tiered");
+    tiered.tiers = Some(
+        r#"[
+            {"up_to": 100000, "flat_amount": 1000},
+            {"up_to": 1000000, "flat_amount": 1500, "percentage_bps": 10},
+            {"up_to": null, "percentage_bps": 50}
+        ]"#
+        .to_string(),
+    );
+
+    let service = service_with_rules(vec![tiered]);
+
+    for (amount, expected) in [(100000, 1000), (500000, 2000), (2000000, 10000)] {
+        let quote = service
+            .calculate_fee(&quote_request("withdraw", amount))
+            .await
+            .unwrap();
+
+        assert_eq!(quote.fee_amount, expected, "amount {}", amount);
+    }
+}
+
+#[tokio::test]
+async fn test_most_specific_rule_wins() {
+    let mut default_rule = rule(1, "percentage");
+    default_rule.transaction_type = "payment".to_string();
+    default_rule.percentage_bps = 100;
+
+    let mut qris_rule = rule(2, "percentage");
+    qris_rule.transaction_type = "payment".to_string();
+    qris_rule.method = Some("qris".to_string());
+    qris_rule.percentage_bps = 70;
+
+    let mut merchant_rule = rule(3, "percentage");
+    merchant_rule.transaction_type = "payment".to_string();
+    merchant_rule.merchant_id = Some(9);
+    merchant_rule.percentage_bps = 50;
+
+    let service = service_with_rules(vec![default_rule, qris_rule, merchant_rule]);
+
+    let mut request = quote_request("payment", 100000);
+    request.method = Some("QRIS".to_string());
+
+    let quote = service.calculate_fee(&request).await.unwrap();
+    assert_eq!(quote.fee_rule_id, Some(2));
+    assert_eq!(quote.fee_amount, 700);
+
+    request.merchant_id = Some(9);
+
+    let quote = service.calculate_fee(&request).await.unwrap();
+    assert_eq!(quote.fee_rule_id, Some(3));
+    assert_eq!(quote.fee_amount, 500);
+
+    request.method = None;
+    request.merchant_id = Some(4);
+
+    let quote = service.calculate_fee(&request).await.unwrap();
+    assert_eq!(quote.fee_rule_id, Some(1));
+    assert_eq!(quote.fee_amount, 1000);
+}
+
+#[tokio::test]
+async fn test_no_matching_rule_is_free() {
+    let mut qris_rule = rule(1, "flat");
+    qris_rule.method = Some("qris".to_string());
+    qris_rule.flat_amount = 1000;
+
+    let service = service_with_rules(vec![qris_rule]);
+
+    let quote = service
+        .calculate_fee(&quote_request("withdraw", 100000))
+        .await
+        .unwrap();
+
+    assert_eq!(quote, FeeQuote::default());
+}
+
+#[tokio::test]
+async fn test_invalid_rule_is_reported() {
+    let service = service_with_rules(vec![rule(1, "tiered")]);
+
+    let result = service
+        .calculate_fee(&quote_request("withdraw", 100000))
+        .await;
+
+    assert!(result.unwrap_err().message.contains("no tier"));
+}
+
+#[tokio::test]
+async fn test_unknown_transaction_type_is_rejected() {
+    let service = FeeService::new(Arc::new(MockFeeRepositoryTrait::new()), None);
+
+    let result = service.calculate_fee(&quote_request("refund", 1000)).await;
+
+    assert!(result.is_err());
+}
+
+#[tokio::test]
+async fn test_fee_is_refused_without_revenue_account() {
+    let mut flat = rule(1, "flat");
+    flat.flat_amount = 2500;
+
+    let mut fee_repo = MockFeeRepositoryTrait::new();
+    fee_repo
+        .expect_find_active_rules_by_type()
+        .returning(move |_| Ok(vec![flat.clone()]));
+    fee_repo.expect_record_revenue().never();
+
+    let service = FeeService::new(Arc::new(fee_repo), None);
+
+    let result = service
+        .calculate_fee(&quote_request("withdraw", 100000))
+        .await;
+
+    assert!(result.unwrap_err().message.contains("revenue account"));
+
+    let result = service
+        .post_fee(&RecordFeeRevenue {
+            transaction_type: "withdraw".to_string(),
+            transaction_id: 5,
+            fee_rule_id: Some(1),
+            amount: 2500,
+        })
+        .await;
+
+    assert!(result.is_err());
+}
+
+#[tokio::test]
+async fn test_post_fee_credits_revenue_account_and_skips_zero() {
+    let mut fee_repo = MockFeeRepositoryTrait::new();
+    fee_repo
+        .expect_record_revenue()
+        .with(
+            predicate::function(|input: &RecordFeeRevenue| {
+                input.transaction_type == "transfer" && input.amount == 2500
+            }),
+            predicate::eq(REVENUE_USER_ID),
+        )
+        .times(1)
+        .returning(|input, _| {
+            Ok(fee_revenues::Model {
+                fee_revenue_id: 1,
+                transaction_type: input.transaction_type.clone(),
+                transaction_id: input.transaction_id,
+                fee_rule_id: input.fee_rule_id,
+                amount: input.amount,
+                created_at: None,
+            })
+        });
+
+    let service = FeeService::new(Arc::new(fee_repo), Some(REVENUE_USER_ID));
+
+    let revenue = |amount| RecordFeeRevenue {
+        transaction_type: "transfer".to_string(),
+        transaction_id: 5,
+        fee_rule_id: Some(1),
+        amount,
+    };
+
+    service.post_fee(&revenue(2500)).await.unwrap();
+    service.post_fee(&revenue(0)).await.unwrap();
+}
@@ -0,0 +1,896 @@
+use std::sync::Arc;
+
+use chrono::{NaiveDate, NaiveDateTime};
+use example_payment_gateway_axum::{
+    abstract_trait::{
+        clock::MockClockTrait,
+        fraud::{FraudEngineTrait, FraudReviewServiceTrait, MockFraudRepositoryTrait},
+        payment_intent::MockPaymentIntentRepositoryTrait,
+        qris::MockQrisRepositoryTrait,
+        scheduled_transfer::MockScheduledTransferRepositoryTrait,
+        settlement::MockSettlementRepositoryTrait,
+        topup::MockTopupServiceTrait,
+        transfer::MockTransferServiceTrait,
+        transfer_batch::MockTransferBatchRepositoryTrait,
+        withdraw::MockWithdrawServiceTrait,
+    },
+    domain::{
+        request::{
+            fraud::{DecideFraudReviewRequest, ScreenTransactionRequest},
+            transfer::CreateTransferRequest,
+            withdraw::CreateWithdrawRequest,
+        },
+        response::{
+            transfer::TransferResponse, withdraw::WithdrawResponse, ApiResponse, ErrorKind,
+            ErrorResponse,
+        },
+    },
+    entities::{
+        fraud_reviews, fraud_rules, payment_intents, qris_payments, settlements,
+        transfer_batch_items, user_devices,
+    },
+    services::{fraud::FraudEngine, fraud_review::FraudReviewService},
+    utils::errors::AppError,
+};
+use mockall::predicate;
+
+fn now() -> NaiveDateTime {
+    NaiveDate::from_ymd_opt(2026, 10, 19)
+        .unwrap()
+        .and_hms_opt(12, 0, 0)
+        .unwrap()
+}
+
+fn mock_clock() -> MockClockTrait {
+    let mut clock = MockClockTrait::new();
+    clock.expect_now().return_const(now());
+    clock
+}
+
+fn rule(name: &str, rule_type: &str, threshold: i32, action: &str) -> fraud_rules::Model {
+    fraud_rules::Model {
+        fraud_rule_id: 1,
+        name: name.to_string(),
+        rule_type: rule_type.to_string(),
+        transaction_type: Some("transfer".to_string()),
+        threshold,
+        window_seconds: Some(3600),
+        action: action.to_string(),
+        is_active: true,
+        created_at: None,
+        updated_at: None,
+    }
+}
+
+fn device(first_seen_at: NaiveDateTime) -> user_devices::Model {
+    user_devices::Model {
+        user_device_id: 1,
+        user_id: 1,
+        device_id: "device-1".to_string(),
+        first_seen_at,
+        last_seen_at: now(),
+    }
+}
+
+fn transfer_request() -> CreateTransferRequest {
+    CreateTransferRequest {
+        transfer_from: 1,
+        transfer_to: 2,
+        transfer_amount: 500000,
+        beneficiary_id: None,
+        device_id: Some("device-1".to_string()),
+        merchant_payment: false,
+    }
+}
+
+fn screen_request() -> ScreenTransactionRequest {
+    ScreenTransactionRequest {
+        transaction_type: "transfer".to_string(),
+        user_id: 1,
+        amount: 500000,
+        counterparty_id: Some(2),
+        device_id: None,
+        payload: serde_json::to_string(&transfer_request()).unwrap(),
+    }
+}
+
+fn review(status: &str) -> fraud_reviews::Model {
+    fraud_reviews::Model {
+        fraud_review_id: 7,
+        user_id: 1,
+        transaction_type: "transfer".to_string(),
+        amount: 500000,
+        counterparty_id: Some(2),
+        payload: serde_json::to_string(&transfer_request()).unwrap(),
+        triggered_rules: "transfer_velocity_review".to_string(),
+        status: status.to_string(),
+        transaction_id: None,
+        reviewer_id: None,
+        review_note: None,
+        reviewed_at: None,
+        created_at: None,
+        updated_at: None,
+    }
+}
+
+fn decision(note: Option<&str>) -> DecideFraudReviewRequest {
+    DecideFraudReviewRequest {
+        fraud_review_id: 7,
+        reviewer_id: 99,
+        note: note.map(str::to_string),
+    }
+}
+
+fn held_payment_intent() -> payment_intents::Model {
+    payment_intents::Model {
+        payment_intent_id: 3,
+        merchant_id: 4,
+        amount: 500000,
+        currency: "IDR".to_string(),
+        reference: "ORDER-1".to_string(),
+        status: "processing".to_string(),
+        expires_at: now(),
+        customer_id: Some(1),
+        transfer_id: None,
+        succeeded_at: None,
+        amount_refunded: 0,
+        settlement_id: None,
+        fraud_review_id: Some(7),
+        created_at: Some(now()),
+        updated_at: Some(now()),
+    }
+}
+
+fn engine(repository: MockFraudRepositoryTrait) -> FraudEngine {
+    FraudEngine::new(Arc::new(repository), Arc::new(mock_clock()))
+}
+
+fn unlinked_payment_intents() -> MockPaymentIntentRepositoryTrait {
+    let mut payment_intent_repository = MockPaymentIntentRepositoryTrait::new();
+    payment_intent_repository
+        .expect_find_by_fraud_review()
+        .returning(|_| Ok(None));
+    payment_intent_repository
+}
+
+fn unlinked_qris() -> MockQrisRepositoryTrait {
+    let mut qris_repository = MockQrisRepositoryTrait::new();
+    qris_repository
+        .expect_find_by_fraud_review()
+        .returning(|_| Ok(None));
+    qris_repository
+}
+
+fn unlinked_batch_items() -> MockTransferBatchRepositoryTrait {
+    let mut transfer_batch_repository = MockTransferBatchRepositoryTrait::new();
+    transfer_batch_repository
+        .expect_find_item_by_fraud_review()
+        .returning(|_| Ok(None));
+    transfer_batch_repository
+}
+
+fn unlinked_scheduled_attempts() -> MockScheduledTransferRepositoryTrait {
+    let mut scheduled_transfer_repository = MockScheduledTransferRepositoryTrait::new();
+    scheduled_transfer_repository
+        .expect_find_attempt_by_fraud_review()
+        .returning(|_| Ok(None));
+    scheduled_transfer_repository
+}
+
+fn review_service(
+    repository: MockFraudRepositoryTrait,
+    transfer_service: MockTransferServiceTrait,
+) -> FraudReviewService {
+    review_service_with_payments(
+        repository,
+        transfer_service,
+        unlinked_payment_intents(),
+        unlinked_qris(),
+    )
+}
+
+fn review_service_with_payments(
+    repository: MockFraudRepositoryTrait,
+    transfer_service: MockTransferServiceTrait,
+    payment_intent_repository: MockPaymentIntentRepositoryTrait,
+    qris_repository: MockQrisRepositoryTrait,
+) -> FraudReviewService {
+    FraudReviewService::new(
+        Arc::new(repository),
+        Arc::new(MockTopupServiceTrait::new()),
+        Arc::new(transfer_service),
+        Arc::new(MockWithdrawServiceTrait::new()),
+        Arc::new(payment_intent_repository),
+        Arc::new(qris_repository),
+        Arc::new(MockSettlementRepositoryTrait::new()),
+        Arc::new(unlinked_batch_items()),
+        Arc::new(unlinked_scheduled_attempts()),
+    )
+}
+
+#[tokio::test]
+async fn test_screen_allows_when_no_rule_triggers() {
+    let mut repository = MockFraudRepositoryTrait::new();
+
+    repository
+        .expect_find_active_rules()
+        .returning(|_| Ok(vec![rule("velocity", "velocity", 5, "review")]));
+    repository
+        .expect_count_user_transactions_since()
+        .returning(|_, _, _| Ok(4));
+    repository.expect_create_review().never();
+
+    assert!(engine(repository).screen(&screen_request()).await.is_ok());
+}
+
+#[tokio::test]
+async fn test_screen_holds_transfer_over_velocity_for_review() {
+    let mut repository = MockFraudRepositoryTrait::new();
+
+    repository
+        .expect_find_active_rules()
+        .with(predicate::eq("transfer"))
+        .returning(|_| {
+            Ok(vec![rule(
+                "transfer_velocity_review",
+                "velocity",
+                5,
+                "review",
+            )])
+        });
+    repository
+        .expect_count_user_transactions_since()
+        .withf(|transaction_type, user_id, since| {
+            transaction_type == "transfer"
+                && *user_id == 1
+                && *since == now() - chrono::Duration::hours(1)
+        })
+        .returning(|_, _, _| Ok(5));
+    repository
+        .expect_create_review()
+        .withf(|input| {
+            input.user_id == 1
+                && input.counterparty_id == Some(2)
+                && input.triggered_rules == vec!["transfer_velocity_review".to_string()]
+                && serde_json::from_str::<CreateTransferRequest>(&input.payload).unwrap()
+                    == transfer_request()
+        })
+        .times(1)
+        .returning(|_| Ok(review("pending")));
+
+    let err = engine(repository)
+        .screen(&screen_request())
+        .await
+        .unwrap_err();
+
+    assert_eq!(err.status, "pending");
+    assert_eq!(err.kind, ErrorKind::HeldForReview(7));
+    assert_eq!(
+        err.message,
+        "The transfer is held for review under fraud review 7"
+    );
+}
+
+#[tokio::test]
+async fn test_screen_deny_wins_over_review() {
+    let mut repository = MockFraudRepositoryTrait::new();
+
+    repository.expect_find_active_rules().returning(|_| {
+        Ok(vec![
+            rule("transfer_velocity_review", "velocity", 5, "review"),
+            rule("transfer_velocity_deny", "velocity", 10, "deny"),
+        ])
+    });
+    repository
+        .expect_count_user_transactions_since()
+        .returning(|_, _, _| Ok(10));
+    repository.expect_create_review().never();
+
+    let err = engine(repository)
+        .screen(&screen_request())
+        .await
+        .unwrap_err();
+
+    assert_eq!(err.status, "error");
+    assert_eq!(err.message, "The transfer was declined by fraud screening");
+}
+
+#[tokio::test]
+async fn test_screen_flags_amount_far_above_average() {
+    let mut repository = MockFraudRepositoryTrait::new();
+
+    repository
+        .expect_find_active_rules()
+        .returning(|_| Ok(vec![rule("amount_anomaly", "amount_anomaly", 10, "review")]));
+    // Three earlier transfers averaging 40,000
+    repository
+        .expect_user_amount_stats_since()
+        .returning(|_, _, _| Ok((3, 120000)));
+    repository
+        .expect_create_review()
+        .times(1)
+        .returning(|_| Ok(review("pending")));
+
+    let err = engine(repository)
+        .screen(&screen_request())
+        .await
+        .unwrap_err();
+
+    assert_eq!(err.status, "pending");
+}
+
+#[tokio::test]
+async fn test_screen_ignores_amount_without_enough_history() {
+    let mut repository = MockFraudRepositoryTrait::new();
+
+    repository
+        .expect_find_active_rules()
+        .returning(|_| Ok(vec![rule("amount_anomaly", "amount_anomaly", 10, "review")]));
+    repository
+        .expect_user_amount_stats_since()
+        .returning(|_, _, _| Ok((2, 20000)));
+    repository.expect_create_review().never();
+
+    assert!(engine(repository).screen(&screen_request()).await.is_ok());
+}
+
+#[tokio::test]
+async fn test_screen_flags_new_device_paying_new_beneficiary() {
+    let mut repository = MockFraudRepositoryTrait::new();
+
+    repository.expect_find_active_rules().returning(|_| {
+        Ok(vec![rule(
+            "new_device_new_beneficiary",
+            "new_device_beneficiary",
+            1,
+            "review",
+        )])
+    });
+    repository
+        .expect_touch_device()
+        .with(predicate::eq(1), predicate::eq("device-1"))
+        .times(1)
+        .returning(|_, _| Ok(device(now())));
+    repository
+        .expect_count_to_counterparty()
+        .with(
+            predicate::eq("transfer"),
+            predicate::eq(1),
+            predicate::eq(2),
+        )
+        .returning(|_, _, _| Ok(0));
+    repository
+        .expect_create_review()
+        .times(1)
+        .returning(|_| Ok(review("pending")));
+
+    let mut input = screen_request();
+    input.device_id = Some("device-1".to_string());
+
+    let err = engine(repository).screen(&input).await.unwrap_err();
+
+    assert_eq!(err.status, "pending");
+}
+
+#[tokio::test]
+async fn test_screen_allows_known_device_paying_new_beneficiary() {
+    let mut repository = MockFraudRepositoryTrait::new();
+
+    repository.expect_find_active_rules().returning(|_| {
+        Ok(vec![rule(
+            "new_device_new_beneficiary",
+            "new_device_beneficiary",
+            1,
+            "review",
+        )])
+    });
+    repository
+        .expect_touch_device()
+        .returning(|_, _| Ok(device(now() - chrono::Duration::days(30))));
+    repository.expect_count_to_counterparty().never();
+    repository.expect_create_review().never();
+
+    let mut input = screen_request();
+    input.device_id = Some("device-1".to_string());
+
+    assert!(engine(repository).screen(&input).await.is_ok());
+}
+
+#[tokio::test]
+async fn test_screen_flags_round_trip_between_same_accounts() {
+    let mut repository = MockFraudRepositoryTrait::new();
+
+    repository
+        .expect_find_active_rules()
+        .returning(|_| Ok(vec![rule("round_trip", "round_trip", 1, "deny")]));
+    // User 2 sent money to user 1 within the window
+    repository
+        .expect_count_transfers_since()
+        .withf(|transfer_from, transfer_to, _| *transfer_from == 2 && *transfer_to == 1)
+        .returning(|_, _, _| Ok(1));
+
+    let err = engine(repository)
+        .screen(&screen_request())
+        .await
+        .unwrap_err();
+
+    assert_eq!(err.message, "The transfer was declined by fraud screening");
+}
+
+#[tokio::test]
+async fn test_approve_releases_held_transfer() {
+    let mut repository = MockFraudRepositoryTrait::new();
+    let mut transfer_service = MockTransferServiceTrait::new();
+
+    let mut current = Some(review("pending"));
+    repository.expect_find_review_by_id().returning(move |_| {
+        let found = current.clone();
+        current = Some(fraud_reviews::Model {
+            transaction_id: Some(11),
+            reviewer_id: Some(99),
+            ..review("approved")
+        });
+        Ok(found)
+    });
+    repository
+        .expect_transition_review()
+        .with(
+            predicate::eq(7),
+            predicate::eq("pending"),
+            predicate::eq("approved"),
+            predicate::eq(99),
+            predicate::eq(None),
+        )
+        .times(1)
+        .returning(|_, _, _, _, _| Ok(true));
+
+    transfer_service
+        .expect_create_approved_transfer()
+        .withf(|input| *input == transfer_request())
+        .times(1)
+        .returning(|input| {
+            Ok(ApiResponse {
+                status: "success".to_string(),
+                message: "Transfer created successfully".to_string(),
+                data: TransferResponse {
+                    transfer_id: 11,
+                    transfer_from: input.transfer_from,
+                    transfer_to: input.transfer_to,
+                    transfer_amount: input.transfer_amount,
+                    transfer_time: chrono::Utc::now(),
+                    fee_amount: 0,
+                    created_at: None,
+                    updated_at: None,
+                },
+            })
+        });
+    transfer_service.expect_create_transfer().never();
+
+    repository
+        .expect_set_review_transaction()
+        .with(predicate::eq(7), predicate::eq(11))
+        .times(1)
+        .returning(|_, _| Ok(()));
+
+    let mut payment_intent_repository = MockPaymentIntentRepositoryTrait::new();
+    payment_intent_repository
+        .expect_find_by_fraud_review()
+        .with(predicate::eq(7))
+        .returning(|_| Ok(Some(held_payment_intent())));
+    payment_intent_repository
+        .expect_record_success()
+        .with(predicate::eq(3), predicate::eq(1), predicate::eq(11))
+        .times(1)
+        .returning(|_, _, transfer_id| {
+            Ok(payment_intents::Model {
+                status: "succeeded".to_string(),
+                transfer_id: Some(transfer_id),
+                ..held_payment_intent()
+            })
+        });
+
+    let response = review_service_with_payments(
+        repository,
+        transfer_service,
+        payment_intent_repository,
+        unlinked_qris(),
+    )
+    .approve_review(&decision(None))
+    .await
+    .unwrap();
+
+    assert_eq!(response.message, "Held transfer released");
+    assert_eq!(response.data.status, "approved");
+    assert_eq!(response.data.transaction_id, Some(11));
+}
+
+#[tokio::test]
+async fn test_approve_marks_review_failed_when_transfer_cannot_run() {
+    let mut repository = MockFraudRepositoryTrait::new();
+    let mut transfer_service = MockTransferServiceTrait::new();
+
+    repository
+        .expect_find_review_by_id()
+        .returning(|_| Ok(Some(review("pending"))));
+    repository
+        .expect_transition_review()
+        .with(
+            predicate::eq(7),
+            predicate::eq("pending"),
+            predicate::eq("approved"),
+            predicate::always(),
+            predicate::always(),
+        )
+        .times(1)
+        .returning(|_, _, _, _, _| Ok(true));
+    transfer_service
+        .expect_create_approved_transfer()
+        .returning(|_| Err(ErrorResponse::from(AppError::InsufficientBalance)));
+    repository
+        .expect_transition_review()
+        .with(
+            predicate::eq(7),
+            predicate::eq("approved"),
+            predicate::eq("failed"),
+            predicate::eq(99),
+            predicate::eq(Some("Insufficient balance".to_string())),
+        )
+        .times(1)
+        .returning(|_, _, _, _, _| Ok(true));
+    repository.expect_set_review_transaction().never();
+
+    let err = review_service(repository, transfer_service)
+        .approve_review(&decision(None))
+        .await
+        .unwrap_err();
+
+    assert_eq!(err.message, "Insufficient balance");
+}
+
+#[tokio::test]
+async fn test_approve_rejects_already_decided_review() {
+    let mut repository = MockFraudRepositoryTrait::new();
+    let mut transfer_service = MockTransferServiceTrait::new();
+
+    repository
+        .expect_find_review_by_id()
+        .returning(|_| Ok(Some(review("declined"))));
+    repository.expect_transition_review().never();
+    transfer_service.expect_create_approved_transfer().never();
+
+    let err = review_service(repository, transfer_service)
+        .approve_review(&decision(None))
+        .await
+        .unwrap_err();
+
+    assert_eq!(
+        err.message,
+        "Fraud review 7 is declined and cannot be approved"
+    );
+}
+
+#[tokio::test]
+async fn test_decline_requires_a_note() {
+    let err = review_service(
+        MockFraudRepositoryTrait::new(),
+        MockTransferServiceTrait::new(),
+    )
+    .decline_review(&decision(None))
+    .await
+    .unwrap_err();
+
+    assert_eq!(
+        err.message,
+        "A note is required when declining a transaction"
+    );
+}
+
+#[tokio::test]
+async fn test_decline_moves_no_money() {
+    let mut repository = MockFraudRepositoryTrait::new();
+    let mut transfer_service = MockTransferServiceTrait::new();
+
+    let mut current = Some(review("pending"));
+    repository.expect_find_review_by_id().returning(move |_| {
+        let found = current.clone();
+        current = Some(review("declined"));
+        Ok(found)
+    });
+    repository
+        .expect_transition_review()
+        .with(
+            predicate::eq(7),
+            predicate::eq("pending"),
+            predicate::eq("declined"),
+            predicate::eq(99),
+            predicate::eq(Some("Account takeover".to_string())),
+        )
+        .times(1)
+        .returning(|_, _, _, _, _| Ok(true));
+    transfer_service.expect_create_approved_transfer().never();
+
+    let mut payment_intent_repository = MockPaymentIntentRepositoryTrait::new();
+    payment_intent_repository
+        .expect_find_by_fraud_review()
+        .with(predicate::eq(7))
+        .returning(|_| Ok(Some(held_payment_intent())));
+    payment_intent_repository
+        .expect_transition_status()
+        .with(
+            predicate::eq(3),
+            predicate::eq("processing"),
+            predicate::eq("requires_confirmation"),
+        )
+        .times(1)
+        .returning(|_, _, _| Ok(true));
+    payment_intent_repository.expect_record_success().never();
+
+    let response = review_service_with_payments(
+        repository,
+        transfer_service,
+        payment_intent_repository,
+        unlinked_qris(),
+    )
+    .decline_review(&decision(Some("Account takeover")))
+    .await
+    .unwrap();
+
+    assert_eq!(response.data.status, "declined");
+}
+
+#[tokio::test]
+async fn test_decline_reopens_held_qris() {
+    let mut repository = MockFraudRepositoryTrait::new();
+
+    let mut current = Some(review("pending"));
+    repository.expect_find_review_by_id().returning(move |_| {
+        let found = current.clone();
+        current = Some(review("declined"));
+        Ok(found)
+    });
+    repository
+        .expect_transition_review()
+        .returning(|_, _, _, _, _| Ok(true));
+
+    let mut qris_repository = MockQrisRepositoryTrait::new();
+    qris_repository
+        .expect_find_by_fraud_review()
+        .with(predicate::eq(7))
+        .returning(|_| {
+            Ok(Some(qris_payments::Model {
+                qris_payment_id: 5,
+                user_id: 2,
+                merchant_id: Some(4),
+                reference: "QRREF1".to_string(),
+                amount: 500000,
+                payload: "000201010212".to_string(),
+                status: "processing".to_string(),
+                payer_id: Some(1),
+                transfer_id: None,
+                paid_at: None,
+                settlement_id: None,
+                fraud_review_id: Some(7),
+                created_at: Some(now()),
+                updated_at: Some(now()),
+            }))
+        });
+    qris_repository
+        .expect_transition_status()
+        .with(
+            predicate::eq(5),
+            predicate::eq("processing"),
+            predicate::eq("pending"),
+        )
+        .times(1)
+        .returning(|_, _, _| Ok(true));
+    qris_repository.expect_record_payment().never();
+
+    review_service_with_payments(
+        repository,
+        MockTransferServiceTrait::new(),
+        unlinked_payment_intents(),
+        qris_repository,
+    )
+    .decline_review(&decision(Some("Account takeover")))
+    .await
+    .unwrap();
+}
+
+#[tokio::test]
+async fn test_approve_completes_held_batch_item() {
+    let mut repository = MockFraudRepositoryTrait::new();
+    let mut transfer_service = MockTransferServiceTrait::new();
+
+    let mut current = Some(review("pending"));
+    repository.expect_find_review_by_id().returning(move |_| {
+        let found = current.clone();
+        current = Some(review("approved"));
+        Ok(found)
+    });
+    repository
+        .expect_transition_review()
+        .returning(|_, _, _, _, _| Ok(true));
+    repository
+        .expect_set_review_transaction()
+        .returning(|_, _| Ok(()));
+
+    transfer_service
+        .expect_create_approved_transfer()
+        .times(1)
+        .returning(|input| {
+            Ok(ApiResponse {
+                status: "success".to_string(),
+                message: "Transfer created successfully".to_string(),
+                data: TransferResponse {
+                    transfer_id: 11,
+                    transfer_from: input.transfer_from,
+                    transfer_to: input.transfer_to,
+                    transfer_amount: input.transfer_amount,
+                    transfer_time: chrono::Utc::now(),
+                    fee_amount: 0,
+                    created_at: None,
+                    updated_at: None,
+                },
+            })
+        });
+
+    let mut transfer_batch_repository = MockTransferBatchRepositoryTrait::new();
+    transfer_batch_repository
+        .expect_find_item_by_fraud_review()
+        .with(predicate::eq(7))
+        .returning(|_| {
+            Ok(Some(transfer_batch_items::Model {
+                item_id: 4,
+                batch_id: 2,
+                line_no: 3,
+                transfer_to: 2,
+                transfer_amount: 500000,
+                status: "held".to_string(),
+                transfer_id: None,
+                error_message: None,
+                fraud_review_id: Some(7),
+                created_at: Some(now()),
+                updated_at: Some(now()),
+            }))
+        });
+    transfer_batch_repository
+        .expect_resolve_held_item()
+        .withf(|input| {
+            input.item_id == 4 && input.status == "succeeded" && input.transfer_id == Some(11)
+        })
+        .times(1)
+        .returning(|_| Ok(true));
+
+    let service = FraudReviewService::new(
+        Arc::new(repository),
+        Arc::new(MockTopupServiceTrait::new()),
+        Arc::new(transfer_service),
+        Arc::new(MockWithdrawServiceTrait::new()),
+        Arc::new(unlinked_payment_intents()),
+        Arc::new(unlinked_qris()),
+        Arc::new(MockSettlementRepositoryTrait::new()),
+        Arc::new(transfer_batch_repository),
+        Arc::new(unlinked_scheduled_attempts()),
+    );
+
+    let response = service.approve_review(&decision(None)).await.unwrap();
+
+    assert_eq!(response.message, "Held transfer released");
+}
+
+#[tokio::test]
+async fn test_approve_pays_out_settlement_held_for_review() {
+    let payout = CreateWithdrawRequest {
+        user_id: 2,
+        bank_account_id: 4,
+        withdraw_amount: 125000,
+        withdraw_time: chrono::Utc::now(),
+        device_id: None,
+    };
+    let held_payout = fraud_reviews::Model {
+        transaction_type: "withdraw".to_string(),
+        payload: serde_json::to_string(&payout).unwrap(),
+        ..review("pending")
+    };
+
+    let mut repository = MockFraudRepositoryTrait::new();
+    let mut current = Some(held_payout.clone());
+    repository.expect_find_review_by_id().returning(move |_| {
+        let found = current.clone();
+        current = Some(fraud_reviews::Model {
+            status: "approved".to_string(),
+            transaction_id: Some(12),
+            ..held_payout.clone()
+        });
+        Ok(found)
+    });
+    repository
+        .expect_transition_review()
+        .returning(|_, _, _, _, _| Ok(true));
+    repository
+        .expect_set_review_transaction()
+        .with(predicate::eq(7), predicate::eq(12))
+        .returning(|_, _| Ok(()));
+
+    let mut withdraw_service = MockWithdrawServiceTrait::new();
+    withdraw_service
+        .expect_create_approved_withdraw()
+        .times(1)
+        .returning(|input| {
+            Ok(ApiResponse {
+                status: "success".to_string(),
+                message: "Withdraw created successfully".to_string(),
+                data: WithdrawResponse {
+                    withdraw_id: 12,
+                    user_id: input.user_id,
+                    withdraw_amount: input.withdraw_amount,
+                    withdraw_time: input.withdraw_time,
+                    bank_account_id: Some(input.bank_account_id),
+                    status: "pending".to_string(),
+                    provider_reference: None,
+                    failure_reason: None,
+                    fee_amount: 0,
+                    created_at: None,
+                    updated_at: None,
+                },
+            })
+        });
+
+    let held_settlement = settlements::Model {
+        settlement_id: 5,
+        merchant_id: 4,
+        period_start: now(),
+        period_end: now(),
+        payment_count: 1,
+        gross_amount: 125000,
+        refunded_amount: 0,
+        fee_amount: 0,
+        net_amount: 125000,
+        status: "in_review".to_string(),
+        withdraw_id: None,
+        failure_reason: Some("Payout held for fraud review".to_string()),
+        fraud_review_id: Some(7),
+        created_at: Some(now()),
+        updated_at: Some(now()),
+    };
+
+    let mut settlement_repository = MockSettlementRepositoryTrait::new();
+    let found = held_settlement.clone();
+    settlement_repository
+        .expect_find_by_fraud_review()
+        .with(predicate::eq(7))
+        .returning(move |_| Ok(Some(found.clone())));
+    settlement_repository
+        .expect_update_payout()
+        .with(
+            predicate::eq(5),
+            predicate::eq("paid_out"),
+            predicate::eq(Some(12)),
+            predicate::eq(None::<String>),
+        )
+        .times(1)
+        .returning(move |_, status, withdraw_id, failure_reason| {
+            Ok(settlements::Model {
+                status: status.to_string(),
+                withdraw_id,
+                failure_reason,
+                ..held_settlement.clone()
+            })
+        });
+
+    let service = FraudReviewService::new(
+        Arc::new(repository),
+        Arc::new(MockTopupServiceTrait::new()),
+        Arc::new(MockTransferServiceTrait::new()),
+        Arc::new(withdraw_service),
+        Arc::new(MockPaymentIntentRepositoryTrait::new()),
+        Arc::new(MockQrisRepositoryTrait::new()),
+        Arc::new(settlement_repository),
+        Arc::new(MockTransferBatchRepositoryTrait::new()),
+        Arc::new(MockScheduledTransferRepositoryTrait::new()),
+    );
+
+    let response = service.approve_review(&decision(None)).await.unwrap();
+
+    assert_eq!(response.message, "Held withdraw released");
+}
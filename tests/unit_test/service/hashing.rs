@@ -59,7 +59,7 @@ async fn test_compare_password_mismatch() {
         .returning(|_, _| {
             Box::pin(async {
                 Err(AppError::HashingError(BcryptError::from(
-                    std::io::Error::new(std::io::ErrorKind::Other, "Passwords do not match."),
+                    std::io::Error::other("Passwords do not match."),
                 )))
             })
         });
@@ -68,8 +68,8 @@ async fn test_compare_password_mismatch() {
         .compare_password(hashed_password, plain_password)
         .await;
 
-    match result {
-        Err(AppError::HashingError(_)) => assert!(true),
-        _ => panic!("Expected AppError::HashingError"),
-    }
+    assert!(
+        matches!(result, Err(AppError::HashingError(_))),
+        "Expected AppError::HashingError"
+    );
 }
@@ -0,0 +1,142 @@
+use std::{sync::Arc, time::Duration};
+
+use chrono::{NaiveDate, NaiveDateTime, TimeDelta};
+use example_payment_gateway_axum::{
+    abstract_trait::{
+        clock::MockClockTrait,
+        health::{HealthServiceTrait, MockHealthRepositoryTrait},
+    },
+    jobs::health::{SharedWorkerHealth, WorkerHealth},
+    services::health::HealthService,
+};
+use sea_orm::DbErr;
+
+fn now() -> NaiveDateTime {
+    NaiveDate::from_ymd_opt(2026, 10, 19)
+        .unwrap()
+        .and_hms_opt(14, 30, 0)
+        .unwrap()
+}
+
+fn clock_at(at: NaiveDateTime) -> MockClockTrait {
+    let mut clock = MockClockTrait::new();
+    clock.expect_now().return_const(at);
+    clock
+}
+
+fn healthy_repository() -> MockHealthRepositoryTrait {
+    let mut repo = MockHealthRepositoryTrait::new();
+    repo.expect_ping().returning(|| Ok(()));
+    repo.expect_ping_replica().returning(|| None);
+    repo.expect_pending_migrations().returning(|| Ok(vec![]));
+    repo
+}
+
+/// Worker heartbeats as last recorded `seconds_ago` before `now()`.
+fn worker_health(seconds_ago: i64) -> SharedWorkerHealth {
+    let worker_health =
+        WorkerHealth::new(Arc::new(clock_at(now() - TimeDelta::seconds(seconds_ago))));
+    worker_health.register("scheduler", Duration::from_secs(30));
+    Arc::new(worker_health)
+}
+
+fn service(repo: MockHealthRepositoryTrait, worker_health: SharedWorkerHealth) -> HealthService {
+    HealthService::new(Arc::new(repo), worker_health, Arc::new(clock_at(now())))
+}
+
+#[tokio::test]
+async fn test_readiness_is_ready_when_every_component_is_up() {
+    let response = service(healthy_repository(), worker_health(10))
+        .readiness()
+        .await;
+
+    assert_eq!(response.status, "ready");
+    assert!(response.is_available());
+    assert_eq!(
+        response.components.keys().collect::<Vec<_>>(),
+        ["database", "migrations", "scheduler"]
+    );
+    assert!(response.components.values().all(|c| c.status == "up"));
+}
+
+#[tokio::test]
+async fn test_readiness_is_unavailable_when_database_is_down() {
+    let mut repo = MockHealthRepositoryTrait::new();
+    repo.expect_ping()
+        .returning(|| Err(DbErr::Custom("connection refused".to_string())));
+    repo.expect_ping_replica().returning(|| None);
+    repo.expect_pending_migrations()
+        .returning(|| Err(DbErr::Custom("connection refused".to_string())));
+
+    let response = service(repo, worker_health(10)).readiness().await;
+
+    assert_eq!(response.status, "unavailable");
+    assert!(!response.is_available());
+    assert_eq!(response.components["database"].status, "down");
+    assert!(response.components["database"].critical);
+    assert_eq!(response.components["scheduler"].status, "up");
+}
+
+#[tokio::test]
+async fn test_readiness_is_unavailable_with_pending_migrations() {
+    let mut repo = MockHealthRepositoryTrait::new();
+    repo.expect_ping().returning(|| Ok(()));
+    repo.expect_ping_replica().returning(|| None);
+    repo.expect_pending_migrations()
+        .returning(|| Ok(vec!["m20260101_000001_add_column".to_string()]));
+
+    let response = service(repo, worker_health(10)).readiness().await;
+
+    assert_eq!(response.status, "unavailable");
+    assert_eq!(
+        response.components["migrations"].detail.as_deref(),
+        Some("Pending migrations: m20260101_000001_add_column")
+    );
+}
+
+#[tokio::test]
+async fn test_readiness_is_degraded_when_a_worker_stalls() {
+    let response = service(healthy_repository(), worker_health(120))
+        .readiness()
+        .await;
+
+    assert_eq!(response.status, "degraded");
+    assert!(response.is_available());
+    assert_eq!(response.components["scheduler"].status, "down");
+    assert!(!response.components["scheduler"].critical);
+    assert_eq!(
+        response.components["scheduler"].detail.as_deref(),
+        Some("No run for 120 seconds")
+    );
+}
+
+#[tokio::test]
+async fn test_readiness_is_degraded_when_a_worker_run_failed() {
+    let worker_health = worker_health(10);
+    worker_health.record_run::<(), _>("scheduler", &Err("database is locked"));
+
+    let response = service(healthy_repository(), worker_health)
+        .readiness()
+        .await;
+
+    assert_eq!(response.status, "degraded");
+    assert_eq!(
+        response.components["scheduler"].detail.as_deref(),
+        Some("Last run failed: database is locked")
+    );
+}
+
+#[tokio::test]
+async fn test_readiness_is_degraded_when_the_replica_is_down() {
+    let mut repo = MockHealthRepositoryTrait::new();
+    repo.expect_ping().returning(|| Ok(()));
+    repo.expect_ping_replica()
+        .returning(|| Some(Err(DbErr::Custom("connection refused".to_string()))));
+    repo.expect_pending_migrations().returning(|| Ok(vec![]));
+
+    let response = service(repo, worker_health(10)).readiness().await;
+
+    assert_eq!(response.status, "degraded");
+    assert_eq!(response.components["database_replica"].status, "down");
+    assert!(!response.components["database_replica"].critical);
+}
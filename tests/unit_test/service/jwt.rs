@@ -55,10 +55,8 @@ async fn test_verify_token_expired() {
 
     let result = mock_jwt_service.verify_token(token);
 
-    assert!(result.is_err());
-    if let AppError::TokenExpiredError = result.unwrap_err() {
-        assert!(true);
-    } else {
-        assert!(false, "Expected AppError::TokenExpiredError");
-    }
+    assert!(
+        matches!(result, Err(AppError::TokenExpiredError)),
+        "Expected AppError::TokenExpiredError"
+    );
 }
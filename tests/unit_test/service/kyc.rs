@@ -0,0 +1,394 @@
+use std::sync::Arc;
+
+use example_payment_gateway_axum::{
+    abstract_trait::{
+        blob_store::MockBlobStoreTrait,
+        kyc::{KycServiceTrait, MockKycRepositoryTrait},
+        user::MockUserRepositoryTrait,
+    },
+    domain::request::kyc::{CreateKycSubmissionRequest, KycDocument, ReviewKycSubmissionRequest},
+    entities::{kyc_submission_events, kyc_submissions, users},
+    services::kyc::KycService,
+    utils::errors::AppError,
+};
+use mockall::predicate;
+use sea_orm::DbErr;
+
+fn user(kyc_level: i32) -> users::Model {
+    users::Model {
+        user_id: 1,
+        firstname: "Test".to_string(),
+        lastname: "User".to_string(),
+        email: "test@example.com".to_string(),
+        password: "hash".to_string(),
+        noc_transfer: "12345".to_string(),
+        kyc_level,
+        role: "user".to_string(),
+        created_at: None,
+        updated_at: None,
+    }
+}
+
+fn submission(status: &str) -> kyc_submissions::Model {
+    kyc_submissions::Model {
+        kyc_submission_id: 5,
+        user_id: 1,
+        requested_level: 1,
+        id_card_key: "kyc/1/abc/id_card.png".to_string(),
+        selfie_key: "kyc/1/abc/selfie.jpg".to_string(),
+        status: status.to_string(),
+        reviewer_id: None,
+        review_note: None,
+        reviewed_at: None,
+        created_at: None,
+        updated_at: None,
+    }
+}
+
+fn event() -> kyc_submission_events::Model {
+    kyc_submission_events::Model {
+        kyc_submission_event_id: 1,
+        kyc_submission_id: 5,
+        actor_id: 1,
+        from_status: None,
+        to_status: "submitted".to_string(),
+        note: None,
+        created_at: None,
+    }
+}
+
+fn upload() -> CreateKycSubmissionRequest {
+    CreateKycSubmissionRequest {
+        user_id: 1,
+        id_card: KycDocument {
+            content_type: "image/png".to_string(),
+            bytes: vec![1, 2, 3],
+        },
+        selfie: KycDocument {
+            content_type: "image/jpeg".to_string(),
+            bytes: vec![4, 5, 6],
+        },
+    }
+}
+
+fn review(status: &str, note: Option<&str>) -> ReviewKycSubmissionRequest {
+    ReviewKycSubmissionRequest {
+        kyc_submission_id: 5,
+        reviewer_id: 99,
+        status: status.to_string(),
+        note: note.map(str::to_string),
+    }
+}
+
+fn service(
+    kyc_repo: MockKycRepositoryTrait,
+    user_repo: MockUserRepositoryTrait,
+    blob_store: MockBlobStoreTrait,
+) -> KycService {
+    KycService::new(
+        Arc::new(kyc_repo),
+        Arc::new(user_repo),
+        Arc::new(blob_store),
+    )
+}
+
+#[tokio::test]
+async fn test_create_submission_stores_both_documents() {
+    let mut kyc_repo = MockKycRepositoryTrait::new();
+    let mut user_repo = MockUserRepositoryTrait::new();
+    let mut blob_store = MockBlobStoreTrait::new();
+
+    user_repo
+        .expect_find_by_id()
+        .returning(|_| Ok(Some(user(0))));
+    kyc_repo.expect_find_open_by_user().returning(|_| Ok(None));
+
+    blob_store
+        .expect_put()
+        .withf(|key, bytes| {
+            key.starts_with("kyc/1/") && key.ends_with("/id_card.png") && bytes == [1, 2, 3]
+        })
+        .times(1)
+        .returning(|_, _| Ok(()));
+    blob_store
+        .expect_put()
+        .withf(|key, bytes| {
+            key.starts_with("kyc/1/") && key.ends_with("/selfie.jpg") && bytes == [4, 5, 6]
+        })
+        .times(1)
+        .returning(|_, _| Ok(()));
+
+    kyc_repo
+        .expect_create_submission()
+        .withf(|user_id, requested_level, id_card_key, selfie_key| {
+            *user_id == 1
+                && *requested_level == 1
+                && id_card_key.ends_with("id_card.png")
+                && selfie_key.ends_with("selfie.jpg")
+        })
+        .return_once(|_, _, _, _| Ok(submission("submitted")));
+
+    kyc_repo
+        .expect_record_event()
+        .withf(|input| input.from_status.is_none() && input.to_status == "submitted")
+        .times(1)
+        .returning(|_| Ok(event()));
+
+    let response = service(kyc_repo, user_repo, blob_store)
+        .create_submission(&upload())
+        .await
+        .unwrap();
+
+    assert_eq!(response.data.status, "submitted");
+    assert_eq!(response.data.requested_level, 1);
+}
+
+#[tokio::test]
+async fn test_create_submission_rejects_unsupported_selfie() {
+    let mut input = upload();
+    input.selfie.content_type = "application/pdf".to_string();
+
+    let err = service(
+        MockKycRepositoryTrait::new(),
+        MockUserRepositoryTrait::new(),
+        MockBlobStoreTrait::new(),
+    )
+    .create_submission(&input)
+    .await
+    .unwrap_err();
+
+    assert_eq!(err.message, "The selfie must be a JPEG or PNG image");
+}
+
+#[tokio::test]
+async fn test_create_submission_rejects_while_one_is_open() {
+    let mut kyc_repo = MockKycRepositoryTrait::new();
+    let mut user_repo = MockUserRepositoryTrait::new();
+    let mut blob_store = MockBlobStoreTrait::new();
+
+    user_repo
+        .expect_find_by_id()
+        .returning(|_| Ok(Some(user(0))));
+    kyc_repo
+        .expect_find_open_by_user()
+        .returning(|_| Ok(Some(submission("in_review"))));
+    blob_store.expect_put().never();
+
+    let err = service(kyc_repo, user_repo, blob_store)
+        .create_submission(&upload())
+        .await
+        .unwrap_err();
+
+    assert_eq!(err.message, "KYC submission 5 is still in_review");
+}
+
+#[tokio::test]
+async fn test_create_submission_removes_documents_when_not_recorded() {
+    let mut kyc_repo = MockKycRepositoryTrait::new();
+    let mut user_repo = MockUserRepositoryTrait::new();
+    let mut blob_store = MockBlobStoreTrait::new();
+
+    user_repo
+        .expect_find_by_id()
+        .returning(|_| Ok(Some(user(0))));
+    kyc_repo.expect_find_open_by_user().returning(|_| Ok(None));
+    blob_store.expect_put().times(2).returning(|_, _| Ok(()));
+    kyc_repo
+        .expect_create_submission()
+        .return_once(|_, _, _, _| Err(DbErr::Custom("insert failed".to_string())));
+    blob_store.expect_delete().times(2).returning(|_| Ok(()));
+    kyc_repo.expect_record_event().never();
+
+    let result = service(kyc_repo, user_repo, blob_store)
+        .create_submission(&upload())
+        .await;
+
+    assert!(result.is_err());
+}
+
+#[tokio::test]
+async fn test_approve_raises_kyc_level_and_records_event() {
+    let mut kyc_repo = MockKycRepositoryTrait::new();
+    let mut user_repo = MockUserRepositoryTrait::new();
+
+    let mut current = Some(submission("in_review"));
+    kyc_repo.expect_find_by_id().returning(move |_| {
+        let found = current.clone();
+        current = Some(kyc_submissions::Model {
+            status: "approved".to_string(),
+            reviewer_id: Some(99),
+            ..submission("approved")
+        });
+        Ok(found)
+    });
+
+    kyc_repo
+        .expect_transition_status()
+        .with(
+            predicate::eq(5),
+            predicate::eq("in_review"),
+            predicate::eq("approved"),
+            predicate::eq(99),
+            predicate::eq(None),
+        )
+        .times(1)
+        .returning(|_, _, _, _, _| Ok(true));
+
+    user_repo
+        .expect_find_by_id()
+        .returning(|_| Ok(Some(user(0))));
+    user_repo
+        .expect_update_kyc_level()
+        .with(predicate::eq(1), predicate::eq(1))
+        .times(1)
+        .returning(|_, kyc_level| Ok(user(kyc_level)));
+
+    kyc_repo
+        .expect_record_event()
+        .withf(|input| {
+            input.actor_id == 99
+                && input.from_status.as_deref() == Some("in_review")
+                && input.to_status == "approved"
+        })
+        .times(1)
+        .returning(|_| Ok(event()));
+
+    let response = service(kyc_repo, user_repo, MockBlobStoreTrait::new())
+        .review_submission(&review("approved", None))
+        .await
+        .unwrap();
+
+    assert_eq!(response.data.status, "approved");
+    assert_eq!(response.data.reviewer_id, Some(99));
+}
+
+#[tokio::test]
+async fn test_approve_requires_review_to_have_started() {
+    let mut kyc_repo = MockKycRepositoryTrait::new();
+    let mut user_repo = MockUserRepositoryTrait::new();
+
+    kyc_repo
+        .expect_find_by_id()
+        .returning(|_| Ok(Some(submission("submitted"))));
+    kyc_repo.expect_transition_status().never();
+    user_repo.expect_update_kyc_level().never();
+
+    let err = service(kyc_repo, user_repo, MockBlobStoreTrait::new())
+        .review_submission(&review("approved", None))
+        .await
+        .unwrap_err();
+
+    assert_eq!(
+        err.message,
+        "KYC submission 5 is submitted and cannot move to approved"
+    );
+}
+
+#[tokio::test]
+async fn test_approve_reopens_submission_when_level_update_fails() {
+    let mut kyc_repo = MockKycRepositoryTrait::new();
+    let mut user_repo = MockUserRepositoryTrait::new();
+
+    kyc_repo
+        .expect_find_by_id()
+        .returning(|_| Ok(Some(submission("in_review"))));
+    kyc_repo
+        .expect_transition_status()
+        .with(
+            predicate::eq(5),
+            predicate::eq("in_review"),
+            predicate::eq("approved"),
+            predicate::always(),
+            predicate::always(),
+        )
+        .times(1)
+        .returning(|_, _, _, _, _| Ok(true));
+    kyc_repo
+        .expect_transition_status()
+        .with(
+            predicate::eq(5),
+            predicate::eq("approved"),
+            predicate::eq("in_review"),
+            predicate::always(),
+            predicate::always(),
+        )
+        .times(1)
+        .returning(|_, _, _, _, _| Ok(true));
+
+    user_repo
+        .expect_find_by_id()
+        .returning(|_| Ok(Some(user(0))));
+    user_repo
+        .expect_update_kyc_level()
+        .returning(|_, _| Err(DbErr::Custom("update failed".to_string())));
+    kyc_repo.expect_record_event().never();
+
+    let result = service(kyc_repo, user_repo, MockBlobStoreTrait::new())
+        .review_submission(&review("approved", None))
+        .await;
+
+    assert!(result.is_err());
+}
+
+#[tokio::test]
+async fn test_reject_requires_a_note() {
+    let err = service(
+        MockKycRepositoryTrait::new(),
+        MockUserRepositoryTrait::new(),
+        MockBlobStoreTrait::new(),
+    )
+    .review_submission(&review("rejected", Some("  ")))
+    .await
+    .unwrap_err();
+
+    assert_eq!(
+        err.message,
+        "A note is required when rejecting a submission"
+    );
+}
+
+#[tokio::test]
+async fn test_get_document_reads_stored_file() {
+    let mut kyc_repo = MockKycRepositoryTrait::new();
+    let mut blob_store = MockBlobStoreTrait::new();
+
+    kyc_repo
+        .expect_find_by_id()
+        .returning(|_| Ok(Some(submission("in_review"))));
+    blob_store
+        .expect_get()
+        .with(predicate::eq("kyc/1/abc/selfie.jpg"))
+        .returning(|_| Ok(vec![9, 9]));
+
+    let service = service(kyc_repo, MockUserRepositoryTrait::new(), blob_store);
+
+    let file = service.get_document(5, "selfie").await.unwrap();
+    assert_eq!(file.content_type, "image/jpeg");
+    assert_eq!(file.bytes, vec![9, 9]);
+
+    let err = service.get_document(5, "passport").await.unwrap_err();
+    assert_eq!(err.message, "KYC document passport not found");
+}
+
+#[tokio::test]
+async fn test_blob_store_errors_are_reported() {
+    let mut kyc_repo = MockKycRepositoryTrait::new();
+    let mut user_repo = MockUserRepositoryTrait::new();
+    let mut blob_store = MockBlobStoreTrait::new();
+
+    user_repo
+        .expect_find_by_id()
+        .returning(|_| Ok(Some(user(0))));
+    kyc_repo.expect_find_open_by_user().returning(|_| Ok(None));
+    blob_store
+        .expect_put()
+        .returning(|_, _| Err(AppError::BlobStoreError("disk full".to_string())));
+    blob_store.expect_delete().never();
+
+    let err = service(kyc_repo, user_repo, blob_store)
+        .create_submission(&upload())
+        .await
+        .unwrap_err();
+
+    assert_eq!(err.message, "Blob store error: disk full");
+}
@@ -0,0 +1,235 @@
+use std::sync::Arc;
+
+use chrono::{NaiveDate, NaiveDateTime};
+use example_payment_gateway_axum::{
+    abstract_trait::{
+        clock::MockClockTrait,
+        limit::{LimitServiceTrait, MockLimitRepositoryTrait},
+        saldo::MockSaldoRepositoryTrait,
+        user::MockUserRepositoryTrait,
+    },
+    domain::request::limit::CheckLimitRequest,
+    entities::{saldo, transaction_limits, users},
+    services::limit::LimitService,
+};
+use mockall::predicate;
+
+fn now() -> NaiveDateTime {
+    NaiveDate::from_ymd_opt(2026, 10, 19)
+        .unwrap()
+        .and_hms_opt(14, 30, 0)
+        .unwrap()
+}
+
+fn user(kyc_level: i32) -> users::Model {
+    users::Model {
+        user_id: 1,
+        firstname: "Test".to_string(),
+        lastname: "User".to_string(),
+        email: "test@example.com".to_string(),
+        password: "hash".to_string(),
+        noc_transfer: "12345".to_string(),
+        kyc_level,
+        role: "user".to_string(),
+        created_at: None,
+        updated_at: None,
+    }
+}
+
+fn limit(transaction_type: &str) -> transaction_limits::Model {
+    transaction_limits::Model {
+        transaction_limit_id: 1,
+        kyc_level: 0,
+        transaction_type: transaction_type.to_string(),
+        max_balance: None,
+        max_transaction_amount: None,
+        daily_amount: None,
+        monthly_amount: None,
+        created_at: None,
+        updated_at: None,
+    }
+}
+
+fn request(transaction_type: &str, amount: i32) -> CheckLimitRequest {
+    CheckLimitRequest {
+        user_id: 1,
+        transaction_type: transaction_type.to_string(),
+        amount,
+    }
+}
+
+fn service(
+    limit_repo: MockLimitRepositoryTrait,
+    saldo_repo: MockSaldoRepositoryTrait,
+) -> LimitService {
+    let mut user_repo = MockUserRepositoryTrait::new();
+    user_repo
+        .expect_find_by_id()
+        .returning(|_| Ok(Some(user(0))));
+
+    let mut clock = MockClockTrait::new();
+    clock.expect_now().return_const(now());
+
+    LimitService::new(
+        Arc::new(limit_repo),
+        Arc::new(user_repo),
+        Arc::new(saldo_repo),
+        Arc::new(clock),
+    )
+}
+
+fn limit_repo_with(limit: transaction_limits::Model) -> MockLimitRepositoryTrait {
+    let mut limit_repo = MockLimitRepositoryTrait::new();
+    limit_repo
+        .expect_find_limit()
+        .with(predicate::eq(0), predicate::always())
+        .returning(move |_, _| Ok(Some(limit.clone())));
+    limit_repo
+}
+
+#[tokio::test]
+async fn test_no_limit_row_allows_transaction() {
+    let mut limit_repo = MockLimitRepositoryTrait::new();
+    limit_repo.expect_find_limit().returning(|_, _| Ok(None));
+    limit_repo.expect_sum_user_amount_since().never();
+
+    let service = service(limit_repo, MockSaldoRepositoryTrait::new());
+
+    assert!(service
+        .check_transaction_limits(&request("transfer", 50_000_000))
+        .await
+        .is_ok());
+}
+
+#[tokio::test]
+async fn test_single_transaction_limit() {
+    let mut transfer = limit("transfer");
+    transfer.max_transaction_amount = Some(1_000_000);
+
+    let service = service(limit_repo_with(transfer), MockSaldoRepositoryTrait::new());
+
+    assert!(service
+        .check_transaction_limits(&request("transfer", 1_000_000))
+        .await
+        .is_ok());
+
+    let err = service
+        .check_transaction_limits(&request("transfer", 1_000_001))
+        .await
+        .unwrap_err();
+    assert_eq!(
+        err.message,
+        "Amount 1000001 is over the single transfer limit of 1000000 for KYC level 0"
+    );
+}
+
+#[tokio::test]
+async fn test_daily_and_monthly_limits_use_calendar_windows() {
+    let mut withdraw = limit("withdraw");
+    withdraw.daily_amount = Some(2_000_000);
+    withdraw.monthly_amount = Some(10_000_000);
+
+    let mut limit_repo = limit_repo_with(withdraw);
+
+    let midnight = NaiveDate::from_ymd_opt(2026, 10, 19)
+        .unwrap()
+        .and_hms_opt(0, 0, 0)
+        .unwrap();
+    let first_of_month = NaiveDate::from_ymd_opt(2026, 10, 1)
+        .unwrap()
+        .and_hms_opt(0, 0, 0)
+        .unwrap();
+
+    limit_repo
+        .expect_sum_user_amount_since()
+        .with(
+            predicate::eq("withdraw"),
+            predicate::eq(1),
+            predicate::eq(midnight),
+        )
+        .returning(|_, _, _| Ok(1_500_000));
+    limit_repo
+        .expect_sum_user_amount_since()
+        .with(
+            predicate::eq("withdraw"),
+            predicate::eq(1),
+            predicate::eq(first_of_month),
+        )
+        .returning(|_, _, _| Ok(9_800_000));
+
+    let service = service(limit_repo, MockSaldoRepositoryTrait::new());
+
+    assert!(service
+        .check_transaction_limits(&request("withdraw", 200_000))
+        .await
+        .is_ok());
+
+    let daily = service
+        .check_transaction_limits(&request("withdraw", 600_000))
+        .await
+        .unwrap_err();
+    assert_eq!(
+        daily.message,
+        "Daily withdraw limit of 2000000 for KYC level 0 would be exceeded, 1500000 already used"
+    );
+
+    let monthly = service
+        .check_transaction_limits(&request("withdraw", 300_000))
+        .await
+        .unwrap_err();
+    assert_eq!(
+        monthly.message,
+        "Monthly withdraw limit of 10000000 for KYC level 0 would be exceeded, 9800000 already used"
+    );
+}
+
+#[tokio::test]
+async fn test_balance_limit() {
+    let mut topup = limit("topup");
+    topup.max_balance = Some(2_000_000);
+
+    let mut saldo_repo = MockSaldoRepositoryTrait::new();
+    saldo_repo.expect_find_by_user_id().returning(|user_id| {
+        Ok(Some(saldo::Model {
+            saldo_id: 1,
+            user_id,
+            total_balance: 1_900_000,
+            withdraw_amount: None,
+            withdraw_time: None,
+            created_at: None,
+            updated_at: None,
+        }))
+    });
+
+    let service = service(limit_repo_with(topup), saldo_repo);
+
+    assert!(service
+        .check_balance_limit(&request("topup", 100_000))
+        .await
+        .is_ok());
+
+    let err = service
+        .check_balance_limit(&request("topup", 100_001))
+        .await
+        .unwrap_err();
+    assert_eq!(
+        err.message,
+        "Receiving 100001 would take the balance of user 1 over the maximum of 2000000 for KYC level 0"
+    );
+}
+
+#[tokio::test]
+async fn test_get_user_limits_returns_limits_of_the_users_level() {
+    let mut limit_repo = MockLimitRepositoryTrait::new();
+    limit_repo
+        .expect_find_by_level()
+        .with(predicate::eq(0))
+        .returning(|_| Ok(vec![limit("topup"), limit("transfer")]));
+
+    let service = service(limit_repo, MockSaldoRepositoryTrait::new());
+
+    let response = service.get_user_limits(1).await.unwrap();
+
+    assert_eq!(response.data.kyc_level, 0);
+    assert_eq!(response.data.limits.len(), 2);
+}
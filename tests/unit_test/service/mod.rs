@@ -1,8 +1,25 @@
+mod audit;
+mod bank_account;
+mod beneficiary;
+mod fee;
+mod fraud;
 mod hashing;
+mod health;
 mod jwt;
+mod kyc;
+mod limit;
+mod payment_intent;
+mod qris;
+mod refund;
 mod saldo;
+mod scheduled_transfer;
+mod screening;
+mod settlement;
 mod transfer;
+mod transfer_batch;
 mod user;
+mod virtual_account;
+mod webhook;
 mod withdraw;
 
 mod topup;
@@ -0,0 +1,288 @@
+use std::sync::Arc;
+
+use chrono::{Duration, NaiveDateTime, TimeZone, Utc};
+use example_payment_gateway_axum::{
+    abstract_trait::{
+        clock::MockClockTrait,
+        merchant::MockMerchantRepositoryTrait,
+        payment_intent::{MockPaymentIntentRepositoryTrait, PaymentIntentServiceTrait},
+        transfer::MockTransferServiceTrait,
+    },
+    domain::{
+        request::{limit::MinimumAmounts, payment_intent::CreatePaymentIntentRequest},
+        response::{transfer::TransferResponse, ApiResponse, ErrorResponse},
+    },
+    entities::{merchants, payment_intents},
+    services::payment_intent::PaymentIntentService,
+    utils::errors::AppError,
+};
+use mockall::predicate;
+
+fn now() -> NaiveDateTime {
+    Utc.with_ymd_and_hms(2026, 10, 19, 9, 0, 0)
+        .unwrap()
+        .naive_utc()
+}
+
+fn mock_clock(now: NaiveDateTime) -> MockClockTrait {
+    let mut clock = MockClockTrait::new();
+    clock.expect_now().return_const(now);
+    clock
+}
+
+fn merchant() -> merchants::Model {
+    merchants::Model {
+        merchant_id: 3,
+        user_id: 2,
+        name: "Warung Sederhana".to_string(),
+        created_at: Some(now()),
+        updated_at: Some(now()),
+    }
+}
+
+fn payment_intent(status: &str, expires_at: NaiveDateTime) -> payment_intents::Model {
+    payment_intents::Model {
+        payment_intent_id: 1,
+        merchant_id: 3,
+        amount: 75000,
+        currency: "IDR".to_string(),
+        reference: "ORDER-1".to_string(),
+        status: status.to_string(),
+        expires_at,
+        customer_id: None,
+        transfer_id: None,
+        succeeded_at: None,
+        amount_refunded: 0,
+        settlement_id: None,
+        fraud_review_id: None,
+        created_at: Some(now()),
+        updated_at: Some(now()),
+    }
+}
+
+#[tokio::test]
+async fn test_create_payment_intent_rejects_past_expiry() {
+    let service = PaymentIntentService::new(
+        Arc::new(MockPaymentIntentRepositoryTrait::new()),
+        Arc::new(MockMerchantRepositoryTrait::new()),
+        Arc::new(MockTransferServiceTrait::new()),
+        Arc::new(mock_clock(now())),
+        MinimumAmounts::default(),
+    );
+
+    let error = service
+        .create_payment_intent(&CreatePaymentIntentRequest {
+            user_id: 2,
+            amount: 75000,
+            currency: "IDR".to_string(),
+            reference: "ORDER-1".to_string(),
+            expires_at: Utc.from_utc_datetime(&(now() - Duration::minutes(1))),
+            })
+        .await
+        .unwrap_err();
+
+    assert_eq!(error.message, "Expiry must be in the future");
+}
+
+#[tokio::test]
+async fn test_confirm_payment_intent_transfers_to_merchant() {
+    let expires_at = now() + Duration::minutes(30);
+
+    let mut mock_intent_repo = MockPaymentIntentRepositoryTrait::new();
+    let mut mock_merchant_repo = MockMerchantRepositoryTrait::new();
+    let mut mock_transfer_service = MockTransferServiceTrait::new();
+
+    mock_intent_repo
+        .expect_find_by_id()
+        .with(predicate::eq(1))
+        .return_once(move |_| Ok(Some(payment_intent("requires_confirmation", expires_at))));
+    mock_intent_repo
+        .expect_transition_status()
+        .with(
+            predicate::eq(1),
+            predicate::eq("requires_confirmation"),
+            predicate::eq("processing"),
+        )
+        .return_once(|_, _, _| Ok(true));
+    mock_intent_repo
+        .expect_record_success()
+        .with(predicate::eq(1), predicate::eq(5), predicate::eq(9))
+        .return_once(move |_, customer_id, transfer_id| {
+            let mut succeeded = payment_intent("succeeded", expires_at);
+            succeeded.customer_id = Some(customer_id);
+            succeeded.transfer_id = Some(transfer_id);
+            Ok(succeeded)
+        });
+
+    mock_merchant_repo
+        .expect_find_by_id()
+        .with(predicate::eq(3))
+        .return_once(|_| Ok(Some(merchant())));
+
+    mock_transfer_service
+        .expect_create_transfer()
+        .withf(|input| {
+            input.transfer_from == 5
+                && input.transfer_to == 2
+                && input.transfer_amount == 75000
+                && input.merchant_payment
+        })
+        .return_once(|input| {
+            Ok(ApiResponse {
+                status: "success".to_string(),
+                message: "Transfer created successfully".to_string(),
+                data: TransferResponse {
+                    transfer_id: 9,
+                    transfer_from: input.transfer_from,
+                    transfer_to: input.transfer_to,
+                    transfer_amount: input.transfer_amount,
+                    transfer_time: Utc::now(),
+                    fee_amount: 0,
+                    created_at: None,
+                    updated_at: None,
+                },
+            })
+        });
+
+    let service = PaymentIntentService::new(
+        Arc::new(mock_intent_repo),
+        Arc::new(mock_merchant_repo),
+        Arc::new(mock_transfer_service),
+        Arc::new(mock_clock(now())),
+        MinimumAmounts::default(),
+    );
+
+    let response = service.confirm_payment_intent(1, 5).await.unwrap();
+
+    assert_eq!(response.data.status, "succeeded");
+    assert_eq!(response.data.transfer_id, Some(9));
+}
+
+#[tokio::test]
+async fn test_confirm_held_for_review_keeps_payment_intent_processing() {
+    let expires_at = now() + Duration::minutes(30);
+
+    let mut mock_intent_repo = MockPaymentIntentRepositoryTrait::new();
+    let mut mock_merchant_repo = MockMerchantRepositoryTrait::new();
+    let mut mock_transfer_service = MockTransferServiceTrait::new();
+
+    mock_intent_repo
+        .expect_find_by_id()
+        .return_once(move |_| Ok(Some(payment_intent("requires_confirmation", expires_at))));
+    mock_intent_repo
+        .expect_transition_status()
+        .with(
+            predicate::eq(1),
+            predicate::eq("requires_confirmation"),
+            predicate::eq("processing"),
+        )
+        .times(1)
+        .return_once(|_, _, _| Ok(true));
+    mock_intent_repo
+        .expect_hold_for_review()
+        .with(predicate::eq(1), predicate::eq(5), predicate::eq(7))
+        .times(1)
+        .return_once(|_, _, _| Ok(true));
+    mock_intent_repo.expect_record_success().never();
+
+    mock_merchant_repo
+        .expect_find_by_id()
+        .return_once(|_| Ok(Some(merchant())));
+
+    mock_transfer_service
+        .expect_create_transfer()
+        .return_once(|_| {
+            Err(ErrorResponse::from(AppError::HeldForReview(
+                7,
+                "The transfer is held for review under fraud review 7".to_string(),
+            )))
+        });
+
+    let service = PaymentIntentService::new(
+        Arc::new(mock_intent_repo),
+        Arc::new(mock_merchant_repo),
+        Arc::new(mock_transfer_service),
+        Arc::new(mock_clock(now())),
+        MinimumAmounts::default(),
+    );
+
+    let error = service.confirm_payment_intent(1, 5).await.unwrap_err();
+
+    assert_eq!(error.status, "pending");
+}
+
+#[tokio::test]
+async fn test_confirm_expired_payment_intent_is_rejected() {
+    let expires_at = now() - Duration::minutes(1);
+
+    let mut mock_intent_repo = MockPaymentIntentRepositoryTrait::new();
+    let mut mock_transfer_service = MockTransferServiceTrait::new();
+
+    let mut lookups = 0;
+    mock_intent_repo
+        .expect_find_by_id()
+        .times(2)
+        .returning(move |_| {
+            lookups += 1;
+            let status = if lookups == 1 {
+                "requires_confirmation"
+            } else {
+                "expired"
+            };
+            Ok(Some(payment_intent(status, expires_at)))
+        });
+    mock_intent_repo
+        .expect_transition_status()
+        .with(
+            predicate::eq(1),
+            predicate::eq("requires_confirmation"),
+            predicate::eq("expired"),
+        )
+        .times(1)
+        .return_once(|_, _, _| Ok(true));
+    mock_transfer_service.expect_create_transfer().never();
+
+    let service = PaymentIntentService::new(
+        Arc::new(mock_intent_repo),
+        Arc::new(MockMerchantRepositoryTrait::new()),
+        Arc::new(mock_transfer_service),
+        Arc::new(mock_clock(now())),
+        MinimumAmounts::default(),
+    );
+
+    let error = service.confirm_payment_intent(1, 5).await.unwrap_err();
+
+    assert_eq!(error.message, "Payment intent is expired");
+}
+
+#[tokio::test]
+async fn test_cancel_payment_intent_requires_owning_merchant() {
+    let expires_at = now() + Duration::minutes(30);
+
+    let mut mock_intent_repo = MockPaymentIntentRepositoryTrait::new();
+    let mut mock_merchant_repo = MockMerchantRepositoryTrait::new();
+
+    mock_merchant_repo.expect_find_by_user().return_once(|_| {
+        Ok(Some(merchants::Model {
+            merchant_id: 4,
+            user_id: 7,
+            ..merchant()
+        }))
+    });
+    mock_intent_repo
+        .expect_find_by_id()
+        .return_once(move |_| Ok(Some(payment_intent("requires_confirmation", expires_at))));
+    mock_intent_repo.expect_transition_status().never();
+
+    let service = PaymentIntentService::new(
+        Arc::new(mock_intent_repo),
+        Arc::new(mock_merchant_repo),
+        Arc::new(MockTransferServiceTrait::new()),
+        Arc::new(mock_clock(now())),
+        MinimumAmounts::default(),
+    );
+
+    let error = service.cancel_payment_intent(1, 7).await.unwrap_err();
+
+    assert!(error.message.contains("not found"));
+}
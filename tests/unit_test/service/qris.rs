@@ -0,0 +1,323 @@
+use std::sync::Arc;
+
+use chrono::Utc;
+use example_payment_gateway_axum::{
+    abstract_trait::{
+        merchant::MockMerchantRepositoryTrait,
+        qris::{MockQrisRepositoryTrait, QrisServiceTrait},
+        transfer::MockTransferServiceTrait,
+        user::MockUserRepositoryTrait,
+    },
+    domain::{
+        request::{
+            limit::MinimumAmounts,
+            qris::{CreateQrisRequest, PayQrisRequest},
+        },
+        response::{transfer::TransferResponse, ApiResponse, ErrorKind, ErrorResponse},
+    },
+    entities::qris_payments,
+    services::qris::QrisService,
+    utils::{
+        errors::AppError,
+        qr_image::{render_png, render_svg},
+        qris::{build_dynamic_qris, crc16_ccitt, decode_tlv, parse_qris, QrisMerchant},
+    },
+};
+use mockall::predicate;
+
+fn merchant() -> QrisMerchant {
+    QrisMerchant {
+        merchant_pan: "4111111111111111".to_string(),
+        merchant_id: "ID0000000000002".to_string(),
+        name: "Warung Sederhana".to_string(),
+        city: "JAKARTA".to_string(),
+        postal_code: "10110".to_string(),
+    }
+}
+
+fn issued_qris(payload: &str) -> qris_payments::Model {
+    qris_payments::Model {
+        qris_payment_id: 1,
+        user_id: 2,
+        merchant_id: Some(3),
+        reference: "QRREF1".to_string(),
+        amount: 75000,
+        payload: payload.to_string(),
+        status: "pending".to_string(),
+        payer_id: None,
+        transfer_id: None,
+        paid_at: None,
+        settlement_id: None,
+        fraud_review_id: None,
+        created_at: Some(Utc::now().naive_utc()),
+        updated_at: Some(Utc::now().naive_utc()),
+    }
+}
+
+#[test]
+fn test_crc16_ccitt_check_value() {
+    assert_eq!(crc16_ccitt(b"123456789"), 0x29B1);
+}
+
+#[test]
+fn test_dynamic_qris_round_trip() {
+    let payload = build_dynamic_qris(&merchant(), 75000, "QRREF1").unwrap();
+
+    assert!(payload.starts_with("000201010212"));
+    assert_eq!(&payload[payload.len() - 8..payload.len() - 4], "6304");
+
+    let fields = decode_tlv(&payload).unwrap();
+    assert_eq!(fields.last().unwrap().tag, "63");
+
+    let decoded = parse_qris(&payload).unwrap();
+    assert!(decoded.dynamic);
+    assert_eq!(decoded.amount, Some(75000));
+    assert_eq!(decoded.reference.as_deref(), Some("QRREF1"));
+    assert_eq!(decoded.merchant_pan.as_deref(), Some("4111111111111111"));
+    assert_eq!(decoded.merchant_name.as_deref(), Some("Warung Sederhana"));
+}
+
+#[test]
+fn test_parse_qris_rejects_bad_crc() {
+    let payload = build_dynamic_qris(&merchant(), 75000, "QRREF1").unwrap();
+    let tampered = payload.replace("540575000", "540515000");
+
+    assert_eq!(
+        parse_qris(&tampered).unwrap_err(),
+        "Payload CRC does not match"
+    );
+}
+
+#[test]
+fn test_qris_images_render() {
+    let payload = build_dynamic_qris(&merchant(), 75000, "QRREF1").unwrap();
+
+    let png = render_png(&payload, 4).unwrap();
+    assert_eq!(&png[..8], &[0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A]);
+
+    let svg = render_svg(&payload).unwrap();
+    assert!(svg.contains("<svg"));
+}
+
+#[tokio::test]
+async fn test_create_qris_requires_a_merchant() {
+    let mut mock_merchant_repo = MockMerchantRepositoryTrait::new();
+    let mut mock_qris_repo = MockQrisRepositoryTrait::new();
+
+    mock_merchant_repo
+        .expect_find_by_user()
+        .with(predicate::eq(2))
+        .return_once(|_| Ok(None));
+    mock_qris_repo.expect_create().never();
+
+    let service = QrisService::new(
+        Arc::new(mock_qris_repo),
+        Arc::new(mock_merchant_repo),
+        Arc::new(MockUserRepositoryTrait::new()),
+        Arc::new(MockTransferServiceTrait::new()),
+        MinimumAmounts::default(),
+    );
+
+    let error = service
+        .create_qris(&CreateQrisRequest {
+            user_id: 2,
+            amount: 75000,
+        })
+        .await
+        .unwrap_err();
+
+    assert_eq!(error.message, "Merchant for user 2 not found");
+}
+
+#[tokio::test]
+async fn test_pay_qris_transfers_to_merchant() {
+    let payload = build_dynamic_qris(&merchant(), 75000, "QRREF1").unwrap();
+    let issued = issued_qris(&payload);
+
+    let mut mock_qris_repo = MockQrisRepositoryTrait::new();
+    let mut mock_transfer_service = MockTransferServiceTrait::new();
+
+    mock_qris_repo
+        .expect_find_by_reference()
+        .with(predicate::eq("QRREF1"))
+        .return_once(move |_| Ok(Some(issued)));
+    mock_qris_repo
+        .expect_transition_status()
+        .with(
+            predicate::eq(1),
+            predicate::eq("pending"),
+            predicate::eq("processing"),
+        )
+        .return_once(|_, _, _| Ok(true));
+
+    mock_transfer_service
+        .expect_create_transfer()
+        .withf(|input| {
+            input.transfer_from == 5
+                && input.transfer_to == 2
+                && input.transfer_amount == 75000
+                && input.merchant_payment
+        })
+        .return_once(|input| {
+            Ok(ApiResponse {
+                status: "success".to_string(),
+                message: "Transfer created successfully".to_string(),
+                data: TransferResponse {
+                    transfer_id: 9,
+                    transfer_from: input.transfer_from,
+                    transfer_to: input.transfer_to,
+                    transfer_amount: input.transfer_amount,
+                    transfer_time: Utc::now(),
+                    fee_amount: 0,
+                    created_at: None,
+                    updated_at: None,
+                },
+            })
+        });
+
+    let paid_payload = payload.clone();
+    mock_qris_repo
+        .expect_record_payment()
+        .with(predicate::eq(1), predicate::eq(5), predicate::eq(9))
+        .return_once(move |_, payer_id, transfer_id| {
+            let mut paid = issued_qris(&paid_payload);
+            paid.status = "paid".to_string();
+            paid.payer_id = Some(payer_id);
+            paid.transfer_id = Some(transfer_id);
+            Ok(paid)
+        });
+
+    let service = QrisService::new(
+        Arc::new(mock_qris_repo),
+        Arc::new(MockMerchantRepositoryTrait::new()),
+        Arc::new(MockUserRepositoryTrait::new()),
+        Arc::new(mock_transfer_service),
+        MinimumAmounts::default(),
+    );
+
+    let response = service
+        .pay_qris(&PayQrisRequest {
+            payer_id: 5,
+            payload,
+        })
+        .await
+        .unwrap();
+
+    assert_eq!(response.data.status, "paid");
+    assert_eq!(response.data.transfer_id, Some(9));
+}
+
+#[tokio::test]
+async fn test_pay_qris_reopens_after_failed_transfer() {
+    let payload = build_dynamic_qris(&merchant(), 75000, "QRREF1").unwrap();
+    let issued = issued_qris(&payload);
+
+    let mut mock_qris_repo = MockQrisRepositoryTrait::new();
+    let mut mock_transfer_service = MockTransferServiceTrait::new();
+
+    mock_qris_repo
+        .expect_find_by_reference()
+        .return_once(move |_| Ok(Some(issued)));
+    mock_qris_repo
+        .expect_transition_status()
+        .with(
+            predicate::eq(1),
+            predicate::eq("pending"),
+            predicate::eq("processing"),
+        )
+        .return_once(|_, _, _| Ok(true));
+    mock_qris_repo
+        .expect_transition_status()
+        .with(
+            predicate::eq(1),
+            predicate::eq("processing"),
+            predicate::eq("pending"),
+        )
+        .times(1)
+        .return_once(|_, _, _| Ok(true));
+    mock_qris_repo.expect_record_payment().never();
+
+    mock_transfer_service
+        .expect_create_transfer()
+        .return_once(|_| {
+            Err(ErrorResponse {
+                status: "error".to_string(),
+                message: "Insufficient balance".to_string(),
+                request_id: None,
+                kind: ErrorKind::InsufficientBalance,
+            })
+        });
+
+    let service = QrisService::new(
+        Arc::new(mock_qris_repo),
+        Arc::new(MockMerchantRepositoryTrait::new()),
+        Arc::new(MockUserRepositoryTrait::new()),
+        Arc::new(mock_transfer_service),
+        MinimumAmounts::default(),
+    );
+
+    let error = service
+        .pay_qris(&PayQrisRequest {
+            payer_id: 5,
+            payload,
+        })
+        .await
+        .unwrap_err();
+
+    assert_eq!(error.message, "Insufficient balance");
+}
+
+#[tokio::test]
+async fn test_pay_qris_held_for_review_stays_processing() {
+    let payload = build_dynamic_qris(&merchant(), 75000, "QRREF1").unwrap();
+    let issued = issued_qris(&payload);
+
+    let mut mock_qris_repo = MockQrisRepositoryTrait::new();
+    let mut mock_transfer_service = MockTransferServiceTrait::new();
+
+    mock_qris_repo
+        .expect_find_by_reference()
+        .return_once(move |_| Ok(Some(issued)));
+    mock_qris_repo
+        .expect_transition_status()
+        .with(
+            predicate::eq(1),
+            predicate::eq("pending"),
+            predicate::eq("processing"),
+        )
+        .times(1)
+        .return_once(|_, _, _| Ok(true));
+    mock_qris_repo
+        .expect_hold_for_review()
+        .with(predicate::eq(1), predicate::eq(5), predicate::eq(7))
+        .times(1)
+        .return_once(|_, _, _| Ok(true));
+    mock_qris_repo.expect_record_payment().never();
+
+    mock_transfer_service
+        .expect_create_transfer()
+        .return_once(|_| {
+            Err(ErrorResponse::from(AppError::HeldForReview(
+                7,
+                "The transfer is held for review under fraud review 7".to_string(),
+            )))
+        });
+
+    let service = QrisService::new(
+        Arc::new(mock_qris_repo),
+        Arc::new(MockMerchantRepositoryTrait::new()),
+        Arc::new(MockUserRepositoryTrait::new()),
+        Arc::new(mock_transfer_service),
+        MinimumAmounts::default(),
+    );
+
+    let error = service
+        .pay_qris(&PayQrisRequest {
+            payer_id: 5,
+            payload,
+        })
+        .await
+        .unwrap_err();
+
+    assert_eq!(error.status, "pending");
+}
@@ -0,0 +1,223 @@
+use std::sync::Arc;
+
+use chrono::Utc;
+use example_payment_gateway_axum::{
+    abstract_trait::{
+        merchant::MockMerchantRepositoryTrait,
+        payment_intent::MockPaymentIntentRepositoryTrait,
+        refund::{MockRefundRepositoryTrait, RefundServiceTrait},
+        saldo::MockSaldoRepositoryTrait,
+    },
+    domain::request::refund::CreateRefundRequest,
+    entities::{merchants, payment_intents, refunds, saldo},
+    services::refund::RefundService,
+};
+use mockall::predicate;
+
+fn merchant() -> merchants::Model {
+    merchants::Model {
+        merchant_id: 3,
+        user_id: 2,
+        name: "Warung Sederhana".to_string(),
+        created_at: Some(Utc::now().naive_utc()),
+        updated_at: Some(Utc::now().naive_utc()),
+    }
+}
+
+fn payment(amount_refunded: i32) -> payment_intents::Model {
+    payment_intents::Model {
+        payment_intent_id: 1,
+        merchant_id: 3,
+        amount: 75000,
+        currency: "IDR".to_string(),
+        reference: "ORDER-1".to_string(),
+        status: "succeeded".to_string(),
+        expires_at: Utc::now().naive_utc(),
+        customer_id: Some(5),
+        transfer_id: Some(9),
+        succeeded_at: Some(Utc::now().naive_utc()),
+        amount_refunded,
+        settlement_id: None,
+        fraud_review_id: None,
+        created_at: Some(Utc::now().naive_utc()),
+        updated_at: Some(Utc::now().naive_utc()),
+    }
+}
+
+fn refund(status: &str) -> refunds::Model {
+    refunds::Model {
+        refund_id: 11,
+        payment_intent_id: 1,
+        amount: 25000,
+        reason: "Item out of stock".to_string(),
+        status: status.to_string(),
+        failure_reason: None,
+        refunded_at: None,
+        settlement_id: None,
+        created_at: Some(Utc::now().naive_utc()),
+        updated_at: Some(Utc::now().naive_utc()),
+    }
+}
+
+fn saldo_of(user_id: i32, total_balance: i32) -> saldo::Model {
+    saldo::Model {
+        saldo_id: user_id,
+        user_id,
+        total_balance,
+        withdraw_amount: None,
+        withdraw_time: None,
+        created_at: Some(Utc::now().naive_utc()),
+        updated_at: Some(Utc::now().naive_utc()),
+    }
+}
+
+fn refund_request(amount: i32) -> CreateRefundRequest {
+    CreateRefundRequest {
+        payment_intent_id: 1,
+        user_id: 2,
+        amount,
+        reason: "Item out of stock".to_string(),
+    }
+}
+
+fn owning_merchant() -> MockMerchantRepositoryTrait {
+    let mut mock_merchant_repo = MockMerchantRepositoryTrait::new();
+    mock_merchant_repo
+        .expect_find_by_user()
+        .with(predicate::eq(2))
+        .returning(|_| Ok(Some(merchant())));
+    mock_merchant_repo
+}
+
+#[tokio::test]
+async fn test_partial_refund_moves_saldo_back_to_customer() {
+    let mut mock_intent_repo = MockPaymentIntentRepositoryTrait::new();
+    let mut mock_refund_repo = MockRefundRepositoryTrait::new();
+    let mut mock_saldo_repo = MockSaldoRepositoryTrait::new();
+
+    mock_intent_repo
+        .expect_find_by_id()
+        .return_once(|_| Ok(Some(payment(0))));
+    mock_intent_repo
+        .expect_reserve_refund()
+        .with(predicate::eq(1), predicate::eq(25000))
+        .return_once(|_, _| Ok(true));
+    mock_intent_repo.expect_release_refund().never();
+
+    mock_refund_repo
+        .expect_create()
+        .return_once(|_| Ok(refund("pending")));
+    mock_refund_repo
+        .expect_mark_succeeded()
+        .with(predicate::eq(11))
+        .return_once(|_| Ok(refund("succeeded")));
+
+    mock_saldo_repo
+        .expect_find_by_user_id()
+        .with(predicate::eq(2))
+        .return_once(|user_id| Ok(Some(saldo_of(user_id, 100000))));
+    mock_saldo_repo
+        .expect_find_by_user_id()
+        .with(predicate::eq(5))
+        .return_once(|user_id| Ok(Some(saldo_of(user_id, 10000))));
+    mock_saldo_repo
+        .expect_update_balance()
+        .withf(|input| input.user_id == 2 && input.total_balance == 75000)
+        .times(1)
+        .return_once(|input| Ok(saldo_of(input.user_id, input.total_balance)));
+    mock_saldo_repo
+        .expect_update_balance()
+        .withf(|input| input.user_id == 5 && input.total_balance == 35000)
+        .times(1)
+        .return_once(|input| Ok(saldo_of(input.user_id, input.total_balance)));
+
+    let service = RefundService::new(
+        Arc::new(mock_refund_repo),
+        Arc::new(mock_intent_repo),
+        Arc::new(owning_merchant()),
+        Arc::new(mock_saldo_repo),
+    );
+
+    let response = service.create_refund(&refund_request(25000)).await.unwrap();
+
+    assert_eq!(response.data.status, "succeeded");
+    assert_eq!(response.data.amount, 25000);
+}
+
+#[tokio::test]
+async fn test_refund_beyond_refundable_amount_is_rejected() {
+    let mut mock_intent_repo = MockPaymentIntentRepositoryTrait::new();
+    let mut mock_refund_repo = MockRefundRepositoryTrait::new();
+
+    mock_intent_repo
+        .expect_find_by_id()
+        .return_once(|_| Ok(Some(payment(60000))));
+    mock_intent_repo
+        .expect_reserve_refund()
+        .return_once(|_, _| Ok(false));
+    mock_refund_repo.expect_create().never();
+
+    let service = RefundService::new(
+        Arc::new(mock_refund_repo),
+        Arc::new(mock_intent_repo),
+        Arc::new(owning_merchant()),
+        Arc::new(MockSaldoRepositoryTrait::new()),
+    );
+
+    let error = service
+        .create_refund(&refund_request(25000))
+        .await
+        .unwrap_err();
+
+    assert_eq!(error.message, "Refund amount exceeds the refundable amount");
+}
+
+#[tokio::test]
+async fn test_failed_refund_releases_reservation() {
+    let mut mock_intent_repo = MockPaymentIntentRepositoryTrait::new();
+    let mut mock_refund_repo = MockRefundRepositoryTrait::new();
+    let mut mock_saldo_repo = MockSaldoRepositoryTrait::new();
+
+    mock_intent_repo
+        .expect_find_by_id()
+        .return_once(|_| Ok(Some(payment(0))));
+    mock_intent_repo
+        .expect_reserve_refund()
+        .return_once(|_, _| Ok(true));
+    mock_intent_repo
+        .expect_release_refund()
+        .with(predicate::eq(1), predicate::eq(25000))
+        .times(1)
+        .return_once(|_, _| Ok(()));
+
+    mock_refund_repo
+        .expect_create()
+        .return_once(|_| Ok(refund("pending")));
+    mock_refund_repo
+        .expect_mark_failed()
+        .with(predicate::eq(11), predicate::always())
+        .times(1)
+        .return_once(|_, _| Ok(refund("failed")));
+    mock_refund_repo.expect_mark_succeeded().never();
+
+    // The merchant has already spent the money.
+    mock_saldo_repo
+        .expect_find_by_user_id()
+        .with(predicate::eq(2))
+        .return_once(|user_id| Ok(Some(saldo_of(user_id, 10000))));
+    mock_saldo_repo.expect_update_balance().never();
+
+    let service = RefundService::new(
+        Arc::new(mock_refund_repo),
+        Arc::new(mock_intent_repo),
+        Arc::new(owning_merchant()),
+        Arc::new(mock_saldo_repo),
+    );
+
+    let error = service
+        .create_refund(&refund_request(25000))
+        .await
+        .unwrap_err();
+
+    assert_eq!(error.message, "Insufficient balance");
+}
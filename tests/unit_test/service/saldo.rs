@@ -1,21 +1,21 @@
 use std::sync::Arc;
 
-use chrono::{DateTime, Utc};
+use chrono::Utc;
 use example_payment_gateway_axum::{
     abstract_trait::{
         saldo::{MockSaldoRepositoryTrait, SaldoServiceTrait},
-        user::{MockUserRepositoryTrait, UserRepositoryTrait},
+        user::MockUserRepositoryTrait,
     },
     domain::request::saldo::{CreateSaldoRequest, UpdateSaldoRequest},
-    entities::{saldo, users, withdraws},
-    services::{saldo::SaldoService, withdraw::WithdrawService},
+    entities::{saldo, users},
+    services::saldo::SaldoService,
 };
 use mockall::predicate;
 
 #[tokio::test]
 async fn test_get_saldos() {
     let mut mock_saldo_repo = MockSaldoRepositoryTrait::new();
-    let mut mock_user_repo = MockUserRepositoryTrait::new();
+    let mock_user_repo = MockUserRepositoryTrait::new();
 
     let mock_saldos = vec![
         saldo::Model {
@@ -24,8 +24,8 @@ async fn test_get_saldos() {
             total_balance: 100000,
             withdraw_amount: None,
             withdraw_time: None,
-            created_at: Some(DateTime::<Utc>::from(Utc::now()).naive_utc()),
-            updated_at: Some(DateTime::<Utc>::from(Utc::now()).naive_utc()),
+            created_at: Some(Utc::now().naive_utc()),
+            updated_at: Some(Utc::now().naive_utc()),
         },
         saldo::Model {
             saldo_id: 2,
@@ -33,8 +33,8 @@ async fn test_get_saldos() {
             total_balance: 200000,
             withdraw_amount: None,
             withdraw_time: None,
-            created_at: Some(DateTime::<Utc>::from(Utc::now()).naive_utc()),
-            updated_at: Some(DateTime::<Utc>::from(Utc::now()).naive_utc()),
+            created_at: Some(Utc::now().naive_utc()),
+            updated_at: Some(Utc::now().naive_utc()),
         },
     ];
 
@@ -67,7 +67,7 @@ async fn test_get_saldos() {
 #[tokio::test]
 async fn test_find_by_id_success() {
     let mut mock_saldo_repo = MockSaldoRepositoryTrait::new();
-    let mut mock_user_repo = MockUserRepositoryTrait::new();
+    let mock_user_repo = MockUserRepositoryTrait::new();
 
     let mock_saldo = saldo::Model {
         saldo_id: 1,
@@ -75,8 +75,8 @@ async fn test_find_by_id_success() {
         total_balance: 100000,
         withdraw_amount: None,
         withdraw_time: None,
-        created_at: Some(DateTime::<Utc>::from(Utc::now()).naive_utc()),
-        updated_at: Some(DateTime::<Utc>::from(Utc::now()).naive_utc()),
+        created_at: Some(Utc::now().naive_utc()),
+        updated_at: Some(Utc::now().naive_utc()),
     };
 
     mock_saldo_repo
@@ -107,7 +107,7 @@ async fn test_find_by_id_success() {
 #[tokio::test]
 async fn test_find_by_id_not_found() {
     let mut mock_saldo_repo = MockSaldoRepositoryTrait::new();
-    let mut mock_user_repo = MockUserRepositoryTrait::new();
+    let mock_user_repo = MockUserRepositoryTrait::new();
 
     mock_saldo_repo
         .expect_find_by_id()
@@ -144,6 +144,8 @@ async fn test_get_saldo_users() {
                 email: "john.doe@example.com".to_string(),
                 password: "hashed_password".to_string(),
                 noc_transfer: "12345".to_string(),
+                kyc_level: 0,
+                role: "user".to_string(),
                 created_at: Some(Utc::now().naive_utc()),
                 updated_at: Some(Utc::now().naive_utc()),
             }))
@@ -212,6 +214,8 @@ async fn test_get_saldo_users_no_saldos() {
                 email: "john.doe@example.com".to_string(),
                 password: "hashed_password".to_string(),
                 noc_transfer: "12345".to_string(),
+                kyc_level: 0,
+                role: "user".to_string(),
                 created_at: Some(Utc::now().naive_utc()),
                 updated_at: Some(Utc::now().naive_utc()),
             }))
@@ -258,6 +262,8 @@ async fn test_get_saldo_user_success() {
                 email: "john.doe@example.com".to_string(),
                 password: "hashed_password".to_string(),
                 noc_transfer: "12345".to_string(),
+                kyc_level: 0,
+                role: "user".to_string(),
                 created_at: Some(Utc::now().naive_utc()),
                 updated_at: Some(Utc::now().naive_utc()),
             }))
@@ -318,6 +324,8 @@ async fn test_get_saldo_user_not_found() {
                 email: "john.doe@example.com".to_string(),
                 password: "hashed_password".to_string(),
                 noc_transfer: "12345".to_string(),
+                kyc_level: 0,
+                role: "user".to_string(),
                 created_at: Some(Utc::now().naive_utc()),
                 updated_at: Some(Utc::now().naive_utc()),
             }))
@@ -377,6 +385,8 @@ async fn test_create_saldo_success() {
                 email: "john.doe@example.com".to_string(),
                 password: "hashed_password".to_string(),
                 noc_transfer: "12345".to_string(),
+                kyc_level: 0,
+                role: "user".to_string(),
                 created_at: Some(Utc::now().naive_utc()),
                 updated_at: Some(Utc::now().naive_utc()),
             }))
@@ -475,6 +485,8 @@ async fn test_update_saldo_success() {
                 email: "john.doe@example.com".to_string(),
                 password: "hashed_password".to_string(),
                 noc_transfer: "12345".to_string(),
+                kyc_level: 0,
+                role: "user".to_string(),
                 created_at: Some(Utc::now().naive_utc()),
                 updated_at: Some(Utc::now().naive_utc()),
             }))
@@ -553,6 +565,8 @@ async fn test_delete_saldo_success() {
                 email: "john.doe@example.com".to_string(),
                 password: "hashed_password".to_string(),
                 noc_transfer: "12345".to_string(),
+                kyc_level: 0,
+                role: "user".to_string(),
                 created_at: Some(Utc::now().naive_utc()),
                 updated_at: Some(Utc::now().naive_utc()),
             }))
@@ -608,6 +622,8 @@ async fn test_delete_saldo_not_found() {
                 email: "john.doe@example.com".to_string(),
                 password: "hashed_password".to_string(),
                 noc_transfer: "12345".to_string(),
+                kyc_level: 0,
+                role: "user".to_string(),
                 created_at: Some(Utc::now().naive_utc()),
                 updated_at: Some(Utc::now().naive_utc()),
             }))
@@ -0,0 +1,403 @@
+use std::sync::Arc;
+
+use chrono::{NaiveDate, NaiveDateTime, TimeZone, Utc};
+use example_payment_gateway_axum::{
+    abstract_trait::{
+        clock::MockClockTrait,
+        scheduled_transfer::{MockScheduledTransferRepositoryTrait, ScheduledTransferServiceTrait},
+        transfer::MockTransferServiceTrait,
+        user::MockUserRepositoryTrait,
+    },
+    domain::{
+        request::{limit::MinimumAmounts, scheduled_transfer::CreateScheduledTransferRequest},
+        response::{transfer::TransferResponse, ApiResponse, ErrorKind, ErrorResponse},
+    },
+    entities::{scheduled_transfer_attempts, scheduled_transfers},
+    services::scheduled_transfer::{RetryPolicy, ScheduledTransferService},
+    utils::schedule::{first_run_at, next_run_after},
+};
+use sea_orm::DbErr;
+
+fn at(year: i32, month: u32, day: u32, hour: u32) -> NaiveDateTime {
+    NaiveDate::from_ymd_opt(year, month, day)
+        .unwrap()
+        .and_hms_opt(hour, 0, 0)
+        .unwrap()
+}
+
+fn mock_clock(now: NaiveDateTime) -> MockClockTrait {
+    let mut clock = MockClockTrait::new();
+    clock.expect_now().return_const(now);
+    clock
+}
+
+fn mock_schedule(
+    frequency: &str,
+    day_of_month: Option<i32>,
+    start_at: NaiveDateTime,
+    next_run_at: NaiveDateTime,
+    retry_count: i32,
+) -> scheduled_transfers::Model {
+    scheduled_transfers::Model {
+        schedule_id: 1,
+        transfer_from: 1,
+        transfer_to: 2,
+        transfer_amount: 50000,
+        frequency: frequency.to_string(),
+        day_of_month,
+        start_at,
+        next_run_at,
+        status: "active".to_string(),
+        retry_count,
+        last_run_at: None,
+        created_at: Some(start_at),
+        updated_at: Some(start_at),
+    }
+}
+
+fn mock_attempt(status: &str, attempted_at: NaiveDateTime) -> scheduled_transfer_attempts::Model {
+    scheduled_transfer_attempts::Model {
+        attempt_id: 1,
+        schedule_id: 1,
+        status: status.to_string(),
+        transfer_id: None,
+        error_message: None,
+        attempted_at,
+        fraud_review_id: None,
+        created_at: Some(attempted_at),
+    }
+}
+
+fn insufficient_balance() -> ErrorResponse {
+    ErrorResponse {
+        status: "error".to_string(),
+        message: "Insufficient balance".to_string(),
+        request_id: None,
+        kind: ErrorKind::InsufficientBalance,
+    }
+}
+
+fn service(
+    repository: MockScheduledTransferRepositoryTrait,
+    transfer_service: MockTransferServiceTrait,
+    now: NaiveDateTime,
+) -> ScheduledTransferService {
+    ScheduledTransferService::new(
+        Arc::new(repository),
+        Arc::new(transfer_service),
+        Arc::new(MockUserRepositoryTrait::new()),
+        Arc::new(mock_clock(now)),
+        RetryPolicy::default(),
+        MinimumAmounts::default(),
+    )
+}
+
+#[test]
+fn test_monthly_schedule_clamps_to_last_day_of_month() {
+    let start_at = at(2026, 1, 5, 9);
+
+    let first = first_run_at("monthly", start_at, Some(31)).unwrap();
+    assert_eq!(first, at(2026, 1, 31, 9));
+
+    let second = next_run_after("monthly", start_at, Some(31), first).unwrap();
+    assert_eq!(second, at(2026, 2, 28, 9));
+
+    let third = next_run_after("monthly", start_at, Some(31), second).unwrap();
+    assert_eq!(third, at(2026, 3, 31, 9));
+}
+
+#[test]
+fn test_weekly_schedule_stays_anchored_after_retries() {
+    let start_at = at(2026, 10, 5, 8);
+
+    let next = next_run_after("weekly", start_at, None, at(2026, 10, 5, 11)).unwrap();
+
+    assert_eq!(next, at(2026, 10, 12, 8));
+    assert!(next_run_after("once", start_at, None, start_at).is_none());
+}
+
+#[tokio::test]
+async fn test_create_scheduled_transfer_in_the_past() {
+    let service = service(
+        MockScheduledTransferRepositoryTrait::new(),
+        MockTransferServiceTrait::new(),
+        at(2026, 10, 19, 12),
+    );
+
+    let request = CreateScheduledTransferRequest {
+        transfer_from: 1,
+        transfer_to: 2,
+        transfer_amount: 50000,
+        frequency: "once".to_string(),
+        start_at: Utc.from_utc_datetime(&at(2026, 10, 18, 12)),
+        day_of_month: None,
+    };
+
+    let error = service
+        .create_scheduled_transfer(&request)
+        .await
+        .unwrap_err();
+
+    assert_eq!(error.message, "Start time must be in the future");
+}
+
+#[tokio::test]
+async fn test_run_due_monthly_schedule_success() {
+    let now = at(2026, 10, 31, 9);
+    let mut mock_repo = MockScheduledTransferRepositoryTrait::new();
+    let mut mock_transfer_service = MockTransferServiceTrait::new();
+
+    mock_repo.expect_find_due().return_once(move |_, _| {
+        Ok(vec![mock_schedule(
+            "monthly",
+            Some(31),
+            at(2026, 9, 1, 9),
+            now,
+            0,
+        )])
+    });
+
+    mock_transfer_service
+        .expect_create_transfer()
+        .withf(|input| input.transfer_from == 1 && input.transfer_to == 2)
+        .return_once(|input| {
+            Ok(ApiResponse {
+                status: "success".to_string(),
+                message: "Transfer created successfully".to_string(),
+                data: TransferResponse {
+                    transfer_id: 7,
+                    transfer_from: input.transfer_from,
+                    transfer_to: input.transfer_to,
+                    transfer_amount: input.transfer_amount,
+                    transfer_time: Utc::now(),
+                    fee_amount: 0,
+                    created_at: None,
+                    updated_at: None,
+                },
+            })
+        });
+
+    mock_repo
+        .expect_claim_run()
+        .withf(move |input, due_at| {
+            *due_at == now
+                && input.status == "active"
+                && input.retry_count == 0
+                && input.next_run_at == at(2026, 11, 30, 9)
+        })
+        .return_once(|_, _| Ok(true));
+
+    mock_repo
+        .expect_create_attempt()
+        .withf(|input| input.status == "succeeded" && input.transfer_id == Some(7))
+        .return_once(move |_| Ok(mock_attempt("succeeded", now)));
+
+    mock_repo.expect_update_run().never();
+
+    let processed = service(mock_repo, mock_transfer_service, now)
+        .run_due_schedules()
+        .await
+        .unwrap();
+
+    assert_eq!(processed, 1);
+}
+
+#[tokio::test]
+async fn test_run_due_schedule_retries_insufficient_balance() {
+    let now = at(2026, 10, 19, 9);
+    let mut mock_repo = MockScheduledTransferRepositoryTrait::new();
+    let mut mock_transfer_service = MockTransferServiceTrait::new();
+
+    mock_repo
+        .expect_find_due()
+        .return_once(move |_, _| Ok(vec![mock_schedule("weekly", None, now, now, 1)]));
+
+    mock_repo.expect_claim_run().return_once(|_, _| Ok(true));
+
+    mock_transfer_service
+        .expect_create_transfer()
+        .return_once(|_| Err(insufficient_balance()));
+
+    mock_repo
+        .expect_create_attempt()
+        .withf(|input| {
+            input.status == "retry_scheduled"
+                && input.error_message.as_deref() == Some("Insufficient balance")
+        })
+        .return_once(move |_| Ok(mock_attempt("retry_scheduled", now)));
+
+    mock_repo
+        .expect_update_run()
+        .withf(|input| {
+            input.status == "active"
+                && input.retry_count == 2
+                && input.next_run_at == at(2026, 10, 19, 10)
+        })
+        .return_once(move |_| Ok(mock_schedule("weekly", None, now, now, 2)));
+
+    service(mock_repo, mock_transfer_service, now)
+        .run_due_schedules()
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_run_due_one_off_schedule_fails_after_retries() {
+    let now = at(2026, 10, 19, 12);
+    let start_at = at(2026, 10, 19, 9);
+    let mut mock_repo = MockScheduledTransferRepositoryTrait::new();
+    let mut mock_transfer_service = MockTransferServiceTrait::new();
+
+    mock_repo
+        .expect_find_due()
+        .return_once(move |_, _| Ok(vec![mock_schedule("once", None, start_at, now, 3)]));
+
+    mock_repo
+        .expect_claim_run()
+        .withf(|input, _| input.status == "completed")
+        .return_once(|_, _| Ok(true));
+
+    mock_transfer_service
+        .expect_create_transfer()
+        .return_once(|_| Err(insufficient_balance()));
+
+    mock_repo
+        .expect_create_attempt()
+        .withf(|input| input.status == "failed")
+        .return_once(move |_| Ok(mock_attempt("failed", now)));
+
+    mock_repo
+        .expect_update_run()
+        .withf(|input| input.status == "failed" && input.retry_count == 0)
+        .return_once(move |_| Ok(mock_schedule("once", None, start_at, now, 0)));
+
+    service(mock_repo, mock_transfer_service, now)
+        .run_due_schedules()
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_run_due_one_off_schedule_held_for_review_is_not_failed() {
+    let now = at(2026, 10, 19, 12);
+    let start_at = at(2026, 10, 19, 9);
+    let mut mock_repo = MockScheduledTransferRepositoryTrait::new();
+    let mut mock_transfer_service = MockTransferServiceTrait::new();
+
+    mock_repo
+        .expect_find_due()
+        .return_once(move |_, _| Ok(vec![mock_schedule("once", None, start_at, now, 0)]));
+
+    mock_repo
+        .expect_claim_run()
+        .withf(|input, _| input.status == "completed")
+        .return_once(|_, _| Ok(true));
+
+    mock_transfer_service
+        .expect_create_transfer()
+        .return_once(|_| {
+            Err(ErrorResponse {
+                status: "pending".to_string(),
+                message: "The transfer is held for review under fraud review 7".to_string(),
+                request_id: None,
+                kind: ErrorKind::HeldForReview(7),
+            })
+        });
+
+    mock_repo
+        .expect_create_attempt()
+        .withf(|input| input.status == "held" && input.fraud_review_id == Some(7))
+        .times(1)
+        .return_once(move |_| Ok(mock_attempt("held", now)));
+
+    // An analyst can still approve the transfer
+    mock_repo.expect_update_run().never();
+
+    service(mock_repo, mock_transfer_service, now)
+        .run_due_schedules()
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_run_due_schedules_continues_after_a_failed_run() {
+    let now = at(2026, 10, 19, 9);
+    let mut mock_repo = MockScheduledTransferRepositoryTrait::new();
+    let mut mock_transfer_service = MockTransferServiceTrait::new();
+
+    mock_repo.expect_find_due().return_once(move |_, _| {
+        let first = mock_schedule("weekly", None, now, now, 0);
+        let second = scheduled_transfers::Model {
+            schedule_id: 2,
+            ..first.clone()
+        };
+        Ok(vec![first, second])
+    });
+
+    mock_repo
+        .expect_claim_run()
+        .withf(|input, _| input.schedule_id == 1)
+        .return_once(|_, _| Err(DbErr::Custom("connection reset".to_string())));
+
+    mock_repo
+        .expect_claim_run()
+        .withf(|input, _| input.schedule_id == 2)
+        .return_once(|_, _| Ok(true));
+
+    mock_transfer_service
+        .expect_create_transfer()
+        .times(1)
+        .return_once(|input| {
+            Ok(ApiResponse {
+                status: "success".to_string(),
+                message: "Transfer created successfully".to_string(),
+                data: TransferResponse {
+                    transfer_id: 10,
+                    transfer_from: input.transfer_from,
+                    transfer_to: input.transfer_to,
+                    transfer_amount: input.transfer_amount,
+                    transfer_time: Utc::now(),
+                    fee_amount: 0,
+                    created_at: None,
+                    updated_at: None,
+                },
+            })
+        });
+
+    mock_repo
+        .expect_create_attempt()
+        .withf(|input| input.schedule_id == 2 && input.status == "succeeded")
+        .return_once(move |_| Ok(mock_attempt("succeeded", now)));
+
+    let processed = service(mock_repo, mock_transfer_service, now)
+        .run_due_schedules()
+        .await
+        .unwrap();
+
+    assert_eq!(processed, 1);
+}
+
+#[tokio::test]
+async fn test_run_already_claimed_is_not_paid_again() {
+    let now = at(2026, 10, 19, 9);
+    let mut mock_repo = MockScheduledTransferRepositoryTrait::new();
+    let mut mock_transfer_service = MockTransferServiceTrait::new();
+
+    mock_repo
+        .expect_find_due()
+        .return_once(move |_, _| Ok(vec![mock_schedule("weekly", None, now, now, 0)]));
+
+    // Another scheduler advanced it between find_due and the claim
+    mock_repo.expect_claim_run().return_once(|_, _| Ok(false));
+
+    mock_transfer_service.expect_create_transfer().never();
+    mock_repo.expect_create_attempt().never();
+    mock_repo.expect_update_run().never();
+
+    let processed = service(mock_repo, mock_transfer_service, now)
+        .run_due_schedules()
+        .await
+        .unwrap();
+
+    assert_eq!(processed, 0);
+}
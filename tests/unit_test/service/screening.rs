@@ -0,0 +1,244 @@
+use std::sync::Arc;
+
+use example_payment_gateway_axum::{
+    abstract_trait::{
+        screening::{MockScreeningRepositoryTrait, ScreeningServiceTrait},
+        watchlist::MockWatchlistTrait,
+    },
+    domain::request::screening::{
+        CreateScreeningResult, ReviewScreeningResultRequest, ScreenNameRequest,
+    },
+    entities::screening_results,
+    services::screening::ScreeningService,
+    utils::{
+        errors::AppError,
+        watchlist::{WatchlistEntry, WatchlistMatch},
+    },
+};
+use mockall::predicate;
+
+fn found(entry_id: &str, matched_name: &str, score: i32) -> WatchlistMatch {
+    WatchlistMatch {
+        entry: WatchlistEntry {
+            entry_id: entry_id.to_string(),
+            name: matched_name.to_string(),
+            list: "UN".to_string(),
+            aliases: vec![],
+        },
+        matched_name: matched_name.to_string(),
+        score,
+    }
+}
+
+fn stored(input: &CreateScreeningResult, status: &str) -> screening_results::Model {
+    screening_results::Model {
+        screening_result_id: 7,
+        subject_type: input.subject_type.clone(),
+        subject_id: input.subject_id,
+        user_id: input.user_id,
+        screened_name: input.screened_name.clone(),
+        watchlist_entry_id: input.watchlist_entry_id.clone(),
+        watchlist_name: input.watchlist_name.clone(),
+        matched_name: input.matched_name.clone(),
+        score: input.score,
+        status: status.to_string(),
+        reviewer_id: None,
+        review_note: None,
+        reviewed_at: None,
+        created_at: None,
+        updated_at: None,
+    }
+}
+
+fn result(status: &str) -> screening_results::Model {
+    stored(
+        &CreateScreeningResult {
+            subject_type: "user".to_string(),
+            subject_id: 1,
+            user_id: 1,
+            screened_name: "Jon Doe".to_string(),
+            watchlist_entry_id: "UN-1".to_string(),
+            watchlist_name: "UN".to_string(),
+            matched_name: "John Doe".to_string(),
+            score: 87,
+        },
+        status,
+    )
+}
+
+fn review(status: &str, note: Option<&str>) -> ReviewScreeningResultRequest {
+    ReviewScreeningResultRequest {
+        screening_result_id: 7,
+        reviewer_id: 99,
+        status: status.to_string(),
+        note: note.map(str::to_string),
+    }
+}
+
+#[tokio::test]
+async fn test_screen_stores_best_match_per_entry() {
+    let mut mock_repo = MockScreeningRepositoryTrait::new();
+    let mut mock_watchlist = MockWatchlistTrait::new();
+
+    mock_watchlist
+        .expect_search()
+        .with(predicate::eq("Landlord"), predicate::always())
+        .return_const(vec![found("UN-1", "John Doe", 86)]);
+
+    mock_watchlist
+        .expect_search()
+        .with(predicate::eq("Jon Doe"), predicate::always())
+        .return_const(vec![
+            found("UN-1", "John Doe", 87),
+            found("UN-2", "Jon Dow", 90),
+        ]);
+
+    mock_repo
+        .expect_create_result()
+        .withf(|input| input.watchlist_entry_id == "UN-1")
+        .times(1)
+        .returning(|input| {
+            assert_eq!(input.screened_name, "Jon Doe");
+            assert_eq!(input.score, 87);
+            Ok(stored(input, "pending"))
+        });
+
+    mock_repo
+        .expect_create_result()
+        .withf(|input| input.watchlist_entry_id == "UN-2")
+        .times(1)
+        .returning(|input| Ok(stored(input, "pending")));
+
+    let service = ScreeningService::new(Arc::new(mock_repo), Arc::new(mock_watchlist));
+
+    let results = service
+        .screen(&ScreenNameRequest {
+            subject_type: "beneficiary".to_string(),
+            subject_id: 3,
+            user_id: 1,
+            names: vec!["Landlord".to_string(), "Jon Doe".to_string()],
+        })
+        .await
+        .unwrap();
+
+    assert_eq!(results.len(), 2);
+    assert!(results.iter().all(|result| result.status == "pending"));
+    assert!(results.iter().all(|result| result.subject_id == 3));
+}
+
+#[tokio::test]
+async fn test_screen_without_matches_stores_nothing() {
+    let mut mock_watchlist = MockWatchlistTrait::new();
+
+    mock_watchlist.expect_search().return_const(vec![]);
+
+    let service = ScreeningService::new(
+        Arc::new(MockScreeningRepositoryTrait::new()),
+        Arc::new(mock_watchlist),
+    );
+
+    let results = service
+        .screen(&ScreenNameRequest {
+            subject_type: "user".to_string(),
+            subject_id: 1,
+            user_id: 1,
+            names: vec!["Siti Aminah".to_string()],
+        })
+        .await
+        .unwrap();
+
+    assert!(results.is_empty());
+}
+
+#[tokio::test]
+async fn test_clearing_a_match_requires_a_note() {
+    let service = ScreeningService::new(
+        Arc::new(MockScreeningRepositoryTrait::new()),
+        Arc::new(MockWatchlistTrait::new()),
+    );
+
+    let error = service
+        .review_result(&review("cleared", None))
+        .await
+        .unwrap_err();
+
+    assert_eq!(error.message, "A note is required when clearing a match");
+}
+
+#[tokio::test]
+async fn test_confirm_pending_match() {
+    let mut mock_repo = MockScreeningRepositoryTrait::new();
+
+    mock_repo
+        .expect_find_by_id()
+        .with(predicate::eq(7))
+        .times(1)
+        .return_once(|_| Ok(Some(result("pending"))));
+
+    mock_repo
+        .expect_transition_status()
+        .with(
+            predicate::eq(7),
+            predicate::eq("pending"),
+            predicate::eq("confirmed"),
+            predicate::eq(99),
+            predicate::eq(None),
+        )
+        .return_once(|_, _, _, _, _| Ok(true));
+
+    mock_repo
+        .expect_find_by_id()
+        .with(predicate::eq(7))
+        .return_once(|_| Ok(Some(result("confirmed"))));
+
+    let service = ScreeningService::new(Arc::new(mock_repo), Arc::new(MockWatchlistTrait::new()));
+
+    let response = service
+        .review_result(&review("confirmed", None))
+        .await
+        .unwrap();
+
+    assert_eq!(response.message, "Screening match confirmed");
+    assert_eq!(response.data.status, "confirmed");
+}
+
+#[tokio::test]
+async fn test_review_already_reviewed_match() {
+    let mut mock_repo = MockScreeningRepositoryTrait::new();
+
+    mock_repo
+        .expect_find_by_id()
+        .return_once(|_| Ok(Some(result("cleared"))));
+
+    let service = ScreeningService::new(Arc::new(mock_repo), Arc::new(MockWatchlistTrait::new()));
+
+    let error = service
+        .review_result(&review("confirmed", None))
+        .await
+        .unwrap_err();
+
+    assert_eq!(
+        error.message,
+        "Screening result 7 is cleared and cannot be confirmed"
+    );
+}
+
+#[tokio::test]
+async fn test_reload_watchlist_failure_is_reported() {
+    let mut mock_watchlist = MockWatchlistTrait::new();
+
+    mock_watchlist.expect_reload().return_once(|| {
+        Err(AppError::WatchlistError(
+            "watchlist.csv: not found".to_string(),
+        ))
+    });
+
+    let service = ScreeningService::new(
+        Arc::new(MockScreeningRepositoryTrait::new()),
+        Arc::new(mock_watchlist),
+    );
+
+    let error = service.reload_watchlist().await.unwrap_err();
+
+    assert_eq!(error.message, "Watchlist error: watchlist.csv: not found");
+}
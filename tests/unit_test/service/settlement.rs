@@ -0,0 +1,700 @@
+use std::sync::Arc;
+
+use chrono::{NaiveDate, NaiveDateTime, Utc};
+use example_payment_gateway_axum::{
+    abstract_trait::{
+        bank_account::MockBankAccountRepositoryTrait,
+        clock::MockClockTrait,
+        fee::{FeeQuote, MockFeeServiceTrait},
+        merchant::MockMerchantRepositoryTrait,
+        saldo::MockSaldoRepositoryTrait,
+        settlement::{MockSettlementRepositoryTrait, SettlementServiceTrait},
+        withdraw::MockWithdrawServiceTrait,
+    },
+    domain::{
+        request::limit::MinimumAmounts,
+        response::{withdraw::WithdrawResponse, ApiResponse, ErrorResponse},
+    },
+    entities::{
+        bank_accounts, merchants, payment_intents, qris_payments, refunds, saldo, settlements,
+    },
+    services::settlement::SettlementService,
+    utils::errors::AppError,
+};
+use mockall::predicate;
+
+fn at(day: u32, hour: u32) -> NaiveDateTime {
+    NaiveDate::from_ymd_opt(2026, 10, day)
+        .unwrap()
+        .and_hms_opt(hour, 0, 0)
+        .unwrap()
+}
+
+fn mock_clock(now: NaiveDateTime) -> MockClockTrait {
+    let mut clock = MockClockTrait::new();
+    clock.expect_now().return_const(now);
+    clock
+}
+
+fn merchant() -> merchants::Model {
+    merchants::Model {
+        merchant_id: 3,
+        user_id: 2,
+        name: "Warung Sederhana".to_string(),
+        created_at: Some(Utc::now().naive_utc()),
+        updated_at: Some(Utc::now().naive_utc()),
+    }
+}
+
+fn settlement(net_amount: i32) -> settlements::Model {
+    settlements::Model {
+        settlement_id: 7,
+        merchant_id: 3,
+        period_start: at(18, 0),
+        period_end: at(19, 0),
+        payment_count: 2,
+        gross_amount: net_amount + 25000,
+        refunded_amount: 25000,
+        fee_amount: 0,
+        net_amount,
+        status: "pending".to_string(),
+        withdraw_id: None,
+        failure_reason: None,
+        fraud_review_id: None,
+        created_at: Some(Utc::now().naive_utc()),
+        updated_at: Some(Utc::now().naive_utc()),
+    }
+}
+
+fn bank_account() -> bank_accounts::Model {
+    bank_accounts::Model {
+        bank_account_id: 4,
+        user_id: 2,
+        bank_code: "BCA".to_string(),
+        account_number: "1234567890".to_string(),
+        holder_name: "Warung Sederhana".to_string(),
+        created_at: Some(Utc::now().naive_utc()),
+        updated_at: Some(Utc::now().naive_utc()),
+    }
+}
+
+fn withdraw_response(amount: i32) -> ApiResponse<WithdrawResponse> {
+    ApiResponse {
+        status: "success".to_string(),
+        message: "Withdraw created successfully".to_string(),
+        data: WithdrawResponse {
+            withdraw_id: 12,
+            user_id: 2,
+            withdraw_amount: amount,
+            withdraw_time: Utc::now(),
+            bank_account_id: Some(4),
+            status: "pending".to_string(),
+            provider_reference: None,
+            failure_reason: None,
+            fee_amount: 0,
+            created_at: None,
+            updated_at: None,
+        },
+    }
+}
+
+fn payment(amount: i32) -> payment_intents::Model {
+    payment_intents::Model {
+        payment_intent_id: 1,
+        merchant_id: 3,
+        amount,
+        currency: "IDR".to_string(),
+        reference: "ORDER-1".to_string(),
+        status: "succeeded".to_string(),
+        expires_at: at(18, 12),
+        customer_id: Some(5),
+        transfer_id: Some(9),
+        succeeded_at: Some(at(18, 10)),
+        amount_refunded: 25000,
+        settlement_id: Some(7),
+        fraud_review_id: None,
+        created_at: Some(at(18, 9)),
+        updated_at: Some(at(18, 10)),
+    }
+}
+
+fn qris_payment(amount: i32) -> qris_payments::Model {
+    qris_payments::Model {
+        qris_payment_id: 5,
+        user_id: 2,
+        merchant_id: Some(3),
+        reference: "QRREF1".to_string(),
+        amount,
+        payload: "000201010212".to_string(),
+        status: "paid".to_string(),
+        payer_id: Some(6),
+        transfer_id: Some(10),
+        paid_at: Some(at(18, 11)),
+        settlement_id: Some(7),
+        fraud_review_id: None,
+        created_at: Some(at(18, 9)),
+        updated_at: Some(at(18, 11)),
+    }
+}
+
+fn no_fee_service() -> MockFeeServiceTrait {
+    let mut fee_service = MockFeeServiceTrait::new();
+    fee_service
+        .expect_calculate_fee()
+        .returning(|_| Ok(FeeQuote::default()));
+    fee_service.expect_post_fee().never();
+    fee_service
+}
+
+fn no_retryable_settlements() -> MockSettlementRepositoryTrait {
+    let mut settlement_repo = MockSettlementRepositoryTrait::new();
+    settlement_repo
+        .expect_find_retryable()
+        .returning(|| Ok(vec![]));
+    settlement_repo
+}
+
+fn service(
+    settlement_repo: MockSettlementRepositoryTrait,
+    merchant_repo: MockMerchantRepositoryTrait,
+    bank_account_repo: MockBankAccountRepositoryTrait,
+    saldo_repo: MockSaldoRepositoryTrait,
+    withdraw_service: MockWithdrawServiceTrait,
+    fee_service: MockFeeServiceTrait,
+) -> SettlementService {
+    SettlementService::new(
+        Arc::new(settlement_repo),
+        Arc::new(merchant_repo),
+        Arc::new(bank_account_repo),
+        Arc::new(saldo_repo),
+        Arc::new(withdraw_service),
+        Arc::new(fee_service),
+        Arc::new(mock_clock(at(19, 1))),
+        MinimumAmounts::default(),
+    )
+}
+
+#[tokio::test]
+async fn test_run_daily_settlements_pays_out_previous_day() {
+    let mut merchant_repo = MockMerchantRepositoryTrait::new();
+    merchant_repo
+        .expect_find_all()
+        .returning(|| Ok(vec![merchant()]));
+
+    let mut settlement_repo = no_retryable_settlements();
+    settlement_repo
+        .expect_create_settlement()
+        .with(
+            predicate::eq(3),
+            predicate::eq(at(18, 0)),
+            predicate::eq(at(19, 0)),
+        )
+        .times(1)
+        .returning(|_, _, _| Ok(Some(settlement(125000))));
+    settlement_repo
+        .expect_find_payments()
+        .returning(|_| Ok(vec![payment(150000)]));
+    settlement_repo
+        .expect_find_qris_payments()
+        .returning(|_| Ok(vec![]));
+    settlement_repo
+        .expect_update_payout()
+        .with(
+            predicate::eq(7),
+            predicate::eq("paid_out"),
+            predicate::eq(Some(12)),
+            predicate::eq(None::<String>),
+        )
+        .times(1)
+        .returning(|_, status, withdraw_id, _| {
+            let mut settlement = settlement(125000);
+            settlement.status = status.to_string();
+            settlement.withdraw_id = withdraw_id;
+            Ok(settlement)
+        });
+
+    let mut bank_account_repo = MockBankAccountRepositoryTrait::new();
+    bank_account_repo
+        .expect_find_by_user()
+        .with(predicate::eq(2))
+        .returning(|_| Ok(vec![bank_account()]));
+
+    let mut withdraw_service = MockWithdrawServiceTrait::new();
+    withdraw_service
+        .expect_create_withdraw()
+        .withf(|input| {
+            input.user_id == 2 && input.bank_account_id == 4 && input.withdraw_amount == 125000
+        })
+        .times(1)
+        .returning(|input| Ok(withdraw_response(input.withdraw_amount)));
+
+    let service = service(
+        settlement_repo,
+        merchant_repo,
+        bank_account_repo,
+        MockSaldoRepositoryTrait::new(),
+        withdraw_service,
+        no_fee_service(),
+    );
+
+    let created = service.run_daily_settlements().await.unwrap();
+
+    assert_eq!(created, 1);
+}
+
+#[tokio::test]
+async fn test_run_daily_settlements_holds_amount_below_minimum_payout() {
+    let mut merchant_repo = MockMerchantRepositoryTrait::new();
+    merchant_repo
+        .expect_find_all()
+        .returning(|| Ok(vec![merchant()]));
+
+    let mut settlement_repo = no_retryable_settlements();
+    settlement_repo
+        .expect_create_settlement()
+        .returning(|_, _, _| Ok(Some(settlement(30000))));
+    settlement_repo
+        .expect_find_payments()
+        .returning(|_| Ok(vec![payment(55000)]));
+    settlement_repo
+        .expect_find_qris_payments()
+        .returning(|_| Ok(vec![]));
+    settlement_repo
+        .expect_update_payout()
+        .withf(|id, status, withdraw_id, failure_reason| {
+            *id == 7 && status == "held" && withdraw_id.is_none() && failure_reason.is_some()
+        })
+        .times(1)
+        .returning(|_, _, _, _| Ok(settlement(30000)));
+
+    let mut withdraw_service = MockWithdrawServiceTrait::new();
+    withdraw_service.expect_create_withdraw().never();
+
+    let service = service(
+        settlement_repo,
+        merchant_repo,
+        MockBankAccountRepositoryTrait::new(),
+        MockSaldoRepositoryTrait::new(),
+        withdraw_service,
+        no_fee_service(),
+    );
+
+    let created = service.run_daily_settlements().await.unwrap();
+
+    assert_eq!(created, 1);
+}
+
+#[tokio::test]
+async fn test_run_daily_settlements_skips_already_settled_merchant() {
+    let mut merchant_repo = MockMerchantRepositoryTrait::new();
+    merchant_repo
+        .expect_find_all()
+        .returning(|| Ok(vec![merchant()]));
+
+    let mut settlement_repo = no_retryable_settlements();
+    settlement_repo
+        .expect_create_settlement()
+        .returning(|_, _, _| Ok(None));
+    settlement_repo.expect_update_payout().never();
+
+    let service = service(
+        settlement_repo,
+        merchant_repo,
+        MockBankAccountRepositoryTrait::new(),
+        MockSaldoRepositoryTrait::new(),
+        MockWithdrawServiceTrait::new(),
+        no_fee_service(),
+    );
+
+    let created = service.run_daily_settlements().await.unwrap();
+
+    assert_eq!(created, 0);
+}
+
+#[tokio::test]
+async fn test_run_daily_settlements_retries_failed_payout() {
+    let mut merchant_repo = MockMerchantRepositoryTrait::new();
+    merchant_repo.expect_find_all().returning(|| Ok(vec![]));
+    merchant_repo
+        .expect_find_by_id()
+        .with(predicate::eq(3))
+        .returning(|_| Ok(Some(merchant())));
+
+    let mut settlement_repo = MockSettlementRepositoryTrait::new();
+    settlement_repo
+        .expect_find_retryable()
+        .times(1)
+        .returning(|| {
+            let mut settlement = settlement(125000);
+            settlement.fee_amount = 1500;
+            settlement.status = "failed".to_string();
+            settlement.failure_reason = Some("Bank unavailable".to_string());
+            Ok(vec![settlement])
+        });
+    settlement_repo
+        .expect_claim_retry()
+        .with(predicate::eq(7), predicate::eq("failed"))
+        .times(1)
+        .returning(|_, _| Ok(true));
+    // The fees were charged before the payout failed
+    settlement_repo.expect_find_payments().never();
+    settlement_repo
+        .expect_update_payout()
+        .with(
+            predicate::eq(7),
+            predicate::eq("paid_out"),
+            predicate::eq(Some(12)),
+            predicate::eq(None::<String>),
+        )
+        .times(1)
+        .returning(|_, status, withdraw_id, _| {
+            let mut settlement = settlement(125000);
+            settlement.status = status.to_string();
+            settlement.withdraw_id = withdraw_id;
+            Ok(settlement)
+        });
+
+    let mut bank_account_repo = MockBankAccountRepositoryTrait::new();
+    bank_account_repo
+        .expect_find_by_user()
+        .with(predicate::eq(2))
+        .returning(|_| Ok(vec![bank_account()]));
+
+    let mut withdraw_service = MockWithdrawServiceTrait::new();
+    withdraw_service
+        .expect_create_withdraw()
+        .withf(|input| input.withdraw_amount == 125000)
+        .times(1)
+        .returning(|input| Ok(withdraw_response(input.withdraw_amount)));
+
+    let service = service(
+        settlement_repo,
+        merchant_repo,
+        bank_account_repo,
+        MockSaldoRepositoryTrait::new(),
+        withdraw_service,
+        no_fee_service(),
+    );
+
+    let created = service.run_daily_settlements().await.unwrap();
+
+    assert_eq!(created, 0);
+}
+
+#[tokio::test]
+async fn test_run_daily_settlements_skips_settlement_claimed_by_another_run() {
+    let mut merchant_repo = MockMerchantRepositoryTrait::new();
+    merchant_repo.expect_find_all().returning(|| Ok(vec![]));
+    merchant_repo
+        .expect_find_by_id()
+        .returning(|_| Ok(Some(merchant())));
+
+    let mut settlement_repo = MockSettlementRepositoryTrait::new();
+    settlement_repo.expect_find_retryable().returning(|| {
+        let mut settlement = settlement(125000);
+        settlement.status = "held".to_string();
+        Ok(vec![settlement])
+    });
+    settlement_repo
+        .expect_claim_retry()
+        .returning(|_, _| Ok(false));
+    settlement_repo.expect_update_payout().never();
+
+    let mut withdraw_service = MockWithdrawServiceTrait::new();
+    withdraw_service.expect_create_withdraw().never();
+
+    let service = service(
+        settlement_repo,
+        merchant_repo,
+        MockBankAccountRepositoryTrait::new(),
+        MockSaldoRepositoryTrait::new(),
+        withdraw_service,
+        no_fee_service(),
+    );
+
+    service.run_daily_settlements().await.unwrap();
+}
+
+#[tokio::test]
+async fn test_run_daily_settlements_parks_payout_held_for_review() {
+    let mut merchant_repo = MockMerchantRepositoryTrait::new();
+    merchant_repo
+        .expect_find_all()
+        .returning(|| Ok(vec![merchant()]));
+
+    let mut settlement_repo = no_retryable_settlements();
+    settlement_repo
+        .expect_create_settlement()
+        .returning(|_, _, _| Ok(Some(settlement(125000))));
+    settlement_repo
+        .expect_find_payments()
+        .returning(|_| Ok(vec![payment(150000)]));
+    settlement_repo
+        .expect_find_qris_payments()
+        .returning(|_| Ok(vec![]));
+    settlement_repo.expect_update_payout().never();
+    settlement_repo
+        .expect_hold_for_review()
+        .with(predicate::eq(7), predicate::eq(9))
+        .times(1)
+        .returning(|_, fraud_review_id| {
+            let mut settlement = settlement(125000);
+            settlement.status = "in_review".to_string();
+            settlement.fraud_review_id = Some(fraud_review_id);
+            Ok(settlement)
+        });
+
+    let mut bank_account_repo = MockBankAccountRepositoryTrait::new();
+    bank_account_repo
+        .expect_find_by_user()
+        .returning(|_| Ok(vec![bank_account()]));
+
+    let mut withdraw_service = MockWithdrawServiceTrait::new();
+    withdraw_service.expect_create_withdraw().returning(|_| {
+        Err(ErrorResponse::from(AppError::HeldForReview(
+            9,
+            "Large payout".to_string(),
+        )))
+    });
+
+    let service = service(
+        settlement_repo,
+        merchant_repo,
+        bank_account_repo,
+        MockSaldoRepositoryTrait::new(),
+        withdraw_service,
+        no_fee_service(),
+    );
+
+    let created = service.run_daily_settlements().await.unwrap();
+
+    assert_eq!(created, 1);
+}
+
+#[tokio::test]
+async fn test_get_settlement_report_csv_lists_payments_qris_and_refunds() {
+    let mut merchant_repo = MockMerchantRepositoryTrait::new();
+    merchant_repo
+        .expect_find_by_user()
+        .with(predicate::eq(2))
+        .returning(|_| Ok(Some(merchant())));
+
+    let mut settlement_repo = MockSettlementRepositoryTrait::new();
+    settlement_repo
+        .expect_find_by_id()
+        .with(predicate::eq(7))
+        .returning(|_| Ok(Some(settlement(125000))));
+    settlement_repo
+        .expect_find_payments()
+        .returning(|_| Ok(vec![payment(150000)]));
+    settlement_repo
+        .expect_find_qris_payments()
+        .returning(|_| Ok(vec![qris_payment(100000)]));
+    settlement_repo.expect_find_refunds().returning(|_| {
+        Ok(vec![refunds::Model {
+            refund_id: 11,
+            payment_intent_id: 1,
+            amount: 25000,
+            reason: "Item out of stock".to_string(),
+            status: "succeeded".to_string(),
+            failure_reason: None,
+            refunded_at: Some(at(18, 15)),
+            settlement_id: Some(7),
+            created_at: Some(at(18, 15)),
+            updated_at: Some(at(18, 15)),
+        }])
+    });
+
+    let service = service(
+        settlement_repo,
+        merchant_repo,
+        MockBankAccountRepositoryTrait::new(),
+        MockSaldoRepositoryTrait::new(),
+        MockWithdrawServiceTrait::new(),
+        no_fee_service(),
+    );
+
+    let csv = service.get_settlement_report_csv(7, 2).await.unwrap();
+
+    assert_eq!(
+        csv,
+        "type,id,reference,amount,occurred_at\n\
+         payment,1,ORDER-1,150000,2026-10-18T10:00:00+00:00\n\
+         qris,5,QRREF1,100000,2026-10-18T11:00:00+00:00\n\
+         refund,11,1,-25000,2026-10-18T15:00:00+00:00\n"
+    );
+}
+
+#[tokio::test]
+async fn test_get_settlement_of_other_merchant_is_not_found() {
+    let mut merchant_repo = MockMerchantRepositoryTrait::new();
+    merchant_repo.expect_find_by_user().returning(|_| {
+        let mut other = merchant();
+        other.merchant_id = 99;
+        Ok(Some(other))
+    });
+
+    let mut settlement_repo = MockSettlementRepositoryTrait::new();
+    settlement_repo
+        .expect_find_by_id()
+        .returning(|_| Ok(Some(settlement(125000))));
+
+    let service = service(
+        settlement_repo,
+        merchant_repo,
+        MockBankAccountRepositoryTrait::new(),
+        MockSaldoRepositoryTrait::new(),
+        MockWithdrawServiceTrait::new(),
+        no_fee_service(),
+    );
+
+    assert!(service.get_settlement(7, 2).await.is_err());
+}
+
+#[tokio::test]
+async fn test_run_daily_settlements_charges_payment_qris_and_payout_fees() {
+    let mut merchant_repo = MockMerchantRepositoryTrait::new();
+    merchant_repo
+        .expect_find_all()
+        .returning(|| Ok(vec![merchant()]));
+
+    let mut settlement_repo = no_retryable_settlements();
+    settlement_repo
+        .expect_create_settlement()
+        .returning(|_, _, _| Ok(Some(settlement(125000))));
+    settlement_repo
+        .expect_find_payments()
+        .returning(|_| Ok(vec![payment(150000)]));
+    settlement_repo
+        .expect_find_qris_payments()
+        .returning(|_| Ok(vec![qris_payment(100000)]));
+    settlement_repo
+        .expect_update_fee()
+        .with(predicate::eq(7), predicate::eq(1750))
+        .times(1)
+        .returning(|_, fee_amount| {
+            let mut settlement = settlement(125000);
+            settlement.fee_amount = fee_amount;
+            settlement.net_amount -= fee_amount;
+            Ok(settlement)
+        });
+    settlement_repo
+        .expect_update_payout()
+        .withf(|_, status, withdraw_id, _| status == "paid_out" && *withdraw_id == Some(12))
+        .times(1)
+        .returning(|_, _, _, _| Ok(settlement(123250)));
+
+    let mut saldo_repo = MockSaldoRepositoryTrait::new();
+    saldo_repo
+        .expect_find_by_user_id()
+        .with(predicate::eq(2))
+        .returning(|_| {
+            Ok(Some(saldo::Model {
+                saldo_id: 2,
+                user_id: 2,
+                total_balance: 125000,
+                withdraw_amount: None,
+                withdraw_time: None,
+                created_at: None,
+                updated_at: None,
+            }))
+        });
+    saldo_repo
+        .expect_update_balance()
+        .withf(|input| input.user_id == 2 && input.total_balance == 123250)
+        .times(1)
+        .returning(|_| {
+            Ok(saldo::Model {
+                saldo_id: 2,
+                user_id: 2,
+                total_balance: 123250,
+                withdraw_amount: None,
+                withdraw_time: None,
+                created_at: None,
+                updated_at: None,
+            })
+        });
+
+    let mut bank_account_repo = MockBankAccountRepositoryTrait::new();
+    bank_account_repo
+        .expect_find_by_user()
+        .returning(|_| Ok(vec![bank_account()]));
+
+    let mut fee_service = MockFeeServiceTrait::new();
+    fee_service
+        .expect_calculate_fee()
+        .withf(|input| {
+            input.transaction_type == "payment"
+                && input.method.is_none()
+                && input.merchant_id == Some(3)
+        })
+        .returning(|_| {
+            Ok(FeeQuote {
+                fee_rule_id: Some(4),
+                fee_amount: 1050,
+            })
+        });
+    fee_service
+        .expect_calculate_fee()
+        .withf(|input| {
+            input.transaction_type == "payment"
+                && input.method.as_deref() == Some("qris")
+                && input.merchant_id == Some(3)
+                && input.amount == 100000
+        })
+        .returning(|_| {
+            Ok(FeeQuote {
+                fee_rule_id: Some(5),
+                fee_amount: 700,
+            })
+        });
+    fee_service
+        .expect_calculate_fee()
+        .withf(|input| input.transaction_type == "withdraw" && input.amount == 123250)
+        .returning(|_| {
+            Ok(FeeQuote {
+                fee_rule_id: Some(1),
+                fee_amount: 2500,
+            })
+        });
+    fee_service
+        .expect_post_fee()
+        .withf(|input| {
+            input.transaction_type == "payment"
+                && input.transaction_id == 1
+                && input.fee_rule_id == Some(4)
+                && input.amount == 1050
+        })
+        .times(1)
+        .returning(|_| Ok(()));
+    fee_service
+        .expect_post_fee()
+        .withf(|input| {
+            input.transaction_type == "qris_payment"
+                && input.transaction_id == 5
+                && input.fee_rule_id == Some(5)
+                && input.amount == 700
+        })
+        .times(1)
+        .returning(|_| Ok(()));
+
+    let mut withdraw_service = MockWithdrawServiceTrait::new();
+    withdraw_service
+        .expect_create_withdraw()
+        .withf(|input| input.withdraw_amount == 120750)
+        .times(1)
+        .returning(|input| Ok(withdraw_response(input.withdraw_amount)));
+
+    let service = service(
+        settlement_repo,
+        merchant_repo,
+        bank_account_repo,
+        saldo_repo,
+        withdraw_service,
+        fee_service,
+    );
+
+    let created = service.run_daily_settlements().await.unwrap();
+
+    assert_eq!(created, 1);
+}
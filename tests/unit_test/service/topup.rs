@@ -3,17 +3,75 @@ use std::sync::Arc;
 use chrono::{DateTime, Utc};
 use example_payment_gateway_axum::{
     abstract_trait::{
-        saldo::{MockSaldoRepositoryTrait, SaldoServiceTrait},
-        topup::{MockTopupRepositoryTrait, TopupRepositoryTrait, TopupServiceTrait},
-        user::{MockUserRepositoryTrait, UserRepositoryTrait},
+        fee::{FeeQuote, MockFeeServiceTrait},
+        fraud::MockFraudEngineTrait,
+        limit::MockLimitServiceTrait,
+        payment_channel::MockPaymentChannelTrait,
+        saldo::MockSaldoRepositoryTrait,
+        topup::{MockTopupRepositoryTrait, TopupServiceTrait},
+        user::MockUserRepositoryTrait,
+    },
+    domain::{
+        request::{
+            limit::MinimumAmounts,
+            topup::{CreateTopupRequest, UpdateTopupRequest},
+        },
+        response::{topup::PaymentInstructions, ErrorResponse},
     },
-    domain::request::topup::{CreateTopupRequest, UpdateTopupRequest},
     entities::{saldo, topups, users},
-    services::{saldo::SaldoService, topup::TopupService},
+    services::topup::TopupService,
+    utils::errors::AppError,
 };
 use mockall::predicate;
 use sea_orm::DbErr;
 
+fn mock_fee_service() -> MockFeeServiceTrait {
+    let mut fee_service = MockFeeServiceTrait::new();
+    fee_service
+        .expect_calculate_fee()
+        .returning(|_| Ok(FeeQuote::default()));
+    fee_service.expect_post_fee().returning(|_| Ok(()));
+    fee_service
+}
+
+fn mock_limit_service() -> MockLimitServiceTrait {
+    let mut limit_service = MockLimitServiceTrait::new();
+    limit_service
+        .expect_check_transaction_limits()
+        .returning(|_| Ok(()));
+    limit_service
+        .expect_check_balance_limit()
+        .returning(|_| Ok(()));
+    limit_service
+}
+
+fn mock_fraud_engine() -> MockFraudEngineTrait {
+    let mut fraud_engine = MockFraudEngineTrait::new();
+    fraud_engine.expect_screen().returning(|_| Ok(()));
+    fraud_engine
+}
+
+fn pending_topup(reference: &str) -> topups::Model {
+    topups::Model {
+        topup_id: 1,
+        topup_no: "TOP12345".to_string(),
+        user_id: 1,
+        topup_amount: 100000,
+        topup_method: "mandiri".to_string(),
+        topup_time: DateTime::from_timestamp(1_634_944_800, 0)
+            .unwrap()
+            .naive_utc(),
+        status: "pending".to_string(),
+        payment_reference: Some(reference.to_string()),
+        payment_instructions: None,
+        paid_at: None,
+        fee_amount: 0,
+        fee_rule_id: None,
+        created_at: Some(Utc::now().naive_utc()),
+        updated_at: Some(Utc::now().naive_utc()),
+    }
+}
+
 #[tokio::test]
 async fn test_get_topups() {
     let mut mock_topup_repo = MockTopupRepositoryTrait::new();
@@ -30,8 +88,14 @@ async fn test_get_topups() {
             topup_time: DateTime::from_timestamp(1_634_944_800, 0)
                 .unwrap()
                 .naive_utc(),
-            created_at: Some(DateTime::<Utc>::from(Utc::now()).naive_utc()),
-            updated_at: Some(DateTime::<Utc>::from(Utc::now()).naive_utc()),
+            status: "paid".to_string(),
+            payment_reference: None,
+            payment_instructions: None,
+            paid_at: None,
+            fee_amount: 0,
+            fee_rule_id: None,
+            created_at: Some(Utc::now().naive_utc()),
+            updated_at: Some(Utc::now().naive_utc()),
         },
         topups::Model {
             topup_id: 2,
@@ -42,8 +106,14 @@ async fn test_get_topups() {
             topup_time: DateTime::from_timestamp(1_634_944_800, 0)
                 .unwrap()
                 .naive_utc(),
-            created_at: Some(DateTime::<Utc>::from(Utc::now()).naive_utc()),
-            updated_at: Some(DateTime::<Utc>::from(Utc::now()).naive_utc()),
+            status: "paid".to_string(),
+            payment_reference: None,
+            payment_instructions: None,
+            paid_at: None,
+            fee_amount: 0,
+            fee_rule_id: None,
+            created_at: Some(Utc::now().naive_utc()),
+            updated_at: Some(Utc::now().naive_utc()),
         },
     ];
 
@@ -55,6 +125,11 @@ async fn test_get_topups() {
         Arc::new(mock_topup_repo),
         Arc::new(_mock_saldo_repo),
         Arc::new(mock_user_repo),
+        Arc::new(MockPaymentChannelTrait::new()),
+        Arc::new(mock_fee_service()),
+        Arc::new(mock_limit_service()),
+        Arc::new(mock_fraud_engine()),
+        MinimumAmounts::default(),
     );
 
     let result = service.get_topups().await;
@@ -88,6 +163,11 @@ async fn test_get_topups_empty_data() {
         Arc::new(mock_topup_repo),
         Arc::new(_mock_saldo_repo),
         Arc::new(mock_user_repo),
+        Arc::new(MockPaymentChannelTrait::new()),
+        Arc::new(mock_fee_service()),
+        Arc::new(mock_limit_service()),
+        Arc::new(mock_fraud_engine()),
+        MinimumAmounts::default(),
     );
 
     let result = service.get_topups().await;
@@ -117,8 +197,14 @@ async fn test_get_topup_success() {
         topup_time: DateTime::from_timestamp(1_634_944_800, 0)
             .unwrap()
             .naive_utc(),
-        created_at: Some(DateTime::<Utc>::from(Utc::now()).naive_utc()),
-        updated_at: Some(DateTime::<Utc>::from(Utc::now()).naive_utc()),
+        status: "paid".to_string(),
+        payment_reference: None,
+        payment_instructions: None,
+        paid_at: None,
+        fee_amount: 0,
+        fee_rule_id: None,
+        created_at: Some(Utc::now().naive_utc()),
+        updated_at: Some(Utc::now().naive_utc()),
     };
 
     mock_topup_repo
@@ -130,6 +216,11 @@ async fn test_get_topup_success() {
         Arc::new(mock_topup_repo),
         Arc::new(_mock_saldo_repo),
         Arc::new(mock_user_repo),
+        Arc::new(MockPaymentChannelTrait::new()),
+        Arc::new(mock_fee_service()),
+        Arc::new(mock_limit_service()),
+        Arc::new(mock_fraud_engine()),
+        MinimumAmounts::default(),
     );
 
     let result = service.get_topup(1).await;
@@ -163,6 +254,11 @@ async fn test_get_topup_not_found() {
         Arc::new(mock_topup_repo),
         Arc::new(_mock_saldo_repo),
         Arc::new(mock_user_repo),
+        Arc::new(MockPaymentChannelTrait::new()),
+        Arc::new(mock_fee_service()),
+        Arc::new(mock_limit_service()),
+        Arc::new(mock_fraud_engine()),
+        MinimumAmounts::default(),
     );
 
     let result = service.get_topup(1).await;
@@ -188,6 +284,11 @@ async fn test_get_topup_error() {
         Arc::new(mock_topup_repo),
         Arc::new(_mock_saldo_repo),
         Arc::new(mock_user_repo),
+        Arc::new(MockPaymentChannelTrait::new()),
+        Arc::new(mock_fee_service()),
+        Arc::new(mock_limit_service()),
+        Arc::new(mock_fraud_engine()),
+        MinimumAmounts::default(),
     );
 
     let result = service.get_topup(1).await;
@@ -217,6 +318,8 @@ async fn test_get_topup_users() {
                 email: "john.doe@example.com".to_string(),
                 password: "hashed_password".to_string(),
                 noc_transfer: "12345".to_string(),
+                kyc_level: 0,
+                role: "user".to_string(),
                 created_at: Some(Utc::now().naive_utc()),
                 updated_at: Some(Utc::now().naive_utc()),
             }))
@@ -232,8 +335,14 @@ async fn test_get_topup_users() {
             topup_time: DateTime::from_timestamp(1_634_944_800, 0)
                 .unwrap()
                 .naive_utc(),
-            created_at: Some(DateTime::<Utc>::from(Utc::now()).naive_utc()),
-            updated_at: Some(DateTime::<Utc>::from(Utc::now()).naive_utc()),
+            status: "paid".to_string(),
+            payment_reference: None,
+            payment_instructions: None,
+            paid_at: None,
+            fee_amount: 0,
+            fee_rule_id: None,
+            created_at: Some(Utc::now().naive_utc()),
+            updated_at: Some(Utc::now().naive_utc()),
         }),
         Some(topups::Model {
             topup_id: 2,
@@ -244,8 +353,14 @@ async fn test_get_topup_users() {
             topup_time: DateTime::from_timestamp(1_634_944_800, 0)
                 .unwrap()
                 .naive_utc(),
-            created_at: Some(DateTime::<Utc>::from(Utc::now()).naive_utc()),
-            updated_at: Some(DateTime::<Utc>::from(Utc::now()).naive_utc()),
+            status: "paid".to_string(),
+            payment_reference: None,
+            payment_instructions: None,
+            paid_at: None,
+            fee_amount: 0,
+            fee_rule_id: None,
+            created_at: Some(Utc::now().naive_utc()),
+            updated_at: Some(Utc::now().naive_utc()),
         }),
     ];
 
@@ -258,6 +373,11 @@ async fn test_get_topup_users() {
         Arc::new(mock_topup_repo),
         Arc::new(_mock_saldo_repo),
         Arc::new(mock_user_repo),
+        Arc::new(MockPaymentChannelTrait::new()),
+        Arc::new(mock_fee_service()),
+        Arc::new(mock_limit_service()),
+        Arc::new(mock_fraud_engine()),
+        MinimumAmounts::default(),
     );
 
     let result = service.get_topup_users(user_id).await;
@@ -285,6 +405,8 @@ async fn test_get_topup_users_error() {
                 email: "john.doe@example.com".to_string(),
                 password: "hashed_password".to_string(),
                 noc_transfer: "12345".to_string(),
+                kyc_level: 0,
+                role: "user".to_string(),
                 created_at: Some(Utc::now().naive_utc()),
                 updated_at: Some(Utc::now().naive_utc()),
             }))
@@ -299,6 +421,11 @@ async fn test_get_topup_users_error() {
         Arc::new(mock_topup_repo),
         Arc::new(_mock_saldo_repo),
         Arc::new(mock_user_repo),
+        Arc::new(MockPaymentChannelTrait::new()),
+        Arc::new(mock_fee_service()),
+        Arc::new(mock_limit_service()),
+        Arc::new(mock_fraud_engine()),
+        MinimumAmounts::default(),
     );
 
     let result = service.get_topup_users(1).await;
@@ -326,6 +453,8 @@ async fn test_get_topup_users_not_found() {
                 email: "john.doe@example.com".to_string(),
                 password: "hashed_password".to_string(),
                 noc_transfer: "12345".to_string(),
+                kyc_level: 0,
+                role: "user".to_string(),
                 created_at: Some(Utc::now().naive_utc()),
                 updated_at: Some(Utc::now().naive_utc()),
             }))
@@ -340,6 +469,11 @@ async fn test_get_topup_users_not_found() {
         Arc::new(mock_topup_repo),
         Arc::new(_mock_saldo_repo),
         Arc::new(mock_user_repo),
+        Arc::new(MockPaymentChannelTrait::new()),
+        Arc::new(mock_fee_service()),
+        Arc::new(mock_limit_service()),
+        Arc::new(mock_fraud_engine()),
+        MinimumAmounts::default(),
     );
 
     let result = service.get_topup_users(1).await;
@@ -369,6 +503,8 @@ async fn test_get_topup_user_success() {
                 email: "john.doe@example.com".to_string(),
                 password: "hashed_password".to_string(),
                 noc_transfer: "12345".to_string(),
+                kyc_level: 0,
+                role: "user".to_string(),
                 created_at: Some(Utc::now().naive_utc()),
                 updated_at: Some(Utc::now().naive_utc()),
             }))
@@ -387,8 +523,14 @@ async fn test_get_topup_user_success() {
                 topup_time: DateTime::from_timestamp(1_634_944_800, 0)
                     .unwrap()
                     .naive_utc(),
-                created_at: Some(DateTime::<Utc>::from(Utc::now()).naive_utc()),
-                updated_at: Some(DateTime::<Utc>::from(Utc::now()).naive_utc()),
+                status: "paid".to_string(),
+                payment_reference: None,
+                payment_instructions: None,
+                paid_at: None,
+                fee_amount: 0,
+                fee_rule_id: None,
+                created_at: Some(Utc::now().naive_utc()),
+                updated_at: Some(Utc::now().naive_utc()),
             }))
         });
 
@@ -396,6 +538,11 @@ async fn test_get_topup_user_success() {
         Arc::new(mock_topup_repo),
         Arc::new(_mock_saldo_repo),
         Arc::new(mock_user_repo),
+        Arc::new(MockPaymentChannelTrait::new()),
+        Arc::new(mock_fee_service()),
+        Arc::new(mock_limit_service()),
+        Arc::new(mock_fraud_engine()),
+        MinimumAmounts::default(),
     );
 
     let result = service.get_topup_user(1).await;
@@ -431,6 +578,8 @@ async fn test_get_topup_user_not_found() {
                 email: "john.doe@example.com".to_string(),
                 password: "hashed_password".to_string(),
                 noc_transfer: "12345".to_string(),
+                kyc_level: 0,
+                role: "user".to_string(),
                 created_at: Some(Utc::now().naive_utc()),
                 updated_at: Some(Utc::now().naive_utc()),
             }))
@@ -445,6 +594,11 @@ async fn test_get_topup_user_not_found() {
         Arc::new(mock_topup_repo),
         Arc::new(_mock_saldo_repo),
         Arc::new(mock_user_repo),
+        Arc::new(MockPaymentChannelTrait::new()),
+        Arc::new(mock_fee_service()),
+        Arc::new(mock_limit_service()),
+        Arc::new(mock_fraud_engine()),
+        MinimumAmounts::default(),
     );
 
     let result = service.get_topup_user(1).await;
@@ -474,6 +628,8 @@ async fn test_get_topup_user_error() {
                 email: "john.doe@example.com".to_string(),
                 password: "hashed_password".to_string(),
                 noc_transfer: "12345".to_string(),
+                kyc_level: 0,
+                role: "user".to_string(),
                 created_at: Some(Utc::now().naive_utc()),
                 updated_at: Some(Utc::now().naive_utc()),
             }))
@@ -488,6 +644,11 @@ async fn test_get_topup_user_error() {
         Arc::new(mock_topup_repo),
         Arc::new(_mock_saldo_repo),
         Arc::new(mock_user_repo),
+        Arc::new(MockPaymentChannelTrait::new()),
+        Arc::new(mock_fee_service()),
+        Arc::new(mock_limit_service()),
+        Arc::new(mock_fraud_engine()),
+        MinimumAmounts::default(),
     );
 
     let result = service.get_topup_user(1).await;
@@ -504,7 +665,8 @@ async fn test_get_topup_user_error() {
 async fn test_create_topup_success() {
     let mut mock_user_repo = MockUserRepositoryTrait::new();
     let mut mock_topup_repo = MockTopupRepositoryTrait::new();
-    let mut mock_saldo_repo = MockSaldoRepositoryTrait::new();
+    let mock_saldo_repo = MockSaldoRepositoryTrait::new();
+    let mut mock_payment_channel = MockPaymentChannelTrait::new();
 
     mock_user_repo.expect_find_by_id().return_once(|_| {
         Ok(Some(users::Model {
@@ -514,53 +676,44 @@ async fn test_create_topup_success() {
             email: "john.doe@example.com".to_string(),
             password: "hashed_password".to_string(),
             noc_transfer: "12345".to_string(),
+            kyc_level: 0,
+            role: "user".to_string(),
             created_at: Some(Utc::now().naive_utc()),
             updated_at: Some(Utc::now().naive_utc()),
         }))
     });
 
-    mock_topup_repo.expect_create().return_once(|_| {
-        Ok(topups::Model {
-            topup_id: 1,
-            topup_no: "TOP12345".to_string(),
-            user_id: 1,
-            topup_amount: 100000,
-            topup_method: "mandiri".to_string(),
-            topup_time: DateTime::from_timestamp(1_634_944_800, 0)
-                .unwrap()
-                .naive_utc(),
-            created_at: Some(Utc::now().naive_utc()),
-            updated_at: Some(Utc::now().naive_utc()),
-        })
-    });
+    mock_topup_repo
+        .expect_create()
+        .return_once(|_, reference, _| Ok(pending_topup(reference)));
 
-    mock_saldo_repo.expect_find_by_user_id().return_once(|_| {
-        Ok(Some(saldo::Model {
-            saldo_id: 1,
-            user_id: 1,
-            total_balance: 100000,
-            withdraw_amount: None,
-            withdraw_time: None,
-            created_at: Some(Utc::now().naive_utc()),
-            updated_at: Some(Utc::now().naive_utc()),
-        }))
-    });
-    mock_saldo_repo.expect_update_balance().return_once(|_| {
-        Ok(saldo::Model {
-            saldo_id: 1,
-            user_id: 1,
-            total_balance: 200000,
-            withdraw_amount: None,
-            withdraw_time: None,
-            created_at: Some(Utc::now().naive_utc()),
-            updated_at: Some(Utc::now().naive_utc()),
-        })
-    });
+    mock_payment_channel
+        .expect_create_payment()
+        .withf(|input| input.reference.starts_with("TOP-") && input.amount == 100000)
+        .return_once(|_| {
+            Ok(PaymentInstructions::VirtualAccount {
+                bank_code: "mandiri".to_string(),
+                account_number: "88081234567890".to_string(),
+            })
+        });
+
+    mock_topup_repo
+        .expect_update_payment_instructions()
+        .return_once(|_, instructions| {
+            let mut topup = pending_topup("TOP-1");
+            topup.payment_instructions = Some(instructions.to_string());
+            Ok(topup)
+        });
 
     let service = TopupService::new(
         Arc::new(mock_topup_repo),
         Arc::new(mock_saldo_repo),
         Arc::new(mock_user_repo),
+        Arc::new(mock_payment_channel),
+        Arc::new(mock_fee_service()),
+        Arc::new(mock_limit_service()),
+        Arc::new(mock_fraud_engine()),
+        MinimumAmounts::default(),
     );
     let input = CreateTopupRequest {
         user_id: 1,
@@ -573,8 +726,64 @@ async fn test_create_topup_success() {
 
     let response = result.unwrap();
     assert_eq!(response.status, "success");
-    assert_eq!(response.message, "Topup created successfully");
+    assert_eq!(response.message, "Topup created, awaiting payment");
     assert_eq!(response.data.topup_amount, 100000);
+    assert_eq!(response.data.status, "pending");
+    assert_eq!(
+        response.data.payment_instructions,
+        Some(PaymentInstructions::VirtualAccount {
+            bank_code: "mandiri".to_string(),
+            account_number: "88081234567890".to_string(),
+        })
+    );
+}
+
+#[tokio::test]
+async fn test_create_topup_over_balance_limit_is_rejected() {
+    let mut mock_user_repo = MockUserRepositoryTrait::new();
+    let mut mock_topup_repo = MockTopupRepositoryTrait::new();
+    let mut mock_limit_service = MockLimitServiceTrait::new();
+
+    mock_user_repo.expect_find_by_id().return_once(|_| Ok(None));
+
+    mock_limit_service
+        .expect_check_transaction_limits()
+        .withf(|input| input.transaction_type == "topup" && input.amount == 100000)
+        .return_once(|_| Ok(()));
+
+    mock_limit_service
+        .expect_check_balance_limit()
+        .return_once(|_| {
+            Err(ErrorResponse::from(AppError::LimitExceeded(
+                "Receiving 100000 would take the balance of user 1 over the maximum of 2000000 for KYC level 0".to_string(),
+            )))
+        });
+
+    mock_topup_repo.expect_create().never();
+
+    let service = TopupService::new(
+        Arc::new(mock_topup_repo),
+        Arc::new(MockSaldoRepositoryTrait::new()),
+        Arc::new(mock_user_repo),
+        Arc::new(MockPaymentChannelTrait::new()),
+        Arc::new(mock_fee_service()),
+        Arc::new(mock_limit_service),
+        Arc::new(mock_fraud_engine()),
+        MinimumAmounts::default(),
+    );
+
+    let err = service
+        .create_topup(&CreateTopupRequest {
+            user_id: 1,
+            topup_no: "TOP12345".to_string(),
+            topup_amount: 100000,
+            topup_method: "mandiri".to_string(),
+        })
+        .await
+        .unwrap_err();
+
+    assert_eq!(err.status, "error");
+    assert!(err.message.contains("over the maximum of 2000000"));
 }
 
 #[tokio::test]
@@ -591,12 +800,14 @@ async fn test_create_topup_validation_error() {
             email: "john.doe@example.com".to_string(),
             password: "hashed_password".to_string(),
             noc_transfer: "12345".to_string(),
+            kyc_level: 0,
+            role: "user".to_string(),
             created_at: Some(Utc::now().naive_utc()),
             updated_at: Some(Utc::now().naive_utc()),
         }))
     });
 
-    mock_topup_repo.expect_create().return_once(|_| {
+    mock_topup_repo.expect_create().return_once(|_, _, _| {
         Ok(topups::Model {
             topup_id: 1,
             topup_no: "TOP12345".to_string(),
@@ -606,6 +817,12 @@ async fn test_create_topup_validation_error() {
             topup_time: DateTime::from_timestamp(1_634_944_800, 0)
                 .unwrap()
                 .naive_utc(),
+            status: "paid".to_string(),
+            payment_reference: None,
+            payment_instructions: None,
+            paid_at: None,
+            fee_amount: 0,
+            fee_rule_id: None,
             created_at: Some(Utc::now().naive_utc()),
             updated_at: Some(Utc::now().naive_utc()),
         })
@@ -638,6 +855,11 @@ async fn test_create_topup_validation_error() {
         Arc::new(mock_topup_repo),
         Arc::new(mock_saldo_repo),
         Arc::new(mock_user_repo),
+        Arc::new(MockPaymentChannelTrait::new()),
+        Arc::new(mock_fee_service()),
+        Arc::new(mock_limit_service()),
+        Arc::new(mock_fraud_engine()),
+        MinimumAmounts::default(),
     );
     let input = CreateTopupRequest {
         user_id: 1,
@@ -653,14 +875,41 @@ async fn test_create_topup_validation_error() {
     assert_eq!(error.status, "Error Validation");
     assert!(error
         .message
-        .contains("Topup amount must be greater than or equal to 50000"));
+        .contains("Topup amount must be at least 50,000"));
+}
+
+#[tokio::test]
+async fn test_create_topup_uses_configured_minimum() {
+    let service = TopupService::new(
+        Arc::new(MockTopupRepositoryTrait::new()),
+        Arc::new(MockSaldoRepositoryTrait::new()),
+        Arc::new(MockUserRepositoryTrait::new()),
+        Arc::new(MockPaymentChannelTrait::new()),
+        Arc::new(mock_fee_service()),
+        Arc::new(mock_limit_service()),
+        Arc::new(mock_fraud_engine()),
+        MinimumAmounts {
+            topup: 100_000,
+            ..MinimumAmounts::default()
+        },
+    );
+    let input = CreateTopupRequest {
+        user_id: 1,
+        topup_no: "TOP12345".to_string(),
+        topup_amount: 75000,
+        topup_method: "mandiri".to_string(),
+    };
+
+    let error = service.create_topup(&input).await.unwrap_err();
+
+    assert_eq!(error.status, "Error Validation");
+    assert_eq!(error.message, "Topup amount must be at least 100,000");
 }
 
 #[tokio::test]
 async fn test_update_topup_success() {
     let mut mock_user_repo = MockUserRepositoryTrait::new();
     let mut mock_topup_repo = MockTopupRepositoryTrait::new();
-    let mut mock_saldo_repo = MockSaldoRepositoryTrait::new();
 
     mock_user_repo.expect_find_by_id().return_once(|_| {
         Ok(Some(users::Model {
@@ -670,6 +919,8 @@ async fn test_update_topup_success() {
             email: "john.doe@example.com".to_string(),
             password: "hashed_password".to_string(),
             noc_transfer: "12345".to_string(),
+            kyc_level: 0,
+            role: "user".to_string(),
             created_at: Some(Utc::now().naive_utc()),
             updated_at: Some(Utc::now().naive_utc()),
         }))
@@ -688,6 +939,12 @@ async fn test_update_topup_success() {
                 topup_time: DateTime::from_timestamp(1_634_944_800, 0)
                     .unwrap()
                     .naive_utc(),
+                status: "pending".to_string(),
+                payment_reference: None,
+                payment_instructions: None,
+                paid_at: None,
+                fee_amount: 0,
+                fee_rule_id: None,
                 created_at: Some(Utc::now().naive_utc()),
                 updated_at: Some(Utc::now().naive_utc()),
             }))
@@ -706,6 +963,12 @@ async fn test_update_topup_success() {
                 topup_time: DateTime::from_timestamp(1_634_944_800, 0)
                     .unwrap()
                     .naive_utc(),
+                status: "pending".to_string(),
+                payment_reference: None,
+                payment_instructions: None,
+                paid_at: None,
+                fee_amount: 0,
+                fee_rule_id: None,
                 created_at: Some(Utc::now().naive_utc()),
                 updated_at: Some(Utc::now().naive_utc()),
             }))
@@ -722,39 +985,27 @@ async fn test_update_topup_success() {
             topup_time: DateTime::from_timestamp(1_634_944_800, 0)
                 .unwrap()
                 .naive_utc(),
+            status: "pending".to_string(),
+            payment_reference: None,
+            payment_instructions: None,
+            paid_at: None,
+            fee_amount: 0,
+            fee_rule_id: None,
             created_at: Some(Utc::now().naive_utc()),
             updated_at: Some(Utc::now().naive_utc()),
         })
     });
 
-    mock_saldo_repo.expect_find_by_user_id().return_once(|_| {
-        Ok(Some(saldo::Model {
-            saldo_id: 1,
-            user_id: 1,
-            total_balance: 100000,
-            withdraw_amount: None,
-            withdraw_time: None,
-            created_at: Some(Utc::now().naive_utc()),
-            updated_at: Some(Utc::now().naive_utc()),
-        }))
-    });
-
-    mock_saldo_repo.expect_update_balance().return_once(|_| {
-        Ok(saldo::Model {
-            saldo_id: 1,
-            user_id: 1,
-            total_balance: 150000,
-            withdraw_amount: None,
-            withdraw_time: None,
-            created_at: Some(Utc::now().naive_utc()),
-            updated_at: Some(Utc::now().naive_utc()),
-        })
-    });
-
+    // A pending topup has not reached the saldo, so the saldo is untouched
     let service = TopupService::new(
         Arc::new(mock_topup_repo),
-        Arc::new(mock_saldo_repo),
+        Arc::new(MockSaldoRepositoryTrait::new()),
         Arc::new(mock_user_repo),
+        Arc::new(MockPaymentChannelTrait::new()),
+        Arc::new(mock_fee_service()),
+        Arc::new(mock_limit_service()),
+        Arc::new(mock_fraud_engine()),
+        MinimumAmounts::default(),
     );
 
     let input = UpdateTopupRequest {
@@ -787,6 +1038,8 @@ async fn test_update_topup_validation_error() {
             email: "john.doe@example.com".to_string(),
             password: "hashed_password".to_string(),
             noc_transfer: "12345".to_string(),
+            kyc_level: 0,
+            role: "user".to_string(),
             created_at: Some(Utc::now().naive_utc()),
             updated_at: Some(Utc::now().naive_utc()),
         }))
@@ -805,6 +1058,12 @@ async fn test_update_topup_validation_error() {
                 topup_time: DateTime::from_timestamp(1_634_944_800, 0)
                     .unwrap()
                     .naive_utc(),
+                status: "paid".to_string(),
+                payment_reference: None,
+                payment_instructions: None,
+                paid_at: None,
+                fee_amount: 0,
+                fee_rule_id: None,
                 created_at: Some(Utc::now().naive_utc()),
                 updated_at: Some(Utc::now().naive_utc()),
             }))
@@ -823,6 +1082,12 @@ async fn test_update_topup_validation_error() {
                 topup_time: DateTime::from_timestamp(1_634_944_800, 0)
                     .unwrap()
                     .naive_utc(),
+                status: "paid".to_string(),
+                payment_reference: None,
+                payment_instructions: None,
+                paid_at: None,
+                fee_amount: 0,
+                fee_rule_id: None,
                 created_at: Some(Utc::now().naive_utc()),
                 updated_at: Some(Utc::now().naive_utc()),
             }))
@@ -838,6 +1103,12 @@ async fn test_update_topup_validation_error() {
             topup_time: DateTime::from_timestamp(1_634_944_800, 0)
                 .unwrap()
                 .naive_utc(),
+            status: "paid".to_string(),
+            payment_reference: None,
+            payment_instructions: None,
+            paid_at: None,
+            fee_amount: 0,
+            fee_rule_id: None,
             created_at: Some(Utc::now().naive_utc()),
             updated_at: Some(Utc::now().naive_utc()),
         })
@@ -871,12 +1142,17 @@ async fn test_update_topup_validation_error() {
         Arc::new(mock_topup_repo),
         Arc::new(mock_saldo_repo),
         Arc::new(mock_user_repo),
+        Arc::new(MockPaymentChannelTrait::new()),
+        Arc::new(mock_fee_service()),
+        Arc::new(mock_limit_service()),
+        Arc::new(mock_fraud_engine()),
+        MinimumAmounts::default(),
     );
 
     let input = UpdateTopupRequest {
         user_id: 1,
         topup_id: 1,
-        topup_amount: 50000,
+        topup_amount: 49999,
         topup_method: "mandiri".to_string(),
     };
 
@@ -887,7 +1163,7 @@ async fn test_update_topup_validation_error() {
     assert_eq!(response.status, "Error Validation");
     assert!(response
         .message
-        .contains("Topup amount must be greater than or equal to 50000"));
+        .contains("Topup amount must be at least 50,000"));
 }
 
 #[tokio::test]
@@ -905,6 +1181,8 @@ async fn test_delete_topup_success() {
             email: "john.doe@example.com".to_string(),
             password: "hashed_password".to_string(),
             noc_transfer: "12345".to_string(),
+            kyc_level: 0,
+            role: "user".to_string(),
             created_at: Some(Utc::now().naive_utc()),
             updated_at: Some(Utc::now().naive_utc()),
         }))
@@ -921,6 +1199,12 @@ async fn test_delete_topup_success() {
                 topup_amount: 50000,
                 topup_method: "mandiri".to_string(),
                 topup_time: Utc::now().naive_utc(),
+                status: "paid".to_string(),
+                payment_reference: None,
+                payment_instructions: None,
+                paid_at: None,
+                fee_amount: 0,
+                fee_rule_id: None,
                 created_at: Some(Utc::now().naive_utc()),
                 updated_at: Some(Utc::now().naive_utc()),
             }))
@@ -935,6 +1219,11 @@ async fn test_delete_topup_success() {
         Arc::new(mock_topup_repo),
         Arc::new(_mock_saldo_repo),
         Arc::new(mock_user_repo),
+        Arc::new(MockPaymentChannelTrait::new()),
+        Arc::new(mock_fee_service()),
+        Arc::new(mock_limit_service()),
+        Arc::new(mock_fraud_engine()),
+        MinimumAmounts::default(),
     );
 
     let result = service.delete_topup(1).await;
@@ -944,3 +1233,427 @@ async fn test_delete_topup_success() {
     assert_eq!(response.status, "success");
     assert_eq!(response.message, "Topup deleted successfully");
 }
+
+#[tokio::test]
+async fn test_payment_callback_credits_saldo_once() {
+    let mut mock_topup_repo = MockTopupRepositoryTrait::new();
+    let mut mock_saldo_repo = MockSaldoRepositoryTrait::new();
+    let mut mock_payment_channel = MockPaymentChannelTrait::new();
+
+    mock_payment_channel
+        .expect_verify_signature()
+        .returning(|_, signature| signature == "valid");
+
+    mock_topup_repo
+        .expect_find_by_payment_reference()
+        .with(predicate::eq("TOP-1"))
+        .returning(|reference| Ok(Some(pending_topup(reference))));
+
+    let mut first_callback = true;
+    mock_topup_repo
+        .expect_transition_status()
+        .with(
+            predicate::eq(1),
+            predicate::eq("pending"),
+            predicate::eq("paid"),
+        )
+        .times(2)
+        .returning(move |_, _, _| Ok(std::mem::replace(&mut first_callback, false)));
+
+    mock_topup_repo.expect_find_by_id().returning(|_| {
+        let mut topup = pending_topup("TOP-1");
+        topup.status = "paid".to_string();
+        topup.paid_at = Some(Utc::now().naive_utc());
+        Ok(Some(topup))
+    });
+
+    mock_saldo_repo
+        .expect_find_by_user_id()
+        .times(1)
+        .return_once(|_| {
+            Ok(Some(saldo::Model {
+                saldo_id: 1,
+                user_id: 1,
+                total_balance: 50000,
+                withdraw_amount: None,
+                withdraw_time: None,
+                created_at: Some(Utc::now().naive_utc()),
+                updated_at: Some(Utc::now().naive_utc()),
+            }))
+        });
+    mock_saldo_repo
+        .expect_update_balance()
+        .withf(|input| input.user_id == 1 && input.total_balance == 150000)
+        .times(1)
+        .return_once(|_| {
+            Ok(saldo::Model {
+                saldo_id: 1,
+                user_id: 1,
+                total_balance: 150000,
+                withdraw_amount: None,
+                withdraw_time: None,
+                created_at: Some(Utc::now().naive_utc()),
+                updated_at: Some(Utc::now().naive_utc()),
+            })
+        });
+
+    let service = TopupService::new(
+        Arc::new(mock_topup_repo),
+        Arc::new(mock_saldo_repo),
+        Arc::new(MockUserRepositoryTrait::new()),
+        Arc::new(mock_payment_channel),
+        Arc::new(mock_fee_service()),
+        Arc::new(mock_limit_service()),
+        Arc::new(mock_fraud_engine()),
+        MinimumAmounts::default(),
+    );
+
+    let payload = br#"{"payment_reference":"TOP-1","status":"paid","amount":100000}"#;
+
+    let first = service
+        .handle_payment_callback(payload, "valid")
+        .await
+        .unwrap();
+    assert_eq!(first.message, "Payment callback processed");
+    assert_eq!(first.data.status, "paid");
+
+    let retried = service
+        .handle_payment_callback(payload, "valid")
+        .await
+        .unwrap();
+    assert_eq!(retried.message, "Payment callback already processed");
+    assert_eq!(retried.data.status, "paid");
+}
+
+#[tokio::test]
+async fn test_payment_callback_reopens_topup_when_fee_cannot_be_posted() {
+    let mut mock_topup_repo = MockTopupRepositoryTrait::new();
+    let mut mock_saldo_repo = MockSaldoRepositoryTrait::new();
+    let mut mock_payment_channel = MockPaymentChannelTrait::new();
+    let mut mock_fee_service = MockFeeServiceTrait::new();
+
+    let topup_with_fee = |reference: &str| {
+        let mut topup = pending_topup(reference);
+        topup.fee_amount = 2500;
+        topup.fee_rule_id = Some(7);
+        topup
+    };
+
+    mock_payment_channel
+        .expect_verify_signature()
+        .returning(|_, signature| signature == "valid");
+
+    mock_topup_repo
+        .expect_find_by_payment_reference()
+        .returning(move |reference| Ok(Some(topup_with_fee(reference))));
+    mock_topup_repo
+        .expect_transition_status()
+        .with(
+            predicate::eq(1),
+            predicate::eq("pending"),
+            predicate::eq("paid"),
+        )
+        .times(1)
+        .returning(|_, _, _| Ok(true));
+    mock_topup_repo
+        .expect_transition_status()
+        .with(
+            predicate::eq(1),
+            predicate::eq("paid"),
+            predicate::eq("pending"),
+        )
+        .times(1)
+        .returning(|_, _, _| Ok(true));
+
+    let mut credited = false;
+    mock_saldo_repo
+        .expect_find_by_user_id()
+        .times(2)
+        .returning(move |user_id| {
+            let total_balance = if std::mem::replace(&mut credited, true) {
+                147500
+            } else {
+                50000
+            };
+            Ok(Some(saldo::Model {
+                saldo_id: 1,
+                user_id,
+                total_balance,
+                withdraw_amount: None,
+                withdraw_time: None,
+                created_at: Some(Utc::now().naive_utc()),
+                updated_at: Some(Utc::now().naive_utc()),
+            }))
+        });
+    // Credited less the fee, then taken back out
+    mock_saldo_repo
+        .expect_update_balance()
+        .withf(|input| input.total_balance == 147500 || input.total_balance == 50000)
+        .times(2)
+        .returning(|input| {
+            Ok(saldo::Model {
+                saldo_id: 1,
+                user_id: input.user_id,
+                total_balance: input.total_balance,
+                withdraw_amount: None,
+                withdraw_time: None,
+                created_at: Some(Utc::now().naive_utc()),
+                updated_at: Some(Utc::now().naive_utc()),
+            })
+        });
+
+    mock_fee_service
+        .expect_post_fee()
+        .withf(|input| input.fee_rule_id == Some(7) && input.amount == 2500)
+        .times(1)
+        .returning(|_| Err(AppError::FeeRuleError("revenue ledger down".to_string()).into()));
+
+    let service = TopupService::new(
+        Arc::new(mock_topup_repo),
+        Arc::new(mock_saldo_repo),
+        Arc::new(MockUserRepositoryTrait::new()),
+        Arc::new(mock_payment_channel),
+        Arc::new(mock_fee_service),
+        Arc::new(mock_limit_service()),
+        Arc::new(mock_fraud_engine()),
+        MinimumAmounts::default(),
+    );
+
+    let payload = br#"{"payment_reference":"TOP-1","status":"paid","amount":100000}"#;
+
+    let result = service.handle_payment_callback(payload, "valid").await;
+
+    assert!(result.unwrap_err().message.contains("revenue ledger down"));
+}
+
+#[tokio::test]
+async fn test_payment_callback_invalid_signature() {
+    let mut mock_payment_channel = MockPaymentChannelTrait::new();
+
+    mock_payment_channel
+        .expect_verify_signature()
+        .return_const(false);
+
+    let service = TopupService::new(
+        Arc::new(MockTopupRepositoryTrait::new()),
+        Arc::new(MockSaldoRepositoryTrait::new()),
+        Arc::new(MockUserRepositoryTrait::new()),
+        Arc::new(mock_payment_channel),
+        Arc::new(mock_fee_service()),
+        Arc::new(mock_limit_service()),
+        Arc::new(mock_fraud_engine()),
+        MinimumAmounts::default(),
+    );
+
+    let payload = br#"{"payment_reference":"TOP-1","status":"paid","amount":100000}"#;
+
+    let result = service.handle_payment_callback(payload, "forged").await;
+
+    let error = result.unwrap_err();
+    assert_eq!(error.status, "error");
+    assert_eq!(error.message, "Invalid callback signature");
+}
+
+#[tokio::test]
+async fn test_payment_callback_amount_mismatch() {
+    let mut mock_topup_repo = MockTopupRepositoryTrait::new();
+    let mut mock_payment_channel = MockPaymentChannelTrait::new();
+
+    mock_payment_channel
+        .expect_verify_signature()
+        .return_const(true);
+
+    mock_topup_repo
+        .expect_find_by_payment_reference()
+        .returning(|reference| Ok(Some(pending_topup(reference))));
+    mock_topup_repo.expect_transition_status().never();
+
+    let service = TopupService::new(
+        Arc::new(mock_topup_repo),
+        Arc::new(MockSaldoRepositoryTrait::new()),
+        Arc::new(MockUserRepositoryTrait::new()),
+        Arc::new(mock_payment_channel),
+        Arc::new(mock_fee_service()),
+        Arc::new(mock_limit_service()),
+        Arc::new(mock_fraud_engine()),
+        MinimumAmounts::default(),
+    );
+
+    let payload = br#"{"payment_reference":"TOP-1","status":"paid","amount":1000}"#;
+
+    let result = service.handle_payment_callback(payload, "valid").await;
+
+    assert!(result.is_err());
+}
+
+#[tokio::test]
+async fn test_create_paid_topup_is_idempotent() {
+    let mut mock_topup_repo = MockTopupRepositoryTrait::new();
+    let mut mock_saldo_repo = MockSaldoRepositoryTrait::new();
+
+    mock_topup_repo
+        .expect_find_by_payment_reference()
+        .with(predicate::eq("VA-PAY-1"))
+        .return_once(|reference| {
+            let mut topup = pending_topup(reference);
+            topup.status = "paid".to_string();
+            Ok(Some(topup))
+        });
+    mock_topup_repo.expect_create().never();
+    mock_saldo_repo.expect_update_balance().never();
+
+    let service = TopupService::new(
+        Arc::new(mock_topup_repo),
+        Arc::new(mock_saldo_repo),
+        Arc::new(MockUserRepositoryTrait::new()),
+        Arc::new(MockPaymentChannelTrait::new()),
+        Arc::new(mock_fee_service()),
+        Arc::new(mock_limit_service()),
+        Arc::new(mock_fraud_engine()),
+        MinimumAmounts::default(),
+    );
+
+    let input = CreateTopupRequest {
+        user_id: 1,
+        topup_no: "PAY-1".to_string(),
+        topup_amount: 100000,
+        topup_method: "bca".to_string(),
+    };
+
+    let response = service.create_paid_topup(&input, "VA-PAY-1").await.unwrap();
+
+    assert_eq!(response.message, "Topup already recorded");
+    assert_eq!(response.data.status, "paid");
+}
+
+fn topup_owner_repo() -> MockUserRepositoryTrait {
+    let mut mock_user_repo = MockUserRepositoryTrait::new();
+    mock_user_repo.expect_find_by_id().returning(|user_id| {
+        Ok(Some(users::Model {
+            user_id,
+            firstname: "John".to_string(),
+            lastname: "Doe".to_string(),
+            email: "john.doe@example.com".to_string(),
+            password: "hashed_password".to_string(),
+            noc_transfer: "12345".to_string(),
+            kyc_level: 0,
+            role: "user".to_string(),
+            created_at: Some(Utc::now().naive_utc()),
+            updated_at: Some(Utc::now().naive_utc()),
+        }))
+    });
+    mock_user_repo
+}
+
+#[tokio::test]
+async fn test_update_topup_refuses_paid_and_foreign_topups() {
+    let mut mock_topup_repo = MockTopupRepositoryTrait::new();
+
+    mock_topup_repo.expect_find_by_id().returning(|_| {
+        let mut topup = pending_topup("TOP-1");
+        topup.status = "paid".to_string();
+        Ok(Some(topup))
+    });
+    mock_topup_repo.expect_update_amount().never();
+
+    // No saldo expectations: adjusting the saldo would panic the mock
+    let service = TopupService::new(
+        Arc::new(mock_topup_repo),
+        Arc::new(MockSaldoRepositoryTrait::new()),
+        Arc::new(topup_owner_repo()),
+        Arc::new(MockPaymentChannelTrait::new()),
+        Arc::new(mock_fee_service()),
+        Arc::new(mock_limit_service()),
+        Arc::new(mock_fraud_engine()),
+        MinimumAmounts::default(),
+    );
+
+    let mut input = UpdateTopupRequest {
+        user_id: 1,
+        topup_id: 1,
+        topup_amount: 900000,
+        topup_method: "mandiri".to_string(),
+    };
+
+    let error = service.update_topup(&input).await.unwrap_err();
+    assert_eq!(error.message, "Topup with status paid cannot be updated");
+
+    input.user_id = 2;
+
+    let error = service.update_topup(&input).await.unwrap_err();
+    assert_eq!(error.message, "Topup with id 1 not found");
+}
+
+#[tokio::test]
+async fn test_reverse_topup_takes_back_credit_and_fee() {
+    let mut mock_topup_repo = MockTopupRepositoryTrait::new();
+    let mut mock_saldo_repo = MockSaldoRepositoryTrait::new();
+    let mut mock_fee_service = MockFeeServiceTrait::new();
+
+    mock_topup_repo.expect_find_by_id().returning(|_| {
+        let mut topup = pending_topup("TOP-1");
+        topup.status = "paid".to_string();
+        topup.fee_amount = 2500;
+        topup.fee_rule_id = Some(7);
+        Ok(Some(topup))
+    });
+    mock_topup_repo
+        .expect_transition_status()
+        .with(
+            predicate::eq(1),
+            predicate::eq("paid"),
+            predicate::eq("reversed"),
+        )
+        .times(1)
+        .returning(|_, _, _| Ok(true));
+
+    mock_saldo_repo
+        .expect_find_by_user_id()
+        .returning(|user_id| {
+            Ok(Some(saldo::Model {
+                saldo_id: 1,
+                user_id,
+                total_balance: 120000,
+                withdraw_amount: None,
+                withdraw_time: None,
+                created_at: Some(Utc::now().naive_utc()),
+                updated_at: Some(Utc::now().naive_utc()),
+            }))
+        });
+    mock_saldo_repo
+        .expect_update_balance()
+        .withf(|input| input.user_id == 1 && input.total_balance == 22500)
+        .times(1)
+        .returning(|input| {
+            Ok(saldo::Model {
+                saldo_id: 1,
+                user_id: input.user_id,
+                total_balance: input.total_balance,
+                withdraw_amount: None,
+                withdraw_time: None,
+                created_at: Some(Utc::now().naive_utc()),
+                updated_at: Some(Utc::now().naive_utc()),
+            })
+        });
+
+    mock_fee_service
+        .expect_post_fee()
+        .withf(|input| input.fee_rule_id == Some(7) && input.amount == -2500)
+        .times(1)
+        .returning(|_| Ok(()));
+
+    let service = TopupService::new(
+        Arc::new(mock_topup_repo),
+        Arc::new(mock_saldo_repo),
+        Arc::new(MockUserRepositoryTrait::new()),
+        Arc::new(MockPaymentChannelTrait::new()),
+        Arc::new(mock_fee_service),
+        Arc::new(mock_limit_service()),
+        Arc::new(mock_fraud_engine()),
+        MinimumAmounts::default(),
+    );
+
+    let response = service.reverse_topup(1).await.unwrap();
+
+    assert_eq!(response.data.status, "reversed");
+}
@@ -1,23 +1,80 @@
 use std::sync::Arc;
 
-use chrono::{DateTime, Utc};
+use chrono::{Duration, NaiveDate, NaiveDateTime, Utc};
 use example_payment_gateway_axum::{
     abstract_trait::{
-        saldo::{MockSaldoRepositoryTrait, SaldoRepositoryTrait},
-        transfer::{MockTransferRepositoryTrait, TransferRepositoryTrait, TransferServiceTrait},
-        user::{MockUserRepositoryTrait, UserRepositoryTrait},
+        beneficiary::MockBeneficiaryRepositoryTrait,
+        clock::MockClockTrait,
+        fee::{FeeQuote, MockFeeServiceTrait},
+        fraud::MockFraudEngineTrait,
+        limit::MockLimitServiceTrait,
+        saldo::MockSaldoRepositoryTrait,
+        transfer::{MockTransferRepositoryTrait, TransferServiceTrait},
+        user::MockUserRepositoryTrait,
+    },
+    domain::{
+        request::{
+            limit::MinimumAmounts,
+            transfer::{
+                CreateTransferByAccountRequest, CreateTransferRequest, UpdateTransferRequest,
+            },
+        },
+        response::ErrorResponse,
+    },
+    entities::{beneficiaries, saldo, transfers, users},
+    services::transfer::{BeneficiaryCoolingOff, TransferService},
+    utils::{
+        errors::AppError,
+        metrics::METRICS,
+        random_vcc::{is_valid_account_number, is_valid_vcc, random_vcc},
     },
-    domain::request::transfer::{CreateTransferRequest, UpdateTransferRequest},
-    entities::{saldo, transfers, users},
-    services::transfer::TransferService,
 };
 use mockall::predicate;
 
+fn mock_fee_service() -> MockFeeServiceTrait {
+    let mut fee_service = MockFeeServiceTrait::new();
+    fee_service
+        .expect_calculate_fee()
+        .returning(|_| Ok(FeeQuote::default()));
+    fee_service.expect_post_fee().returning(|_| Ok(()));
+    fee_service
+}
+
+fn mock_limit_service() -> MockLimitServiceTrait {
+    let mut limit_service = MockLimitServiceTrait::new();
+    limit_service
+        .expect_check_transaction_limits()
+        .returning(|_| Ok(()));
+    limit_service
+        .expect_check_balance_limit()
+        .returning(|_| Ok(()));
+    limit_service
+}
+
+fn now() -> NaiveDateTime {
+    NaiveDate::from_ymd_opt(2026, 10, 19)
+        .unwrap()
+        .and_hms_opt(12, 0, 0)
+        .unwrap()
+}
+
+fn mock_clock() -> MockClockTrait {
+    let mut clock = MockClockTrait::new();
+    clock.expect_now().return_const(now());
+    clock
+}
+
+fn mock_fraud_engine() -> MockFraudEngineTrait {
+    let mut fraud_engine = MockFraudEngineTrait::new();
+    fraud_engine.expect_screen().returning(|_| Ok(()));
+    fraud_engine
+}
+
 #[tokio::test]
 async fn test_get_transfers() {
     let mut mock_transfer_repo = MockTransferRepositoryTrait::new();
-    let mut mock_saldo_repo = MockSaldoRepositoryTrait::new();
-    let mut mock_user_repo = MockUserRepositoryTrait::new();
+    let mock_saldo_repo = MockSaldoRepositoryTrait::new();
+    let mock_user_repo = MockUserRepositoryTrait::new();
 
     let mock_transfers = vec![
         transfers::Model {
@@ -25,7 +82,8 @@ async fn test_get_transfers() {
             transfer_from: 1,
             transfer_to: 2,
             transfer_amount: 10000,
-            transfer_time: DateTime::<Utc>::from(Utc::now()).naive_utc(),
+            transfer_time: Utc::now().naive_utc(),
+            fee_amount: 0,
             created_at: Some(Utc::now().naive_utc()),
             updated_at: Some(Utc::now().naive_utc()),
         },
@@ -34,7 +92,8 @@ async fn test_get_transfers() {
             transfer_from: 2,
             transfer_to: 1,
             transfer_amount: 5000,
-            transfer_time: DateTime::<Utc>::from(Utc::now()).naive_utc(),
+            transfer_time: Utc::now().naive_utc(),
+            fee_amount: 0,
             created_at: Some(Utc::now().naive_utc()),
             updated_at: Some(Utc::now().naive_utc()),
         },
@@ -48,6 +107,13 @@ async fn test_get_transfers() {
         Arc::new(mock_transfer_repo),
         Arc::new(mock_saldo_repo),
         Arc::new(mock_user_repo),
+        Arc::new(MockBeneficiaryRepositoryTrait::new()),
+        Arc::new(mock_fee_service()),
+        Arc::new(mock_limit_service()),
+        Arc::new(mock_fraud_engine()),
+        Arc::new(mock_clock()),
+        BeneficiaryCoolingOff::default(),
+        MinimumAmounts::default(),
     );
 
     let result = transfer_service.get_transfers().await;
@@ -80,7 +146,8 @@ async fn test_find_by_id_success() {
         transfer_from: 1,
         transfer_to: 2,
         transfer_amount: 10000,
-        transfer_time: DateTime::<Utc>::from(Utc::now()).naive_utc(),
+        transfer_time: Utc::now().naive_utc(),
+        fee_amount: 0,
         created_at: Some(Utc::now().naive_utc()),
         updated_at: Some(Utc::now().naive_utc()),
     };
@@ -94,6 +161,13 @@ async fn test_find_by_id_success() {
         Arc::new(mock_transfer_repo),
         Arc::new(_mock_saldo_repo),
         Arc::new(_mock_user_repo),
+        Arc::new(MockBeneficiaryRepositoryTrait::new()),
+        Arc::new(mock_fee_service()),
+        Arc::new(mock_limit_service()),
+        Arc::new(mock_fraud_engine()),
+        Arc::new(mock_clock()),
+        BeneficiaryCoolingOff::default(),
+        MinimumAmounts::default(),
     );
 
     let result = transfer_service.get_transfer(1).await;
@@ -128,6 +202,13 @@ async fn test_find_by_id_not_found() {
         Arc::new(mock_transfer_repo),
         Arc::new(_mock_saldo_repo),
         Arc::new(_mock_user_repo),
+        Arc::new(MockBeneficiaryRepositoryTrait::new()),
+        Arc::new(mock_fee_service()),
+        Arc::new(mock_limit_service()),
+        Arc::new(mock_fraud_engine()),
+        Arc::new(mock_clock()),
+        BeneficiaryCoolingOff::default(),
+        MinimumAmounts::default(),
     );
 
     let result = transfer_service.get_transfer(1).await;
@@ -152,7 +233,8 @@ async fn test_find_transfer_users() {
             transfer_from: 1,
             transfer_to: 2,
             transfer_amount: 10000,
-            transfer_time: DateTime::<Utc>::from(Utc::now()).naive_utc(),
+            transfer_time: Utc::now().naive_utc(),
+            fee_amount: 0,
             created_at: Some(Utc::now().naive_utc()),
             updated_at: Some(Utc::now().naive_utc()),
         },
@@ -161,7 +243,8 @@ async fn test_find_transfer_users() {
             transfer_from: 2,
             transfer_to: 1,
             transfer_amount: 5000,
-            transfer_time: DateTime::<Utc>::from(Utc::now()).naive_utc(),
+            transfer_time: Utc::now().naive_utc(),
+            fee_amount: 0,
             created_at: Some(Utc::now().naive_utc()),
             updated_at: Some(Utc::now().naive_utc()),
         },
@@ -175,6 +258,13 @@ async fn test_find_transfer_users() {
         Arc::new(mock_transfer_repo),
         Arc::new(_mock_saldo_repo),
         Arc::new(_mock_user_repo),
+        Arc::new(MockBeneficiaryRepositoryTrait::new()),
+        Arc::new(mock_fee_service()),
+        Arc::new(mock_limit_service()),
+        Arc::new(mock_fraud_engine()),
+        Arc::new(mock_clock()),
+        BeneficiaryCoolingOff::default(),
+        MinimumAmounts::default(),
     );
 
     let result = transfer_service.get_transfers().await;
@@ -210,6 +300,13 @@ async fn test_find_transfer_users_not_found() {
         Arc::new(mock_transfer_repo),
         Arc::new(_mock_saldo_repo),
         Arc::new(_mock_user_repo),
+        Arc::new(MockBeneficiaryRepositoryTrait::new()),
+        Arc::new(mock_fee_service()),
+        Arc::new(mock_limit_service()),
+        Arc::new(mock_fraud_engine()),
+        Arc::new(mock_clock()),
+        BeneficiaryCoolingOff::default(),
+        MinimumAmounts::default(),
     );
 
     let result = transfer_service.get_transfers().await;
@@ -235,6 +332,8 @@ async fn test_get_transfer_user_success() {
         email: "john.doe@example.com".to_string(),
         password: "hashed_password".to_string(),
         noc_transfer: "12345".to_string(),
+        kyc_level: 0,
+        role: "user".to_string(),
         created_at: Some(Utc::now().naive_utc()),
         updated_at: Some(Utc::now().naive_utc()),
     };
@@ -244,7 +343,8 @@ async fn test_get_transfer_user_success() {
         transfer_from: user_id,
         transfer_to: 2,
         transfer_amount: 10000,
-        transfer_time: DateTime::<Utc>::from(Utc::now()).naive_utc(),
+        transfer_time: Utc::now().naive_utc(),
+        fee_amount: 0,
         created_at: Some(Utc::now().naive_utc()),
         updated_at: Some(Utc::now().naive_utc()),
     };
@@ -266,6 +366,13 @@ async fn test_get_transfer_user_success() {
         Arc::new(mock_transfer_repo),
         Arc::new(MockSaldoRepositoryTrait::new()),
         Arc::new(mock_user_repo),
+        Arc::new(MockBeneficiaryRepositoryTrait::new()),
+        Arc::new(mock_fee_service()),
+        Arc::new(mock_limit_service()),
+        Arc::new(mock_fraud_engine()),
+        Arc::new(mock_clock()),
+        BeneficiaryCoolingOff::default(),
+        MinimumAmounts::default(),
     );
 
     // Execute test
@@ -303,6 +410,8 @@ async fn test_get_transfer_user_not_found() {
         email: "john.doe@example.com".to_string(),
         password: "hashed_password".to_string(),
         noc_transfer: "12345".to_string(),
+        kyc_level: 0,
+        role: "user".to_string(),
         created_at: Some(Utc::now().naive_utc()),
         updated_at: Some(Utc::now().naive_utc()),
     };
@@ -322,6 +431,13 @@ async fn test_get_transfer_user_not_found() {
         Arc::new(mock_transfer_repo),
         Arc::new(MockSaldoRepositoryTrait::new()),
         Arc::new(mock_user_repo),
+        Arc::new(MockBeneficiaryRepositoryTrait::new()),
+        Arc::new(mock_fee_service()),
+        Arc::new(mock_limit_service()),
+        Arc::new(mock_fraud_engine()),
+        Arc::new(mock_clock()),
+        BeneficiaryCoolingOff::default(),
+        MinimumAmounts::default(),
     );
 
     // Execute test
@@ -341,16 +457,24 @@ async fn test_create_transfer_success() {
     let mut mock_transfer_repo = MockTransferRepositoryTrait::new();
     let mut mock_saldo_repo = MockSaldoRepositoryTrait::new();
 
+    // User 2 has been paid by user 1 long before the cooling-off window
+    mock_transfer_repo
+        .expect_find_first_transfer_time()
+        .returning(|_, _| Ok(Some(now() - Duration::days(30))));
+
     let sender_id = 1;
     let receiver_id = 2;
     let transfer_amount = 50000000;
-    let sender_initial_balance = 500000;
+    let sender_initial_balance = 60000000;
     let receiver_initial_balance = 500000;
 
     let create_request = CreateTransferRequest {
         transfer_from: sender_id,
         transfer_to: receiver_id,
         transfer_amount,
+        beneficiary_id: None,
+        device_id: None,
+        merchant_payment: false,
     };
 
     mock_user_repo
@@ -364,6 +488,8 @@ async fn test_create_transfer_success() {
                 email: "sender@test.com".to_string(),
                 password: "hash".to_string(),
                 noc_transfer: "12345".to_string(),
+                kyc_level: 0,
+                role: "user".to_string(),
                 created_at: Some(Utc::now().naive_utc()),
                 updated_at: Some(Utc::now().naive_utc()),
             }))
@@ -380,6 +506,8 @@ async fn test_create_transfer_success() {
                 email: "receiver@test.com".to_string(),
                 password: "hash".to_string(),
                 noc_transfer: "67890".to_string(),
+                kyc_level: 0,
+                role: "user".to_string(),
                 created_at: Some(Utc::now().naive_utc()),
                 updated_at: Some(Utc::now().naive_utc()),
             }))
@@ -391,6 +519,7 @@ async fn test_create_transfer_success() {
         transfer_to: receiver_id,
         transfer_amount,
         transfer_time: Utc::now().naive_utc(),
+        fee_amount: 0,
         created_at: Some(Utc::now().naive_utc()),
         updated_at: Some(Utc::now().naive_utc()),
     };
@@ -399,12 +528,12 @@ async fn test_create_transfer_success() {
 
     mock_transfer_repo
         .expect_create()
-        .withf(move |req| {
+        .withf(move |req, _| {
             req.transfer_from == create_request.transfer_from
                 && req.transfer_to == create_request.transfer_to
                 && req.transfer_amount == create_request.transfer_amount
         })
-        .return_once(move |_| Ok(expected_transfer_clone));
+        .return_once(move |_, _| Ok(expected_transfer_clone));
 
     mock_saldo_repo
         .expect_find_by_user_id()
@@ -476,6 +605,13 @@ async fn test_create_transfer_success() {
         Arc::new(mock_transfer_repo),
         Arc::new(mock_saldo_repo),
         Arc::new(mock_user_repo),
+        Arc::new(MockBeneficiaryRepositoryTrait::new()),
+        Arc::new(mock_fee_service()),
+        Arc::new(mock_limit_service()),
+        Arc::new(mock_fraud_engine()),
+        Arc::new(mock_clock()),
+        BeneficiaryCoolingOff::default(),
+        MinimumAmounts::default(),
     );
 
     let result = service.create_transfer(&create_request).await;
@@ -490,6 +626,319 @@ async fn test_create_transfer_success() {
     assert_eq!(data.transfer_amount, expected_transfer.transfer_amount);
 }
 
+#[tokio::test]
+async fn test_merchant_payment_is_not_charged_the_transfer_fee() {
+    let mut mock_user_repo = MockUserRepositoryTrait::new();
+    let mut mock_transfer_repo = MockTransferRepositoryTrait::new();
+    let mut mock_saldo_repo = MockSaldoRepositoryTrait::new();
+    let mut mock_fee_service = MockFeeServiceTrait::new();
+
+    // The merchant pays its fee at settlement, so the payer is never quoted one
+    mock_fee_service.expect_calculate_fee().never();
+    mock_fee_service
+        .expect_post_fee()
+        .withf(|input| input.amount == 0)
+        .returning(|_| Ok(()));
+
+    mock_user_repo
+        .expect_find_by_id()
+        .returning(|user_id| Ok(Some(new_friend_user(user_id))));
+
+    mock_transfer_repo
+        .expect_find_first_transfer_time()
+        .returning(|_, _| Ok(Some(now() - Duration::days(30))));
+
+    mock_transfer_repo
+        .expect_create()
+        .withf(|_, fee_amount| *fee_amount == 0)
+        .return_once(|input, _| {
+            Ok(transfers::Model {
+                transfer_id: 1,
+                transfer_from: input.transfer_from,
+                transfer_to: input.transfer_to,
+                transfer_amount: input.transfer_amount,
+                transfer_time: Utc::now().naive_utc(),
+                fee_amount: 0,
+                created_at: Some(Utc::now().naive_utc()),
+                updated_at: Some(Utc::now().naive_utc()),
+            })
+        });
+
+    mock_saldo_repo
+        .expect_find_by_user_id()
+        .returning(|user_id| {
+            Ok(Some(saldo::Model {
+                saldo_id: user_id,
+                user_id,
+                total_balance: 200000,
+                withdraw_amount: None,
+                withdraw_time: None,
+                created_at: Some(Utc::now().naive_utc()),
+                updated_at: Some(Utc::now().naive_utc()),
+            }))
+        });
+
+    mock_saldo_repo
+        .expect_update_balance()
+        .withf(|req| {
+            (req.user_id == 1 && req.total_balance == 125000)
+                || (req.user_id == 2 && req.total_balance == 275000)
+        })
+        .times(2)
+        .returning(|req| {
+            Ok(saldo::Model {
+                saldo_id: req.user_id,
+                user_id: req.user_id,
+                total_balance: req.total_balance,
+                withdraw_amount: None,
+                withdraw_time: None,
+                created_at: Some(Utc::now().naive_utc()),
+                updated_at: Some(Utc::now().naive_utc()),
+            })
+        });
+
+    let service = TransferService::new(
+        Arc::new(mock_transfer_repo),
+        Arc::new(mock_saldo_repo),
+        Arc::new(mock_user_repo),
+        Arc::new(MockBeneficiaryRepositoryTrait::new()),
+        Arc::new(mock_fee_service),
+        Arc::new(mock_limit_service()),
+        Arc::new(mock_fraud_engine()),
+        Arc::new(mock_clock()),
+        BeneficiaryCoolingOff::default(),
+        MinimumAmounts::default(),
+    );
+
+    let request = CreateTransferRequest {
+        transfer_from: 1,
+        transfer_to: 2,
+        transfer_amount: 75000,
+        beneficiary_id: None,
+        device_id: None,
+        merchant_payment: true,
+    };
+
+    let response = service.create_transfer(&request).await.unwrap();
+
+    assert_eq!(response.data.transfer_amount, 75000);
+}
+
+#[tokio::test]
+async fn test_create_transfer_insufficient_balance() {
+    let mut mock_user_repo = MockUserRepositoryTrait::new();
+    let mut mock_transfer_repo = MockTransferRepositoryTrait::new();
+    let mut mock_saldo_repo = MockSaldoRepositoryTrait::new();
+
+    // User 2 has never been paid by user 1
+    mock_transfer_repo
+        .expect_find_first_transfer_time()
+        .returning(|_, _| Ok(None));
+
+    let create_request = CreateTransferRequest {
+        transfer_from: 1,
+        transfer_to: 2,
+        transfer_amount: 100000,
+        beneficiary_id: None,
+        device_id: None,
+        merchant_payment: false,
+    };
+
+    mock_user_repo.expect_find_by_id().returning(|id| {
+        Ok(Some(users::Model {
+            user_id: id,
+            firstname: "Test".to_string(),
+            lastname: "User".to_string(),
+            email: format!("user{}@test.com", id),
+            password: "hash".to_string(),
+            noc_transfer: id.to_string(),
+            kyc_level: 0,
+            role: "user".to_string(),
+            created_at: Some(Utc::now().naive_utc()),
+            updated_at: Some(Utc::now().naive_utc()),
+        }))
+    });
+
+    mock_saldo_repo
+        .expect_find_by_user_id()
+        .with(predicate::eq(1))
+        .return_once(|_| {
+            Ok(Some(saldo::Model {
+                saldo_id: 1,
+                user_id: 1,
+                total_balance: 50000,
+                withdraw_amount: None,
+                withdraw_time: None,
+                created_at: Some(Utc::now().naive_utc()),
+                updated_at: Some(Utc::now().naive_utc()),
+            }))
+        });
+
+    let service = TransferService::new(
+        Arc::new(mock_transfer_repo),
+        Arc::new(mock_saldo_repo),
+        Arc::new(mock_user_repo),
+        Arc::new(MockBeneficiaryRepositoryTrait::new()),
+        Arc::new(mock_fee_service()),
+        Arc::new(mock_limit_service()),
+        Arc::new(mock_fraud_engine()),
+        Arc::new(mock_clock()),
+        BeneficiaryCoolingOff::default(),
+        MinimumAmounts::default(),
+    );
+
+    let rejections = METRICS.insufficient_funds.with_label_values(&["transfer"]);
+    let failed = METRICS
+        .transaction_amount
+        .with_label_values(&["transfer", "wallet", "failed"]);
+    let (rejections_before, failed_before) = (rejections.get(), failed.get());
+
+    let result = service.create_transfer(&create_request).await;
+
+    let error = result.unwrap_err();
+    assert_eq!(error.status, "error");
+    assert_eq!(error.message, "Insufficient balance");
+
+    // Other tests share the counters, so only a lower bound holds
+    assert!(rejections.get() > rejections_before);
+    assert!(failed.get() >= failed_before + 100000);
+}
+
+#[test]
+fn test_generated_account_numbers_pass_luhn_check() {
+    for _ in 0..100 {
+        assert!(is_valid_vcc(&random_vcc().unwrap()));
+    }
+
+    assert!(!is_valid_vcc("4111111111111112"));
+    assert!(!is_valid_vcc("411111111111111a"));
+}
+
+#[test]
+fn test_legacy_account_numbers_are_still_addressable() {
+    // 17 digits from the generator used before the 16-digit format
+    assert!(is_valid_account_number("41234567890123454"));
+    assert!(!is_valid_vcc("41234567890123454"));
+
+    assert!(is_valid_account_number("4111111111111111"));
+    assert!(!is_valid_account_number("41234567890123455"));
+    assert!(!is_valid_account_number("51234567890123454"));
+}
+
+#[tokio::test]
+async fn test_find_recipient_masks_name() {
+    let mut mock_user_repo = MockUserRepositoryTrait::new();
+
+    mock_user_repo
+        .expect_find_by_noc_transfer()
+        .with(predicate::eq("4111111111111111"))
+        .return_once(|noc_transfer| {
+            Ok(Some(users::Model {
+                user_id: 2,
+                firstname: "Budi".to_string(),
+                lastname: "Santoso".to_string(),
+                email: "budi@test.com".to_string(),
+                password: "hash".to_string(),
+                noc_transfer: noc_transfer.to_string(),
+                kyc_level: 0,
+                role: "user".to_string(),
+                created_at: Some(Utc::now().naive_utc()),
+                updated_at: Some(Utc::now().naive_utc()),
+            }))
+        });
+
+    let service = TransferService::new(
+        Arc::new(MockTransferRepositoryTrait::new()),
+        Arc::new(MockSaldoRepositoryTrait::new()),
+        Arc::new(mock_user_repo),
+        Arc::new(MockBeneficiaryRepositoryTrait::new()),
+        Arc::new(mock_fee_service()),
+        Arc::new(mock_limit_service()),
+        Arc::new(mock_fraud_engine()),
+        Arc::new(mock_clock()),
+        BeneficiaryCoolingOff::default(),
+        MinimumAmounts::default(),
+    );
+
+    let response = service.find_recipient("4111111111111111").await.unwrap();
+
+    assert_eq!(response.data.noc_transfer, "4111111111111111");
+    assert_eq!(response.data.masked_name, "B*** S******");
+}
+
+#[tokio::test]
+async fn test_find_recipient_rejects_invalid_account_number() {
+    let service = TransferService::new(
+        Arc::new(MockTransferRepositoryTrait::new()),
+        Arc::new(MockSaldoRepositoryTrait::new()),
+        Arc::new(MockUserRepositoryTrait::new()),
+        Arc::new(MockBeneficiaryRepositoryTrait::new()),
+        Arc::new(mock_fee_service()),
+        Arc::new(mock_limit_service()),
+        Arc::new(mock_fraud_engine()),
+        Arc::new(mock_clock()),
+        BeneficiaryCoolingOff::default(),
+        MinimumAmounts::default(),
+    );
+
+    let error = service
+        .find_recipient("4111111111111112")
+        .await
+        .unwrap_err();
+
+    assert_eq!(error.status, "Error Validation");
+    assert_eq!(error.message, "Invalid recipient account number");
+}
+
+#[tokio::test]
+async fn test_create_transfer_by_account_to_own_account() {
+    let mut mock_user_repo = MockUserRepositoryTrait::new();
+
+    mock_user_repo
+        .expect_find_by_noc_transfer()
+        .return_once(|noc_transfer| {
+            Ok(Some(users::Model {
+                user_id: 1,
+                firstname: "Test".to_string(),
+                lastname: "User".to_string(),
+                email: "user1@test.com".to_string(),
+                password: "hash".to_string(),
+                noc_transfer: noc_transfer.to_string(),
+                kyc_level: 0,
+                role: "user".to_string(),
+                created_at: Some(Utc::now().naive_utc()),
+                updated_at: Some(Utc::now().naive_utc()),
+            }))
+        });
+
+    let service = TransferService::new(
+        Arc::new(MockTransferRepositoryTrait::new()),
+        Arc::new(MockSaldoRepositoryTrait::new()),
+        Arc::new(mock_user_repo),
+        Arc::new(MockBeneficiaryRepositoryTrait::new()),
+        Arc::new(mock_fee_service()),
+        Arc::new(mock_limit_service()),
+        Arc::new(mock_fraud_engine()),
+        Arc::new(mock_clock()),
+        BeneficiaryCoolingOff::default(),
+        MinimumAmounts::default(),
+    );
+
+    let request = CreateTransferByAccountRequest {
+        transfer_from: 1,
+        noc_transfer: "4111111111111111".to_string(),
+        transfer_amount: 50000,
+        device_id: None,
+    };
+
+    let error = service
+        .create_transfer_by_account(&request)
+        .await
+        .unwrap_err();
+
+    assert_eq!(error.message, "Cannot transfer to your own account");
+}
+
 #[tokio::test]
 async fn test_update_transfer_success() {
     let mut _mock_user_repo = MockUserRepositoryTrait::new();
@@ -517,6 +966,7 @@ async fn test_update_transfer_success() {
         transfer_to: receiver_id,
         transfer_amount: original_transfer_amount,
         transfer_time: Utc::now().naive_utc(),
+        fee_amount: 0,
         created_at: Some(Utc::now().naive_utc()),
         updated_at: Some(Utc::now().naive_utc()),
     };
@@ -597,6 +1047,7 @@ async fn test_update_transfer_success() {
         transfer_to: receiver_id,
         transfer_amount: new_transfer_amount,
         transfer_time: Utc::now().naive_utc(),
+        fee_amount: 0,
         created_at: Some(Utc::now().naive_utc()),
         updated_at: Some(Utc::now().naive_utc()),
     };
@@ -612,6 +1063,13 @@ async fn test_update_transfer_success() {
         Arc::new(mock_transfer_repo),
         Arc::new(mock_saldo_repo),
         Arc::new(_mock_user_repo),
+        Arc::new(MockBeneficiaryRepositoryTrait::new()),
+        Arc::new(mock_fee_service()),
+        Arc::new(mock_limit_service()),
+        Arc::new(mock_fraud_engine()),
+        Arc::new(mock_clock()),
+        BeneficiaryCoolingOff::default(),
+        MinimumAmounts::default(),
     );
 
     let result = service.update_transfer(&update_request).await;
@@ -639,6 +1097,8 @@ async fn test_delete_transfer_success() {
         email: "john.doe@example.com".to_string(),
         password: "hashed_password".to_string(),
         noc_transfer: "12345".to_string(),
+        kyc_level: 0,
+        role: "user".to_string(),
         created_at: Some(Utc::now().naive_utc()),
         updated_at: Some(Utc::now().naive_utc()),
     };
@@ -648,12 +1108,13 @@ async fn test_delete_transfer_success() {
         transfer_from: user_id,
         transfer_to: 2,
         transfer_amount: 10000,
-        transfer_time: DateTime::<Utc>::from(Utc::now()).naive_utc(),
+        transfer_time: Utc::now().naive_utc(),
+        fee_amount: 0,
         created_at: Some(Utc::now().naive_utc()),
         updated_at: Some(Utc::now().naive_utc()),
     };
 
-    let mock_transfer_id = mock_transfer.transfer_id.clone();
+    let mock_transfer_id = mock_transfer.transfer_id;
 
     mock_user_repo
         .expect_find_by_id()
@@ -674,6 +1135,13 @@ async fn test_delete_transfer_success() {
         Arc::new(mock_transfer_repo),
         Arc::new(MockSaldoRepositoryTrait::new()),
         Arc::new(mock_user_repo),
+        Arc::new(MockBeneficiaryRepositoryTrait::new()),
+        Arc::new(mock_fee_service()),
+        Arc::new(mock_limit_service()),
+        Arc::new(mock_fraud_engine()),
+        Arc::new(mock_clock()),
+        BeneficiaryCoolingOff::default(),
+        MinimumAmounts::default(),
     );
 
     let result = service.delete_transfer(user_id).await;
@@ -701,6 +1169,8 @@ async fn test_delete_transfer_not_found() {
                 email: "test@example.com".to_string(),
                 password: "hash".to_string(),
                 noc_transfer: "12345".to_string(),
+                kyc_level: 0,
+                role: "user".to_string(),
                 created_at: Some(Utc::now().naive_utc()),
                 updated_at: Some(Utc::now().naive_utc()),
             }))
@@ -715,6 +1185,13 @@ async fn test_delete_transfer_not_found() {
         Arc::new(mock_transfer_repo),
         Arc::new(MockSaldoRepositoryTrait::new()),
         Arc::new(mock_user_repo),
+        Arc::new(MockBeneficiaryRepositoryTrait::new()),
+        Arc::new(mock_fee_service()),
+        Arc::new(mock_limit_service()),
+        Arc::new(mock_fraud_engine()),
+        Arc::new(mock_clock()),
+        BeneficiaryCoolingOff::default(),
+        MinimumAmounts::default(),
     );
 
     let result = service.delete_transfer(user_id).await;
@@ -727,3 +1204,457 @@ async fn test_delete_transfer_not_found() {
         format!("Topup with id {} not found", user_id)
     );
 }
+
+fn new_friend_user(user_id: i32) -> users::Model {
+    // User 2 is the new friend, user 1 the sender
+    let noc_transfer = match user_id {
+        2 => "4111111111111111".to_string(),
+        _ => "4000000000000006".to_string(),
+    };
+
+    users::Model {
+        user_id,
+        firstname: "New".to_string(),
+        lastname: "Friend".to_string(),
+        email: format!("user{}@test.com", user_id),
+        password: "hash".to_string(),
+        noc_transfer,
+        kyc_level: 0,
+        role: "user".to_string(),
+        created_at: Some(Utc::now().naive_utc()),
+        updated_at: Some(Utc::now().naive_utc()),
+    }
+}
+
+fn new_friend_beneficiary() -> beneficiaries::Model {
+    beneficiaries::Model {
+        beneficiary_id: 3,
+        user_id: 1,
+        alias: "New friend".to_string(),
+        beneficiary_type: "account".to_string(),
+        noc_transfer: Some("4111111111111111".to_string()),
+        bank_code: None,
+        bank_account_number: None,
+        verified: true,
+        created_at: Some(Utc::now().naive_utc()),
+        updated_at: Some(Utc::now().naive_utc()),
+    }
+}
+
+/// A service where user 1 first paid user 2 at `first_paid_at` and has sent
+/// them 900000 since.
+fn new_friend_service(
+    mock_beneficiary_repo: MockBeneficiaryRepositoryTrait,
+    first_paid_at: NaiveDateTime,
+) -> TransferService {
+    let mut mock_user_repo = MockUserRepositoryTrait::new();
+    let mut mock_transfer_repo = MockTransferRepositoryTrait::new();
+
+    mock_user_repo
+        .expect_find_by_id()
+        .returning(|user_id| Ok(Some(new_friend_user(user_id))));
+
+    mock_user_repo
+        .expect_find_by_noc_transfer()
+        .with(predicate::eq("4111111111111111"))
+        .returning(|_| Ok(Some(new_friend_user(2))));
+
+    mock_transfer_repo
+        .expect_find_first_transfer_time()
+        .with(predicate::eq(1), predicate::eq(2))
+        .return_once(move |_, _| Ok(Some(first_paid_at)));
+
+    mock_transfer_repo
+        .expect_sum_amount_since()
+        .with(
+            predicate::eq(1),
+            predicate::eq(2),
+            predicate::eq(first_paid_at),
+        )
+        .return_once(|_, _, _| Ok(900000));
+
+    TransferService::new(
+        Arc::new(mock_transfer_repo),
+        Arc::new(MockSaldoRepositoryTrait::new()),
+        Arc::new(mock_user_repo),
+        Arc::new(mock_beneficiary_repo),
+        Arc::new(mock_fee_service()),
+        Arc::new(mock_limit_service()),
+        Arc::new(mock_fraud_engine()),
+        Arc::new(mock_clock()),
+        BeneficiaryCoolingOff::default(),
+        MinimumAmounts::default(),
+    )
+}
+
+#[tokio::test]
+async fn test_create_transfer_to_new_beneficiary_over_cooling_off_limit() {
+    let mut mock_beneficiary_repo = MockBeneficiaryRepositoryTrait::new();
+
+    mock_beneficiary_repo
+        .expect_find_by_id()
+        .with(predicate::eq(3))
+        .return_once(|_| Ok(Some(new_friend_beneficiary())));
+
+    let service = new_friend_service(mock_beneficiary_repo, now() - Duration::hours(1));
+
+    let request = CreateTransferRequest {
+        transfer_from: 1,
+        transfer_to: 0,
+        transfer_amount: 200000,
+        beneficiary_id: Some(3),
+        device_id: None,
+        merchant_payment: false,
+    };
+
+    let error = service.create_transfer(&request).await.unwrap_err();
+
+    assert_eq!(
+        error.message,
+        "Transfers to a recipient first paid in the last 24 hours are limited to 1000000"
+    );
+}
+
+#[tokio::test]
+async fn test_create_transfer_by_account_to_new_recipient_over_cooling_off_limit() {
+    // No beneficiary is involved; the transfer history alone marks user 2 as new
+    let service = new_friend_service(
+        MockBeneficiaryRepositoryTrait::new(),
+        now() - Duration::hours(23),
+    );
+
+    let request = CreateTransferByAccountRequest {
+        transfer_from: 1,
+        noc_transfer: "4111111111111111".to_string(),
+        transfer_amount: 200000,
+        device_id: None,
+    };
+
+    let error = service
+        .create_transfer_by_account(&request)
+        .await
+        .unwrap_err();
+
+    assert_eq!(
+        error.message,
+        "Transfers to a recipient first paid in the last 24 hours are limited to 1000000"
+    );
+}
+
+#[tokio::test]
+async fn test_create_transfer_to_first_time_recipient_over_cooling_off_limit() {
+    let mut mock_user_repo = MockUserRepositoryTrait::new();
+    let mut mock_transfer_repo = MockTransferRepositoryTrait::new();
+
+    mock_user_repo
+        .expect_find_by_id()
+        .returning(|user_id| Ok(Some(new_friend_user(user_id))));
+
+    mock_transfer_repo
+        .expect_find_first_transfer_time()
+        .return_once(|_, _| Ok(None));
+    mock_transfer_repo.expect_sum_amount_since().never();
+    mock_transfer_repo.expect_create().never();
+
+    let service = TransferService::new(
+        Arc::new(mock_transfer_repo),
+        Arc::new(MockSaldoRepositoryTrait::new()),
+        Arc::new(mock_user_repo),
+        Arc::new(MockBeneficiaryRepositoryTrait::new()),
+        Arc::new(mock_fee_service()),
+        Arc::new(mock_limit_service()),
+        Arc::new(mock_fraud_engine()),
+        Arc::new(mock_clock()),
+        BeneficiaryCoolingOff::default(),
+        MinimumAmounts::default(),
+    );
+
+    let request = CreateTransferRequest {
+        transfer_from: 1,
+        transfer_to: 2,
+        transfer_amount: 1_500_000,
+        beneficiary_id: None,
+        device_id: None,
+        merchant_payment: false,
+    };
+
+    let error = service.create_transfer(&request).await.unwrap_err();
+
+    assert_eq!(
+        error.message,
+        "Transfers to a recipient first paid in the last 24 hours are limited to 1000000"
+    );
+}
+
+#[tokio::test]
+async fn test_create_transfer_to_recipient_paid_before_the_window_is_not_limited() {
+    let mut mock_user_repo = MockUserRepositoryTrait::new();
+    let mut mock_transfer_repo = MockTransferRepositoryTrait::new();
+    let mut mock_limit_service = MockLimitServiceTrait::new();
+
+    mock_user_repo
+        .expect_find_by_id()
+        .returning(|user_id| Ok(Some(new_friend_user(user_id))));
+
+    mock_transfer_repo
+        .expect_find_first_transfer_time()
+        .return_once(|_, _| Ok(Some(now() - Duration::hours(25))));
+    mock_transfer_repo.expect_sum_amount_since().never();
+
+    // Reaching the tier limits means the cooling-off check let it through
+    mock_limit_service
+        .expect_check_transaction_limits()
+        .return_once(|_| {
+            Err(ErrorResponse::from(AppError::ValidationError(
+                "Daily limit reached".to_string(),
+            )))
+        });
+
+    let service = TransferService::new(
+        Arc::new(mock_transfer_repo),
+        Arc::new(MockSaldoRepositoryTrait::new()),
+        Arc::new(mock_user_repo),
+        Arc::new(MockBeneficiaryRepositoryTrait::new()),
+        Arc::new(mock_fee_service()),
+        Arc::new(mock_limit_service),
+        Arc::new(mock_fraud_engine()),
+        Arc::new(mock_clock()),
+        BeneficiaryCoolingOff::default(),
+        MinimumAmounts::default(),
+    );
+
+    let request = CreateTransferRequest {
+        transfer_from: 1,
+        transfer_to: 2,
+        transfer_amount: 1_500_000,
+        beneficiary_id: None,
+        device_id: None,
+        merchant_payment: false,
+    };
+
+    let error = service.create_transfer(&request).await.unwrap_err();
+
+    assert_eq!(error.message, "Daily limit reached");
+}
+
+#[tokio::test]
+async fn test_create_transfer_charges_fee_to_sender() {
+    let mut mock_user_repo = MockUserRepositoryTrait::new();
+    let mut mock_transfer_repo = MockTransferRepositoryTrait::new();
+    let mut mock_saldo_repo = MockSaldoRepositoryTrait::new();
+    let mut mock_fee_service = MockFeeServiceTrait::new();
+
+    // User 2 has never been paid by user 1
+    mock_transfer_repo
+        .expect_find_first_transfer_time()
+        .returning(|_, _| Ok(None));
+
+    mock_user_repo.expect_find_by_id().returning(|user_id| {
+        Ok(Some(users::Model {
+            user_id,
+            firstname: "Test".to_string(),
+            lastname: "User".to_string(),
+            email: format!("user{}@test.com", user_id),
+            password: "hash".to_string(),
+            noc_transfer: format!("{}", 12345 + user_id),
+            kyc_level: 0,
+            role: "user".to_string(),
+            created_at: Some(Utc::now().naive_utc()),
+            updated_at: Some(Utc::now().naive_utc()),
+        }))
+    });
+
+    mock_fee_service
+        .expect_calculate_fee()
+        .withf(|input| input.transaction_type == "transfer" && input.amount == 100000)
+        .returning(|_| {
+            Ok(FeeQuote {
+                fee_rule_id: Some(3),
+                fee_amount: 1000,
+            })
+        });
+
+    mock_fee_service
+        .expect_post_fee()
+        .withf(|input| {
+            input.transaction_type == "transfer"
+                && input.transaction_id == 1
+                && input.fee_rule_id == Some(3)
+                && input.amount == 1000
+        })
+        .times(1)
+        .returning(|_| Ok(()));
+
+    mock_transfer_repo
+        .expect_create()
+        .withf(|_, fee_amount| *fee_amount == 1000)
+        .return_once(|input, fee_amount| {
+            Ok(transfers::Model {
+                transfer_id: 1,
+                transfer_from: input.transfer_from,
+                transfer_to: input.transfer_to,
+                transfer_amount: input.transfer_amount,
+                transfer_time: Utc::now().naive_utc(),
+                fee_amount,
+                created_at: Some(Utc::now().naive_utc()),
+                updated_at: Some(Utc::now().naive_utc()),
+            })
+        });
+
+    mock_saldo_repo
+        .expect_find_by_user_id()
+        .returning(|user_id| {
+            Ok(Some(saldo::Model {
+                saldo_id: user_id,
+                user_id,
+                total_balance: 200000,
+                withdraw_amount: None,
+                withdraw_time: None,
+                created_at: Some(Utc::now().naive_utc()),
+                updated_at: Some(Utc::now().naive_utc()),
+            }))
+        });
+
+    // Sender pays amount plus fee, receiver gets the amount
+    mock_saldo_repo
+        .expect_update_balance()
+        .withf(|req| {
+            (req.user_id == 1 && req.total_balance == 99000)
+                || (req.user_id == 2 && req.total_balance == 300000)
+        })
+        .times(2)
+        .returning(|req| {
+            Ok(saldo::Model {
+                saldo_id: req.user_id,
+                user_id: req.user_id,
+                total_balance: req.total_balance,
+                withdraw_amount: None,
+                withdraw_time: None,
+                created_at: Some(Utc::now().naive_utc()),
+                updated_at: Some(Utc::now().naive_utc()),
+            })
+        });
+
+    let service = TransferService::new(
+        Arc::new(mock_transfer_repo),
+        Arc::new(mock_saldo_repo),
+        Arc::new(mock_user_repo),
+        Arc::new(MockBeneficiaryRepositoryTrait::new()),
+        Arc::new(mock_fee_service),
+        Arc::new(mock_limit_service()),
+        Arc::new(mock_fraud_engine()),
+        Arc::new(mock_clock()),
+        BeneficiaryCoolingOff::default(),
+        MinimumAmounts::default(),
+    );
+
+    let response = service
+        .create_transfer(&CreateTransferRequest {
+            transfer_from: 1,
+            transfer_to: 2,
+            transfer_amount: 100000,
+            beneficiary_id: None,
+            device_id: None,
+            merchant_payment: false,
+        })
+        .await
+        .unwrap();
+
+    assert_eq!(response.data.fee_amount, 1000);
+}
+
+#[tokio::test]
+async fn test_create_transfer_held_for_review_moves_no_money() {
+    let mut mock_user_repo = MockUserRepositoryTrait::new();
+    let mut mock_transfer_repo = MockTransferRepositoryTrait::new();
+    let mut mock_saldo_repo = MockSaldoRepositoryTrait::new();
+    let mut mock_fee_service = MockFeeServiceTrait::new();
+    let mut mock_fraud_engine = MockFraudEngineTrait::new();
+
+    // User 2 has never been paid by user 1
+    mock_transfer_repo
+        .expect_find_first_transfer_time()
+        .returning(|_, _| Ok(None));
+
+    mock_user_repo.expect_find_by_id().returning(|user_id| {
+        Ok(Some(users::Model {
+            user_id,
+            firstname: "Test".to_string(),
+            lastname: "User".to_string(),
+            email: format!("user{}@test.com", user_id),
+            password: "hash".to_string(),
+            noc_transfer: format!("{}", 12345 + user_id),
+            kyc_level: 0,
+            role: "user".to_string(),
+            created_at: Some(Utc::now().naive_utc()),
+            updated_at: Some(Utc::now().naive_utc()),
+        }))
+    });
+
+    mock_fee_service.expect_calculate_fee().returning(|_| {
+        Ok(FeeQuote {
+            fee_rule_id: None,
+            fee_amount: 0,
+        })
+    });
+
+    mock_saldo_repo
+        .expect_find_by_user_id()
+        .returning(|user_id| {
+            Ok(Some(saldo::Model {
+                saldo_id: user_id,
+                user_id,
+                total_balance: 200000,
+                withdraw_amount: None,
+                withdraw_time: None,
+                created_at: Some(Utc::now().naive_utc()),
+                updated_at: Some(Utc::now().naive_utc()),
+            }))
+        });
+
+    mock_fraud_engine
+        .expect_screen()
+        .withf(|input| {
+            input.transaction_type == "transfer"
+                && input.user_id == 1
+                && input.counterparty_id == Some(2)
+                && input.device_id.as_deref() == Some("phone-1")
+        })
+        .times(1)
+        .returning(|_| {
+            Err(ErrorResponse::from(AppError::HeldForReview(
+                7,
+                "The transfer is held for review under fraud review 7".to_string(),
+            )))
+        });
+
+    mock_transfer_repo.expect_create().never();
+    mock_saldo_repo.expect_update_balance().never();
+
+    let service = TransferService::new(
+        Arc::new(mock_transfer_repo),
+        Arc::new(mock_saldo_repo),
+        Arc::new(mock_user_repo),
+        Arc::new(MockBeneficiaryRepositoryTrait::new()),
+        Arc::new(mock_fee_service),
+        Arc::new(mock_limit_service()),
+        Arc::new(mock_fraud_engine),
+        Arc::new(mock_clock()),
+        BeneficiaryCoolingOff::default(),
+        MinimumAmounts::default(),
+    );
+
+    let error = service
+        .create_transfer(&CreateTransferRequest {
+            transfer_from: 1,
+            transfer_to: 2,
+            transfer_amount: 100000,
+            beneficiary_id: None,
+            device_id: Some("phone-1".to_string()),
+            merchant_payment: false,
+        })
+        .await
+        .unwrap_err();
+
+    assert_eq!(error.status, "pending");
+}
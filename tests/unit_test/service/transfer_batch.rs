@@ -0,0 +1,578 @@
+use std::sync::Arc;
+
+use chrono::Utc;
+use example_payment_gateway_axum::{
+    abstract_trait::{
+        saldo::MockSaldoRepositoryTrait,
+        transfer::MockTransferServiceTrait,
+        transfer_batch::{MockTransferBatchRepositoryTrait, TransferBatchServiceTrait},
+        user::MockUserRepositoryTrait,
+    },
+    domain::{
+        request::{
+            limit::MinimumAmounts,
+            transfer_batch::{CreateTransferBatchRequest, TransferBatchItemRequest},
+        },
+        response::{transfer::TransferResponse, ApiResponse, ErrorKind, ErrorResponse},
+    },
+    entities::{saldo, transfer_batch_items, transfer_batches, users},
+    services::transfer_batch::TransferBatchService,
+};
+use mockall::predicate;
+use sea_orm::DbErr;
+
+fn mock_saldo(user_id: i32, total_balance: i32) -> saldo::Model {
+    saldo::Model {
+        saldo_id: user_id,
+        user_id,
+        total_balance,
+        withdraw_amount: None,
+        withdraw_time: None,
+        created_at: Some(Utc::now().naive_utc()),
+        updated_at: Some(Utc::now().naive_utc()),
+    }
+}
+
+fn mock_user(user_id: i32) -> users::Model {
+    users::Model {
+        user_id,
+        firstname: "Receiver".to_string(),
+        lastname: "User".to_string(),
+        email: format!("receiver{}@test.com", user_id),
+        password: "hash".to_string(),
+        noc_transfer: user_id.to_string(),
+        kyc_level: 0,
+        role: "user".to_string(),
+        created_at: Some(Utc::now().naive_utc()),
+        updated_at: Some(Utc::now().naive_utc()),
+    }
+}
+
+fn mock_batch(status: &str, succeeded_items: i32, failed_items: i32) -> transfer_batches::Model {
+    transfer_batches::Model {
+        batch_id: 1,
+        user_id: 1,
+        status: status.to_string(),
+        total_items: 2,
+        total_amount: 150000,
+        succeeded_items,
+        failed_items,
+        completed_at: None,
+        created_at: Some(Utc::now().naive_utc()),
+        updated_at: Some(Utc::now().naive_utc()),
+    }
+}
+
+fn mock_item(item_id: i32, transfer_to: i32, transfer_amount: i32) -> transfer_batch_items::Model {
+    transfer_batch_items::Model {
+        item_id,
+        batch_id: 1,
+        line_no: item_id,
+        transfer_to,
+        transfer_amount,
+        status: "pending".to_string(),
+        transfer_id: None,
+        error_message: None,
+        fraud_review_id: None,
+        created_at: Some(Utc::now().naive_utc()),
+        updated_at: Some(Utc::now().naive_utc()),
+    }
+}
+
+#[test]
+fn test_batch_request_from_csv() {
+    let csv = b"transfer_to,transfer_amount\n2, 50000\n3,100000\n";
+
+    let request = CreateTransferBatchRequest::from_csv(1, csv).unwrap();
+
+    assert_eq!(request.transfer_from, 1);
+    assert_eq!(request.items.len(), 2);
+    assert_eq!(request.items[1].transfer_to, 3);
+    assert_eq!(request.total_amount(), 150000);
+}
+
+#[test]
+fn test_batch_request_from_csv_reports_line() {
+    let csv = b"transfer_to,transfer_amount\n2,50000\nabc,100000\n";
+
+    let error = CreateTransferBatchRequest::from_csv(1, csv).unwrap_err();
+
+    assert!(error.starts_with("Line 3:"));
+}
+
+#[tokio::test]
+async fn test_create_batch_validation_failure() {
+    let service = TransferBatchService::new(
+        Arc::new(MockTransferBatchRepositoryTrait::new()),
+        Arc::new(MockTransferServiceTrait::new()),
+        Arc::new(MockSaldoRepositoryTrait::new()),
+        Arc::new(MockUserRepositoryTrait::new()),
+        MinimumAmounts::default(),
+    );
+
+    let request = CreateTransferBatchRequest {
+        transfer_from: 1,
+        items: vec![
+            TransferBatchItemRequest {
+                transfer_to: 2,
+                transfer_amount: 100,
+            },
+            TransferBatchItemRequest {
+                transfer_to: 1,
+                transfer_amount: 50000,
+            },
+        ],
+    };
+
+    let error = service.create_batch(&request).await.unwrap_err();
+
+    assert_eq!(error.status, "Error Validation");
+    assert_eq!(
+        error.message,
+        "Item 1: Transfer amount must be at least 50,000; Item 2: Transfer to cannot be the sender"
+    );
+}
+
+#[tokio::test]
+async fn test_create_batch_insufficient_balance() {
+    let mut mock_saldo_repo = MockSaldoRepositoryTrait::new();
+
+    mock_saldo_repo
+        .expect_find_by_user_id()
+        .with(predicate::eq(1))
+        .return_once(|_| Ok(Some(mock_saldo(1, 100000))));
+
+    let service = TransferBatchService::new(
+        Arc::new(MockTransferBatchRepositoryTrait::new()),
+        Arc::new(MockTransferServiceTrait::new()),
+        Arc::new(mock_saldo_repo),
+        Arc::new(MockUserRepositoryTrait::new()),
+        MinimumAmounts::default(),
+    );
+
+    let request = CreateTransferBatchRequest {
+        transfer_from: 1,
+        items: vec![
+            TransferBatchItemRequest {
+                transfer_to: 2,
+                transfer_amount: 50000,
+            },
+            TransferBatchItemRequest {
+                transfer_to: 3,
+                transfer_amount: 100000,
+            },
+        ],
+    };
+
+    let error = service.create_batch(&request).await.unwrap_err();
+
+    assert_eq!(error.message, "Insufficient balance for batch total");
+}
+
+#[tokio::test]
+async fn test_create_batch_success() {
+    let mut mock_batch_repo = MockTransferBatchRepositoryTrait::new();
+    let mut mock_saldo_repo = MockSaldoRepositoryTrait::new();
+    let mut mock_user_repo = MockUserRepositoryTrait::new();
+
+    mock_saldo_repo
+        .expect_find_by_user_id()
+        .with(predicate::eq(1))
+        .return_once(|_| Ok(Some(mock_saldo(1, 1000000))));
+
+    mock_user_repo
+        .expect_find_by_id()
+        .times(2)
+        .returning(|id| Ok(Some(mock_user(id))));
+
+    mock_batch_repo
+        .expect_create()
+        .withf(|input| input.transfer_from == 1 && input.items.len() == 2)
+        .return_once(|_| Ok(mock_batch("pending", 0, 0)));
+
+    let service = TransferBatchService::new(
+        Arc::new(mock_batch_repo),
+        Arc::new(MockTransferServiceTrait::new()),
+        Arc::new(mock_saldo_repo),
+        Arc::new(mock_user_repo),
+        MinimumAmounts::default(),
+    );
+
+    let request = CreateTransferBatchRequest {
+        transfer_from: 1,
+        items: vec![
+            TransferBatchItemRequest {
+                transfer_to: 2,
+                transfer_amount: 50000,
+            },
+            TransferBatchItemRequest {
+                transfer_to: 3,
+                transfer_amount: 100000,
+            },
+        ],
+    };
+
+    let response = service.create_batch(&request).await.unwrap();
+
+    assert_eq!(response.status, "success");
+    assert_eq!(response.message, "Transfer batch accepted for processing");
+    assert_eq!(response.data.status, "pending");
+    assert_eq!(response.data.total_items, 2);
+}
+
+#[tokio::test]
+async fn test_process_pending_batches_partial_success() {
+    let mut mock_batch_repo = MockTransferBatchRepositoryTrait::new();
+    let mut mock_transfer_service = MockTransferServiceTrait::new();
+
+    mock_batch_repo
+        .expect_find_by_status()
+        .with(predicate::eq("processing"), predicate::always())
+        .return_once(|_, _| Ok(vec![]));
+
+    mock_batch_repo
+        .expect_find_by_status()
+        .with(predicate::eq("pending"), predicate::always())
+        .return_once(|_, _| Ok(vec![mock_batch("pending", 0, 0)]));
+
+    mock_batch_repo
+        .expect_update_status()
+        .withf(|input| input.status == "processing")
+        .return_once(|_| Ok(mock_batch("processing", 0, 0)));
+
+    mock_batch_repo
+        .expect_find_items()
+        .with(predicate::eq(1))
+        .return_once(|_| Ok(vec![mock_item(1, 2, 50000), mock_item(2, 3, 100000)]));
+
+    mock_batch_repo
+        .expect_claim_item()
+        .times(2)
+        .returning(|_| Ok(true));
+
+    mock_transfer_service
+        .expect_create_transfer()
+        .withf(|input| input.transfer_to == 2)
+        .return_once(|input| {
+            Ok(ApiResponse {
+                status: "success".to_string(),
+                message: "Transfer created successfully".to_string(),
+                data: TransferResponse {
+                    transfer_id: 10,
+                    transfer_from: input.transfer_from,
+                    transfer_to: input.transfer_to,
+                    transfer_amount: input.transfer_amount,
+                    transfer_time: Utc::now(),
+                    fee_amount: 0,
+                    created_at: None,
+                    updated_at: None,
+                },
+            })
+        });
+
+    mock_transfer_service
+        .expect_create_transfer()
+        .withf(|input| input.transfer_to == 3)
+        .return_once(|_| {
+            Err(ErrorResponse {
+                status: "error".to_string(),
+                message: "Insufficient balance".to_string(),
+                request_id: None,
+                kind: ErrorKind::InsufficientBalance,
+            })
+        });
+
+    mock_batch_repo
+        .expect_update_item_result()
+        .withf(|input| {
+            input.item_id == 1 && input.status == "succeeded" && input.transfer_id == Some(10)
+        })
+        .return_once(|_| Ok(true));
+
+    mock_batch_repo
+        .expect_update_item_result()
+        .withf(|input| {
+            input.item_id == 2
+                && input.status == "failed"
+                && input.error_message.as_deref() == Some("Insufficient balance")
+        })
+        .return_once(|_| Ok(true));
+
+    mock_batch_repo
+        .expect_update_status()
+        .withf(|input| {
+            input.status == "partially_completed"
+                && input.succeeded_items == 1
+                && input.failed_items == 1
+        })
+        .return_once(|_| Ok(mock_batch("partially_completed", 1, 1)));
+
+    let service = TransferBatchService::new(
+        Arc::new(mock_batch_repo),
+        Arc::new(mock_transfer_service),
+        Arc::new(MockSaldoRepositoryTrait::new()),
+        Arc::new(MockUserRepositoryTrait::new()),
+        MinimumAmounts::default(),
+    );
+
+    let processed = service.process_pending_batches().await.unwrap();
+
+    assert_eq!(processed, 1);
+}
+
+fn created_transfer(transfer_id: i32) -> ApiResponse<TransferResponse> {
+    ApiResponse {
+        status: "success".to_string(),
+        message: "Transfer created successfully".to_string(),
+        data: TransferResponse {
+            transfer_id,
+            transfer_from: 1,
+            transfer_to: 4,
+            transfer_amount: 25000,
+            transfer_time: Utc::now(),
+            fee_amount: 0,
+            created_at: None,
+            updated_at: None,
+        },
+    }
+}
+
+#[tokio::test]
+async fn test_resumed_batch_does_not_repeat_interrupted_items() {
+    let mut mock_batch_repo = MockTransferBatchRepositoryTrait::new();
+    let mut mock_transfer_service = MockTransferServiceTrait::new();
+
+    mock_batch_repo
+        .expect_find_by_status()
+        .with(predicate::eq("processing"), predicate::always())
+        .return_once(|_, _| Ok(vec![mock_batch("processing", 0, 0)]));
+
+    mock_batch_repo
+        .expect_find_by_status()
+        .with(predicate::eq("pending"), predicate::always())
+        .return_once(|_, _| Ok(vec![]));
+
+    mock_batch_repo
+        .expect_update_status()
+        .withf(|input| input.status == "processing")
+        .return_once(|_| Ok(mock_batch("processing", 0, 0)));
+
+    // Line 1 finished before the crash, line 2 was mid-transfer, line 3
+    // never started.
+    let mut done = mock_item(1, 2, 50000);
+    done.status = "succeeded".to_string();
+    done.transfer_id = Some(10);
+    let mut in_flight = mock_item(2, 3, 100000);
+    in_flight.status = "processing".to_string();
+    let untouched = mock_item(3, 4, 25000);
+
+    mock_batch_repo
+        .expect_find_items()
+        .return_once(move |_| Ok(vec![done, in_flight, untouched]));
+
+    mock_batch_repo
+        .expect_claim_item()
+        .with(predicate::eq(3))
+        .times(1)
+        .return_once(|_| Ok(true));
+
+    mock_transfer_service
+        .expect_create_transfer()
+        .withf(|input| input.transfer_to == 4)
+        .times(1)
+        .return_once(|_| Ok(created_transfer(11)));
+
+    mock_batch_repo
+        .expect_update_item_result()
+        .withf(|input| {
+            input.item_id == 2 && input.status == "interrupted" && input.transfer_id.is_none()
+        })
+        .return_once(|_| Ok(true));
+
+    mock_batch_repo
+        .expect_update_item_result()
+        .withf(|input| {
+            input.item_id == 3 && input.status == "succeeded" && input.transfer_id == Some(11)
+        })
+        .return_once(|_| Ok(true));
+
+    mock_batch_repo
+        .expect_update_status()
+        .withf(|input| {
+            input.status == "partially_completed"
+                && input.succeeded_items == 2
+                && input.failed_items == 1
+        })
+        .return_once(|_| Ok(mock_batch("partially_completed", 2, 1)));
+
+    let service = TransferBatchService::new(
+        Arc::new(mock_batch_repo),
+        Arc::new(mock_transfer_service),
+        Arc::new(MockSaldoRepositoryTrait::new()),
+        Arc::new(MockUserRepositoryTrait::new()),
+        MinimumAmounts::default(),
+    );
+
+    let processed = service.process_pending_batches().await.unwrap();
+
+    assert_eq!(processed, 1);
+}
+
+#[tokio::test]
+async fn test_item_result_write_failure_does_not_stop_the_batch() {
+    let mut mock_batch_repo = MockTransferBatchRepositoryTrait::new();
+    let mut mock_transfer_service = MockTransferServiceTrait::new();
+
+    mock_batch_repo
+        .expect_find_by_status()
+        .with(predicate::eq("processing"), predicate::always())
+        .return_once(|_, _| Ok(vec![]));
+
+    mock_batch_repo
+        .expect_find_by_status()
+        .with(predicate::eq("pending"), predicate::always())
+        .return_once(|_, _| Ok(vec![mock_batch("pending", 0, 0)]));
+
+    mock_batch_repo
+        .expect_update_status()
+        .withf(|input| input.status == "processing")
+        .return_once(|_| Ok(mock_batch("processing", 0, 0)));
+
+    mock_batch_repo
+        .expect_find_items()
+        .return_once(|_| Ok(vec![mock_item(1, 2, 50000), mock_item(2, 3, 100000)]));
+
+    mock_batch_repo
+        .expect_claim_item()
+        .times(2)
+        .returning(|_| Ok(true));
+
+    mock_transfer_service
+        .expect_create_transfer()
+        .times(2)
+        .returning(|input| Ok(created_transfer(input.transfer_to * 10)));
+
+    mock_batch_repo
+        .expect_update_item_result()
+        .withf(|input| input.item_id == 1)
+        .return_once(|_| Err(DbErr::Custom("connection reset".to_string())));
+
+    mock_batch_repo
+        .expect_update_item_result()
+        .withf(|input| input.item_id == 2 && input.transfer_id == Some(30))
+        .return_once(|_| Ok(true));
+
+    mock_batch_repo
+        .expect_update_status()
+        .withf(|input| input.status == "completed" && input.succeeded_items == 2)
+        .return_once(|_| Ok(mock_batch("completed", 2, 0)));
+
+    let service = TransferBatchService::new(
+        Arc::new(mock_batch_repo),
+        Arc::new(mock_transfer_service),
+        Arc::new(MockSaldoRepositoryTrait::new()),
+        Arc::new(MockUserRepositoryTrait::new()),
+        MinimumAmounts::default(),
+    );
+
+    let processed = service.process_pending_batches().await.unwrap();
+
+    assert_eq!(processed, 1);
+}
+
+#[tokio::test]
+async fn test_held_and_claimed_items_keep_the_batch_processing() {
+    let mut mock_batch_repo = MockTransferBatchRepositoryTrait::new();
+    let mut mock_transfer_service = MockTransferServiceTrait::new();
+
+    mock_batch_repo
+        .expect_find_by_status()
+        .with(predicate::eq("processing"), predicate::always())
+        .return_once(|_, _| Ok(vec![]));
+
+    mock_batch_repo
+        .expect_find_by_status()
+        .with(predicate::eq("pending"), predicate::always())
+        .return_once(|_, _| Ok(vec![mock_batch("pending", 0, 0)]));
+
+    mock_batch_repo
+        .expect_find_items()
+        .return_once(|_| Ok(vec![mock_item(1, 2, 50000), mock_item(2, 3, 100000)]));
+
+    mock_batch_repo
+        .expect_claim_item()
+        .with(predicate::eq(1))
+        .return_once(|_| Ok(true));
+
+    // Another run got to line 2 first
+    mock_batch_repo
+        .expect_claim_item()
+        .with(predicate::eq(2))
+        .return_once(|_| Ok(false));
+
+    mock_transfer_service
+        .expect_create_transfer()
+        .times(1)
+        .return_once(|_| {
+            Err(ErrorResponse {
+                status: "pending".to_string(),
+                message: "The transfer is held for review under fraud review 7".to_string(),
+                request_id: None,
+                kind: ErrorKind::HeldForReview(7),
+            })
+        });
+
+    mock_batch_repo
+        .expect_update_item_result()
+        .withf(|input| {
+            input.item_id == 1
+                && input.status == "held"
+                && input.transfer_id.is_none()
+                && input.fraud_review_id == Some(7)
+        })
+        .times(1)
+        .return_once(|_| Ok(true));
+
+    // Neither line has an outcome yet, so the batch is not finished
+    mock_batch_repo
+        .expect_update_status()
+        .withf(|input| {
+            input.status == "processing" && input.succeeded_items == 0 && input.failed_items == 0
+        })
+        .times(2)
+        .returning(|_| Ok(mock_batch("processing", 0, 0)));
+
+    let service = TransferBatchService::new(
+        Arc::new(mock_batch_repo),
+        Arc::new(mock_transfer_service),
+        Arc::new(MockSaldoRepositoryTrait::new()),
+        Arc::new(MockUserRepositoryTrait::new()),
+        MinimumAmounts::default(),
+    );
+
+    let processed = service.process_pending_batches().await.unwrap();
+
+    assert_eq!(processed, 1);
+}
+
+#[tokio::test]
+async fn test_get_batch_of_other_user_not_found() {
+    let mut mock_batch_repo = MockTransferBatchRepositoryTrait::new();
+
+    mock_batch_repo
+        .expect_find_by_id()
+        .with(predicate::eq(1))
+        .return_once(|_| Ok(Some(mock_batch("completed", 2, 0))));
+
+    let service = TransferBatchService::new(
+        Arc::new(mock_batch_repo),
+        Arc::new(MockTransferServiceTrait::new()),
+        Arc::new(MockSaldoRepositoryTrait::new()),
+        Arc::new(MockUserRepositoryTrait::new()),
+        MinimumAmounts::default(),
+    );
+
+    let error = service.get_batch(1, 99).await.unwrap_err();
+
+    assert_eq!(error.message, "Transfer batch with id 1 not found");
+}
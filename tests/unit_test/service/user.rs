@@ -1,10 +1,10 @@
 use std::sync::Arc;
 
-use chrono::{DateTime, Utc};
+use chrono::Utc;
 use example_payment_gateway_axum::{
     abstract_trait::{
         hashing::MockHashingTrait,
-        user::{MockUserRepositoryTrait, UserRepositoryTrait, UserServiceTrait},
+        user::{MockUserRepositoryTrait, UserServiceTrait},
     },
     domain::request::{
         auth::RegisterRequest,
@@ -30,6 +30,8 @@ async fn test_get_users() {
             email: "john.doe@example.com".to_string(),
             password: "hashed_password".to_string(),
             noc_transfer: "12345".to_string(),
+            kyc_level: 0,
+            role: "user".to_string(),
             created_at: Some(Utc::now().naive_utc()),
             updated_at: Some(Utc::now().naive_utc()),
         },
@@ -40,6 +42,8 @@ async fn test_get_users() {
             email: "jane.doe@example.com".to_string(),
             password: "hashed_password".to_string(),
             noc_transfer: "67890".to_string(),
+            kyc_level: 0,
+            role: "user".to_string(),
             created_at: Some(Utc::now().naive_utc()),
             updated_at: Some(Utc::now().naive_utc()),
         },
@@ -93,6 +97,8 @@ async fn test_find_by_id_success() {
         email: "john.doe@example.com".to_string(),
         password: "hashed_password".to_string(),
         noc_transfer: "12345".to_string(),
+        kyc_level: 0,
+        role: "user".to_string(),
         created_at: Some(Utc::now().naive_utc()),
         updated_at: Some(Utc::now().naive_utc()),
     };
@@ -188,6 +194,8 @@ async fn test_create_user_success() {
                 email: req.email.clone(),
                 password: req.password.clone(),
                 noc_transfer: req.noc_transfer.clone().unwrap_or_default(),
+                kyc_level: 0,
+                role: "user".to_string(),
                 created_at: Some(Utc::now().naive_utc()),
                 updated_at: Some(Utc::now().naive_utc()),
             })
@@ -341,6 +349,8 @@ async fn test_update_user_success() {
                 email: input_email,
                 password: input_password,
                 noc_transfer: "12345".to_string(),
+                kyc_level: 0,
+                role: "user".to_string(),
                 created_at: Some(Utc::now().naive_utc()),
                 updated_at: Some(Utc::now().naive_utc()),
             })
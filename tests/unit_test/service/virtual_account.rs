@@ -0,0 +1,200 @@
+use std::sync::Arc;
+
+use chrono::Utc;
+use example_payment_gateway_axum::{
+    abstract_trait::{
+        payment_channel::MockPaymentChannelTrait,
+        topup::MockTopupServiceTrait,
+        user::MockUserRepositoryTrait,
+        virtual_account::{MockVirtualAccountRepositoryTrait, VirtualAccountServiceTrait},
+    },
+    domain::{
+        request::virtual_account::CreateVirtualAccountRequest,
+        response::{topup::TopupResponse, ApiResponse},
+    },
+    entities::{suspense_payments, virtual_accounts},
+    services::virtual_account::VirtualAccountService,
+};
+use mockall::predicate;
+
+fn virtual_account(user_id: i32) -> virtual_accounts::Model {
+    virtual_accounts::Model {
+        virtual_account_id: 1,
+        user_id,
+        bank_code: "bca".to_string(),
+        va_number: "3935800000000007".to_string(),
+        created_at: Some(Utc::now().naive_utc()),
+        updated_at: Some(Utc::now().naive_utc()),
+    }
+}
+
+fn paid_topup(user_id: i32, amount: i32) -> ApiResponse<TopupResponse> {
+    ApiResponse {
+        status: "success".to_string(),
+        message: "Topup recorded successfully".to_string(),
+        data: TopupResponse {
+            topup_id: 10,
+            user_id,
+            topup_no: "PAY-1".to_string(),
+            topup_amount: amount,
+            topup_method: "bca".to_string(),
+            topup_time: Utc::now(),
+            status: "paid".to_string(),
+            payment_reference: Some("VA-PAY-1".to_string()),
+            payment_instructions: None,
+            paid_at: Some(Utc::now()),
+            fee_amount: 0,
+            created_at: Some(Utc::now()),
+            updated_at: Some(Utc::now()),
+        },
+    }
+}
+
+fn signed_channel() -> MockPaymentChannelTrait {
+    let mut mock_payment_channel = MockPaymentChannelTrait::new();
+    mock_payment_channel
+        .expect_verify_signature()
+        .returning(|_, signature| signature == "valid");
+    mock_payment_channel
+}
+
+#[tokio::test]
+async fn test_create_virtual_account_generates_number_per_bank() {
+    let mut mock_virtual_account_repo = MockVirtualAccountRepositoryTrait::new();
+
+    mock_virtual_account_repo
+        .expect_find_by_user()
+        .return_once(|_| Ok(vec![]));
+    mock_virtual_account_repo
+        .expect_create()
+        .with(predicate::always(), predicate::eq("3935800000000007"))
+        .return_once(|input, _| Ok(virtual_account(input.user_id)));
+
+    let service = VirtualAccountService::new(
+        Arc::new(mock_virtual_account_repo),
+        Arc::new(MockTopupServiceTrait::new()),
+        Arc::new(MockUserRepositoryTrait::new()),
+        Arc::new(MockPaymentChannelTrait::new()),
+    );
+
+    let response = service
+        .create_virtual_account(&CreateVirtualAccountRequest {
+            user_id: 7,
+            bank_code: "bca".to_string(),
+        })
+        .await
+        .unwrap();
+
+    assert_eq!(response.message, "Virtual account created successfully");
+    assert_eq!(response.data.va_number, "3935800000000007");
+
+    let unsupported = CreateVirtualAccountRequest {
+        user_id: 7,
+        bank_code: "bri".to_string(),
+    };
+    assert!(unsupported.validate().is_err());
+}
+
+#[tokio::test]
+async fn test_virtual_account_payment_creates_paid_topup() {
+    let mut mock_virtual_account_repo = MockVirtualAccountRepositoryTrait::new();
+    let mut mock_topup_service = MockTopupServiceTrait::new();
+
+    mock_virtual_account_repo
+        .expect_find_suspense_by_payment_id()
+        .return_once(|_| Ok(None));
+    mock_virtual_account_repo
+        .expect_find_by_number()
+        .with(predicate::eq("bca"), predicate::eq("3935800000000007"))
+        .return_once(|_, _| Ok(Some(virtual_account(7))));
+    mock_virtual_account_repo.expect_create_suspense().never();
+
+    mock_topup_service
+        .expect_create_paid_topup()
+        .withf(|input, reference| {
+            input.user_id == 7 && input.topup_amount == 75000 && reference == "VA-PAY-1"
+        })
+        .return_once(|input, _| Ok(paid_topup(input.user_id, input.topup_amount)));
+
+    let service = VirtualAccountService::new(
+        Arc::new(mock_virtual_account_repo),
+        Arc::new(mock_topup_service),
+        Arc::new(MockUserRepositoryTrait::new()),
+        Arc::new(signed_channel()),
+    );
+
+    let payload =
+        br#"{"payment_id":"PAY-1","bank_code":"bca","va_number":"3935800000000007","amount":75000}"#;
+
+    let response = service.handle_payment(payload, "valid").await.unwrap();
+
+    assert!(response.data.matched);
+    assert_eq!(response.data.topup.unwrap().status, "paid");
+    assert!(response.data.suspense_payment.is_none());
+}
+
+#[tokio::test]
+async fn test_virtual_account_payment_unmatched_goes_to_suspense() {
+    let mut mock_virtual_account_repo = MockVirtualAccountRepositoryTrait::new();
+    let mut mock_topup_service = MockTopupServiceTrait::new();
+
+    mock_virtual_account_repo
+        .expect_find_suspense_by_payment_id()
+        .return_once(|_| Ok(None));
+    mock_virtual_account_repo
+        .expect_find_by_number()
+        .return_once(|_, _| Ok(None));
+    mock_virtual_account_repo
+        .expect_create_suspense()
+        .return_once(|input| {
+            Ok(suspense_payments::Model {
+                suspense_payment_id: 3,
+                payment_id: input.payment_id.clone(),
+                bank_code: input.bank_code.clone(),
+                va_number: input.va_number.clone(),
+                amount: input.amount,
+                status: "unresolved".to_string(),
+                resolved_user_id: None,
+                topup_id: None,
+                created_at: Some(Utc::now().naive_utc()),
+                updated_at: Some(Utc::now().naive_utc()),
+            })
+        });
+    mock_topup_service.expect_create_paid_topup().never();
+
+    let service = VirtualAccountService::new(
+        Arc::new(mock_virtual_account_repo),
+        Arc::new(mock_topup_service),
+        Arc::new(MockUserRepositoryTrait::new()),
+        Arc::new(signed_channel()),
+    );
+
+    let payload =
+        br#"{"payment_id":"PAY-2","bank_code":"bni","va_number":"9881200000000099","amount":75000}"#;
+
+    let response = service.handle_payment(payload, "valid").await.unwrap();
+
+    assert!(!response.data.matched);
+    assert_eq!(response.message, "Payment queued for review");
+    assert_eq!(response.data.suspense_payment.unwrap().status, "unresolved");
+}
+
+#[tokio::test]
+async fn test_virtual_account_payment_invalid_signature() {
+    let mut mock_virtual_account_repo = MockVirtualAccountRepositoryTrait::new();
+    mock_virtual_account_repo.expect_find_by_number().never();
+
+    let service = VirtualAccountService::new(
+        Arc::new(mock_virtual_account_repo),
+        Arc::new(MockTopupServiceTrait::new()),
+        Arc::new(MockUserRepositoryTrait::new()),
+        Arc::new(signed_channel()),
+    );
+
+    let payload =
+        br#"{"payment_id":"PAY-1","bank_code":"bca","va_number":"3935800000000007","amount":75000}"#;
+
+    let error = service.handle_payment(payload, "forged").await.unwrap_err();
+
+    assert_eq!(error.message, "Invalid callback signature");
+}
@@ -0,0 +1,177 @@
+use std::sync::Arc;
+
+use chrono::{Duration, NaiveDateTime, TimeZone, Utc};
+use example_payment_gateway_axum::{
+    abstract_trait::{
+        clock::MockClockTrait,
+        merchant::MockMerchantRepositoryTrait,
+        webhook::{MockWebhookRepositoryTrait, WebhookServiceTrait},
+        webhook_sender::MockWebhookSenderTrait,
+    },
+    entities::{webhook_deliveries, webhook_endpoints, webhook_events},
+    services::webhook::{WebhookRetryPolicy, WebhookService},
+    utils::{errors::AppError, signature::verify_hmac_sha256},
+};
+use mockall::predicate;
+
+const SECRET: &str = "endpoint-secret";
+
+fn now() -> NaiveDateTime {
+    Utc.with_ymd_and_hms(2026, 10, 19, 9, 0, 0)
+        .unwrap()
+        .naive_utc()
+}
+
+fn mock_clock() -> MockClockTrait {
+    let mut clock = MockClockTrait::new();
+    clock.expect_now().return_const(now());
+    clock
+}
+
+fn delivery(attempts: i32) -> webhook_deliveries::Model {
+    webhook_deliveries::Model {
+        webhook_delivery_id: 7,
+        webhook_event_id: 4,
+        webhook_endpoint_id: 2,
+        merchant_id: 3,
+        status: "pending".to_string(),
+        attempts,
+        next_attempt_at: now(),
+        last_response_status: None,
+        last_error: None,
+        delivered_at: None,
+        created_at: Some(now()),
+        updated_at: Some(now()),
+    }
+}
+
+fn repository_with(attempts: i32) -> MockWebhookRepositoryTrait {
+    let mut mock_repo = MockWebhookRepositoryTrait::new();
+
+    mock_repo
+        .expect_find_due_deliveries()
+        .return_once(move |_, _| Ok(vec![delivery(attempts)]));
+    mock_repo.expect_find_endpoint_by_id().return_once(|_| {
+        Ok(Some(webhook_endpoints::Model {
+            webhook_endpoint_id: 2,
+            merchant_id: 3,
+            url: "https://merchant.example/webhooks".to_string(),
+            secret: SECRET.to_string(),
+            is_active: true,
+            created_at: Some(now()),
+            updated_at: Some(now()),
+        }))
+    });
+    mock_repo.expect_find_event_by_id().return_once(|_| {
+        Ok(Some(webhook_events::Model {
+            webhook_event_id: 4,
+            merchant_id: 3,
+            event_type: "payment_intent.succeeded".to_string(),
+            payload: r#"{"payment_intent_id":1,"status":"succeeded"}"#.to_string(),
+            created_at: Some(now()),
+        }))
+    });
+
+    mock_repo
+}
+
+fn service(
+    mock_repo: MockWebhookRepositoryTrait,
+    mock_sender: MockWebhookSenderTrait,
+) -> WebhookService {
+    WebhookService::new(
+        Arc::new(mock_repo),
+        Arc::new(MockMerchantRepositoryTrait::new()),
+        Arc::new(mock_sender),
+        Arc::new(mock_clock()),
+        WebhookRetryPolicy::default(),
+    )
+}
+
+#[test]
+fn test_backoff_doubles_after_each_attempt() {
+    let policy = WebhookRetryPolicy::default();
+
+    assert_eq!(policy.backoff(1), Duration::seconds(30));
+    assert_eq!(policy.backoff(2), Duration::seconds(60));
+    assert_eq!(policy.backoff(5), Duration::seconds(480));
+}
+
+#[tokio::test]
+async fn test_delivery_is_signed_and_marked_succeeded() {
+    let mut mock_repo = repository_with(0);
+    let mut mock_sender = MockWebhookSenderTrait::new();
+
+    mock_sender
+        .expect_send()
+        .withf(|request| {
+            request.delivery_id == 7
+                && request.event_type == "payment_intent.succeeded"
+                && verify_hmac_sha256(SECRET, request.body.as_bytes(), &request.signature)
+        })
+        .times(1)
+        .return_once(|_| Ok(200));
+
+    mock_repo
+        .expect_record_attempt()
+        .withf(|id, attempt| {
+            *id == 7 && attempt.status == "succeeded" && attempt.response_status == Some(200)
+        })
+        .times(1)
+        .return_once(|_, _| Ok(delivery(1)));
+
+    assert_eq!(
+        service(mock_repo, mock_sender)
+            .deliver_due_webhooks()
+            .await
+            .unwrap(),
+        1
+    );
+}
+
+#[tokio::test]
+async fn test_failed_delivery_is_retried_with_backoff() {
+    let mut mock_repo = repository_with(1);
+    let mut mock_sender = MockWebhookSenderTrait::new();
+
+    mock_sender.expect_send().return_once(|_| Ok(503));
+
+    mock_repo
+        .expect_record_attempt()
+        .with(predicate::eq(7), predicate::always())
+        .withf(|_, attempt| {
+            attempt.status == "pending"
+                && attempt.response_status == Some(503)
+                && attempt.next_attempt_at == now() + Duration::seconds(60)
+        })
+        .times(1)
+        .return_once(|_, _| Ok(delivery(2)));
+
+    service(mock_repo, mock_sender)
+        .deliver_due_webhooks()
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_delivery_gives_up_after_max_attempts() {
+    let mut mock_repo = repository_with(5);
+    let mut mock_sender = MockWebhookSenderTrait::new();
+
+    mock_sender.expect_send().return_once(|_| {
+        Err(AppError::WebhookDeliveryError(
+            "connection refused".to_string(),
+        ))
+    });
+
+    mock_repo
+        .expect_record_attempt()
+        .withf(|_, attempt| attempt.status == "failed" && attempt.response_status.is_none())
+        .times(1)
+        .return_once(|_, _| Ok(delivery(6)));
+
+    service(mock_repo, mock_sender)
+        .deliver_due_webhooks()
+        .await
+        .unwrap();
+}
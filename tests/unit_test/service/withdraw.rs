@@ -1,18 +1,102 @@
 use std::sync::Arc;
 
-use chrono::{DateTime, Utc};
+use chrono::{Duration, Utc};
 use example_payment_gateway_axum::{
     abstract_trait::{
+        bank_account::MockBankAccountRepositoryTrait,
+        fee::{FeeQuote, MockFeeServiceTrait},
+        fraud::MockFraudEngineTrait,
+        limit::MockLimitServiceTrait,
+        payout::{MockPayoutProviderTrait, PayoutResult, PayoutStatus},
         saldo::MockSaldoRepositoryTrait,
-        user::{MockUserRepositoryTrait, UserRepositoryTrait},
-        withdraw::{MockWithdrawRepositoryTrait, WithdrawRepositoryTrait, WithdrawServiceTrait},
+        user::MockUserRepositoryTrait,
+        withdraw::{MockWithdrawRepositoryTrait, WithdrawServiceTrait},
     },
-    domain::request::withdraw::{CreateWithdrawRequest, UpdateWithdrawRequest},
-    entities::{saldo, users, withdraws},
+    domain::request::{
+        limit::MinimumAmounts,
+        withdraw::{CreateWithdrawRequest, UpdateWithdrawRequest},
+    },
+    entities::{bank_accounts, saldo, users, withdraws},
     services::withdraw::WithdrawService,
+    utils::errors::AppError,
 };
 use mockall::predicate;
 
+fn mock_fee_service() -> MockFeeServiceTrait {
+    let mut fee_service = MockFeeServiceTrait::new();
+    fee_service
+        .expect_calculate_fee()
+        .returning(|_| Ok(FeeQuote::default()));
+    fee_service.expect_post_fee().returning(|_| Ok(()));
+    fee_service
+}
+
+fn mock_limit_service() -> MockLimitServiceTrait {
+    let mut limit_service = MockLimitServiceTrait::new();
+    limit_service
+        .expect_check_transaction_limits()
+        .returning(|_| Ok(()));
+    limit_service
+        .expect_check_balance_limit()
+        .returning(|_| Ok(()));
+    limit_service
+}
+
+fn mock_fraud_engine() -> MockFraudEngineTrait {
+    let mut fraud_engine = MockFraudEngineTrait::new();
+    fraud_engine.expect_screen().returning(|_| Ok(()));
+    fraud_engine
+}
+
+fn mock_bank_account_repo(user_id: i32) -> MockBankAccountRepositoryTrait {
+    let mut mock_bank_account_repo = MockBankAccountRepositoryTrait::new();
+    mock_bank_account_repo
+        .expect_find_by_id()
+        .with(predicate::eq(1))
+        .returning(move |id| {
+            Ok(Some(bank_accounts::Model {
+                bank_account_id: id,
+                user_id,
+                bank_code: "bca".to_string(),
+                account_number: "1234567890".to_string(),
+                holder_name: "John Doe".to_string(),
+                created_at: Some(Utc::now().naive_utc()),
+                updated_at: Some(Utc::now().naive_utc()),
+            }))
+        });
+    mock_bank_account_repo
+}
+
+fn pending_withdraw(user_id: i32, withdraw_amount: i32) -> withdraws::Model {
+    withdraws::Model {
+        withdraw_id: 1,
+        user_id,
+        withdraw_amount,
+        withdraw_time: Utc::now().naive_utc(),
+        bank_account_id: Some(1),
+        status: "pending".to_string(),
+        provider_reference: Some("SIM-1".to_string()),
+        payout_reference: None,
+        failure_reason: None,
+        fee_amount: 0,
+        fee_rule_id: None,
+        created_at: Some(Utc::now().naive_utc()),
+        updated_at: Some(Utc::now().naive_utc()),
+    }
+}
+
+fn saldo_with_balance(user_id: i32, total_balance: i32) -> saldo::Model {
+    saldo::Model {
+        saldo_id: 1,
+        user_id,
+        total_balance,
+        withdraw_amount: None,
+        withdraw_time: None,
+        created_at: Some(Utc::now().naive_utc()),
+        updated_at: Some(Utc::now().naive_utc()),
+    }
+}
+
 #[tokio::test]
 async fn test_get_withdraws() {
     let mut mock_withdraw_repo = MockWithdrawRepositoryTrait::new();
@@ -24,17 +108,31 @@ async fn test_get_withdraws() {
             withdraw_id: 1,
             user_id: 101,
             withdraw_amount: 5000,
-            withdraw_time: DateTime::<Utc>::from(Utc::now()).naive_utc(),
-            created_at: Some(DateTime::<Utc>::from(Utc::now()).naive_utc()),
-            updated_at: Some(DateTime::<Utc>::from(Utc::now()).naive_utc()),
+            withdraw_time: Utc::now().naive_utc(),
+            bank_account_id: None,
+            status: "succeeded".to_string(),
+            provider_reference: None,
+            payout_reference: None,
+            failure_reason: None,
+            fee_amount: 0,
+            fee_rule_id: None,
+            created_at: Some(Utc::now().naive_utc()),
+            updated_at: Some(Utc::now().naive_utc()),
         },
         withdraws::Model {
             withdraw_id: 2,
             user_id: 102,
             withdraw_amount: 7000,
-            withdraw_time: DateTime::<Utc>::from(Utc::now()).naive_utc(),
-            created_at: Some(DateTime::<Utc>::from(Utc::now()).naive_utc()),
-            updated_at: Some(DateTime::<Utc>::from(Utc::now()).naive_utc()),
+            withdraw_time: Utc::now().naive_utc(),
+            bank_account_id: None,
+            status: "succeeded".to_string(),
+            provider_reference: None,
+            payout_reference: None,
+            failure_reason: None,
+            fee_amount: 0,
+            fee_rule_id: None,
+            created_at: Some(Utc::now().naive_utc()),
+            updated_at: Some(Utc::now().naive_utc()),
         },
     ];
 
@@ -46,6 +144,12 @@ async fn test_get_withdraws() {
         Arc::new(mock_withdraw_repo),
         Arc::new(mock_saldo_repo),
         Arc::new(mock_user_repo),
+        Arc::new(MockBankAccountRepositoryTrait::new()),
+        Arc::new(MockPayoutProviderTrait::new()),
+        Arc::new(mock_fee_service()),
+        Arc::new(mock_limit_service()),
+        Arc::new(mock_fraud_engine()),
+        MinimumAmounts::default(),
     );
 
     let result = service.get_withdraws().await;
@@ -70,9 +174,16 @@ async fn test_get_withdraw() {
         withdraw_id: 1,
         user_id: 101,
         withdraw_amount: 5000,
-        withdraw_time: DateTime::<Utc>::from(Utc::now()).naive_utc(),
-        created_at: Some(DateTime::<Utc>::from(Utc::now()).naive_utc()),
-        updated_at: Some(DateTime::<Utc>::from(Utc::now()).naive_utc()),
+        withdraw_time: Utc::now().naive_utc(),
+        bank_account_id: None,
+        status: "succeeded".to_string(),
+        provider_reference: None,
+        payout_reference: None,
+        failure_reason: None,
+        fee_amount: 0,
+        fee_rule_id: None,
+        created_at: Some(Utc::now().naive_utc()),
+        updated_at: Some(Utc::now().naive_utc()),
     });
 
     mock_withdraw_repo
@@ -84,6 +195,12 @@ async fn test_get_withdraw() {
         Arc::new(mock_withdraw_repo),
         Arc::new(MockSaldoRepositoryTrait::new()),
         Arc::new(mock_user_repo),
+        Arc::new(MockBankAccountRepositoryTrait::new()),
+        Arc::new(MockPayoutProviderTrait::new()),
+        Arc::new(mock_fee_service()),
+        Arc::new(mock_limit_service()),
+        Arc::new(mock_fraud_engine()),
+        MinimumAmounts::default(),
     );
 
     let result = service.get_withdraw(1).await;
@@ -111,6 +228,12 @@ async fn test_get_withdraw_not_found() {
         Arc::new(mock_withdraw_repo),
         Arc::new(MockSaldoRepositoryTrait::new()),
         Arc::new(mock_user_repo),
+        Arc::new(MockBankAccountRepositoryTrait::new()),
+        Arc::new(MockPayoutProviderTrait::new()),
+        Arc::new(mock_fee_service()),
+        Arc::new(mock_limit_service()),
+        Arc::new(mock_fraud_engine()),
+        MinimumAmounts::default(),
     );
 
     let result = service.get_withdraw(1).await;
@@ -137,8 +260,10 @@ async fn test_get_withdraw_users() {
                 email: "johndoe@example.com".to_string(),
                 password: "hashed_password".to_string(),
                 noc_transfer: "12345".to_string(),
-                created_at: Some(DateTime::<Utc>::from(Utc::now()).naive_utc()),
-                updated_at: Some(DateTime::<Utc>::from(Utc::now()).naive_utc()),
+                kyc_level: 0,
+                role: "user".to_string(),
+                created_at: Some(Utc::now().naive_utc()),
+                updated_at: Some(Utc::now().naive_utc()),
             }))
         });
 
@@ -146,9 +271,16 @@ async fn test_get_withdraw_users() {
         withdraw_id: 1,
         user_id: 1,
         withdraw_amount: 5000,
-        withdraw_time: DateTime::<Utc>::from(Utc::now()).naive_utc(),
-        created_at: Some(DateTime::<Utc>::from(Utc::now()).naive_utc()),
-        updated_at: Some(DateTime::<Utc>::from(Utc::now()).naive_utc()),
+        withdraw_time: Utc::now().naive_utc(),
+        bank_account_id: None,
+        status: "succeeded".to_string(),
+        provider_reference: None,
+        payout_reference: None,
+        failure_reason: None,
+        fee_amount: 0,
+        fee_rule_id: None,
+        created_at: Some(Utc::now().naive_utc()),
+        updated_at: Some(Utc::now().naive_utc()),
     }]);
 
     mock_withdraw_repo
@@ -160,6 +292,12 @@ async fn test_get_withdraw_users() {
         Arc::new(mock_withdraw_repo),
         Arc::new(MockSaldoRepositoryTrait::new()),
         Arc::new(mock_user_repo),
+        Arc::new(MockBankAccountRepositoryTrait::new()),
+        Arc::new(MockPayoutProviderTrait::new()),
+        Arc::new(mock_fee_service()),
+        Arc::new(mock_limit_service()),
+        Arc::new(mock_fraud_engine()),
+        MinimumAmounts::default(),
     );
 
     let result = service.get_withdraw_users(1).await;
@@ -194,6 +332,8 @@ async fn test_get_withdraw_users_not_found() {
                 email: "john.doe@example.com".to_string(),
                 password: "password".to_string(),
                 noc_transfer: "12345".to_string(),
+                kyc_level: 0,
+                role: "user".to_string(),
                 created_at: None,
                 updated_at: None,
             }))
@@ -203,6 +343,12 @@ async fn test_get_withdraw_users_not_found() {
         Arc::new(mock_withdraw_repo),
         Arc::new(mock_saldo_repo),
         Arc::new(mock_user_repo),
+        Arc::new(MockBankAccountRepositoryTrait::new()),
+        Arc::new(MockPayoutProviderTrait::new()),
+        Arc::new(mock_fee_service()),
+        Arc::new(mock_limit_service()),
+        Arc::new(mock_fraud_engine()),
+        MinimumAmounts::default(),
     );
 
     let result = service.get_withdraw_users(1).await;
@@ -212,10 +358,7 @@ async fn test_get_withdraw_users_not_found() {
 
     assert_eq!(response.status, "success");
     assert_eq!(response.message, "No withdraw found for user with id 1");
-    match response.data {
-        None => assert!(true),
-        Some(_) => assert!(false),
-    }
+    assert!(response.data.is_none());
 }
 
 #[tokio::test]
@@ -234,6 +377,8 @@ async fn test_get_withdraw_user_success() {
                 email: "john.doe@example.com".to_string(),
                 password: "password".to_string(),
                 noc_transfer: "12345".to_string(),
+                kyc_level: 0,
+                role: "user".to_string(),
                 created_at: None,
                 updated_at: None,
             }))
@@ -247,9 +392,16 @@ async fn test_get_withdraw_user_success() {
                 withdraw_id: 1,
                 user_id: 1,
                 withdraw_amount: 5000,
-                withdraw_time: DateTime::<Utc>::from(Utc::now()).naive_utc(),
-                created_at: Some(DateTime::<Utc>::from(Utc::now()).naive_utc()),
-                updated_at: Some(DateTime::<Utc>::from(Utc::now()).naive_utc()),
+                withdraw_time: Utc::now().naive_utc(),
+                bank_account_id: None,
+                status: "succeeded".to_string(),
+                provider_reference: None,
+                payout_reference: None,
+                failure_reason: None,
+                fee_amount: 0,
+                fee_rule_id: None,
+                created_at: Some(Utc::now().naive_utc()),
+                updated_at: Some(Utc::now().naive_utc()),
             }))
         });
 
@@ -257,6 +409,12 @@ async fn test_get_withdraw_user_success() {
         Arc::new(mock_withdraw_repo),
         Arc::new(MockSaldoRepositoryTrait::new()),
         Arc::new(mock_user_repo),
+        Arc::new(MockBankAccountRepositoryTrait::new()),
+        Arc::new(MockPayoutProviderTrait::new()),
+        Arc::new(mock_fee_service()),
+        Arc::new(mock_limit_service()),
+        Arc::new(mock_fraud_engine()),
+        MinimumAmounts::default(),
     );
 
     let result = service.get_withdraw_user(1).await;
@@ -292,6 +450,8 @@ async fn test_get_withdraw_user_not_found() {
                 email: "john.doe@example.com".to_string(),
                 password: "password".to_string(),
                 noc_transfer: "12345".to_string(),
+                kyc_level: 0,
+                role: "user".to_string(),
                 created_at: None,
                 updated_at: None,
             }))
@@ -306,6 +466,12 @@ async fn test_get_withdraw_user_not_found() {
         Arc::new(mock_withdraw_repo),
         Arc::new(mock_saldo_repo),
         Arc::new(mock_user_repo),
+        Arc::new(MockBankAccountRepositoryTrait::new()),
+        Arc::new(MockPayoutProviderTrait::new()),
+        Arc::new(mock_fee_service()),
+        Arc::new(mock_limit_service()),
+        Arc::new(mock_fraud_engine()),
+        MinimumAmounts::default(),
     );
 
     let result = service.get_withdraw_user(1).await;
@@ -337,6 +503,12 @@ async fn test_get_withdraw_user_user_not_found() {
         Arc::new(mock_withdraw_repo),
         Arc::new(mock_saldo_repo),
         Arc::new(mock_user_repo),
+        Arc::new(MockBankAccountRepositoryTrait::new()),
+        Arc::new(MockPayoutProviderTrait::new()),
+        Arc::new(mock_fee_service()),
+        Arc::new(mock_limit_service()),
+        Arc::new(mock_fraud_engine()),
+        MinimumAmounts::default(),
     );
 
     let result = service.get_withdraw_user(1).await;
@@ -368,6 +540,8 @@ async fn test_create_withdraw_success() {
                 email: "john.doe@example.com".to_string(),
                 password: "password".to_string(),
                 noc_transfer: "12345".to_string(),
+                kyc_level: 0,
+                role: "user".to_string(),
                 created_at: Some(Utc::now().naive_utc()),
                 updated_at: Some(Utc::now().naive_utc()),
             }))
@@ -405,15 +579,58 @@ async fn test_create_withdraw_success() {
 
     mock_withdraw_repo
         .expect_create()
-        .with(predicate::always())
-        .return_once(move |input| {
+        .with(predicate::always(), predicate::always())
+        .return_once(move |input, _| {
             Ok(withdraws::Model {
                 withdraw_id: 1,
                 user_id: input.user_id,
                 withdraw_amount: input.withdraw_amount,
                 withdraw_time: input.withdraw_time.naive_utc(),
+                bank_account_id: Some(input.bank_account_id),
+                status: "pending".to_string(),
+                provider_reference: None,
+                payout_reference: None,
+                failure_reason: None,
+                fee_amount: 0,
+                fee_rule_id: None,
+                created_at: Some(Utc::now().naive_utc()),
+                updated_at: Some(Utc::now().naive_utc()),
+            })
+        });
+
+    mock_withdraw_repo
+        .expect_update_status()
+        .withf(|input| {
+            input.status == "pending" && input.provider_reference.as_deref() == Some("SIM-1")
+        })
+        .return_once(move |input| {
+            Ok(Some(withdraws::Model {
+                withdraw_id: input.withdraw_id,
+                user_id,
+                withdraw_amount,
+                withdraw_time: Utc::now().naive_utc(),
+                bank_account_id: Some(1),
+                status: input.status.clone(),
+                provider_reference: input.provider_reference.clone(),
+                payout_reference: None,
+                failure_reason: None,
+                fee_amount: 0,
+                fee_rule_id: None,
                 created_at: Some(Utc::now().naive_utc()),
                 updated_at: Some(Utc::now().naive_utc()),
+            }))
+        });
+
+    let mut mock_payout_provider = MockPayoutProviderTrait::new();
+    mock_payout_provider
+        .expect_create_payout()
+        .withf(|input| {
+            input.reference == "WD-1" && input.bank_code == "bca" && input.amount == 100000
+        })
+        .return_once(|_| {
+            Ok(PayoutResult {
+                provider_reference: "SIM-1".to_string(),
+                status: PayoutStatus::Pending,
             })
         });
 
@@ -421,12 +638,20 @@ async fn test_create_withdraw_success() {
         Arc::new(mock_withdraw_repo),
         Arc::new(mock_saldo_repo),
         Arc::new(mock_user_repo),
+        Arc::new(mock_bank_account_repo(user_id)),
+        Arc::new(mock_payout_provider),
+        Arc::new(mock_fee_service()),
+        Arc::new(mock_limit_service()),
+        Arc::new(mock_fraud_engine()),
+        MinimumAmounts::default(),
     );
 
     let input = CreateWithdrawRequest {
         user_id,
+        bank_account_id: 1,
         withdraw_amount,
         withdraw_time: Utc::now(),
+        device_id: None,
     };
 
     let result = service.create_withdraw(&input).await;
@@ -442,6 +667,8 @@ async fn test_create_withdraw_success() {
     let data = response.data;
     assert_eq!(data.user_id, user_id);
     assert_eq!(data.withdraw_amount, withdraw_amount);
+    assert_eq!(data.status, "pending");
+    assert_eq!(data.provider_reference.as_deref(), Some("SIM-1"));
 }
 
 #[tokio::test]
@@ -465,6 +692,8 @@ async fn test_create_withdraw_insufficient_balance() {
                 email: "john.doe@example.com".to_string(),
                 password: "password".to_string(),
                 noc_transfer: "12345".to_string(),
+                kyc_level: 0,
+                role: "user".to_string(),
                 created_at: Some(Utc::now().naive_utc()),
                 updated_at: Some(Utc::now().naive_utc()),
             }))
@@ -489,12 +718,20 @@ async fn test_create_withdraw_insufficient_balance() {
         Arc::new(mock_withdraw_repo),
         Arc::new(mock_saldo_repo),
         Arc::new(mock_user_repo),
+        Arc::new(mock_bank_account_repo(user_id)),
+        Arc::new(MockPayoutProviderTrait::new()),
+        Arc::new(mock_fee_service()),
+        Arc::new(mock_limit_service()),
+        Arc::new(mock_fraud_engine()),
+        MinimumAmounts::default(),
     );
 
     let input = CreateWithdrawRequest {
         user_id,
+        bank_account_id: 1,
         withdraw_amount,
         withdraw_time: Utc::now(),
+        device_id: None,
     };
 
     let result = service.create_withdraw(&input).await;
@@ -506,7 +743,7 @@ async fn test_create_withdraw_insufficient_balance() {
     assert!(result.is_err());
 
     let error = result.unwrap_err();
-    assert_eq!(error.status, "Error Validation");
+    assert_eq!(error.status, "error");
     assert_eq!(error.message, "Insufficient balance");
 }
 
@@ -530,6 +767,8 @@ async fn test_create_withdraw_saldo_not_found() {
                 email: "john.doe@example.com".to_string(),
                 password: "password".to_string(),
                 noc_transfer: "12345".to_string(),
+                kyc_level: 0,
+                role: "user".to_string(),
                 created_at: Some(Utc::now().naive_utc()),
                 updated_at: Some(Utc::now().naive_utc()),
             }))
@@ -544,12 +783,20 @@ async fn test_create_withdraw_saldo_not_found() {
         Arc::new(mock_withdraw_repo),
         Arc::new(mock_saldo_repo),
         Arc::new(mock_user_repo),
+        Arc::new(mock_bank_account_repo(user_id)),
+        Arc::new(MockPayoutProviderTrait::new()),
+        Arc::new(mock_fee_service()),
+        Arc::new(mock_limit_service()),
+        Arc::new(mock_fraud_engine()),
+        MinimumAmounts::default(),
     );
 
     let input = CreateWithdrawRequest {
         user_id,
+        bank_account_id: 1,
         withdraw_amount,
         withdraw_time: Utc::now(),
+        device_id: None,
     };
 
     let result = service.create_withdraw(&input).await;
@@ -578,12 +825,20 @@ async fn test_create_withdraw_validation_error() {
         Arc::new(mock_withdraw_repo),
         Arc::new(mock_saldo_repo),
         Arc::new(mock_user_repo),
+        Arc::new(MockBankAccountRepositoryTrait::new()),
+        Arc::new(MockPayoutProviderTrait::new()),
+        Arc::new(mock_fee_service()),
+        Arc::new(mock_limit_service()),
+        Arc::new(mock_fraud_engine()),
+        MinimumAmounts::default(),
     );
 
     let input = CreateWithdrawRequest {
         user_id,
+        bank_account_id: 1,
         withdraw_amount,
         withdraw_time: Utc::now(),
+        device_id: None,
     };
 
     let result = service.create_withdraw(&input).await;
@@ -599,6 +854,290 @@ async fn test_create_withdraw_validation_error() {
     assert_eq!(error.message, "Withdraw amount must be at least 50,000");
 }
 
+#[tokio::test]
+async fn test_create_withdraw_rejects_foreign_bank_account() {
+    let user_id = 1;
+
+    let service = WithdrawService::new(
+        Arc::new(MockWithdrawRepositoryTrait::new()),
+        Arc::new(MockSaldoRepositoryTrait::new()),
+        Arc::new(MockUserRepositoryTrait::new()),
+        Arc::new(mock_bank_account_repo(2)),
+        Arc::new(MockPayoutProviderTrait::new()),
+        Arc::new(mock_fee_service()),
+        Arc::new(mock_limit_service()),
+        Arc::new(mock_fraud_engine()),
+        MinimumAmounts::default(),
+    );
+
+    let input = CreateWithdrawRequest {
+        user_id,
+        bank_account_id: 1,
+        withdraw_amount: 100000,
+        withdraw_time: Utc::now(),
+        device_id: None,
+    };
+
+    let error = service.create_withdraw(&input).await.unwrap_err();
+
+    assert_eq!(error.message, "Bank account with id 1 not found");
+}
+
+/// A withdraw whose payout request has not reached the provider yet.
+fn unsent_withdraw(user_id: i32, withdraw_amount: i32) -> withdraws::Model {
+    let mut withdraw = pending_withdraw(user_id, withdraw_amount);
+    withdraw.provider_reference = None;
+    withdraw.payout_reference = Some(format!("WD-{}", withdraw.withdraw_id));
+    withdraw
+}
+
+#[tokio::test]
+async fn test_create_withdraw_keeps_hold_when_payout_request_fails() {
+    let mut mock_withdraw_repo = MockWithdrawRepositoryTrait::new();
+    let mut mock_saldo_repo = MockSaldoRepositoryTrait::new();
+    let mut mock_payout_provider = MockPayoutProviderTrait::new();
+    let user_id = 1;
+    let withdraw_amount = 100000;
+
+    mock_saldo_repo
+        .expect_find_by_user_id()
+        .times(1)
+        .returning(move |_| Ok(Some(saldo_with_balance(user_id, 1000000))));
+
+    mock_saldo_repo
+        .expect_update_saldo_withdraw()
+        .return_once(move |_| Ok(saldo_with_balance(user_id, 900000)));
+
+    // The provider may have accepted it, so the hold is not released
+    mock_saldo_repo.expect_update_balance().never();
+    mock_withdraw_repo.expect_update_status().never();
+
+    mock_withdraw_repo
+        .expect_create()
+        .return_once(move |_, _| Ok(unsent_withdraw(user_id, withdraw_amount)));
+
+    mock_payout_provider
+        .expect_create_payout()
+        .withf(|input| input.reference == "WD-1")
+        .return_once(|_| Err(AppError::PayoutProviderError("Bank offline".to_string())));
+
+    let service = WithdrawService::new(
+        Arc::new(mock_withdraw_repo),
+        Arc::new(mock_saldo_repo),
+        Arc::new(MockUserRepositoryTrait::new()),
+        Arc::new(mock_bank_account_repo(user_id)),
+        Arc::new(mock_payout_provider),
+        Arc::new(mock_fee_service()),
+        Arc::new(mock_limit_service()),
+        Arc::new(mock_fraud_engine()),
+        MinimumAmounts::default(),
+    );
+
+    let input = CreateWithdrawRequest {
+        user_id,
+        bank_account_id: 1,
+        withdraw_amount,
+        withdraw_time: Utc::now(),
+        device_id: None,
+    };
+
+    let response = service.create_withdraw(&input).await.unwrap();
+
+    assert_eq!(response.data.status, "pending");
+}
+
+#[tokio::test]
+async fn test_sync_looks_up_unsent_payouts_by_reference() {
+    let mut mock_withdraw_repo = MockWithdrawRepositoryTrait::new();
+    let mut mock_saldo_repo = MockSaldoRepositoryTrait::new();
+    let mut mock_payout_provider = MockPayoutProviderTrait::new();
+
+    // Accepted by the provider although the request errored
+    let mut accepted = unsent_withdraw(1, 100000);
+    accepted.withdraw_id = 1;
+    accepted.payout_reference = Some("WD-1".to_string());
+    // Never reached the provider, long ago
+    let mut lost = unsent_withdraw(2, 200000);
+    lost.withdraw_id = 2;
+    lost.payout_reference = Some("WD-2".to_string());
+    lost.created_at = Some(Utc::now().naive_utc() - Duration::hours(1));
+    // Never reached the provider, but may still be in flight
+    let mut recent = unsent_withdraw(3, 300000);
+    recent.withdraw_id = 3;
+    recent.payout_reference = Some("WD-3".to_string());
+
+    mock_withdraw_repo
+        .expect_find_by_status()
+        .return_once(move |_, _| Ok(vec![accepted, lost, recent]));
+
+    mock_payout_provider
+        .expect_find_payout()
+        .returning(|reference| {
+            Ok((reference == "WD-1").then(|| PayoutResult {
+                provider_reference: "SIM-1".to_string(),
+                status: PayoutStatus::Succeeded,
+            }))
+        });
+
+    mock_saldo_repo
+        .expect_find_by_user_id()
+        .with(predicate::eq(2))
+        .return_once(|user_id| Ok(Some(saldo_with_balance(user_id, 50000))));
+    mock_saldo_repo
+        .expect_update_balance()
+        .withf(|input| input.user_id == 2 && input.total_balance == 250000)
+        .times(1)
+        .return_once(|input| Ok(saldo_with_balance(input.user_id, input.total_balance)));
+
+    mock_withdraw_repo
+        .expect_update_status()
+        .withf(|input| {
+            (input.withdraw_id == 1
+                && input.status == "succeeded"
+                && input.provider_reference.as_deref() == Some("SIM-1"))
+                || (input.withdraw_id == 2
+                    && input.status == "failed"
+                    && input.failure_reason.as_deref() == Some("Payout never reached the provider"))
+        })
+        .times(2)
+        .returning(|input| {
+            let mut withdraw = unsent_withdraw(1, 100000);
+            withdraw.withdraw_id = input.withdraw_id;
+            withdraw.status = input.status.clone();
+            Ok(Some(withdraw))
+        });
+
+    let service = WithdrawService::new(
+        Arc::new(mock_withdraw_repo),
+        Arc::new(mock_saldo_repo),
+        Arc::new(MockUserRepositoryTrait::new()),
+        Arc::new(MockBankAccountRepositoryTrait::new()),
+        Arc::new(mock_payout_provider),
+        Arc::new(mock_fee_service()),
+        Arc::new(mock_limit_service()),
+        Arc::new(mock_fraud_engine()),
+        MinimumAmounts::default(),
+    );
+
+    let settled = service.sync_pending_payouts().await.unwrap();
+
+    assert_eq!(settled, 2);
+}
+
+#[tokio::test]
+async fn test_sync_pending_payouts_settles_and_releases() {
+    let mut mock_withdraw_repo = MockWithdrawRepositoryTrait::new();
+    let mut mock_saldo_repo = MockSaldoRepositoryTrait::new();
+    let mut mock_payout_provider = MockPayoutProviderTrait::new();
+
+    let mut succeeded = pending_withdraw(1, 100000);
+    succeeded.withdraw_id = 1;
+    succeeded.provider_reference = Some("SIM-1".to_string());
+    let mut failed = pending_withdraw(2, 200000);
+    failed.withdraw_id = 2;
+    failed.provider_reference = Some("SIM-2".to_string());
+    let mut in_flight = pending_withdraw(3, 300000);
+    in_flight.withdraw_id = 3;
+    in_flight.provider_reference = Some("SIM-3".to_string());
+
+    mock_withdraw_repo
+        .expect_find_by_status()
+        .with(predicate::eq("pending"), predicate::always())
+        .return_once(move |_, _| Ok(vec![succeeded, failed, in_flight]));
+
+    mock_payout_provider
+        .expect_get_payout_status()
+        .returning(|reference| match reference {
+            "SIM-1" => Ok(PayoutStatus::Succeeded),
+            "SIM-2" => Ok(PayoutStatus::Failed("Account closed".to_string())),
+            _ => Ok(PayoutStatus::Pending),
+        });
+
+    mock_saldo_repo
+        .expect_find_by_user_id()
+        .with(predicate::eq(2))
+        .return_once(|user_id| Ok(Some(saldo_with_balance(user_id, 50000))));
+
+    mock_saldo_repo
+        .expect_update_balance()
+        .withf(|input| input.user_id == 2 && input.total_balance == 250000)
+        .times(1)
+        .return_once(|input| Ok(saldo_with_balance(input.user_id, input.total_balance)));
+
+    mock_withdraw_repo
+        .expect_update_status()
+        .withf(|input| {
+            (input.withdraw_id == 1 && input.status == "succeeded")
+                || (input.withdraw_id == 2
+                    && input.status == "failed"
+                    && input.failure_reason.as_deref() == Some("Account closed"))
+        })
+        .times(2)
+        .returning(|input| {
+            let mut withdraw = pending_withdraw(1, 100000);
+            withdraw.withdraw_id = input.withdraw_id;
+            withdraw.status = input.status.clone();
+            Ok(Some(withdraw))
+        });
+
+    let service = WithdrawService::new(
+        Arc::new(mock_withdraw_repo),
+        Arc::new(mock_saldo_repo),
+        Arc::new(MockUserRepositoryTrait::new()),
+        Arc::new(MockBankAccountRepositoryTrait::new()),
+        Arc::new(mock_payout_provider),
+        Arc::new(mock_fee_service()),
+        Arc::new(mock_limit_service()),
+        Arc::new(mock_fraud_engine()),
+        MinimumAmounts::default(),
+    );
+
+    let settled = service.sync_pending_payouts().await.unwrap();
+
+    assert_eq!(settled, 2);
+}
+
+#[tokio::test]
+async fn test_sync_leaves_hold_alone_when_withdraw_was_already_settled() {
+    let mut mock_withdraw_repo = MockWithdrawRepositoryTrait::new();
+    let mut mock_payout_provider = MockPayoutProviderTrait::new();
+
+    let mut failed = pending_withdraw(2, 200000);
+    failed.withdraw_id = 2;
+    failed.provider_reference = Some("SIM-2".to_string());
+
+    mock_withdraw_repo
+        .expect_find_by_status()
+        .return_once(move |_, _| Ok(vec![failed]));
+
+    mock_payout_provider
+        .expect_get_payout_status()
+        .returning(|_| Ok(PayoutStatus::Failed("Account closed".to_string())));
+
+    // A concurrent sync already moved it out of pending
+    mock_withdraw_repo
+        .expect_update_status()
+        .times(1)
+        .return_once(|_| Ok(None));
+
+    // No saldo expectations: releasing the hold again would panic the mock
+    let service = WithdrawService::new(
+        Arc::new(mock_withdraw_repo),
+        Arc::new(MockSaldoRepositoryTrait::new()),
+        Arc::new(MockUserRepositoryTrait::new()),
+        Arc::new(MockBankAccountRepositoryTrait::new()),
+        Arc::new(mock_payout_provider),
+        Arc::new(mock_fee_service()),
+        Arc::new(mock_limit_service()),
+        Arc::new(mock_fraud_engine()),
+        MinimumAmounts::default(),
+    );
+
+    let settled = service.sync_pending_payouts().await.unwrap();
+
+    assert_eq!(settled, 0);
+}
+
 #[tokio::test]
 async fn test_update_withdraw_success() {
     let mut mock_withdraw_repo = MockWithdrawRepositoryTrait::new();
@@ -619,6 +1158,13 @@ async fn test_update_withdraw_success() {
                 user_id,
                 withdraw_amount: 3000,
                 withdraw_time: Utc::now().naive_utc(),
+                bank_account_id: None,
+                status: "succeeded".to_string(),
+                provider_reference: None,
+                payout_reference: None,
+                failure_reason: None,
+                fee_amount: 0,
+                fee_rule_id: None,
                 created_at: Some(Utc::now().naive_utc()),
                 updated_at: Some(Utc::now().naive_utc()),
             }))
@@ -648,6 +1194,13 @@ async fn test_update_withdraw_success() {
                 user_id: input.user_id,
                 withdraw_amount: input.withdraw_amount,
                 withdraw_time: input.withdraw_time.naive_utc(),
+                bank_account_id: None,
+                status: "succeeded".to_string(),
+                provider_reference: None,
+                payout_reference: None,
+                failure_reason: None,
+                fee_amount: 0,
+                fee_rule_id: None,
                 created_at: Some(Utc::now().naive_utc()),
                 updated_at: Some(Utc::now().naive_utc()),
             })
@@ -672,6 +1225,12 @@ async fn test_update_withdraw_success() {
         Arc::new(mock_withdraw_repo),
         Arc::new(mock_saldo_repo),
         Arc::new(mock_user_repo),
+        Arc::new(MockBankAccountRepositoryTrait::new()),
+        Arc::new(MockPayoutProviderTrait::new()),
+        Arc::new(mock_fee_service()),
+        Arc::new(mock_limit_service()),
+        Arc::new(mock_fraud_engine()),
+        MinimumAmounts::default(),
     );
 
     let input = UpdateWithdrawRequest {
@@ -706,6 +1265,12 @@ async fn test_update_withdraw_validation_error() {
         Arc::new(mock_withdraw_repo),
         Arc::new(mock_saldo_repo),
         Arc::new(mock_user_repo),
+        Arc::new(MockBankAccountRepositoryTrait::new()),
+        Arc::new(MockPayoutProviderTrait::new()),
+        Arc::new(mock_fee_service()),
+        Arc::new(mock_limit_service()),
+        Arc::new(mock_fraud_engine()),
+        MinimumAmounts::default(),
     );
 
     let input = UpdateWithdrawRequest {
@@ -744,6 +1309,8 @@ async fn test_update_withdraw_saldo_not_found() {
                 email: "john.doe@example.com".to_string(),
                 password: "password".to_string(),
                 noc_transfer: "12345".to_string(),
+                kyc_level: 0,
+                role: "user".to_string(),
                 created_at: Some(Utc::now().naive_utc()),
                 updated_at: Some(Utc::now().naive_utc()),
             }))
@@ -758,6 +1325,13 @@ async fn test_update_withdraw_saldo_not_found() {
                 user_id,
                 withdraw_amount: 50000,
                 withdraw_time: Utc::now().naive_utc(),
+                bank_account_id: None,
+                status: "succeeded".to_string(),
+                provider_reference: None,
+                payout_reference: None,
+                failure_reason: None,
+                fee_amount: 0,
+                fee_rule_id: None,
                 created_at: Some(Utc::now().naive_utc()),
                 updated_at: Some(Utc::now().naive_utc()),
             }))
@@ -772,6 +1346,12 @@ async fn test_update_withdraw_saldo_not_found() {
         Arc::new(mock_withdraw_repo),
         Arc::new(mock_saldo_repo),
         Arc::new(mock_user_repo),
+        Arc::new(MockBankAccountRepositoryTrait::new()),
+        Arc::new(MockPayoutProviderTrait::new()),
+        Arc::new(mock_fee_service()),
+        Arc::new(mock_limit_service()),
+        Arc::new(mock_fraud_engine()),
+        MinimumAmounts::default(),
     );
 
     let input = UpdateWithdrawRequest {
@@ -793,3 +1373,97 @@ async fn test_update_withdraw_saldo_not_found() {
     assert_eq!(error.status, "error");
     assert_eq!(error.message, format!("Saldo not found"));
 }
+
+#[tokio::test]
+async fn test_sync_keeps_withdraw_pending_when_fee_cannot_be_posted() {
+    let mut mock_withdraw_repo = MockWithdrawRepositoryTrait::new();
+    let mut mock_payout_provider = MockPayoutProviderTrait::new();
+    let mut mock_fee_service = MockFeeServiceTrait::new();
+
+    let mut succeeded = pending_withdraw(1, 100000);
+    succeeded.fee_amount = 2500;
+    succeeded.fee_rule_id = Some(4);
+
+    mock_withdraw_repo
+        .expect_find_by_status()
+        .return_once(move |_, _| Ok(vec![succeeded]));
+
+    mock_payout_provider
+        .expect_get_payout_status()
+        .returning(|_| Ok(PayoutStatus::Succeeded));
+
+    mock_fee_service
+        .expect_post_fee()
+        .withf(|input| input.fee_rule_id == Some(4) && input.amount == 2500)
+        .times(1)
+        .returning(|_| Err(AppError::FeeRuleError("revenue ledger down".to_string()).into()));
+
+    // The withdraw is only settled once its fee is in revenue
+    mock_withdraw_repo.expect_update_status().never();
+
+    let service = WithdrawService::new(
+        Arc::new(mock_withdraw_repo),
+        Arc::new(MockSaldoRepositoryTrait::new()),
+        Arc::new(MockUserRepositoryTrait::new()),
+        Arc::new(MockBankAccountRepositoryTrait::new()),
+        Arc::new(mock_payout_provider),
+        Arc::new(mock_fee_service),
+        Arc::new(mock_limit_service()),
+        Arc::new(mock_fraud_engine()),
+        MinimumAmounts::default(),
+    );
+
+    let settled = service.sync_pending_payouts().await.unwrap();
+
+    assert_eq!(settled, 0);
+}
+
+#[tokio::test]
+async fn test_sync_reverses_fee_when_withdraw_was_already_settled() {
+    let mut mock_withdraw_repo = MockWithdrawRepositoryTrait::new();
+    let mut mock_payout_provider = MockPayoutProviderTrait::new();
+    let mut mock_fee_service = MockFeeServiceTrait::new();
+
+    let mut succeeded = pending_withdraw(1, 100000);
+    succeeded.fee_amount = 2500;
+
+    mock_withdraw_repo
+        .expect_find_by_status()
+        .return_once(move |_, _| Ok(vec![succeeded]));
+
+    mock_payout_provider
+        .expect_get_payout_status()
+        .returning(|_| Ok(PayoutStatus::Succeeded));
+
+    mock_withdraw_repo
+        .expect_update_status()
+        .times(1)
+        .return_once(|_| Ok(None));
+
+    mock_fee_service
+        .expect_post_fee()
+        .withf(|input| input.amount == 2500)
+        .times(1)
+        .returning(|_| Ok(()));
+    mock_fee_service
+        .expect_post_fee()
+        .withf(|input| input.amount == -2500)
+        .times(1)
+        .returning(|_| Ok(()));
+
+    let service = WithdrawService::new(
+        Arc::new(mock_withdraw_repo),
+        Arc::new(MockSaldoRepositoryTrait::new()),
+        Arc::new(MockUserRepositoryTrait::new()),
+        Arc::new(MockBankAccountRepositoryTrait::new()),
+        Arc::new(mock_payout_provider),
+        Arc::new(mock_fee_service),
+        Arc::new(mock_limit_service()),
+        Arc::new(mock_fraud_engine()),
+        MinimumAmounts::default(),
+    );
+
+    let settled = service.sync_pending_payouts().await.unwrap();
+
+    assert_eq!(settled, 0);
+}